@@ -22,10 +22,10 @@ fn main() -> Result<(), Box<dyn Error>> {
     // 3) walk up ancestors until we find the profile directory
     let mut candidate: &Path = out_dir.as_path();
     let dest = loop {
-        if let Some(name) = candidate.file_name().and_then(|s| s.to_str()) {
-            if name == profile {
-                break candidate.to_path_buf();
-            }
+        if let Some(name) = candidate.file_name().and_then(|s| s.to_str())
+            && name == profile
+        {
+            break candidate.to_path_buf();
         }
         candidate = candidate
             .parent()
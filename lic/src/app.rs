@@ -2,12 +2,19 @@
 //!
 //! Defines the main application struct `LichenApp` and its core execution logic.
 
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use crate::commands::{apply, generate, init, unapply}; // Import handlers
+use crate::commands::{
+    apply, check, compat, deps, generate, hook, init, list, show, unapply, undo, watch,
+}; // Import handlers
 use crate::config::Config;
 use crate::error::LichenError;
-use crate::models::Commands;
+use crate::journal::{self, Journal};
+use crate::models::{Commands, OutputFormat};
+use crate::utils;
 use log::debug;
 
 /// The main application structure for Lichen.
@@ -33,7 +40,9 @@ impl LichenApp {
     /// A `Result` indicating success or a `FileProcessingError`.
     pub async fn run(&self, command: Commands, config_path: PathBuf) -> Result<(), LichenError> {
         debug!("Dispatching command: {:?}", command);
-        let cfg = Config::load_or_default(config_path)?;
+        let cfg = Config::load_or_default(&config_path)?;
+        // The journal is shared by the whole project, so `lic undo` finds it from anywhere
+        let journal = || Arc::new(Journal::new(&journal::project_root(&config_path)));
         match command {
             Commands::Gen(args) => {
                 // If there are no licenses in your configuration, no need for multiple runs, and so you can safely fallback to a single run.
@@ -42,7 +51,7 @@ impl LichenApp {
                     generate::handle_gen(&settings)?;
                 } else {
                     // Fallback loop through each and every license by index
-                    for (idx, _license) in cfg.licenses.iter().enumerate() {
+                    for (idx, _license) in cfg.licenses.iter().flatten().enumerate() {
                         let settings = generate::GenSettings::new(&args, &cfg, Some(idx))?;
                         generate::handle_gen(&settings)?;
                     }
@@ -50,26 +59,95 @@ impl LichenApp {
 
                 Ok(()) // No errs
             }
+            Commands::Apply(args) if args.stdin.unwrap_or(false) => {
+                let filename = args
+                    .filename
+                    .clone()
+                    .expect("clap requires --filename with --stdin");
+                let mut content = Vec::new();
+                std::io::stdin().read_to_end(&mut content)?;
+
+                // Each license covering the file applies its header, as `apply` would on disk
+                for settings in apply::ApplySettings::for_config(&args, &cfg)? {
+                    content = apply::handle_apply_stdin(&settings, &filename, content)?;
+                }
+
+                std::io::stdout().write_all(&content)?;
+                Ok(())
+            }
+            Commands::Apply(args) if args.watch.unwrap_or(false) => {
+                let settings = apply::ApplySettings::for_config(&args, &cfg)?;
+                let journal = journal();
+                watch::handle_watch(settings, &journal).await
+            }
             Commands::Apply(args) => {
-                // If there are no licenses in your configuration, no need for multiple runs, and so you can safely fallback to a single run.
-                if cfg.licenses.is_none() {
-                    let settings = apply::ApplySettings::new(&args, &cfg, None)?;
-                    apply::handle_apply(&settings).await?;
-                } else {
-                    // Fallback loop through each license by index
-                    for (idx, _license) in cfg.licenses.iter().enumerate() {
-                        let settings = apply::ApplySettings::new(&args, &cfg, Some(idx))?;
-                        apply::handle_apply(&settings).await?;
-                    }
+                if args.format == Some(OutputFormat::Sarif) {
+                    return Err(utils::sarif_unsupported());
                 }
-                Ok(()) // No errs
+
+                let settings = apply::ApplySettings::for_config(&args, &cfg)?;
+                let journal = journal();
+                let unstaged = unstaged_files(args.file_args.staged)?;
+
+                // The outcome of every file across licenses, reported even if some failed
+                let (summary, mut errors) = apply::handle_apply_all(&settings, &journal).await?;
+
+                // Headers applied to staged files are committed with them
+                if args.file_args.staged.unwrap_or(false)
+                    && let Err(err) =
+                        utils::stage_files(Path::new("."), &journal.recorded_paths(), &unstaged)
+                {
+                    errors.push(err);
+                }
+
+                if let Err(err) = utils::finish_run(
+                    summary,
+                    "application",
+                    args.format.unwrap_or_default(),
+                    args.dry_run.unwrap_or(false) || args.patch.is_some(),
+                    args.patch.as_deref(),
+                ) {
+                    errors.push(err);
+                }
+                LichenError::combine(errors).map_or(Ok(()), Err)
             }
+            Commands::Check(args) => check::handle_check(&args, &cfg, &journal()).await,
+            Commands::Compat(args) => compat::handle_compat(&args, &cfg).await,
+            Commands::Deps(args) => deps::handle_deps(&args, &cfg),
+            Commands::Hook(args) => hook::handle_hook(args), // CLI only
             Commands::Init(args) => init::handle_init(args), // CLI only
-            Commands::Unapply(args) => unapply::handle_unapply(args).await, // CLI only
+            Commands::List(args) => list::handle_list(&args),
+            Commands::Show(args) => show::handle_show(&args),
+            Commands::Undo => undo::handle_undo(&journal::project_root(&config_path)),
+            Commands::Unapply(args) => {
+                if args.format == Some(OutputFormat::Sarif) {
+                    return Err(utils::sarif_unsupported());
+                }
+                let patch = args.patch.clone();
+                let staged = args.file_args.staged.unwrap_or(false);
+                let format = args.format.unwrap_or_default();
+                let dry_run = args.dry_run.unwrap_or(false) || patch.is_some();
+                let journal = journal();
+                let unstaged = unstaged_files(args.file_args.staged)?;
+                let summary = unapply::handle_unapply(args, &cfg, &journal).await?;
+                if staged {
+                    utils::stage_files(Path::new("."), &journal.recorded_paths(), &unstaged)?;
+                }
+                utils::finish_run(summary, "removal", format, dry_run, patch.as_deref())
+            }
         }
     }
 }
 
+/// The files with unstaged changes when working on the staged files, which must not be staged
+/// again once edited. Empty otherwise.
+fn unstaged_files(staged: Option<bool>) -> Result<HashSet<PathBuf>, LichenError> {
+    if !staged.unwrap_or(false) {
+        return Ok(HashSet::new());
+    }
+    utils::git_changed_files(Path::new("."), &utils::GitChanges::Unstaged)
+}
+
 impl Default for LichenApp {
     fn default() -> Self {
         Self::new()
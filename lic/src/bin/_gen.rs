@@ -1,9 +1,11 @@
 use heck::ToUpperCamelCase; // Keep this for non-acronym parts
-use quote::{format_ident, quote};
-use std::{env, fs, path::PathBuf};
+use quote::{ToTokens, format_ident, quote};
+use std::{collections::HashMap, env, fs, path::PathBuf};
 
 // --- Configuration (same as before) ---
 const LICENSE_DIR: &str = "lic/assets/licenses";
+const LICENSE_LIST: &str = "lic/assets/license-list.json";
+const LICENSE_REPLACEMENTS: &str = "lic/assets/license-replacements.json";
 // --- End Configuration ---
 
 // Helper function to check if a string slice consists entirely of uppercase ASCII letters
@@ -11,198 +13,183 @@ fn is_all_caps_acronym(s: &str) -> bool {
     !s.is_empty() && s.chars().all(|c| c.is_ascii_uppercase())
 }
 
-// async fn is_osi(license_path: &PathBuf) -> Result<bool, Box<dyn std::error::Error>> {
-//     use metadata_gen::utils::async_extract_metadata_from_file;
-
-//     // Extract metadata from the provided license_path instead of the hardcoded path
-//     let (metadata, _, _) =
-//         async_extract_metadata_from_file(&license_path.to_string_lossy().to_string()).await?;
-
-//     // Check if osiApproved is in the metadata and return its value
-//     if let Some(osi_status) = metadata.get("osiApproved") {
-//         if osi_status != "unknown" {
-//             // Check for edge case where there is no decision.
-//             if osi_status
-//                 .trim()
-//                 .parse::<bool>()
-//                 .expect("Should include osi approved key in meta")
-//                 == true
-//             // Convert the value to bool
-//             {
-//                 Ok(true)
-//             } else {
-//                 Ok(false)
-//             }
-//         } else {
-//             Ok(false) // If marked unknown, safe to say it's not OSI in a way that you could trust.
-//         }
-//     } else {
-//         // If osiApproved key is nonexistent, assume false.
-//         Ok(false)
-//     }
-// }
-
-fn main() -> std::io::Result<()> {
-    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
-    let generated_file_path = out_dir.join("generated_licenses.rs");
-    let license_dir_path = PathBuf::from(LICENSE_DIR);
-
-    let mut variants = Vec::new();
-    let mut license_details = Vec::new(); // (variant_ident, filename_str, is_osi_bool)
-
-    if license_dir_path.is_dir() {
-        for entry in fs::read_dir(&license_dir_path)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.is_file() && path.extension().is_some_and(|ext| ext == "txt") {
-                if let (Some(file_stem), Some(file_name_osstr)) = (
-                    path.with_extension("").file_stem(),
-                    path.with_extension("").with_extension("").file_name(),
-                ) {
-                    let file_stem_str = file_stem.to_string_lossy();
-                    let file_name_str = file_name_osstr.to_string_lossy().to_string(); // Keep original filename
-
-                    // --- Step 1: Number and Dot Replacements ---
-                    let mut stem_with_replacements = String::new();
-                    for c in file_stem_str.chars() {
-                        match c {
-                            '0' => stem_with_replacements.push_str("Zero"),
-                            '1' => stem_with_replacements.push_str("One"),
-                            '2' => stem_with_replacements.push_str("Two"),
-                            '3' => stem_with_replacements.push_str("Three"),
-                            '4' => stem_with_replacements.push_str("Four"),
-                            '5' => stem_with_replacements.push_str("Five"),
-                            '6' => stem_with_replacements.push_str("Six"),
-                            '7' => stem_with_replacements.push_str("Seven"),
-                            '8' => stem_with_replacements.push_str("Eight"),
-                            '9' => stem_with_replacements.push_str("Nine"),
-                            '+' => stem_with_replacements.push_str("Plus"),
-                            '.' => stem_with_replacements.push_str("Dot"),
-                            _ => stem_with_replacements.push(c),
-                        }
-                    }
+/// What the SPDX license list says about a license or an exception.
+#[derive(Default)]
+struct ListEntry {
+    name: Option<String>,
+    reference: String,
+    /// Other pages with the text of the license, ex: its upstream.
+    see_also: Vec<String>,
+    osi_approved: bool,
+    fsf_libre: bool,
+    deprecated: bool,
+    exception: bool,
+    /// The expression recommended in place of a deprecated identifier.
+    replacement: Option<String>,
+}
 
-                    // --- Step 2: Split into parts based on common delimiters ---
-                    // We split by non-alphanumeric characters to separate words/acronyms
-                    let parts: Vec<&str> = stem_with_replacements
-                        .split(|c: char| !c.is_ascii_alphanumeric())
-                        .filter(|s| !s.is_empty()) // Remove empty strings resulting from splits
-                        .collect();
-
-                    // --- Step 3 & 4: Process Parts (Acronyms/CamelCase) & Join ---
-                    let mut final_ident_string = String::new();
-                    for part in parts {
-                        if is_all_caps_acronym(part) {
-                            // Append acronym part as is (e.g., "GPL", "MIT")
-                            final_ident_string.push_str(part);
-                        } else {
-                            // Convert non-acronym parts to UpperCamelCase (e.g., "only" -> "Only")
-                            final_ident_string.push_str(&part.to_upper_camel_case());
-                        }
-                    }
+// Reads the license list, keyed by SPDX identifier. It merges `licenses.json` and `exceptions.json`
+// from https://github.com/spdx/license-list-data, trimmed to the fields read here
+fn read_license_list() -> std::io::Result<HashMap<String, ListEntry>> {
+    let data: serde_json::Value = serde_json::from_str(&fs::read_to_string(LICENSE_LIST)?)?;
+    let flag = |entry: &serde_json::Value, key: &str| entry[key].as_bool().unwrap_or(false);
+    let text = |entry: &serde_json::Value, key: &str| entry[key].as_str().map(str::to_string);
+    let urls = |entry: &serde_json::Value| -> Vec<String> {
+        entry["seeAlso"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|url| url.as_str().map(str::to_string))
+            .collect()
+    };
 
-                    // Handle cases where the split results in an empty identifier (e.g., filename was just "-.--")
-                    if final_ident_string.is_empty() {
-                        eprintln!(
-                            "cargo:warning=Could not generate valid identifier for filename: {}",
-                            file_name_str
-                        );
-                        // Optionally create a placeholder identifier or skip this file
-                        // For now, we'll create a potentially invalid identifier to highlight the issue
-                        final_ident_string.push_str("InvalidLicenseName");
-                    }
+    let mut list = HashMap::new();
+    for entry in data["licenses"].as_array().into_iter().flatten() {
+        if let Some(id) = text(entry, "licenseId") {
+            list.insert(
+                id,
+                ListEntry {
+                    name: text(entry, "name"),
+                    reference: text(entry, "reference").unwrap_or_default(),
+                    see_also: urls(entry),
+                    osi_approved: flag(entry, "isOsiApproved"),
+                    fsf_libre: flag(entry, "isFsfLibre"),
+                    deprecated: flag(entry, "isDeprecatedLicenseId"),
+                    exception: false,
+                    replacement: None,
+                },
+            );
+        }
+    }
+    for entry in data["exceptions"].as_array().into_iter().flatten() {
+        if let Some(id) = text(entry, "licenseExceptionId") {
+            list.insert(
+                id,
+                ListEntry {
+                    reference: text(entry, "reference").unwrap_or_default(),
+                    see_also: urls(entry),
+                    deprecated: flag(entry, "isDeprecatedLicenseId"),
+                    exception: true,
+                    ..Default::default()
+                },
+            );
+        }
+    }
 
-                    // --- Step 5: Format the final identifier ---
-                    let variant_ident = format_ident!("{}", final_ident_string);
-
-                    // --- End Modification ---
-                    // let is_osi = tokio::runtime::Runtime::new().unwrap().block_on(async {
-                    //     match is_osi(&path).await {
-                    //         Ok(val) => val,
-                    //         Err(_) => false, // Handle any errors by defaulting to false
-                    //     }
-                    // });
-                    variants.push(variant_ident.clone()); // Collect idents for enum definition
-                    // Store the ORIGINAL filename string along with the generated variant ident and OSI status
-                    license_details.push((variant_ident, file_name_str));
-                }
-            }
+    // The license list only flags deprecated identifiers, what replaces them is kept alongside
+    let replacements: HashMap<String, String> =
+        serde_json::from_str(&fs::read_to_string(LICENSE_REPLACEMENTS)?)?;
+    for (id, replacement) in replacements {
+        match list.get_mut(&id) {
+            Some(entry) => entry.replacement = Some(replacement),
+            None => eprintln!(
+                "cargo:warning=Replaced license '{}' is missing from {}",
+                id, LICENSE_LIST
+            ),
         }
+    }
+    Ok(list)
+}
+
+// Matches the variants for which the flag is set, or none
+fn flag_match<T: ToTokens>(
+    license_details: &[(T, String, String, ListEntry)],
+    flag: fn(&ListEntry) -> bool,
+) -> impl ToTokens {
+    let variants: Vec<_> = license_details
+        .iter()
+        .filter(|(_, _, _, entry)| flag(entry))
+        .map(|(variant, _, _, _)| variant)
+        .collect();
+    if variants.is_empty() {
+        quote! { false }
     } else {
-        eprintln!(
-            "cargo:warning=License directory '{}' not found or not a directory. Generating empty License enum.",
-            LICENSE_DIR
-        );
+        quote! { matches!(self, #( Self::#variants )|*) }
     }
+}
 
-    // --- Generate Code using quote! (No changes needed here from previous version) ---
+// Generates the enum of a catalog of templates, and the methods shared by licenses and exceptions
+fn catalog_code<T: ToTokens>(
+    enum_name: &str,
+    parse_fn: &str,
+    details: &[(T, String, String, ListEntry)],
+) -> (impl ToTokens, impl ToTokens) {
+    let enum_ident = format_ident!("{}", enum_name);
+    let parse_fn = format_ident!("{}", parse_fn);
 
-    let variants_with_attrs = license_details.iter().map(|(variant, filename)| {
+    let variants_with_attrs = details.iter().map(|(variant, spdx_id, _, _)| {
         quote! {
-            #[value(name = #filename)] // Original SPDX id
+            #[value(name = #spdx_id)] // Original SPDX id
             #variant
         }
     });
-
-    let name_match_arms = license_details.iter().map(|(variant, filename)| {
-        quote! { Self::#variant => #filename }
+    let name_match_arms = details.iter().map(|(variant, spdx_id, _, _)| {
+        quote! { Self::#variant => #spdx_id }
     });
-    // let is_osi_match_arms = license_details.iter().map(|(variant, _, is_osi)| {
-    //     quote! { Self::#variant => #is_osi }
-    // });
-    let from_str_match_arms = license_details.iter().map(|(variant, filename)| {
-        // Match against parsed filename (SPDX ID)
-        quote! { #filename => Ok(Self::#variant), }
+    let reference_match_arms = details.iter().map(|(variant, _, _, entry)| {
+        let reference = &entry.reference;
+        quote! { Self::#variant => #reference }
     });
-
-    let template_content_match_arms = license_details.iter().map(|(variant, template_path)| {
+    let see_also_match_arms = details.iter().map(|(variant, _, _, entry)| {
+        let see_also = &entry.see_also;
+        quote! { Self::#variant => &[#( #see_also ),*] }
+    });
+    let is_deprecated = flag_match(details, |entry| entry.deprecated);
+    let replacement_match_arms =
+        details
+            .iter()
+            .map(|(variant, _, _, entry)| match &entry.replacement {
+                Some(replacement) => quote! { Self::#variant => Some(#replacement) },
+                None => quote! { Self::#variant => None },
+            });
+    let template_content_match_arms = details.iter().map(|(variant, _, template_path, _)| {
         // Use concat! to join MANIFEST_DIR with the relative path
         quote! {
             Self::#variant => include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/licenses/", #template_path, ".template.txt"))
         }
     });
-
-    // Retrive just the variant idents for the iter() method
-    let variant_idents = license_details.iter().map(|(variant, _)| variant);
-
-    let generated_code = quote! {
-        #![allow(clippy::all)]
-
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
-        pub enum License {
+    let enum_code = quote! {
+        #[allow(clippy::all)]
+        #[derive(Debug, Clone, Deserialize, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+        #[allow(non_camel_case_types)]
+        pub enum #enum_ident {
              #( #variants_with_attrs ),*
         }
+    };
 
-        #[derive(Debug, Clone, PartialEq, Eq)]
-        pub struct ParseLicenseError;
-
-        impl std::fmt::Display for ParseLicenseError {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "Provided string does not match any known license filename")
+    let impl_code = quote! {
+        impl #enum_ident {
+            /// Returns the SPDX identifier of the license (e.g., "Apache-2.0").
+            pub fn spdx_id(&self) -> &'static str {
+                match self {
+                    #( #name_match_arms ),* }
             }
-        }
 
-        impl std::error::Error for ParseLicenseError {}
+            /// Returns the page of the license on the SPDX website.
+            pub fn reference_url(&self) -> &'static str {
+                match self {
+                    #( #reference_match_arms ),* }
+            }
 
-        impl License {
-            /// Returns the original filename of the license (e.g., "Apache-2.0.md").
-            pub fn spdx_id(&self) -> &'static str {
+            /// Returns the other pages the SPDX license list gives for the text, ex: its upstream.
+            pub fn see_also(&self) -> &'static [&'static str] {
                 match self {
-                    #( #name_match_arms ),* }
+                    #( #see_also_match_arms ),* }
             }
 
-            // /// Returns true if the license is OSI approved (based on the build-time list).
-            // pub fn is_osi_approved(&self) -> bool {
-            //     match self {
-            //         #( #is_osi_match_arms ),* }
-            // }
+            /// Returns true if the SPDX identifier is deprecated.
+            pub fn is_deprecated(&self) -> bool {
+                #is_deprecated
+            }
 
-            /// Returns an iterator over all available license variants.
-            pub fn iter() -> impl Iterator<Item = Self> {
-                 [ #( Self::#variant_idents ),* ].iter().copied()
+            /// Returns the SPDX expression recommended in place of a deprecated identifier, if any.
+            pub fn replacement(&self) -> Option<&'static str> {
+                match self {
+                    #( #replacement_match_arms ),* }
             }
 
+            // Iterate with `clap::ValueEnum::value_variants()`
+
             /// Returns the embedded template content for the license.
             /// The content will be from `.template.txt` if available, otherwise `.txt`.
              pub fn template_content(&self) -> &'static str {
@@ -212,32 +199,209 @@ fn main() -> std::io::Result<()> {
             }
         }
 
-        impl std::fmt::Display for License {
+        impl std::fmt::Display for #enum_ident {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                // Delegate formatting to the name() method which returns the original filename
+                // Delegate formatting to the spdx_id() method
                 write!(f, "{}", self.spdx_id())
             }
         }
 
-        impl std::str::FromStr for License {
+        impl std::str::FromStr for #enum_ident {
             type Err = ParseLicenseError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    // Open up those match arms
-                    #( #from_str_match_arms )*
-                    // If no match, throw error
-                    _ => Err(ParseLicenseError),
+                // Ignoring case and separators, with suggestions when nothing matches
+                crate::models::#parse_fn(s).map_err(ParseLicenseError)
+            }
+        }
+    };
+
+    (enum_code, impl_code)
+}
+
+fn main() -> std::io::Result<()> {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+    let generated_file_path = out_dir.join("generated_licenses.rs");
+    let generated_models_path = out_dir.join("generated_models.rs");
+    let license_dir_path = PathBuf::from(LICENSE_DIR);
+
+    let mut variants = Vec::new();
+    let mut license_details = Vec::new(); // (variant_ident, spdx_id, filename_str, list_entry)
+    let mut license_list = read_license_list()?;
+
+    if license_dir_path.is_dir() {
+        for entry in fs::read_dir(&license_dir_path)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if path.is_file()
+                && path.extension().is_some_and(|ext| ext == "txt")
+                && let (Some(file_stem), Some(file_name_osstr)) = (
+                    path.with_extension("").file_stem(),
+                    path.with_extension("").with_extension("").file_name(),
+                )
+            {
+                let file_stem_str = file_stem.to_string_lossy();
+                let file_name_str = file_name_osstr.to_string_lossy().to_string(); // Keep original filename
+
+                // --- Step 1: Number and Dot Replacements ---
+                let mut stem_with_replacements = String::new();
+                for c in file_stem_str.chars() {
+                    match c {
+                        '0' => stem_with_replacements.push_str("Zero"),
+                        '1' => stem_with_replacements.push_str("One"),
+                        '2' => stem_with_replacements.push_str("Two"),
+                        '3' => stem_with_replacements.push_str("Three"),
+                        '4' => stem_with_replacements.push_str("Four"),
+                        '5' => stem_with_replacements.push_str("Five"),
+                        '6' => stem_with_replacements.push_str("Six"),
+                        '7' => stem_with_replacements.push_str("Seven"),
+                        '8' => stem_with_replacements.push_str("Eight"),
+                        '9' => stem_with_replacements.push_str("Nine"),
+                        '+' => stem_with_replacements.push_str("Plus"),
+                        '.' => stem_with_replacements.push_str("Dot"),
+                        _ => stem_with_replacements.push(c),
+                    }
                 }
+
+                // --- Step 2: Split into parts based on common delimiters ---
+                // We split by non-alphanumeric characters to separate words/acronyms
+                let parts: Vec<&str> = stem_with_replacements
+                    .split(|c: char| !c.is_ascii_alphanumeric())
+                    .filter(|s| !s.is_empty()) // Remove empty strings resulting from splits
+                    .collect();
+
+                // --- Step 3 & 4: Process Parts (Acronyms/CamelCase) & Join ---
+                let mut final_ident_string = String::new();
+                for part in parts {
+                    if is_all_caps_acronym(part) {
+                        // Append acronym part as is (e.g., "GPL", "MIT")
+                        final_ident_string.push_str(part);
+                    } else {
+                        // Convert non-acronym parts to UpperCamelCase (e.g., "only" -> "Only")
+                        final_ident_string.push_str(&part.to_upper_camel_case());
+                    }
+                }
+
+                // Handle cases where the split results in an empty identifier (e.g., filename was just "-.--")
+                if final_ident_string.is_empty() {
+                    eprintln!(
+                        "cargo:warning=Could not generate valid identifier for filename: {}",
+                        file_name_str
+                    );
+                    // Optionally create a placeholder identifier or skip this file
+                    // For now, we'll create a potentially invalid identifier to highlight the issue
+                    final_ident_string.push_str("InvalidLicenseName");
+                }
+
+                // --- Step 5: Format the final identifier ---
+                let variant_ident = format_ident!("{}", final_ident_string);
+
+                // --- Step 6: Look up the license list, deprecated ids are prefixed in filenames ---
+                let spdx_id = file_name_str
+                    .strip_prefix("deprecated_")
+                    .unwrap_or(&file_name_str);
+                let list_entry = license_list.remove(spdx_id).unwrap_or_else(|| {
+                    eprintln!(
+                        "cargo:warning=License '{}' is missing from {}",
+                        spdx_id, LICENSE_LIST
+                    );
+                    ListEntry {
+                        reference: format!("https://spdx.org/licenses/{}.html", spdx_id),
+                        ..Default::default()
+                    }
+                });
+
+                let spdx_id = spdx_id.to_string();
+                variants.push(variant_ident.clone()); // Collect idents for enum definition
+                // Store the SPDX id and the ORIGINAL filename along with the generated variant ident and list entry
+                license_details.push((variant_ident, spdx_id, file_name_str, list_entry));
             }
         }
+    } else {
+        eprintln!(
+            "cargo:warning=License directory '{}' not found or not a directory. Generating empty License enum.",
+            LICENSE_DIR
+        );
+    }
+
+    // --- Generate Code using quote! ---
+
+    // Sorted, so the generated code is the same whatever the filesystem order.
+    // Deprecated filenames are prefixed and come last, so templates match current ids first
+    license_details.sort_by(|a, b| a.2.cmp(&b.2));
+    // Exceptions only make sense after `WITH`, they get their own enum
+    let (exception_details, license_details): (Vec<_>, Vec<_>) = license_details
+        .into_iter()
+        .partition(|(_, _, _, entry)| entry.exception);
+
+    let (license_enum, license_impl) =
+        catalog_code("License", "parse_license_id", &license_details);
+    let (exception_enum, exception_impl) =
+        catalog_code("LicenseException", "parse_exception_id", &exception_details);
+
+    let full_name_match_arms =
+        license_details
+            .iter()
+            .map(|(variant, _, _, entry)| match &entry.name {
+                Some(name) => quote! { Self::#variant => Some(#name) },
+                None => quote! { Self::#variant => None },
+            });
+    let is_osi_approved = flag_match(&license_details, |entry| entry.osi_approved);
+    let is_fsf_libre = flag_match(&license_details, |entry| entry.fsf_libre);
+
+    // The enums go in `models.rs`, where the build script can see them for completions
+    let generated_models = quote! {
+        #license_enum
+        #exception_enum
+    };
+
+    let generated_code = quote! {
+        use crate::models::{License, LicenseException};
+
+        /// An unknown identifier, with the closest known ones.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct ParseLicenseError(pub String);
+
+        impl std::fmt::Display for ParseLicenseError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl std::error::Error for ParseLicenseError {}
+
+        #license_impl
+
+        impl License {
+            /// Returns the full name of the license (e.g., "MIT License").
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    #( #full_name_match_arms ),* }
+            }
+
+            /// Returns true if the license is approved by the Open Source Initiative.
+            pub fn is_osi_approved(&self) -> bool {
+                #is_osi_approved
+            }
+
+            /// Returns true if the Free Software Foundation considers the license libre.
+            pub fn is_fsf_libre(&self) -> bool {
+                #is_fsf_libre
+            }
+        }
+
+        #exception_impl
     };
 
     fs::write(&generated_file_path, generated_code.to_string())?;
+    fs::write(&generated_models_path, generated_models.to_string())?;
 
     // --- Tell Cargo when to rerun the script (same as before) ---
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed={}", LICENSE_DIR);
+    println!("cargo:rerun-if-changed={}", LICENSE_LIST);
+    println!("cargo:rerun-if-changed={}", LICENSE_REPLACEMENTS);
     if license_dir_path.is_dir() {
         for entry in fs::read_dir(&license_dir_path)?.flatten() {
             if entry.path().is_file() {
@@ -1,21 +1,28 @@
 //! # Apply Command
 //!
-//! Logic for the `lichen apply` command.
+//! Logic for the `lic apply` command.
 
 use crate::config::Config;
 use crate::error::LichenError;
+use crate::header::HeaderMatcher;
+use crate::journal::Journal;
 use crate::models::ApplyArgs;
 use crate::models::Authors;
-use crate::models::License; // Ensure License is imported
+use crate::models::ThirdPartyAction;
+use crate::models::{License, LicenseException, LicenseExpression}; // Ensure License is imported
 use crate::utils;
+use crate::utils::ProcessingSummary;
 use jiff::civil::Date;
 use log::{debug, info, trace};
 use regex::Regex;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct ApplySettings {
     pub license: License,
+    /// The exception to the license, from `WITH` in its expression.
+    pub exception: Option<LicenseException>,
     pub prefer_block: bool,
     pub multiple: bool,
     pub authors: Option<Authors>,
@@ -23,15 +30,24 @@ pub struct ApplySettings {
     pub targets: Vec<PathBuf>,
     pub date: Date,
     pub dry_run: bool,
+    pub width: Option<usize>,
+    pub legacy_encodings: bool,
+    pub marker: bool,
+    pub replace_foreign: bool,
+    pub third_party: ThirdPartyAction,
+    /// Licenses whose existing headers are replaced, even once their marker is lost.
+    pub known_licenses: Vec<License>,
+    /// Only process the files git reports as changed.
+    pub git_changes: Option<utils::GitChanges>,
 }
 
 impl ApplySettings {
     pub fn new(cli: &ApplyArgs, cfg: &Config, index: Option<usize>) -> Result<Self, LichenError> {
-        let license = if let Some(cli_lic) = cli.license_args.license {
+        let expression = if let Some(cli_lic) = cli.license_args.license {
             // An explicity passed CLI license
             cli_lic
         } else if let Some(idx) = index {
-            // User did `lichen gen` without `--license` but we have a config entry
+            // User did `lic gen` without `--license` but we have a config entry
             let lic = cfg
                 .licenses
                 .as_ref()
@@ -43,6 +59,13 @@ impl ApplySettings {
             // no CLI value, no config entry, nothing.
             return Err(LichenError::MissingLicense);
         };
+        let license = expression.license;
+        let deny_deprecated = cli
+            .license_args
+            .deny_deprecated
+            .or(cfg.deny_deprecated)
+            .unwrap_or(false);
+        utils::check_deprecated(&expression, deny_deprecated)?;
 
         let default_target = vec![PathBuf::from(".")];
 
@@ -98,84 +121,337 @@ impl ApplySettings {
 
         let prefer_block = cli.prefer_block.or(cfg.prefer_block).unwrap_or(false);
 
-        let dry_run = cli.dry_run.unwrap_or(false);
+        // Writing a patch file is a dry run too
+        let dry_run = cli.dry_run.unwrap_or(false) || cli.patch.is_some();
+
+        let width = cli.width.or(cfg.width);
+
+        let legacy_encodings = cli
+            .file_args
+            .legacy_encodings
+            .or(cfg.legacy_encodings)
+            .unwrap_or(false);
+
+        // Either the CLI or the config can turn the marker off
+        let marker = !cli.no_marker.unwrap_or(false) && cfg.marker.unwrap_or(true);
+
+        let replace_foreign = cli.replace_foreign.or(cfg.replace_foreign).unwrap_or(false);
+
+        let third_party = cli.third_party.or(cfg.third_party).unwrap_or_default();
+
+        // Our headers of any configured license are recognized, so switching licenses replaces
+        // them. Foreign headers are told apart from the whole catalog, see `find_foreign_header`.
+        let mut known_licenses = vec![license];
+        for lic in cfg.licenses.iter().flatten() {
+            if !known_licenses.contains(&lic.id.license) {
+                known_licenses.push(lic.id.license);
+            }
+        }
+
+        let git_changes =
+            utils::GitChanges::from_args(&cli.file_args.changed_since, cli.file_args.staged);
 
         Ok(ApplySettings {
             exclude,
             license,
+            exception: expression.exception,
             dry_run,
             targets,
             prefer_block,
             authors,
             date,
             multiple,
+            width,
+            legacy_encodings,
+            marker,
+            replace_foreign,
+            third_party,
+            known_licenses,
+            git_changes,
         })
     }
 }
 
-/// Handles the `apply` command logic.
-pub async fn handle_apply(settings: &ApplySettings) -> Result<(), LichenError> {
+impl ApplySettings {
+    /// The settings of every license in the configuration, or of the CLI's license alone when
+    /// there are none.
+    pub fn for_config(cli: &ApplyArgs, cfg: &Config) -> Result<Vec<Self>, LichenError> {
+        if cfg.licenses.is_none() {
+            return Ok(vec![Self::new(cli, cfg, None)?]);
+        }
+        cfg.licenses
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(idx, _license)| Self::new(cli, cfg, Some(idx)))
+            .collect()
+    }
+
+    pub fn apply_options(&self) -> utils::ApplyOptions {
+        utils::ApplyOptions {
+            prefers_block: self.prefer_block,
+            multiple: self.multiple,
+            width: self.width,
+            legacy_encodings: self.legacy_encodings,
+            marker: self.marker,
+            replace_foreign: self.replace_foreign,
+            dry_run: self.dry_run,
+            third_party: self.third_party,
+        }
+    }
+
+    /// The license and its exception, as an SPDX expression.
+    pub fn expression(&self) -> LicenseExpression {
+        LicenseExpression {
+            license: self.license,
+            exception: self.exception,
+        }
+    }
+
+    /// Renders the license header text, without comment markers.
+    pub fn render_header(&self) -> Result<String, LichenError> {
+        debug!("Using embedded template content for {}", self.expression());
+        debug!(
+            "Embedded template content:\n{}",
+            self.license.template_content()
+        );
+
+        let rendered_license =
+            utils::render_expression(&self.expression(), &self.date, &self.authors)
+                .map_err(LichenError::RenderError)?; // Convert RenderError for compatibility
+        trace!("License content rendered successfully.");
+        debug!("Rendered content:\n{}", rendered_license);
+        Ok(rendered_license)
+    }
+
+    /// Names of the authors. Copyright notices naming anyone else mark third-party code.
+    pub fn owners(&self) -> Vec<String> {
+        self.authors
+            .iter()
+            .flat_map(|authors| authors.0.iter().map(|author| author.name.clone()))
+            .collect()
+    }
+
+    /// Whether the file falls under the targets of this license, and isn't excluded.
+    /// Relative and absolute paths are both compared from the current directory.
+    pub fn covers(&self, path: &Path) -> bool {
+        utils::covers(&self.targets, self.exclude.as_ref(), path)
+    }
+}
+
+/// Handles `apply --stdin`, applying the header to content that isn't on disk.
+/// Content outside of the license's targets, or excluded, is handed back unchanged.
+pub fn handle_apply_stdin(
+    settings: &ApplySettings,
+    filename: &Path,
+    content: Vec<u8>,
+) -> Result<Vec<u8>, LichenError> {
+    if !settings.covers(filename) {
+        debug!(
+            "'{}' isn't covered by {}, passing it through",
+            filename.display(),
+            settings.expression()
+        );
+        return Ok(content);
+    }
+
+    let matcher = HeaderMatcher::new(settings.known_licenses.iter().copied());
+    utils::apply_header_to_content(
+        &settings.render_header()?,
+        &matcher,
+        &settings.owners(),
+        filename,
+        content,
+        settings.apply_options(),
+    )
+}
+
+/// Handles the `apply` command logic, returning the outcome of every file.
+/// On a dry run, files are left untouched and the edits are returned instead.
+/// Original content of modified files is recorded in the journal, for `lic undo`.
+pub async fn handle_apply(
+    settings: &ApplySettings,
+    journal: &Arc<Journal>,
+) -> Result<ProcessingSummary, LichenError> {
     debug!("Starting handle_apply with args: {:?}", settings);
 
     // ▰▰▰ Get options from setting struct ▰▰▰
-    let license = settings.license;
     let exclude_pattern = &settings.exclude;
     let targets = &settings.targets;
-    let multiple = settings.multiple;
-    let authors = &settings.authors;
-    let year = &settings.date;
-    let dry_run = settings.dry_run;
-    let preference = settings.prefer_block;
+    let options = settings.apply_options();
     //
 
     debug!(
         "Applying license header for: {} to targets: {:?}",
-        license.spdx_id(),
+        settings.expression(),
         targets
     );
     debug!("Exclusion pattern: {:?}", exclude_pattern);
-    debug!("Block comments preferred?: {}", preference);
+    debug!("Header options: {:?}", options);
 
     // ▰▰▰ Get License Header Content ▰▰▰ //
-    let template_content = settings.license.template_content();
-    debug!(
-        "Using embedded template content for {}",
-        settings.license.spdx_id()
-    );
-    debug!("Embedded template content:\n{}", template_content);
-
-    let rendered_license =
-        utils::render_license(template_content, year, authors).map_err(LichenError::RenderError)?; // Convert RenderError for compatibility
-    trace!("License content rendered successfully.");
-    debug!("Rendered content:\n{}", rendered_license);
+    let rendered_license = settings.render_header()?;
 
     // ▰▰▰ Find Files ▰▰▰
-    let files_to_process = utils::get_valid_files(targets, exclude_pattern)?;
-    if files_to_process.is_empty() {
-        return Err(LichenError::Msg(
-            "No files require processing based on targets and exclusions. Exiting 'apply' command."
-                .to_string(),
-        )); // Nothing to do, error. 
+    let mut files_to_process = utils::get_valid_files(targets, exclude_pattern)?;
+    if let Some(changes) = &settings.git_changes {
+        files_to_process = utils::filter_git_changes(files_to_process, changes)?;
+        if files_to_process.is_empty() {
+            // Nothing changed, which is fine in a pre-commit hook
+            info!("No changed files to process.");
+            return Ok(ProcessingSummary::default());
+        }
     }
-
-    if dry_run {
-        info!("Changes will impact {:?}", files_to_process);
-        return Ok(());
+    if files_to_process.is_empty() {
+        // Nothing to do, which scripts tell apart by its exit code
+        return Err(LichenError::NothingToDo(
+            "No files require processing based on targets and exclusions.".to_string(),
+        ));
     }
 
     // ▰▰▰ Apply Headers ▰▰▰
     // TODO: Make concurrency configurable?
     let max_concurrency = std::thread::available_parallelism()
         .expect("There should always be some available parellism on the computer"); // Use available cores
-    utils::apply_headers_to_files(
+    let matcher = HeaderMatcher::new(settings.known_licenses.iter().copied());
+    let mut summary = utils::apply_headers_to_files(
         &rendered_license,
+        matcher,
+        &settings.owners(),
         &files_to_process,
         max_concurrency,
-        preference,
-        multiple,
+        options,
+        journal.clone(),
     )
-    .await?;
+    .await;
+    summary.set_license(&settings.expression().to_string());
 
     info!("Finished applying license headers.");
-    Ok(())
+    Ok(summary)
+}
+
+/// Handles `apply` for each license in turn, a failing license not stopping the others: the
+/// outcome of every file is returned along with their errors, so it can still be reported.
+/// There is only nothing to do when none of the licenses had files to process.
+pub async fn handle_apply_all(
+    settings: &[ApplySettings],
+    journal: &Arc<Journal>,
+) -> Result<(ProcessingSummary, Vec<LichenError>), LichenError> {
+    let mut summary = ProcessingSummary::default();
+    let mut errors = Vec::new();
+    let mut nothing_to_do = None;
+    let mut had_files = false;
+
+    for settings in settings {
+        match handle_apply(settings, journal).await {
+            Ok(processed) => {
+                had_files = true;
+                summary.merge(processed);
+            }
+            Err(LichenError::NothingToDo(msg)) => nothing_to_do = Some(msg),
+            Err(err) => {
+                had_files = true;
+                errors.push(err);
+            }
+        }
+    }
+
+    match nothing_to_do {
+        Some(msg) if !had_files => Err(LichenError::NothingToDo(msg)),
+        _ => Ok((summary, errors)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Cli, Commands};
+    use clap::Parser;
+    use std::fs;
+
+    #[test]
+    fn covers_relative_and_absolute_paths() {
+        let cli = Cli::try_parse_from(["lic", "apply", "MIT", "src"]).unwrap();
+        let Commands::Apply(args) = cli.command else {
+            unreachable!()
+        };
+        let settings = ApplySettings::new(&args, &Config::default(), None).unwrap();
+        let current_dir = std::env::current_dir().unwrap();
+
+        assert!(settings.covers(Path::new("src/new_module.rs")));
+        assert!(settings.covers(Path::new("./src/new_module.rs")));
+        assert!(settings.covers(&current_dir.join("src/new_module.rs")));
+        assert!(!settings.covers(&current_dir.join("tests/new_test.rs")));
+        assert!(!settings.covers(Path::new("/elsewhere/src/new_module.rs")));
+    }
+
+    #[tokio::test]
+    async fn only_nothing_to_do_when_no_license_has_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("a")).unwrap();
+        fs::create_dir_all(dir.path().join("b")).unwrap();
+        fs::write(dir.path().join("b").join("lib.rs"), "fn b() {}\n").unwrap();
+        let root = dir.path().display();
+        let cli = Cli::try_parse_from(["lic", "apply", "--dry-run", "--all", "-a", "Me"]).unwrap();
+        let Commands::Apply(args) = cli.command else {
+            unreachable!()
+        };
+        let journal = Arc::new(Journal::new(dir.path()));
+
+        // The empty first license doesn't keep the second from running
+        let cfg: Config = toml::from_str(&format!(
+            "[[license]]\nid = \"MIT\"\ntargets = [\"{root}/a\"]\n\n\
+             [[license]]\nid = \"Apache-2.0\"\ntargets = [\"{root}/b\"]\n"
+        ))
+        .unwrap();
+        let settings = ApplySettings::for_config(&args, &cfg).unwrap();
+        let (summary, errors) = handle_apply_all(&settings, &journal).await.unwrap();
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(summary.edits.len(), 1);
+
+        let cfg: Config = toml::from_str(&format!(
+            "[[license]]\nid = \"MIT\"\ntargets = [\"{root}/a\"]\n"
+        ))
+        .unwrap();
+        let settings = ApplySettings::for_config(&args, &cfg).unwrap();
+        assert!(matches!(
+            handle_apply_all(&settings, &journal).await,
+            Err(LichenError::NothingToDo(_))
+        ));
+    }
+
+    #[test]
+    fn stdin_gets_the_header_of_a_later_license() {
+        let cfg: Config = toml::from_str(
+            "[[license]]\nid = \"MIT\"\ntargets = [\"a\"]\n\n\
+             [[license]]\nid = \"Apache-2.0\"\ntargets = [\"b\"]\n",
+        )
+        .unwrap();
+        let cli = Cli::try_parse_from([
+            "lic",
+            "apply",
+            "--stdin",
+            "--filename",
+            "b/lib.rs",
+            "-a",
+            "Me",
+        ])
+        .unwrap();
+        let Commands::Apply(args) = cli.command else {
+            unreachable!()
+        };
+
+        let mut content = b"fn b() {}\n".to_vec();
+        for settings in ApplySettings::for_config(&args, &cfg).unwrap() {
+            content = handle_apply_stdin(&settings, Path::new("b/lib.rs"), content).unwrap();
+        }
+        let content = String::from_utf8(content).unwrap();
+        assert!(content.contains("\"Licensor\" shall mean"), "{}", content);
+        assert!(
+            !content.contains("Permission is hereby granted"),
+            "{}",
+            content
+        );
+        assert!(content.ends_with("fn b() {}\n"), "{}", content);
+    }
 }
@@ -0,0 +1,360 @@
+//! # Check Command
+//!
+//! Logic for the `lic check` command.
+
+use crate::commands::apply;
+use crate::config::Config;
+use crate::error::LichenError;
+use crate::header::HeaderProblem;
+use crate::journal::Journal;
+use crate::models::{ApplyArgs, CheckArgs, OutputFormat};
+use crate::utils;
+use crate::utils::FileEdit;
+use log::info;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Handles the `check` command logic.
+/// Runs `apply` without writing anything, and fails listing the files it would change.
+/// Dry runs never record anything in the journal.
+pub async fn handle_check(
+    args: &CheckArgs,
+    cfg: &Config,
+    journal: &Arc<Journal>,
+) -> Result<(), LichenError> {
+    let mut settings = apply::ApplySettings::for_config(&apply_args(args), cfg)?;
+    for settings in &mut settings {
+        settings.dry_run = true;
+    }
+    let (summary, errors) = apply::handle_apply_all(&settings, journal).await?;
+
+    let report = missing_header_report(&summary.edits);
+    let format = args.format.unwrap_or_default();
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => summary.write_json_report(true)?,
+        OutputFormat::Sarif => write_sarif_report(&summary.edits)?,
+    }
+    let edits = summary.into_result("application")?;
+
+    // The fixes are only written out when asked for, the report is enough otherwise
+    let print_diffs = format == OutputFormat::Text && args.dry_run.unwrap_or(false);
+    if print_diffs || args.patch.is_some() {
+        utils::write_diffs(edits, args.patch.as_deref())?;
+    }
+
+    if let Some(err) = LichenError::combine(errors) {
+        return Err(err);
+    }
+    match report {
+        Some(report) => Err(LichenError::NonCompliant(report)),
+        None => {
+            info!("All files have their license header.");
+            Ok(())
+        }
+    }
+}
+
+/// The `apply` the check runs, the header's options coming from the configuration only.
+fn apply_args(args: &CheckArgs) -> ApplyArgs {
+    ApplyArgs {
+        license_args: args.license_args.clone(),
+        file_args: args.file_args.clone(),
+        dry_run: args.dry_run,
+        prefer_block: None,
+        width: None,
+        no_marker: None,
+        replace_foreign: None,
+        third_party: None,
+        patch: None,
+        format: None,
+        stdin: None,
+        filename: None,
+        watch: None,
+    }
+}
+
+/// Path of the edited file, relative to where Lichen runs.
+fn display_path(edit: &FileEdit) -> String {
+    let path = edit.path.to_string_lossy();
+    path.strip_prefix("./").unwrap_or(&path).to_string()
+}
+
+/// Lists the files `apply` would change, one per line, with their problem.
+fn missing_header_report(edits: &[FileEdit]) -> Option<String> {
+    // A file may be changed by several licenses, its first problem is enough
+    let problems: BTreeMap<String, Option<HeaderProblem>> = edits
+        .iter()
+        .rev()
+        .map(|edit| (display_path(edit), edit.finding.map(|f| f.problem)))
+        .collect();
+
+    if problems.is_empty() {
+        return None;
+    }
+    let mut report = match problems.len() {
+        1 => "1 file is missing its license header or has an outdated one, run `lic apply` to fix it:"
+            .to_string(),
+        count => format!(
+            "{} files are missing their license header or have an outdated one, run `lic apply` to fix them:",
+            count
+        ),
+    };
+    for (path, problem) in problems {
+        report.push_str("\n  ");
+        report.push_str(&path);
+        if let Some(problem) = problem {
+            report.push_str(&format!(" ({})", problem.id()));
+        }
+    }
+    Some(report)
+}
+
+/// Builds a SARIF 2.1.0 log of the header problems, one rule per kind of problem.
+fn sarif_report(edits: &[FileEdit]) -> serde_json::Value {
+    let rules: Vec<_> = HeaderProblem::ALL
+        .iter()
+        .map(|problem| {
+            json!({
+                "id": problem.id(),
+                "shortDescription": { "text": problem.description() },
+                "defaultConfiguration": { "level": "error" },
+            })
+        })
+        .collect();
+
+    let mut edits: Vec<&FileEdit> = edits.iter().collect();
+    edits.sort_by(|a, b| a.path.cmp(&b.path));
+    let results: Vec<_> = edits
+        .iter()
+        .filter_map(|edit| {
+            let finding = edit.finding?;
+            let rule_index = HeaderProblem::ALL
+                .iter()
+                .position(|problem| *problem == finding.problem)?;
+            Some(json!({
+                "ruleId": finding.problem.id(),
+                "ruleIndex": rule_index,
+                "level": "error",
+                "message": { "text": format!("{} Run `lic apply` to fix it.", finding.problem.description()) },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": artifact_location(edit),
+                        "region": {
+                            "startLine": finding.start_line,
+                            "endLine": finding.end_line,
+                        },
+                    },
+                }],
+            }))
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "lichen",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/philocalyst/lichen",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Where the file is, relative to the source root unless an absolute target was given.
+fn artifact_location(edit: &FileEdit) -> serde_json::Value {
+    let uri = display_path(edit).replace('\\', "/");
+    if edit.path.is_absolute() {
+        json!({ "uri": format!("file://{}", uri) })
+    } else {
+        json!({ "uri": uri, "uriBaseId": "%SRCROOT%" })
+    }
+}
+
+/// Prints the SARIF log of the header problems, for code scanning tools.
+fn write_sarif_report(edits: &[FileEdit]) -> Result<(), LichenError> {
+    let mut stdout = std::io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, &sarif_report(edits))?;
+    writeln!(stdout)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Cli, Commands};
+    use clap::Parser;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn check_args(target: &Path) -> CheckArgs {
+        let cli = Cli::try_parse_from([
+            "lic",
+            "check",
+            "MIT",
+            "--authors",
+            "Jane Doe",
+            "--all",
+            &target.to_string_lossy(),
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Check(args) => args,
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn check_fails_until_the_header_is_applied() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("main.rs");
+        fs::write(&file, "fn main() {}\n").unwrap();
+        let args = check_args(&file);
+        let journal = Arc::new(Journal::new(dir.path()));
+
+        let report = match handle_check(&args, &Config::default(), &journal).await {
+            Err(LichenError::NonCompliant(report)) => report,
+            other => panic!("Check should fail, got {:?}", other),
+        };
+        assert!(report.starts_with("1 file is missing"), "{}", report);
+        assert!(report.contains("main.rs"), "{}", report);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "fn main() {}\n");
+
+        // Once applied, the check passes
+        let settings =
+            apply::ApplySettings::new(&apply_args(&args), &Config::default(), None).unwrap();
+        apply::handle_apply(&settings, &journal).await.unwrap();
+        assert!(
+            handle_check(&args, &Config::default(), &journal)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn check_covers_every_license_of_the_config() {
+        let dir = tempdir().unwrap();
+        for sub in ["a", "b"] {
+            fs::create_dir_all(dir.path().join(sub)).unwrap();
+            fs::write(dir.path().join(sub).join("lib.rs"), "fn f() {}\n").unwrap();
+        }
+        let root = dir.path().display();
+        let cfg: Config = toml::from_str(&format!(
+            "[[license]]\nid = \"MIT\"\ntargets = [\"{root}/a\"]\n\n\
+             [[license]]\nid = \"Apache-2.0\"\ntargets = [\"{root}/b\"]\n"
+        ))
+        .unwrap();
+        let cli = Cli::try_parse_from(["lic", "check", "--all"]).unwrap();
+        let Commands::Check(args) = cli.command else {
+            unreachable!()
+        };
+
+        let journal = Arc::new(Journal::new(dir.path()));
+        let report = match handle_check(&args, &cfg, &journal).await {
+            Err(LichenError::NonCompliant(report)) => report,
+            other => panic!("Check should fail, got {:?}", other),
+        };
+        assert!(report.starts_with("2 files are missing"), "{}", report);
+        assert!(report.contains("a/lib.rs"), "{}", report);
+        assert!(report.contains("b/lib.rs"), "{}", report);
+    }
+
+    #[tokio::test]
+    async fn check_flags_deprecated_spdx_tags() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("main.rs");
+        fs::write(
+            &file,
+            "// SPDX-License-Identifier: GPL-2.0+\nfn main() {}\n",
+        )
+        .unwrap();
+        let args = check_args(&file);
+
+        // Applying migrates the tag along with adding the header
+        let journal = Arc::new(Journal::new(dir.path()));
+        let settings =
+            apply::ApplySettings::new(&apply_args(&args), &Config::default(), None).unwrap();
+        apply::handle_apply(&settings, &journal).await.unwrap();
+        let applied = fs::read_to_string(&file).unwrap();
+        assert!(applied.contains("SPDX-License-Identifier: GPL-2.0-or-later"));
+
+        // A deprecated id is the only problem left once the tag is reverted
+        fs::write(&file, applied.replace("GPL-2.0-or-later", "GPL-2.0+")).unwrap();
+        let report = match handle_check(&args, &Config::default(), &journal).await {
+            Err(LichenError::NonCompliant(report)) => report,
+            other => panic!("Check should fail, got {:?}", other),
+        };
+        assert!(
+            report.contains("main.rs (deprecated-license)"),
+            "{}",
+            report
+        );
+    }
+
+    #[test]
+    fn check_rejects_apply_only_flags() {
+        for flag in ["--stdin", "--replace-foreign", "--no-marker", "--width=80"] {
+            let err = Cli::try_parse_from(["lic", "check", "MIT", flag]).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                clap::error::ErrorKind::UnknownArgument,
+                "{}",
+                flag
+            );
+        }
+    }
+
+    #[test]
+    fn deprecated_licenses_are_denied_when_asked() {
+        let cli = Cli::try_parse_from(["lic", "check", "GPL-2.0+", "--deny-deprecated"]).unwrap();
+        let Commands::Check(args) = cli.command else {
+            unreachable!()
+        };
+        let err =
+            apply::ApplySettings::new(&apply_args(&args), &Config::default(), None).unwrap_err();
+        assert!(err.to_string().contains("use 'GPL-2.0-or-later' instead"));
+        assert_eq!(err.exit_code(), 3);
+    }
+
+    #[tokio::test]
+    async fn sarif_report_locates_problems() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("main.rs");
+        fs::write(&file, "fn main() {}\n").unwrap();
+        let args = check_args(&file);
+        let mut settings =
+            apply::ApplySettings::new(&apply_args(&args), &Config::default(), None).unwrap();
+        settings.dry_run = true;
+        let journal = Arc::new(Journal::new(dir.path()));
+        let summary = apply::handle_apply(&settings, &journal).await.unwrap();
+
+        let sarif = sarif_report(&summary.edits);
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().unwrap().len(),
+            HeaderProblem::ALL.len()
+        );
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "missing-header");
+        assert_eq!(result["ruleIndex"], 0);
+        let location = &result["locations"][0]["physicalLocation"];
+        assert!(
+            location["artifactLocation"]["uri"]
+                .as_str()
+                .unwrap()
+                .ends_with("/main.rs")
+        );
+        assert!(location["artifactLocation"]["uriBaseId"].is_null());
+        assert_eq!(location["region"]["startLine"], 1);
+    }
+}
@@ -0,0 +1,516 @@
+//! # Compat Command
+//!
+//! Logic for the `lic compat` command.
+
+use crate::compat;
+use crate::config::{Config, LicenseConfig};
+use crate::deps;
+use crate::error::LichenError;
+use crate::header::{self, HeaderMatcher};
+use crate::models::{CompatArgs, LicenseExpression, OutputFormat, SpdxExpression};
+use crate::utils::{self, TextConventions};
+use log::{debug, info};
+use serde::Serialize;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Where a license was declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentKind {
+    /// In the header or SPDX tag of a source file.
+    File,
+    /// In the manifest of a local dependency.
+    Dependency,
+}
+
+/// A part of the project, a file or a dependency, with the license it declares.
+#[derive(Debug)]
+pub struct Component {
+    pub kind: ComponentKind,
+    /// The path of the file, or the name of the dependency.
+    pub name: String,
+    pub license: SpdxExpression,
+}
+
+/// A part of the project whose license the project's license doesn't allow, and why.
+#[derive(Debug, Serialize)]
+pub struct Conflict {
+    pub kind: ComponentKind,
+    pub name: String,
+    pub license: String,
+    /// The license of the project it was checked against.
+    pub project_license: String,
+    pub reasons: Vec<String>,
+}
+
+/// The project's license, as a whole and for the files each `[[license]]` block covers.
+#[derive(Debug)]
+struct ProjectLicense<'a> {
+    /// Every license of the project, which dependencies are checked against.
+    whole: SpdxExpression,
+    /// The `[[license]]` blocks of the configuration, when the project's license comes from them.
+    blocks: &'a [LicenseConfig],
+}
+
+impl ProjectLicense<'_> {
+    /// The license the component is checked against. A file is checked against the licenses of
+    /// the blocks covering it, which all apply, and none when no block covers it.
+    fn of(&self, component: &Component) -> Option<SpdxExpression> {
+        if self.blocks.is_empty() || component.kind == ComponentKind::Dependency {
+            return Some(self.whole.clone());
+        }
+        let path = Path::new(&component.name);
+        let covering: Vec<_> = self
+            .blocks
+            .iter()
+            .filter(|block| block.covers(path))
+            .map(|block| block.id)
+            .collect();
+        joined(&covering)
+    }
+}
+
+/// The outcome of the check, for tooling.
+#[derive(Serialize)]
+struct CompatReport<'a> {
+    project_license: String,
+    checked: usize,
+    conflicts: &'a [Conflict],
+}
+
+/// Handles the `compat` command logic.
+/// Checks the license of every file and local dependency against the project's license, and fails
+/// explaining each conflict.
+pub async fn handle_compat(args: &CompatArgs, cfg: &Config) -> Result<(), LichenError> {
+    let format = args.format.unwrap_or_default();
+    if format == OutputFormat::Sarif {
+        return Err(utils::sarif_unsupported());
+    }
+
+    let root = Path::new(".");
+    let project = ProjectLicense {
+        whole: project_license(args.license.as_ref(), cfg, root)?,
+        // The license given on the command line covers every file
+        blocks: match &args.license {
+            Some(_) => &[],
+            None => cfg.licenses.as_deref().unwrap_or_default(),
+        },
+    };
+    debug!(
+        "Checking compatibility with the project's license {}",
+        project.whole
+    );
+
+    let mut components = file_licenses(args, cfg).await?;
+    if !args.no_deps.unwrap_or(false) {
+        components.extend(local_dependencies(root)?);
+    }
+    let conflicts = find_conflicts(&project, &components);
+
+    if format == OutputFormat::Json {
+        let report = CompatReport {
+            project_license: project.whole.to_string(),
+            checked: components.len(),
+            conflicts: &conflicts,
+        };
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &report)?;
+        writeln!(stdout)?;
+    }
+
+    match conflict_report(&project.whole, &conflicts) {
+        Some(report) => Err(LichenError::NonCompliant(report)),
+        None => {
+            info!(
+                "No license conflicts with {} among {} files and dependencies.",
+                project.whole,
+                components.len()
+            );
+            Ok(())
+        }
+    }
+}
+
+/// The project's license: given on the command line, else the licenses of the configuration,
+/// which all apply to the project as a whole, else the `license` of the project's Cargo.toml or
+/// package.json.
+fn project_license(
+    cli: Option<&SpdxExpression>,
+    cfg: &Config,
+    root: &Path,
+) -> Result<SpdxExpression, LichenError> {
+    if let Some(license) = cli {
+        return Ok(license.clone());
+    }
+
+    if let Some(licenses) = &cfg.licenses
+        && let Some(license) = joined(&licenses.iter().map(|l| l.id).collect::<Vec<_>>())
+    {
+        return Ok(license);
+    }
+
+    for manifest in ["Cargo.toml", "package.json"] {
+        if let Some((_, license)) = deps::read_manifest(&root.join(manifest))?
+            .and_then(|manifest| package_license(&manifest))
+        {
+            return Ok(license);
+        }
+    }
+    Err(LichenError::MissingLicense)
+}
+
+/// The licenses joined by AND, once each, or none when there are none.
+fn joined(licenses: &[LicenseExpression]) -> Option<SpdxExpression> {
+    let mut parts: Vec<SpdxExpression> = Vec::new();
+    for license in licenses {
+        let part = SpdxExpression::from(*license);
+        if !parts.contains(&part) {
+            parts.push(part);
+        }
+    }
+    match parts.len() {
+        0 => None,
+        1 => Some(parts.remove(0)),
+        _ => Some(SpdxExpression::And(parts)),
+    }
+}
+
+/// The files of the targets declaring a license, by their SPDX tag or their header.
+async fn file_licenses(args: &CompatArgs, cfg: &Config) -> Result<Vec<Component>, LichenError> {
+    let file_args = &args.file_args;
+    let targets = file_args.targets.clone().unwrap_or(vec![".".into()]);
+    let exclude = utils::build_exclude_regex(
+        &file_args.exclude,
+        Some(cfg),
+        file_args.all.unwrap_or_default(),
+        None,
+    )?;
+
+    let mut files = utils::get_valid_files(&targets, &exclude)?;
+    if let Some(changes) = utils::GitChanges::from_args(&file_args.changed_since, file_args.staged)
+    {
+        files = utils::filter_git_changes(files, &changes)?;
+    }
+    let legacy_encodings = file_args
+        .legacy_encodings
+        .or(cfg.legacy_encodings)
+        .unwrap_or_default();
+
+    // Any license of the catalog may have been applied, by Lichen or by hand
+    let matcher = HeaderMatcher::catalog();
+    let mut components = Vec::new();
+    for path in files {
+        if path.is_dir() {
+            continue;
+        }
+        let Ok((raw_content, _)) = utils::read_text_file(&path, legacy_encodings).await else {
+            continue;
+        };
+        let content = TextConventions::detect(&raw_content).normalize(&raw_content);
+        let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+        let preamble_len = utils::preamble_length(&content, ext);
+        let comment_tokens = utils::get_comment_tokens_for_ext(ext).unwrap_or_default();
+
+        match header::declared_license(&content, preamble_len, &comment_tokens, matcher) {
+            Some(license) => components.push(Component {
+                kind: ComponentKind::File,
+                name: display_path(&path),
+                license,
+            }),
+            None => debug!("No license declared in '{}'", path.display()),
+        }
+    }
+    Ok(components)
+}
+
+/// The local dependencies of the project, with the license their manifest declares: the path
+/// dependencies of Cargo.toml, the crates vendored in `vendor/`, and the `file:` and `link:`
+/// dependencies of package.json.
+fn local_dependencies(root: &Path) -> Result<Vec<Component>, LichenError> {
+    let mut manifests: Vec<PathBuf> = Vec::new();
+
+    if let Some(cargo) = deps::read_manifest(&root.join("Cargo.toml"))? {
+        let tables = [
+            cargo.get("dependencies"),
+            cargo.get("build-dependencies"),
+            cargo.pointer("/workspace/dependencies"),
+        ];
+        for table in tables.into_iter().flatten().filter_map(|t| t.as_object()) {
+            for spec in table.values() {
+                if let Some(path) = spec.get("path").and_then(|path| path.as_str()) {
+                    manifests.push(root.join(path).join("Cargo.toml"));
+                }
+            }
+        }
+    }
+
+    if let Ok(entries) = fs::read_dir(root.join("vendor")) {
+        let mut vendored: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path().join("Cargo.toml"))
+            .collect();
+        vendored.sort();
+        manifests.extend(vendored);
+    }
+
+    if let Some(package) = deps::read_manifest(&root.join("package.json"))? {
+        for section in ["dependencies", "optionalDependencies"] {
+            let Some(dependencies) = package.get(section).and_then(|deps| deps.as_object()) else {
+                continue;
+            };
+            for spec in dependencies.values().filter_map(|spec| spec.as_str()) {
+                if let Some(path) = spec
+                    .strip_prefix("file:")
+                    .or_else(|| spec.strip_prefix("link:"))
+                {
+                    manifests.push(root.join(path).join("package.json"));
+                }
+            }
+        }
+    }
+
+    let mut components = Vec::new();
+    for path in manifests {
+        let Some(manifest) = deps::read_manifest(&path)? else {
+            continue;
+        };
+        match package_license(&manifest) {
+            Some((name, license)) => components.push(Component {
+                kind: ComponentKind::Dependency,
+                name,
+                license,
+            }),
+            None => debug!("No license declared in '{}'", path.display()),
+        }
+    }
+    Ok(components)
+}
+
+/// The name and license a manifest declares for its package.
+fn package_license(manifest: &serde_json::Value) -> Option<(String, SpdxExpression)> {
+    let name = deps::manifest_package(manifest).get("name")?.as_str()?;
+    let license = deps::declared_license(manifest).and_then(deps::normalize_license)?;
+    Some((name.to_string(), license))
+}
+
+/// The components whose license conflicts with the project's, with the explanations.
+fn find_conflicts(project: &ProjectLicense, components: &[Component]) -> Vec<Conflict> {
+    components
+        .iter()
+        .filter_map(|component| {
+            let Some(project_license) = project.of(component) else {
+                debug!("No license of the project covers '{}'", component.name);
+                return None;
+            };
+            let reasons = compat::conflicts(&project_license, &component.license);
+            (!reasons.is_empty()).then(|| Conflict {
+                kind: component.kind,
+                name: component.name.clone(),
+                license: component.license.to_string(),
+                project_license: project_license.to_string(),
+                reasons,
+            })
+        })
+        .collect()
+}
+
+/// Path of the file, relative to where Lichen runs.
+fn display_path(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    path.strip_prefix("./").unwrap_or(&path).to_string()
+}
+
+/// Lists the conflicting files and dependencies, each followed by its explanations.
+fn conflict_report(project: &SpdxExpression, conflicts: &[Conflict]) -> Option<String> {
+    if conflicts.is_empty() {
+        return None;
+    }
+
+    let count = match conflicts.len() {
+        1 => "1 file or dependency has a license".to_string(),
+        n => format!("{} files and dependencies have licenses", n),
+    };
+    let mut report = format!("{} incompatible with the project's ({}):", count, project);
+    for conflict in conflicts {
+        let kind = match conflict.kind {
+            ComponentKind::File => "",
+            ComponentKind::Dependency => "dependency ",
+        };
+        report.push_str(&format!(
+            "\n  {}{} ({})",
+            kind, conflict.name, conflict.license
+        ));
+        for reason in &conflict.reasons {
+            report.push_str("\n    ");
+            report.push_str(reason);
+        }
+    }
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FileProcessingArgs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn project_license_prefers_cli_then_config_then_manifest() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"app\"\nlicense = \"MIT/Apache-2.0\"\n",
+        )
+        .unwrap();
+        let cfg: Config =
+            toml::from_str("[[license]]\nid = \"GPL-3.0-only\"\n\n[[license]]\nid = \"MIT\"\n")
+                .unwrap();
+        let cli: SpdxExpression = "AGPL-3.0-or-later".parse().unwrap();
+
+        let license = |cli, cfg| project_license(cli, cfg, dir.path()).unwrap().to_string();
+        assert_eq!(license(Some(&cli), &cfg), "AGPL-3.0-or-later");
+        assert_eq!(license(None, &cfg), "GPL-3.0-only AND MIT");
+        assert_eq!(license(None, &Config::default()), "MIT OR Apache-2.0");
+
+        let empty = tempdir().unwrap();
+        let err = project_license(None, &Config::default(), empty.path()).unwrap_err();
+        assert!(matches!(err, LichenError::MissingLicense));
+    }
+
+    #[test]
+    fn files_are_checked_against_the_licenses_covering_them() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().display();
+        let cfg: Config = toml::from_str(&format!(
+            "[[license]]\nid = \"MIT\"\ntargets = [\"{root}/src\"]\n\n\
+             [[license]]\nid = \"GPL-3.0-only\"\ntargets = [\"{root}/examples\"]\n"
+        ))
+        .unwrap();
+        let project = ProjectLicense {
+            whole: project_license(None, &cfg, dir.path()).unwrap(),
+            blocks: cfg.licenses.as_deref().unwrap(),
+        };
+        let component = |kind, name: String| Component {
+            kind,
+            name,
+            license: "GPL-3.0-only".parse().unwrap(),
+        };
+
+        let conflicts = find_conflicts(
+            &project,
+            &[
+                component(ComponentKind::File, format!("{root}/examples/demo.rs")),
+                component(ComponentKind::File, format!("{root}/src/lib.rs")),
+                component(ComponentKind::File, format!("{root}/build.rs")),
+                component(ComponentKind::Dependency, "core".to_string()),
+            ],
+        );
+        let found: Vec<_> = conflicts
+            .iter()
+            .map(|conflict| (conflict.name.clone(), conflict.project_license.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (format!("{root}/src/lib.rs"), "MIT"),
+                ("core".to_string(), "MIT AND GPL-3.0-only"),
+            ]
+        );
+    }
+
+    #[test]
+    fn local_dependencies_reads_path_vendored_and_npm_manifests() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\ncore = { path = \"crates/core\" }\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("crates/core")).unwrap();
+        fs::write(
+            root.join("crates/core/Cargo.toml"),
+            "[package]\nname = \"core\"\nlicense = \"GPL-2.0-only\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("vendor/zlib-sys")).unwrap();
+        fs::write(
+            root.join("vendor/zlib-sys/Cargo.toml"),
+            "[package]\nname = \"zlib-sys\"\nlicense = \"Zlib\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "dependencies": { "left-pad": "^1.0.0", "ui": "file:web/ui" } }"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("web/ui")).unwrap();
+        fs::write(
+            root.join("web/ui/package.json"),
+            r#"{ "name": "ui", "license": "UNLICENSED" }"#,
+        )
+        .unwrap();
+
+        let found: Vec<(String, String)> = local_dependencies(root)
+            .unwrap()
+            .into_iter()
+            .map(|dep| (dep.name, dep.license.to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("core".to_string(), "GPL-2.0-only".to_string()),
+                ("zlib-sys".to_string(), "Zlib".to_string()),
+                ("ui".to_string(), "UNLICENSED".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn compat_fails_explaining_conflicts() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("a.rs"),
+            "// SPDX-License-Identifier: Apache-2.0\nfn main() {}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("b.rs"),
+            "// SPDX-License-Identifier: MIT\nfn b() {}\n",
+        )
+        .unwrap();
+        let args = |license: &str| CompatArgs {
+            license: Some(license.parse().unwrap()),
+            file_args: FileProcessingArgs {
+                targets: Some(vec![dir.path().to_path_buf()]),
+                exclude: None,
+                all: Some(true),
+                legacy_encodings: None,
+                changed_since: None,
+                staged: None,
+            },
+            no_deps: Some(true),
+            format: None,
+        };
+
+        let err = handle_compat(&args("GPL-2.0-only"), &Config::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.exit_code(), 3);
+        let LichenError::NonCompliant(report) = err else {
+            unreachable!()
+        };
+        assert!(report.starts_with("1 file or dependency has a license incompatible"));
+        assert!(report.contains("a.rs (Apache-2.0)"));
+        assert!(report.contains("patent"));
+        assert!(!report.contains("b.rs"));
+
+        assert!(
+            handle_compat(&args("GPL-3.0-or-later"), &Config::default())
+                .await
+                .is_ok()
+        );
+    }
+}
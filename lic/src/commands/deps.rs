@@ -0,0 +1,277 @@
+//! # Deps Command
+//!
+//! Logic for the `lic deps` command.
+
+use crate::config::{Config, PolicyConfig};
+use crate::deps::{self, Dependency, SearchPaths};
+use crate::error::LichenError;
+use crate::models::{DepsArgs, Ecosystem, OutputFormat};
+use crate::policy::{self, Violation};
+use crate::utils;
+use clap::ValueEnum;
+use log::{info, warn};
+use serde::Serialize;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// The outcome of the policy check, for tooling.
+#[derive(Serialize)]
+struct PolicyCheckReport<'a> {
+    checked: usize,
+    violations: &'a [Violation<'a>],
+}
+
+/// Handles the `deps` command logic.
+/// Prints the dependencies found in the lockfiles and installed packages of the project, with their
+/// license, or checks them against the policy of the configuration with `--check`.
+pub fn handle_deps(args: &DepsArgs, cfg: &Config) -> Result<(), LichenError> {
+    let format = args.format.unwrap_or_default();
+    if format == OutputFormat::Sarif {
+        return Err(utils::sarif_unsupported());
+    }
+    let policy = match (args.check.unwrap_or(false), &cfg.policy) {
+        (false, _) => None,
+        (true, Some(policy)) => Some(policy),
+        (true, None) => {
+            return Err(LichenError::InvalidConfig(
+                "Checking dependencies needs a [policy] in the configuration, listing the licenses to allow or deny."
+                    .to_string(),
+            ));
+        }
+    };
+
+    let root = args.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    let ecosystems = args
+        .ecosystem
+        .clone()
+        .unwrap_or_else(|| Ecosystem::value_variants().to_vec());
+    let dependencies = deps::inventory(&root, &ecosystems, &SearchPaths::from_env())?;
+    if dependencies.is_empty() {
+        return Err(LichenError::NothingToDo(format!(
+            "No dependencies found in '{}': no Cargo.lock, package-lock.json or Python virtual environment.",
+            root.display()
+        )));
+    }
+
+    if let Some(policy) = policy {
+        return check_policy(policy, &dependencies, format);
+    }
+
+    let mut stdout = std::io::stdout().lock();
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &dependencies)?;
+            writeln!(stdout)?;
+        }
+        _ => write_table(&mut stdout, &dependencies)?,
+    }
+
+    let unknown = dependencies
+        .iter()
+        .filter(|dependency| dependency.license.is_none())
+        .count();
+    info!(
+        "{} dependencies, {} without a known license.",
+        dependencies.len(),
+        unknown
+    );
+    Ok(())
+}
+
+/// Checks the dependencies against the policy, failing with a report of the violations.
+fn check_policy(
+    policy: &PolicyConfig,
+    dependencies: &[Dependency],
+    format: OutputFormat,
+) -> Result<(), LichenError> {
+    let report = policy::check(policy, dependencies);
+    for exception in &report.unused_exceptions {
+        warn!(
+            "The policy exception for '{}' matches no dependency, it can be removed.",
+            exception.name
+        );
+    }
+
+    if format == OutputFormat::Json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(
+            &mut stdout,
+            &PolicyCheckReport {
+                checked: dependencies.len(),
+                violations: &report.violations,
+            },
+        )?;
+        writeln!(stdout)?;
+    }
+
+    match violation_report(&report.violations) {
+        Some(report) => Err(LichenError::NonCompliant(report)),
+        None => {
+            info!(
+                "All {} dependencies comply with the license policy.",
+                dependencies.len()
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Explains every violation of the policy, `None` when there are none.
+fn violation_report(violations: &[Violation]) -> Option<String> {
+    if violations.is_empty() {
+        return None;
+    }
+
+    let mut report = match violations.len() {
+        1 => "1 dependency violates the license policy:".to_string(),
+        count => format!("{} dependencies violate the license policy:", count),
+    };
+    for violation in violations {
+        let dependency = violation.dependency;
+        report.push_str(&format!(
+            "\n  {} {} {} ({})",
+            ecosystem_name(dependency.ecosystem),
+            dependency.name,
+            dependency.version,
+            license_name(dependency)
+        ));
+        for reason in &violation.reasons {
+            report.push_str("\n    ");
+            report.push_str(reason);
+        }
+    }
+    report.push_str(
+        "\nAdd a [[policy.exception]] with the reason to accept a package's license anyway.",
+    );
+    Some(report)
+}
+
+/// The name of the package manager, as given to `--ecosystem`.
+fn ecosystem_name(ecosystem: Ecosystem) -> String {
+    ecosystem
+        .to_possible_value()
+        .expect("No ecosystem is skipped")
+        .get_name()
+        .to_string()
+}
+
+/// The license of the dependency, for people.
+fn license_name(dependency: &Dependency) -> String {
+    dependency
+        .license
+        .as_ref()
+        .map_or_else(|| "unknown".to_string(), ToString::to_string)
+}
+
+/// Prints the dependencies as a table, one per line.
+fn write_table(out: &mut impl Write, dependencies: &[Dependency]) -> Result<(), LichenError> {
+    let rows: Vec<[String; 4]> = dependencies
+        .iter()
+        .map(|dependency| {
+            [
+                ecosystem_name(dependency.ecosystem),
+                dependency.name.clone(),
+                dependency.version.clone(),
+                license_name(dependency),
+            ]
+        })
+        .collect();
+
+    let header = ["ECOSYSTEM", "NAME", "VERSION", "LICENSE"].map(str::to_string);
+    let widths: Vec<usize> = (0..3)
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[column].len())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for row in std::iter::once(&header).chain(&rows) {
+        writeln!(
+            out,
+            "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SpdxExpression;
+
+    fn dependency(name: &str, license: Option<&str>) -> Dependency {
+        Dependency {
+            ecosystem: Ecosystem::Cargo,
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            declared: license.map(str::to_string),
+            license: license.map(|l| l.parse::<SpdxExpression>().unwrap()),
+            manifest: None,
+        }
+    }
+
+    #[test]
+    fn write_table_aligns_columns() {
+        let mut out = Vec::new();
+        write_table(
+            &mut out,
+            &[
+                dependency("serde", Some("MIT OR Apache-2.0")),
+                dependency("mystery-crate", None),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "ECOSYSTEM  NAME           VERSION  LICENSE\n\
+             cargo      serde          1.0.0    MIT OR Apache-2.0\n\
+             cargo      mystery-crate  1.0.0    unknown\n"
+        );
+    }
+
+    #[test]
+    fn violation_report_explains_each_violation() {
+        let policy = PolicyConfig {
+            allow: vec!["permissive".parse().unwrap()],
+            deny: Vec::new(),
+            exceptions: Vec::new(),
+        };
+        let dependencies = [
+            dependency("serde", Some("MIT OR Apache-2.0")),
+            dependency("mystery-crate", None),
+        ];
+        let err = check_policy(&policy, &dependencies, OutputFormat::Text).unwrap_err();
+        assert_eq!(err.exit_code(), 3);
+        let LichenError::NonCompliant(report) = err else {
+            unreachable!()
+        };
+        assert!(report.starts_with(
+            "1 dependency violates the license policy:\n  \
+             cargo mystery-crate 1.0.0 (unknown)\n    \
+             A missing license isn't allowed by the policy."
+        ));
+        assert!(!report.contains("serde"));
+    }
+
+    #[test]
+    fn checking_needs_a_policy() {
+        let args = DepsArgs {
+            path: None,
+            ecosystem: None,
+            check: Some(true),
+            format: None,
+        };
+        let err = handle_deps(&args, &Config::default()).unwrap_err();
+        assert!(matches!(err, LichenError::InvalidConfig(_)));
+    }
+}
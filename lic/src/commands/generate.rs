@@ -1,12 +1,12 @@
 //! # Generate Command
 //!
-//! Logic for the `lichen gen` command.
+//! Logic for the `lic gen` command.
 
 use crate::config::Config;
 use crate::error::LichenError;
 use crate::models::Authors;
 use crate::models::GenArgs;
-use crate::models::License; // Make sure License is imported
+use crate::models::LicenseExpression;
 use crate::utils;
 use jiff::civil::Date;
 use log::{debug, info, trace, warn};
@@ -15,7 +15,7 @@ use std::path::PathBuf;
 
 #[derive(Debug)]
 pub struct GenSettings {
-    pub license: License,
+    pub license: LicenseExpression,
     pub multiple: bool,
     pub targets: Vec<PathBuf>,
     pub authors: Option<Authors>,
@@ -28,7 +28,7 @@ impl GenSettings {
             // user explicitly passed one on the command line
             cli_lic
         } else if let Some(idx) = index {
-            // user did `lichen gen` without `--license` but we have a config entry
+            // user did `lic gen` without `--license` but we have a config entry
             let lic = cfg
                 .licenses
                 .as_ref()
@@ -40,6 +40,12 @@ impl GenSettings {
             // no CLI value, no config entry
             return Err(LichenError::MissingLicense);
         };
+        let deny_deprecated = cli
+            .license_args
+            .deny_deprecated
+            .or(cfg.deny_deprecated)
+            .unwrap_or(false);
+        utils::check_deprecated(&license, deny_deprecated)?;
 
         let default_target = vec![PathBuf::from(".")];
 
@@ -104,7 +110,7 @@ pub fn handle_gen(settings: &GenSettings) -> Result<(), LichenError> {
     debug!("Starting handle_gen with args: {:?}", settings);
 
     // ▰▰▰ Resolve options from settings ▰▰▰
-    let license = settings.license;
+    let license = settings.license.license;
     let targets = &settings.targets;
     let multiple = settings.multiple;
     let authors = &settings.authors;
@@ -121,15 +127,12 @@ pub fn handle_gen(settings: &GenSettings) -> Result<(), LichenError> {
     );
 
     // ▰▰▰ Get License Header Content ▰▰▰ //
-    let template_content = settings.license.template_content();
-    debug!(
-        "Using embedded template content for {}",
-        settings.license.spdx_id()
-    );
-    trace!("Embedded template content:\n{}", template_content);
+    debug!("Using embedded template content for {}", settings.license);
+    trace!("Embedded template content:\n{}", license.template_content());
 
     // ▰▰▰ Render Template ▰▰▰
-    let rendered_license = utils::render_license(template_content, &year, authors)
+    // An exception's text follows the license's
+    let rendered_license = utils::render_expression(&settings.license, &year, authors)
         .map_err(LichenError::RenderError)?; // Convert RenderError
     debug!("License content rendered successfully.");
     trace!("Rendered content:\n{}", rendered_license);
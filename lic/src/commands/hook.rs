@@ -0,0 +1,129 @@
+//! # Hook Command
+//!
+//! Logic for the `lic hook` command, managing the git pre-commit hook.
+
+use crate::error::LichenError;
+use crate::models::{HookAction, HookArgs, HookMode};
+use crate::utils;
+use log::{debug, info};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Identifies the hooks written by Lichen, so others are never overwritten or removed.
+const HOOK_SIGNATURE: &str = "Installed by `lic hook install`";
+
+/// Handles the `hook` command logic, in the current repository.
+pub fn handle_hook(args: HookArgs) -> Result<(), LichenError> {
+    let repo = Path::new(".");
+    match args.action {
+        HookAction::Install(install) => {
+            install_hook(repo, install.mode, install.force.unwrap_or(false))
+        }
+        HookAction::Uninstall => uninstall_hook(repo),
+    }
+}
+
+/// Where git looks for the pre-commit hook, following `core.hooksPath`.
+fn hook_path(repo: &Path) -> Result<PathBuf, LichenError> {
+    let path = utils::run_git(repo, &["rev-parse", "--git-path", "hooks/pre-commit"])?;
+    Ok(repo.join(path.trim()))
+}
+
+/// The hook script. Changes made by `apply` are staged by `--staged` itself.
+fn hook_script(mode: HookMode) -> String {
+    let command = match mode {
+        HookMode::Check => "check",
+        HookMode::Apply => "apply",
+    };
+    format!(
+        "#!/bin/sh\n# Lichen pre-commit hook. {}, remove it with `lic hook uninstall`.\nexec lic {} --staged\n",
+        HOOK_SIGNATURE, command
+    )
+}
+
+fn is_lichen_hook(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|content| content.contains(HOOK_SIGNATURE))
+}
+
+/// Writes the pre-commit hook, refusing to replace a hook from another tool unless forced.
+fn install_hook(repo: &Path, mode: HookMode, force: bool) -> Result<(), LichenError> {
+    let path = hook_path(repo)?;
+    if path.exists() && !force && !is_lichen_hook(&path) {
+        return Err(LichenError::Msg(format!(
+            "A pre-commit hook already exists at '{}'. Use --force to replace it.",
+            path.display()
+        )));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, hook_script(mode))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    debug!("Wrote pre-commit hook to '{}'", path.display());
+    info!("Installed the pre-commit hook ({:?} mode).", mode);
+    Ok(())
+}
+
+/// Removes the pre-commit hook, if it was installed by Lichen.
+fn uninstall_hook(repo: &Path) -> Result<(), LichenError> {
+    let path = hook_path(repo)?;
+    if !path.exists() {
+        return Err(LichenError::NothingToDo(
+            "No pre-commit hook is installed.".to_string(),
+        ));
+    }
+    if !is_lichen_hook(&path) {
+        return Err(LichenError::Msg(format!(
+            "The pre-commit hook at '{}' wasn't installed by Lichen, leaving it in place.",
+            path.display()
+        )));
+    }
+
+    fs::remove_file(&path)?;
+    info!("Removed the pre-commit hook.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn install_and_uninstall_keep_other_hooks() {
+        let dir = tempdir().unwrap();
+        utils::run_git(dir.path(), &["init", "--quiet"]).unwrap();
+        let path = hook_path(dir.path()).unwrap();
+
+        install_hook(dir.path(), HookMode::Apply, false).unwrap();
+        assert!(
+            fs::read_to_string(&path)
+                .unwrap()
+                .contains("exec lic apply --staged")
+        );
+        // Reinstalling our own hook is fine
+        install_hook(dir.path(), HookMode::Check, false).unwrap();
+        assert!(
+            fs::read_to_string(&path)
+                .unwrap()
+                .contains("exec lic check --staged")
+        );
+        uninstall_hook(dir.path()).unwrap();
+        assert!(!path.exists());
+
+        // Hooks of other tools are left alone
+        fs::write(&path, "#!/bin/sh\nmake lint\n").unwrap();
+        assert!(install_hook(dir.path(), HookMode::Check, false).is_err());
+        assert!(uninstall_hook(dir.path()).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#!/bin/sh\nmake lint\n");
+
+        install_hook(dir.path(), HookMode::Check, true).unwrap();
+        assert!(is_lichen_hook(&path));
+    }
+}
@@ -0,0 +1,149 @@
+//! # List Command
+//!
+//! Logic for the `lic list` command.
+
+use crate::error::LichenError;
+use crate::models::{License, LicenseException, ListArgs, OutputFormat};
+use crate::utils::{self, LicenseMetadata};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::Write;
+
+/// A license of the embedded catalog, with what the SPDX license list says about it.
+#[derive(Debug, Serialize)]
+pub struct CatalogEntry {
+    pub id: &'static str,
+    #[serde(flatten)]
+    pub metadata: LicenseMetadata,
+}
+
+/// Handles the `list` command logic.
+pub fn handle_list(args: &ListArgs) -> Result<(), LichenError> {
+    let entries = matching_licenses(args);
+
+    let mut stdout = std::io::stdout().lock();
+    match args.format.unwrap_or_default() {
+        OutputFormat::Text => {
+            let width = entries
+                .iter()
+                .map(|entry| entry.id.len())
+                .max()
+                .unwrap_or(0);
+            for entry in &entries {
+                writeln!(
+                    stdout,
+                    "{:<width$}  {}",
+                    entry.id,
+                    describe(&entry.metadata)
+                )?;
+            }
+        }
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &entries)?;
+            writeln!(stdout)?;
+        }
+        OutputFormat::Sarif => return Err(utils::sarif_unsupported()),
+    }
+    Ok(())
+}
+
+/// The licenses passing every filter given, ordered by identifier.
+fn matching_licenses(args: &ListArgs) -> Vec<CatalogEntry> {
+    let search = args.search.as_deref().map(str::to_lowercase);
+    let licenses = License::value_variants()
+        .iter()
+        .map(|license| CatalogEntry {
+            id: license.spdx_id(),
+            metadata: utils::license_metadata(*license),
+        });
+    let exceptions = LicenseException::value_variants()
+        .iter()
+        .map(|exception| CatalogEntry {
+            id: exception.spdx_id(),
+            metadata: utils::exception_metadata(*exception),
+        });
+    let mut entries: Vec<CatalogEntry> = licenses
+        .chain(exceptions)
+        .filter(|entry| {
+            let meta = &entry.metadata;
+            let found = search.as_ref().is_none_or(|search| {
+                entry.id.to_lowercase().contains(search)
+                    || meta
+                        .name
+                        .is_some_and(|name| name.to_lowercase().contains(search))
+            });
+            found
+                && (!args.osi.unwrap_or(false) || meta.osi_approved)
+                && (!args.fsf.unwrap_or(false) || meta.fsf_libre)
+                && (!args.deprecated.unwrap_or(false) || meta.deprecated)
+                && (!args.exceptions.unwrap_or(false) || meta.exception)
+        })
+        .collect();
+    entries.sort_by_key(|entry| entry.id.to_lowercase());
+    entries
+}
+
+/// The name of a license followed by its flags, ex: "MIT License [OSI, FSF libre]".
+fn describe(metadata: &LicenseMetadata) -> String {
+    let mut flags = Vec::new();
+    if metadata.osi_approved {
+        flags.push("OSI");
+    }
+    if metadata.fsf_libre {
+        flags.push("FSF libre");
+    }
+    if metadata.exception {
+        flags.push("exception");
+    }
+    if metadata.deprecated {
+        flags.push("deprecated");
+    }
+
+    let name = metadata.name.unwrap_or("");
+    if flags.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} [{}]", name, flags.join(", "))
+            .trim()
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Cli, Commands};
+    use clap::Parser;
+
+    fn list_args(args: &[&str]) -> ListArgs {
+        let cli = Cli::try_parse_from(["lic", "list"].iter().chain(args)).unwrap();
+        match cli.command {
+            Commands::List(args) => args,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn list_filters_the_catalog() {
+        let ids = |args: &[&str]| -> Vec<&str> {
+            matching_licenses(&list_args(args))
+                .iter()
+                .map(|entry| entry.id)
+                .collect()
+        };
+
+        assert!(ids(&["apache", "--osi"]).contains(&"Apache-2.0"));
+        assert!(ids(&["--osi"]).contains(&"MIT"));
+        assert!(!ids(&["--osi"]).contains(&"CC-BY-NC-4.0"));
+        assert!(ids(&["--exceptions"]).contains(&"LLVM-exception"));
+        assert!(!ids(&["--exceptions"]).contains(&"MIT"));
+        assert!(ids(&["--deprecated"]).contains(&"GPL-2.0+"));
+        assert!(
+            ids(&["--deprecated"])
+                .iter()
+                .all(|id| !id.ends_with("-only"))
+        );
+        // Names are searched too
+        assert!(ids(&["zero clause"]).contains(&"0BSD"));
+    }
+}
@@ -3,6 +3,14 @@
 //! This module declares the submodules responsible for handling specific CLI commands.
 
 pub mod apply;
+pub mod check;
+pub mod compat;
+pub mod deps;
 pub mod generate;
+pub mod hook;
 pub mod init;
+pub mod list;
+pub mod show;
 pub mod unapply;
+pub mod undo;
+pub mod watch;
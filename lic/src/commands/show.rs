@@ -0,0 +1,147 @@
+//! # Show Command
+//!
+//! Logic for the `lic show` command.
+
+use crate::error::LichenError;
+use crate::models::{CatalogEntry, OutputFormat, ShowArgs};
+use crate::utils::{self, LicenseMetadata};
+use serde::Serialize;
+use std::io::Write;
+
+/// A license as shown, for tooling.
+#[derive(Serialize)]
+struct LicenseDetails<'a> {
+    id: &'static str,
+    #[serde(flatten)]
+    metadata: LicenseMetadata,
+    /// The exception from `WITH`, if any.
+    with: Option<ExceptionDetails>,
+    variables: Vec<TemplateVariable<'a>>,
+    text: &'a str,
+}
+
+/// An exception to the license.
+#[derive(Serialize)]
+struct ExceptionDetails {
+    id: &'static str,
+    #[serde(flatten)]
+    metadata: LicenseMetadata,
+}
+
+/// A variable of the template, with the text used when it's left unset.
+#[derive(Serialize)]
+struct TemplateVariable<'a> {
+    name: &'a str,
+    default: Option<&'a str>,
+}
+
+/// Handles the `show` command logic.
+/// Prints the metadata and variables of the license or exception, then its text, rendered unless
+/// asked for raw.
+pub fn handle_show(args: &ShowArgs) -> Result<(), LichenError> {
+    let date = args.date.unwrap_or_else(|| jiff::Zoned::now().date());
+    let (id, metadata, exception, templates, rendered) = match args.license {
+        CatalogEntry::License(expression) => {
+            let exception = expression.exception.map(|exception| ExceptionDetails {
+                id: exception.spdx_id(),
+                metadata: utils::exception_metadata(exception),
+            });
+            // An exception's text follows the license's
+            let templates: Vec<&str> = std::iter::once(expression.license.template_content())
+                .chain(expression.exception.map(|e| e.template_content()))
+                .collect();
+            (
+                expression.license.spdx_id(),
+                utils::license_metadata(expression.license),
+                exception,
+                templates,
+                utils::render_expression(&expression, &date, &args.authors),
+            )
+        }
+        CatalogEntry::Exception(exception) => (
+            exception.spdx_id(),
+            utils::exception_metadata(exception),
+            None,
+            vec![exception.template_content()],
+            utils::render_exception(exception),
+        ),
+    };
+    let variables: Vec<_> = templates
+        .iter()
+        .flat_map(|template| utils::template_variables(template))
+        .collect();
+
+    let text = if args.raw.unwrap_or(false) {
+        templates.join("\n\n")
+    } else {
+        rendered?
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    match args.format.unwrap_or_default() {
+        OutputFormat::Text => {
+            let yes_no = |flag: bool| if flag { "yes" } else { "no" };
+            writeln!(
+                stdout,
+                "{}: {}",
+                id,
+                metadata.name.unwrap_or("License exception")
+            )?;
+            if !metadata.exception {
+                writeln!(stdout, "OSI approved: {}", yes_no(metadata.osi_approved))?;
+                writeln!(stdout, "FSF libre: {}", yes_no(metadata.fsf_libre))?;
+            }
+            match metadata.replacement {
+                Some(replacement) => writeln!(stdout, "Deprecated: yes, use {}", replacement)?,
+                None => writeln!(stdout, "Deprecated: {}", yes_no(metadata.deprecated))?,
+            }
+            writeln!(stdout, "Reference: {}", metadata.reference)?;
+            for url in metadata.see_also {
+                writeln!(stdout, "See also: {}", url)?;
+            }
+            if let Some(exception) = &exception {
+                writeln!(
+                    stdout,
+                    "With: {} ({}{})",
+                    exception.id,
+                    exception.metadata.reference,
+                    if exception.metadata.deprecated {
+                        ", deprecated"
+                    } else {
+                        ""
+                    }
+                )?;
+            }
+            writeln!(stdout, "Variables:")?;
+            for (name, default) in &variables {
+                // Defaults may span lines, a glimpse is enough
+                let default = default
+                    .as_deref()
+                    .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+                    .unwrap_or_default();
+                writeln!(stdout, "  {}: {}", name, default)?;
+            }
+            writeln!(stdout)?;
+            writeln!(stdout, "{}", text.trim())?;
+        }
+        OutputFormat::Json => {
+            let details = LicenseDetails {
+                id,
+                metadata,
+                with: exception,
+                variables: variables
+                    .iter()
+                    .map(|(name, default)| TemplateVariable {
+                        name,
+                        default: default.as_deref(),
+                    })
+                    .collect(),
+                text: &text,
+            };
+            serde_json::to_writer_pretty(&mut stdout, &details)?;
+            writeln!(stdout)?;
+        }
+        OutputFormat::Sarif => return Err(utils::sarif_unsupported()),
+    }
+    Ok(())
+}
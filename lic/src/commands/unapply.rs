@@ -1,13 +1,26 @@
 //! # Unapply Command
 //!
-//! Logic for the `lichen unapply` command.
+//! Logic for the `lic unapply` command.
 
+use crate::config::Config;
 use crate::error::LichenError;
-use crate::models::UnapplyArgs;
+use crate::header::HeaderMatcher;
+use crate::journal::Journal;
+use crate::models::{License, UnapplyArgs};
 use crate::utils;
+use crate::utils::ProcessingSummary;
+use clap::ValueEnum;
 use log::{debug, info};
+use std::sync::Arc;
 
-pub async fn handle_unapply(args: UnapplyArgs) -> Result<(), LichenError> {
+/// Handles the `unapply` command logic, returning the outcome of every file.
+/// On a dry run, files are left untouched and the edits are returned instead.
+/// Original content of modified files is recorded in the journal, for `lic undo`.
+pub async fn handle_unapply(
+    args: UnapplyArgs,
+    cfg: &Config,
+    journal: &Arc<Journal>,
+) -> Result<ProcessingSummary, LichenError> {
     // Load options
     let targets = args.file_args.targets.unwrap_or(vec![".".into()]);
     let exclude = utils::build_exclude_regex(
@@ -18,39 +31,85 @@ pub async fn handle_unapply(args: UnapplyArgs) -> Result<(), LichenError> {
     )?;
 
     // ▰▰▰ Find Files ▰▰▰
-    let files_to_process = utils::get_valid_files(&targets, &exclude)?;
+    let mut files_to_process = utils::get_valid_files(&targets, &exclude)?;
+    if let Some(changes) =
+        utils::GitChanges::from_args(&args.file_args.changed_since, args.file_args.staged)
+    {
+        files_to_process = utils::filter_git_changes(files_to_process, &changes)?;
+        if files_to_process.is_empty() {
+            // Nothing changed, which is fine in a pre-commit hook
+            info!("No changed files to process.");
+            return Ok(ProcessingSummary::default());
+        }
+    }
     if files_to_process.is_empty() {
-        return Err(LichenError::Msg(
-            "No files require processing based on targets and exclusions. Exiting 'apply' command."
-                .to_string(),
-        )); // Nothing to do, error. 
+        // Nothing to do, which scripts tell apart by its exit code
+        return Err(LichenError::NothingToDo(
+            "No files require processing based on targets and exclusions.".to_string(),
+        ));
     }
 
-    // If dry run was passed, just return the files changes *would* have impacted
-    if args.dry_run.unwrap_or(false) {
-        info!("Changes will impact {:?}", files_to_process);
-        return Ok(());
-    }
+    // Writing a patch file is a dry run too
+    let dry_run = args.dry_run.unwrap_or(false) || args.patch.is_some();
 
     // ▰▰▰ Apply Headers ▰▰▰
     // TODO: Make concurrency configurable?
     let max_concurrency = std::thread::available_parallelism()
         .expect("There should always be some available parellism on the computer"); // Use available cores
-    utils::remove_headers_from_files(&files_to_process, max_concurrency).await?;
+    let legacy_encodings = args
+        .file_args
+        .legacy_encodings
+        .or(cfg.legacy_encodings)
+        .unwrap_or_default();
+
+    // Headers that lost their marker are recognized by the templates of the expected licenses
+    let matcher = if let Some(license) = args.license {
+        HeaderMatcher::new([license])
+    } else if let Some(licenses) = &cfg.licenses {
+        HeaderMatcher::new(licenses.iter().map(|lic| lic.id.license))
+    } else {
+        HeaderMatcher::new(License::value_variants().iter().copied())
+    };
+
+    // Others' headers rendered from the same templates are left in place
+    let owners: Vec<String> = match &args.authors {
+        Some(authors) => authors.0.iter().map(|a| a.name.clone()).collect(),
+        None => cfg
+            .licenses
+            .iter()
+            .flatten()
+            .flat_map(|lic| lic.authors.iter().flat_map(|authors| &authors.0))
+            .map(|author| author.name.clone())
+            .collect(),
+    };
+
+    let mut summary = utils::remove_headers_from_files(
+        &files_to_process,
+        matcher,
+        owners,
+        max_concurrency,
+        legacy_encodings,
+        dry_run,
+        journal.clone(),
+    )
+    .await;
+    if let Some(license) = args.license {
+        summary.set_license(license.spdx_id());
+    }
 
     info!(
         "Successfully unapplied license header for targets: {:?}",
         targets
     );
     debug!("Exclusion pattern: {:?}", exclude);
-    Ok(())
+    Ok(summary)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::{FileProcessingArgs, UnapplyArgs};
-    use crate::utils::{HEADER_MARKER, HEADER_MARKER_STR}; // Import marker
+    use crate::utils::HEADER_MARKER; // Import marker
     use std::fs;
     use tempfile::tempdir;
 
@@ -60,7 +119,7 @@ mod tests {
         let file_path = temp_dir.path().join("test_unapply.rs");
         fs::write(
             &file_path,
-            format!("// Header{}\n// Real Code", HEADER_MARKER_STR),
+            format!("// Header{}\n// Real Code", HEADER_MARKER),
         )
         .unwrap();
 
@@ -69,16 +128,29 @@ mod tests {
                 targets: Some(vec![file_path.clone()]),
                 exclude: None,
                 all: Some(true), // Ensure file is processed
+                legacy_encodings: None,
+                changed_since: None,
+                staged: None,
             },
             dry_run: Some(true),
+            license: None,
+            authors: None,
+            patch: None,
+            format: None,
         };
 
-        let result = handle_unapply(args).await;
+        let journal = Arc::new(Journal::new(temp_dir.path()));
+        let result = handle_unapply(args, &Config::default(), &journal).await;
         assert!(
             result.is_ok(),
             "handle_unapply dry run failed: {:?}",
             result
         );
+        assert_eq!(
+            result.unwrap().edits.len(),
+            1,
+            "The removal should be returned"
+        );
 
         // Verify file was NOT modified in dry run
         let content = fs::read_to_string(file_path).unwrap();
@@ -88,6 +160,33 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn handle_unapply_without_files_has_nothing_to_do() {
+        let temp_dir = tempdir().unwrap();
+        let args = UnapplyArgs {
+            file_args: FileProcessingArgs {
+                targets: Some(vec![temp_dir.path().to_path_buf()]),
+                exclude: None,
+                all: Some(true),
+                legacy_encodings: None,
+                changed_since: None,
+                staged: None,
+            },
+            dry_run: Some(true),
+            license: None,
+            authors: None,
+            patch: None,
+            format: None,
+        };
+
+        let journal = Arc::new(Journal::new(temp_dir.path()));
+        let err = handle_unapply(args, &Config::default(), &journal)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LichenError::NothingToDo(_)));
+        assert_eq!(err.exit_code(), 6);
+    }
+
     // Test actual removal requires utils::remove_headers_from_files test
     // which is also somewhat integration-like due to async fs.
 }
@@ -0,0 +1,17 @@
+//! # Undo Command
+//!
+//! Logic for the `lic undo` command.
+
+use crate::error::LichenError;
+use crate::journal;
+use log::{debug, info};
+use std::path::Path;
+
+/// Handles the `undo` command logic, restoring the files changed by the last `apply` or `unapply`.
+pub fn handle_undo(root: &Path) -> Result<(), LichenError> {
+    debug!("Undoing the last run recorded in '{}'", root.display());
+
+    let restored = journal::undo(root)?;
+    info!("Rolled back the last run, {} files restored.", restored);
+    Ok(())
+}
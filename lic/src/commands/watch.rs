@@ -0,0 +1,197 @@
+//! # Watch Mode
+//!
+//! Logic for `lic apply --watch`, applying headers to files as they appear.
+
+use crate::commands::apply::ApplySettings;
+use crate::error::LichenError;
+use crate::header::HeaderMatcher;
+use crate::journal::Journal;
+use crate::utils;
+use log::{debug, error, info, trace};
+use notify::event::{CreateKind, ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// How long the targets must stay quiet before the collected files are processed,
+/// so editors and tools creating files in several steps are done writing.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Handles `apply --watch`, applying the headers to files created or renamed under the targets.
+/// Runs until interrupted. The journal is shared by the whole session, `lic undo` rolls it back.
+pub async fn handle_watch(
+    settings: Vec<ApplySettings>,
+    journal: &Arc<Journal>,
+) -> Result<(), LichenError> {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        // The receiver only goes away once watching stops
+        let _ = sender.send(event);
+    })
+    .map_err(watch_error)?;
+
+    let mut targets: Vec<&PathBuf> = settings.iter().flat_map(|s| &s.targets).collect();
+    targets.sort();
+    targets.dedup();
+    for target in &targets {
+        watcher
+            .watch(target, RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+        debug!("Watching '{}'", target.display());
+    }
+    info!(
+        "Watching {:?} for new files, press Ctrl-C to stop.",
+        targets
+    );
+
+    // Events carry absolute paths, while targets and exclusions are written relative to here
+    let root = std::env::current_dir()?.canonicalize()?;
+    // Headers are written by renaming a temporary file into place, which is seen as a new file
+    let mut written = journal.recorded_paths().len();
+
+    while let Some(event) = receiver.recv().await {
+        let mut created = BTreeSet::new();
+        collect_created(event, &mut created);
+        while let Ok(Some(event)) = tokio::time::timeout(DEBOUNCE, receiver.recv()).await {
+            collect_created(event, &mut created);
+        }
+
+        let ours: Vec<PathBuf> = journal.recorded_paths().split_off(written);
+        written += ours.len();
+
+        let files: Vec<PathBuf> = created
+            .into_iter()
+            .filter(|path| path.is_file() && !ours.contains(path))
+            .map(|path| match path.strip_prefix(&root) {
+                Ok(relative) => Path::new(".").join(relative),
+                Err(_) => path,
+            })
+            .collect();
+        if files.is_empty() {
+            continue;
+        }
+        trace!("New files: {:?}", files);
+
+        for settings in &settings {
+            // A failure is reported, without ending the session
+            if let Err(e) = apply_to_new_files(settings, &files, journal).await {
+                error!("Failed to apply headers to new files: {}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps the paths of files that were created, or renamed into place.
+fn collect_created(event: notify::Result<Event>, created: &mut BTreeSet<PathBuf>) {
+    let event = match event {
+        Ok(event) => event,
+        Err(e) => {
+            error!("File watching error: {}", e);
+            return;
+        }
+    };
+
+    match event.kind {
+        EventKind::Create(CreateKind::File | CreateKind::Any) => created.extend(event.paths),
+        // Renames give the new path last
+        EventKind::Modify(ModifyKind::Name(
+            RenameMode::To | RenameMode::Both | RenameMode::Any,
+        )) => created.extend(event.paths.last().cloned()),
+        _ => {}
+    }
+}
+
+/// Applies one license's header to the new files it covers.
+async fn apply_to_new_files(
+    settings: &ApplySettings,
+    files: &[PathBuf],
+    journal: &Arc<Journal>,
+) -> Result<(), LichenError> {
+    let files: Vec<PathBuf> = files
+        .iter()
+        .filter(|file| settings.covers(file))
+        .cloned()
+        .collect();
+    if files.is_empty() {
+        return Ok(());
+    }
+    info!(
+        "Applying {} to {} new files",
+        settings.license.spdx_id(),
+        files.len()
+    );
+
+    let max_concurrency = std::thread::available_parallelism()
+        .expect("There should always be some available parellism on the computer");
+    utils::apply_headers_to_files(
+        &settings.render_header()?,
+        HeaderMatcher::new(settings.known_licenses.iter().copied()),
+        &settings.owners(),
+        &files,
+        max_concurrency,
+        settings.apply_options(),
+        journal.clone(),
+    )
+    .await
+    .into_result("application")
+    .map(|_| ())
+}
+
+fn watch_error(e: notify::Error) -> LichenError {
+    LichenError::Msg(format!("Could not watch the targets: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{DataChange, RemoveKind};
+
+    #[test]
+    fn only_created_and_renamed_files_are_collected() {
+        let event = |kind, paths: &[&str]| {
+            Ok(Event {
+                kind,
+                paths: paths.iter().map(PathBuf::from).collect(),
+                attrs: Default::default(),
+            })
+        };
+
+        let mut created = BTreeSet::new();
+        collect_created(
+            event(EventKind::Create(CreateKind::File), &["new.rs"]),
+            &mut created,
+        );
+        collect_created(
+            event(
+                EventKind::Modify(ModifyKind::Name(RenameMode::Both)),
+                &["old.rs", "renamed.rs"],
+            ),
+            &mut created,
+        );
+        collect_created(
+            event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                &["edited.rs"],
+            ),
+            &mut created,
+        );
+        collect_created(
+            event(EventKind::Remove(RemoveKind::File), &["removed.rs"]),
+            &mut created,
+        );
+        collect_created(
+            event(EventKind::Create(CreateKind::Folder), &["dir"]),
+            &mut created,
+        );
+
+        assert_eq!(
+            created.into_iter().collect::<Vec<_>>(),
+            [PathBuf::from("new.rs"), PathBuf::from("renamed.rs")]
+        );
+    }
+}
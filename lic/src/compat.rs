@@ -0,0 +1,627 @@
+//! # License Compatibility
+//!
+//! An embedded matrix of the known incompatibilities between licenses, telling whether code under
+//! one license can be part of a project distributed under another, and why not.
+
+use crate::models::{License, LicenseException, LicenseExpression, SpdxExpression};
+
+/// The GNU licenses, whose copyleft extends to the work combining them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GnuFamily {
+    Gpl,
+    Lgpl,
+    Agpl,
+}
+
+/// How far the copyleft of a license reaches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Copyleft {
+    /// Modified files, or the library itself, stay under the license.
+    Weak,
+    /// The whole work combining the code is released under the license.
+    Strong,
+}
+
+/// What an exception to a license waives when combining code under it with code under another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Waiver {
+    /// The copyleft stops at the code under the license, such as when linking to it.
+    Copyleft,
+    /// Code under the OpenSSL licenses may be combined with it, despite their advertising clauses.
+    OpenSsl,
+}
+
+/// What the terms of a license mean when combining code under it with code under another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Terms {
+    /// A GNU license, and the major versions it may be used under.
+    Gnu(GnuFamily, &'static [u8]),
+    /// Forbids uses free software licenses allow, the restriction completing "it ...".
+    NonFree(&'static str),
+    /// No license is granted at all.
+    Proprietary,
+    /// Permissive, or with a copyleft limited to its own files.
+    Other,
+    /// A license outside the catalog, which nothing is known about.
+    Unknown,
+}
+
+/// Licenses whose terms conflict with the GNU licenses, with the major versions of the GNU
+/// licenses they can't be combined with, and the reason why, completing "it ...".
+const GNU_INCOMPATIBLE: &[(License, &[u8], &str)] = &[
+    (
+        License::ApacheTwoDotZero,
+        &[1, 2],
+        "has patent termination and indemnification provisions, further restrictions only version 3 accepts",
+    ),
+    (
+        License::ApacheOneDotZero,
+        &[1, 2, 3],
+        "requires acknowledging the Apache Group in advertising materials",
+    ),
+    (
+        License::ApacheOneDotOne,
+        &[1, 2, 3],
+        "forbids naming derived products after Apache",
+    ),
+    (
+        License::BSDFourClause,
+        &[1, 2, 3],
+        "requires acknowledging the authors in advertising materials",
+    ),
+    (
+        License::OpenSsl,
+        &[1, 2, 3],
+        "requires acknowledging OpenSSL and Eric Young in advertising materials",
+    ),
+    (
+        License::OpenSslStandalone,
+        &[1, 2, 3],
+        "requires acknowledging OpenSSL in advertising materials",
+    ),
+    (
+        License::SsLeayStandalone,
+        &[1, 2, 3],
+        "requires acknowledging Eric Young in advertising materials",
+    ),
+    (
+        License::EPLOneDotZero,
+        &[1, 2, 3],
+        "has a weak copyleft and a choice of law clause the GNU licenses don't allow",
+    ),
+    (
+        License::EPLTwoDotZero,
+        &[1, 2, 3],
+        "has a weak copyleft the GNU licenses don't allow, unless the code names them as Secondary Licenses",
+    ),
+    (
+        License::CPLOneDotZero,
+        &[1, 2, 3],
+        "has a weak copyleft and a choice of law clause the GNU licenses don't allow",
+    ),
+    (
+        License::CDDLOneDotZero,
+        &[1, 2, 3],
+        "requires modified files to stay under the CDDL",
+    ),
+    (
+        License::CDDLOneDotOne,
+        &[1, 2, 3],
+        "requires modified files to stay under the CDDL",
+    ),
+    (
+        License::MPLOneDotZero,
+        &[1, 2, 3],
+        "requires modified files to stay under the MPL",
+    ),
+    (
+        License::MPLOneDotOne,
+        &[1, 2, 3],
+        "requires modified files to stay under the MPL",
+    ),
+    (
+        License::MPLTwoDotZeroNoCopyleftException,
+        &[1, 2, 3],
+        "opts out of the MPL-2.0 clause allowing its use under the GNU licenses",
+    ),
+    (
+        License::OSLThreeDotZero,
+        &[1, 2, 3],
+        "requires derived works to stay under the OSL, and has patent termination provisions",
+    ),
+    (
+        License::PHPThreeDotZeroOne,
+        &[1, 2, 3],
+        "forbids naming derived products after PHP",
+    ),
+    (
+        License::QPLOneDotZero,
+        &[1, 2, 3],
+        "requires modifications to be distributed as patches, apart from the original",
+    ),
+    (
+        License::ArtisticOneDotZero,
+        &[1, 2, 3],
+        "is too vague to grant what the GNU licenses require",
+    ),
+    (
+        License::MSPL,
+        &[1, 2, 3],
+        "requires the code to be distributed under the MS-PL only",
+    ),
+    (
+        License::MSRL,
+        &[1, 2, 3],
+        "requires modified files to stay under the MS-RL",
+    ),
+];
+
+/// Licenses that aren't free, with the restriction they impose, completing "it ...".
+/// Creative Commons licenses are told apart by their identifier, see `non_free_restriction`.
+const NON_FREE: &[(License, &str)] = &[
+    (
+        License::BUSLOneDotOne,
+        "restricts production use until its change date",
+    ),
+    (
+        License::SSPLOneDotZero,
+        "requires releasing the source of any service offering the software",
+    ),
+    (
+        License::ElasticTwoDotZero,
+        "forbids offering the software as a managed service",
+    ),
+    (
+        License::PolyFormNoncommercialOneDotZeroDotZero,
+        "forbids commercial use",
+    ),
+    (
+        License::PolyFormSmallBusinessOneDotZeroDotZero,
+        "restricts use to small businesses",
+    ),
+    (
+        License::JSON,
+        "requires the software be used for good, not evil",
+    ),
+    (
+        License::HippocraticTwoDotOne,
+        "restricts use to purposes its authors approve of",
+    ),
+];
+
+/// Identifier prefixes of the licenses whose copyleft reaches the whole work, besides the GNU ones.
+const STRONG_COPYLEFT: &[&str] = &[
+    "OSL-",
+    "EUPL-",
+    "CECILL-1",
+    "CECILL-2",
+    "RPL-",
+    "RPSL-",
+    "SSPL-",
+    "Sleepycat",
+    "CPAL-",
+    "CC-BY-SA-",
+    "CC-BY-NC-SA-",
+    "GFDL-",
+    "ODbL-",
+];
+
+/// Identifier prefixes of the licenses whose copyleft stops at their own files, besides the LGPL.
+const WEAK_COPYLEFT: &[&str] = &[
+    "MPL-", "EPL-", "CDDL-", "CPL-", "MS-RL", "CECILL-C", "APSL-", "ErlPL-", "IPL-", "SPL-", "NPL-",
+];
+
+/// Identifier prefixes of the exceptions letting works combine the code under any terms, such as
+/// the linking, runtime library and generated output exceptions.
+const COPYLEFT_WAIVERS: &[&str] = &[
+    "389-exception",
+    "Autoconf-exception-",
+    "Bison-exception-",
+    "Bootloader-exception",
+    "CLISP-exception-",
+    "Classpath-exception-",
+    "Digia-Qt-LGPL-exception-",
+    "FLTK-exception",
+    "Fawkes-Runtime-exception",
+    "Font-exception-",
+    "GCC-exception-",
+    "GNAT-exception",
+    "GNU-compiler-exception",
+    "GPL-3.0-389-ds-base-exception",
+    "GPL-3.0-interface-exception",
+    "GStreamer-exception-",
+    "Independent-modules-exception",
+    "KiCad-libraries-exception",
+    "LZMA-exception",
+    "Libtool-exception",
+    "Linux-syscall-note",
+    "Nokia-Qt-exception-",
+    "OpenJDK-assembly-exception-",
+    "PS-or-PDF-font-exception-",
+    "Qt-GPL-exception-",
+    "Qt-LGPL-exception-",
+    "SANE-exception",
+    "SWI-exception",
+    "Texinfo-exception",
+    "UBDL-exception",
+    "Universal-FOSS-exception-",
+    "WxWindows-exception-",
+    "eCos-exception-",
+    "freertos-exception-",
+    "gnu-javamail-exception",
+    "harbour-exception",
+    "i2p-gpl-java-exception",
+    "mif-exception",
+    "polyparse-exception",
+    "u-boot-exception-",
+];
+
+/// The licenses of OpenSSL, whose advertising clauses the OpenSSL exceptions waive.
+const OPENSSL_LICENSES: &[License] = &[
+    License::OpenSsl,
+    License::OpenSslStandalone,
+    License::SsLeayStandalone,
+];
+
+/// What the exception waives, if anything that matters when combining code.
+fn exception_waiver(exception: LicenseException) -> Option<Waiver> {
+    let id = exception.spdx_id();
+    if id.contains("linking") || COPYLEFT_WAIVERS.iter().any(|prefix| id.starts_with(prefix)) {
+        return Some(Waiver::Copyleft);
+    }
+    if id.to_lowercase().contains("openssl") || id == "stunnel-exception" {
+        return Some(Waiver::OpenSsl);
+    }
+    None
+}
+
+/// Whether the exception of the single license of an SPDX expression waives these terms.
+fn waives(expression: &SpdxExpression, waiver: Waiver) -> bool {
+    match expression {
+        SpdxExpression::License(license) => license
+            .exception
+            .and_then(exception_waiver)
+            .is_some_and(|found| found == waiver),
+        _ => false,
+    }
+}
+
+/// The GNU family and versions of a license, if it is one of the GNU licenses.
+fn gnu_terms(license: License) -> Option<(GnuFamily, &'static [u8])> {
+    use GnuFamily::*;
+
+    Some(match license {
+        License::GPLOneDotZeroOnly => (Gpl, &[1]),
+        License::GPLOneDotZeroOrLater => (Gpl, &[1, 2, 3]),
+        License::GPLTwoDotZeroOnly => (Gpl, &[2]),
+        License::GPLTwoDotZeroOrLater => (Gpl, &[2, 3]),
+        License::GPLThreeDotZeroOnly | License::GPLThreeDotZeroOrLater => (Gpl, &[3]),
+        License::LGPLTwoDotZeroOnly | License::LGPLTwoDotOneOnly => (Lgpl, &[2]),
+        License::LGPLTwoDotZeroOrLater | License::LGPLTwoDotOneOrLater => (Lgpl, &[2, 3]),
+        License::LGPLThreeDotZeroOnly | License::LGPLThreeDotZeroOrLater => (Lgpl, &[3]),
+        License::AGPLOneDotZeroOnly => (Agpl, &[1]),
+        License::AGPLOneDotZeroOrLater => (Agpl, &[1, 3]),
+        License::AGPLThreeDotZeroOnly | License::AGPLThreeDotZeroOrLater => (Agpl, &[3]),
+        _ => return None,
+    })
+}
+
+/// What combining code under the license means, deprecated identifiers read as their replacement.
+fn terms(expression: &SpdxExpression) -> Terms {
+    let license = match expression {
+        SpdxExpression::License(license) => current_license(*license),
+        SpdxExpression::Other(id) if is_proprietary(id) => return Terms::Proprietary,
+        _ => return Terms::Unknown,
+    };
+
+    // Exceptions only grant more, what they waive is weighed against the other license
+    if let Some((family, versions)) = gnu_terms(license.license) {
+        return Terms::Gnu(family, versions);
+    }
+
+    match non_free_restriction(license.license) {
+        Some(restriction) => Terms::NonFree(restriction),
+        None => Terms::Other,
+    }
+}
+
+/// The restriction making the license non-free, completing "it ...", if it isn't free.
+fn non_free_restriction(license: License) -> Option<&'static str> {
+    let id = license.spdx_id();
+    if id.starts_with("CC-BY-NC") {
+        return Some("forbids commercial use");
+    }
+    if id.starts_with("CC-BY-ND") {
+        return Some("forbids distributing modified versions");
+    }
+    NON_FREE
+        .iter()
+        .find(|(entry, _)| *entry == license)
+        .map(|(_, restriction)| *restriction)
+}
+
+/// Whether the license restricts uses free software licenses allow, such as commercial use.
+pub fn is_non_free(license: LicenseExpression) -> bool {
+    non_free_restriction(current_license(license).license).is_some()
+}
+
+/// How far the copyleft of the license reaches, if it has one. Exceptions waiving the copyleft
+/// of the combining work make a strong copyleft weak, as the GNU linking exceptions do.
+pub fn copyleft(license: LicenseExpression) -> Option<Copyleft> {
+    let license = current_license(license);
+    let id = license.license.spdx_id();
+    let reach = match gnu_terms(license.license) {
+        Some((GnuFamily::Lgpl, _)) => Copyleft::Weak,
+        Some(_) => Copyleft::Strong,
+        None if STRONG_COPYLEFT.iter().any(|prefix| id.starts_with(prefix)) => Copyleft::Strong,
+        None if WEAK_COPYLEFT.iter().any(|prefix| id.starts_with(prefix)) => Copyleft::Weak,
+        None => return None,
+    };
+    Some(match license.exception.and_then(exception_waiver) {
+        Some(Waiver::Copyleft) => Copyleft::Weak,
+        _ => reach,
+    })
+}
+
+/// The license the deprecated identifier stands for, ex: `GPL-2.0-or-later` for `GPL-2.0+`.
+pub fn current_license(expression: LicenseExpression) -> LicenseExpression {
+    match expression
+        .license
+        .replacement()
+        .and_then(|replacement| replacement.parse::<LicenseExpression>().ok())
+    {
+        Some(replacement) => LicenseExpression {
+            license: replacement.license,
+            exception: expression.exception.or(replacement.exception),
+        },
+        None => expression,
+    }
+}
+
+/// Whether a license outside the catalog grants nothing, such as `LicenseRef-Proprietary`,
+/// or npm's `UNLICENSED`.
+pub fn is_proprietary(id: &str) -> bool {
+    let id = id.to_lowercase();
+    id == "unlicensed" || id.contains("proprietary") || id.contains("confidential")
+}
+
+/// The versions a GNU licensed component may be used under in a project of the family.
+/// LGPL code can be relicensed under the GPL, version 2 or later for its versions 2.
+fn usable_versions(
+    component: (GnuFamily, &'static [u8]),
+    project_family: GnuFamily,
+) -> &'static [u8] {
+    match component {
+        (GnuFamily::Lgpl, versions) if project_family != GnuFamily::Lgpl => {
+            if versions.contains(&2) { &[2, 3] } else { &[3] }
+        }
+        (_, versions) => versions,
+    }
+}
+
+/// Lists versions for people, ex: "2 or 3".
+fn version_list(versions: &[u8]) -> String {
+    versions
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+/// Why GNU licensed code can't be part of a project under another GNU license, if it can't.
+/// An exception waiving the component's copyleft lets an LGPL project combine it, but versions
+/// must still agree.
+fn gnu_conflict(
+    project: (GnuFamily, &'static [u8]),
+    component: (GnuFamily, &'static [u8]),
+    copyleft_waived: bool,
+) -> Option<String> {
+    let (project_family, project_versions) = project;
+    if project_family == GnuFamily::Lgpl && component.0 != GnuFamily::Lgpl && !copyleft_waived {
+        return Some(
+            "its copyleft covers the whole work, while the LGPL lets others link to it under any terms"
+                .to_string(),
+        );
+    }
+
+    let versions = usable_versions(component, project_family);
+    let common: Vec<u8> = versions
+        .iter()
+        .copied()
+        .filter(|version| project_versions.contains(version))
+        .collect();
+    if common.is_empty() {
+        return Some(format!(
+            "it may only be used under version {} of the GNU licenses, and the project only under version {}",
+            version_list(versions),
+            version_list(project_versions)
+        ));
+    }
+
+    // Only their versions 3 let the GPL and AGPL be combined
+    let mixed = (project_family == GnuFamily::Agpl) != (component.0 == GnuFamily::Agpl);
+    if mixed && !common.contains(&3) {
+        return Some("only version 3 of the GPL and AGPL may be combined".to_string());
+    }
+    None
+}
+
+/// Why code under the `component` license can't be part of a project under the `project`
+/// license, both single licenses of SPDX expressions. Nothing is known of licenses outside the
+/// catalog, so they never conflict, unless they are proprietary.
+fn license_conflict(project: &SpdxExpression, component: &SpdxExpression) -> Option<String> {
+    let copyleft =
+        "its copyleft requires the whole work, sources included, to be released under it";
+    let reason = match (terms(project), terms(component)) {
+        (Terms::Unknown, _) | (_, Terms::Unknown) => return None,
+        (Terms::Gnu(family, versions), Terms::Gnu(component_family, component_versions)) => {
+            gnu_conflict(
+                (family, versions),
+                (component_family, component_versions),
+                waives(component, Waiver::Copyleft),
+            )?
+        }
+        (Terms::Gnu(..), Terms::NonFree(restriction)) => {
+            format!(
+                "it {}, a further restriction the GNU licenses forbid",
+                restriction
+            )
+        }
+        (Terms::Gnu(..), Terms::Proprietary) => {
+            "it grants none of the freedoms the GNU licenses require".to_string()
+        }
+        (Terms::Gnu(_, versions), Terms::Other) => {
+            let SpdxExpression::License(license) = component else {
+                return None;
+            };
+            let license = current_license(*license).license;
+            if OPENSSL_LICENSES.contains(&license) && waives(project, Waiver::OpenSsl) {
+                return None;
+            }
+            let (_, incompatible, reason) = GNU_INCOMPATIBLE
+                .iter()
+                .find(|(entry, _, _)| *entry == license)?;
+            if !versions
+                .iter()
+                .all(|version| incompatible.contains(version))
+            {
+                return None;
+            }
+            format!("it {}", reason)
+        }
+        (_, Terms::Gnu(GnuFamily::Gpl | GnuFamily::Agpl, _))
+            if !waives(component, Waiver::Copyleft) =>
+        {
+            copyleft.to_string()
+        }
+        (Terms::Other, Terms::Proprietary) => {
+            "it grants none of the rights the project's license does".to_string()
+        }
+        (Terms::Other, Terms::NonFree(restriction)) => {
+            format!("it {}, which the project's license allows", restriction)
+        }
+        _ => return None,
+    };
+    Some(format!(
+        "{} code can't be distributed under {}: {}.",
+        component, project, reason
+    ))
+}
+
+/// Why code under the `component` license can't be part of a project under the `project` license.
+/// Every license of the project's expression must allow the component, which is allowed when all
+/// the licenses joined by AND are, or any of those joined by OR.
+///
+/// # Returns
+///
+/// An explanation per conflict, empty when no conflict is known.
+pub fn conflicts(project: &SpdxExpression, component: &SpdxExpression) -> Vec<String> {
+    let mut reasons: Vec<String> = Vec::new();
+    for outbound in project.leaves() {
+        for reason in component.rejections(&|leaf| license_conflict(outbound, leaf)) {
+            if !reasons.contains(&reason) {
+                reasons.push(reason);
+            }
+        }
+    }
+    reasons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conflicts_of(project: &str, component: &str) -> Vec<String> {
+        conflicts(
+            &project.parse().unwrap(),
+            &SpdxExpression::parse_lenient(component).unwrap(),
+        )
+    }
+
+    #[test]
+    fn apache_conflicts_with_gpl_version_2_only() {
+        let reasons = conflicts_of("GPL-2.0-only", "Apache-2.0");
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons[0].starts_with("Apache-2.0 code can't be distributed under GPL-2.0-only"));
+        assert!(reasons[0].contains("patent"));
+
+        assert!(conflicts_of("GPL-2.0-or-later", "Apache-2.0").is_empty());
+        assert!(conflicts_of("GPL-3.0-only", "Apache-2.0").is_empty());
+        assert!(!conflicts_of("LGPL-2.1-only", "Apache-2.0").is_empty());
+    }
+
+    #[test]
+    fn gnu_versions_and_families_must_agree() {
+        assert!(!conflicts_of("GPL-2.0-only", "GPL-3.0-only").is_empty());
+        assert!(conflicts_of("GPL-2.0-or-later", "GPL-3.0-or-later").is_empty());
+        assert!(conflicts_of("GPL-3.0-only", "GPL-2.0+").is_empty());
+        assert!(conflicts_of("GPL-3.0-only", "LGPL-2.1-only").is_empty());
+        assert!(!conflicts_of("GPL-2.0-only", "LGPL-3.0-only").is_empty());
+        assert!(conflicts_of("GPL-3.0-or-later", "AGPL-3.0-only").is_empty());
+        assert!(!conflicts_of("GPL-2.0-only", "AGPL-3.0-only").is_empty());
+        assert!(!conflicts_of("LGPL-3.0-only", "GPL-3.0-only").is_empty());
+    }
+
+    #[test]
+    fn copyleft_code_conflicts_with_other_licenses() {
+        assert!(!conflicts_of("MIT", "GPL-3.0-only").is_empty());
+        assert!(!conflicts_of("LicenseRef-Proprietary", "AGPL-3.0-only").is_empty());
+        // Weak copyleft and linking exceptions stop at the component
+        assert!(conflicts_of("MIT", "LGPL-2.1-or-later").is_empty());
+        assert!(conflicts_of("MIT", "MPL-2.0").is_empty());
+        assert!(conflicts_of("Apache-2.0", "GPL-2.0-only WITH Classpath-exception-2.0").is_empty());
+        assert!(
+            conflicts_of("LGPL-2.1-only", "GPL-2.0-only WITH Classpath-exception-2.0").is_empty()
+        );
+        // Other exceptions leave the copyleft as it is
+        assert!(!conflicts_of("MIT", "GPL-2.0-only WITH GPL-CC-1.0").is_empty());
+    }
+
+    #[test]
+    fn exceptions_only_waive_their_own_terms() {
+        // A linking exception doesn't change the versions the code may be used under
+        let reasons = conflicts_of("GPL-2.0-only", "GPL-3.0-only WITH GCC-exception-3.1");
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons[0].contains("version 3"));
+
+        // Nor does the project's exception lift the further restrictions of the code it includes
+        let reasons = conflicts_of("GPL-2.0-only WITH Classpath-exception-2.0", "Apache-2.0");
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons[0].contains("patent"));
+
+        // An OpenSSL exception only lifts the OpenSSL advertising clauses
+        assert!(
+            conflicts_of("GPL-2.0-or-later WITH openvpn-openssl-exception", "OpenSSL").is_empty()
+        );
+        assert!(!conflicts_of("GPL-2.0-or-later", "OpenSSL").is_empty());
+        assert!(
+            !conflicts_of(
+                "GPL-2.0-or-later WITH openvpn-openssl-exception",
+                "BSD-4-Clause"
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn non_free_and_proprietary_code_conflicts_with_free_licenses() {
+        assert!(!conflicts_of("AGPL-3.0-only", "LicenseRef-Proprietary").is_empty());
+        assert!(!conflicts_of("GPL-3.0-only", "CC-BY-NC-4.0").is_empty());
+        assert!(!conflicts_of("MIT", "BUSL-1.1").is_empty());
+        assert!(!conflicts_of("MIT", "UNLICENSED").is_empty());
+        assert!(conflicts_of("LicenseRef-Proprietary", "BUSL-1.1").is_empty());
+        assert!(conflicts_of("MIT", "LicenseRef-Custom").is_empty());
+    }
+
+    #[test]
+    fn expressions_are_allowed_by_any_choice_and_every_part() {
+        assert!(conflicts_of("GPL-2.0-only", "MIT OR Apache-2.0").is_empty());
+        assert_eq!(conflicts_of("GPL-2.0-only", "MIT AND Apache-2.0").len(), 1);
+        assert_eq!(
+            conflicts_of("GPL-2.0-only", "Apache-2.0 OR CDDL-1.0").len(),
+            2
+        );
+        // Every license the project is offered under must allow the component
+        assert_eq!(conflicts_of("MIT OR GPL-2.0-only", "Apache-2.0").len(), 1);
+    }
+}
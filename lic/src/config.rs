@@ -3,8 +3,9 @@
 //! Manages the loading of options from a TOML config input
 
 use crate::error::LichenError;
-use crate::models::License;
-use crate::models::{Author, Authors};
+use crate::models::LicenseExpression;
+use crate::models::{Author, Authors, Ecosystem, PolicyEntry, ThirdPartyAction};
+use crate::utils;
 use jiff::civil::Date;
 use log::{debug, warn};
 use regex::Regex;
@@ -33,9 +34,39 @@ pub struct Config {
     #[serde(default)]
     pub all: Option<bool>,
 
+    /// Decode UTF-16 and ISO-8859-1 files instead of skipping them as unsupported.
+    #[serde(default)]
+    pub legacy_encodings: Option<bool>,
+
+    /// The column that applied headers are reflowed to, comment prefix included.
+    /// Unset leaves the template lines as they are.
+    #[serde(default)]
+    pub width: Option<usize>,
+
+    /// Whether applied headers carry an invisible marker, making them quicker to find.
+    /// Unmarked headers are still recognized by their content. Defaults to true.
+    #[serde(default)]
+    pub marker: Option<bool>,
+
+    /// Replace leading copyright or license comments not written by Lichen when applying.
+    #[serde(default)]
+    pub replace_foreign: Option<bool>,
+
+    /// What to do with files carrying copyright notices that don't name the authors.
+    #[serde(default)]
+    pub third_party: Option<ThirdPartyAction>,
+
+    /// Refuse licenses with deprecated SPDX identifiers instead of warning about them.
+    #[serde(default)]
+    pub deny_deprecated: Option<bool>,
+
     /// Per‑license configuration blocks.
     #[serde(rename = "license", default)]
     pub licenses: Option<Vec<LicenseConfig>>,
+
+    /// The licenses dependencies may have, checked by `lic deps --check`.
+    #[serde(default)]
+    pub policy: Option<PolicyConfig>,
 }
 
 /// Try to load and parse the config file.
@@ -43,7 +74,14 @@ pub struct Config {
 impl Config {
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, LichenError> {
         let s = fs::read_to_string(path.as_ref()).map_err(LichenError::from)?;
-        toml::from_str(&s).map_err(|e| LichenError::Msg(format!("config parse error: {}", e)))
+        // The TOML error shows the offending line, pointing at the problem
+        toml::from_str(&s).map_err(|e| {
+            LichenError::InvalidConfig(format!(
+                "config parse error in '{}':\n{}",
+                path.as_ref().display(),
+                e.to_string().trim_end()
+            ))
+        })
     }
 
     /// Like `load`, but if the file was *not found*, you get `Config::default()`.
@@ -80,14 +118,77 @@ pub struct LicenseConfig {
     #[serde(default)]
     pub date: Option<Date>,
 
-    /// SPDX identifier.
-    pub id: License,
+    /// SPDX identifier, optionally followed by WITH and an exception.
+    pub id: LicenseExpression,
 
     /// List of named authors.
     #[serde(default)]
     pub authors: Option<Authors>,
 }
 
+impl LicenseConfig {
+    /// Whether the file falls under the targets of this license, the current directory when it
+    /// has none, and isn't excluded by it.
+    pub fn covers(&self, path: &Path) -> bool {
+        let targets = self
+            .targets
+            .clone()
+            .unwrap_or_else(|| vec![PathBuf::from(".")]);
+        utils::covers(&targets, self.exclude.as_ref(), path)
+    }
+}
+
+/// The licenses dependencies may have.
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// Licenses and categories dependencies may have. Empty allows any license not denied.
+    #[serde(default)]
+    pub allow: Vec<PolicyEntry>,
+
+    /// Licenses and categories dependencies may not have, even when a category allows them.
+    #[serde(default)]
+    pub deny: Vec<PolicyEntry>,
+
+    /// Packages the policy makes an exception for.
+    #[serde(rename = "exception", default)]
+    pub exceptions: Vec<PolicyException>,
+}
+
+/// An exception of the policy for a single package, such as one whose license was cleared by
+/// legal review.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyException {
+    /// Name of the package.
+    pub name: String,
+
+    /// Only this version of the package. Unset matches every version.
+    #[serde(default)]
+    pub version: Option<String>,
+
+    /// Only the package of this package manager. Unset matches every package manager.
+    #[serde(default)]
+    pub ecosystem: Option<Ecosystem>,
+
+    /// Licenses and categories allowed for the package only. Empty exempts the package entirely.
+    #[serde(default)]
+    pub allow: Vec<PolicyEntry>,
+
+    /// Why the exception is made, required so that it can be reviewed.
+    #[serde(deserialize_with = "non_blank")]
+    pub reason: String,
+}
+
+/// Reads a text that must say something, such as the reason of a policy exception.
+fn non_blank<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    if text.trim().is_empty() {
+        return Err(serde::de::Error::custom("can't be empty"));
+    }
+    Ok(text)
+}
+
 impl fmt::Display for Author {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Open to this being changed, just what made sense at the time.
@@ -168,7 +269,7 @@ mod tests {
 mod tests_load {
     // Separate module to avoid conflicts with existing tests mod
     use super::*;
-    use crate::models::License;
+    use crate::models::{License, LicenseCategory};
     use std::fs;
     use tempfile::NamedTempFile; // Import License
 
@@ -246,7 +347,7 @@ authors = [
 
         // Check the first (and only) license
         let lic1 = &licenses[0];
-        assert_eq!(lic1.id.spdx_id(), "MIT"); // Check the raw string ID from TOML
+        assert_eq!(lic1.id.license.spdx_id(), "MIT"); // Check the raw string ID from TOML
 
         assert_eq!(
             lic1.targets,
@@ -287,17 +388,119 @@ id = "Unlicense"
         assert!(config.multiple.is_none());
         assert!(config.exclude.is_none());
         assert!(config.all.is_none());
+        assert!(config.width.is_none());
+        assert!(config.legacy_encodings.is_none());
+        assert!(config.marker.is_none());
+        assert!(config.replace_foreign.is_none());
+        assert!(config.third_party.is_none());
+        assert!(config.deny_deprecated.is_none());
 
         assert!(config.licenses.is_some());
         let licenses = config.licenses.unwrap();
         assert_eq!(licenses.len(), 1);
-        assert_eq!(licenses[0].id, License::Unlicense);
+        assert_eq!(licenses[0].id, License::Unlicense.into());
         assert!(licenses[0].targets.is_none());
         assert!(licenses[0].authors.is_none());
         assert!(licenses[0].exclude.is_none());
         assert!(licenses[0].date.is_none());
     }
 
+    #[test]
+    fn config_load_license_with_exception() {
+        let content = r#"
+[[license]]
+id = "GPL-2.0-or-later WITH Classpath-exception-2.0"
+"#;
+        let file = NamedTempFile::new().unwrap();
+        fs::write(file.path(), content).unwrap();
+        let licenses = Config::load(file.path()).unwrap().licenses.unwrap();
+        assert_eq!(licenses[0].id.license.spdx_id(), "GPL-2.0-or-later");
+        assert_eq!(
+            licenses[0].id.exception.map(|e| e.spdx_id()),
+            Some("Classpath-exception-2.0")
+        );
+        assert_eq!(
+            licenses[0].id.to_string(),
+            "GPL-2.0-or-later WITH Classpath-exception-2.0"
+        );
+
+        // Informal names are read too, unknown ones get suggestions
+        fs::write(file.path(), "[[license]]\nid = \"apache 2.0\"\n").unwrap();
+        let licenses = Config::load(file.path()).unwrap().licenses.unwrap();
+        assert_eq!(licenses[0].id, License::ApacheTwoDotZero.into());
+        fs::write(file.path(), "[[license]]\nid = \"Apach-2.0\"\n").unwrap();
+        let err = Config::load(file.path()).unwrap_err().to_string();
+        assert!(err.contains("did you mean 'Apache-2.0'"), "{}", err);
+
+        // Exceptions aren't licenses on their own
+        fs::write(file.path(), "[[license]]\nid = \"LLVM-exception\"\n").unwrap();
+        let err = Config::load(file.path()).unwrap_err().to_string();
+        assert!(err.contains("'LLVM-exception' is a license exception"));
+    }
+
+    #[test]
+    fn config_load_policy() {
+        let content = r#"
+[policy]
+allow = ["permissive", "MPL-2.0", "apache 2.0"]
+deny = ["strong-copyleft"]
+
+[[policy.exception]]
+name = "ring"
+ecosystem = "cargo"
+allow = ["OpenSSL"]
+reason = "Cleared by legal review"
+
+[[policy.exception]]
+name = "internal-tool"
+version = "0.3.1"
+reason = "Build tool, never distributed"
+"#;
+        let file = NamedTempFile::new().unwrap();
+        fs::write(file.path(), content).unwrap();
+        let policy = Config::load(file.path()).unwrap().policy.unwrap();
+
+        assert_eq!(
+            policy.allow,
+            vec![
+                PolicyEntry::Category(LicenseCategory::Permissive),
+                PolicyEntry::License(License::MPLTwoDotZero.into()),
+                PolicyEntry::License(License::ApacheTwoDotZero.into()),
+            ]
+        );
+        assert_eq!(
+            policy.deny,
+            vec![PolicyEntry::Category(LicenseCategory::StrongCopyleft)]
+        );
+        assert_eq!(policy.exceptions.len(), 2);
+        assert_eq!(policy.exceptions[0].ecosystem, Some(Ecosystem::Cargo));
+        assert_eq!(
+            policy.exceptions[0].allow,
+            vec![PolicyEntry::License(License::OpenSsl.into())]
+        );
+        assert_eq!(policy.exceptions[1].version.as_deref(), Some("0.3.1"));
+        assert!(policy.exceptions[1].allow.is_empty());
+
+        // Exceptions must say why, and entries be licenses or categories
+        fs::write(file.path(), "[[policy.exception]]\nname = \"ring\"\n").unwrap();
+        let err = Config::load(file.path()).unwrap_err().to_string();
+        assert!(err.contains("missing field `reason`"), "{}", err);
+        fs::write(
+            file.path(),
+            "[[policy.exception]]\nname = \"ring\"\nreason = \" \"\n",
+        )
+        .unwrap();
+        let err = Config::load(file.path()).unwrap_err().to_string();
+        assert!(err.contains("can't be empty"), "{}", err);
+        // A misspelt key would otherwise leave the policy allowing everything
+        fs::write(file.path(), "[policy]\nalow = [\"MIT\"]\n").unwrap();
+        let err = Config::load(file.path()).unwrap_err().to_string();
+        assert!(err.contains("unknown field `alow`"), "{}", err);
+        fs::write(file.path(), "[policy]\ndeny = [\"copylefty\"]\n").unwrap();
+        let err = Config::load(file.path()).unwrap_err().to_string();
+        assert!(err.contains("weak-copyleft"), "{}", err);
+    }
+
     #[test]
     fn config_load_invalid_toml_returns_err() {
         let content = r#"
@@ -309,7 +512,7 @@ multiple = "not a boolean" # Invalid type
 
         let result = Config::load(file.path());
         assert!(result.is_err());
-        assert!(matches!(result, Err(LichenError::Msg(_))));
+        assert!(matches!(result, Err(LichenError::InvalidConfig(_))));
         assert!(
             result
                 .unwrap_err()
@@ -353,6 +556,6 @@ multiple = "not a boolean" # Invalid type
 
         let result = Config::load_or_default(file.path());
         assert!(result.is_err());
-        assert!(matches!(result, Err(LichenError::Msg(_))));
+        assert!(matches!(result, Err(LichenError::InvalidConfig(_))));
     }
 }
@@ -0,0 +1,638 @@
+//! # Dependency Inventory
+//!
+//! Finds the dependencies of a project and the licenses they declare without any network access,
+//! from lockfiles and the manifests of vendored or installed packages, for Cargo, npm and Python.
+
+use crate::error::LichenError;
+use crate::models::{Ecosystem, SpdxExpression};
+use log::{debug, warn};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Trove classifiers of Python packages naming a license, and its SPDX identifier.
+/// Classifiers such as "BSD License" are left out, as they don't tell which license it is.
+const CLASSIFIER_LICENSES: &[(&str, &str)] = &[
+    ("Apache Software License", "Apache-2.0"),
+    ("MIT License", "MIT"),
+    ("MIT No Attribution License (MIT-0)", "MIT-0"),
+    ("ISC License (ISCL)", "ISC"),
+    ("Mozilla Public License 1.1 (MPL 1.1)", "MPL-1.1"),
+    ("Mozilla Public License 2.0 (MPL 2.0)", "MPL-2.0"),
+    ("GNU General Public License v2 (GPLv2)", "GPL-2.0-only"),
+    (
+        "GNU General Public License v2 or later (GPLv2+)",
+        "GPL-2.0-or-later",
+    ),
+    ("GNU General Public License v3 (GPLv3)", "GPL-3.0-only"),
+    (
+        "GNU General Public License v3 or later (GPLv3+)",
+        "GPL-3.0-or-later",
+    ),
+    (
+        "GNU Lesser General Public License v2 (LGPLv2)",
+        "LGPL-2.0-only",
+    ),
+    (
+        "GNU Lesser General Public License v2 or later (LGPLv2+)",
+        "LGPL-2.0-or-later",
+    ),
+    (
+        "GNU Lesser General Public License v3 (LGPLv3)",
+        "LGPL-3.0-only",
+    ),
+    (
+        "GNU Lesser General Public License v3 or later (LGPLv3+)",
+        "LGPL-3.0-or-later",
+    ),
+    ("GNU Affero General Public License v3", "AGPL-3.0-only"),
+    (
+        "GNU Affero General Public License v3 or later (AGPLv3+)",
+        "AGPL-3.0-or-later",
+    ),
+    ("Python Software Foundation License", "PSF-2.0"),
+    ("The Unlicense (Unlicense)", "Unlicense"),
+    ("Boost Software License 1.0 (BSL-1.0)", "BSL-1.0"),
+    ("Eclipse Public License 2.0 (EPL-2.0)", "EPL-2.0"),
+    ("European Union Public Licence 1.2 (EUPL 1.2)", "EUPL-1.2"),
+    ("Universal Permissive License (UPL)", "UPL-1.0"),
+    ("zlib/libpng License", "Zlib"),
+    ("Zope Public License", "ZPL-2.1"),
+    ("Historical Permission Notice and Disclaimer (HPND)", "HPND"),
+    (
+        "CC0 1.0 Universal (CC0 1.0) Public Domain Dedication",
+        "CC0-1.0",
+    ),
+];
+
+/// Python `License` fields longer than this hold the license text rather than its name.
+const MAX_LICENSE_NAME_LENGTH: usize = 100;
+
+/// A dependency of the project, with the license it declares.
+#[derive(Debug, Clone, Serialize)]
+pub struct Dependency {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    pub version: String,
+    /// The license as the package declares it.
+    pub declared: Option<String>,
+    /// The declared license as an SPDX expression, licenses outside the catalog kept as they are.
+    pub license: Option<SpdxExpression>,
+    /// The manifest or lockfile the license was read from.
+    pub manifest: Option<PathBuf>,
+}
+
+impl Dependency {
+    fn new(ecosystem: Ecosystem, name: &str, version: &str) -> Self {
+        Dependency {
+            ecosystem,
+            name: name.to_string(),
+            version: version.to_string(),
+            declared: None,
+            license: None,
+            manifest: None,
+        }
+    }
+
+    /// Records the license declared in the manifest at `path`.
+    fn declare(&mut self, declared: Option<&str>, path: &Path) {
+        self.license = declared.and_then(normalize_license);
+        self.declared = declared.map(str::to_string);
+        self.manifest = Some(path.to_path_buf());
+    }
+}
+
+/// Where installed packages are looked for, besides the project itself.
+#[derive(Debug, Clone, Default)]
+pub struct SearchPaths {
+    /// Cargo's home, holding the sources of the crates downloaded from registries.
+    pub cargo_home: Option<PathBuf>,
+    /// The active Python virtual environment.
+    pub virtual_env: Option<PathBuf>,
+}
+
+impl SearchPaths {
+    /// The locations of the environment: `CARGO_HOME`, or `~/.cargo`, and `VIRTUAL_ENV`.
+    pub fn from_env() -> Self {
+        let cargo_home = std::env::var_os("CARGO_HOME")
+            .map(PathBuf::from)
+            .or_else(|| directories::BaseDirs::new().map(|dirs| dirs.home_dir().join(".cargo")));
+        SearchPaths {
+            cargo_home,
+            virtual_env: std::env::var_os("VIRTUAL_ENV").map(PathBuf::from),
+        }
+    }
+}
+
+/// Lists the dependencies of the project in `root` and their licenses.
+///
+/// # Arguments
+///
+/// * `root`: The directory of the project.
+/// * `ecosystems`: The package managers to read the dependencies of.
+/// * `paths`: Where installed packages are looked for, see `SearchPaths`.
+///
+/// # Returns
+///
+/// The dependencies, grouped by package manager. Those whose manifest wasn't found have no license.
+pub fn inventory(
+    root: &Path,
+    ecosystems: &[Ecosystem],
+    paths: &SearchPaths,
+) -> Result<Vec<Dependency>, LichenError> {
+    let mut dependencies = Vec::new();
+    for ecosystem in ecosystems {
+        dependencies.extend(match ecosystem {
+            Ecosystem::Cargo => cargo_dependencies(root, paths.cargo_home.as_deref())?,
+            Ecosystem::Npm => npm_dependencies(root)?,
+            Ecosystem::Python => python_dependencies(root, paths.virtual_env.as_deref())?,
+        });
+    }
+    Ok(dependencies)
+}
+
+/// Reads the license string of a package as an SPDX expression, ex: "MIT/Apache-2.0" as
+/// `MIT OR Apache-2.0`. Placeholders such as "UNKNOWN" give nothing.
+pub fn normalize_license(declared: &str) -> Option<SpdxExpression> {
+    let declared = declared.trim();
+    if declared.is_empty() || ["UNKNOWN", "NOASSERTION", "NONE"].contains(&declared) {
+        return None;
+    }
+    SpdxExpression::parse_lenient(declared)
+        .inspect_err(|e| debug!("'{}' isn't a license expression: {}", declared, e))
+        .ok()
+}
+
+/// Reads a manifest or lockfile, as JSON whatever its format: package.json and package-lock.json
+/// are JSON, the others (Cargo.toml, Cargo.lock) TOML. Files that don't exist or can't be parsed
+/// give nothing, the latter with a warning.
+pub fn read_manifest(path: &Path) -> Result<Option<Value>, LichenError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(LichenError::from(e).for_file("reading", path)),
+    };
+
+    let parsed = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    } else {
+        toml::from_str::<toml::Value>(&content)
+            .map_err(|e| e.to_string())
+            .and_then(|value| serde_json::to_value(value).map_err(|e| e.to_string()))
+    };
+    match parsed {
+        Ok(manifest) => Ok(Some(manifest)),
+        Err(e) => {
+            warn!("Skipping '{}', it can't be parsed: {}", path.display(), e);
+            Ok(None)
+        }
+    }
+}
+
+/// The package table of a Cargo.toml, or the whole package.json.
+pub fn manifest_package(manifest: &Value) -> &Value {
+    manifest.get("package").unwrap_or(manifest)
+}
+
+/// The license string a Cargo.toml or package.json declares for its package, including the
+/// legacy `{ "type": "MIT" }` form of npm.
+pub fn declared_license(manifest: &Value) -> Option<&str> {
+    let license = manifest_package(manifest).get("license")?;
+    license.as_str().or_else(|| license.get("type")?.as_str())
+}
+
+/// The crates of the Cargo.lock of the project, or of the workspace it belongs to.
+/// Their manifest is looked for in the workspace's `vendor/`, then in the registry sources of
+/// Cargo's home. Crates without a source are the workspace's own, and left out.
+fn cargo_dependencies(
+    root: &Path,
+    cargo_home: Option<&Path>,
+) -> Result<Vec<Dependency>, LichenError> {
+    let Some(lock_path) = root
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.is_file())
+    else {
+        debug!("No Cargo.lock found from '{}'", root.display());
+        return Ok(Vec::new());
+    };
+    let Some(lock) = read_manifest(&lock_path)? else {
+        return Ok(Vec::new());
+    };
+    let workspace = lock_path.parent().unwrap_or(root);
+
+    // Each registry has its own directory of sources
+    let mut registries: Vec<PathBuf> = cargo_home
+        .and_then(|home| fs::read_dir(home.join("registry").join("src")).ok())
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default();
+    registries.sort();
+
+    let packages = lock.get("package").and_then(Value::as_array);
+    let mut dependencies = Vec::new();
+    for package in packages.into_iter().flatten() {
+        let (Some(name), Some(version)) = (
+            package.get("name").and_then(Value::as_str),
+            package.get("version").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+        if package.get("source").is_none() {
+            continue;
+        }
+
+        // `cargo vendor` suffixes the version only when several are vendored
+        let versioned = format!("{}-{}", name, version);
+        let candidates = [
+            workspace.join("vendor").join(name),
+            workspace.join("vendor").join(&versioned),
+        ]
+        .into_iter()
+        .chain(registries.iter().map(|registry| registry.join(&versioned)));
+
+        let mut dependency = Dependency::new(Ecosystem::Cargo, name, version);
+        for dir in candidates {
+            let path = dir.join("Cargo.toml");
+            let Some(manifest) = read_manifest(&path)? else {
+                continue;
+            };
+            if manifest_package(&manifest)
+                .get("version")
+                .and_then(Value::as_str)
+                == Some(version)
+            {
+                dependency.declare(declared_license(&manifest), &path);
+                break;
+            }
+        }
+        dependencies.push(dependency);
+    }
+    Ok(dependencies)
+}
+
+/// The packages of the package-lock.json of the project. Their license is read from the lockfile,
+/// which records it since npm 7, else from their package.json in `node_modules/`.
+fn npm_dependencies(root: &Path) -> Result<Vec<Dependency>, LichenError> {
+    let lock_path = root.join("package-lock.json");
+    let Some(lock) = read_manifest(&lock_path)? else {
+        return Ok(Vec::new());
+    };
+
+    // Lockfiles list packages by install path since their version 2, by name before
+    let mut installed: Vec<(String, &Value)> = Vec::new();
+    if let Some(packages) = lock.get("packages").and_then(Value::as_object) {
+        installed.extend(
+            packages
+                .iter()
+                .filter(|(path, _)| !path.is_empty())
+                .map(|(path, entry)| (path.clone(), entry)),
+        );
+    } else if let Some(dependencies) = lock.get("dependencies").and_then(Value::as_object) {
+        collect_npm_v1_packages(dependencies, "", &mut installed);
+    }
+
+    let mut seen = HashSet::new();
+    let mut dependencies = Vec::new();
+    for (path, entry) in installed {
+        // Links point at the project's own workspaces
+        if entry.get("link").and_then(Value::as_bool) == Some(true) {
+            continue;
+        }
+        let name = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_else(|| {
+                path.rsplit_once("node_modules/")
+                    .map_or(path.as_str(), |(_, name)| name)
+            });
+        let version = entry.get("version").and_then(Value::as_str).unwrap_or("");
+        if !seen.insert((name.to_string(), version.to_string())) {
+            continue;
+        }
+
+        let mut dependency = Dependency::new(Ecosystem::Npm, name, version);
+        if let Some(license) = declared_license(entry) {
+            dependency.declare(Some(license), &lock_path);
+        } else {
+            let manifest_path = root.join(&path).join("package.json");
+            if let Some(manifest) = read_manifest(&manifest_path)? {
+                dependency.declare(declared_license(&manifest), &manifest_path);
+            }
+        }
+        dependencies.push(dependency);
+    }
+    Ok(dependencies)
+}
+
+/// Flattens the nested dependencies of a version 1 package-lock.json into install paths.
+fn collect_npm_v1_packages<'a>(
+    dependencies: &'a serde_json::Map<String, Value>,
+    parent: &str,
+    installed: &mut Vec<(String, &'a Value)>,
+) {
+    for (name, entry) in dependencies {
+        let path = format!("{}node_modules/{}", parent, name);
+        if let Some(nested) = entry.get("dependencies").and_then(Value::as_object) {
+            collect_npm_v1_packages(nested, &format!("{}/", path), installed);
+        }
+        installed.push((path, entry));
+    }
+}
+
+/// The packages installed in the Python virtual environments of the project (`.venv`, `venv` or
+/// `env`) and the active one, read from their `.dist-info` metadata.
+fn python_dependencies(
+    root: &Path,
+    virtual_env: Option<&Path>,
+) -> Result<Vec<Dependency>, LichenError> {
+    let mut environments: Vec<PathBuf> = virtual_env.into_iter().map(Path::to_path_buf).collect();
+    environments.extend([".venv", "venv", "env"].map(|name| root.join(name)));
+
+    let mut seen = HashSet::new();
+    let mut dependencies = Vec::new();
+    for environment in environments {
+        for site_packages in site_packages_dirs(&environment) {
+            let Ok(entries) = fs::read_dir(&site_packages) else {
+                continue;
+            };
+            let mut distributions: Vec<PathBuf> = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "dist-info"))
+                .collect();
+            distributions.sort();
+
+            for distribution in distributions {
+                let metadata_path = distribution.join("METADATA");
+                let content = match fs::read_to_string(&metadata_path) {
+                    Ok(content) => content,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                    Err(e) => return Err(LichenError::from(e).for_file("reading", &metadata_path)),
+                };
+                let Some(dependency) = python_dependency(&content, &metadata_path) else {
+                    continue;
+                };
+                if seen.insert((dependency.name.to_lowercase(), dependency.version.clone())) {
+                    dependencies.push(dependency);
+                }
+            }
+        }
+    }
+    Ok(dependencies)
+}
+
+/// The `site-packages` directories of a virtual environment, on Unix and on Windows.
+fn site_packages_dirs(environment: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(environment.join("lib"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.file_name().to_string_lossy().starts_with("python"))
+                .map(|entry| entry.path().join("site-packages"))
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs.push(environment.join("Lib").join("site-packages"));
+    dirs.retain(|dir| dir.is_dir());
+    dirs
+}
+
+/// Reads a Python package from its core metadata. The license is its `License-Expression`, else
+/// its `License` unless that holds the whole text, else its license classifiers naming a license,
+/// any of which applies.
+fn python_dependency(metadata: &str, path: &Path) -> Option<Dependency> {
+    let fields = metadata_fields(metadata);
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+            .filter(|value| !value.is_empty() && *value != "UNKNOWN")
+    };
+    let classifiers: Vec<&str> = fields
+        .iter()
+        .filter(|(key, _)| key.eq_ignore_ascii_case("Classifier"))
+        .filter_map(|(_, value)| value.strip_prefix("License :: "))
+        .filter_map(|value| value.rsplit(" :: ").next())
+        .filter_map(|name| {
+            let found = CLASSIFIER_LICENSES
+                .iter()
+                .find(|(classifier, _)| *classifier == name);
+            if found.is_none() {
+                debug!("The classifier '{}' doesn't tell which license it is", name);
+            }
+            found.map(|(_, id)| *id)
+        })
+        .collect();
+    let classified = (!classifiers.is_empty()).then(|| classifiers.join(" OR "));
+
+    let mut dependency = Dependency::new(Ecosystem::Python, field("Name")?, field("Version")?);
+    let declared = field("License-Expression")
+        .or_else(|| {
+            field("License").filter(|license| {
+                !license.contains('\n') && license.len() <= MAX_LICENSE_NAME_LENGTH
+            })
+        })
+        .or(classified.as_deref());
+    dependency.declare(declared, path);
+    Some(dependency)
+}
+
+/// The header fields of a core metadata file, continuation lines joined to their field.
+/// The description follows the first blank line, and is left out.
+fn metadata_fields(content: &str) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = fields.last_mut() {
+                value.push('\n');
+                value.push_str(line.trim());
+            }
+        } else if let Some((key, value)) = line.split_once(':') {
+            fields.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn licenses(dependencies: &[Dependency]) -> Vec<(String, String)> {
+        dependencies
+            .iter()
+            .map(|dep| {
+                let license = dep.license.as_ref().map(ToString::to_string);
+                (dep.name.clone(), license.unwrap_or_default())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cargo_dependencies_read_vendored_and_registry_manifests() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("Cargo.lock"),
+            r#"
+[[package]]
+name = "app"
+version = "0.1.0"
+
+[[package]]
+name = "serde"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "zlib-sys"
+version = "0.2.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "mystery"
+version = "0.1.0"
+source = "git+https://example.com/mystery"
+"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("vendor/zlib-sys")).unwrap();
+        fs::write(
+            root.join("vendor/zlib-sys/Cargo.toml"),
+            "[package]\nname = \"zlib-sys\"\nversion = \"0.2.0\"\nlicense = \"Zlib\"\n",
+        )
+        .unwrap();
+        let home = root.join("cargo-home");
+        let sources = home.join("registry/src/index.crates.io-0000/serde-1.0.0");
+        fs::create_dir_all(&sources).unwrap();
+        fs::write(
+            sources.join("Cargo.toml"),
+            "[package]\nname = \"serde\"\nversion = \"1.0.0\"\nlicense = \"MIT/Apache-2.0\"\n",
+        )
+        .unwrap();
+
+        // From a member of the workspace too
+        fs::create_dir_all(root.join("member")).unwrap();
+        let dependencies = cargo_dependencies(&root.join("member"), Some(&home)).unwrap();
+        assert_eq!(
+            licenses(&dependencies),
+            vec![
+                ("serde".to_string(), "MIT OR Apache-2.0".to_string()),
+                ("zlib-sys".to_string(), "Zlib".to_string()),
+                ("mystery".to_string(), String::new()),
+            ]
+        );
+        assert_eq!(dependencies[0].declared.as_deref(), Some("MIT/Apache-2.0"));
+    }
+
+    #[test]
+    fn npm_dependencies_read_the_lockfile_then_node_modules() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("package-lock.json"),
+            r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "app", "version": "1.0.0" },
+    "node_modules/left-pad": { "version": "1.3.0", "license": "WTFPL" },
+    "node_modules/@scope/util": { "version": "2.0.0" },
+    "node_modules/ui": { "resolved": "web/ui", "link": true }
+  }
+}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("node_modules/@scope/util")).unwrap();
+        fs::write(
+            root.join("node_modules/@scope/util/package.json"),
+            r#"{ "name": "@scope/util", "license": { "type": "ISC" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            licenses(&npm_dependencies(root).unwrap()),
+            vec![
+                ("@scope/util".to_string(), "ISC".to_string()),
+                ("left-pad".to_string(), "WTFPL".to_string()),
+            ]
+        );
+
+        // Version 1 nests the dependencies of dependencies
+        fs::write(
+            root.join("package-lock.json"),
+            r#"{ "lockfileVersion": 1, "dependencies": {
+  "a": { "version": "1.0.0", "dependencies": { "b": { "version": "2.0.0" } } }
+} }"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("node_modules/a/node_modules/b")).unwrap();
+        fs::write(
+            root.join("node_modules/a/node_modules/b/package.json"),
+            r#"{ "name": "b", "license": "MIT" }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            licenses(&npm_dependencies(root).unwrap()),
+            vec![
+                ("b".to_string(), "MIT".to_string()),
+                ("a".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn python_dependencies_read_dist_info_metadata() {
+        let dir = tempdir().unwrap();
+        let site_packages = dir.path().join(".venv/lib/python3.12/site-packages");
+        let write = |name: &str, metadata: &str| {
+            let dist_info = site_packages.join(format!("{}.dist-info", name));
+            fs::create_dir_all(&dist_info).unwrap();
+            fs::write(dist_info.join("METADATA"), metadata).unwrap();
+        };
+        write(
+            "attrs-23.1.0",
+            "Metadata-Version: 2.4\nName: attrs\nVersion: 23.1.0\nLicense-Expression: MIT\n\nThe description\n",
+        );
+        write(
+            "requests-2.31.0",
+            "Metadata-Version: 2.1\nName: requests\nVersion: 2.31.0\nLicense: Apache 2.0\n",
+        );
+        write(
+            "numpy-1.26.0",
+            "Metadata-Version: 2.1\nName: numpy\nVersion: 1.26.0\nLicense: Copyright (c) 2005 NumPy Developers.\n        All rights reserved.\nClassifier: License :: OSI Approved :: BSD License\n",
+        );
+        write(
+            "certifi-2023.7.22",
+            "Metadata-Version: 2.1\nName: certifi\nVersion: 2023.7.22\nClassifier: License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)\n",
+        );
+
+        assert_eq!(
+            licenses(&python_dependencies(dir.path(), None).unwrap()),
+            vec![
+                ("attrs".to_string(), "MIT".to_string()),
+                ("certifi".to_string(), "MPL-2.0".to_string()),
+                // "BSD License" doesn't tell which BSD license it is
+                ("numpy".to_string(), String::new()),
+                ("requests".to_string(), "Apache-2.0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn classifier_licenses_name_known_licenses() {
+        for (classifier, id) in CLASSIFIER_LICENSES {
+            let license = normalize_license(id).unwrap();
+            assert_eq!(license.to_string(), *id, "for {}", classifier);
+        }
+        assert!(normalize_license("UNKNOWN").is_none());
+        assert!(normalize_license("MIT OR (").is_none());
+    }
+}
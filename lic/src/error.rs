@@ -2,7 +2,11 @@
 //!
 //! Defines a single “MEGA” error type for the entirety of Lichen.
 
-use std::{error::Error, fmt, io};
+use std::{
+    error::Error,
+    fmt, io,
+    path::{Path, PathBuf},
+};
 
 /// All errors that can occur in the Lichen application, including
 /// general, file-processing, and template-rendering errors.
@@ -32,8 +36,90 @@ pub enum LichenError {
     /// An error occurred during template rendering.
     RenderError(handlebars::RenderError),
 
+    /// The configuration file couldn't be parsed.
+    InvalidConfig(String),
+
+    /// The arguments given can't be used together.
+    Usage(String),
+
+    /// Some files lack their license header, as reported by `lic check`.
+    NonCompliant(String),
+
+    /// There was nothing to process, which callers may expect.
+    NothingToDo(String),
+
     /// Generic error message.
     Msg(String),
+
+    /// An operation on a file failed, ex: writing it.
+    File {
+        operation: &'static str,
+        path: PathBuf,
+        source: Box<LichenError>,
+    },
+
+    /// Several files failed, each with its own error.
+    Multiple(Vec<LichenError>),
+}
+
+impl LichenError {
+    /// The process exit code for this error, stable across releases:
+    ///
+    /// | Code | Meaning                                      |
+    /// |------|----------------------------------------------|
+    /// | 0    | Success                                      |
+    /// | 1    | Any other failure                            |
+    /// | 2    | Invalid arguments                            |
+    /// | 3    | Files are non-compliant (`lic check`)        |
+    /// | 4    | Invalid configuration or license             |
+    /// | 5    | I/O failure                                  |
+    /// | 6    | Nothing to do                                |
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            LichenError::File { source, .. } => source.exit_code(),
+            // The failures share a class, or are just failures
+            LichenError::Multiple(errors) => {
+                let mut codes = errors.iter().map(LichenError::exit_code);
+                let first = codes.next().unwrap_or(1);
+                if codes.all(|code| code == first) {
+                    first
+                } else {
+                    1
+                }
+            }
+            LichenError::Usage(_) => 2,
+            LichenError::NonCompliant(_) => 3,
+            LichenError::InvalidConfig(_)
+            | LichenError::MissingLicense
+            | LichenError::RegexError(..) => 4,
+            LichenError::IoError(_)
+            | LichenError::WalkdirError(_)
+            | LichenError::InvalidPath(_) => 5,
+            LichenError::NothingToDo(_) => 6,
+            LichenError::InvalidIndex(_)
+            | LichenError::JsonError(_)
+            | LichenError::RenderError(_)
+            | LichenError::Msg(_) => 1,
+        }
+    }
+
+    /// Attaches the file and the operation that failed on it, ex: `"writing"`.
+    pub fn for_file(self, operation: &'static str, path: &Path) -> Self {
+        LichenError::File {
+            operation,
+            path: path.to_path_buf(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Combines the errors of several files, keeping a lone error as is.
+    pub fn combine(mut errors: Vec<LichenError>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(LichenError::Multiple(errors)),
+        }
+    }
 }
 
 impl fmt::Display for LichenError {
@@ -42,7 +128,7 @@ impl fmt::Display for LichenError {
             LichenError::InvalidIndex(idx) => write!(f, "Invalid index: {}", idx),
             LichenError::MissingLicense => write!(
                 f,
-                "Missing license: A license must be set either via `lichen gen <LICENSE_ID>` or in config (.lichen.toml)"
+                "Missing license: A license must be set either via `lic gen <LICENSE_ID>` or in config (.lichen.toml)"
             ),
             LichenError::RegexError(pattern, err) => {
                 write!(f, "Regex Error: for this pattern {} {}", pattern, err)
@@ -52,7 +138,23 @@ impl fmt::Display for LichenError {
             LichenError::WalkdirError(err) => write!(f, "Directory walk error: {}", err),
             LichenError::JsonError(err) => write!(f, "JSON error: {}", err),
             LichenError::RenderError(err) => write!(f, "Template rendering error: {}", err),
-            LichenError::Msg(msg) => write!(f, "{}", msg),
+            LichenError::InvalidConfig(msg) => write!(f, "Invalid configuration: {}", msg),
+            LichenError::Usage(msg)
+            | LichenError::NonCompliant(msg)
+            | LichenError::NothingToDo(msg)
+            | LichenError::Msg(msg) => write!(f, "{}", msg),
+            LichenError::File {
+                operation,
+                path,
+                source,
+            } => write!(f, "Failed {} '{}': {}", operation, path.display(), source),
+            LichenError::Multiple(errors) => {
+                write!(f, "{} files failed:", errors.len())?;
+                for err in errors {
+                    write!(f, "\n  - {}", err)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -64,6 +166,7 @@ impl Error for LichenError {
             LichenError::WalkdirError(err) => Some(err),
             LichenError::JsonError(err) => Some(err),
             LichenError::RenderError(err) => Some(err),
+            LichenError::File { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
@@ -0,0 +1,924 @@
+//! # Header Detection
+//!
+//! Recognizes license headers at the start of files. Lichen's own invisible `HEADER_MARKER`
+//! is the fast path, otherwise the leading comment block is compared against license templates,
+//! so headers survive formatters, editors and copy-paste that strip the marker.
+
+use crate::models::{CommentToken, License, LicenseException, SpdxExpression};
+use crate::utils::HEADER_MARKER;
+use clap::ValueEnum;
+use log::{debug, trace};
+use regex::Regex;
+use serde::Serialize;
+use std::ops::Range;
+use std::sync::{LazyLock, OnceLock};
+
+/// Matches the telltale signs of a license header: copyright notices, license grants and SPDX tags.
+static FOREIGN_HEADER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\bcopyright\b|©|\(c\)\s*\d{4}|\bspdx-license-identifier\b|\blicensed under\b|\ball rights reserved\b",
+    )
+    .expect("Foreign header pattern is valid")
+});
+
+/// Matches a copyright notice line, ex: `// Copyright (c) 2019 Jane Doe` or `# SPDX-FileCopyrightText: 2020 ACME`.
+/// A year is required, to leave out mentions such as "the above copyright notice".
+static COPYRIGHT_NOTICE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^[^\w]*(?:copyright\b|©|spdx-filecopyrighttext:).*?\b(?:19|20)\d{2}\b")
+        .expect("Copyright notice pattern is valid")
+});
+
+/// Matches the copyright line Lichen renders, ex: `Copyright (c) 2024 Jane Doe; All rights reserved.`,
+/// capturing the authors it names. Whitespace must be normalized, as the line may be wrapped.
+static OWN_COPYRIGHT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bcopyright \(c\) (?:19|20)\d{2}\b([^;]*); all rights reserved\.")
+        .expect("Own copyright pattern is valid")
+});
+
+/// How many lines from the start of a file are searched for copyright notices.
+const NOTICE_SEARCH_LINES: usize = 50;
+
+/// Openings of documentation comments, which describe the code rather than license it.
+const DOC_COMMENT_PREFIXES: &[&str] = &["///", "//!", "/*!"];
+
+/// Matches any handlebars tag, ex: `{{#if copyright}}`, `{{else}}` or `{{/if}}`.
+static TEMPLATE_TAG_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{[^{}]*\}\}").expect("Template tag pattern is valid"));
+
+/// Matches the years of copyright notices.
+static YEAR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:19|20)\d{2}\b").expect("Year pattern is valid"));
+
+/// Matches the expression of an SPDX license tag, ex: `// SPDX-License-Identifier: GPL-2.0+`.
+static SPDX_TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?im)\bSPDX-License-Identifier:[ \t]*([A-Za-z0-9.+:() \t-]+)")
+        .expect("SPDX tag pattern is valid")
+});
+
+/// Matches the identifiers of an SPDX expression, leaving out its operators and parentheses.
+static SPDX_ID_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[A-Za-z0-9.+:-]+").expect("SPDX id pattern is valid"));
+
+/// Matches notices of code that isn't licensed to others at all.
+static PROPRIETARY_NOTICE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:proprietary|confidential)\b")
+        .expect("Proprietary notice pattern is valid")
+});
+
+/// What is wrong with a file's header, one rule per kind in `lic check` reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum HeaderProblem {
+    #[serde(rename = "missing-header")]
+    Missing,
+    #[serde(rename = "wrong-license")]
+    WrongLicense,
+    #[serde(rename = "outdated-year")]
+    OutdatedYear,
+    #[serde(rename = "malformed-header")]
+    Malformed,
+    #[serde(rename = "deprecated-license")]
+    DeprecatedLicense,
+}
+
+impl HeaderProblem {
+    pub const ALL: [HeaderProblem; 5] = [
+        HeaderProblem::Missing,
+        HeaderProblem::WrongLicense,
+        HeaderProblem::OutdatedYear,
+        HeaderProblem::Malformed,
+        HeaderProblem::DeprecatedLicense,
+    ];
+
+    /// Stable identifier of the problem, as a rule id.
+    pub fn id(self) -> &'static str {
+        match self {
+            HeaderProblem::Missing => "missing-header",
+            HeaderProblem::WrongLicense => "wrong-license",
+            HeaderProblem::OutdatedYear => "outdated-year",
+            HeaderProblem::Malformed => "malformed-header",
+            HeaderProblem::DeprecatedLicense => "deprecated-license",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            HeaderProblem::Missing => "The file has no license header.",
+            HeaderProblem::WrongLicense => "The license header is for another license.",
+            HeaderProblem::OutdatedYear => "The license header has an outdated copyright year.",
+            HeaderProblem::Malformed => {
+                "The license header differs from the one rendered from the license template."
+            }
+            HeaderProblem::DeprecatedLicense => {
+                "The license header uses a deprecated SPDX license identifier."
+            }
+        }
+    }
+}
+
+/// A header problem, and the lines it spans (1-based, inclusive).
+/// A missing header points at the line it would be inserted at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderFinding {
+    pub problem: HeaderProblem,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+impl HeaderFinding {
+    /// Locates the problem found in the `range` of the content, see `diagnose_header`.
+    pub fn new(problem: HeaderProblem, content: &str, range: Range<usize>) -> Self {
+        // Blank lines swallowed with the header aren't part of it
+        let text = &content[range.clone()];
+        let start = range.start + (text.len() - text.trim_start().len());
+        let end = (range.start + text.trim_end().len()).max(start);
+        HeaderFinding {
+            problem,
+            start_line: content[..start].matches('\n').count() + 1,
+            end_line: content[..end].matches('\n').count() + 1,
+        }
+    }
+}
+
+/// Tells what's wrong with an existing header, compared to the header expected in its place.
+/// The existing header may have been rendered from any license of the catalog.
+///
+/// # Arguments
+///
+/// * `existing`: The header found in the file, with its comment syntax.
+/// * `expected`: The header that would be written, with its comment syntax.
+/// * `comment_tokens`: The comment tokens of the file's language.
+pub fn diagnose_header(
+    existing: &str,
+    expected: &str,
+    comment_tokens: &[CommentToken],
+) -> HeaderProblem {
+    let existing = strip_comment_syntax(existing, comment_tokens);
+    let expected = strip_comment_syntax(expected, comment_tokens);
+
+    let matcher = HeaderMatcher::catalog();
+    if let (Some(found), Some(wanted)) = (
+        matcher.matching_license(&existing),
+        matcher.matching_license(&expected),
+    ) && found != wanted
+    {
+        return HeaderProblem::WrongLicense;
+    }
+
+    let without_years = |text: &str| normalize_whitespace(&YEAR_REGEX.replace_all(text, ""));
+    if without_years(&existing) == without_years(&expected) {
+        HeaderProblem::OutdatedYear
+    } else {
+        HeaderProblem::Malformed
+    }
+}
+
+/// Replaces the deprecated identifiers of the SPDX license tags at the start of the content
+/// with their recommended replacement, ex: `GPL-2.0+` with `GPL-2.0-or-later`.
+/// Tags are searched for in the `header_lines` first lines and the few following them.
+/// Returns the updated content and the range of the first tag replaced, if any was.
+pub fn migrate_deprecated_tags(
+    content: &str,
+    header_lines: usize,
+) -> Option<(String, Range<usize>)> {
+    let search_end = content
+        .match_indices('\n')
+        .nth(header_lines + NOTICE_SEARCH_LINES - 1)
+        .map_or(content.len(), |(pos, _)| pos);
+
+    let mut migrated = String::with_capacity(content.len());
+    let mut first_tag = None;
+    let mut last = 0;
+    for captures in SPDX_TAG_REGEX.captures_iter(&content[..search_end]) {
+        let expression = captures.get(1).expect("The tag pattern has a group");
+        let replaced = SPDX_ID_REGEX.replace_all(expression.as_str(), |id: &regex::Captures| {
+            let id = &id[0];
+            // Tags hold SPDX ids, only their case may vary
+            let replacement = match License::from_str(id, true) {
+                Ok(license) if license.is_deprecated() => license.replacement(),
+                _ => match LicenseException::from_str(id, true) {
+                    Ok(exception) if exception.is_deprecated() => exception.replacement(),
+                    _ => None,
+                },
+            };
+            replacement.unwrap_or(id).to_string()
+        });
+        if replaced == expression.as_str() {
+            continue;
+        }
+
+        debug!(
+            "Migrating SPDX tag '{}' to '{}'",
+            expression.as_str(),
+            replaced
+        );
+        let tag = captures.get(0).expect("Group 0 is the whole match");
+        first_tag.get_or_insert(tag.range());
+        migrated.push_str(&content[last..expression.start()]);
+        migrated.push_str(&replaced);
+        last = expression.end();
+    }
+
+    let first_tag = first_tag?;
+    migrated.push_str(&content[last..]);
+    Some((migrated, first_tag))
+}
+
+/// Recognizes the rendered text of license templates, whatever the values substituted into them,
+/// the line wrapping or the whitespace.
+#[derive(Debug, Default)]
+pub struct HeaderMatcher {
+    candidates: Vec<TemplateCandidate>,
+}
+
+/// A single license template that a header may have been rendered from.
+#[derive(Debug)]
+struct TemplateCandidate {
+    license: License,
+    /// The longest literal run of the template, cheap to look for before compiling the full pattern.
+    anchor: String,
+    /// Source of the regex matching the rendered template, compiled on first use.
+    source: String,
+    pattern: OnceLock<Option<Regex>>,
+}
+
+impl HeaderMatcher {
+    /// Creates a matcher recognizing headers rendered from any of the given licenses.
+    pub fn new(licenses: impl IntoIterator<Item = License>) -> Self {
+        let candidates = licenses
+            .into_iter()
+            .map(|license| {
+                let (source, anchor) = template_to_pattern(license.template_content());
+                TemplateCandidate {
+                    license,
+                    anchor,
+                    source,
+                    pattern: OnceLock::new(),
+                }
+            })
+            .collect();
+
+        HeaderMatcher { candidates }
+    }
+
+    /// A matcher recognizing headers rendered from any license of the catalog, such as headers
+    /// applied for another license than the expected one. Its patterns are compiled on first use.
+    pub fn catalog() -> &'static HeaderMatcher {
+        static CATALOG: LazyLock<HeaderMatcher> =
+            LazyLock::new(|| HeaderMatcher::new(License::value_variants().iter().copied()));
+        &CATALOG
+    }
+
+    /// Finds the license whose template the text was rendered from, if any.
+    /// The text should be stripped of comment syntax.
+    pub fn matching_license(&self, text: &str) -> Option<License> {
+        let normalized = normalize_whitespace(text);
+
+        self.candidates
+            .iter()
+            // Only compile the patterns of templates that could plausibly match
+            .filter(|candidate| normalized.contains(&candidate.anchor))
+            .find(|candidate| {
+                candidate
+                    .pattern
+                    .get_or_init(|| match Regex::new(&candidate.source) {
+                        Ok(re) => Some(re),
+                        Err(e) => {
+                            debug!(
+                                "Template of {} can't be used for detection: {}",
+                                candidate.license, e
+                            );
+                            None
+                        }
+                    })
+                    .as_ref()
+                    .is_some_and(|re| re.is_match(&normalized))
+            })
+            .map(|candidate| candidate.license)
+    }
+}
+
+/// Collapses every run of whitespace into a single space, and trims the ends.
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Converts a handlebars license template into the source of a regex matching its rendered text.
+/// Every handlebars construct becomes a wildcard, as their values are unknown.
+///
+/// # Returns
+///
+/// The regex source, and the longest literal run of words (the anchor) in the template.
+fn template_to_pattern(template: &str) -> (String, String) {
+    // The copyright line may have been prepended, see `render_license`
+    let mut source = String::from(r"(?s)^(?i:copyright\b.*?)?");
+    let mut anchor = String::new();
+    let mut depth = 0usize;
+    let mut last_end = 0;
+
+    for tag in TEMPLATE_TAG_REGEX.find_iter(template) {
+        if depth == 0 {
+            push_literal(&mut source, &mut anchor, &template[last_end..tag.start()]);
+        }
+
+        let tag_str = tag.as_str();
+        if tag_str.starts_with("{{#") {
+            // A whole `{{#if}}...{{/if}}` section stands for a single unknown value
+            if depth == 0 {
+                source.push_str(".*?");
+            }
+            depth += 1;
+        } else if tag_str.starts_with("{{/") {
+            depth = depth.saturating_sub(1);
+        } else if depth == 0 && tag_str != "{{else}}" {
+            source.push_str(".*?");
+        }
+
+        last_end = tag.end();
+    }
+    if depth == 0 {
+        push_literal(&mut source, &mut anchor, &template[last_end..]);
+    }
+
+    source.push('$');
+    (source, anchor)
+}
+
+/// Appends a literal run of template text to the regex source, tolerant to whitespace changes.
+fn push_literal(source: &mut String, anchor: &mut String, literal: &str) {
+    let words: Vec<&str> = literal.split_whitespace().collect();
+
+    source.push_str(r"\s*");
+    if words.is_empty() {
+        return;
+    }
+    source.push_str(
+        &words
+            .iter()
+            .map(|word| regex::escape(word))
+            .collect::<Vec<_>>()
+            .join(r"\s+"),
+    );
+    source.push_str(r"\s*");
+
+    // The longest run is the most selective
+    let joined = words.join(" ");
+    if joined.len() > anchor.len() {
+        *anchor = joined;
+    }
+}
+
+/// The comment block found at the start of a file.
+#[derive(Debug)]
+struct CommentBlock {
+    /// Where the block starts, ahead of any blank lines preceding it.
+    start: usize,
+    /// Byte ranges of the block's lines. A block comment is a single range.
+    lines: Vec<Range<usize>>,
+}
+
+/// Returns the end of the line containing `pos`, past its newline.
+fn line_end(content: &str, pos: usize) -> usize {
+    content[pos..]
+        .find('\n')
+        .map_or(content.len(), |offset| pos + offset + 1)
+}
+
+/// Finds the comment block at `start`, skipping blank lines, using any of the comment tokens.
+fn leading_comment_block(
+    content: &str,
+    start: usize,
+    comment_tokens: &[CommentToken],
+) -> Option<CommentBlock> {
+    let body = &content[start..];
+    let block_start = start + (body.len() - body.trim_start().len());
+    let rest = &content[block_start..];
+
+    for token in comment_tokens {
+        match token {
+            CommentToken::Block {
+                start: open,
+                end: close,
+            } if rest.starts_with(open.as_str()) => {
+                // The block runs until its closing token, through the end of that line
+                let close_pos = rest[open.len()..].find(close.as_str())? + open.len();
+                let end = line_end(content, block_start + close_pos + close.len());
+                return Some(CommentBlock {
+                    start,
+                    lines: std::iter::once(block_start..end).collect(),
+                });
+            }
+            CommentToken::Line(line_token) if rest.starts_with(line_token.as_str()) => {
+                // Consecutive lines opening with the token make up the block, until a blank line
+                let mut lines = Vec::new();
+                let mut pos = block_start;
+                while pos < content.len()
+                    && content[pos..]
+                        .trim_start_matches([' ', '\t'])
+                        .starts_with(line_token.as_str())
+                {
+                    let end = line_end(content, pos);
+                    lines.push(pos..end);
+                    pos = end;
+                }
+                return Some(CommentBlock { start, lines });
+            }
+            _ => continue,
+        }
+    }
+
+    None
+}
+
+/// Strips comment tokens and Lichen's marker from a comment, leaving its text.
+fn strip_comment_syntax(comment: &str, comment_tokens: &[CommentToken]) -> String {
+    let comment = comment.replace(HEADER_MARKER, "");
+    let trimmed = comment.trim();
+
+    for token in comment_tokens {
+        match token {
+            CommentToken::Block { start, end } => {
+                if let Some(inner) = trimmed
+                    .strip_prefix(start.as_str())
+                    .and_then(|s| s.strip_suffix(end.as_str()))
+                {
+                    return inner.to_string();
+                }
+            }
+            CommentToken::Line(line_token) => {
+                if trimmed.starts_with(line_token.as_str()) {
+                    return trimmed
+                        .lines()
+                        .map(|line| {
+                            let line = line.trim_start();
+                            line.strip_prefix(line_token.as_str()).unwrap_or(line)
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                }
+            }
+        }
+    }
+
+    comment
+}
+
+/// Locates Lichen's license header at the start of the content, after the preamble.
+///
+/// Lichen's `HEADER_MARKER` is looked for first, the header spanning the lines from the first
+/// marker to the last. Failing that, the leading comment block is compared to the templates known
+/// by the matcher, so headers that lost their marker are still found. Such a header is only taken
+/// for Lichen's when it carries the copyright line Lichen renders, naming one of the owners, or no
+/// one when there are none. Headers rendered from the same templates by others are left to
+/// `find_foreign_header`.
+///
+/// # Arguments
+///
+/// * `content`: The normalized file content.
+/// * `preamble_len`: Length of the preamble that precedes any header, see `preamble_length`.
+/// * `comment_tokens`: The comment tokens of the file's language.
+/// * `matcher`: The templates a marker-less header may have been rendered from.
+/// * `owners`: Names of the project's authors.
+///
+/// # Returns
+///
+/// The byte range of the header, including preceding blank lines and its final newline.
+pub fn find_header(
+    content: &str,
+    preamble_len: usize,
+    comment_tokens: &[CommentToken],
+    matcher: &HeaderMatcher,
+    owners: &[String],
+) -> Option<Range<usize>> {
+    if let Some(header) = find_marked_header(content, preamble_len) {
+        return Some(header);
+    }
+
+    let (header, license) = find_template_header(content, preamble_len, comment_tokens, matcher)?;
+    let text = strip_comment_syntax(&content[header.clone()], comment_tokens);
+    if !has_own_copyright(&text, owners) {
+        debug!(
+            "The {} header doesn't carry Lichen's copyright line for {:?}, it isn't ours",
+            license, owners
+        );
+        return None;
+    }
+    Some(header)
+}
+
+/// Locates the header between Lichen's markers, after the preamble.
+fn find_marked_header(content: &str, preamble_len: usize) -> Option<Range<usize>> {
+    let body = &content[preamble_len..];
+    let (first, last) = (body.find(HEADER_MARKER)?, body.rfind(HEADER_MARKER)?);
+    let first_line_start = body[..first].rfind('\n').map_or(0, |pos| pos + 1);
+    // Swallow blank lines between the preamble and the header
+    let start = if body[..first_line_start].trim().is_empty() {
+        preamble_len
+    } else {
+        preamble_len + first_line_start
+    };
+    trace!("Found header marker, header starts at byte {}", start);
+    Some(start..line_end(content, preamble_len + last))
+}
+
+/// Locates a header rendered from one of the matcher's templates in the leading comment block,
+/// whoever wrote it, along with its license.
+fn find_template_header(
+    content: &str,
+    preamble_len: usize,
+    comment_tokens: &[CommentToken],
+    matcher: &HeaderMatcher,
+) -> Option<(Range<usize>, License)> {
+    let block = leading_comment_block(content, preamble_len, comment_tokens)?;
+
+    // Other comments may directly follow a line comment header, so try shorter runs of lines too
+    for line_count in (1..=block.lines.len()).rev() {
+        let end = block.lines[line_count - 1].end;
+        let text = strip_comment_syntax(&content[block.lines[0].start..end], comment_tokens);
+        if let Some(license) = matcher.matching_license(&text) {
+            debug!("Found {} header by its content", license);
+            return Some((block.start..end, license));
+        }
+    }
+
+    None
+}
+
+/// Whether the text carries the copyright line Lichen renders, naming one of the owners, or no
+/// one when there are none.
+fn has_own_copyright(text: &str, owners: &[String]) -> bool {
+    let text = normalize_whitespace(text).to_lowercase();
+    OWN_COPYRIGHT_REGEX.captures_iter(&text).any(|captures| {
+        let named = captures[1].trim();
+        if owners.is_empty() {
+            named.is_empty()
+        } else {
+            owners
+                .iter()
+                .any(|owner| named.contains(&owner.to_lowercase()))
+        }
+    })
+}
+
+/// Locates a license header written by another tool or by hand (`addlicense`, `licensure`, etc.),
+/// so that it can be replaced by a Lichen header.
+///
+/// The comment block right after the preamble is taken as a header when it holds a copyright
+/// notice, a license grant or an SPDX tag, or when its text matches one of the matcher's templates.
+/// Documentation comments are never taken as headers.
+///
+/// # Returns
+///
+/// The byte range of the whole comment block, including preceding blank lines and its final newline.
+pub fn find_foreign_header(
+    content: &str,
+    preamble_len: usize,
+    comment_tokens: &[CommentToken],
+    matcher: &HeaderMatcher,
+) -> Option<Range<usize>> {
+    let block = leading_comment_block(content, preamble_len, comment_tokens)?;
+    let end = block.lines.last()?.end;
+    let comment = &content[block.lines[0].start..end];
+
+    if DOC_COMMENT_PREFIXES
+        .iter()
+        .any(|prefix| comment.starts_with(prefix))
+    {
+        trace!("Leading comment is documentation, not a header");
+        return None;
+    }
+
+    let text = strip_comment_syntax(comment, comment_tokens);
+    if FOREIGN_HEADER_REGEX.is_match(&text) || matcher.matching_license(&text).is_some() {
+        debug!("Found a foreign license header");
+        Some(block.start..end)
+    } else {
+        None
+    }
+}
+
+/// Finds the license the content declares at its start: the expression of its SPDX tag, else the
+/// license whose template its header was rendered from. A header with a proprietary or
+/// confidential notice instead declares `LicenseRef-Proprietary`.
+///
+/// # Arguments
+///
+/// * `content`: The normalized file content.
+/// * `preamble_len`: Length of the preamble that precedes any header, see `preamble_length`.
+/// * `comment_tokens`: The comment tokens of the file's language.
+/// * `matcher`: The templates the header may have been rendered from.
+pub fn declared_license(
+    content: &str,
+    preamble_len: usize,
+    comment_tokens: &[CommentToken],
+    matcher: &HeaderMatcher,
+) -> Option<SpdxExpression> {
+    // |1| SPDX tags name the license plainly
+    let search_end = content
+        .match_indices('\n')
+        .nth(NOTICE_SEARCH_LINES - 1)
+        .map_or(content.len(), |(pos, _)| pos);
+    if let Some(captures) = SPDX_TAG_REGEX.captures(&content[..search_end])
+        && let Ok(expression) = SpdxExpression::parse_lenient(&captures[1])
+    {
+        return Some(expression);
+    }
+
+    // |2| The text of the header, Lichen's or another
+    let header = find_marked_header(content, preamble_len)
+        .or_else(|| {
+            find_template_header(content, preamble_len, comment_tokens, matcher)
+                .map(|(header, _)| header)
+        })
+        .or_else(|| find_foreign_header(content, preamble_len, comment_tokens, matcher))?;
+    let text = strip_comment_syntax(&content[header], comment_tokens);
+    if let Some(license) = matcher.matching_license(&text) {
+        return Some(license.into());
+    }
+
+    PROPRIETARY_NOTICE_REGEX
+        .is_match(&text)
+        .then(|| SpdxExpression::Other("LicenseRef-Proprietary".to_string()))
+}
+
+/// Lists the copyright notices near the start of the content that don't name any of the owners,
+/// meaning the file (or part of it) belongs to someone else.
+///
+/// # Arguments
+///
+/// * `content`: The file content, without Lichen's own header.
+/// * `owners`: Names of the project's authors. Without any, every notice is someone else's.
+///
+/// # Returns
+///
+/// The text of each third-party notice, comment syntax removed.
+pub fn third_party_notices(content: &str, owners: &[String]) -> Vec<String> {
+    let owners: Vec<String> = owners.iter().map(|owner| owner.to_lowercase()).collect();
+
+    content
+        .lines()
+        .take(NOTICE_SEARCH_LINES)
+        .filter(|line| COPYRIGHT_NOTICE_REGEX.is_match(line))
+        .map(|line| {
+            line.trim_start_matches(|c: char| !c.is_alphanumeric() && c != '©')
+                .trim_end_matches(|c: char| {
+                    c.is_whitespace() || c == '*' || c == '/' || c == HEADER_MARKER
+                })
+        })
+        .filter(|notice| {
+            let notice = notice.to_lowercase();
+            !owners.iter().any(|owner| notice.contains(owner))
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_tokens() -> Vec<CommentToken> {
+        vec![CommentToken::Line("//".to_string())]
+    }
+
+    #[test]
+    fn template_to_pattern_turns_sections_into_wildcards() {
+        let (source, anchor) = template_to_pattern(
+            "{{#if copyright}}{{copyright}}{{else}}Copyright{{/if}}\nSome   words here.",
+        );
+        let re = Regex::new(&source).unwrap();
+        assert!(re.is_match("Copyright (c) 2025 Someone Some words here."));
+        assert!(!re.is_match("Copyright (c) 2025 Someone Other words here."));
+        assert_eq!(anchor, "Some words here.");
+    }
+
+    #[test]
+    fn matching_license_ignores_wrapping_and_values() {
+        let matcher = HeaderMatcher::new([License::MIT]);
+        let rendered = crate::utils::render_license(
+            License::MIT.template_content(),
+            &jiff::civil::Date::new(2024, 1, 1).unwrap(),
+            &None,
+        )
+        .unwrap();
+        let wrapped = crate::utils::reflow_text(&rendered, 40);
+        assert_eq!(matcher.matching_license(&wrapped), Some(License::MIT));
+        assert_eq!(matcher.matching_license("Some other comment"), None);
+    }
+
+    #[test]
+    fn find_header_uses_marker_through_end_of_line() {
+        let content = format!("\n/*{m}\nText\n{m}*/\nfn main() {{}}\n", m = HEADER_MARKER);
+        let tokens = vec![CommentToken::Block {
+            start: "/*".to_string(),
+            end: "*/".to_string(),
+        }];
+        let range = find_header(&content, 0, &tokens, &HeaderMatcher::default(), &[]).unwrap();
+        assert_eq!(&content[range.end..], "fn main() {}\n");
+        assert_eq!(range.start, 0);
+    }
+
+    #[test]
+    fn find_header_matches_markerless_header_followed_by_comments() {
+        let matcher = HeaderMatcher::new([License::MIT]);
+        let rendered = crate::utils::render_license(
+            License::MIT.template_content(),
+            &jiff::civil::Date::new(2024, 1, 1).unwrap(),
+            &None,
+        )
+        .unwrap();
+        let header =
+            crate::utils::format_header_with_comments(&rendered, &line_tokens(), false, None, None)
+                .unwrap();
+        let content = format!("#!/bin/sh\n{}\n// Module docs\nfn main() {{}}\n", header);
+        let preamble_len = "#!/bin/sh\n".len();
+
+        let range = find_header(&content, preamble_len, &line_tokens(), &matcher, &[]).unwrap();
+        assert_eq!(range.start, preamble_len);
+        assert_eq!(&content[range.end..], "// Module docs\nfn main() {}\n");
+    }
+
+    #[test]
+    fn find_foreign_header_recognizes_copyright_blocks() {
+        let matcher = HeaderMatcher::default();
+        // addlicense style, ending at the blank line
+        let content = "// Copyright 2020 Google LLC\n// SPDX-License-Identifier: Apache-2.0\n\n// Package docs\npackage main\n";
+        let range = find_foreign_header(content, 0, &line_tokens(), &matcher).unwrap();
+        assert_eq!(&content[range.end..], "\n// Package docs\npackage main\n");
+
+        // licensure style block comment
+        let tokens = vec![CommentToken::Block {
+            start: "/*".to_string(),
+            end: "*/".to_string(),
+        }];
+        let content = "/*\n * (c) 2019 Someone\n * Licensed under the MIT license\n */\nint x;\n";
+        let range = find_foreign_header(content, 0, &tokens, &matcher).unwrap();
+        assert_eq!(&content[range.end..], "int x;\n");
+    }
+
+    #[test]
+    fn find_foreign_header_ignores_docs_and_plain_comments() {
+        let matcher = HeaderMatcher::default();
+        let docs = "//! Crate docs, see the copyright notice in LICENSE\nfn main() {}\n";
+        assert!(find_foreign_header(docs, 0, &line_tokens(), &matcher).is_none());
+
+        let plain = "// Entry point\nfn main() {}\n";
+        assert!(find_foreign_header(plain, 0, &line_tokens(), &matcher).is_none());
+    }
+
+    #[test]
+    fn third_party_notices_leaves_out_owners_and_mentions() {
+        let content = "/*\n * Copyright (c) 2019 Jane Doe\n * © 2021 ACME Corp.\n * The above copyright notice shall be included.\n */\n// SPDX-FileCopyrightText: 2020 Project Team\n";
+        let owners = vec!["project team".to_string()];
+        assert_eq!(
+            third_party_notices(content, &owners),
+            vec!["Copyright (c) 2019 Jane Doe", "© 2021 ACME Corp."]
+        );
+        assert_eq!(third_party_notices(content, &[]).len(), 3);
+    }
+
+    #[test]
+    fn migrate_deprecated_tags_replaces_deprecated_ids() {
+        let content = "// SPDX-License-Identifier: MIT OR GPL-2.0+\nfn main() {}\n";
+        let (migrated, range) = migrate_deprecated_tags(content, 0).unwrap();
+        assert_eq!(
+            migrated,
+            "// SPDX-License-Identifier: MIT OR GPL-2.0-or-later\nfn main() {}\n"
+        );
+        assert_eq!(range.start, 3);
+
+        let with = "# SPDX-License-Identifier: GPL-2.0-with-classpath-exception\n";
+        assert_eq!(
+            migrate_deprecated_tags(with, 0).unwrap().0,
+            "# SPDX-License-Identifier: GPL-2.0-only WITH Classpath-exception-2.0\n"
+        );
+
+        // Current ids and deprecated ids without a replacement are left alone
+        assert!(migrate_deprecated_tags("// SPDX-License-Identifier: GPL-2.0-only\n", 0).is_none());
+        assert!(migrate_deprecated_tags("// SPDX-License-Identifier: Net-SNMP\n", 0).is_none());
+    }
+
+    #[test]
+    fn find_header_ignores_unrelated_comments() {
+        let matcher = HeaderMatcher::new([License::MIT]);
+        let content = "// Just a comment\nfn main() {}\n";
+        assert!(find_header(content, 0, &line_tokens(), &matcher, &[]).is_none());
+    }
+
+    #[test]
+    fn find_header_only_takes_markerless_headers_of_the_owners() {
+        let matcher = HeaderMatcher::new([License::MIT]);
+        let header = |authors: Option<&str>| {
+            let rendered = crate::utils::render_license(
+                License::MIT.template_content(),
+                &jiff::civil::Date::new(2019, 1, 1).unwrap(),
+                &authors.map(|name| crate::models::parse_to_author(name).unwrap()),
+            )
+            .unwrap();
+            let header = crate::utils::format_header_with_comments(
+                &rendered,
+                &line_tokens(),
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+            format!("{}\nfn main() {{}}\n", header)
+        };
+        let owners = vec!["Me".to_string()];
+
+        let ours = header(Some("Me"));
+        assert!(find_header(&ours, 0, &line_tokens(), &matcher, &owners).is_some());
+        let anonymous = header(None);
+        assert!(find_header(&anonymous, 0, &line_tokens(), &matcher, &[]).is_some());
+        assert!(find_header(&anonymous, 0, &line_tokens(), &matcher, &owners).is_none());
+
+        // The same template, under someone else's copyright
+        let theirs = header(Some("Jane Doe"));
+        assert!(find_header(&theirs, 0, &line_tokens(), &matcher, &owners).is_none());
+        assert!(find_header(&theirs, 0, &line_tokens(), &matcher, &[]).is_none());
+    }
+
+    #[test]
+    fn declared_license_reads_tags_headers_and_notices() {
+        let matcher = HeaderMatcher::new([License::MIT, License::ApacheTwoDotZero]);
+        let declared = |content: &str| {
+            declared_license(content, 0, &line_tokens(), &matcher).map(|e| e.to_string())
+        };
+
+        assert_eq!(
+            declared("// SPDX-License-Identifier: MIT OR Apache-2.0\nfn main() {}\n").unwrap(),
+            "MIT OR Apache-2.0"
+        );
+        let rendered = crate::utils::render_license(
+            License::ApacheTwoDotZero.template_content(),
+            &jiff::civil::Date::new(2025, 1, 1).unwrap(),
+            &None,
+        )
+        .unwrap();
+        let header =
+            crate::utils::format_header_with_comments(&rendered, &line_tokens(), false, None, None)
+                .unwrap();
+        assert_eq!(
+            declared(&format!("{}\nfn main() {{}}\n", header)).unwrap(),
+            "Apache-2.0"
+        );
+        assert_eq!(
+            declared("// Copyright 2020 ACME Corp.\n// Confidential and proprietary.\nint x;\n")
+                .unwrap(),
+            "LicenseRef-Proprietary"
+        );
+        assert!(declared("// Entry point\nfn main() {}\n").is_none());
+    }
+
+    #[test]
+    fn diagnose_header_tells_problems_apart() {
+        let header = |license: License, year: i16| {
+            let rendered = crate::utils::render_license(
+                license.template_content(),
+                &jiff::civil::Date::new(year, 1, 1).unwrap(),
+                &None,
+            )
+            .unwrap();
+            crate::utils::format_header_with_comments(&rendered, &line_tokens(), false, None, None)
+                .unwrap()
+        };
+        let expected = header(License::MIT, 2025);
+
+        assert_eq!(
+            diagnose_header(&header(License::MIT, 2019), &expected, &line_tokens()),
+            HeaderProblem::OutdatedYear
+        );
+        assert_eq!(
+            diagnose_header(
+                &header(License::ApacheTwoDotZero, 2025),
+                &expected,
+                &line_tokens()
+            ),
+            HeaderProblem::WrongLicense
+        );
+        // Whatever the licenses configured, a header of another license is told apart
+        assert_eq!(
+            diagnose_header(
+                &header(License::BSDThreeClause, 2025),
+                &expected,
+                &line_tokens()
+            ),
+            HeaderProblem::WrongLicense
+        );
+        assert_eq!(
+            diagnose_header(
+                "// Copyright (c) 2025 Jane\n// All mine\n",
+                &expected,
+                &line_tokens()
+            ),
+            HeaderProblem::Malformed
+        );
+
+        let finding = HeaderFinding::new(
+            HeaderProblem::Malformed,
+            "#!/bin/sh\n\n# a\n# b\necho\n",
+            10..19,
+        );
+        assert_eq!((finding.start_line, finding.end_line), (3, 4));
+    }
+}
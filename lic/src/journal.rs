@@ -0,0 +1,210 @@
+//! # Undo Journal
+//!
+//! Keeps the original content of every file a run modifies under `.lichen/`,
+//! so that `lic undo` can roll the last run back.
+
+use crate::error::LichenError;
+use crate::utils::{self, write_atomically};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Where the journal lives, relative to the project root.
+pub const JOURNAL_DIR: &str = ".lichen/journal";
+
+/// Lists the recorded files, one JSON entry per line so an interrupted run leaves it readable.
+const MANIFEST_FILE: &str = "manifest.jsonl";
+
+/// A file recorded in the journal, and the name of the copy of its original content.
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalEntry {
+    path: PathBuf,
+    backup: String,
+}
+
+/// The directory the journal of the project lives in, whichever subdirectory Lichen runs from:
+/// the top of the git repository, else the directory of the configuration file, else the current
+/// directory.
+pub fn project_root(config_path: &Path) -> PathBuf {
+    if let Ok(toplevel) = utils::git_toplevel(Path::new(".")) {
+        return toplevel;
+    }
+    match config_path.parent() {
+        Some(dir) if config_path.is_file() && !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Records the files of a single run, before they are overwritten.
+#[derive(Debug)]
+pub struct Journal {
+    dir: PathBuf,
+    /// Files recorded so far, `None` until the first one replaces the previous journal.
+    recorded: Mutex<Option<Vec<PathBuf>>>,
+}
+
+impl Journal {
+    /// Creates the journal of a run. Nothing is written until a file is recorded,
+    /// so runs that don't change anything (ex: dry runs) keep the previous journal.
+    pub fn new(root: &Path) -> Self {
+        Journal {
+            dir: root.join(JOURNAL_DIR),
+            recorded: Mutex::new(None),
+        }
+    }
+
+    /// Saves the current content of the file, before it is overwritten.
+    pub fn record(&self, path: &Path) -> Result<(), LichenError> {
+        let mut recorded = self
+            .recorded
+            .lock()
+            .map_err(|_| LichenError::Msg("The undo journal was poisoned".to_string()))?;
+
+        let recorded = match &mut *recorded {
+            Some(recorded) => recorded,
+            None => {
+                // Only the last run can be undone, its journal replaces the previous one
+                if self.dir.exists() {
+                    fs::remove_dir_all(&self.dir)?;
+                }
+                fs::create_dir_all(&self.dir)?;
+                // The backups are full copies of the project's files, keep them out of git
+                if let Some(lichen_dir) = self.dir.parent()
+                    && !lichen_dir.join(".gitignore").exists()
+                {
+                    fs::write(lichen_dir.join(".gitignore"), "*\n")?;
+                }
+                debug!("Started undo journal in '{}'", self.dir.display());
+                recorded.insert(Vec::new())
+            }
+        };
+
+        // Absolute paths, so the run can be undone from anywhere in the project
+        let path = fs::canonicalize(path)?;
+        let backup = recorded.len().to_string();
+        fs::copy(&path, self.dir.join(&backup))?;
+
+        let mut manifest = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(MANIFEST_FILE))?;
+        writeln!(
+            manifest,
+            "{}",
+            serde_json::to_string(&JournalEntry {
+                path: path.clone(),
+                backup
+            })?
+        )?;
+
+        recorded.push(path);
+        Ok(())
+    }
+
+    /// Lists the files recorded so far, as canonical paths.
+    pub fn recorded_paths(&self) -> Vec<PathBuf> {
+        self.recorded
+            .lock()
+            .map(|recorded| recorded.clone().unwrap_or_default())
+            .unwrap_or_default()
+    }
+}
+
+/// Restores every file recorded by the last run to its original content, then discards the journal.
+///
+/// # Returns
+///
+/// The number of files restored.
+pub fn undo(root: &Path) -> Result<usize, LichenError> {
+    let dir = root.join(JOURNAL_DIR);
+    let manifest = match fs::read_to_string(dir.join(MANIFEST_FILE)) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(LichenError::NothingToDo(
+                "Nothing to undo, no run was recorded in this directory.".to_string(),
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let entries = manifest
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect::<Result<Vec<JournalEntry>, _>>()?;
+
+    // Newest first, so a file changed twice in a run ends up with its oldest content
+    for entry in entries.iter().rev() {
+        let original = fs::read(dir.join(&entry.backup))
+            .map_err(|e| LichenError::from(e).for_file("reading the backup of", &entry.path))?;
+        write_atomically(&entry.path, &original)
+            .map_err(|e| e.for_file("restoring", &entry.path))?;
+        debug!("Restored '{}'", entry.path.display());
+    }
+
+    fs::remove_dir_all(&dir)?;
+
+    let mut restored: Vec<&PathBuf> = entries.iter().map(|entry| &entry.path).collect();
+    restored.sort();
+    restored.dedup();
+    info!("Restored {} files", restored.len());
+    Ok(restored.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn undo_restores_the_oldest_content_of_the_last_run() {
+        let root = tempdir().unwrap();
+        let file = root.path().join("main.rs");
+
+        // A first run, replaced by the next one
+        fs::write(&file, "first").unwrap();
+        Journal::new(root.path()).record(&file).unwrap();
+
+        fs::write(&file, "second").unwrap();
+        let journal = Journal::new(root.path());
+        journal.record(&file).unwrap();
+        fs::write(&file, "third").unwrap();
+        journal.record(&file).unwrap();
+        fs::write(&file, "fourth").unwrap();
+
+        // The backups are kept out of git
+        let gitignore = root.path().join(".lichen/.gitignore");
+        assert_eq!(fs::read_to_string(&gitignore).unwrap(), "*\n");
+
+        assert_eq!(undo(root.path()).unwrap(), 1);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "second");
+        assert!(!root.path().join(JOURNAL_DIR).exists());
+    }
+
+    #[test]
+    fn undo_without_journal_fails() {
+        let root = tempdir().unwrap();
+        assert!(matches!(
+            undo(root.path()),
+            Err(LichenError::NothingToDo(_))
+        ));
+    }
+
+    #[test]
+    fn unused_journal_keeps_the_previous_run() {
+        let root = tempdir().unwrap();
+        let file = root.path().join("main.rs");
+        fs::write(&file, "original").unwrap();
+        Journal::new(root.path()).record(&file).unwrap();
+        fs::write(&file, "changed").unwrap();
+
+        // A dry run, that doesn't record anything
+        let _ = Journal::new(root.path());
+
+        assert_eq!(undo(root.path()).unwrap(), 1);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "original");
+    }
+}
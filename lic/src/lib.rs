@@ -1,8 +1,13 @@
 //! Sets up a library configuration for Lichen
 pub mod app;
 pub mod commands;
+pub mod compat;
 pub mod config;
+pub mod deps;
 pub mod error;
+pub mod header;
+pub mod journal;
 pub mod license;
 pub mod models;
+pub mod policy;
 pub mod utils;
@@ -1,4205 +1,6524 @@
-use crate::models::License;
+use crate::models::{License, LicenseException};
+#[doc = r" An unknown identifier, with the closest known ones."]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ParseLicenseError;
+pub struct ParseLicenseError(pub String);
 impl std::fmt::Display for ParseLicenseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Provided string does not match any known license filename"
-        )
+        write!(f, "{}", self.0)
     }
 }
 impl std::error::Error for ParseLicenseError {}
 impl License {
-    #[doc = r#" Returns the original filename of the license (e.g., "Apache-2.0.md")."#]
+    #[doc = r#" Returns the SPDX identifier of the license (e.g., "Apache-2.0")."#]
     pub fn spdx_id(&self) -> &'static str {
         match self {
+            Self::ZeroBsd => "0BSD",
+            Self::ThreeDSlicerOneDotZero => "3D-Slicer-1.0",
+            Self::AAL => "AAL",
+            Self::ADSL => "ADSL",
+            Self::AFLOneDotOne => "AFL-1.1",
             Self::AFLOneDotTwo => "AFL-1.2",
-            Self::LinuxSyscallNote => "Linux-syscall-note",
-            Self::DocBookStylesheet => "DocBook-Stylesheet",
-            Self::HPNDSellMITDisclaimerXserver => "HPND-sell-MIT-disclaimer-xserver",
-            Self::OSLTwoDotOne => "OSL-2.1",
-            Self::SWL => "SWL",
-            Self::CCBYNCFourDotZero => "CC-BY-NC-4.0",
-            Self::NLODTwoDotZero => "NLOD-2.0",
-            Self::Unlicense => "Unlicense",
-            Self::ODCByOneDotZero => "ODC-By-1.0",
-            Self::UPLOneDotZero => "UPL-1.0",
-            Self::SNIA => "SNIA",
-            Self::GCCExceptionThreeDotOne => "GCC-exception-3.1",
-            Self::OLDAPOneDotThree => "OLDAP-1.3",
-            Self::Dotseqn => "Dotseqn",
-            Self::TMate => "TMate",
-            Self::GFDLOneDotOneInvariantsOrLater => "GFDL-1.1-invariants-or-later",
-            Self::MmiXware => "MMIXware",
-            Self::Crossword => "Crossword",
-            Self::OLDAPOneDotFour => "OLDAP-1.4",
-            Self::GLWTPL => "GLWTPL",
-            Self::Curl => "curl",
-            Self::GPLTwoDotZeroOrLater => "GPL-2.0-or-later",
-            Self::OFLOneDotOneNoRFN => "OFL-1.1-no-RFN",
-            Self::MSLPL => "MS-LPL",
-            Self::CCBYOneDotZero => "CC-BY-1.0",
-            Self::CDLAPermissiveOneDotZero => "CDLA-Permissive-1.0",
-            Self::QPLOneDotZeroINRIATwoZeroZeroFourException => "QPL-1.0-INRIA-2004-exception",
-            Self::ClasspathExceptionTwoDotZero => "Classpath-exception-2.0",
-            Self::BSDThreeClause => "BSD-3-Clause",
-            Self::OPLOneDotZero => "OPL-1.0",
-            Self::ODbLOneDotZero => "ODbL-1.0",
-            Self::IndependentModulesException => "Independent-modules-exception",
-            Self::MITFestival => "MIT-Festival",
-            Self::CalderaNoPreamble => "Caldera-no-preamble",
-            Self::SGIBOneDotOne => "SGI-B-1.1",
-            Self::Soundex => "Soundex",
-            Self::SANEException => "SANE-exception",
-            Self::MirOs => "MirOS",
-            Self::Ruby => "Ruby",
-            Self::PolyparseException => "polyparse-exception",
-            Self::BSDThreeClauseLBNL => "BSD-3-Clause-LBNL",
-            Self::BSDTwoClause => "BSD-2-Clause",
-            Self::EFLTwoDotZero => "EFL-2.0",
-            Self::LLGPL => "LLGPL",
-            Self::VsftpdOpensslException => "vsftpd-openssl-exception",
-            Self::OFLOneDotZeroNoRFN => "OFL-1.0-no-RFN",
-            Self::CCBYNCNDThreeDotZeroDE => "CC-BY-NC-ND-3.0-DE",
-            Self::ArtisticTwoDotZero => "Artistic-2.0",
-            Self::CDDLOneDotZero => "CDDL-1.0",
-            Self::SWIException => "SWI-exception",
-            Self::GFDLOneDotThreeInvariantsOnly => "GFDL-1.3-invariants-only",
-            Self::MSRL => "MS-RL",
+            Self::AFLTwoDotZero => "AFL-2.0",
+            Self::AFLTwoDotOne => "AFL-2.1",
+            Self::AFLThreeDotZero => "AFL-3.0",
+            Self::AGPLOneDotZeroOnly => "AGPL-1.0-only",
+            Self::AGPLOneDotZeroOrLater => "AGPL-1.0-or-later",
+            Self::AGPLThreeDotZeroOnly => "AGPL-3.0-only",
+            Self::AGPLThreeDotZeroOrLater => "AGPL-3.0-or-later",
+            Self::AMDNewlib => "AMD-newlib",
+            Self::AMDPLPA => "AMDPLPA",
+            Self::AML => "AML",
+            Self::AMLGlslang => "AML-glslang",
+            Self::AMPAS => "AMPAS",
+            Self::ANTLRPD => "ANTLR-PD",
+            Self::ANTLRPDFallback => "ANTLR-PD-fallback",
+            Self::APAFML => "APAFML",
+            Self::APLOneDotZero => "APL-1.0",
+            Self::APSLOneDotZero => "APSL-1.0",
+            Self::APSLOneDotOne => "APSL-1.1",
+            Self::APSLOneDotTwo => "APSL-1.2",
+            Self::APSLTwoDotZero => "APSL-2.0",
+            Self::ASWFDigitalAssetsOneDotZero => "ASWF-Digital-Assets-1.0",
             Self::ASWFDigitalAssetsOneDotOne => "ASWF-Digital-Assets-1.1",
-            Self::LGPLTwoDotOneOnly => "LGPL-2.1-only",
-            Self::HPOneNineEightSix => "HP-1986",
-            Self::PythonTwoDotZeroDotOne => "Python-2.0.1",
             Self::Abstyles => "Abstyles",
-            Self::RHeCosOneDotOne => "RHeCos-1.1",
-            Self::MIT => "MIT",
+            Self::AdaCoreDoc => "AdaCore-doc",
+            Self::AdobeTwoZeroZeroSix => "Adobe-2006",
+            Self::AdobeDisplayPostScript => "Adobe-Display-PostScript",
+            Self::AdobeGlyph => "Adobe-Glyph",
+            Self::AdobeUtopia => "Adobe-Utopia",
+            Self::Afmparse => "Afmparse",
+            Self::Aladdin => "Aladdin",
+            Self::ApacheOneDotZero => "Apache-1.0",
+            Self::ApacheOneDotOne => "Apache-1.1",
+            Self::ApacheTwoDotZero => "Apache-2.0",
+            Self::AppSTwop => "App-s2p",
+            Self::ArphicOneNineNineNine => "Arphic-1999",
+            Self::ArtisticOneDotZero => "Artistic-1.0",
+            Self::ArtisticOneDotZeroPerl => "Artistic-1.0-Perl",
+            Self::ArtisticOneDotZeroClEight => "Artistic-1.0-cl8",
+            Self::ArtisticTwoDotZero => "Artistic-2.0",
             Self::BSDOneClause => "BSD-1-Clause",
-            Self::RomicException => "romic-exception",
-            Self::OSLThreeDotZero => "OSL-3.0",
-            Self::CCBYNCThreeDotZero => "CC-BY-NC-3.0",
-            Self::GCCExceptionTwoDotZero => "GCC-exception-2.0",
-            Self::DeprecatedLGPLThreeDotZeroPlus => "deprecated_LGPL-3.0+",
-            Self::Clips => "Clips",
+            Self::BSDTwoClause => "BSD-2-Clause",
             Self::BSDTwoClauseDarwin => "BSD-2-Clause-Darwin",
-            Self::CNRIPythonGPLCompatible => "CNRI-Python-GPL-Compatible",
-            Self::LPDDocument => "LPD-document",
-            Self::GPLCCOneDotZero => "GPL-CC-1.0",
-            Self::BSDThreeClauseFlex => "BSD-3-Clause-flex",
-            Self::HPNDUC => "HPND-UC",
-            Self::ISCVeillard => "ISC-Veillard",
-            Self::PDDLOneDotZero => "PDDL-1.0",
-            Self::LGPLTwoDotZeroOrLater => "LGPL-2.0-or-later",
-            Self::CCBYNCSAThreeDotZeroIGO => "CC-BY-NC-SA-3.0-IGO",
-            Self::TORQUEOneDotOne => "TORQUE-1.1",
-            Self::FrameworxOneDotZero => "Frameworx-1.0",
-            Self::OLDAPTwoDotEight => "OLDAP-2.8",
-            Self::DeprecatedGPLThreeDotZero => "deprecated_GPL-3.0",
-            Self::QwtExceptionOneDotZero => "Qwt-exception-1.0",
-            Self::DocBookDTD => "DocBook-DTD",
-            Self::Psfrag => "psfrag",
-            Self::IJGShort => "IJG-short",
-            Self::Psutils => "psutils",
-            Self::DRLOneDotOne => "DRL-1.1",
-            Self::BoehmGC => "Boehm-GC",
-            Self::CECILLTwoDotZero => "CECILL-2.0",
-            Self::WThreeCOneNineNineEightZeroSevenTwoZero => "W3C-19980720",
-            Self::OUDAOneDotZero => "O-UDA-1.0",
-            Self::CLISPExceptionTwoDotZero => "CLISP-exception-2.0",
-            Self::DLDEZEROTwoDotZero => "DL-DE-ZERO-2.0",
-            Self::DeprecatedGFDLOneDotThree => "deprecated_GFDL-1.3",
-            Self::SgpFour => "SGP4",
-            Self::FDKAAC => "FDK-AAC",
-            Self::ApacheOneDotOne => "Apache-1.1",
-            Self::TTWL => "TTWL",
-            Self::MartinBirgmeier => "Martin-Birgmeier",
-            Self::CCBYNDThreeDotZeroDE => "CC-BY-ND-3.0-DE",
-            Self::ECLTwoDotZero => "ECL-2.0",
-            Self::ICU => "ICU",
-            Self::StunnelException => "stunnel-exception",
-            Self::GameProgrammingGems => "Game-Programming-Gems",
+            Self::BSDTwoClausePatent => "BSD-2-Clause-Patent",
+            Self::BSDTwoClauseViews => "BSD-2-Clause-Views",
+            Self::BSDTwoClauseFirstLines => "BSD-2-Clause-first-lines",
+            Self::BSDTwoClausePkgconfDisclaimer => "BSD-2-Clause-pkgconf-disclaimer",
+            Self::BSDThreeClause => "BSD-3-Clause",
             Self::BSDThreeClauseAttribution => "BSD-3-Clause-Attribution",
-            Self::SGIBOneDotZero => "SGI-B-1.0",
-            Self::NRL => "NRL",
-            Self::Kazlib => "Kazlib",
-            Self::PHPThreeDotZeroOne => "PHP-3.01",
-            Self::Magaz => "magaz",
-            Self::CPALOneDotZero => "CPAL-1.0",
-            Self::AppSTwop => "App-s2p",
-            Self::RrDtoolFLOSSExceptionTwoDotZero => "RRDtool-FLOSS-exception-2.0",
-            Self::LibtoolException => "Libtool-exception",
-            Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven => {
-                "PS-or-PDF-font-exception-20170817"
+            Self::BSDThreeClauseClear => "BSD-3-Clause-Clear",
+            Self::BSDThreeClauseHP => "BSD-3-Clause-HP",
+            Self::BSDThreeClauseLBNL => "BSD-3-Clause-LBNL",
+            Self::BSDThreeClauseModification => "BSD-3-Clause-Modification",
+            Self::BSDThreeClauseNoMilitaryLicense => "BSD-3-Clause-No-Military-License",
+            Self::BSDThreeClauseNoNuclearLicense => "BSD-3-Clause-No-Nuclear-License",
+            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => {
+                "BSD-3-Clause-No-Nuclear-License-2014"
             }
+            Self::BSDThreeClauseNoNuclearWarranty => "BSD-3-Clause-No-Nuclear-Warranty",
+            Self::BSDThreeClauseOpenMPI => "BSD-3-Clause-Open-MPI",
+            Self::BSDThreeClauseSun => "BSD-3-Clause-Sun",
+            Self::BSDThreeClauseAcpica => "BSD-3-Clause-acpica",
+            Self::BSDThreeClauseFlex => "BSD-3-Clause-flex",
+            Self::BSDFourClause => "BSD-4-Clause",
+            Self::BSDFourClauseShortened => "BSD-4-Clause-Shortened",
+            Self::BSDFourClauseUC => "BSD-4-Clause-UC",
+            Self::BSDFourDotThreeReno => "BSD-4.3RENO",
+            Self::BSDFourDotThreeTahoe => "BSD-4.3TAHOE",
+            Self::BSDAdvertisingAcknowledgement => "BSD-Advertising-Acknowledgement",
+            Self::BSDAttributionHPNDDisclaimer => "BSD-Attribution-HPND-disclaimer",
+            Self::BSDInfernoNettverk => "BSD-Inferno-Nettverk",
+            Self::BSDProtection => "BSD-Protection",
+            Self::BSDSourceCode => "BSD-Source-Code",
+            Self::BSDSourceBeginningFile => "BSD-Source-beginning-file",
+            Self::BSDSystemics => "BSD-Systemics",
+            Self::BSDSystemicsWThreeWorks => "BSD-Systemics-W3Works",
+            Self::BSLOneDotZero => "BSL-1.0",
+            Self::BUSLOneDotOne => "BUSL-1.1",
+            Self::Baekmuk => "Baekmuk",
+            Self::Bahyph => "Bahyph",
+            Self::Barr => "Barr",
+            Self::Beerware => "Beerware",
+            Self::BitTorrentOneDotZero => "BitTorrent-1.0",
+            Self::BitTorrentOneDotOne => "BitTorrent-1.1",
+            Self::BitstreamCharter => "Bitstream-Charter",
+            Self::BitstreamVera => "Bitstream-Vera",
+            Self::BlueOakOneDotZeroDotZero => "BlueOak-1.0.0",
+            Self::BoehmGC => "Boehm-GC",
+            Self::BoehmGCWithoutFee => "Boehm-GC-without-fee",
+            Self::Borceux => "Borceux",
             Self::BrianGladmanTwoClause => "Brian-Gladman-2-Clause",
-            Self::DeprecatedGPLTwoDotZero => "deprecated_GPL-2.0",
-            Self::LGPLThreeDotZeroOnly => "LGPL-3.0-only",
-            Self::DigiRuleFOSSException => "DigiRule-FOSS-exception",
-            Self::GPLThreeDotZeroLinkingException => "GPL-3.0-linking-exception",
-            Self::ASWFDigitalAssetsOneDotZero => "ASWF-Digital-Assets-1.0",
-            Self::Mailprio => "mailprio",
-            Self::LPPLOneDotTwo => "LPPL-1.2",
-            Self::CDDLOneDotOne => "CDDL-1.1",
-            Self::HPND => "HPND",
-            Self::NTPZero => "NTP-0",
-            Self::CCBYNDTwoDotFive => "CC-BY-ND-2.5",
-            Self::CCBYNCNDTwoDotFive => "CC-BY-NC-ND-2.5",
-            Self::FreertosExceptionTwoDotZero => "freertos-exception-2.0",
             Self::BrianGladmanThreeClause => "Brian-Gladman-3-Clause",
-            Self::DeprecatedGPLTwoDotZeroWithBisonException => {
-                "deprecated_GPL-2.0-with-bison-exception"
-            }
-            Self::SugarCrmOneDotOneDotThree => "SugarCRM-1.1.3",
-            Self::SISSL => "SISSL",
-            Self::CFITSIO => "CFITSIO",
-            Self::TUBerlinTwoDotZero => "TU-Berlin-2.0",
-            Self::CMUMachNodoc => "CMU-Mach-nodoc",
-            Self::CondorOneDotOne => "Condor-1.1",
-            Self::OSLTwoDotZero => "OSL-2.0",
-            Self::CCBYNCTwoDotZero => "CC-BY-NC-2.0",
-            Self::AdaCoreDoc => "AdaCore-doc",
-            Self::TermReadKey => "TermReadKey",
-            Self::UnixCrypt => "UnixCrypt",
-            Self::Jove => "jove",
-            Self::NTP => "NTP",
-            Self::AdobeDisplayPostScript => "Adobe-Display-PostScript",
+            Self::CUDAOneDotZero => "C-UDA-1.0",
+            Self::CALOneDotZero => "CAL-1.0",
+            Self::CALOneDotZeroCombinedWorkException => "CAL-1.0-Combined-Work-Exception",
+            Self::CATOSLOneDotOne => "CATOSL-1.1",
+            Self::CCBYOneDotZero => "CC-BY-1.0",
+            Self::CCBYTwoDotZero => "CC-BY-2.0",
+            Self::CCBYTwoDotFive => "CC-BY-2.5",
+            Self::CCBYTwoDotFiveAU => "CC-BY-2.5-AU",
+            Self::CCBYThreeDotZero => "CC-BY-3.0",
+            Self::CCBYThreeDotZeroAT => "CC-BY-3.0-AT",
+            Self::CCBYThreeDotZeroAU => "CC-BY-3.0-AU",
             Self::CCBYThreeDotZeroDE => "CC-BY-3.0-DE",
-            Self::Kastrup => "Kastrup",
-            Self::LGPLTwoDotZeroOnly => "LGPL-2.0-only",
-            Self::OLDAPOneDotTwo => "OLDAP-1.2",
-            Self::LinuxManPagesOnePara => "Linux-man-pages-1-para",
-            Self::DLDEBYTwoDotZero => "DL-DE-BY-2.0",
+            Self::CCBYThreeDotZeroIGO => "CC-BY-3.0-IGO",
+            Self::CCBYThreeDotZeroNL => "CC-BY-3.0-NL",
+            Self::CCBYThreeDotZeroUS => "CC-BY-3.0-US",
+            Self::CCBYFourDotZero => "CC-BY-4.0",
+            Self::CCBYNCOneDotZero => "CC-BY-NC-1.0",
+            Self::CCBYNCTwoDotZero => "CC-BY-NC-2.0",
+            Self::CCBYNCTwoDotFive => "CC-BY-NC-2.5",
+            Self::CCBYNCThreeDotZero => "CC-BY-NC-3.0",
             Self::CCBYNCThreeDotZeroDE => "CC-BY-NC-3.0-DE",
-            Self::FreeImage => "FreeImage",
-            Self::CECILLTwoDotOne => "CECILL-2.1",
-            Self::DRLOneDotZero => "DRL-1.0",
-            Self::HPNDExportUSAcknowledgement => "HPND-export-US-acknowledgement",
-            Self::OLDAPTwoDotZeroDotOne => "OLDAP-2.0.1",
-            Self::Threeparttable => "threeparttable",
-            Self::MITOpenGroup => "MIT-open-group",
-            Self::FSFULLR => "FSFULLR",
-            Self::AutoconfExceptionMacro => "Autoconf-exception-macro",
-            Self::NCL => "NCL",
-            Self::EPICS => "EPICS",
-            Self::ApacheOneDotZero => "Apache-1.0",
-            Self::JSON => "JSON",
-            Self::DeprecatedGFDLOneDotTwo => "deprecated_GFDL-1.2",
-            Self::SpencerEightSix => "Spencer-86",
-            Self::BSDFourClause => "BSD-4-Clause",
-            Self::SHLZeroDotFiveOne => "SHL-0.51",
-            Self::IJG => "IJG",
-            Self::APSLOneDotTwo => "APSL-1.2",
-            Self::DeprecatedGPLTwoDotZeroPlus => "deprecated_GPL-2.0+",
-            Self::ErlangOtpLinkingException => "erlang-otp-linking-exception",
-            Self::SAXPD => "SAX-PD",
-            Self::PSFTwoDotZero => "PSF-2.0",
-            Self::BSDThreeClauseModification => "BSD-3-Clause-Modification",
-            Self::QPLOneDotZeroINRIATwoZeroZeroFour => "QPL-1.0-INRIA-2004",
-            Self::DeprecatedGPLTwoDotZeroWithClasspathException => {
-                "deprecated_GPL-2.0-with-classpath-exception"
-            }
-            Self::EUPLOneDotTwo => "EUPL-1.2",
-            Self::BSDTwoClauseFirstLines => "BSD-2-Clause-first-lines",
-            Self::GNOMEExamplesException => "GNOME-examples-exception",
-            Self::XOneOneDistributeModificationsVariant => "X11-distribute-modifications-variant",
-            Self::LPLOneDotZero => "LPL-1.0",
-            Self::SpencerNineFour => "Spencer-94",
+            Self::CCBYNCFourDotZero => "CC-BY-NC-4.0",
+            Self::CCBYNCNDOneDotZero => "CC-BY-NC-ND-1.0",
+            Self::CCBYNCNDTwoDotZero => "CC-BY-NC-ND-2.0",
+            Self::CCBYNCNDTwoDotFive => "CC-BY-NC-ND-2.5",
+            Self::CCBYNCNDThreeDotZero => "CC-BY-NC-ND-3.0",
+            Self::CCBYNCNDThreeDotZeroDE => "CC-BY-NC-ND-3.0-DE",
+            Self::CCBYNCNDThreeDotZeroIGO => "CC-BY-NC-ND-3.0-IGO",
+            Self::CCBYNCNDFourDotZero => "CC-BY-NC-ND-4.0",
+            Self::CCBYNCSAOneDotZero => "CC-BY-NC-SA-1.0",
+            Self::CCBYNCSATwoDotZero => "CC-BY-NC-SA-2.0",
+            Self::CCBYNCSATwoDotZeroDE => "CC-BY-NC-SA-2.0-DE",
+            Self::CCBYNCSATwoDotZeroFR => "CC-BY-NC-SA-2.0-FR",
+            Self::CCBYNCSATwoDotZeroUK => "CC-BY-NC-SA-2.0-UK",
             Self::CCBYNCSATwoDotFive => "CC-BY-NC-SA-2.5",
-            Self::UBootExceptionTwoDotZero => "u-boot-exception-2.0",
+            Self::CCBYNCSAThreeDotZero => "CC-BY-NC-SA-3.0",
+            Self::CCBYNCSAThreeDotZeroDE => "CC-BY-NC-SA-3.0-DE",
+            Self::CCBYNCSAThreeDotZeroIGO => "CC-BY-NC-SA-3.0-IGO",
+            Self::CCBYNCSAFourDotZero => "CC-BY-NC-SA-4.0",
+            Self::CCBYNDOneDotZero => "CC-BY-ND-1.0",
+            Self::CCBYNDTwoDotZero => "CC-BY-ND-2.0",
+            Self::CCBYNDTwoDotFive => "CC-BY-ND-2.5",
+            Self::CCBYNDThreeDotZero => "CC-BY-ND-3.0",
+            Self::CCBYNDThreeDotZeroDE => "CC-BY-ND-3.0-DE",
+            Self::CCBYNDFourDotZero => "CC-BY-ND-4.0",
+            Self::CCBYSAOneDotZero => "CC-BY-SA-1.0",
+            Self::CCBYSATwoDotZero => "CC-BY-SA-2.0",
+            Self::CCBYSATwoDotZeroUK => "CC-BY-SA-2.0-UK",
+            Self::CCBYSATwoDotOneJP => "CC-BY-SA-2.1-JP",
             Self::CCBYSATwoDotFive => "CC-BY-SA-2.5",
-            Self::Newsletr => "Newsletr",
-            Self::Noweb => "Noweb",
-            Self::APLOneDotZero => "APL-1.0",
-            Self::WThreem => "w3m",
-            Self::FSLOneDotOneMIT => "FSL-1.1-MIT",
-            Self::TexinfoException => "Texinfo-exception",
-            Self::GFDLOneDotTwoNoInvariantsOrLater => "GFDL-1.2-no-invariants-or-later",
-            Self::ArtisticOneDotZero => "Artistic-1.0",
-            Self::EFLOneDotZero => "EFL-1.0",
-            Self::WatcomOneDotZero => "Watcom-1.0",
-            Self::CCBYThreeDotZeroAU => "CC-BY-3.0-AU",
-            Self::ISC => "ISC",
-            Self::ZlibAcknowledgement => "zlib-acknowledgement",
-            Self::TCPWrappers => "TCP-wrappers",
-            Self::UniversalFOSSExceptionOneDotZero => "Universal-FOSS-exception-1.0",
-            Self::Hdparm => "hdparm",
-            Self::SHLTwoDotZero => "SHL-2.0",
-            Self::OLDAPTwoDotTwoDotOne => "OLDAP-2.2.1",
-            Self::GFDLOneDotThreeNoInvariantsOnly => "GFDL-1.3-no-invariants-only",
-            Self::EGenix => "eGenix",
-            Self::AGPLOneDotZeroOnly => "AGPL-1.0-only",
-            Self::FSFAP => "FSFAP",
-            Self::SpencerNineNine => "Spencer-99",
-            Self::OLDAPTwoDotThree => "OLDAP-2.3",
-            Self::GmshException => "Gmsh-exception",
-            Self::Dvipdfm => "dvipdfm",
-            Self::DeprecatedNetSNMP => "deprecated_Net-SNMP",
-            Self::LZMAException => "LZMA-exception",
-            Self::Libpng => "Libpng",
-            Self::Xnet => "Xnet",
-            Self::HPNDMerchantabilityVariant => "HPND-merchantability-variant",
-            Self::Intel => "Intel",
-            Self::OLDAPTwoDotFour => "OLDAP-2.4",
-            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => "W3C-20150513",
-            Self::MITKhronosOld => "MIT-Khronos-old",
+            Self::CCBYSAThreeDotZero => "CC-BY-SA-3.0",
+            Self::CCBYSAThreeDotZeroAT => "CC-BY-SA-3.0-AT",
+            Self::CCBYSAThreeDotZeroDE => "CC-BY-SA-3.0-DE",
+            Self::CCBYSAThreeDotZeroIGO => "CC-BY-SA-3.0-IGO",
+            Self::CCBYSAFourDotZero => "CC-BY-SA-4.0",
+            Self::CCPDDC => "CC-PDDC",
+            Self::CCPDMOneDotZero => "CC-PDM-1.0",
+            Self::CCSAOneDotZero => "CC-SA-1.0",
+            Self::CcZeroOneDotZero => "CC0-1.0",
+            Self::CDDLOneDotZero => "CDDL-1.0",
+            Self::CDDLOneDotOne => "CDDL-1.1",
+            Self::CDLOneDotZero => "CDL-1.0",
+            Self::CDLAPermissiveOneDotZero => "CDLA-Permissive-1.0",
             Self::CDLAPermissiveTwoDotZero => "CDLA-Permissive-2.0",
-            Self::CCBYTwoDotZero => "CC-BY-2.0",
-            Self::MpiPermissive => "mpi-permissive",
-            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => {
-                "deprecated_GPL-2.0-with-autoconf-exception"
-            }
-            Self::MIPS => "MIPS",
-            Self::Cube => "Cube",
-            Self::OSLOneDotOne => "OSL-1.1",
-            Self::MPLTwoDotZeroNoCopyleftException => "MPL-2.0-no-copyleft-exception",
-            Self::NLODOneDotZero => "NLOD-1.0",
-            Self::GFDLOneDotOneOrLater => "GFDL-1.1-or-later",
-            Self::WidgetWorkshop => "Widget-Workshop",
-            Self::CATOSLOneDotOne => "CATOSL-1.1",
-            Self::CryptsetupOpenSslException => "cryptsetup-OpenSSL-exception",
-            Self::BSDFourDotThreeReno => "BSD-4.3RENO",
-            Self::BSDSystemics => "BSD-Systemics",
-            Self::BoehmGCWithoutFee => "Boehm-GC-without-fee",
-            Self::GCCExceptionTwoDotZeroNote => "GCC-exception-2.0-note",
-            Self::AGPLThreeDotZeroOnly => "AGPL-3.0-only",
-            Self::BSDSystemicsWThreeWorks => "BSD-Systemics-W3Works",
-            Self::BSDThreeClauseOpenMPI => "BSD-3-Clause-Open-MPI",
-            Self::OCLCTwoDotZero => "OCLC-2.0",
-            Self::LALOneDotTwo => "LAL-1.2",
-            Self::XdebugOneDotZeroThree => "Xdebug-1.03",
-            Self::FSLOneDotOneALvTwo => "FSL-1.1-ALv2",
-            Self::HPNDUCExportUS => "HPND-UC-export-US",
-            Self::ECLOneDotZero => "ECL-1.0",
-            Self::CCBYNCNDThreeDotZeroIGO => "CC-BY-NC-ND-3.0-IGO",
-            Self::OGLCanadaTwoDotZero => "OGL-Canada-2.0",
-            Self::AGPLOneDotZeroOrLater => "AGPL-1.0-or-later",
-            Self::LGPLLR => "LGPLLR",
-            Self::Fair => "Fair",
-            Self::SwiftException => "Swift-exception",
-            Self::JasPerTwoDotZero => "JasPer-2.0",
+            Self::CDLASharingOneDotZero => "CDLA-Sharing-1.0",
             Self::CECILLOneDotZero => "CECILL-1.0",
-            Self::GPLThreeDotZeroLinkingSourceException => "GPL-3.0-linking-source-exception",
-            Self::BSDSourceCode => "BSD-Source-Code",
-            Self::Metamail => "metamail",
-            Self::LLVMException => "LLVM-exception",
-            Self::VSLOneDotZero => "VSL-1.0",
-            Self::LPPLOneDotThreec => "LPPL-1.3c",
-            Self::Furuseth => "Furuseth",
-            Self::BSDThreeClauseAcpica => "BSD-3-Clause-acpica",
+            Self::CECILLOneDotOne => "CECILL-1.1",
+            Self::CECILLTwoDotZero => "CECILL-2.0",
+            Self::CECILLTwoDotOne => "CECILL-2.1",
+            Self::CECILLB => "CECILL-B",
+            Self::CECILLC => "CECILL-C",
+            Self::CERNOHLOneDotOne => "CERN-OHL-1.1",
+            Self::CERNOHLOneDotTwo => "CERN-OHL-1.2",
+            Self::CERNOHLPTwoDotZero => "CERN-OHL-P-2.0",
+            Self::CERNOHLSTwoDotZero => "CERN-OHL-S-2.0",
+            Self::CERNOHLWTwoDotZero => "CERN-OHL-W-2.0",
+            Self::CFITSIO => "CFITSIO",
+            Self::CMUMach => "CMU-Mach",
+            Self::CMUMachNodoc => "CMU-Mach-nodoc",
             Self::CNRIJython => "CNRI-Jython",
-            Self::LiLiQPOneDotOne => "LiLiQ-P-1.1",
-            Self::FergusonTwofish => "Ferguson-Twofish",
-            Self::HPNDINRIAIMAG => "HPND-INRIA-IMAG",
-            Self::BitstreamCharter => "Bitstream-Charter",
-            Self::UnicodeDFSTwoZeroOneSix => "Unicode-DFS-2016",
-            Self::MITModernVariant => "MIT-Modern-Variant",
-            Self::DeprecatedGPLThreeDotZeroPlus => "deprecated_GPL-3.0+",
-            Self::Afmparse => "Afmparse",
-            Self::HPNDFennebergLivingston => "HPND-Fenneberg-Livingston",
-            Self::Wwl => "wwl",
-            Self::ClArtistic => "ClArtistic",
-            Self::HPNDMarkusKuhn => "HPND-Markus-Kuhn",
-            Self::Blessing => "blessing",
-            Self::SoftSurfer => "softSurfer",
-            Self::BisonExceptionOneDotTwoFour => "Bison-exception-1.24",
-            Self::CrystalStacker => "CrystalStacker",
-            Self::AML => "AML",
-            Self::NCBIPD => "NCBI-PD",
-            Self::GFDLOneDotTwoInvariantsOrLater => "GFDL-1.2-invariants-or-later",
-            Self::DeprecatedECosTwoDotZero => "deprecated_eCos-2.0",
-            Self::OLDAPTwoDotFive => "OLDAP-2.5",
-            Self::AMPAS => "AMPAS",
-            Self::GFDLOneDotOneNoInvariantsOrLater => "GFDL-1.1-no-invariants-or-later",
-            Self::CCBYFourDotZero => "CC-BY-4.0",
-            Self::OLDAPTwoDotTwo => "OLDAP-2.2",
             Self::CNRIPython => "CNRI-Python",
-            Self::BSLOneDotZero => "BSL-1.0",
-            Self::SMAILGPL => "SMAIL-GPL",
-            Self::TUBerlinOneDotZero => "TU-Berlin-1.0",
-            Self::VOSTROM => "VOSTROM",
-            Self::LibpngTwoDotZero => "libpng-2.0",
-            Self::GnuJavamailException => "gnu-javamail-exception",
-            Self::CCBYNCOneDotZero => "CC-BY-NC-1.0",
-            Self::GPLThreeDotZeroThreeEightNineDsBaseException => "GPL-3.0-389-ds-base-exception",
-            Self::OSLOneDotZero => "OSL-1.0",
-            Self::UnicodeThreeDotZero => "Unicode-3.0",
-            Self::GFDLOneDotThreeOrLater => "GFDL-1.3-or-later",
-            Self::InnoSetup => "InnoSetup",
-            Self::CALOneDotZero => "CAL-1.0",
-            Self::CCBYSAThreeDotZeroIGO => "CC-BY-SA-3.0-IGO",
-            Self::Saxpath => "Saxpath",
-            Self::OPUBLOneDotZero => "OPUBL-1.0",
-            Self::LinuxManPagesCopyleftTwoPara => "Linux-man-pages-copyleft-2-para",
-            Self::CCBYThreeDotZeroAT => "CC-BY-3.0-AT",
-            Self::GNATException => "GNAT-exception",
-            Self::CERNOHLPTwoDotZero => "CERN-OHL-P-2.0",
-            Self::XOneOnevncOpensslException => "x11vnc-openssl-exception",
-            Self::FSFUL => "FSFUL",
-            Self::OPLUKThreeDotZero => "OPL-UK-3.0",
-            Self::UMichMerit => "UMich-Merit",
-            Self::CornellLosslessJPEG => "Cornell-Lossless-JPEG",
-            Self::OGDLTaiwanOneDotZero => "OGDL-Taiwan-1.0",
-            Self::BSDThreeClauseHP => "BSD-3-Clause-HP",
-            Self::Plexus => "Plexus",
-            Self::BcryptSolarDesigner => "bcrypt-Solar-Designer",
-            Self::CCBYNCSATwoDotZeroUK => "CC-BY-NC-SA-2.0-UK",
-            Self::SGIBTwoDotZero => "SGI-B-2.0",
-            Self::CCBYThreeDotZeroIGO => "CC-BY-3.0-IGO",
-            Self::HippocraticTwoDotOne => "Hippocratic-2.1",
-            Self::SHLTwoDotOne => "SHL-2.1",
-            Self::KiCadLibrariesException => "KiCad-libraries-exception",
+            Self::CNRIPythonGPLCompatible => "CNRI-Python-GPL-Compatible",
+            Self::COILOneDotZero => "COIL-1.0",
+            Self::CPALOneDotZero => "CPAL-1.0",
+            Self::CPLOneDotZero => "CPL-1.0",
             Self::CPOLOneDotZeroTwo => "CPOL-1.02",
-            Self::DeprecatedGPLOneDotZero => "deprecated_GPL-1.0",
-            Self::Cronyx => "Cronyx",
-            Self::LatexTwoeTranslatedNotice => "Latex2e-translated-notice",
-            Self::KnuthCTAN => "Knuth-CTAN",
-            Self::CCBYThreeDotZero => "CC-BY-3.0",
-            Self::BzipTwoOneDotZeroDotSix => "bzip2-1.0.6",
-            Self::OCCTPL => "OCCT-PL",
-            Self::SendmailEightDotTwoThree => "Sendmail-8.23",
+            Self::CUAOPLOneDotZero => "CUA-OPL-1.0",
+            Self::Caldera => "Caldera",
+            Self::CalderaNoPreamble => "Caldera-no-preamble",
             Self::Catharon => "Catharon",
-            Self::IPLOneDotZero => "IPL-1.0",
-            Self::DeprecatedGPLTwoDotZeroWithFontException => {
-                "deprecated_GPL-2.0-with-font-exception"
-            }
-            Self::APAFML => "APAFML",
-            Self::Motosoto => "Motosoto",
-            Self::CheckCvs => "check-cvs",
-            Self::Sendmail => "Sendmail",
-            Self::PPL => "PPL",
-            Self::PostgreSql => "PostgreSQL",
-            Self::CDLOneDotZero => "CDL-1.0",
-            Self::GPLOneDotZeroOrLater => "GPL-1.0-or-later",
-            Self::CCSAOneDotZero => "CC-SA-1.0",
-            Self::IBMPibs => "IBM-pibs",
-            Self::CERNOHLOneDotTwo => "CERN-OHL-1.2",
-            Self::IntelACPI => "Intel-ACPI",
-            Self::AdobeTwoZeroZeroSix => "Adobe-2006",
-            Self::DeprecatedLGPLTwoDotZeroPlus => "deprecated_LGPL-2.0+",
-            Self::SPLOneDotZero => "SPL-1.0",
-            Self::OML => "OML",
-            Self::DOC => "DOC",
-            Self::MITClick => "MIT-Click",
-            Self::MxmlException => "mxml-exception",
-            Self::ApacheTwoDotZero => "Apache-2.0",
-            Self::NCGLUKTwoDotZero => "NCGL-UK-2.0",
-            Self::HTMLTIDY => "HTMLTIDY",
-            Self::ANTLRPD => "ANTLR-PD",
-            Self::LALOneDotThree => "LAL-1.3",
-            Self::BSDInfernoNettverk => "BSD-Inferno-Nettverk",
-            Self::RPLOneDotFive => "RPL-1.5",
-            Self::CcZeroOneDotZero => "CC0-1.0",
-            Self::CECILLOneDotOne => "CECILL-1.1",
-            Self::AMLGlslang => "AML-glslang",
-            Self::DigiaQtLGPLExceptionOneDotOne => "Digia-Qt-LGPL-exception-1.1",
+            Self::ClArtistic => "ClArtistic",
+            Self::Clips => "Clips",
+            Self::CommunitySpecOneDotZero => "Community-Spec-1.0",
+            Self::CondorOneDotOne => "Condor-1.1",
+            Self::CornellLosslessJPEG => "Cornell-Lossless-JPEG",
+            Self::Cronyx => "Cronyx",
+            Self::Crossword => "Crossword",
+            Self::CryptoSwift => "CryptoSwift",
+            Self::CrystalStacker => "CrystalStacker",
+            Self::Cube => "Cube",
+            Self::DFSLOneDotZero => "D-FSL-1.0",
+            Self::DECThreeClause => "DEC-3-Clause",
+            Self::DLDEBYTwoDotZero => "DL-DE-BY-2.0",
+            Self::DLDEZEROTwoDotZero => "DL-DE-ZERO-2.0",
+            Self::DOC => "DOC",
+            Self::DRLOneDotZero => "DRL-1.0",
+            Self::DRLOneDotOne => "DRL-1.1",
+            Self::DSDP => "DSDP",
+            Self::DocBookDTD => "DocBook-DTD",
+            Self::DocBookSchema => "DocBook-Schema",
+            Self::DocBookStylesheet => "DocBook-Stylesheet",
+            Self::DocBookXML => "DocBook-XML",
+            Self::Dotseqn => "Dotseqn",
+            Self::ECLOneDotZero => "ECL-1.0",
+            Self::ECLTwoDotZero => "ECL-2.0",
+            Self::EFLOneDotZero => "EFL-1.0",
+            Self::EFLTwoDotZero => "EFL-2.0",
+            Self::EPICS => "EPICS",
+            Self::EPLOneDotZero => "EPL-1.0",
+            Self::EPLTwoDotZero => "EPL-2.0",
+            Self::EuDatagrid => "EUDatagrid",
+            Self::EUPLOneDotZero => "EUPL-1.0",
+            Self::EUPLOneDotOne => "EUPL-1.1",
+            Self::EUPLOneDotTwo => "EUPL-1.2",
+            Self::ElasticTwoDotZero => "Elastic-2.0",
+            Self::Entessa => "Entessa",
+            Self::ErlPlOneDotOne => "ErlPL-1.1",
+            Self::Eurosym => "Eurosym",
+            Self::FBM => "FBM",
+            Self::FDKAAC => "FDK-AAC",
+            Self::FSFAP => "FSFAP",
+            Self::FSFAPNoWarrantyDisclaimer => "FSFAP-no-warranty-disclaimer",
+            Self::FSFUL => "FSFUL",
+            Self::FSFULLR => "FSFULLR",
+            Self::FSFULLRWD => "FSFULLRWD",
+            Self::FSLOneDotOneALvTwo => "FSL-1.1-ALv2",
+            Self::FSLOneDotOneMIT => "FSL-1.1-MIT",
+            Self::FTL => "FTL",
+            Self::Fair => "Fair",
+            Self::FergusonTwofish => "Ferguson-Twofish",
+            Self::FrameworxOneDotZero => "Frameworx-1.0",
+            Self::FreeBsdDOC => "FreeBSD-DOC",
+            Self::FreeImage => "FreeImage",
+            Self::Furuseth => "Furuseth",
             Self::GCRDocs => "GCR-docs",
+            Self::GD => "GD",
+            Self::GFDLOneDotOneInvariantsOnly => "GFDL-1.1-invariants-only",
+            Self::GFDLOneDotOneInvariantsOrLater => "GFDL-1.1-invariants-or-later",
+            Self::GFDLOneDotOneNoInvariantsOnly => "GFDL-1.1-no-invariants-only",
+            Self::GFDLOneDotOneNoInvariantsOrLater => "GFDL-1.1-no-invariants-or-later",
+            Self::GFDLOneDotOneOnly => "GFDL-1.1-only",
+            Self::GFDLOneDotOneOrLater => "GFDL-1.1-or-later",
+            Self::GFDLOneDotTwoInvariantsOnly => "GFDL-1.2-invariants-only",
+            Self::GFDLOneDotTwoInvariantsOrLater => "GFDL-1.2-invariants-or-later",
+            Self::GFDLOneDotTwoNoInvariantsOnly => "GFDL-1.2-no-invariants-only",
+            Self::GFDLOneDotTwoNoInvariantsOrLater => "GFDL-1.2-no-invariants-or-later",
+            Self::GFDLOneDotTwoOnly => "GFDL-1.2-only",
+            Self::GFDLOneDotTwoOrLater => "GFDL-1.2-or-later",
+            Self::GFDLOneDotThreeInvariantsOnly => "GFDL-1.3-invariants-only",
+            Self::GFDLOneDotThreeInvariantsOrLater => "GFDL-1.3-invariants-or-later",
+            Self::GFDLOneDotThreeNoInvariantsOnly => "GFDL-1.3-no-invariants-only",
+            Self::GFDLOneDotThreeNoInvariantsOrLater => "GFDL-1.3-no-invariants-or-later",
+            Self::GFDLOneDotThreeOnly => "GFDL-1.3-only",
+            Self::GFDLOneDotThreeOrLater => "GFDL-1.3-or-later",
+            Self::GlTwoPs => "GL2PS",
+            Self::GLWTPL => "GLWTPL",
+            Self::GPLOneDotZeroOnly => "GPL-1.0-only",
+            Self::GPLOneDotZeroOrLater => "GPL-1.0-or-later",
+            Self::GPLTwoDotZeroOnly => "GPL-2.0-only",
+            Self::GPLTwoDotZeroOrLater => "GPL-2.0-or-later",
+            Self::GPLThreeDotZeroOnly => "GPL-3.0-only",
+            Self::GPLThreeDotZeroOrLater => "GPL-3.0-or-later",
+            Self::GameProgrammingGems => "Game-Programming-Gems",
+            Self::Giftware => "Giftware",
             Self::Glide => "Glide",
-            Self::CCBYSATwoDotZeroUK => "CC-BY-SA-2.0-UK",
             Self::Glulxe => "Glulxe",
-            Self::MITZero => "MIT-0",
-            Self::BSDFourClauseUC => "BSD-4-Clause-UC",
-            Self::DeprecatedLGPLTwoDotOne => "deprecated_LGPL-2.1",
-            Self::AFLTwoDotOne => "AFL-2.1",
-            Self::ECosExceptionTwoDotZero => "eCos-exception-2.0",
-            Self::CERNOHLWTwoDotZero => "CERN-OHL-W-2.0",
-            Self::Minpack => "Minpack",
-            Self::HPNDNetrek => "HPND-Netrek",
-            Self::DocBookSchema => "DocBook-Schema",
-            Self::ManTwohtml => "man2html",
-            Self::LGPLTwoDotOneOrLater => "LGPL-2.1-or-later",
-            Self::CMUMach => "CMU-Mach",
-            Self::OLDAPTwoDotZero => "OLDAP-2.0",
-            Self::Borceux => "Borceux",
-            Self::PHPThreeDotZero => "PHP-3.0",
-            Self::HPNDDEC => "HPND-DEC",
-            Self::Radvd => "radvd",
-            Self::OGTSL => "OGTSL",
-            Self::OLDAPTwoDotSeven => "OLDAP-2.7",
-            Self::OGLUKTwoDotZero => "OGL-UK-2.0",
-            Self::LGPLThreeDotZeroLinkingException => "LGPL-3.0-linking-exception",
-            Self::TtypZero => "TTYP0",
-            Self::Zed => "Zed",
-            Self::PADL => "PADL",
-            Self::OFLOneDotOneRFN => "OFL-1.1-RFN",
-            Self::OLDAPTwoDotTwoDotTwo => "OLDAP-2.2.2",
-            Self::Diffmark => "diffmark",
-            Self::WThreeC => "W3C",
-            Self::HPNDSellVariantMITDisclaimer => "HPND-sell-variant-MIT-disclaimer",
-            Self::ArtisticOneDotZeroPerl => "Artistic-1.0-Perl",
-            Self::OCamlLGPLLinkingException => "OCaml-LGPL-linking-exception",
-            Self::PythonTwoDotZero => "Python-2.0",
-            Self::MackerrasThreeClauseAcknowledgment => "Mackerras-3-Clause-acknowledgment",
-            Self::CCBYNCNDOneDotZero => "CC-BY-NC-ND-1.0",
-            Self::CCBYNDOneDotZero => "CC-BY-ND-1.0",
-            Self::Mup => "Mup",
-            Self::TPDL => "TPDL",
+            Self::GraphicsGems => "Graphics-Gems",
+            Self::Gutmann => "Gutmann",
             Self::HIDAPI => "HIDAPI",
-            Self::InfoZIP => "Info-ZIP",
-            Self::DeprecatedLGPLThreeDotZero => "deprecated_LGPL-3.0",
-            Self::AFLThreeDotZero => "AFL-3.0",
+            Self::HPOneNineEightSix => "HP-1986",
             Self::HPOneNineEightNine => "HP-1989",
-            Self::FSFULLRWD => "FSFULLRWD",
-            Self::Leptonica => "Leptonica",
-            Self::GraphicsGems => "Graphics-Gems",
-            Self::UnicodeDFSTwoZeroOneFive => "Unicode-DFS-2015",
-            Self::TrustedQsl => "TrustedQSL",
-            Self::APSLTwoDotZero => "APSL-2.0",
-            Self::NLPL => "NLPL",
-            Self::Giftware => "Giftware",
-            Self::SSPLOneDotZero => "SSPL-1.0",
-            Self::CCBYTwoDotFiveAU => "CC-BY-2.5-AU",
+            Self::HPND => "HPND",
+            Self::HPNDDEC => "HPND-DEC",
+            Self::HPNDFennebergLivingston => "HPND-Fenneberg-Livingston",
+            Self::HPNDINRIAIMAG => "HPND-INRIA-IMAG",
+            Self::HPNDIntel => "HPND-Intel",
+            Self::HPNDKevlinHenney => "HPND-Kevlin-Henney",
+            Self::HPNDMITDisclaimer => "HPND-MIT-disclaimer",
+            Self::HPNDMarkusKuhn => "HPND-Markus-Kuhn",
+            Self::HPNDNetrek => "HPND-Netrek",
+            Self::HPNDPbmplus => "HPND-Pbmplus",
+            Self::HPNDUC => "HPND-UC",
+            Self::HPNDUCExportUS => "HPND-UC-export-US",
+            Self::HPNDDoc => "HPND-doc",
+            Self::HPNDDocSell => "HPND-doc-sell",
+            Self::HPNDExportUS => "HPND-export-US",
+            Self::HPNDExportUSAcknowledgement => "HPND-export-US-acknowledgement",
+            Self::HPNDExportUSModify => "HPND-export-US-modify",
+            Self::HPNDExportTwoUS => "HPND-export2-US",
+            Self::HPNDMerchantabilityVariant => "HPND-merchantability-variant",
+            Self::HPNDSellMITDisclaimerXserver => "HPND-sell-MIT-disclaimer-xserver",
+            Self::HPNDSellRegexpr => "HPND-sell-regexpr",
+            Self::HPNDSellVariant => "HPND-sell-variant",
+            Self::HPNDSellVariantMITDisclaimer => "HPND-sell-variant-MIT-disclaimer",
             Self::HPNDSellVariantMITDisclaimerRev => "HPND-sell-variant-MIT-disclaimer-rev",
-            Self::DeprecatedGPLOneDotZeroPlus => "deprecated_GPL-1.0+",
-            Self::Libtiff => "libtiff",
+            Self::HTMLTIDY => "HTMLTIDY",
+            Self::HaskellReport => "HaskellReport",
+            Self::HippocraticTwoDotOne => "Hippocratic-2.1",
+            Self::IBMPibs => "IBM-pibs",
+            Self::ICU => "ICU",
+            Self::IECCodeComponentsEULA => "IEC-Code-Components-EULA",
+            Self::IJG => "IJG",
+            Self::IJGShort => "IJG-short",
             Self::IPA => "IPA",
-            Self::CCBYSAOneDotZero => "CC-BY-SA-1.0",
-            Self::CCBYNCSAOneDotZero => "CC-BY-NC-SA-1.0",
-            Self::ErlPlOneDotOne => "ErlPL-1.1",
-            Self::MPLTwoDotZero => "MPL-2.0",
-            Self::Pkgconf => "pkgconf",
-            Self::BSDProtection => "BSD-Protection",
-            Self::GFDLOneDotTwoNoInvariantsOnly => "GFDL-1.2-no-invariants-only",
-            Self::AdobeGlyph => "Adobe-Glyph",
-            Self::EPLOneDotZero => "EPL-1.0",
-            Self::DeprecatedLGPLTwoDotOnePlus => "deprecated_LGPL-2.1+",
-            Self::ITwopGplJavaException => "i2p-gpl-java-exception",
-            Self::DeprecatedGPLThreeDotZeroWithGCCException => {
-                "deprecated_GPL-3.0-with-GCC-exception"
-            }
-            Self::AMDNewlib => "AMD-newlib",
+            Self::IPLOneDotZero => "IPL-1.0",
+            Self::ISC => "ISC",
+            Self::ISCVeillard => "ISC-Veillard",
+            Self::ImageMagick => "ImageMagick",
+            Self::ImlibTwo => "Imlib2",
+            Self::InfoZIP => "Info-ZIP",
+            Self::InnerNetTwoDotZero => "Inner-Net-2.0",
+            Self::InnoSetup => "InnoSetup",
+            Self::Intel => "Intel",
+            Self::IntelACPI => "Intel-ACPI",
+            Self::InterbaseOneDotZero => "Interbase-1.0",
+            Self::JPLImage => "JPL-image",
+            Self::JPNIC => "JPNIC",
+            Self::JSON => "JSON",
             Self::Jam => "Jam",
-            Self::DeprecatedAGPLOneDotZero => "deprecated_AGPL-1.0",
-            Self::Baekmuk => "Baekmuk",
-            Self::Qhull => "Qhull",
-            Self::OpenSslStandalone => "OpenSSL-standalone",
-            Self::PcreTwoException => "PCRE2-exception",
-            Self::BSDTwoClausePkgconfDisclaimer => "BSD-2-Clause-pkgconf-disclaimer",
-            Self::NBPLOneDotZero => "NBPL-1.0",
-            Self::MulanPslTwoDotZero => "MulanPSL-2.0",
-            Self::CCBYThreeDotZeroUS => "CC-BY-3.0-US",
+            Self::JasPerTwoDotZero => "JasPer-2.0",
+            Self::Kastrup => "Kastrup",
+            Self::Kazlib => "Kazlib",
+            Self::KnuthCTAN => "Knuth-CTAN",
+            Self::LALOneDotTwo => "LAL-1.2",
+            Self::LALOneDotThree => "LAL-1.3",
+            Self::LGPLTwoDotZeroOnly => "LGPL-2.0-only",
+            Self::LGPLTwoDotZeroOrLater => "LGPL-2.0-or-later",
+            Self::LGPLTwoDotOneOnly => "LGPL-2.1-only",
+            Self::LGPLTwoDotOneOrLater => "LGPL-2.1-or-later",
+            Self::LGPLThreeDotZeroOnly => "LGPL-3.0-only",
+            Self::LGPLThreeDotZeroOrLater => "LGPL-3.0-or-later",
+            Self::LGPLLR => "LGPLLR",
+            Self::LOOP => "LOOP",
+            Self::LPDDocument => "LPD-document",
+            Self::LPLOneDotZero => "LPL-1.0",
+            Self::LPLOneDotZeroTwo => "LPL-1.02",
+            Self::LPPLOneDotZero => "LPPL-1.0",
+            Self::LPPLOneDotOne => "LPPL-1.1",
+            Self::LPPLOneDotTwo => "LPPL-1.2",
+            Self::LPPLOneDotThreea => "LPPL-1.3a",
+            Self::LPPLOneDotThreec => "LPPL-1.3c",
+            Self::LZMASDKNineDotOneOneToNineDotTwoZero => "LZMA-SDK-9.11-to-9.20",
+            Self::LZMASDKNineDotTwoTwo => "LZMA-SDK-9.22",
+            Self::LatexTwoe => "Latex2e",
+            Self::LatexTwoeTranslatedNotice => "Latex2e-translated-notice",
+            Self::Leptonica => "Leptonica",
+            Self::LiLiQPOneDotOne => "LiLiQ-P-1.1",
+            Self::LiLiQROneDotOne => "LiLiQ-R-1.1",
+            Self::LiLiQRplusOneDotOne => "LiLiQ-Rplus-1.1",
+            Self::Libpng => "Libpng",
+            Self::LinuxOpenIb => "Linux-OpenIB",
+            Self::LinuxManPagesOnePara => "Linux-man-pages-1-para",
+            Self::LinuxManPagesCopyleft => "Linux-man-pages-copyleft",
+            Self::LinuxManPagesCopyleftTwoPara => "Linux-man-pages-copyleft-2-para",
+            Self::LinuxManPagesCopyleftVar => "Linux-man-pages-copyleft-var",
             Self::LucidaBitmapFonts => "Lucida-Bitmap-Fonts",
-            Self::CCBYNCSATwoDotZeroFR => "CC-BY-NC-SA-2.0-FR",
-            Self::ANTLRPDFallback => "ANTLR-PD-fallback",
+            Self::MIPS => "MIPS",
+            Self::MIT => "MIT",
+            Self::MITZero => "MIT-0",
+            Self::MITCMU => "MIT-CMU",
+            Self::MITClick => "MIT-Click",
+            Self::MITFestival => "MIT-Festival",
+            Self::MITKhronosOld => "MIT-Khronos-old",
+            Self::MITModernVariant => "MIT-Modern-Variant",
+            Self::MITWu => "MIT-Wu",
             Self::MITAdvertising => "MIT-advertising",
-            Self::HPNDExportUSModify => "HPND-export-US-modify",
-            Self::Swrule => "swrule",
-            Self::Beerware => "Beerware",
-            Self::SMLNJ => "SMLNJ",
-            Self::MPEGSSG => "MPEG-SSG",
-            Self::PolyFormSmallBusinessOneDotZeroDotZero => "PolyForm-Small-Business-1.0.0",
-            Self::AGPLThreeDotZeroOrLater => "AGPL-3.0-or-later",
-            Self::BSDAdvertisingAcknowledgement => "BSD-Advertising-Acknowledgement",
-            Self::ZPLTwoDotZero => "ZPL-2.0",
-            Self::Xpp => "xpp",
-            Self::Nokia => "Nokia",
-            Self::HPNDKevlinHenney => "HPND-Kevlin-Henney",
-            Self::PolyFormNoncommercialOneDotZeroDotZero => "PolyForm-Noncommercial-1.0.0",
-            Self::CCBYSATwoDotOneJP => "CC-BY-SA-2.1-JP",
-            Self::XkeyboardConfigZinoviev => "xkeyboard-config-Zinoviev",
-            Self::NAISTTwoZeroZeroThree => "NAIST-2003",
-            Self::HPNDExportUS => "HPND-export-US",
-            Self::GStreamerExceptionTwoZeroZeroEight => "GStreamer-exception-2008",
-            Self::DeprecatedBSDTwoClauseNetBsd => "deprecated_BSD-2-Clause-NetBSD",
-            Self::WxWindowsExceptionThreeDotOne => "WxWindows-exception-3.1",
-            Self::LibselinuxOneDotZero => "libselinux-1.0",
-            Self::QPLOneDotZero => "QPL-1.0",
-            Self::Pixar => "Pixar",
-            Self::FawkesRuntimeException => "Fawkes-Runtime-exception",
-            Self::CCPDDC => "CC-PDDC",
-            Self::Xerox => "Xerox",
-            Self::BSDThreeClauseNoNuclearWarranty => "BSD-3-Clause-No-Nuclear-Warranty",
-            Self::AnyOSIPerlModules => "any-OSI-perl-modules",
-            Self::BUSLOneDotOne => "BUSL-1.1",
+            Self::MITEnna => "MIT-enna",
+            Self::MITFeh => "MIT-feh",
+            Self::MITOpenGroup => "MIT-open-group",
+            Self::MITTestregex => "MIT-testregex",
+            Self::MITNFA => "MITNFA",
+            Self::MmiXware => "MMIXware",
+            Self::MPEGSSG => "MPEG-SSG",
+            Self::MPLOneDotZero => "MPL-1.0",
+            Self::MPLOneDotOne => "MPL-1.1",
+            Self::MPLTwoDotZero => "MPL-2.0",
+            Self::MPLTwoDotZeroNoCopyleftException => "MPL-2.0-no-copyleft-exception",
+            Self::MSLPL => "MS-LPL",
+            Self::MSPL => "MS-PL",
+            Self::MSRL => "MS-RL",
+            Self::MTLL => "MTLL",
+            Self::MackerrasThreeClause => "Mackerras-3-Clause",
+            Self::MackerrasThreeClauseAcknowledgment => "Mackerras-3-Clause-acknowledgment",
             Self::MakeIndex => "MakeIndex",
+            Self::MartinBirgmeier => "Martin-Birgmeier",
+            Self::McPheeSlideshow => "McPhee-slideshow",
+            Self::Minpack => "Minpack",
+            Self::MirOs => "MirOS",
+            Self::Motosoto => "Motosoto",
+            Self::MulanPslOneDotZero => "MulanPSL-1.0",
+            Self::MulanPslTwoDotZero => "MulanPSL-2.0",
+            Self::Multics => "Multics",
+            Self::Mup => "Mup",
+            Self::NAISTTwoZeroZeroThree => "NAIST-2003",
+            Self::NASAOneDotThree => "NASA-1.3",
+            Self::NBPLOneDotZero => "NBPL-1.0",
+            Self::NCBIPD => "NCBI-PD",
+            Self::NCGLUKTwoDotZero => "NCGL-UK-2.0",
+            Self::NCL => "NCL",
+            Self::NCSA => "NCSA",
+            Self::NGPL => "NGPL",
+            Self::NICTAOneDotZero => "NICTA-1.0",
+            Self::NISTPD => "NIST-PD",
+            Self::NISTPDFallback => "NIST-PD-fallback",
+            Self::NISTSoftware => "NIST-Software",
+            Self::NLODOneDotZero => "NLOD-1.0",
+            Self::NLODTwoDotZero => "NLOD-2.0",
+            Self::NLPL => "NLPL",
+            Self::NOSL => "NOSL",
+            Self::NPLOneDotZero => "NPL-1.0",
+            Self::NPLOneDotOne => "NPL-1.1",
+            Self::NPOSLThreeDotZero => "NPOSL-3.0",
+            Self::NRL => "NRL",
             Self::NTIAPD => "NTIA-PD",
-            Self::LGPLThreeDotZeroOrLater => "LGPL-3.0-or-later",
-            Self::DeprecatedNunit => "deprecated_Nunit",
+            Self::NTP => "NTP",
+            Self::NTPZero => "NTP-0",
+            Self::Naumen => "Naumen",
+            Self::NetCdf => "NetCDF",
+            Self::Newsletr => "Newsletr",
+            Self::Nokia => "Nokia",
+            Self::Noweb => "Noweb",
+            Self::OUDAOneDotZero => "O-UDA-1.0",
+            Self::OAR => "OAR",
+            Self::OCCTPL => "OCCT-PL",
+            Self::OCLCTwoDotZero => "OCLC-2.0",
+            Self::ODCByOneDotZero => "ODC-By-1.0",
+            Self::ODbLOneDotZero => "ODbL-1.0",
             Self::OFFIS => "OFFIS",
-            Self::LatexTwoe => "Latex2e",
-            Self::SSHOpenSsh => "SSH-OpenSSH",
-            Self::Entessa => "Entessa",
-            Self::AFLTwoDotZero => "AFL-2.0",
-            Self::DeprecatedLGPLTwoDotZero => "deprecated_LGPL-2.0",
-            Self::ZendTwoDotZero => "Zend-2.0",
-            Self::MifException => "mif-exception",
-            Self::Xfig => "Xfig",
-            Self::Caldera => "Caldera",
-            Self::LibutilDavidNugent => "libutil-David-Nugent",
-            Self::AsteriskLinkingProtocolsException => "Asterisk-linking-protocols-exception",
-            Self::OLDAPTwoDotSix => "OLDAP-2.6",
-            Self::ZeroBsd => "0BSD",
-            Self::GNUCompilerException => "GNU-compiler-exception",
-            Self::Barr => "Barr",
-            Self::SGIOpenGl => "SGI-OpenGL",
-            Self::CCPDMOneDotZero => "CC-PDM-1.0",
-            Self::MITEnna => "MIT-enna",
-            Self::NISTPD => "NIST-PD",
-            Self::CCBYTwoDotFive => "CC-BY-2.5",
-            Self::HPNDSellRegexpr => "HPND-sell-regexpr",
-            Self::LOOP => "LOOP",
+            Self::OFLOneDotZero => "OFL-1.0",
+            Self::OFLOneDotZeroRFN => "OFL-1.0-RFN",
+            Self::OFLOneDotZeroNoRFN => "OFL-1.0-no-RFN",
+            Self::OFLOneDotOne => "OFL-1.1",
+            Self::OFLOneDotOneRFN => "OFL-1.1-RFN",
+            Self::OFLOneDotOneNoRFN => "OFL-1.1-no-RFN",
+            Self::OGCOneDotZero => "OGC-1.0",
+            Self::OGDLTaiwanOneDotZero => "OGDL-Taiwan-1.0",
+            Self::OGLCanadaTwoDotZero => "OGL-Canada-2.0",
+            Self::OGLUKOneDotZero => "OGL-UK-1.0",
+            Self::OGLUKTwoDotZero => "OGL-UK-2.0",
+            Self::OGLUKThreeDotZero => "OGL-UK-3.0",
+            Self::OGTSL => "OGTSL",
+            Self::OLDAPOneDotOne => "OLDAP-1.1",
+            Self::OLDAPOneDotTwo => "OLDAP-1.2",
+            Self::OLDAPOneDotThree => "OLDAP-1.3",
+            Self::OLDAPOneDotFour => "OLDAP-1.4",
+            Self::OLDAPTwoDotZero => "OLDAP-2.0",
+            Self::OLDAPTwoDotZeroDotOne => "OLDAP-2.0.1",
             Self::OLDAPTwoDotOne => "OLDAP-2.1",
-            Self::SISSLOneDotTwo => "SISSL-1.2",
-            Self::ThreeDSlicerOneDotZero => "3D-Slicer-1.0",
-            Self::OCCTExceptionOneDotZero => "OCCT-exception-1.0",
-            Self::ThreeEightNineException => "389-exception",
-            Self::SL => "SL",
-            Self::FLTKException => "FLTK-exception",
-            Self::GFDLOneDotOneOnly => "GFDL-1.1-only",
-            Self::CALOneDotZeroCombinedWorkException => "CAL-1.0-Combined-Work-Exception",
-            Self::DeprecatedStandardMlNJ => "deprecated_StandardML-NJ",
-            Self::ADSL => "ADSL",
-            Self::BSDFourDotThreeTahoe => "BSD-4.3TAHOE",
-            Self::ZPLTwoDotOne => "ZPL-2.1",
-            Self::ImlibTwo => "Imlib2",
-            Self::RPLOneDotOne => "RPL-1.1",
-            Self::Gnuplot => "gnuplot",
-            Self::DFSLOneDotZero => "D-FSL-1.0",
-            Self::AdobeUtopia => "Adobe-Utopia",
-            Self::OpenSsl => "OpenSSL",
-            Self::GPLThreeDotZeroOrLater => "GPL-3.0-or-later",
-            Self::OSETPLTwoDotOne => "OSET-PL-2.1",
-            Self::LZMASDKNineDotOneOneToNineDotTwoZero => "LZMA-SDK-9.11-to-9.20",
-            Self::SAXPDTwoDotZero => "SAX-PD-2.0",
-            Self::BSDThreeClauseClear => "BSD-3-Clause-Clear",
-            Self::NASAOneDotThree => "NASA-1.3",
-            Self::EuDatagrid => "EUDatagrid",
-            Self::CERNOHLOneDotOne => "CERN-OHL-1.1",
-            Self::EtalabTwoDotZero => "etalab-2.0",
-            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => {
-                "deprecated_GPL-3.0-with-autoconf-exception"
-            }
-            Self::CERNOHLSTwoDotZero => "CERN-OHL-S-2.0",
-            Self::Gutmann => "Gutmann",
+            Self::OLDAPTwoDotTwo => "OLDAP-2.2",
+            Self::OLDAPTwoDotTwoDotOne => "OLDAP-2.2.1",
+            Self::OLDAPTwoDotTwoDotTwo => "OLDAP-2.2.2",
+            Self::OLDAPTwoDotThree => "OLDAP-2.3",
+            Self::OLDAPTwoDotFour => "OLDAP-2.4",
+            Self::OLDAPTwoDotFive => "OLDAP-2.5",
+            Self::OLDAPTwoDotSix => "OLDAP-2.6",
+            Self::OLDAPTwoDotSeven => "OLDAP-2.7",
+            Self::OLDAPTwoDotEight => "OLDAP-2.8",
             Self::OLFLOneDotThree => "OLFL-1.3",
-            Self::LinuxManPagesCopyleftVar => "Linux-man-pages-copyleft-var",
-            Self::OGLUKThreeDotZero => "OGL-UK-3.0",
+            Self::OML => "OML",
+            Self::OPLOneDotZero => "OPL-1.0",
+            Self::OPLUKThreeDotZero => "OPL-UK-3.0",
+            Self::OPUBLOneDotZero => "OPUBL-1.0",
+            Self::OSETPLTwoDotOne => "OSET-PL-2.1",
+            Self::OSLOneDotZero => "OSL-1.0",
+            Self::OSLOneDotOne => "OSL-1.1",
+            Self::OSLTwoDotZero => "OSL-2.0",
+            Self::OSLTwoDotOne => "OSL-2.1",
+            Self::OSLThreeDotZero => "OSL-3.0",
+            Self::OpenPbsTwoDotThree => "OpenPBS-2.3",
+            Self::OpenSsl => "OpenSSL",
+            Self::OpenSslStandalone => "OpenSSL-standalone",
+            Self::OpenVision => "OpenVision",
+            Self::PADL => "PADL",
+            Self::PDDLOneDotZero => "PDDL-1.0",
+            Self::PHPThreeDotZero => "PHP-3.0",
+            Self::PHPThreeDotZeroOne => "PHP-3.01",
+            Self::PPL => "PPL",
+            Self::PSFTwoDotZero => "PSF-2.0",
             Self::ParitySixDotZeroDotZero => "Parity-6.0.0",
-            Self::LPPLOneDotThreea => "LPPL-1.3a",
-            Self::Mplus => "mplus",
-            Self::COILOneDotZero => "COIL-1.0",
-            Self::Gtkbook => "gtkbook",
-            Self::XSkat => "XSkat",
-            Self::HPNDDocSell => "HPND-doc-sell",
-            Self::RSAMD => "RSA-MD",
-            Self::CCBYSAThreeDotZeroAT => "CC-BY-SA-3.0-AT",
-            Self::NISTPDFallback => "NIST-PD-fallback",
-            Self::IMatix => "iMatix",
-            Self::GPLThreeDotZeroOnly => "GPL-3.0-only",
-            Self::CryptoSwift => "CryptoSwift",
-            Self::WTFPL => "WTFPL",
-            Self::BSDThreeClauseNoNuclearLicense => "BSD-3-Clause-No-Nuclear-License",
-            Self::CveTou => "cve-tou",
-            Self::HPNDMITDisclaimer => "HPND-MIT-disclaimer",
-            Self::CCBYNDTwoDotZero => "CC-BY-ND-2.0",
-            Self::Vim => "Vim",
-            Self::NISTSoftware => "NIST-Software",
-            Self::LPPLOneDotZero => "LPPL-1.0",
-            Self::YPLOneDotOne => "YPL-1.1",
-            Self::CCBYNCNDTwoDotZero => "CC-BY-NC-ND-2.0",
-            Self::CopyleftNextZeroDotThreeDotOne => "copyleft-next-0.3.1",
-            Self::MITCMU => "MIT-CMU",
+            Self::ParitySevenDotZeroDotZero => "Parity-7.0.0",
+            Self::Pixar => "Pixar",
+            Self::Plexus => "Plexus",
+            Self::PolyFormNoncommercialOneDotZeroDotZero => "PolyForm-Noncommercial-1.0.0",
+            Self::PolyFormSmallBusinessOneDotZeroDotZero => "PolyForm-Small-Business-1.0.0",
+            Self::PostgreSql => "PostgreSQL",
+            Self::PythonTwoDotZero => "Python-2.0",
+            Self::PythonTwoDotZeroDotOne => "Python-2.0.1",
+            Self::QPLOneDotZero => "QPL-1.0",
+            Self::QPLOneDotZeroINRIATwoZeroZeroFour => "QPL-1.0-INRIA-2004",
+            Self::Qhull => "Qhull",
+            Self::RHeCosOneDotOne => "RHeCos-1.1",
+            Self::RPLOneDotOne => "RPL-1.1",
+            Self::RPLOneDotFive => "RPL-1.5",
             Self::RPSLOneDotZero => "RPSL-1.0",
-            Self::BSDTwoClausePatent => "BSD-2-Clause-Patent",
-            Self::Dtoa => "dtoa",
-            Self::NCSA => "NCSA",
-            Self::NPLOneDotOne => "NPL-1.1",
+            Self::RSAMD => "RSA-MD",
+            Self::RSCPL => "RSCPL",
+            Self::Rdisc => "Rdisc",
+            Self::Ruby => "Ruby",
+            Self::RubyPty => "Ruby-pty",
+            Self::SAXPD => "SAX-PD",
+            Self::SAXPDTwoDotZero => "SAX-PD-2.0",
             Self::SCEA => "SCEA",
+            Self::SGIBOneDotZero => "SGI-B-1.0",
+            Self::SGIBOneDotOne => "SGI-B-1.1",
+            Self::SGIBTwoDotZero => "SGI-B-2.0",
+            Self::SGIOpenGl => "SGI-OpenGL",
+            Self::SgpFour => "SGP4",
+            Self::SHLZeroDotFive => "SHL-0.5",
+            Self::SHLZeroDotFiveOne => "SHL-0.51",
+            Self::SISSL => "SISSL",
+            Self::SISSLOneDotTwo => "SISSL-1.2",
+            Self::SL => "SL",
+            Self::SMAILGPL => "SMAIL-GPL",
+            Self::SMLNJ => "SMLNJ",
             Self::SMPPL => "SMPPL",
-            Self::LiLiQROneDotOne => "LiLiQ-R-1.1",
-            Self::OFLOneDotZeroRFN => "OFL-1.0-RFN",
-            Self::NPOSLThreeDotZero => "NPOSL-3.0",
-            Self::ImageMagick => "ImageMagick",
-            Self::BSDFourClauseShortened => "BSD-4-Clause-Shortened",
-            Self::AsteriskException => "Asterisk-exception",
-            Self::LibpriOpenHThreeTwoThreeException => "libpri-OpenH323-exception",
-            Self::Aladdin => "Aladdin",
-            Self::UnicodeTOU => "Unicode-TOU",
-            Self::OpenPbsTwoDotThree => "OpenPBS-2.3",
-            Self::AnyOSI => "any-OSI",
-            Self::UCLOneDotZero => "UCL-1.0",
-            Self::ZimbraOneDotFour => "Zimbra-1.4",
-            Self::BootloaderException => "Bootloader-exception",
-            Self::BisonExceptionTwoDotTwo => "Bison-exception-2.2",
-            Self::TGPPLOneDotZero => "TGPPL-1.0",
-            Self::BitTorrentOneDotOne => "BitTorrent-1.1",
-            Self::Wsuipa => "Wsuipa",
-            Self::DeprecatedNokiaQtExceptionOneDotOne => "deprecated_Nokia-Qt-exception-1.1",
-            Self::CCBYNCSATwoDotZeroDE => "CC-BY-NC-SA-2.0-DE",
-            Self::LinuxManPagesCopyleft => "Linux-man-pages-copyleft",
-            Self::Xlock => "xlock",
-            Self::LiLiQRplusOneDotOne => "LiLiQ-Rplus-1.1",
-            Self::GenericXts => "generic-xts",
-            Self::ZimbraOneDotThree => "Zimbra-1.3",
-            Self::GPLTwoDotZeroOnly => "GPL-2.0-only",
-            Self::OGLUKOneDotZero => "OGL-UK-1.0",
-            Self::AFLOneDotOne => "AFL-1.1",
+            Self::SNIA => "SNIA",
+            Self::SPLOneDotZero => "SPL-1.0",
+            Self::SSHOpenSsh => "SSH-OpenSSH",
+            Self::SSHShort => "SSH-short",
             Self::SsLeayStandalone => "SSLeay-standalone",
-            Self::XOneOneSwapped => "X11-swapped",
-            Self::OpenJdkAssemblyExceptionOneDotZero => "OpenJDK-assembly-exception-1.0",
-            Self::GFDLOneDotThreeNoInvariantsOrLater => "GFDL-1.3-no-invariants-or-later",
-            Self::MTLL => "MTLL",
-            Self::UbuntuFontOneDotZero => "Ubuntu-font-1.0",
-            Self::DocBookXML => "DocBook-XML",
-            Self::MITTestregex => "MIT-testregex",
-            Self::CCBYNCTwoDotFive => "CC-BY-NC-2.5",
-            Self::PythonLdap => "python-ldap",
-            Self::GlTwoPs => "GL2PS",
-            Self::LPLOneDotZeroTwo => "LPL-1.02",
-            Self::MITNFA => "MITNFA",
-            Self::Checkmk => "checkmk",
-            Self::GFDLOneDotTwoOnly => "GFDL-1.2-only",
-            Self::NGPL => "NGPL",
-            Self::MulanPslOneDotZero => "MulanPSL-1.0",
-            Self::DeprecatedWxWindows => "deprecated_wxWindows",
-            Self::OGCOneDotZero => "OGC-1.0",
-            Self::Ulem => "ulem",
-            Self::AutoconfExceptionThreeDotZero => "Autoconf-exception-3.0",
-            Self::HarbourException => "harbour-exception",
-            Self::UCAR => "UCAR",
-            Self::MSPL => "MS-PL",
-            Self::JPLImage => "JPL-image",
-            Self::FontExceptionTwoDotZero => "Font-exception-2.0",
-            Self::GFDLOneDotThreeInvariantsOrLater => "GFDL-1.3-invariants-or-later",
-            Self::Fwlw => "fwlw",
-            Self::InnerNetTwoDotZero => "Inner-Net-2.0",
-            Self::MPLOneDotZero => "MPL-1.0",
-            Self::CommunitySpecOneDotZero => "Community-Spec-1.0",
-            Self::CUAOPLOneDotZero => "CUA-OPL-1.0",
-            Self::UBDLException => "UBDL-exception",
-            Self::GFDLOneDotOneInvariantsOnly => "GFDL-1.1-invariants-only",
-            Self::FreeBsdDOC => "FreeBSD-DOC",
-            Self::EPLTwoDotZero => "EPL-2.0",
+            Self::SSPLOneDotZero => "SSPL-1.0",
+            Self::SWL => "SWL",
+            Self::Saxpath => "Saxpath",
+            Self::SchemeReport => "SchemeReport",
+            Self::Sendmail => "Sendmail",
+            Self::SendmailEightDotTwoThree => "Sendmail-8.23",
             Self::SendmailOpenSourceOneDotOne => "Sendmail-Open-Source-1.1",
-            Self::Eurosym => "Eurosym",
-            Self::GPLOneDotZeroOnly => "GPL-1.0-only",
-            Self::DeprecatedBSDTwoClauseFreeBsd => "deprecated_BSD-2-Clause-FreeBSD",
-            Self::SHLZeroDotFive => "SHL-0.5",
-            Self::XOneOne => "X11",
-            Self::ThirdEye => "ThirdEye",
-            Self::FSFAPNoWarrantyDisclaimer => "FSFAP-no-warranty-disclaimer",
             Self::SimPlTwoDotZero => "SimPL-2.0",
-            Self::InterbaseOneDotZero => "Interbase-1.0",
-            Self::DeprecatedBzipTwoOneDotZeroDotFive => "deprecated_bzip2-1.0.5",
-            Self::Multics => "Multics",
-            Self::GStreamerExceptionTwoZeroZeroFive => "GStreamer-exception-2005",
-            Self::CCBYNCSATwoDotZero => "CC-BY-NC-SA-2.0",
-            Self::GD => "GD",
-            Self::CCBYSATwoDotZero => "CC-BY-SA-2.0",
-            Self::CECILLC => "CECILL-C",
-            Self::ElasticTwoDotZero => "Elastic-2.0",
-            Self::MITWu => "MIT-Wu",
-            Self::Snprintf => "snprintf",
-            Self::EUPLOneDotZero => "EUPL-1.0",
-            Self::AMDPLPA => "AMDPLPA",
-            Self::APSLOneDotZero => "APSL-1.0",
-            Self::BSDSourceBeginningFile => "BSD-Source-beginning-file",
+            Self::Sleepycat => "Sleepycat",
+            Self::Soundex => "Soundex",
+            Self::SpencerEightSix => "Spencer-86",
+            Self::SpencerNineFour => "Spencer-94",
+            Self::SpencerNineNine => "Spencer-99",
+            Self::SugarCrmOneDotOneDotThree => "SugarCRM-1.1.3",
+            Self::SunPPP => "Sun-PPP",
+            Self::SunPPPTwoZeroZeroZero => "Sun-PPP-2000",
             Self::SunPro => "SunPro",
-            Self::HPNDIntel => "HPND-Intel",
-            Self::LZMASDKNineDotTwoTwo => "LZMA-SDK-9.22",
-            Self::OFLOneDotZero => "OFL-1.0",
-            Self::DeprecatedGPLTwoDotZeroWithGCCException => {
-                "deprecated_GPL-2.0-with-GCC-exception"
-            }
-            Self::AutoconfExceptionGenericThreeDotZero => "Autoconf-exception-generic-3.0",
-            Self::CCBYSAThreeDotZero => "CC-BY-SA-3.0",
-            Self::CCBYNCSAThreeDotZero => "CC-BY-NC-SA-3.0",
-            Self::Bahyph => "Bahyph",
-            Self::DSDP => "DSDP",
-            Self::McPheeSlideshow => "McPhee-slideshow",
-            Self::BitTorrentOneDotZero => "BitTorrent-1.0",
-            Self::CCBYThreeDotZeroNL => "CC-BY-3.0-NL",
-            Self::OLDAPOneDotOne => "OLDAP-1.1",
-            Self::BitstreamVera => "Bitstream-Vera",
-            Self::GFDLOneDotTwoInvariantsOnly => "GFDL-1.2-invariants-only",
-            Self::SchemeReport => "SchemeReport",
-            Self::HPNDExportTwoUS => "HPND-export2-US",
-            Self::Xinetd => "xinetd",
-            Self::HaskellReport => "HaskellReport",
-            Self::TCL => "TCL",
-            Self::NetCdf => "NetCDF",
             Self::Symlinks => "Symlinks",
-            Self::OpenvpnOpensslException => "openvpn-openssl-exception",
-            Self::ArphicOneNineNineNine => "Arphic-1999",
-            Self::HPNDPbmplus => "HPND-Pbmplus",
-            Self::OAR => "OAR",
-            Self::CopyleftNextZeroDotThreeDotZero => "copyleft-next-0.3.0",
-            Self::YPLOneDotZero => "YPL-1.0",
-            Self::LPPLOneDotOne => "LPPL-1.1",
-            Self::CCBYNDFourDotZero => "CC-BY-ND-4.0",
-            Self::AutoconfExceptionTwoDotZero => "Autoconf-exception-2.0",
-            Self::XFreeEightSixOneDotOne => "XFree86-1.1",
-            Self::CCBYNCNDFourDotZero => "CC-BY-NC-ND-4.0",
-            Self::CCBYNCSAThreeDotZeroDE => "CC-BY-NC-SA-3.0-DE",
+            Self::TAPROHLOneDotZero => "TAPR-OHL-1.0",
+            Self::TCL => "TCL",
+            Self::TCPWrappers => "TCP-wrappers",
+            Self::TGPPLOneDotZero => "TGPPL-1.0",
+            Self::TMate => "TMate",
+            Self::TORQUEOneDotOne => "TORQUE-1.1",
+            Self::TOSL => "TOSL",
+            Self::TPDL => "TPDL",
             Self::TPLOneDotZero => "TPL-1.0",
-            Self::Naumen => "Naumen",
-            Self::NICTAOneDotZero => "NICTA-1.0",
-            Self::NOSL => "NOSL",
-            Self::Pnmstitch => "pnmstitch",
-            Self::CPLOneDotZero => "CPL-1.0",
-            Self::Xzoom => "xzoom",
-            Self::IECCodeComponentsEULA => "IEC-Code-Components-EULA",
-            Self::DeprecatedAGPLThreeDotZero => "deprecated_AGPL-3.0",
-            Self::RSCPL => "RSCPL",
-            Self::NPLOneDotZero => "NPL-1.0",
-            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => {
-                "BSD-3-Clause-No-Nuclear-License-2014"
-            }
-            Self::Sleepycat => "Sleepycat",
-            Self::CDLASharingOneDotZero => "CDLA-Sharing-1.0",
-            Self::GFDLOneDotThreeOnly => "GFDL-1.3-only",
-            Self::Lsof => "lsof",
-            Self::ParitySevenDotZeroDotZero => "Parity-7.0.0",
-            Self::AAL => "AAL",
-            Self::Zeeff => "Zeeff",
-            Self::CCBYNCSAFourDotZero => "CC-BY-NC-SA-4.0",
-            Self::BlueOakOneDotZeroDotZero => "BlueOak-1.0.0",
-            Self::CCBYSAFourDotZero => "CC-BY-SA-4.0",
-            Self::GFDLOneDotTwoOrLater => "GFDL-1.2-or-later",
-            Self::OFLOneDotOne => "OFL-1.1",
-            Self::APSLOneDotOne => "APSL-1.1",
-            Self::GPLThreeDotZeroInterfaceException => "GPL-3.0-interface-exception",
-            Self::QtLGPLExceptionOneDotOne => "Qt-LGPL-exception-1.1",
-            Self::MackerrasThreeClause => "Mackerras-3-Clause",
-            Self::EUPLOneDotOne => "EUPL-1.1",
-            Self::AutoconfExceptionGeneric => "Autoconf-exception-generic",
-            Self::SunPPP => "Sun-PPP",
-            Self::CECILLB => "CECILL-B",
-            Self::LinuxOpenIb => "Linux-OpenIB",
-            Self::BSDAttributionHPNDDisclaimer => "BSD-Attribution-HPND-disclaimer",
-            Self::FmtException => "fmt-exception",
-            Self::MITFeh => "MIT-feh",
-            Self::RubyPty => "Ruby-pty",
-            Self::BSDThreeClauseNoMilitaryLicense => "BSD-3-Clause-No-Military-License",
-            Self::HPNDSellVariant => "HPND-sell-variant",
-            Self::DECThreeClause => "DEC-3-Clause",
+            Self::TTWL => "TTWL",
+            Self::TtypZero => "TTYP0",
+            Self::TUBerlinOneDotZero => "TU-Berlin-1.0",
+            Self::TUBerlinTwoDotZero => "TU-Berlin-2.0",
+            Self::TermReadKey => "TermReadKey",
+            Self::ThirdEye => "ThirdEye",
+            Self::TrustedQsl => "TrustedQSL",
+            Self::UCAR => "UCAR",
+            Self::UCLOneDotZero => "UCL-1.0",
+            Self::UMichMerit => "UMich-Merit",
+            Self::UPLOneDotZero => "UPL-1.0",
+            Self::URTRLE => "URT-RLE",
+            Self::UbuntuFontOneDotZero => "Ubuntu-font-1.0",
+            Self::UnicodeThreeDotZero => "Unicode-3.0",
+            Self::UnicodeDFSTwoZeroOneFive => "Unicode-DFS-2015",
+            Self::UnicodeDFSTwoZeroOneSix => "Unicode-DFS-2016",
+            Self::UnicodeTOU => "Unicode-TOU",
+            Self::UnixCrypt => "UnixCrypt",
+            Self::Unlicense => "Unlicense",
+            Self::VOSTROM => "VOSTROM",
+            Self::VSLOneDotZero => "VSL-1.0",
+            Self::Vim => "Vim",
+            Self::WThreeC => "W3C",
+            Self::WThreeCOneNineNineEightZeroSevenTwoZero => "W3C-19980720",
+            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => "W3C-20150513",
+            Self::WTFPL => "WTFPL",
+            Self::WatcomOneDotZero => "Watcom-1.0",
+            Self::WidgetWorkshop => "Widget-Workshop",
+            Self::Wsuipa => "Wsuipa",
+            Self::XOneOne => "X11",
+            Self::XOneOneDistributeModificationsVariant => "X11-distribute-modifications-variant",
+            Self::XOneOneSwapped => "X11-swapped",
+            Self::XFreeEightSixOneDotOne => "XFree86-1.1",
+            Self::XSkat => "XSkat",
+            Self::XdebugOneDotZeroThree => "Xdebug-1.03",
+            Self::Xerox => "Xerox",
+            Self::Xfig => "Xfig",
+            Self::Xnet => "Xnet",
+            Self::YPLOneDotZero => "YPL-1.0",
+            Self::YPLOneDotOne => "YPL-1.1",
             Self::ZPLOneDotOne => "ZPL-1.1",
+            Self::ZPLTwoDotZero => "ZPL-2.0",
+            Self::ZPLTwoDotOne => "ZPL-2.1",
+            Self::Zed => "Zed",
+            Self::Zeeff => "Zeeff",
+            Self::ZendTwoDotZero => "Zend-2.0",
+            Self::ZimbraOneDotThree => "Zimbra-1.3",
+            Self::ZimbraOneDotFour => "Zimbra-1.4",
             Self::Zlib => "Zlib",
-            Self::FTL => "FTL",
-            Self::CCBYSAThreeDotZeroDE => "CC-BY-SA-3.0-DE",
-            Self::CCBYNCNDThreeDotZero => "CC-BY-NC-ND-3.0",
-            Self::TOSL => "TOSL",
-            Self::FBM => "FBM",
-            Self::CCBYNDThreeDotZero => "CC-BY-ND-3.0",
-            Self::CUDAOneDotZero => "C-UDA-1.0",
-            Self::QtGPLExceptionOneDotZero => "Qt-GPL-exception-1.0",
-            Self::SunPPPTwoZeroZeroZero => "Sun-PPP-2000",
-            Self::OpenVision => "OpenVision",
-            Self::DeprecatedGFDLOneDotOne => "deprecated_GFDL-1.1",
-            Self::SshKeyscan => "ssh-keyscan",
-            Self::SSHShort => "SSH-short",
+            Self::AnyOSI => "any-OSI",
+            Self::AnyOSIPerlModules => "any-OSI-perl-modules",
+            Self::BcryptSolarDesigner => "bcrypt-Solar-Designer",
+            Self::Blessing => "blessing",
+            Self::BzipTwoOneDotZeroDotSix => "bzip2-1.0.6",
+            Self::CheckCvs => "check-cvs",
+            Self::Checkmk => "checkmk",
+            Self::CopyleftNextZeroDotThreeDotZero => "copyleft-next-0.3.0",
+            Self::CopyleftNextZeroDotThreeDotOne => "copyleft-next-0.3.1",
+            Self::Curl => "curl",
+            Self::CveTou => "cve-tou",
+            Self::DeprecatedAGPLOneDotZero => "AGPL-1.0",
+            Self::DeprecatedAGPLThreeDotZero => "AGPL-3.0",
+            Self::DeprecatedBSDTwoClauseFreeBsd => "BSD-2-Clause-FreeBSD",
+            Self::DeprecatedBSDTwoClauseNetBsd => "BSD-2-Clause-NetBSD",
+            Self::DeprecatedGFDLOneDotOne => "GFDL-1.1",
+            Self::DeprecatedGFDLOneDotTwo => "GFDL-1.2",
+            Self::DeprecatedGFDLOneDotThree => "GFDL-1.3",
+            Self::DeprecatedGPLOneDotZero => "GPL-1.0",
+            Self::DeprecatedGPLOneDotZeroPlus => "GPL-1.0+",
+            Self::DeprecatedGPLTwoDotZero => "GPL-2.0",
+            Self::DeprecatedGPLTwoDotZeroPlus => "GPL-2.0+",
+            Self::DeprecatedGPLTwoDotZeroWithGCCException => "GPL-2.0-with-GCC-exception",
+            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => "GPL-2.0-with-autoconf-exception",
+            Self::DeprecatedGPLTwoDotZeroWithBisonException => "GPL-2.0-with-bison-exception",
+            Self::DeprecatedGPLTwoDotZeroWithClasspathException => {
+                "GPL-2.0-with-classpath-exception"
+            }
+            Self::DeprecatedGPLTwoDotZeroWithFontException => "GPL-2.0-with-font-exception",
+            Self::DeprecatedGPLThreeDotZero => "GPL-3.0",
+            Self::DeprecatedGPLThreeDotZeroPlus => "GPL-3.0+",
+            Self::DeprecatedGPLThreeDotZeroWithGCCException => "GPL-3.0-with-GCC-exception",
+            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => {
+                "GPL-3.0-with-autoconf-exception"
+            }
+            Self::DeprecatedLGPLTwoDotZero => "LGPL-2.0",
+            Self::DeprecatedLGPLTwoDotZeroPlus => "LGPL-2.0+",
+            Self::DeprecatedLGPLTwoDotOne => "LGPL-2.1",
+            Self::DeprecatedLGPLTwoDotOnePlus => "LGPL-2.1+",
+            Self::DeprecatedLGPLThreeDotZero => "LGPL-3.0",
+            Self::DeprecatedLGPLThreeDotZeroPlus => "LGPL-3.0+",
+            Self::DeprecatedNetSNMP => "Net-SNMP",
+            Self::DeprecatedNunit => "Nunit",
+            Self::DeprecatedStandardMlNJ => "StandardML-NJ",
+            Self::DeprecatedBzipTwoOneDotZeroDotFive => "bzip2-1.0.5",
+            Self::DeprecatedECosTwoDotZero => "eCos-2.0",
+            Self::DeprecatedWxWindows => "wxWindows",
+            Self::Diffmark => "diffmark",
+            Self::Dtoa => "dtoa",
+            Self::Dvipdfm => "dvipdfm",
+            Self::EGenix => "eGenix",
+            Self::EtalabTwoDotZero => "etalab-2.0",
+            Self::Fwlw => "fwlw",
             Self::GSoapOneDotThreeb => "gSOAP-1.3b",
-            Self::JPNIC => "JPNIC",
-            Self::GFDLOneDotOneNoInvariantsOnly => "GFDL-1.1-no-invariants-only",
-            Self::TAPROHLOneDotZero => "TAPR-OHL-1.0",
-            Self::Rdisc => "Rdisc",
-            Self::BSDTwoClauseViews => "BSD-2-Clause-Views",
-            Self::ArtisticOneDotZeroClEight => "Artistic-1.0-cl8",
-            Self::HPNDDoc => "HPND-doc",
-            Self::BSDThreeClauseSun => "BSD-3-Clause-Sun",
-            Self::URTRLE => "URT-RLE",
+            Self::GenericXts => "generic-xts",
+            Self::Gnuplot => "gnuplot",
+            Self::Gtkbook => "gtkbook",
+            Self::Hdparm => "hdparm",
+            Self::IMatix => "iMatix",
+            Self::Jove => "jove",
+            Self::LibpngTwoDotZero => "libpng-2.0",
+            Self::LibselinuxOneDotZero => "libselinux-1.0",
+            Self::Libtiff => "libtiff",
+            Self::LibutilDavidNugent => "libutil-David-Nugent",
+            Self::Lsof => "lsof",
+            Self::Magaz => "magaz",
+            Self::Mailprio => "mailprio",
+            Self::ManTwohtml => "man2html",
+            Self::Metamail => "metamail",
+            Self::MpiPermissive => "mpi-permissive",
             Self::MpichTwo => "mpich2",
-            Self::CGALLinkingException => "CGAL-linking-exception",
-            Self::MPLOneDotOne => "MPL-1.1",
+            Self::Mplus => "mplus",
+            Self::Pkgconf => "pkgconf",
+            Self::Pnmstitch => "pnmstitch",
+            Self::Psfrag => "psfrag",
+            Self::Psutils => "psutils",
+            Self::PythonLdap => "python-ldap",
+            Self::Radvd => "radvd",
+            Self::Snprintf => "snprintf",
+            Self::SoftSurfer => "softSurfer",
+            Self::SshKeyscan => "ssh-keyscan",
+            Self::Swrule => "swrule",
+            Self::Threeparttable => "threeparttable",
+            Self::Ulem => "ulem",
+            Self::WThreem => "w3m",
+            Self::Wwl => "wwl",
+            Self::Xinetd => "xinetd",
+            Self::XkeyboardConfigZinoviev => "xkeyboard-config-Zinoviev",
+            Self::Xlock => "xlock",
+            Self::Xpp => "xpp",
+            Self::Xzoom => "xzoom",
+            Self::ZlibAcknowledgement => "zlib-acknowledgement",
+        }
+    }
+    #[doc = r" Returns the page of the license on the SPDX website."]
+    pub fn reference_url(&self) -> &'static str {
+        match self {
+            Self::ZeroBsd => "https://spdx.org/licenses/0BSD.html",
+            Self::ThreeDSlicerOneDotZero => "https://spdx.org/licenses/3D-Slicer-1.0.html",
+            Self::AAL => "https://spdx.org/licenses/AAL.html",
+            Self::ADSL => "https://spdx.org/licenses/ADSL.html",
+            Self::AFLOneDotOne => "https://spdx.org/licenses/AFL-1.1.html",
+            Self::AFLOneDotTwo => "https://spdx.org/licenses/AFL-1.2.html",
+            Self::AFLTwoDotZero => "https://spdx.org/licenses/AFL-2.0.html",
+            Self::AFLTwoDotOne => "https://spdx.org/licenses/AFL-2.1.html",
+            Self::AFLThreeDotZero => "https://spdx.org/licenses/AFL-3.0.html",
+            Self::AGPLOneDotZeroOnly => "https://spdx.org/licenses/AGPL-1.0-only.html",
+            Self::AGPLOneDotZeroOrLater => "https://spdx.org/licenses/AGPL-1.0-or-later.html",
+            Self::AGPLThreeDotZeroOnly => "https://spdx.org/licenses/AGPL-3.0-only.html",
+            Self::AGPLThreeDotZeroOrLater => "https://spdx.org/licenses/AGPL-3.0-or-later.html",
+            Self::AMDNewlib => "https://spdx.org/licenses/AMD-newlib.html",
+            Self::AMDPLPA => "https://spdx.org/licenses/AMDPLPA.html",
+            Self::AML => "https://spdx.org/licenses/AML.html",
+            Self::AMLGlslang => "https://spdx.org/licenses/AML-glslang.html",
+            Self::AMPAS => "https://spdx.org/licenses/AMPAS.html",
+            Self::ANTLRPD => "https://spdx.org/licenses/ANTLR-PD.html",
+            Self::ANTLRPDFallback => "https://spdx.org/licenses/ANTLR-PD-fallback.html",
+            Self::APAFML => "https://spdx.org/licenses/APAFML.html",
+            Self::APLOneDotZero => "https://spdx.org/licenses/APL-1.0.html",
+            Self::APSLOneDotZero => "https://spdx.org/licenses/APSL-1.0.html",
+            Self::APSLOneDotOne => "https://spdx.org/licenses/APSL-1.1.html",
+            Self::APSLOneDotTwo => "https://spdx.org/licenses/APSL-1.2.html",
+            Self::APSLTwoDotZero => "https://spdx.org/licenses/APSL-2.0.html",
+            Self::ASWFDigitalAssetsOneDotZero => {
+                "https://spdx.org/licenses/ASWF-Digital-Assets-1.0.html"
+            }
+            Self::ASWFDigitalAssetsOneDotOne => {
+                "https://spdx.org/licenses/ASWF-Digital-Assets-1.1.html"
+            }
+            Self::Abstyles => "https://spdx.org/licenses/Abstyles.html",
+            Self::AdaCoreDoc => "https://spdx.org/licenses/AdaCore-doc.html",
+            Self::AdobeTwoZeroZeroSix => "https://spdx.org/licenses/Adobe-2006.html",
+            Self::AdobeDisplayPostScript => {
+                "https://spdx.org/licenses/Adobe-Display-PostScript.html"
+            }
+            Self::AdobeGlyph => "https://spdx.org/licenses/Adobe-Glyph.html",
+            Self::AdobeUtopia => "https://spdx.org/licenses/Adobe-Utopia.html",
+            Self::Afmparse => "https://spdx.org/licenses/Afmparse.html",
+            Self::Aladdin => "https://spdx.org/licenses/Aladdin.html",
+            Self::ApacheOneDotZero => "https://spdx.org/licenses/Apache-1.0.html",
+            Self::ApacheOneDotOne => "https://spdx.org/licenses/Apache-1.1.html",
+            Self::ApacheTwoDotZero => "https://spdx.org/licenses/Apache-2.0.html",
+            Self::AppSTwop => "https://spdx.org/licenses/App-s2p.html",
+            Self::ArphicOneNineNineNine => "https://spdx.org/licenses/Arphic-1999.html",
+            Self::ArtisticOneDotZero => "https://spdx.org/licenses/Artistic-1.0.html",
+            Self::ArtisticOneDotZeroPerl => "https://spdx.org/licenses/Artistic-1.0-Perl.html",
+            Self::ArtisticOneDotZeroClEight => "https://spdx.org/licenses/Artistic-1.0-cl8.html",
+            Self::ArtisticTwoDotZero => "https://spdx.org/licenses/Artistic-2.0.html",
+            Self::BSDOneClause => "https://spdx.org/licenses/BSD-1-Clause.html",
+            Self::BSDTwoClause => "https://spdx.org/licenses/BSD-2-Clause.html",
+            Self::BSDTwoClauseDarwin => "https://spdx.org/licenses/BSD-2-Clause-Darwin.html",
+            Self::BSDTwoClausePatent => "https://spdx.org/licenses/BSD-2-Clause-Patent.html",
+            Self::BSDTwoClauseViews => "https://spdx.org/licenses/BSD-2-Clause-Views.html",
+            Self::BSDTwoClauseFirstLines => {
+                "https://spdx.org/licenses/BSD-2-Clause-first-lines.html"
+            }
+            Self::BSDTwoClausePkgconfDisclaimer => {
+                "https://spdx.org/licenses/BSD-2-Clause-pkgconf-disclaimer.html"
+            }
+            Self::BSDThreeClause => "https://spdx.org/licenses/BSD-3-Clause.html",
+            Self::BSDThreeClauseAttribution => {
+                "https://spdx.org/licenses/BSD-3-Clause-Attribution.html"
+            }
+            Self::BSDThreeClauseClear => "https://spdx.org/licenses/BSD-3-Clause-Clear.html",
+            Self::BSDThreeClauseHP => "https://spdx.org/licenses/BSD-3-Clause-HP.html",
+            Self::BSDThreeClauseLBNL => "https://spdx.org/licenses/BSD-3-Clause-LBNL.html",
+            Self::BSDThreeClauseModification => {
+                "https://spdx.org/licenses/BSD-3-Clause-Modification.html"
+            }
+            Self::BSDThreeClauseNoMilitaryLicense => {
+                "https://spdx.org/licenses/BSD-3-Clause-No-Military-License.html"
+            }
+            Self::BSDThreeClauseNoNuclearLicense => {
+                "https://spdx.org/licenses/BSD-3-Clause-No-Nuclear-License.html"
+            }
+            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => {
+                "https://spdx.org/licenses/BSD-3-Clause-No-Nuclear-License-2014.html"
+            }
+            Self::BSDThreeClauseNoNuclearWarranty => {
+                "https://spdx.org/licenses/BSD-3-Clause-No-Nuclear-Warranty.html"
+            }
+            Self::BSDThreeClauseOpenMPI => "https://spdx.org/licenses/BSD-3-Clause-Open-MPI.html",
+            Self::BSDThreeClauseSun => "https://spdx.org/licenses/BSD-3-Clause-Sun.html",
+            Self::BSDThreeClauseAcpica => "https://spdx.org/licenses/BSD-3-Clause-acpica.html",
+            Self::BSDThreeClauseFlex => "https://spdx.org/licenses/BSD-3-Clause-flex.html",
+            Self::BSDFourClause => "https://spdx.org/licenses/BSD-4-Clause.html",
+            Self::BSDFourClauseShortened => "https://spdx.org/licenses/BSD-4-Clause-Shortened.html",
+            Self::BSDFourClauseUC => "https://spdx.org/licenses/BSD-4-Clause-UC.html",
+            Self::BSDFourDotThreeReno => "https://spdx.org/licenses/BSD-4.3RENO.html",
+            Self::BSDFourDotThreeTahoe => "https://spdx.org/licenses/BSD-4.3TAHOE.html",
+            Self::BSDAdvertisingAcknowledgement => {
+                "https://spdx.org/licenses/BSD-Advertising-Acknowledgement.html"
+            }
+            Self::BSDAttributionHPNDDisclaimer => {
+                "https://spdx.org/licenses/BSD-Attribution-HPND-disclaimer.html"
+            }
+            Self::BSDInfernoNettverk => "https://spdx.org/licenses/BSD-Inferno-Nettverk.html",
+            Self::BSDProtection => "https://spdx.org/licenses/BSD-Protection.html",
+            Self::BSDSourceCode => "https://spdx.org/licenses/BSD-Source-Code.html",
+            Self::BSDSourceBeginningFile => {
+                "https://spdx.org/licenses/BSD-Source-beginning-file.html"
+            }
+            Self::BSDSystemics => "https://spdx.org/licenses/BSD-Systemics.html",
+            Self::BSDSystemicsWThreeWorks => "https://spdx.org/licenses/BSD-Systemics-W3Works.html",
+            Self::BSLOneDotZero => "https://spdx.org/licenses/BSL-1.0.html",
+            Self::BUSLOneDotOne => "https://spdx.org/licenses/BUSL-1.1.html",
+            Self::Baekmuk => "https://spdx.org/licenses/Baekmuk.html",
+            Self::Bahyph => "https://spdx.org/licenses/Bahyph.html",
+            Self::Barr => "https://spdx.org/licenses/Barr.html",
+            Self::Beerware => "https://spdx.org/licenses/Beerware.html",
+            Self::BitTorrentOneDotZero => "https://spdx.org/licenses/BitTorrent-1.0.html",
+            Self::BitTorrentOneDotOne => "https://spdx.org/licenses/BitTorrent-1.1.html",
+            Self::BitstreamCharter => "https://spdx.org/licenses/Bitstream-Charter.html",
+            Self::BitstreamVera => "https://spdx.org/licenses/Bitstream-Vera.html",
+            Self::BlueOakOneDotZeroDotZero => "https://spdx.org/licenses/BlueOak-1.0.0.html",
+            Self::BoehmGC => "https://spdx.org/licenses/Boehm-GC.html",
+            Self::BoehmGCWithoutFee => "https://spdx.org/licenses/Boehm-GC-without-fee.html",
+            Self::Borceux => "https://spdx.org/licenses/Borceux.html",
+            Self::BrianGladmanTwoClause => "https://spdx.org/licenses/Brian-Gladman-2-Clause.html",
+            Self::BrianGladmanThreeClause => {
+                "https://spdx.org/licenses/Brian-Gladman-3-Clause.html"
+            }
+            Self::CUDAOneDotZero => "https://spdx.org/licenses/C-UDA-1.0.html",
+            Self::CALOneDotZero => "https://spdx.org/licenses/CAL-1.0.html",
+            Self::CALOneDotZeroCombinedWorkException => {
+                "https://spdx.org/licenses/CAL-1.0-Combined-Work-Exception.html"
+            }
+            Self::CATOSLOneDotOne => "https://spdx.org/licenses/CATOSL-1.1.html",
+            Self::CCBYOneDotZero => "https://spdx.org/licenses/CC-BY-1.0.html",
+            Self::CCBYTwoDotZero => "https://spdx.org/licenses/CC-BY-2.0.html",
+            Self::CCBYTwoDotFive => "https://spdx.org/licenses/CC-BY-2.5.html",
+            Self::CCBYTwoDotFiveAU => "https://spdx.org/licenses/CC-BY-2.5-AU.html",
+            Self::CCBYThreeDotZero => "https://spdx.org/licenses/CC-BY-3.0.html",
+            Self::CCBYThreeDotZeroAT => "https://spdx.org/licenses/CC-BY-3.0-AT.html",
+            Self::CCBYThreeDotZeroAU => "https://spdx.org/licenses/CC-BY-3.0-AU.html",
+            Self::CCBYThreeDotZeroDE => "https://spdx.org/licenses/CC-BY-3.0-DE.html",
+            Self::CCBYThreeDotZeroIGO => "https://spdx.org/licenses/CC-BY-3.0-IGO.html",
+            Self::CCBYThreeDotZeroNL => "https://spdx.org/licenses/CC-BY-3.0-NL.html",
+            Self::CCBYThreeDotZeroUS => "https://spdx.org/licenses/CC-BY-3.0-US.html",
+            Self::CCBYFourDotZero => "https://spdx.org/licenses/CC-BY-4.0.html",
+            Self::CCBYNCOneDotZero => "https://spdx.org/licenses/CC-BY-NC-1.0.html",
+            Self::CCBYNCTwoDotZero => "https://spdx.org/licenses/CC-BY-NC-2.0.html",
+            Self::CCBYNCTwoDotFive => "https://spdx.org/licenses/CC-BY-NC-2.5.html",
+            Self::CCBYNCThreeDotZero => "https://spdx.org/licenses/CC-BY-NC-3.0.html",
+            Self::CCBYNCThreeDotZeroDE => "https://spdx.org/licenses/CC-BY-NC-3.0-DE.html",
+            Self::CCBYNCFourDotZero => "https://spdx.org/licenses/CC-BY-NC-4.0.html",
+            Self::CCBYNCNDOneDotZero => "https://spdx.org/licenses/CC-BY-NC-ND-1.0.html",
+            Self::CCBYNCNDTwoDotZero => "https://spdx.org/licenses/CC-BY-NC-ND-2.0.html",
+            Self::CCBYNCNDTwoDotFive => "https://spdx.org/licenses/CC-BY-NC-ND-2.5.html",
+            Self::CCBYNCNDThreeDotZero => "https://spdx.org/licenses/CC-BY-NC-ND-3.0.html",
+            Self::CCBYNCNDThreeDotZeroDE => "https://spdx.org/licenses/CC-BY-NC-ND-3.0-DE.html",
+            Self::CCBYNCNDThreeDotZeroIGO => "https://spdx.org/licenses/CC-BY-NC-ND-3.0-IGO.html",
+            Self::CCBYNCNDFourDotZero => "https://spdx.org/licenses/CC-BY-NC-ND-4.0.html",
+            Self::CCBYNCSAOneDotZero => "https://spdx.org/licenses/CC-BY-NC-SA-1.0.html",
+            Self::CCBYNCSATwoDotZero => "https://spdx.org/licenses/CC-BY-NC-SA-2.0.html",
+            Self::CCBYNCSATwoDotZeroDE => "https://spdx.org/licenses/CC-BY-NC-SA-2.0-DE.html",
+            Self::CCBYNCSATwoDotZeroFR => "https://spdx.org/licenses/CC-BY-NC-SA-2.0-FR.html",
+            Self::CCBYNCSATwoDotZeroUK => "https://spdx.org/licenses/CC-BY-NC-SA-2.0-UK.html",
+            Self::CCBYNCSATwoDotFive => "https://spdx.org/licenses/CC-BY-NC-SA-2.5.html",
+            Self::CCBYNCSAThreeDotZero => "https://spdx.org/licenses/CC-BY-NC-SA-3.0.html",
+            Self::CCBYNCSAThreeDotZeroDE => "https://spdx.org/licenses/CC-BY-NC-SA-3.0-DE.html",
+            Self::CCBYNCSAThreeDotZeroIGO => "https://spdx.org/licenses/CC-BY-NC-SA-3.0-IGO.html",
+            Self::CCBYNCSAFourDotZero => "https://spdx.org/licenses/CC-BY-NC-SA-4.0.html",
+            Self::CCBYNDOneDotZero => "https://spdx.org/licenses/CC-BY-ND-1.0.html",
+            Self::CCBYNDTwoDotZero => "https://spdx.org/licenses/CC-BY-ND-2.0.html",
+            Self::CCBYNDTwoDotFive => "https://spdx.org/licenses/CC-BY-ND-2.5.html",
+            Self::CCBYNDThreeDotZero => "https://spdx.org/licenses/CC-BY-ND-3.0.html",
+            Self::CCBYNDThreeDotZeroDE => "https://spdx.org/licenses/CC-BY-ND-3.0-DE.html",
+            Self::CCBYNDFourDotZero => "https://spdx.org/licenses/CC-BY-ND-4.0.html",
+            Self::CCBYSAOneDotZero => "https://spdx.org/licenses/CC-BY-SA-1.0.html",
+            Self::CCBYSATwoDotZero => "https://spdx.org/licenses/CC-BY-SA-2.0.html",
+            Self::CCBYSATwoDotZeroUK => "https://spdx.org/licenses/CC-BY-SA-2.0-UK.html",
+            Self::CCBYSATwoDotOneJP => "https://spdx.org/licenses/CC-BY-SA-2.1-JP.html",
+            Self::CCBYSATwoDotFive => "https://spdx.org/licenses/CC-BY-SA-2.5.html",
+            Self::CCBYSAThreeDotZero => "https://spdx.org/licenses/CC-BY-SA-3.0.html",
+            Self::CCBYSAThreeDotZeroAT => "https://spdx.org/licenses/CC-BY-SA-3.0-AT.html",
+            Self::CCBYSAThreeDotZeroDE => "https://spdx.org/licenses/CC-BY-SA-3.0-DE.html",
+            Self::CCBYSAThreeDotZeroIGO => "https://spdx.org/licenses/CC-BY-SA-3.0-IGO.html",
+            Self::CCBYSAFourDotZero => "https://spdx.org/licenses/CC-BY-SA-4.0.html",
+            Self::CCPDDC => "https://spdx.org/licenses/CC-PDDC.html",
+            Self::CCPDMOneDotZero => "https://spdx.org/licenses/CC-PDM-1.0.html",
+            Self::CCSAOneDotZero => "https://spdx.org/licenses/CC-SA-1.0.html",
+            Self::CcZeroOneDotZero => "https://spdx.org/licenses/CC0-1.0.html",
+            Self::CDDLOneDotZero => "https://spdx.org/licenses/CDDL-1.0.html",
+            Self::CDDLOneDotOne => "https://spdx.org/licenses/CDDL-1.1.html",
+            Self::CDLOneDotZero => "https://spdx.org/licenses/CDL-1.0.html",
+            Self::CDLAPermissiveOneDotZero => "https://spdx.org/licenses/CDLA-Permissive-1.0.html",
+            Self::CDLAPermissiveTwoDotZero => "https://spdx.org/licenses/CDLA-Permissive-2.0.html",
+            Self::CDLASharingOneDotZero => "https://spdx.org/licenses/CDLA-Sharing-1.0.html",
+            Self::CECILLOneDotZero => "https://spdx.org/licenses/CECILL-1.0.html",
+            Self::CECILLOneDotOne => "https://spdx.org/licenses/CECILL-1.1.html",
+            Self::CECILLTwoDotZero => "https://spdx.org/licenses/CECILL-2.0.html",
+            Self::CECILLTwoDotOne => "https://spdx.org/licenses/CECILL-2.1.html",
+            Self::CECILLB => "https://spdx.org/licenses/CECILL-B.html",
+            Self::CECILLC => "https://spdx.org/licenses/CECILL-C.html",
+            Self::CERNOHLOneDotOne => "https://spdx.org/licenses/CERN-OHL-1.1.html",
+            Self::CERNOHLOneDotTwo => "https://spdx.org/licenses/CERN-OHL-1.2.html",
+            Self::CERNOHLPTwoDotZero => "https://spdx.org/licenses/CERN-OHL-P-2.0.html",
+            Self::CERNOHLSTwoDotZero => "https://spdx.org/licenses/CERN-OHL-S-2.0.html",
+            Self::CERNOHLWTwoDotZero => "https://spdx.org/licenses/CERN-OHL-W-2.0.html",
+            Self::CFITSIO => "https://spdx.org/licenses/CFITSIO.html",
+            Self::CMUMach => "https://spdx.org/licenses/CMU-Mach.html",
+            Self::CMUMachNodoc => "https://spdx.org/licenses/CMU-Mach-nodoc.html",
+            Self::CNRIJython => "https://spdx.org/licenses/CNRI-Jython.html",
+            Self::CNRIPython => "https://spdx.org/licenses/CNRI-Python.html",
+            Self::CNRIPythonGPLCompatible => {
+                "https://spdx.org/licenses/CNRI-Python-GPL-Compatible.html"
+            }
+            Self::COILOneDotZero => "https://spdx.org/licenses/COIL-1.0.html",
+            Self::CPALOneDotZero => "https://spdx.org/licenses/CPAL-1.0.html",
+            Self::CPLOneDotZero => "https://spdx.org/licenses/CPL-1.0.html",
+            Self::CPOLOneDotZeroTwo => "https://spdx.org/licenses/CPOL-1.02.html",
+            Self::CUAOPLOneDotZero => "https://spdx.org/licenses/CUA-OPL-1.0.html",
+            Self::Caldera => "https://spdx.org/licenses/Caldera.html",
+            Self::CalderaNoPreamble => "https://spdx.org/licenses/Caldera-no-preamble.html",
+            Self::Catharon => "https://spdx.org/licenses/Catharon.html",
+            Self::ClArtistic => "https://spdx.org/licenses/ClArtistic.html",
+            Self::Clips => "https://spdx.org/licenses/Clips.html",
+            Self::CommunitySpecOneDotZero => "https://spdx.org/licenses/Community-Spec-1.0.html",
+            Self::CondorOneDotOne => "https://spdx.org/licenses/Condor-1.1.html",
+            Self::CornellLosslessJPEG => "https://spdx.org/licenses/Cornell-Lossless-JPEG.html",
+            Self::Cronyx => "https://spdx.org/licenses/Cronyx.html",
+            Self::Crossword => "https://spdx.org/licenses/Crossword.html",
+            Self::CryptoSwift => "https://spdx.org/licenses/CryptoSwift.html",
+            Self::CrystalStacker => "https://spdx.org/licenses/CrystalStacker.html",
+            Self::Cube => "https://spdx.org/licenses/Cube.html",
+            Self::DFSLOneDotZero => "https://spdx.org/licenses/D-FSL-1.0.html",
+            Self::DECThreeClause => "https://spdx.org/licenses/DEC-3-Clause.html",
+            Self::DLDEBYTwoDotZero => "https://spdx.org/licenses/DL-DE-BY-2.0.html",
+            Self::DLDEZEROTwoDotZero => "https://spdx.org/licenses/DL-DE-ZERO-2.0.html",
+            Self::DOC => "https://spdx.org/licenses/DOC.html",
+            Self::DRLOneDotZero => "https://spdx.org/licenses/DRL-1.0.html",
+            Self::DRLOneDotOne => "https://spdx.org/licenses/DRL-1.1.html",
+            Self::DSDP => "https://spdx.org/licenses/DSDP.html",
+            Self::DocBookDTD => "https://spdx.org/licenses/DocBook-DTD.html",
+            Self::DocBookSchema => "https://spdx.org/licenses/DocBook-Schema.html",
+            Self::DocBookStylesheet => "https://spdx.org/licenses/DocBook-Stylesheet.html",
+            Self::DocBookXML => "https://spdx.org/licenses/DocBook-XML.html",
+            Self::Dotseqn => "https://spdx.org/licenses/Dotseqn.html",
+            Self::ECLOneDotZero => "https://spdx.org/licenses/ECL-1.0.html",
+            Self::ECLTwoDotZero => "https://spdx.org/licenses/ECL-2.0.html",
+            Self::EFLOneDotZero => "https://spdx.org/licenses/EFL-1.0.html",
+            Self::EFLTwoDotZero => "https://spdx.org/licenses/EFL-2.0.html",
+            Self::EPICS => "https://spdx.org/licenses/EPICS.html",
+            Self::EPLOneDotZero => "https://spdx.org/licenses/EPL-1.0.html",
+            Self::EPLTwoDotZero => "https://spdx.org/licenses/EPL-2.0.html",
+            Self::EuDatagrid => "https://spdx.org/licenses/EUDatagrid.html",
+            Self::EUPLOneDotZero => "https://spdx.org/licenses/EUPL-1.0.html",
+            Self::EUPLOneDotOne => "https://spdx.org/licenses/EUPL-1.1.html",
+            Self::EUPLOneDotTwo => "https://spdx.org/licenses/EUPL-1.2.html",
+            Self::ElasticTwoDotZero => "https://spdx.org/licenses/Elastic-2.0.html",
+            Self::Entessa => "https://spdx.org/licenses/Entessa.html",
+            Self::ErlPlOneDotOne => "https://spdx.org/licenses/ErlPL-1.1.html",
+            Self::Eurosym => "https://spdx.org/licenses/Eurosym.html",
+            Self::FBM => "https://spdx.org/licenses/FBM.html",
+            Self::FDKAAC => "https://spdx.org/licenses/FDK-AAC.html",
+            Self::FSFAP => "https://spdx.org/licenses/FSFAP.html",
+            Self::FSFAPNoWarrantyDisclaimer => {
+                "https://spdx.org/licenses/FSFAP-no-warranty-disclaimer.html"
+            }
+            Self::FSFUL => "https://spdx.org/licenses/FSFUL.html",
+            Self::FSFULLR => "https://spdx.org/licenses/FSFULLR.html",
+            Self::FSFULLRWD => "https://spdx.org/licenses/FSFULLRWD.html",
+            Self::FSLOneDotOneALvTwo => "https://spdx.org/licenses/FSL-1.1-ALv2.html",
+            Self::FSLOneDotOneMIT => "https://spdx.org/licenses/FSL-1.1-MIT.html",
+            Self::FTL => "https://spdx.org/licenses/FTL.html",
+            Self::Fair => "https://spdx.org/licenses/Fair.html",
+            Self::FergusonTwofish => "https://spdx.org/licenses/Ferguson-Twofish.html",
+            Self::FrameworxOneDotZero => "https://spdx.org/licenses/Frameworx-1.0.html",
+            Self::FreeBsdDOC => "https://spdx.org/licenses/FreeBSD-DOC.html",
+            Self::FreeImage => "https://spdx.org/licenses/FreeImage.html",
+            Self::Furuseth => "https://spdx.org/licenses/Furuseth.html",
+            Self::GCRDocs => "https://spdx.org/licenses/GCR-docs.html",
+            Self::GD => "https://spdx.org/licenses/GD.html",
+            Self::GFDLOneDotOneInvariantsOnly => {
+                "https://spdx.org/licenses/GFDL-1.1-invariants-only.html"
+            }
+            Self::GFDLOneDotOneInvariantsOrLater => {
+                "https://spdx.org/licenses/GFDL-1.1-invariants-or-later.html"
+            }
+            Self::GFDLOneDotOneNoInvariantsOnly => {
+                "https://spdx.org/licenses/GFDL-1.1-no-invariants-only.html"
+            }
+            Self::GFDLOneDotOneNoInvariantsOrLater => {
+                "https://spdx.org/licenses/GFDL-1.1-no-invariants-or-later.html"
+            }
+            Self::GFDLOneDotOneOnly => "https://spdx.org/licenses/GFDL-1.1-only.html",
+            Self::GFDLOneDotOneOrLater => "https://spdx.org/licenses/GFDL-1.1-or-later.html",
+            Self::GFDLOneDotTwoInvariantsOnly => {
+                "https://spdx.org/licenses/GFDL-1.2-invariants-only.html"
+            }
+            Self::GFDLOneDotTwoInvariantsOrLater => {
+                "https://spdx.org/licenses/GFDL-1.2-invariants-or-later.html"
+            }
+            Self::GFDLOneDotTwoNoInvariantsOnly => {
+                "https://spdx.org/licenses/GFDL-1.2-no-invariants-only.html"
+            }
+            Self::GFDLOneDotTwoNoInvariantsOrLater => {
+                "https://spdx.org/licenses/GFDL-1.2-no-invariants-or-later.html"
+            }
+            Self::GFDLOneDotTwoOnly => "https://spdx.org/licenses/GFDL-1.2-only.html",
+            Self::GFDLOneDotTwoOrLater => "https://spdx.org/licenses/GFDL-1.2-or-later.html",
+            Self::GFDLOneDotThreeInvariantsOnly => {
+                "https://spdx.org/licenses/GFDL-1.3-invariants-only.html"
+            }
+            Self::GFDLOneDotThreeInvariantsOrLater => {
+                "https://spdx.org/licenses/GFDL-1.3-invariants-or-later.html"
+            }
+            Self::GFDLOneDotThreeNoInvariantsOnly => {
+                "https://spdx.org/licenses/GFDL-1.3-no-invariants-only.html"
+            }
+            Self::GFDLOneDotThreeNoInvariantsOrLater => {
+                "https://spdx.org/licenses/GFDL-1.3-no-invariants-or-later.html"
+            }
+            Self::GFDLOneDotThreeOnly => "https://spdx.org/licenses/GFDL-1.3-only.html",
+            Self::GFDLOneDotThreeOrLater => "https://spdx.org/licenses/GFDL-1.3-or-later.html",
+            Self::GlTwoPs => "https://spdx.org/licenses/GL2PS.html",
+            Self::GLWTPL => "https://spdx.org/licenses/GLWTPL.html",
+            Self::GPLOneDotZeroOnly => "https://spdx.org/licenses/GPL-1.0-only.html",
+            Self::GPLOneDotZeroOrLater => "https://spdx.org/licenses/GPL-1.0-or-later.html",
+            Self::GPLTwoDotZeroOnly => "https://spdx.org/licenses/GPL-2.0-only.html",
+            Self::GPLTwoDotZeroOrLater => "https://spdx.org/licenses/GPL-2.0-or-later.html",
+            Self::GPLThreeDotZeroOnly => "https://spdx.org/licenses/GPL-3.0-only.html",
+            Self::GPLThreeDotZeroOrLater => "https://spdx.org/licenses/GPL-3.0-or-later.html",
+            Self::GameProgrammingGems => "https://spdx.org/licenses/Game-Programming-Gems.html",
+            Self::Giftware => "https://spdx.org/licenses/Giftware.html",
+            Self::Glide => "https://spdx.org/licenses/Glide.html",
+            Self::Glulxe => "https://spdx.org/licenses/Glulxe.html",
+            Self::GraphicsGems => "https://spdx.org/licenses/Graphics-Gems.html",
+            Self::Gutmann => "https://spdx.org/licenses/Gutmann.html",
+            Self::HIDAPI => "https://spdx.org/licenses/HIDAPI.html",
+            Self::HPOneNineEightSix => "https://spdx.org/licenses/HP-1986.html",
+            Self::HPOneNineEightNine => "https://spdx.org/licenses/HP-1989.html",
+            Self::HPND => "https://spdx.org/licenses/HPND.html",
+            Self::HPNDDEC => "https://spdx.org/licenses/HPND-DEC.html",
+            Self::HPNDFennebergLivingston => {
+                "https://spdx.org/licenses/HPND-Fenneberg-Livingston.html"
+            }
+            Self::HPNDINRIAIMAG => "https://spdx.org/licenses/HPND-INRIA-IMAG.html",
+            Self::HPNDIntel => "https://spdx.org/licenses/HPND-Intel.html",
+            Self::HPNDKevlinHenney => "https://spdx.org/licenses/HPND-Kevlin-Henney.html",
+            Self::HPNDMITDisclaimer => "https://spdx.org/licenses/HPND-MIT-disclaimer.html",
+            Self::HPNDMarkusKuhn => "https://spdx.org/licenses/HPND-Markus-Kuhn.html",
+            Self::HPNDNetrek => "https://spdx.org/licenses/HPND-Netrek.html",
+            Self::HPNDPbmplus => "https://spdx.org/licenses/HPND-Pbmplus.html",
+            Self::HPNDUC => "https://spdx.org/licenses/HPND-UC.html",
+            Self::HPNDUCExportUS => "https://spdx.org/licenses/HPND-UC-export-US.html",
+            Self::HPNDDoc => "https://spdx.org/licenses/HPND-doc.html",
+            Self::HPNDDocSell => "https://spdx.org/licenses/HPND-doc-sell.html",
+            Self::HPNDExportUS => "https://spdx.org/licenses/HPND-export-US.html",
+            Self::HPNDExportUSAcknowledgement => {
+                "https://spdx.org/licenses/HPND-export-US-acknowledgement.html"
+            }
+            Self::HPNDExportUSModify => "https://spdx.org/licenses/HPND-export-US-modify.html",
+            Self::HPNDExportTwoUS => "https://spdx.org/licenses/HPND-export2-US.html",
+            Self::HPNDMerchantabilityVariant => {
+                "https://spdx.org/licenses/HPND-merchantability-variant.html"
+            }
+            Self::HPNDSellMITDisclaimerXserver => {
+                "https://spdx.org/licenses/HPND-sell-MIT-disclaimer-xserver.html"
+            }
+            Self::HPNDSellRegexpr => "https://spdx.org/licenses/HPND-sell-regexpr.html",
+            Self::HPNDSellVariant => "https://spdx.org/licenses/HPND-sell-variant.html",
+            Self::HPNDSellVariantMITDisclaimer => {
+                "https://spdx.org/licenses/HPND-sell-variant-MIT-disclaimer.html"
+            }
+            Self::HPNDSellVariantMITDisclaimerRev => {
+                "https://spdx.org/licenses/HPND-sell-variant-MIT-disclaimer-rev.html"
+            }
+            Self::HTMLTIDY => "https://spdx.org/licenses/HTMLTIDY.html",
+            Self::HaskellReport => "https://spdx.org/licenses/HaskellReport.html",
+            Self::HippocraticTwoDotOne => "https://spdx.org/licenses/Hippocratic-2.1.html",
+            Self::IBMPibs => "https://spdx.org/licenses/IBM-pibs.html",
+            Self::ICU => "https://spdx.org/licenses/ICU.html",
+            Self::IECCodeComponentsEULA => {
+                "https://spdx.org/licenses/IEC-Code-Components-EULA.html"
+            }
+            Self::IJG => "https://spdx.org/licenses/IJG.html",
+            Self::IJGShort => "https://spdx.org/licenses/IJG-short.html",
+            Self::IPA => "https://spdx.org/licenses/IPA.html",
+            Self::IPLOneDotZero => "https://spdx.org/licenses/IPL-1.0.html",
+            Self::ISC => "https://spdx.org/licenses/ISC.html",
+            Self::ISCVeillard => "https://spdx.org/licenses/ISC-Veillard.html",
+            Self::ImageMagick => "https://spdx.org/licenses/ImageMagick.html",
+            Self::ImlibTwo => "https://spdx.org/licenses/Imlib2.html",
+            Self::InfoZIP => "https://spdx.org/licenses/Info-ZIP.html",
+            Self::InnerNetTwoDotZero => "https://spdx.org/licenses/Inner-Net-2.0.html",
+            Self::InnoSetup => "https://spdx.org/licenses/InnoSetup.html",
+            Self::Intel => "https://spdx.org/licenses/Intel.html",
+            Self::IntelACPI => "https://spdx.org/licenses/Intel-ACPI.html",
+            Self::InterbaseOneDotZero => "https://spdx.org/licenses/Interbase-1.0.html",
+            Self::JPLImage => "https://spdx.org/licenses/JPL-image.html",
+            Self::JPNIC => "https://spdx.org/licenses/JPNIC.html",
+            Self::JSON => "https://spdx.org/licenses/JSON.html",
+            Self::Jam => "https://spdx.org/licenses/Jam.html",
+            Self::JasPerTwoDotZero => "https://spdx.org/licenses/JasPer-2.0.html",
+            Self::Kastrup => "https://spdx.org/licenses/Kastrup.html",
+            Self::Kazlib => "https://spdx.org/licenses/Kazlib.html",
+            Self::KnuthCTAN => "https://spdx.org/licenses/Knuth-CTAN.html",
+            Self::LALOneDotTwo => "https://spdx.org/licenses/LAL-1.2.html",
+            Self::LALOneDotThree => "https://spdx.org/licenses/LAL-1.3.html",
+            Self::LGPLTwoDotZeroOnly => "https://spdx.org/licenses/LGPL-2.0-only.html",
+            Self::LGPLTwoDotZeroOrLater => "https://spdx.org/licenses/LGPL-2.0-or-later.html",
+            Self::LGPLTwoDotOneOnly => "https://spdx.org/licenses/LGPL-2.1-only.html",
+            Self::LGPLTwoDotOneOrLater => "https://spdx.org/licenses/LGPL-2.1-or-later.html",
+            Self::LGPLThreeDotZeroOnly => "https://spdx.org/licenses/LGPL-3.0-only.html",
+            Self::LGPLThreeDotZeroOrLater => "https://spdx.org/licenses/LGPL-3.0-or-later.html",
+            Self::LGPLLR => "https://spdx.org/licenses/LGPLLR.html",
+            Self::LOOP => "https://spdx.org/licenses/LOOP.html",
+            Self::LPDDocument => "https://spdx.org/licenses/LPD-document.html",
+            Self::LPLOneDotZero => "https://spdx.org/licenses/LPL-1.0.html",
+            Self::LPLOneDotZeroTwo => "https://spdx.org/licenses/LPL-1.02.html",
+            Self::LPPLOneDotZero => "https://spdx.org/licenses/LPPL-1.0.html",
+            Self::LPPLOneDotOne => "https://spdx.org/licenses/LPPL-1.1.html",
+            Self::LPPLOneDotTwo => "https://spdx.org/licenses/LPPL-1.2.html",
+            Self::LPPLOneDotThreea => "https://spdx.org/licenses/LPPL-1.3a.html",
+            Self::LPPLOneDotThreec => "https://spdx.org/licenses/LPPL-1.3c.html",
+            Self::LZMASDKNineDotOneOneToNineDotTwoZero => {
+                "https://spdx.org/licenses/LZMA-SDK-9.11-to-9.20.html"
+            }
+            Self::LZMASDKNineDotTwoTwo => "https://spdx.org/licenses/LZMA-SDK-9.22.html",
+            Self::LatexTwoe => "https://spdx.org/licenses/Latex2e.html",
+            Self::LatexTwoeTranslatedNotice => {
+                "https://spdx.org/licenses/Latex2e-translated-notice.html"
+            }
+            Self::Leptonica => "https://spdx.org/licenses/Leptonica.html",
+            Self::LiLiQPOneDotOne => "https://spdx.org/licenses/LiLiQ-P-1.1.html",
+            Self::LiLiQROneDotOne => "https://spdx.org/licenses/LiLiQ-R-1.1.html",
+            Self::LiLiQRplusOneDotOne => "https://spdx.org/licenses/LiLiQ-Rplus-1.1.html",
+            Self::Libpng => "https://spdx.org/licenses/Libpng.html",
+            Self::LinuxOpenIb => "https://spdx.org/licenses/Linux-OpenIB.html",
+            Self::LinuxManPagesOnePara => "https://spdx.org/licenses/Linux-man-pages-1-para.html",
+            Self::LinuxManPagesCopyleft => {
+                "https://spdx.org/licenses/Linux-man-pages-copyleft.html"
+            }
+            Self::LinuxManPagesCopyleftTwoPara => {
+                "https://spdx.org/licenses/Linux-man-pages-copyleft-2-para.html"
+            }
+            Self::LinuxManPagesCopyleftVar => {
+                "https://spdx.org/licenses/Linux-man-pages-copyleft-var.html"
+            }
+            Self::LucidaBitmapFonts => "https://spdx.org/licenses/Lucida-Bitmap-Fonts.html",
+            Self::MIPS => "https://spdx.org/licenses/MIPS.html",
+            Self::MIT => "https://spdx.org/licenses/MIT.html",
+            Self::MITZero => "https://spdx.org/licenses/MIT-0.html",
+            Self::MITCMU => "https://spdx.org/licenses/MIT-CMU.html",
+            Self::MITClick => "https://spdx.org/licenses/MIT-Click.html",
+            Self::MITFestival => "https://spdx.org/licenses/MIT-Festival.html",
+            Self::MITKhronosOld => "https://spdx.org/licenses/MIT-Khronos-old.html",
+            Self::MITModernVariant => "https://spdx.org/licenses/MIT-Modern-Variant.html",
+            Self::MITWu => "https://spdx.org/licenses/MIT-Wu.html",
+            Self::MITAdvertising => "https://spdx.org/licenses/MIT-advertising.html",
+            Self::MITEnna => "https://spdx.org/licenses/MIT-enna.html",
+            Self::MITFeh => "https://spdx.org/licenses/MIT-feh.html",
+            Self::MITOpenGroup => "https://spdx.org/licenses/MIT-open-group.html",
+            Self::MITTestregex => "https://spdx.org/licenses/MIT-testregex.html",
+            Self::MITNFA => "https://spdx.org/licenses/MITNFA.html",
+            Self::MmiXware => "https://spdx.org/licenses/MMIXware.html",
+            Self::MPEGSSG => "https://spdx.org/licenses/MPEG-SSG.html",
+            Self::MPLOneDotZero => "https://spdx.org/licenses/MPL-1.0.html",
+            Self::MPLOneDotOne => "https://spdx.org/licenses/MPL-1.1.html",
+            Self::MPLTwoDotZero => "https://spdx.org/licenses/MPL-2.0.html",
+            Self::MPLTwoDotZeroNoCopyleftException => {
+                "https://spdx.org/licenses/MPL-2.0-no-copyleft-exception.html"
+            }
+            Self::MSLPL => "https://spdx.org/licenses/MS-LPL.html",
+            Self::MSPL => "https://spdx.org/licenses/MS-PL.html",
+            Self::MSRL => "https://spdx.org/licenses/MS-RL.html",
+            Self::MTLL => "https://spdx.org/licenses/MTLL.html",
+            Self::MackerrasThreeClause => "https://spdx.org/licenses/Mackerras-3-Clause.html",
+            Self::MackerrasThreeClauseAcknowledgment => {
+                "https://spdx.org/licenses/Mackerras-3-Clause-acknowledgment.html"
+            }
+            Self::MakeIndex => "https://spdx.org/licenses/MakeIndex.html",
+            Self::MartinBirgmeier => "https://spdx.org/licenses/Martin-Birgmeier.html",
+            Self::McPheeSlideshow => "https://spdx.org/licenses/McPhee-slideshow.html",
+            Self::Minpack => "https://spdx.org/licenses/Minpack.html",
+            Self::MirOs => "https://spdx.org/licenses/MirOS.html",
+            Self::Motosoto => "https://spdx.org/licenses/Motosoto.html",
+            Self::MulanPslOneDotZero => "https://spdx.org/licenses/MulanPSL-1.0.html",
+            Self::MulanPslTwoDotZero => "https://spdx.org/licenses/MulanPSL-2.0.html",
+            Self::Multics => "https://spdx.org/licenses/Multics.html",
+            Self::Mup => "https://spdx.org/licenses/Mup.html",
+            Self::NAISTTwoZeroZeroThree => "https://spdx.org/licenses/NAIST-2003.html",
+            Self::NASAOneDotThree => "https://spdx.org/licenses/NASA-1.3.html",
+            Self::NBPLOneDotZero => "https://spdx.org/licenses/NBPL-1.0.html",
+            Self::NCBIPD => "https://spdx.org/licenses/NCBI-PD.html",
+            Self::NCGLUKTwoDotZero => "https://spdx.org/licenses/NCGL-UK-2.0.html",
+            Self::NCL => "https://spdx.org/licenses/NCL.html",
+            Self::NCSA => "https://spdx.org/licenses/NCSA.html",
+            Self::NGPL => "https://spdx.org/licenses/NGPL.html",
+            Self::NICTAOneDotZero => "https://spdx.org/licenses/NICTA-1.0.html",
+            Self::NISTPD => "https://spdx.org/licenses/NIST-PD.html",
+            Self::NISTPDFallback => "https://spdx.org/licenses/NIST-PD-fallback.html",
+            Self::NISTSoftware => "https://spdx.org/licenses/NIST-Software.html",
+            Self::NLODOneDotZero => "https://spdx.org/licenses/NLOD-1.0.html",
+            Self::NLODTwoDotZero => "https://spdx.org/licenses/NLOD-2.0.html",
+            Self::NLPL => "https://spdx.org/licenses/NLPL.html",
+            Self::NOSL => "https://spdx.org/licenses/NOSL.html",
+            Self::NPLOneDotZero => "https://spdx.org/licenses/NPL-1.0.html",
+            Self::NPLOneDotOne => "https://spdx.org/licenses/NPL-1.1.html",
+            Self::NPOSLThreeDotZero => "https://spdx.org/licenses/NPOSL-3.0.html",
+            Self::NRL => "https://spdx.org/licenses/NRL.html",
+            Self::NTIAPD => "https://spdx.org/licenses/NTIA-PD.html",
+            Self::NTP => "https://spdx.org/licenses/NTP.html",
+            Self::NTPZero => "https://spdx.org/licenses/NTP-0.html",
+            Self::Naumen => "https://spdx.org/licenses/Naumen.html",
+            Self::NetCdf => "https://spdx.org/licenses/NetCDF.html",
+            Self::Newsletr => "https://spdx.org/licenses/Newsletr.html",
+            Self::Nokia => "https://spdx.org/licenses/Nokia.html",
+            Self::Noweb => "https://spdx.org/licenses/Noweb.html",
+            Self::OUDAOneDotZero => "https://spdx.org/licenses/O-UDA-1.0.html",
+            Self::OAR => "https://spdx.org/licenses/OAR.html",
+            Self::OCCTPL => "https://spdx.org/licenses/OCCT-PL.html",
+            Self::OCLCTwoDotZero => "https://spdx.org/licenses/OCLC-2.0.html",
+            Self::ODCByOneDotZero => "https://spdx.org/licenses/ODC-By-1.0.html",
+            Self::ODbLOneDotZero => "https://spdx.org/licenses/ODbL-1.0.html",
+            Self::OFFIS => "https://spdx.org/licenses/OFFIS.html",
+            Self::OFLOneDotZero => "https://spdx.org/licenses/OFL-1.0.html",
+            Self::OFLOneDotZeroRFN => "https://spdx.org/licenses/OFL-1.0-RFN.html",
+            Self::OFLOneDotZeroNoRFN => "https://spdx.org/licenses/OFL-1.0-no-RFN.html",
+            Self::OFLOneDotOne => "https://spdx.org/licenses/OFL-1.1.html",
+            Self::OFLOneDotOneRFN => "https://spdx.org/licenses/OFL-1.1-RFN.html",
+            Self::OFLOneDotOneNoRFN => "https://spdx.org/licenses/OFL-1.1-no-RFN.html",
+            Self::OGCOneDotZero => "https://spdx.org/licenses/OGC-1.0.html",
+            Self::OGDLTaiwanOneDotZero => "https://spdx.org/licenses/OGDL-Taiwan-1.0.html",
+            Self::OGLCanadaTwoDotZero => "https://spdx.org/licenses/OGL-Canada-2.0.html",
+            Self::OGLUKOneDotZero => "https://spdx.org/licenses/OGL-UK-1.0.html",
+            Self::OGLUKTwoDotZero => "https://spdx.org/licenses/OGL-UK-2.0.html",
+            Self::OGLUKThreeDotZero => "https://spdx.org/licenses/OGL-UK-3.0.html",
+            Self::OGTSL => "https://spdx.org/licenses/OGTSL.html",
+            Self::OLDAPOneDotOne => "https://spdx.org/licenses/OLDAP-1.1.html",
+            Self::OLDAPOneDotTwo => "https://spdx.org/licenses/OLDAP-1.2.html",
+            Self::OLDAPOneDotThree => "https://spdx.org/licenses/OLDAP-1.3.html",
+            Self::OLDAPOneDotFour => "https://spdx.org/licenses/OLDAP-1.4.html",
+            Self::OLDAPTwoDotZero => "https://spdx.org/licenses/OLDAP-2.0.html",
+            Self::OLDAPTwoDotZeroDotOne => "https://spdx.org/licenses/OLDAP-2.0.1.html",
+            Self::OLDAPTwoDotOne => "https://spdx.org/licenses/OLDAP-2.1.html",
+            Self::OLDAPTwoDotTwo => "https://spdx.org/licenses/OLDAP-2.2.html",
+            Self::OLDAPTwoDotTwoDotOne => "https://spdx.org/licenses/OLDAP-2.2.1.html",
+            Self::OLDAPTwoDotTwoDotTwo => "https://spdx.org/licenses/OLDAP-2.2.2.html",
+            Self::OLDAPTwoDotThree => "https://spdx.org/licenses/OLDAP-2.3.html",
+            Self::OLDAPTwoDotFour => "https://spdx.org/licenses/OLDAP-2.4.html",
+            Self::OLDAPTwoDotFive => "https://spdx.org/licenses/OLDAP-2.5.html",
+            Self::OLDAPTwoDotSix => "https://spdx.org/licenses/OLDAP-2.6.html",
+            Self::OLDAPTwoDotSeven => "https://spdx.org/licenses/OLDAP-2.7.html",
+            Self::OLDAPTwoDotEight => "https://spdx.org/licenses/OLDAP-2.8.html",
+            Self::OLFLOneDotThree => "https://spdx.org/licenses/OLFL-1.3.html",
+            Self::OML => "https://spdx.org/licenses/OML.html",
+            Self::OPLOneDotZero => "https://spdx.org/licenses/OPL-1.0.html",
+            Self::OPLUKThreeDotZero => "https://spdx.org/licenses/OPL-UK-3.0.html",
+            Self::OPUBLOneDotZero => "https://spdx.org/licenses/OPUBL-1.0.html",
+            Self::OSETPLTwoDotOne => "https://spdx.org/licenses/OSET-PL-2.1.html",
+            Self::OSLOneDotZero => "https://spdx.org/licenses/OSL-1.0.html",
+            Self::OSLOneDotOne => "https://spdx.org/licenses/OSL-1.1.html",
+            Self::OSLTwoDotZero => "https://spdx.org/licenses/OSL-2.0.html",
+            Self::OSLTwoDotOne => "https://spdx.org/licenses/OSL-2.1.html",
+            Self::OSLThreeDotZero => "https://spdx.org/licenses/OSL-3.0.html",
+            Self::OpenPbsTwoDotThree => "https://spdx.org/licenses/OpenPBS-2.3.html",
+            Self::OpenSsl => "https://spdx.org/licenses/OpenSSL.html",
+            Self::OpenSslStandalone => "https://spdx.org/licenses/OpenSSL-standalone.html",
+            Self::OpenVision => "https://spdx.org/licenses/OpenVision.html",
+            Self::PADL => "https://spdx.org/licenses/PADL.html",
+            Self::PDDLOneDotZero => "https://spdx.org/licenses/PDDL-1.0.html",
+            Self::PHPThreeDotZero => "https://spdx.org/licenses/PHP-3.0.html",
+            Self::PHPThreeDotZeroOne => "https://spdx.org/licenses/PHP-3.01.html",
+            Self::PPL => "https://spdx.org/licenses/PPL.html",
+            Self::PSFTwoDotZero => "https://spdx.org/licenses/PSF-2.0.html",
+            Self::ParitySixDotZeroDotZero => "https://spdx.org/licenses/Parity-6.0.0.html",
+            Self::ParitySevenDotZeroDotZero => "https://spdx.org/licenses/Parity-7.0.0.html",
+            Self::Pixar => "https://spdx.org/licenses/Pixar.html",
+            Self::Plexus => "https://spdx.org/licenses/Plexus.html",
+            Self::PolyFormNoncommercialOneDotZeroDotZero => {
+                "https://spdx.org/licenses/PolyForm-Noncommercial-1.0.0.html"
+            }
+            Self::PolyFormSmallBusinessOneDotZeroDotZero => {
+                "https://spdx.org/licenses/PolyForm-Small-Business-1.0.0.html"
+            }
+            Self::PostgreSql => "https://spdx.org/licenses/PostgreSQL.html",
+            Self::PythonTwoDotZero => "https://spdx.org/licenses/Python-2.0.html",
+            Self::PythonTwoDotZeroDotOne => "https://spdx.org/licenses/Python-2.0.1.html",
+            Self::QPLOneDotZero => "https://spdx.org/licenses/QPL-1.0.html",
+            Self::QPLOneDotZeroINRIATwoZeroZeroFour => {
+                "https://spdx.org/licenses/QPL-1.0-INRIA-2004.html"
+            }
+            Self::Qhull => "https://spdx.org/licenses/Qhull.html",
+            Self::RHeCosOneDotOne => "https://spdx.org/licenses/RHeCos-1.1.html",
+            Self::RPLOneDotOne => "https://spdx.org/licenses/RPL-1.1.html",
+            Self::RPLOneDotFive => "https://spdx.org/licenses/RPL-1.5.html",
+            Self::RPSLOneDotZero => "https://spdx.org/licenses/RPSL-1.0.html",
+            Self::RSAMD => "https://spdx.org/licenses/RSA-MD.html",
+            Self::RSCPL => "https://spdx.org/licenses/RSCPL.html",
+            Self::Rdisc => "https://spdx.org/licenses/Rdisc.html",
+            Self::Ruby => "https://spdx.org/licenses/Ruby.html",
+            Self::RubyPty => "https://spdx.org/licenses/Ruby-pty.html",
+            Self::SAXPD => "https://spdx.org/licenses/SAX-PD.html",
+            Self::SAXPDTwoDotZero => "https://spdx.org/licenses/SAX-PD-2.0.html",
+            Self::SCEA => "https://spdx.org/licenses/SCEA.html",
+            Self::SGIBOneDotZero => "https://spdx.org/licenses/SGI-B-1.0.html",
+            Self::SGIBOneDotOne => "https://spdx.org/licenses/SGI-B-1.1.html",
+            Self::SGIBTwoDotZero => "https://spdx.org/licenses/SGI-B-2.0.html",
+            Self::SGIOpenGl => "https://spdx.org/licenses/SGI-OpenGL.html",
+            Self::SgpFour => "https://spdx.org/licenses/SGP4.html",
+            Self::SHLZeroDotFive => "https://spdx.org/licenses/SHL-0.5.html",
+            Self::SHLZeroDotFiveOne => "https://spdx.org/licenses/SHL-0.51.html",
+            Self::SISSL => "https://spdx.org/licenses/SISSL.html",
+            Self::SISSLOneDotTwo => "https://spdx.org/licenses/SISSL-1.2.html",
+            Self::SL => "https://spdx.org/licenses/SL.html",
+            Self::SMAILGPL => "https://spdx.org/licenses/SMAIL-GPL.html",
+            Self::SMLNJ => "https://spdx.org/licenses/SMLNJ.html",
+            Self::SMPPL => "https://spdx.org/licenses/SMPPL.html",
+            Self::SNIA => "https://spdx.org/licenses/SNIA.html",
+            Self::SPLOneDotZero => "https://spdx.org/licenses/SPL-1.0.html",
+            Self::SSHOpenSsh => "https://spdx.org/licenses/SSH-OpenSSH.html",
+            Self::SSHShort => "https://spdx.org/licenses/SSH-short.html",
+            Self::SsLeayStandalone => "https://spdx.org/licenses/SSLeay-standalone.html",
+            Self::SSPLOneDotZero => "https://spdx.org/licenses/SSPL-1.0.html",
+            Self::SWL => "https://spdx.org/licenses/SWL.html",
+            Self::Saxpath => "https://spdx.org/licenses/Saxpath.html",
+            Self::SchemeReport => "https://spdx.org/licenses/SchemeReport.html",
+            Self::Sendmail => "https://spdx.org/licenses/Sendmail.html",
+            Self::SendmailEightDotTwoThree => "https://spdx.org/licenses/Sendmail-8.23.html",
+            Self::SendmailOpenSourceOneDotOne => {
+                "https://spdx.org/licenses/Sendmail-Open-Source-1.1.html"
+            }
+            Self::SimPlTwoDotZero => "https://spdx.org/licenses/SimPL-2.0.html",
+            Self::Sleepycat => "https://spdx.org/licenses/Sleepycat.html",
+            Self::Soundex => "https://spdx.org/licenses/Soundex.html",
+            Self::SpencerEightSix => "https://spdx.org/licenses/Spencer-86.html",
+            Self::SpencerNineFour => "https://spdx.org/licenses/Spencer-94.html",
+            Self::SpencerNineNine => "https://spdx.org/licenses/Spencer-99.html",
+            Self::SugarCrmOneDotOneDotThree => "https://spdx.org/licenses/SugarCRM-1.1.3.html",
+            Self::SunPPP => "https://spdx.org/licenses/Sun-PPP.html",
+            Self::SunPPPTwoZeroZeroZero => "https://spdx.org/licenses/Sun-PPP-2000.html",
+            Self::SunPro => "https://spdx.org/licenses/SunPro.html",
+            Self::Symlinks => "https://spdx.org/licenses/Symlinks.html",
+            Self::TAPROHLOneDotZero => "https://spdx.org/licenses/TAPR-OHL-1.0.html",
+            Self::TCL => "https://spdx.org/licenses/TCL.html",
+            Self::TCPWrappers => "https://spdx.org/licenses/TCP-wrappers.html",
+            Self::TGPPLOneDotZero => "https://spdx.org/licenses/TGPPL-1.0.html",
+            Self::TMate => "https://spdx.org/licenses/TMate.html",
+            Self::TORQUEOneDotOne => "https://spdx.org/licenses/TORQUE-1.1.html",
+            Self::TOSL => "https://spdx.org/licenses/TOSL.html",
+            Self::TPDL => "https://spdx.org/licenses/TPDL.html",
+            Self::TPLOneDotZero => "https://spdx.org/licenses/TPL-1.0.html",
+            Self::TTWL => "https://spdx.org/licenses/TTWL.html",
+            Self::TtypZero => "https://spdx.org/licenses/TTYP0.html",
+            Self::TUBerlinOneDotZero => "https://spdx.org/licenses/TU-Berlin-1.0.html",
+            Self::TUBerlinTwoDotZero => "https://spdx.org/licenses/TU-Berlin-2.0.html",
+            Self::TermReadKey => "https://spdx.org/licenses/TermReadKey.html",
+            Self::ThirdEye => "https://spdx.org/licenses/ThirdEye.html",
+            Self::TrustedQsl => "https://spdx.org/licenses/TrustedQSL.html",
+            Self::UCAR => "https://spdx.org/licenses/UCAR.html",
+            Self::UCLOneDotZero => "https://spdx.org/licenses/UCL-1.0.html",
+            Self::UMichMerit => "https://spdx.org/licenses/UMich-Merit.html",
+            Self::UPLOneDotZero => "https://spdx.org/licenses/UPL-1.0.html",
+            Self::URTRLE => "https://spdx.org/licenses/URT-RLE.html",
+            Self::UbuntuFontOneDotZero => "https://spdx.org/licenses/Ubuntu-font-1.0.html",
+            Self::UnicodeThreeDotZero => "https://spdx.org/licenses/Unicode-3.0.html",
+            Self::UnicodeDFSTwoZeroOneFive => "https://spdx.org/licenses/Unicode-DFS-2015.html",
+            Self::UnicodeDFSTwoZeroOneSix => "https://spdx.org/licenses/Unicode-DFS-2016.html",
+            Self::UnicodeTOU => "https://spdx.org/licenses/Unicode-TOU.html",
+            Self::UnixCrypt => "https://spdx.org/licenses/UnixCrypt.html",
+            Self::Unlicense => "https://spdx.org/licenses/Unlicense.html",
+            Self::VOSTROM => "https://spdx.org/licenses/VOSTROM.html",
+            Self::VSLOneDotZero => "https://spdx.org/licenses/VSL-1.0.html",
+            Self::Vim => "https://spdx.org/licenses/Vim.html",
+            Self::WThreeC => "https://spdx.org/licenses/W3C.html",
+            Self::WThreeCOneNineNineEightZeroSevenTwoZero => {
+                "https://spdx.org/licenses/W3C-19980720.html"
+            }
+            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => {
+                "https://spdx.org/licenses/W3C-20150513.html"
+            }
+            Self::WTFPL => "https://spdx.org/licenses/WTFPL.html",
+            Self::WatcomOneDotZero => "https://spdx.org/licenses/Watcom-1.0.html",
+            Self::WidgetWorkshop => "https://spdx.org/licenses/Widget-Workshop.html",
+            Self::Wsuipa => "https://spdx.org/licenses/Wsuipa.html",
+            Self::XOneOne => "https://spdx.org/licenses/X11.html",
+            Self::XOneOneDistributeModificationsVariant => {
+                "https://spdx.org/licenses/X11-distribute-modifications-variant.html"
+            }
+            Self::XOneOneSwapped => "https://spdx.org/licenses/X11-swapped.html",
+            Self::XFreeEightSixOneDotOne => "https://spdx.org/licenses/XFree86-1.1.html",
+            Self::XSkat => "https://spdx.org/licenses/XSkat.html",
+            Self::XdebugOneDotZeroThree => "https://spdx.org/licenses/Xdebug-1.03.html",
+            Self::Xerox => "https://spdx.org/licenses/Xerox.html",
+            Self::Xfig => "https://spdx.org/licenses/Xfig.html",
+            Self::Xnet => "https://spdx.org/licenses/Xnet.html",
+            Self::YPLOneDotZero => "https://spdx.org/licenses/YPL-1.0.html",
+            Self::YPLOneDotOne => "https://spdx.org/licenses/YPL-1.1.html",
+            Self::ZPLOneDotOne => "https://spdx.org/licenses/ZPL-1.1.html",
+            Self::ZPLTwoDotZero => "https://spdx.org/licenses/ZPL-2.0.html",
+            Self::ZPLTwoDotOne => "https://spdx.org/licenses/ZPL-2.1.html",
+            Self::Zed => "https://spdx.org/licenses/Zed.html",
+            Self::Zeeff => "https://spdx.org/licenses/Zeeff.html",
+            Self::ZendTwoDotZero => "https://spdx.org/licenses/Zend-2.0.html",
+            Self::ZimbraOneDotThree => "https://spdx.org/licenses/Zimbra-1.3.html",
+            Self::ZimbraOneDotFour => "https://spdx.org/licenses/Zimbra-1.4.html",
+            Self::Zlib => "https://spdx.org/licenses/Zlib.html",
+            Self::AnyOSI => "https://spdx.org/licenses/any-OSI.html",
+            Self::AnyOSIPerlModules => "https://spdx.org/licenses/any-OSI-perl-modules.html",
+            Self::BcryptSolarDesigner => "https://spdx.org/licenses/bcrypt-Solar-Designer.html",
+            Self::Blessing => "https://spdx.org/licenses/blessing.html",
+            Self::BzipTwoOneDotZeroDotSix => "https://spdx.org/licenses/bzip2-1.0.6.html",
+            Self::CheckCvs => "https://spdx.org/licenses/check-cvs.html",
+            Self::Checkmk => "https://spdx.org/licenses/checkmk.html",
+            Self::CopyleftNextZeroDotThreeDotZero => {
+                "https://spdx.org/licenses/copyleft-next-0.3.0.html"
+            }
+            Self::CopyleftNextZeroDotThreeDotOne => {
+                "https://spdx.org/licenses/copyleft-next-0.3.1.html"
+            }
+            Self::Curl => "https://spdx.org/licenses/curl.html",
+            Self::CveTou => "https://spdx.org/licenses/cve-tou.html",
+            Self::DeprecatedAGPLOneDotZero => "https://spdx.org/licenses/AGPL-1.0.html",
+            Self::DeprecatedAGPLThreeDotZero => "https://spdx.org/licenses/AGPL-3.0.html",
+            Self::DeprecatedBSDTwoClauseFreeBsd => {
+                "https://spdx.org/licenses/BSD-2-Clause-FreeBSD.html"
+            }
+            Self::DeprecatedBSDTwoClauseNetBsd => {
+                "https://spdx.org/licenses/BSD-2-Clause-NetBSD.html"
+            }
+            Self::DeprecatedGFDLOneDotOne => "https://spdx.org/licenses/GFDL-1.1.html",
+            Self::DeprecatedGFDLOneDotTwo => "https://spdx.org/licenses/GFDL-1.2.html",
+            Self::DeprecatedGFDLOneDotThree => "https://spdx.org/licenses/GFDL-1.3.html",
+            Self::DeprecatedGPLOneDotZero => "https://spdx.org/licenses/GPL-1.0.html",
+            Self::DeprecatedGPLOneDotZeroPlus => "https://spdx.org/licenses/GPL-1.0+.html",
+            Self::DeprecatedGPLTwoDotZero => "https://spdx.org/licenses/GPL-2.0.html",
+            Self::DeprecatedGPLTwoDotZeroPlus => "https://spdx.org/licenses/GPL-2.0+.html",
+            Self::DeprecatedGPLTwoDotZeroWithGCCException => {
+                "https://spdx.org/licenses/GPL-2.0-with-GCC-exception.html"
+            }
+            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => {
+                "https://spdx.org/licenses/GPL-2.0-with-autoconf-exception.html"
+            }
+            Self::DeprecatedGPLTwoDotZeroWithBisonException => {
+                "https://spdx.org/licenses/GPL-2.0-with-bison-exception.html"
+            }
+            Self::DeprecatedGPLTwoDotZeroWithClasspathException => {
+                "https://spdx.org/licenses/GPL-2.0-with-classpath-exception.html"
+            }
+            Self::DeprecatedGPLTwoDotZeroWithFontException => {
+                "https://spdx.org/licenses/GPL-2.0-with-font-exception.html"
+            }
+            Self::DeprecatedGPLThreeDotZero => "https://spdx.org/licenses/GPL-3.0.html",
+            Self::DeprecatedGPLThreeDotZeroPlus => "https://spdx.org/licenses/GPL-3.0+.html",
+            Self::DeprecatedGPLThreeDotZeroWithGCCException => {
+                "https://spdx.org/licenses/GPL-3.0-with-GCC-exception.html"
+            }
+            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => {
+                "https://spdx.org/licenses/GPL-3.0-with-autoconf-exception.html"
+            }
+            Self::DeprecatedLGPLTwoDotZero => "https://spdx.org/licenses/LGPL-2.0.html",
+            Self::DeprecatedLGPLTwoDotZeroPlus => "https://spdx.org/licenses/LGPL-2.0+.html",
+            Self::DeprecatedLGPLTwoDotOne => "https://spdx.org/licenses/LGPL-2.1.html",
+            Self::DeprecatedLGPLTwoDotOnePlus => "https://spdx.org/licenses/LGPL-2.1+.html",
+            Self::DeprecatedLGPLThreeDotZero => "https://spdx.org/licenses/LGPL-3.0.html",
+            Self::DeprecatedLGPLThreeDotZeroPlus => "https://spdx.org/licenses/LGPL-3.0+.html",
+            Self::DeprecatedNetSNMP => "https://spdx.org/licenses/Net-SNMP.html",
+            Self::DeprecatedNunit => "https://spdx.org/licenses/Nunit.html",
+            Self::DeprecatedStandardMlNJ => "https://spdx.org/licenses/StandardML-NJ.html",
+            Self::DeprecatedBzipTwoOneDotZeroDotFive => {
+                "https://spdx.org/licenses/bzip2-1.0.5.html"
+            }
+            Self::DeprecatedECosTwoDotZero => "https://spdx.org/licenses/eCos-2.0.html",
+            Self::DeprecatedWxWindows => "https://spdx.org/licenses/wxWindows.html",
+            Self::Diffmark => "https://spdx.org/licenses/diffmark.html",
+            Self::Dtoa => "https://spdx.org/licenses/dtoa.html",
+            Self::Dvipdfm => "https://spdx.org/licenses/dvipdfm.html",
+            Self::EGenix => "https://spdx.org/licenses/eGenix.html",
+            Self::EtalabTwoDotZero => "https://spdx.org/licenses/etalab-2.0.html",
+            Self::Fwlw => "https://spdx.org/licenses/fwlw.html",
+            Self::GSoapOneDotThreeb => "https://spdx.org/licenses/gSOAP-1.3b.html",
+            Self::GenericXts => "https://spdx.org/licenses/generic-xts.html",
+            Self::Gnuplot => "https://spdx.org/licenses/gnuplot.html",
+            Self::Gtkbook => "https://spdx.org/licenses/gtkbook.html",
+            Self::Hdparm => "https://spdx.org/licenses/hdparm.html",
+            Self::IMatix => "https://spdx.org/licenses/iMatix.html",
+            Self::Jove => "https://spdx.org/licenses/jove.html",
+            Self::LibpngTwoDotZero => "https://spdx.org/licenses/libpng-2.0.html",
+            Self::LibselinuxOneDotZero => "https://spdx.org/licenses/libselinux-1.0.html",
+            Self::Libtiff => "https://spdx.org/licenses/libtiff.html",
+            Self::LibutilDavidNugent => "https://spdx.org/licenses/libutil-David-Nugent.html",
+            Self::Lsof => "https://spdx.org/licenses/lsof.html",
+            Self::Magaz => "https://spdx.org/licenses/magaz.html",
+            Self::Mailprio => "https://spdx.org/licenses/mailprio.html",
+            Self::ManTwohtml => "https://spdx.org/licenses/man2html.html",
+            Self::Metamail => "https://spdx.org/licenses/metamail.html",
+            Self::MpiPermissive => "https://spdx.org/licenses/mpi-permissive.html",
+            Self::MpichTwo => "https://spdx.org/licenses/mpich2.html",
+            Self::Mplus => "https://spdx.org/licenses/mplus.html",
+            Self::Pkgconf => "https://spdx.org/licenses/pkgconf.html",
+            Self::Pnmstitch => "https://spdx.org/licenses/pnmstitch.html",
+            Self::Psfrag => "https://spdx.org/licenses/psfrag.html",
+            Self::Psutils => "https://spdx.org/licenses/psutils.html",
+            Self::PythonLdap => "https://spdx.org/licenses/python-ldap.html",
+            Self::Radvd => "https://spdx.org/licenses/radvd.html",
+            Self::Snprintf => "https://spdx.org/licenses/snprintf.html",
+            Self::SoftSurfer => "https://spdx.org/licenses/softSurfer.html",
+            Self::SshKeyscan => "https://spdx.org/licenses/ssh-keyscan.html",
+            Self::Swrule => "https://spdx.org/licenses/swrule.html",
+            Self::Threeparttable => "https://spdx.org/licenses/threeparttable.html",
+            Self::Ulem => "https://spdx.org/licenses/ulem.html",
+            Self::WThreem => "https://spdx.org/licenses/w3m.html",
+            Self::Wwl => "https://spdx.org/licenses/wwl.html",
+            Self::Xinetd => "https://spdx.org/licenses/xinetd.html",
+            Self::XkeyboardConfigZinoviev => {
+                "https://spdx.org/licenses/xkeyboard-config-Zinoviev.html"
+            }
+            Self::Xlock => "https://spdx.org/licenses/xlock.html",
+            Self::Xpp => "https://spdx.org/licenses/xpp.html",
+            Self::Xzoom => "https://spdx.org/licenses/xzoom.html",
+            Self::ZlibAcknowledgement => "https://spdx.org/licenses/zlib-acknowledgement.html",
+        }
+    }
+    #[doc = r" Returns the other pages the SPDX license list gives for the text, ex: its upstream."]
+    pub fn see_also(&self) -> &'static [&'static str] {
+        match self {
+            Self::ZeroBsd => &[],
+            Self::ThreeDSlicerOneDotZero => &[],
+            Self::AAL => &[],
+            Self::ADSL => &[],
+            Self::AFLOneDotOne => &[],
+            Self::AFLOneDotTwo => &[],
+            Self::AFLTwoDotZero => &[],
+            Self::AFLTwoDotOne => &[],
+            Self::AFLThreeDotZero => &[],
+            Self::AGPLOneDotZeroOnly => &[],
+            Self::AGPLOneDotZeroOrLater => &[],
+            Self::AGPLThreeDotZeroOnly => &[],
+            Self::AGPLThreeDotZeroOrLater => &[],
+            Self::AMDNewlib => &[],
+            Self::AMDPLPA => &[],
+            Self::AML => &[],
+            Self::AMLGlslang => &[],
+            Self::AMPAS => &[],
+            Self::ANTLRPD => &[],
+            Self::ANTLRPDFallback => &[],
+            Self::APAFML => &[],
+            Self::APLOneDotZero => &[],
+            Self::APSLOneDotZero => &[],
+            Self::APSLOneDotOne => &[],
+            Self::APSLOneDotTwo => &[],
+            Self::APSLTwoDotZero => &[],
+            Self::ASWFDigitalAssetsOneDotZero => &[],
+            Self::ASWFDigitalAssetsOneDotOne => &[],
+            Self::Abstyles => &[],
+            Self::AdaCoreDoc => &[],
+            Self::AdobeTwoZeroZeroSix => &[],
+            Self::AdobeDisplayPostScript => &[],
+            Self::AdobeGlyph => &[],
+            Self::AdobeUtopia => &[],
+            Self::Afmparse => &[],
+            Self::Aladdin => &[],
+            Self::ApacheOneDotZero => &[],
+            Self::ApacheOneDotOne => &[],
+            Self::ApacheTwoDotZero => &[],
+            Self::AppSTwop => &[],
+            Self::ArphicOneNineNineNine => &[],
+            Self::ArtisticOneDotZero => &[],
+            Self::ArtisticOneDotZeroPerl => &[],
+            Self::ArtisticOneDotZeroClEight => &[],
+            Self::ArtisticTwoDotZero => &[],
+            Self::BSDOneClause => &[],
+            Self::BSDTwoClause => &[],
+            Self::BSDTwoClauseDarwin => &[],
+            Self::BSDTwoClausePatent => &[],
+            Self::BSDTwoClauseViews => &[],
+            Self::BSDTwoClauseFirstLines => &[],
+            Self::BSDTwoClausePkgconfDisclaimer => &[],
+            Self::BSDThreeClause => &[],
+            Self::BSDThreeClauseAttribution => &[],
+            Self::BSDThreeClauseClear => &[],
+            Self::BSDThreeClauseHP => &[],
+            Self::BSDThreeClauseLBNL => &[],
+            Self::BSDThreeClauseModification => &[],
+            Self::BSDThreeClauseNoMilitaryLicense => &[],
+            Self::BSDThreeClauseNoNuclearLicense => &[],
+            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => &[],
+            Self::BSDThreeClauseNoNuclearWarranty => &[],
+            Self::BSDThreeClauseOpenMPI => &[],
+            Self::BSDThreeClauseSun => &[],
+            Self::BSDThreeClauseAcpica => &[],
+            Self::BSDThreeClauseFlex => &[],
+            Self::BSDFourClause => &[],
+            Self::BSDFourClauseShortened => &[],
+            Self::BSDFourClauseUC => &[],
+            Self::BSDFourDotThreeReno => &[],
+            Self::BSDFourDotThreeTahoe => &[],
+            Self::BSDAdvertisingAcknowledgement => &[],
+            Self::BSDAttributionHPNDDisclaimer => &[],
+            Self::BSDInfernoNettverk => &[],
+            Self::BSDProtection => &[],
+            Self::BSDSourceCode => &[],
+            Self::BSDSourceBeginningFile => &[],
+            Self::BSDSystemics => &[],
+            Self::BSDSystemicsWThreeWorks => &[],
+            Self::BSLOneDotZero => &[],
+            Self::BUSLOneDotOne => &[],
+            Self::Baekmuk => &[],
+            Self::Bahyph => &[],
+            Self::Barr => &[],
+            Self::Beerware => &[],
+            Self::BitTorrentOneDotZero => &[],
+            Self::BitTorrentOneDotOne => &[],
+            Self::BitstreamCharter => &[],
+            Self::BitstreamVera => &[],
+            Self::BlueOakOneDotZeroDotZero => &[],
+            Self::BoehmGC => &[],
+            Self::BoehmGCWithoutFee => &[],
+            Self::Borceux => &[],
+            Self::BrianGladmanTwoClause => &[],
+            Self::BrianGladmanThreeClause => &[],
+            Self::CUDAOneDotZero => &[],
+            Self::CALOneDotZero => &[],
+            Self::CALOneDotZeroCombinedWorkException => &[],
+            Self::CATOSLOneDotOne => &[],
+            Self::CCBYOneDotZero => &[],
+            Self::CCBYTwoDotZero => &[],
+            Self::CCBYTwoDotFive => &[],
+            Self::CCBYTwoDotFiveAU => &[],
+            Self::CCBYThreeDotZero => &[],
+            Self::CCBYThreeDotZeroAT => &[],
+            Self::CCBYThreeDotZeroAU => &[],
+            Self::CCBYThreeDotZeroDE => &[],
+            Self::CCBYThreeDotZeroIGO => &[],
+            Self::CCBYThreeDotZeroNL => &[],
+            Self::CCBYThreeDotZeroUS => &[],
+            Self::CCBYFourDotZero => &[],
+            Self::CCBYNCOneDotZero => &[],
+            Self::CCBYNCTwoDotZero => &[],
+            Self::CCBYNCTwoDotFive => &[],
+            Self::CCBYNCThreeDotZero => &[],
+            Self::CCBYNCThreeDotZeroDE => &[],
+            Self::CCBYNCFourDotZero => &[],
+            Self::CCBYNCNDOneDotZero => &[],
+            Self::CCBYNCNDTwoDotZero => &[],
+            Self::CCBYNCNDTwoDotFive => &[],
+            Self::CCBYNCNDThreeDotZero => &[],
+            Self::CCBYNCNDThreeDotZeroDE => &[],
+            Self::CCBYNCNDThreeDotZeroIGO => &[],
+            Self::CCBYNCNDFourDotZero => &[],
+            Self::CCBYNCSAOneDotZero => &[],
+            Self::CCBYNCSATwoDotZero => &[],
+            Self::CCBYNCSATwoDotZeroDE => &[],
+            Self::CCBYNCSATwoDotZeroFR => &[],
+            Self::CCBYNCSATwoDotZeroUK => &[],
+            Self::CCBYNCSATwoDotFive => &[],
+            Self::CCBYNCSAThreeDotZero => &[],
+            Self::CCBYNCSAThreeDotZeroDE => &[],
+            Self::CCBYNCSAThreeDotZeroIGO => &[],
+            Self::CCBYNCSAFourDotZero => &[],
+            Self::CCBYNDOneDotZero => &[],
+            Self::CCBYNDTwoDotZero => &[],
+            Self::CCBYNDTwoDotFive => &[],
+            Self::CCBYNDThreeDotZero => &[],
+            Self::CCBYNDThreeDotZeroDE => &[],
+            Self::CCBYNDFourDotZero => &[],
+            Self::CCBYSAOneDotZero => &[],
+            Self::CCBYSATwoDotZero => &[],
+            Self::CCBYSATwoDotZeroUK => &[],
+            Self::CCBYSATwoDotOneJP => &[],
+            Self::CCBYSATwoDotFive => &[],
+            Self::CCBYSAThreeDotZero => &[],
+            Self::CCBYSAThreeDotZeroAT => &[],
+            Self::CCBYSAThreeDotZeroDE => &[],
+            Self::CCBYSAThreeDotZeroIGO => &[],
+            Self::CCBYSAFourDotZero => &[],
+            Self::CCPDDC => &[],
+            Self::CCPDMOneDotZero => &[],
+            Self::CCSAOneDotZero => &[],
+            Self::CcZeroOneDotZero => &[],
+            Self::CDDLOneDotZero => &[],
+            Self::CDDLOneDotOne => &[],
+            Self::CDLOneDotZero => &[],
+            Self::CDLAPermissiveOneDotZero => &[],
+            Self::CDLAPermissiveTwoDotZero => &[],
+            Self::CDLASharingOneDotZero => &[],
+            Self::CECILLOneDotZero => &[],
+            Self::CECILLOneDotOne => &[],
+            Self::CECILLTwoDotZero => &[],
+            Self::CECILLTwoDotOne => &[],
+            Self::CECILLB => &[],
+            Self::CECILLC => &[],
+            Self::CERNOHLOneDotOne => &[],
+            Self::CERNOHLOneDotTwo => &[],
+            Self::CERNOHLPTwoDotZero => &[],
+            Self::CERNOHLSTwoDotZero => &[],
+            Self::CERNOHLWTwoDotZero => &[],
+            Self::CFITSIO => &[],
+            Self::CMUMach => &[],
+            Self::CMUMachNodoc => &[],
+            Self::CNRIJython => &[],
+            Self::CNRIPython => &[],
+            Self::CNRIPythonGPLCompatible => &[],
+            Self::COILOneDotZero => &[],
+            Self::CPALOneDotZero => &[],
+            Self::CPLOneDotZero => &[],
+            Self::CPOLOneDotZeroTwo => &[],
+            Self::CUAOPLOneDotZero => &[],
+            Self::Caldera => &[],
+            Self::CalderaNoPreamble => &[],
+            Self::Catharon => &[],
+            Self::ClArtistic => &[],
+            Self::Clips => &[],
+            Self::CommunitySpecOneDotZero => &[],
+            Self::CondorOneDotOne => &[],
+            Self::CornellLosslessJPEG => &[],
+            Self::Cronyx => &[],
+            Self::Crossword => &[],
+            Self::CryptoSwift => &[],
+            Self::CrystalStacker => &[],
+            Self::Cube => &[],
+            Self::DFSLOneDotZero => &[],
+            Self::DECThreeClause => &[],
+            Self::DLDEBYTwoDotZero => &[],
+            Self::DLDEZEROTwoDotZero => &[],
+            Self::DOC => &[],
+            Self::DRLOneDotZero => &[],
+            Self::DRLOneDotOne => &[],
+            Self::DSDP => &[],
+            Self::DocBookDTD => &[],
+            Self::DocBookSchema => &[],
+            Self::DocBookStylesheet => &[],
+            Self::DocBookXML => &[],
+            Self::Dotseqn => &[],
+            Self::ECLOneDotZero => &[],
+            Self::ECLTwoDotZero => &[],
+            Self::EFLOneDotZero => &[],
+            Self::EFLTwoDotZero => &[],
+            Self::EPICS => &[],
+            Self::EPLOneDotZero => &[],
+            Self::EPLTwoDotZero => &[],
+            Self::EuDatagrid => &[],
+            Self::EUPLOneDotZero => &[],
+            Self::EUPLOneDotOne => &[],
+            Self::EUPLOneDotTwo => &[],
+            Self::ElasticTwoDotZero => &[],
+            Self::Entessa => &[],
+            Self::ErlPlOneDotOne => &[],
+            Self::Eurosym => &[],
+            Self::FBM => &[],
+            Self::FDKAAC => &[],
+            Self::FSFAP => &[],
+            Self::FSFAPNoWarrantyDisclaimer => &[],
+            Self::FSFUL => &[],
+            Self::FSFULLR => &[],
+            Self::FSFULLRWD => &[],
+            Self::FSLOneDotOneALvTwo => &[],
+            Self::FSLOneDotOneMIT => &[],
+            Self::FTL => &[],
+            Self::Fair => &[],
+            Self::FergusonTwofish => &[],
+            Self::FrameworxOneDotZero => &[],
+            Self::FreeBsdDOC => &[],
+            Self::FreeImage => &[],
+            Self::Furuseth => &[],
+            Self::GCRDocs => &[],
+            Self::GD => &[],
+            Self::GFDLOneDotOneInvariantsOnly => &[],
+            Self::GFDLOneDotOneInvariantsOrLater => &[],
+            Self::GFDLOneDotOneNoInvariantsOnly => &[],
+            Self::GFDLOneDotOneNoInvariantsOrLater => &[],
+            Self::GFDLOneDotOneOnly => &[],
+            Self::GFDLOneDotOneOrLater => &[],
+            Self::GFDLOneDotTwoInvariantsOnly => &[],
+            Self::GFDLOneDotTwoInvariantsOrLater => &[],
+            Self::GFDLOneDotTwoNoInvariantsOnly => &[],
+            Self::GFDLOneDotTwoNoInvariantsOrLater => &[],
+            Self::GFDLOneDotTwoOnly => &[],
+            Self::GFDLOneDotTwoOrLater => &[],
+            Self::GFDLOneDotThreeInvariantsOnly => &[],
+            Self::GFDLOneDotThreeInvariantsOrLater => &[],
+            Self::GFDLOneDotThreeNoInvariantsOnly => &[],
+            Self::GFDLOneDotThreeNoInvariantsOrLater => &[],
+            Self::GFDLOneDotThreeOnly => &[],
+            Self::GFDLOneDotThreeOrLater => &[],
+            Self::GlTwoPs => &[],
+            Self::GLWTPL => &[],
+            Self::GPLOneDotZeroOnly => &[],
+            Self::GPLOneDotZeroOrLater => &[],
+            Self::GPLTwoDotZeroOnly => &[],
+            Self::GPLTwoDotZeroOrLater => &[],
+            Self::GPLThreeDotZeroOnly => &[],
+            Self::GPLThreeDotZeroOrLater => &[],
+            Self::GameProgrammingGems => &[],
+            Self::Giftware => &[],
+            Self::Glide => &[],
+            Self::Glulxe => &[],
+            Self::GraphicsGems => &[],
+            Self::Gutmann => &[],
+            Self::HIDAPI => &[],
+            Self::HPOneNineEightSix => &[],
+            Self::HPOneNineEightNine => &[],
+            Self::HPND => &[],
+            Self::HPNDDEC => &[],
+            Self::HPNDFennebergLivingston => &[],
+            Self::HPNDINRIAIMAG => &[],
+            Self::HPNDIntel => &[],
+            Self::HPNDKevlinHenney => &[],
+            Self::HPNDMITDisclaimer => &[],
+            Self::HPNDMarkusKuhn => &[],
+            Self::HPNDNetrek => &[],
+            Self::HPNDPbmplus => &[],
+            Self::HPNDUC => &[],
+            Self::HPNDUCExportUS => &[],
+            Self::HPNDDoc => &[],
+            Self::HPNDDocSell => &[],
+            Self::HPNDExportUS => &[],
+            Self::HPNDExportUSAcknowledgement => &[],
+            Self::HPNDExportUSModify => &[],
+            Self::HPNDExportTwoUS => &[],
+            Self::HPNDMerchantabilityVariant => &[],
+            Self::HPNDSellMITDisclaimerXserver => &[],
+            Self::HPNDSellRegexpr => &[],
+            Self::HPNDSellVariant => &[],
+            Self::HPNDSellVariantMITDisclaimer => &[],
+            Self::HPNDSellVariantMITDisclaimerRev => &[],
+            Self::HTMLTIDY => &[],
+            Self::HaskellReport => &[],
+            Self::HippocraticTwoDotOne => &[],
+            Self::IBMPibs => &[],
+            Self::ICU => &[],
+            Self::IECCodeComponentsEULA => &[],
+            Self::IJG => &[],
+            Self::IJGShort => &[],
+            Self::IPA => &[],
+            Self::IPLOneDotZero => &[],
+            Self::ISC => &[],
+            Self::ISCVeillard => &[],
+            Self::ImageMagick => &[],
+            Self::ImlibTwo => &[],
+            Self::InfoZIP => &[],
+            Self::InnerNetTwoDotZero => &[],
+            Self::InnoSetup => &[],
+            Self::Intel => &[],
+            Self::IntelACPI => &[],
+            Self::InterbaseOneDotZero => &[],
+            Self::JPLImage => &[],
+            Self::JPNIC => &[],
+            Self::JSON => &[],
+            Self::Jam => &[],
+            Self::JasPerTwoDotZero => &[],
+            Self::Kastrup => &[],
+            Self::Kazlib => &[],
+            Self::KnuthCTAN => &[],
+            Self::LALOneDotTwo => &[],
+            Self::LALOneDotThree => &[],
+            Self::LGPLTwoDotZeroOnly => &[],
+            Self::LGPLTwoDotZeroOrLater => &[],
+            Self::LGPLTwoDotOneOnly => &[],
+            Self::LGPLTwoDotOneOrLater => &[],
+            Self::LGPLThreeDotZeroOnly => &[],
+            Self::LGPLThreeDotZeroOrLater => &[],
+            Self::LGPLLR => &[],
+            Self::LOOP => &[],
+            Self::LPDDocument => &[],
+            Self::LPLOneDotZero => &[],
+            Self::LPLOneDotZeroTwo => &[],
+            Self::LPPLOneDotZero => &[],
+            Self::LPPLOneDotOne => &[],
+            Self::LPPLOneDotTwo => &[],
+            Self::LPPLOneDotThreea => &[],
+            Self::LPPLOneDotThreec => &[],
+            Self::LZMASDKNineDotOneOneToNineDotTwoZero => &[],
+            Self::LZMASDKNineDotTwoTwo => &[],
+            Self::LatexTwoe => &[],
+            Self::LatexTwoeTranslatedNotice => &[],
+            Self::Leptonica => &[],
+            Self::LiLiQPOneDotOne => &[],
+            Self::LiLiQROneDotOne => &[],
+            Self::LiLiQRplusOneDotOne => &[],
+            Self::Libpng => &[],
+            Self::LinuxOpenIb => &[],
+            Self::LinuxManPagesOnePara => &[],
+            Self::LinuxManPagesCopyleft => &[],
+            Self::LinuxManPagesCopyleftTwoPara => &[],
+            Self::LinuxManPagesCopyleftVar => &[],
+            Self::LucidaBitmapFonts => &[],
+            Self::MIPS => &[],
+            Self::MIT => &[],
+            Self::MITZero => &[],
+            Self::MITCMU => &[],
+            Self::MITClick => &[],
+            Self::MITFestival => &[],
+            Self::MITKhronosOld => &[],
+            Self::MITModernVariant => &[],
+            Self::MITWu => &[],
+            Self::MITAdvertising => &[],
+            Self::MITEnna => &[],
+            Self::MITFeh => &[],
+            Self::MITOpenGroup => &[],
+            Self::MITTestregex => &[],
+            Self::MITNFA => &[],
+            Self::MmiXware => &[],
+            Self::MPEGSSG => &[],
+            Self::MPLOneDotZero => &[],
+            Self::MPLOneDotOne => &[],
+            Self::MPLTwoDotZero => &[],
+            Self::MPLTwoDotZeroNoCopyleftException => &[],
+            Self::MSLPL => &[],
+            Self::MSPL => &[],
+            Self::MSRL => &[],
+            Self::MTLL => &[],
+            Self::MackerrasThreeClause => &[],
+            Self::MackerrasThreeClauseAcknowledgment => &[],
+            Self::MakeIndex => &[],
+            Self::MartinBirgmeier => &[],
+            Self::McPheeSlideshow => &[],
+            Self::Minpack => &[],
+            Self::MirOs => &[],
+            Self::Motosoto => &[],
+            Self::MulanPslOneDotZero => &[],
+            Self::MulanPslTwoDotZero => &[],
+            Self::Multics => &[],
+            Self::Mup => &[],
+            Self::NAISTTwoZeroZeroThree => &[],
+            Self::NASAOneDotThree => &[],
+            Self::NBPLOneDotZero => &[],
+            Self::NCBIPD => &[],
+            Self::NCGLUKTwoDotZero => &[],
+            Self::NCL => &[],
+            Self::NCSA => &[],
+            Self::NGPL => &[],
+            Self::NICTAOneDotZero => &[],
+            Self::NISTPD => &[],
+            Self::NISTPDFallback => &[],
+            Self::NISTSoftware => &[],
+            Self::NLODOneDotZero => &[],
+            Self::NLODTwoDotZero => &[],
+            Self::NLPL => &[],
+            Self::NOSL => &[],
+            Self::NPLOneDotZero => &[],
+            Self::NPLOneDotOne => &[],
+            Self::NPOSLThreeDotZero => &[],
+            Self::NRL => &[],
+            Self::NTIAPD => &[],
+            Self::NTP => &[],
+            Self::NTPZero => &[],
+            Self::Naumen => &[],
+            Self::NetCdf => &[],
+            Self::Newsletr => &[],
+            Self::Nokia => &[],
+            Self::Noweb => &[],
+            Self::OUDAOneDotZero => &[],
+            Self::OAR => &[],
+            Self::OCCTPL => &[],
+            Self::OCLCTwoDotZero => &[],
+            Self::ODCByOneDotZero => &[],
+            Self::ODbLOneDotZero => &[],
+            Self::OFFIS => &[],
+            Self::OFLOneDotZero => &[],
+            Self::OFLOneDotZeroRFN => &[],
+            Self::OFLOneDotZeroNoRFN => &[],
+            Self::OFLOneDotOne => &[],
+            Self::OFLOneDotOneRFN => &[],
+            Self::OFLOneDotOneNoRFN => &[],
+            Self::OGCOneDotZero => &[],
+            Self::OGDLTaiwanOneDotZero => &[],
+            Self::OGLCanadaTwoDotZero => &[],
+            Self::OGLUKOneDotZero => &[],
+            Self::OGLUKTwoDotZero => &[],
+            Self::OGLUKThreeDotZero => &[],
+            Self::OGTSL => &[],
+            Self::OLDAPOneDotOne => &[],
+            Self::OLDAPOneDotTwo => &[],
+            Self::OLDAPOneDotThree => &[],
+            Self::OLDAPOneDotFour => &[],
+            Self::OLDAPTwoDotZero => &[],
+            Self::OLDAPTwoDotZeroDotOne => &[],
+            Self::OLDAPTwoDotOne => &[],
+            Self::OLDAPTwoDotTwo => &[],
+            Self::OLDAPTwoDotTwoDotOne => &[],
+            Self::OLDAPTwoDotTwoDotTwo => &[],
+            Self::OLDAPTwoDotThree => &[],
+            Self::OLDAPTwoDotFour => &[],
+            Self::OLDAPTwoDotFive => &[],
+            Self::OLDAPTwoDotSix => &[],
+            Self::OLDAPTwoDotSeven => &[],
+            Self::OLDAPTwoDotEight => &[],
+            Self::OLFLOneDotThree => &[],
+            Self::OML => &[],
+            Self::OPLOneDotZero => &[],
+            Self::OPLUKThreeDotZero => &[],
+            Self::OPUBLOneDotZero => &[],
+            Self::OSETPLTwoDotOne => &[],
+            Self::OSLOneDotZero => &[],
+            Self::OSLOneDotOne => &[],
+            Self::OSLTwoDotZero => &[],
+            Self::OSLTwoDotOne => &[],
+            Self::OSLThreeDotZero => &[],
+            Self::OpenPbsTwoDotThree => &[],
+            Self::OpenSsl => &[],
+            Self::OpenSslStandalone => &[],
+            Self::OpenVision => &[],
+            Self::PADL => &[],
+            Self::PDDLOneDotZero => &[],
+            Self::PHPThreeDotZero => &[],
+            Self::PHPThreeDotZeroOne => &[],
+            Self::PPL => &[],
+            Self::PSFTwoDotZero => &[],
+            Self::ParitySixDotZeroDotZero => &[],
+            Self::ParitySevenDotZeroDotZero => &[],
+            Self::Pixar => &[],
+            Self::Plexus => &[],
+            Self::PolyFormNoncommercialOneDotZeroDotZero => &[],
+            Self::PolyFormSmallBusinessOneDotZeroDotZero => &[],
+            Self::PostgreSql => &[],
+            Self::PythonTwoDotZero => &[],
+            Self::PythonTwoDotZeroDotOne => &[],
+            Self::QPLOneDotZero => &[],
+            Self::QPLOneDotZeroINRIATwoZeroZeroFour => &[],
+            Self::Qhull => &[],
+            Self::RHeCosOneDotOne => &[],
+            Self::RPLOneDotOne => &[],
+            Self::RPLOneDotFive => &[],
+            Self::RPSLOneDotZero => &[],
+            Self::RSAMD => &[],
+            Self::RSCPL => &[],
+            Self::Rdisc => &[],
+            Self::Ruby => &[],
+            Self::RubyPty => &[],
+            Self::SAXPD => &[],
+            Self::SAXPDTwoDotZero => &[],
+            Self::SCEA => &[],
+            Self::SGIBOneDotZero => &[],
+            Self::SGIBOneDotOne => &[],
+            Self::SGIBTwoDotZero => &[],
+            Self::SGIOpenGl => &[],
+            Self::SgpFour => &[],
+            Self::SHLZeroDotFive => &[],
+            Self::SHLZeroDotFiveOne => &[],
+            Self::SISSL => &[],
+            Self::SISSLOneDotTwo => &[],
+            Self::SL => &[],
+            Self::SMAILGPL => &[],
+            Self::SMLNJ => &[],
+            Self::SMPPL => &[],
+            Self::SNIA => &[],
+            Self::SPLOneDotZero => &[],
+            Self::SSHOpenSsh => &[],
+            Self::SSHShort => &[],
+            Self::SsLeayStandalone => &[],
+            Self::SSPLOneDotZero => &[],
+            Self::SWL => &[],
+            Self::Saxpath => &[],
+            Self::SchemeReport => &[],
+            Self::Sendmail => &[],
+            Self::SendmailEightDotTwoThree => &[],
+            Self::SendmailOpenSourceOneDotOne => &[],
+            Self::SimPlTwoDotZero => &[],
+            Self::Sleepycat => &[],
+            Self::Soundex => &[],
+            Self::SpencerEightSix => &[],
+            Self::SpencerNineFour => &[],
+            Self::SpencerNineNine => &[],
+            Self::SugarCrmOneDotOneDotThree => &[],
+            Self::SunPPP => &[],
+            Self::SunPPPTwoZeroZeroZero => &[],
+            Self::SunPro => &[],
+            Self::Symlinks => &[],
+            Self::TAPROHLOneDotZero => &[],
+            Self::TCL => &[],
+            Self::TCPWrappers => &[],
+            Self::TGPPLOneDotZero => &[],
+            Self::TMate => &[],
+            Self::TORQUEOneDotOne => &[],
+            Self::TOSL => &[],
+            Self::TPDL => &[],
+            Self::TPLOneDotZero => &[],
+            Self::TTWL => &[],
+            Self::TtypZero => &[],
+            Self::TUBerlinOneDotZero => &[],
+            Self::TUBerlinTwoDotZero => &[],
+            Self::TermReadKey => &[],
+            Self::ThirdEye => &[],
+            Self::TrustedQsl => &[],
+            Self::UCAR => &[],
+            Self::UCLOneDotZero => &[],
+            Self::UMichMerit => &[],
+            Self::UPLOneDotZero => &[],
+            Self::URTRLE => &[],
+            Self::UbuntuFontOneDotZero => &[],
+            Self::UnicodeThreeDotZero => &[],
+            Self::UnicodeDFSTwoZeroOneFive => &[],
+            Self::UnicodeDFSTwoZeroOneSix => &[],
+            Self::UnicodeTOU => &[],
+            Self::UnixCrypt => &[],
+            Self::Unlicense => &[],
+            Self::VOSTROM => &[],
+            Self::VSLOneDotZero => &[],
+            Self::Vim => &[],
+            Self::WThreeC => &[],
+            Self::WThreeCOneNineNineEightZeroSevenTwoZero => &[],
+            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => &[],
+            Self::WTFPL => &[],
+            Self::WatcomOneDotZero => &[],
+            Self::WidgetWorkshop => &[],
+            Self::Wsuipa => &[],
+            Self::XOneOne => &[],
+            Self::XOneOneDistributeModificationsVariant => &[],
+            Self::XOneOneSwapped => &[],
+            Self::XFreeEightSixOneDotOne => &[],
+            Self::XSkat => &[],
+            Self::XdebugOneDotZeroThree => &[],
+            Self::Xerox => &[],
+            Self::Xfig => &[],
+            Self::Xnet => &[],
+            Self::YPLOneDotZero => &[],
+            Self::YPLOneDotOne => &[],
+            Self::ZPLOneDotOne => &[],
+            Self::ZPLTwoDotZero => &[],
+            Self::ZPLTwoDotOne => &[],
+            Self::Zed => &[],
+            Self::Zeeff => &[],
+            Self::ZendTwoDotZero => &[],
+            Self::ZimbraOneDotThree => &[],
+            Self::ZimbraOneDotFour => &[],
+            Self::Zlib => &[],
+            Self::AnyOSI => &[],
+            Self::AnyOSIPerlModules => &[],
+            Self::BcryptSolarDesigner => &[],
+            Self::Blessing => &[],
+            Self::BzipTwoOneDotZeroDotSix => &[],
+            Self::CheckCvs => &[],
+            Self::Checkmk => &[],
+            Self::CopyleftNextZeroDotThreeDotZero => &[],
+            Self::CopyleftNextZeroDotThreeDotOne => &[],
+            Self::Curl => &[],
+            Self::CveTou => &[],
+            Self::DeprecatedAGPLOneDotZero => &[],
+            Self::DeprecatedAGPLThreeDotZero => &[],
+            Self::DeprecatedBSDTwoClauseFreeBsd => &[],
+            Self::DeprecatedBSDTwoClauseNetBsd => &[],
+            Self::DeprecatedGFDLOneDotOne => &[],
+            Self::DeprecatedGFDLOneDotTwo => &[],
+            Self::DeprecatedGFDLOneDotThree => &[],
+            Self::DeprecatedGPLOneDotZero => &[],
+            Self::DeprecatedGPLOneDotZeroPlus => &[],
+            Self::DeprecatedGPLTwoDotZero => &[],
+            Self::DeprecatedGPLTwoDotZeroPlus => &[],
+            Self::DeprecatedGPLTwoDotZeroWithGCCException => &[],
+            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => &[],
+            Self::DeprecatedGPLTwoDotZeroWithBisonException => &[],
+            Self::DeprecatedGPLTwoDotZeroWithClasspathException => &[],
+            Self::DeprecatedGPLTwoDotZeroWithFontException => &[],
+            Self::DeprecatedGPLThreeDotZero => &[],
+            Self::DeprecatedGPLThreeDotZeroPlus => &[],
+            Self::DeprecatedGPLThreeDotZeroWithGCCException => &[],
+            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => &[],
+            Self::DeprecatedLGPLTwoDotZero => &[],
+            Self::DeprecatedLGPLTwoDotZeroPlus => &[],
+            Self::DeprecatedLGPLTwoDotOne => &[],
+            Self::DeprecatedLGPLTwoDotOnePlus => &[],
+            Self::DeprecatedLGPLThreeDotZero => &[],
+            Self::DeprecatedLGPLThreeDotZeroPlus => &[],
+            Self::DeprecatedNetSNMP => &[],
+            Self::DeprecatedNunit => &[],
+            Self::DeprecatedStandardMlNJ => &[],
+            Self::DeprecatedBzipTwoOneDotZeroDotFive => &[],
+            Self::DeprecatedECosTwoDotZero => &[],
+            Self::DeprecatedWxWindows => &[],
+            Self::Diffmark => &[],
+            Self::Dtoa => &[],
+            Self::Dvipdfm => &[],
+            Self::EGenix => &[],
+            Self::EtalabTwoDotZero => &[],
+            Self::Fwlw => &[],
+            Self::GSoapOneDotThreeb => &[],
+            Self::GenericXts => &[],
+            Self::Gnuplot => &[],
+            Self::Gtkbook => &[],
+            Self::Hdparm => &[],
+            Self::IMatix => &[],
+            Self::Jove => &[],
+            Self::LibpngTwoDotZero => &[],
+            Self::LibselinuxOneDotZero => &[],
+            Self::Libtiff => &[],
+            Self::LibutilDavidNugent => &[],
+            Self::Lsof => &[],
+            Self::Magaz => &[],
+            Self::Mailprio => &[],
+            Self::ManTwohtml => &[],
+            Self::Metamail => &[],
+            Self::MpiPermissive => &[],
+            Self::MpichTwo => &[],
+            Self::Mplus => &[],
+            Self::Pkgconf => &[],
+            Self::Pnmstitch => &[],
+            Self::Psfrag => &[],
+            Self::Psutils => &[],
+            Self::PythonLdap => &[],
+            Self::Radvd => &[],
+            Self::Snprintf => &[],
+            Self::SoftSurfer => &[],
+            Self::SshKeyscan => &[],
+            Self::Swrule => &[],
+            Self::Threeparttable => &[],
+            Self::Ulem => &[],
+            Self::WThreem => &[],
+            Self::Wwl => &[],
+            Self::Xinetd => &[],
+            Self::XkeyboardConfigZinoviev => &[],
+            Self::Xlock => &[],
+            Self::Xpp => &[],
+            Self::Xzoom => &[],
+            Self::ZlibAcknowledgement => &[],
+        }
+    }
+    #[doc = r" Returns true if the SPDX identifier is deprecated."]
+    pub fn is_deprecated(&self) -> bool {
+        matches!(
+            self,
+            Self::DeprecatedAGPLOneDotZero
+                | Self::DeprecatedAGPLThreeDotZero
+                | Self::DeprecatedBSDTwoClauseFreeBsd
+                | Self::DeprecatedBSDTwoClauseNetBsd
+                | Self::DeprecatedGFDLOneDotOne
+                | Self::DeprecatedGFDLOneDotTwo
+                | Self::DeprecatedGFDLOneDotThree
+                | Self::DeprecatedGPLOneDotZero
+                | Self::DeprecatedGPLOneDotZeroPlus
+                | Self::DeprecatedGPLTwoDotZero
+                | Self::DeprecatedGPLTwoDotZeroPlus
+                | Self::DeprecatedGPLTwoDotZeroWithGCCException
+                | Self::DeprecatedGPLTwoDotZeroWithAutoconfException
+                | Self::DeprecatedGPLTwoDotZeroWithBisonException
+                | Self::DeprecatedGPLTwoDotZeroWithClasspathException
+                | Self::DeprecatedGPLTwoDotZeroWithFontException
+                | Self::DeprecatedGPLThreeDotZero
+                | Self::DeprecatedGPLThreeDotZeroPlus
+                | Self::DeprecatedGPLThreeDotZeroWithGCCException
+                | Self::DeprecatedGPLThreeDotZeroWithAutoconfException
+                | Self::DeprecatedLGPLTwoDotZero
+                | Self::DeprecatedLGPLTwoDotZeroPlus
+                | Self::DeprecatedLGPLTwoDotOne
+                | Self::DeprecatedLGPLTwoDotOnePlus
+                | Self::DeprecatedLGPLThreeDotZero
+                | Self::DeprecatedLGPLThreeDotZeroPlus
+                | Self::DeprecatedNetSNMP
+                | Self::DeprecatedNunit
+                | Self::DeprecatedStandardMlNJ
+                | Self::DeprecatedBzipTwoOneDotZeroDotFive
+                | Self::DeprecatedECosTwoDotZero
+                | Self::DeprecatedWxWindows
+        )
+    }
+    #[doc = r" Returns the SPDX expression recommended in place of a deprecated identifier, if any."]
+    pub fn replacement(&self) -> Option<&'static str> {
+        match self {
+            Self::ZeroBsd => None,
+            Self::ThreeDSlicerOneDotZero => None,
+            Self::AAL => None,
+            Self::ADSL => None,
+            Self::AFLOneDotOne => None,
+            Self::AFLOneDotTwo => None,
+            Self::AFLTwoDotZero => None,
+            Self::AFLTwoDotOne => None,
+            Self::AFLThreeDotZero => None,
+            Self::AGPLOneDotZeroOnly => None,
+            Self::AGPLOneDotZeroOrLater => None,
+            Self::AGPLThreeDotZeroOnly => None,
+            Self::AGPLThreeDotZeroOrLater => None,
+            Self::AMDNewlib => None,
+            Self::AMDPLPA => None,
+            Self::AML => None,
+            Self::AMLGlslang => None,
+            Self::AMPAS => None,
+            Self::ANTLRPD => None,
+            Self::ANTLRPDFallback => None,
+            Self::APAFML => None,
+            Self::APLOneDotZero => None,
+            Self::APSLOneDotZero => None,
+            Self::APSLOneDotOne => None,
+            Self::APSLOneDotTwo => None,
+            Self::APSLTwoDotZero => None,
+            Self::ASWFDigitalAssetsOneDotZero => None,
+            Self::ASWFDigitalAssetsOneDotOne => None,
+            Self::Abstyles => None,
+            Self::AdaCoreDoc => None,
+            Self::AdobeTwoZeroZeroSix => None,
+            Self::AdobeDisplayPostScript => None,
+            Self::AdobeGlyph => None,
+            Self::AdobeUtopia => None,
+            Self::Afmparse => None,
+            Self::Aladdin => None,
+            Self::ApacheOneDotZero => None,
+            Self::ApacheOneDotOne => None,
+            Self::ApacheTwoDotZero => None,
+            Self::AppSTwop => None,
+            Self::ArphicOneNineNineNine => None,
+            Self::ArtisticOneDotZero => None,
+            Self::ArtisticOneDotZeroPerl => None,
+            Self::ArtisticOneDotZeroClEight => None,
+            Self::ArtisticTwoDotZero => None,
+            Self::BSDOneClause => None,
+            Self::BSDTwoClause => None,
+            Self::BSDTwoClauseDarwin => None,
+            Self::BSDTwoClausePatent => None,
+            Self::BSDTwoClauseViews => None,
+            Self::BSDTwoClauseFirstLines => None,
+            Self::BSDTwoClausePkgconfDisclaimer => None,
+            Self::BSDThreeClause => None,
+            Self::BSDThreeClauseAttribution => None,
+            Self::BSDThreeClauseClear => None,
+            Self::BSDThreeClauseHP => None,
+            Self::BSDThreeClauseLBNL => None,
+            Self::BSDThreeClauseModification => None,
+            Self::BSDThreeClauseNoMilitaryLicense => None,
+            Self::BSDThreeClauseNoNuclearLicense => None,
+            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => None,
+            Self::BSDThreeClauseNoNuclearWarranty => None,
+            Self::BSDThreeClauseOpenMPI => None,
+            Self::BSDThreeClauseSun => None,
+            Self::BSDThreeClauseAcpica => None,
+            Self::BSDThreeClauseFlex => None,
+            Self::BSDFourClause => None,
+            Self::BSDFourClauseShortened => None,
+            Self::BSDFourClauseUC => None,
+            Self::BSDFourDotThreeReno => None,
+            Self::BSDFourDotThreeTahoe => None,
+            Self::BSDAdvertisingAcknowledgement => None,
+            Self::BSDAttributionHPNDDisclaimer => None,
+            Self::BSDInfernoNettverk => None,
+            Self::BSDProtection => None,
+            Self::BSDSourceCode => None,
+            Self::BSDSourceBeginningFile => None,
+            Self::BSDSystemics => None,
+            Self::BSDSystemicsWThreeWorks => None,
+            Self::BSLOneDotZero => None,
+            Self::BUSLOneDotOne => None,
+            Self::Baekmuk => None,
+            Self::Bahyph => None,
+            Self::Barr => None,
+            Self::Beerware => None,
+            Self::BitTorrentOneDotZero => None,
+            Self::BitTorrentOneDotOne => None,
+            Self::BitstreamCharter => None,
+            Self::BitstreamVera => None,
+            Self::BlueOakOneDotZeroDotZero => None,
+            Self::BoehmGC => None,
+            Self::BoehmGCWithoutFee => None,
+            Self::Borceux => None,
+            Self::BrianGladmanTwoClause => None,
+            Self::BrianGladmanThreeClause => None,
+            Self::CUDAOneDotZero => None,
+            Self::CALOneDotZero => None,
+            Self::CALOneDotZeroCombinedWorkException => None,
+            Self::CATOSLOneDotOne => None,
+            Self::CCBYOneDotZero => None,
+            Self::CCBYTwoDotZero => None,
+            Self::CCBYTwoDotFive => None,
+            Self::CCBYTwoDotFiveAU => None,
+            Self::CCBYThreeDotZero => None,
+            Self::CCBYThreeDotZeroAT => None,
+            Self::CCBYThreeDotZeroAU => None,
+            Self::CCBYThreeDotZeroDE => None,
+            Self::CCBYThreeDotZeroIGO => None,
+            Self::CCBYThreeDotZeroNL => None,
+            Self::CCBYThreeDotZeroUS => None,
+            Self::CCBYFourDotZero => None,
+            Self::CCBYNCOneDotZero => None,
+            Self::CCBYNCTwoDotZero => None,
+            Self::CCBYNCTwoDotFive => None,
+            Self::CCBYNCThreeDotZero => None,
+            Self::CCBYNCThreeDotZeroDE => None,
+            Self::CCBYNCFourDotZero => None,
+            Self::CCBYNCNDOneDotZero => None,
+            Self::CCBYNCNDTwoDotZero => None,
+            Self::CCBYNCNDTwoDotFive => None,
+            Self::CCBYNCNDThreeDotZero => None,
+            Self::CCBYNCNDThreeDotZeroDE => None,
+            Self::CCBYNCNDThreeDotZeroIGO => None,
+            Self::CCBYNCNDFourDotZero => None,
+            Self::CCBYNCSAOneDotZero => None,
+            Self::CCBYNCSATwoDotZero => None,
+            Self::CCBYNCSATwoDotZeroDE => None,
+            Self::CCBYNCSATwoDotZeroFR => None,
+            Self::CCBYNCSATwoDotZeroUK => None,
+            Self::CCBYNCSATwoDotFive => None,
+            Self::CCBYNCSAThreeDotZero => None,
+            Self::CCBYNCSAThreeDotZeroDE => None,
+            Self::CCBYNCSAThreeDotZeroIGO => None,
+            Self::CCBYNCSAFourDotZero => None,
+            Self::CCBYNDOneDotZero => None,
+            Self::CCBYNDTwoDotZero => None,
+            Self::CCBYNDTwoDotFive => None,
+            Self::CCBYNDThreeDotZero => None,
+            Self::CCBYNDThreeDotZeroDE => None,
+            Self::CCBYNDFourDotZero => None,
+            Self::CCBYSAOneDotZero => None,
+            Self::CCBYSATwoDotZero => None,
+            Self::CCBYSATwoDotZeroUK => None,
+            Self::CCBYSATwoDotOneJP => None,
+            Self::CCBYSATwoDotFive => None,
+            Self::CCBYSAThreeDotZero => None,
+            Self::CCBYSAThreeDotZeroAT => None,
+            Self::CCBYSAThreeDotZeroDE => None,
+            Self::CCBYSAThreeDotZeroIGO => None,
+            Self::CCBYSAFourDotZero => None,
+            Self::CCPDDC => None,
+            Self::CCPDMOneDotZero => None,
+            Self::CCSAOneDotZero => None,
+            Self::CcZeroOneDotZero => None,
+            Self::CDDLOneDotZero => None,
+            Self::CDDLOneDotOne => None,
+            Self::CDLOneDotZero => None,
+            Self::CDLAPermissiveOneDotZero => None,
+            Self::CDLAPermissiveTwoDotZero => None,
+            Self::CDLASharingOneDotZero => None,
+            Self::CECILLOneDotZero => None,
+            Self::CECILLOneDotOne => None,
+            Self::CECILLTwoDotZero => None,
+            Self::CECILLTwoDotOne => None,
+            Self::CECILLB => None,
+            Self::CECILLC => None,
+            Self::CERNOHLOneDotOne => None,
+            Self::CERNOHLOneDotTwo => None,
+            Self::CERNOHLPTwoDotZero => None,
+            Self::CERNOHLSTwoDotZero => None,
+            Self::CERNOHLWTwoDotZero => None,
+            Self::CFITSIO => None,
+            Self::CMUMach => None,
+            Self::CMUMachNodoc => None,
+            Self::CNRIJython => None,
+            Self::CNRIPython => None,
+            Self::CNRIPythonGPLCompatible => None,
+            Self::COILOneDotZero => None,
+            Self::CPALOneDotZero => None,
+            Self::CPLOneDotZero => None,
+            Self::CPOLOneDotZeroTwo => None,
+            Self::CUAOPLOneDotZero => None,
+            Self::Caldera => None,
+            Self::CalderaNoPreamble => None,
+            Self::Catharon => None,
+            Self::ClArtistic => None,
+            Self::Clips => None,
+            Self::CommunitySpecOneDotZero => None,
+            Self::CondorOneDotOne => None,
+            Self::CornellLosslessJPEG => None,
+            Self::Cronyx => None,
+            Self::Crossword => None,
+            Self::CryptoSwift => None,
+            Self::CrystalStacker => None,
+            Self::Cube => None,
+            Self::DFSLOneDotZero => None,
+            Self::DECThreeClause => None,
+            Self::DLDEBYTwoDotZero => None,
+            Self::DLDEZEROTwoDotZero => None,
+            Self::DOC => None,
+            Self::DRLOneDotZero => None,
+            Self::DRLOneDotOne => None,
+            Self::DSDP => None,
+            Self::DocBookDTD => None,
+            Self::DocBookSchema => None,
+            Self::DocBookStylesheet => None,
+            Self::DocBookXML => None,
+            Self::Dotseqn => None,
+            Self::ECLOneDotZero => None,
+            Self::ECLTwoDotZero => None,
+            Self::EFLOneDotZero => None,
+            Self::EFLTwoDotZero => None,
+            Self::EPICS => None,
+            Self::EPLOneDotZero => None,
+            Self::EPLTwoDotZero => None,
+            Self::EuDatagrid => None,
+            Self::EUPLOneDotZero => None,
+            Self::EUPLOneDotOne => None,
+            Self::EUPLOneDotTwo => None,
+            Self::ElasticTwoDotZero => None,
+            Self::Entessa => None,
+            Self::ErlPlOneDotOne => None,
+            Self::Eurosym => None,
+            Self::FBM => None,
+            Self::FDKAAC => None,
+            Self::FSFAP => None,
+            Self::FSFAPNoWarrantyDisclaimer => None,
+            Self::FSFUL => None,
+            Self::FSFULLR => None,
+            Self::FSFULLRWD => None,
+            Self::FSLOneDotOneALvTwo => None,
+            Self::FSLOneDotOneMIT => None,
+            Self::FTL => None,
+            Self::Fair => None,
+            Self::FergusonTwofish => None,
+            Self::FrameworxOneDotZero => None,
+            Self::FreeBsdDOC => None,
+            Self::FreeImage => None,
+            Self::Furuseth => None,
+            Self::GCRDocs => None,
+            Self::GD => None,
+            Self::GFDLOneDotOneInvariantsOnly => None,
+            Self::GFDLOneDotOneInvariantsOrLater => None,
+            Self::GFDLOneDotOneNoInvariantsOnly => None,
+            Self::GFDLOneDotOneNoInvariantsOrLater => None,
+            Self::GFDLOneDotOneOnly => None,
+            Self::GFDLOneDotOneOrLater => None,
+            Self::GFDLOneDotTwoInvariantsOnly => None,
+            Self::GFDLOneDotTwoInvariantsOrLater => None,
+            Self::GFDLOneDotTwoNoInvariantsOnly => None,
+            Self::GFDLOneDotTwoNoInvariantsOrLater => None,
+            Self::GFDLOneDotTwoOnly => None,
+            Self::GFDLOneDotTwoOrLater => None,
+            Self::GFDLOneDotThreeInvariantsOnly => None,
+            Self::GFDLOneDotThreeInvariantsOrLater => None,
+            Self::GFDLOneDotThreeNoInvariantsOnly => None,
+            Self::GFDLOneDotThreeNoInvariantsOrLater => None,
+            Self::GFDLOneDotThreeOnly => None,
+            Self::GFDLOneDotThreeOrLater => None,
+            Self::GlTwoPs => None,
+            Self::GLWTPL => None,
+            Self::GPLOneDotZeroOnly => None,
+            Self::GPLOneDotZeroOrLater => None,
+            Self::GPLTwoDotZeroOnly => None,
+            Self::GPLTwoDotZeroOrLater => None,
+            Self::GPLThreeDotZeroOnly => None,
+            Self::GPLThreeDotZeroOrLater => None,
+            Self::GameProgrammingGems => None,
+            Self::Giftware => None,
+            Self::Glide => None,
+            Self::Glulxe => None,
+            Self::GraphicsGems => None,
+            Self::Gutmann => None,
+            Self::HIDAPI => None,
+            Self::HPOneNineEightSix => None,
+            Self::HPOneNineEightNine => None,
+            Self::HPND => None,
+            Self::HPNDDEC => None,
+            Self::HPNDFennebergLivingston => None,
+            Self::HPNDINRIAIMAG => None,
+            Self::HPNDIntel => None,
+            Self::HPNDKevlinHenney => None,
+            Self::HPNDMITDisclaimer => None,
+            Self::HPNDMarkusKuhn => None,
+            Self::HPNDNetrek => None,
+            Self::HPNDPbmplus => None,
+            Self::HPNDUC => None,
+            Self::HPNDUCExportUS => None,
+            Self::HPNDDoc => None,
+            Self::HPNDDocSell => None,
+            Self::HPNDExportUS => None,
+            Self::HPNDExportUSAcknowledgement => None,
+            Self::HPNDExportUSModify => None,
+            Self::HPNDExportTwoUS => None,
+            Self::HPNDMerchantabilityVariant => None,
+            Self::HPNDSellMITDisclaimerXserver => None,
+            Self::HPNDSellRegexpr => None,
+            Self::HPNDSellVariant => None,
+            Self::HPNDSellVariantMITDisclaimer => None,
+            Self::HPNDSellVariantMITDisclaimerRev => None,
+            Self::HTMLTIDY => None,
+            Self::HaskellReport => None,
+            Self::HippocraticTwoDotOne => None,
+            Self::IBMPibs => None,
+            Self::ICU => None,
+            Self::IECCodeComponentsEULA => None,
+            Self::IJG => None,
+            Self::IJGShort => None,
+            Self::IPA => None,
+            Self::IPLOneDotZero => None,
+            Self::ISC => None,
+            Self::ISCVeillard => None,
+            Self::ImageMagick => None,
+            Self::ImlibTwo => None,
+            Self::InfoZIP => None,
+            Self::InnerNetTwoDotZero => None,
+            Self::InnoSetup => None,
+            Self::Intel => None,
+            Self::IntelACPI => None,
+            Self::InterbaseOneDotZero => None,
+            Self::JPLImage => None,
+            Self::JPNIC => None,
+            Self::JSON => None,
+            Self::Jam => None,
+            Self::JasPerTwoDotZero => None,
+            Self::Kastrup => None,
+            Self::Kazlib => None,
+            Self::KnuthCTAN => None,
+            Self::LALOneDotTwo => None,
+            Self::LALOneDotThree => None,
+            Self::LGPLTwoDotZeroOnly => None,
+            Self::LGPLTwoDotZeroOrLater => None,
+            Self::LGPLTwoDotOneOnly => None,
+            Self::LGPLTwoDotOneOrLater => None,
+            Self::LGPLThreeDotZeroOnly => None,
+            Self::LGPLThreeDotZeroOrLater => None,
+            Self::LGPLLR => None,
+            Self::LOOP => None,
+            Self::LPDDocument => None,
+            Self::LPLOneDotZero => None,
+            Self::LPLOneDotZeroTwo => None,
+            Self::LPPLOneDotZero => None,
+            Self::LPPLOneDotOne => None,
+            Self::LPPLOneDotTwo => None,
+            Self::LPPLOneDotThreea => None,
+            Self::LPPLOneDotThreec => None,
+            Self::LZMASDKNineDotOneOneToNineDotTwoZero => None,
+            Self::LZMASDKNineDotTwoTwo => None,
+            Self::LatexTwoe => None,
+            Self::LatexTwoeTranslatedNotice => None,
+            Self::Leptonica => None,
+            Self::LiLiQPOneDotOne => None,
+            Self::LiLiQROneDotOne => None,
+            Self::LiLiQRplusOneDotOne => None,
+            Self::Libpng => None,
+            Self::LinuxOpenIb => None,
+            Self::LinuxManPagesOnePara => None,
+            Self::LinuxManPagesCopyleft => None,
+            Self::LinuxManPagesCopyleftTwoPara => None,
+            Self::LinuxManPagesCopyleftVar => None,
+            Self::LucidaBitmapFonts => None,
+            Self::MIPS => None,
+            Self::MIT => None,
+            Self::MITZero => None,
+            Self::MITCMU => None,
+            Self::MITClick => None,
+            Self::MITFestival => None,
+            Self::MITKhronosOld => None,
+            Self::MITModernVariant => None,
+            Self::MITWu => None,
+            Self::MITAdvertising => None,
+            Self::MITEnna => None,
+            Self::MITFeh => None,
+            Self::MITOpenGroup => None,
+            Self::MITTestregex => None,
+            Self::MITNFA => None,
+            Self::MmiXware => None,
+            Self::MPEGSSG => None,
+            Self::MPLOneDotZero => None,
+            Self::MPLOneDotOne => None,
+            Self::MPLTwoDotZero => None,
+            Self::MPLTwoDotZeroNoCopyleftException => None,
+            Self::MSLPL => None,
+            Self::MSPL => None,
+            Self::MSRL => None,
+            Self::MTLL => None,
+            Self::MackerrasThreeClause => None,
+            Self::MackerrasThreeClauseAcknowledgment => None,
+            Self::MakeIndex => None,
+            Self::MartinBirgmeier => None,
+            Self::McPheeSlideshow => None,
+            Self::Minpack => None,
+            Self::MirOs => None,
+            Self::Motosoto => None,
+            Self::MulanPslOneDotZero => None,
+            Self::MulanPslTwoDotZero => None,
+            Self::Multics => None,
+            Self::Mup => None,
+            Self::NAISTTwoZeroZeroThree => None,
+            Self::NASAOneDotThree => None,
+            Self::NBPLOneDotZero => None,
+            Self::NCBIPD => None,
+            Self::NCGLUKTwoDotZero => None,
+            Self::NCL => None,
+            Self::NCSA => None,
+            Self::NGPL => None,
+            Self::NICTAOneDotZero => None,
+            Self::NISTPD => None,
+            Self::NISTPDFallback => None,
+            Self::NISTSoftware => None,
+            Self::NLODOneDotZero => None,
+            Self::NLODTwoDotZero => None,
+            Self::NLPL => None,
+            Self::NOSL => None,
+            Self::NPLOneDotZero => None,
+            Self::NPLOneDotOne => None,
+            Self::NPOSLThreeDotZero => None,
+            Self::NRL => None,
+            Self::NTIAPD => None,
+            Self::NTP => None,
+            Self::NTPZero => None,
+            Self::Naumen => None,
+            Self::NetCdf => None,
+            Self::Newsletr => None,
+            Self::Nokia => None,
+            Self::Noweb => None,
+            Self::OUDAOneDotZero => None,
+            Self::OAR => None,
+            Self::OCCTPL => None,
+            Self::OCLCTwoDotZero => None,
+            Self::ODCByOneDotZero => None,
+            Self::ODbLOneDotZero => None,
+            Self::OFFIS => None,
+            Self::OFLOneDotZero => None,
+            Self::OFLOneDotZeroRFN => None,
+            Self::OFLOneDotZeroNoRFN => None,
+            Self::OFLOneDotOne => None,
+            Self::OFLOneDotOneRFN => None,
+            Self::OFLOneDotOneNoRFN => None,
+            Self::OGCOneDotZero => None,
+            Self::OGDLTaiwanOneDotZero => None,
+            Self::OGLCanadaTwoDotZero => None,
+            Self::OGLUKOneDotZero => None,
+            Self::OGLUKTwoDotZero => None,
+            Self::OGLUKThreeDotZero => None,
+            Self::OGTSL => None,
+            Self::OLDAPOneDotOne => None,
+            Self::OLDAPOneDotTwo => None,
+            Self::OLDAPOneDotThree => None,
+            Self::OLDAPOneDotFour => None,
+            Self::OLDAPTwoDotZero => None,
+            Self::OLDAPTwoDotZeroDotOne => None,
+            Self::OLDAPTwoDotOne => None,
+            Self::OLDAPTwoDotTwo => None,
+            Self::OLDAPTwoDotTwoDotOne => None,
+            Self::OLDAPTwoDotTwoDotTwo => None,
+            Self::OLDAPTwoDotThree => None,
+            Self::OLDAPTwoDotFour => None,
+            Self::OLDAPTwoDotFive => None,
+            Self::OLDAPTwoDotSix => None,
+            Self::OLDAPTwoDotSeven => None,
+            Self::OLDAPTwoDotEight => None,
+            Self::OLFLOneDotThree => None,
+            Self::OML => None,
+            Self::OPLOneDotZero => None,
+            Self::OPLUKThreeDotZero => None,
+            Self::OPUBLOneDotZero => None,
+            Self::OSETPLTwoDotOne => None,
+            Self::OSLOneDotZero => None,
+            Self::OSLOneDotOne => None,
+            Self::OSLTwoDotZero => None,
+            Self::OSLTwoDotOne => None,
+            Self::OSLThreeDotZero => None,
+            Self::OpenPbsTwoDotThree => None,
+            Self::OpenSsl => None,
+            Self::OpenSslStandalone => None,
+            Self::OpenVision => None,
+            Self::PADL => None,
+            Self::PDDLOneDotZero => None,
+            Self::PHPThreeDotZero => None,
+            Self::PHPThreeDotZeroOne => None,
+            Self::PPL => None,
+            Self::PSFTwoDotZero => None,
+            Self::ParitySixDotZeroDotZero => None,
+            Self::ParitySevenDotZeroDotZero => None,
+            Self::Pixar => None,
+            Self::Plexus => None,
+            Self::PolyFormNoncommercialOneDotZeroDotZero => None,
+            Self::PolyFormSmallBusinessOneDotZeroDotZero => None,
+            Self::PostgreSql => None,
+            Self::PythonTwoDotZero => None,
+            Self::PythonTwoDotZeroDotOne => None,
+            Self::QPLOneDotZero => None,
+            Self::QPLOneDotZeroINRIATwoZeroZeroFour => None,
+            Self::Qhull => None,
+            Self::RHeCosOneDotOne => None,
+            Self::RPLOneDotOne => None,
+            Self::RPLOneDotFive => None,
+            Self::RPSLOneDotZero => None,
+            Self::RSAMD => None,
+            Self::RSCPL => None,
+            Self::Rdisc => None,
+            Self::Ruby => None,
+            Self::RubyPty => None,
+            Self::SAXPD => None,
+            Self::SAXPDTwoDotZero => None,
+            Self::SCEA => None,
+            Self::SGIBOneDotZero => None,
+            Self::SGIBOneDotOne => None,
+            Self::SGIBTwoDotZero => None,
+            Self::SGIOpenGl => None,
+            Self::SgpFour => None,
+            Self::SHLZeroDotFive => None,
+            Self::SHLZeroDotFiveOne => None,
+            Self::SISSL => None,
+            Self::SISSLOneDotTwo => None,
+            Self::SL => None,
+            Self::SMAILGPL => None,
+            Self::SMLNJ => None,
+            Self::SMPPL => None,
+            Self::SNIA => None,
+            Self::SPLOneDotZero => None,
+            Self::SSHOpenSsh => None,
+            Self::SSHShort => None,
+            Self::SsLeayStandalone => None,
+            Self::SSPLOneDotZero => None,
+            Self::SWL => None,
+            Self::Saxpath => None,
+            Self::SchemeReport => None,
+            Self::Sendmail => None,
+            Self::SendmailEightDotTwoThree => None,
+            Self::SendmailOpenSourceOneDotOne => None,
+            Self::SimPlTwoDotZero => None,
+            Self::Sleepycat => None,
+            Self::Soundex => None,
+            Self::SpencerEightSix => None,
+            Self::SpencerNineFour => None,
+            Self::SpencerNineNine => None,
+            Self::SugarCrmOneDotOneDotThree => None,
+            Self::SunPPP => None,
+            Self::SunPPPTwoZeroZeroZero => None,
+            Self::SunPro => None,
+            Self::Symlinks => None,
+            Self::TAPROHLOneDotZero => None,
+            Self::TCL => None,
+            Self::TCPWrappers => None,
+            Self::TGPPLOneDotZero => None,
+            Self::TMate => None,
+            Self::TORQUEOneDotOne => None,
+            Self::TOSL => None,
+            Self::TPDL => None,
+            Self::TPLOneDotZero => None,
+            Self::TTWL => None,
+            Self::TtypZero => None,
+            Self::TUBerlinOneDotZero => None,
+            Self::TUBerlinTwoDotZero => None,
+            Self::TermReadKey => None,
+            Self::ThirdEye => None,
+            Self::TrustedQsl => None,
+            Self::UCAR => None,
+            Self::UCLOneDotZero => None,
+            Self::UMichMerit => None,
+            Self::UPLOneDotZero => None,
+            Self::URTRLE => None,
+            Self::UbuntuFontOneDotZero => None,
+            Self::UnicodeThreeDotZero => None,
+            Self::UnicodeDFSTwoZeroOneFive => None,
+            Self::UnicodeDFSTwoZeroOneSix => None,
+            Self::UnicodeTOU => None,
+            Self::UnixCrypt => None,
+            Self::Unlicense => None,
+            Self::VOSTROM => None,
+            Self::VSLOneDotZero => None,
+            Self::Vim => None,
+            Self::WThreeC => None,
+            Self::WThreeCOneNineNineEightZeroSevenTwoZero => None,
+            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => None,
+            Self::WTFPL => None,
+            Self::WatcomOneDotZero => None,
+            Self::WidgetWorkshop => None,
+            Self::Wsuipa => None,
+            Self::XOneOne => None,
+            Self::XOneOneDistributeModificationsVariant => None,
+            Self::XOneOneSwapped => None,
+            Self::XFreeEightSixOneDotOne => None,
+            Self::XSkat => None,
+            Self::XdebugOneDotZeroThree => None,
+            Self::Xerox => None,
+            Self::Xfig => None,
+            Self::Xnet => None,
+            Self::YPLOneDotZero => None,
+            Self::YPLOneDotOne => None,
+            Self::ZPLOneDotOne => None,
+            Self::ZPLTwoDotZero => None,
+            Self::ZPLTwoDotOne => None,
+            Self::Zed => None,
+            Self::Zeeff => None,
+            Self::ZendTwoDotZero => None,
+            Self::ZimbraOneDotThree => None,
+            Self::ZimbraOneDotFour => None,
+            Self::Zlib => None,
+            Self::AnyOSI => None,
+            Self::AnyOSIPerlModules => None,
+            Self::BcryptSolarDesigner => None,
+            Self::Blessing => None,
+            Self::BzipTwoOneDotZeroDotSix => None,
+            Self::CheckCvs => None,
+            Self::Checkmk => None,
+            Self::CopyleftNextZeroDotThreeDotZero => None,
+            Self::CopyleftNextZeroDotThreeDotOne => None,
+            Self::Curl => None,
+            Self::CveTou => None,
+            Self::DeprecatedAGPLOneDotZero => Some("AGPL-1.0-only"),
+            Self::DeprecatedAGPLThreeDotZero => Some("AGPL-3.0-only"),
+            Self::DeprecatedBSDTwoClauseFreeBsd => Some("BSD-2-Clause-Views"),
+            Self::DeprecatedBSDTwoClauseNetBsd => Some("BSD-2-Clause"),
+            Self::DeprecatedGFDLOneDotOne => Some("GFDL-1.1-only"),
+            Self::DeprecatedGFDLOneDotTwo => Some("GFDL-1.2-only"),
+            Self::DeprecatedGFDLOneDotThree => Some("GFDL-1.3-only"),
+            Self::DeprecatedGPLOneDotZero => Some("GPL-1.0-only"),
+            Self::DeprecatedGPLOneDotZeroPlus => Some("GPL-1.0-or-later"),
+            Self::DeprecatedGPLTwoDotZero => Some("GPL-2.0-only"),
+            Self::DeprecatedGPLTwoDotZeroPlus => Some("GPL-2.0-or-later"),
+            Self::DeprecatedGPLTwoDotZeroWithGCCException => {
+                Some("GPL-2.0-only WITH GCC-exception-2.0")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => {
+                Some("GPL-2.0-only WITH Autoconf-exception-2.0")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithBisonException => {
+                Some("GPL-2.0-only WITH Bison-exception-2.2")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithClasspathException => {
+                Some("GPL-2.0-only WITH Classpath-exception-2.0")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithFontException => {
+                Some("GPL-2.0-only WITH Font-exception-2.0")
+            }
+            Self::DeprecatedGPLThreeDotZero => Some("GPL-3.0-only"),
+            Self::DeprecatedGPLThreeDotZeroPlus => Some("GPL-3.0-or-later"),
+            Self::DeprecatedGPLThreeDotZeroWithGCCException => {
+                Some("GPL-3.0-only WITH GCC-exception-3.1")
+            }
+            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => {
+                Some("GPL-3.0-only WITH Autoconf-exception-3.0")
+            }
+            Self::DeprecatedLGPLTwoDotZero => Some("LGPL-2.0-only"),
+            Self::DeprecatedLGPLTwoDotZeroPlus => Some("LGPL-2.0-or-later"),
+            Self::DeprecatedLGPLTwoDotOne => Some("LGPL-2.1-only"),
+            Self::DeprecatedLGPLTwoDotOnePlus => Some("LGPL-2.1-or-later"),
+            Self::DeprecatedLGPLThreeDotZero => Some("LGPL-3.0-only"),
+            Self::DeprecatedLGPLThreeDotZeroPlus => Some("LGPL-3.0-or-later"),
+            Self::DeprecatedNetSNMP => None,
+            Self::DeprecatedNunit => Some("zlib-acknowledgement"),
+            Self::DeprecatedStandardMlNJ => Some("SMLNJ"),
+            Self::DeprecatedBzipTwoOneDotZeroDotFive => Some("bzip2-1.0.6"),
+            Self::DeprecatedECosTwoDotZero => Some("GPL-2.0-or-later WITH eCos-exception-2.0"),
+            Self::DeprecatedWxWindows => Some("GPL-2.0-or-later WITH WxWindows-exception-3.1"),
+            Self::Diffmark => None,
+            Self::Dtoa => None,
+            Self::Dvipdfm => None,
+            Self::EGenix => None,
+            Self::EtalabTwoDotZero => None,
+            Self::Fwlw => None,
+            Self::GSoapOneDotThreeb => None,
+            Self::GenericXts => None,
+            Self::Gnuplot => None,
+            Self::Gtkbook => None,
+            Self::Hdparm => None,
+            Self::IMatix => None,
+            Self::Jove => None,
+            Self::LibpngTwoDotZero => None,
+            Self::LibselinuxOneDotZero => None,
+            Self::Libtiff => None,
+            Self::LibutilDavidNugent => None,
+            Self::Lsof => None,
+            Self::Magaz => None,
+            Self::Mailprio => None,
+            Self::ManTwohtml => None,
+            Self::Metamail => None,
+            Self::MpiPermissive => None,
+            Self::MpichTwo => None,
+            Self::Mplus => None,
+            Self::Pkgconf => None,
+            Self::Pnmstitch => None,
+            Self::Psfrag => None,
+            Self::Psutils => None,
+            Self::PythonLdap => None,
+            Self::Radvd => None,
+            Self::Snprintf => None,
+            Self::SoftSurfer => None,
+            Self::SshKeyscan => None,
+            Self::Swrule => None,
+            Self::Threeparttable => None,
+            Self::Ulem => None,
+            Self::WThreem => None,
+            Self::Wwl => None,
+            Self::Xinetd => None,
+            Self::XkeyboardConfigZinoviev => None,
+            Self::Xlock => None,
+            Self::Xpp => None,
+            Self::Xzoom => None,
+            Self::ZlibAcknowledgement => None,
         }
     }
     #[doc = r" Returns the embedded template content for the license."]
     #[doc = r" The content will be from `.template.txt` if available, otherwise `.txt`."]
     pub fn template_content(&self) -> &'static str {
         match self {
+            Self::ZeroBsd => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "0BSD",
+                ".template.txt"
+            )),
+            Self::ThreeDSlicerOneDotZero => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "3D-Slicer-1.0",
+                ".template.txt"
+            )),
+            Self::AAL => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "AAL",
+                ".template.txt"
+            )),
+            Self::ADSL => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "ADSL",
+                ".template.txt"
+            )),
+            Self::AFLOneDotOne => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "AFL-1.1",
+                ".template.txt"
+            )),
             Self::AFLOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
                 "AFL-1.2",
                 ".template.txt"
             )),
-            Self::LinuxSyscallNote => include_str!(concat!(
+            Self::AFLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Linux-syscall-note",
+                "AFL-2.0",
                 ".template.txt"
             )),
-            Self::DocBookStylesheet => include_str!(concat!(
+            Self::AFLTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DocBook-Stylesheet",
+                "AFL-2.1",
                 ".template.txt"
             )),
-            Self::HPNDSellMITDisclaimerXserver => include_str!(concat!(
+            Self::AFLThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-sell-MIT-disclaimer-xserver",
+                "AFL-3.0",
                 ".template.txt"
             )),
-            Self::OSLTwoDotOne => include_str!(concat!(
+            Self::AGPLOneDotZeroOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OSL-2.1",
+                "AGPL-1.0-only",
                 ".template.txt"
             )),
-            Self::SWL => include_str!(concat!(
+            Self::AGPLOneDotZeroOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SWL",
+                "AGPL-1.0-or-later",
                 ".template.txt"
             )),
-            Self::CCBYNCFourDotZero => include_str!(concat!(
+            Self::AGPLThreeDotZeroOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-4.0",
+                "AGPL-3.0-only",
                 ".template.txt"
             )),
-            Self::NLODTwoDotZero => include_str!(concat!(
+            Self::AGPLThreeDotZeroOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NLOD-2.0",
+                "AGPL-3.0-or-later",
                 ".template.txt"
             )),
-            Self::Unlicense => include_str!(concat!(
+            Self::AMDNewlib => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Unlicense",
+                "AMD-newlib",
                 ".template.txt"
             )),
-            Self::ODCByOneDotZero => include_str!(concat!(
+            Self::AMDPLPA => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ODC-By-1.0",
+                "AMDPLPA",
                 ".template.txt"
             )),
-            Self::UPLOneDotZero => include_str!(concat!(
+            Self::AML => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "UPL-1.0",
+                "AML",
                 ".template.txt"
             )),
-            Self::SNIA => include_str!(concat!(
+            Self::AMLGlslang => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SNIA",
+                "AML-glslang",
                 ".template.txt"
             )),
-            Self::GCCExceptionThreeDotOne => include_str!(concat!(
+            Self::AMPAS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GCC-exception-3.1",
+                "AMPAS",
                 ".template.txt"
             )),
-            Self::OLDAPOneDotThree => include_str!(concat!(
+            Self::ANTLRPD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-1.3",
+                "ANTLR-PD",
                 ".template.txt"
             )),
-            Self::Dotseqn => include_str!(concat!(
+            Self::ANTLRPDFallback => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "ANTLR-PD-fallback",
+                ".template.txt"
+            )),
+            Self::APAFML => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "APAFML",
+                ".template.txt"
+            )),
+            Self::APLOneDotZero => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "APL-1.0",
+                ".template.txt"
+            )),
+            Self::APSLOneDotZero => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "APSL-1.0",
+                ".template.txt"
+            )),
+            Self::APSLOneDotOne => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "APSL-1.1",
+                ".template.txt"
+            )),
+            Self::APSLOneDotTwo => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "APSL-1.2",
+                ".template.txt"
+            )),
+            Self::APSLTwoDotZero => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "APSL-2.0",
+                ".template.txt"
+            )),
+            Self::ASWFDigitalAssetsOneDotZero => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "ASWF-Digital-Assets-1.0",
+                ".template.txt"
+            )),
+            Self::ASWFDigitalAssetsOneDotOne => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "ASWF-Digital-Assets-1.1",
+                ".template.txt"
+            )),
+            Self::Abstyles => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Abstyles",
+                ".template.txt"
+            )),
+            Self::AdaCoreDoc => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "AdaCore-doc",
+                ".template.txt"
+            )),
+            Self::AdobeTwoZeroZeroSix => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Adobe-2006",
+                ".template.txt"
+            )),
+            Self::AdobeDisplayPostScript => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Adobe-Display-PostScript",
+                ".template.txt"
+            )),
+            Self::AdobeGlyph => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Adobe-Glyph",
+                ".template.txt"
+            )),
+            Self::AdobeUtopia => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Adobe-Utopia",
+                ".template.txt"
+            )),
+            Self::Afmparse => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Afmparse",
+                ".template.txt"
+            )),
+            Self::Aladdin => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Aladdin",
+                ".template.txt"
+            )),
+            Self::ApacheOneDotZero => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Apache-1.0",
+                ".template.txt"
+            )),
+            Self::ApacheOneDotOne => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Apache-1.1",
+                ".template.txt"
+            )),
+            Self::ApacheTwoDotZero => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/assets/licenses/",
+                "Apache-2.0",
+                ".template.txt"
+            )),
+            Self::AppSTwop => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Dotseqn",
+                "App-s2p",
                 ".template.txt"
             )),
-            Self::TMate => include_str!(concat!(
+            Self::ArphicOneNineNineNine => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TMate",
+                "Arphic-1999",
                 ".template.txt"
             )),
-            Self::GFDLOneDotOneInvariantsOrLater => include_str!(concat!(
+            Self::ArtisticOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.1-invariants-or-later",
+                "Artistic-1.0",
                 ".template.txt"
             )),
-            Self::MmiXware => include_str!(concat!(
+            Self::ArtisticOneDotZeroPerl => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MMIXware",
+                "Artistic-1.0-Perl",
                 ".template.txt"
             )),
-            Self::Crossword => include_str!(concat!(
+            Self::ArtisticOneDotZeroClEight => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Crossword",
+                "Artistic-1.0-cl8",
                 ".template.txt"
             )),
-            Self::OLDAPOneDotFour => include_str!(concat!(
+            Self::ArtisticTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-1.4",
+                "Artistic-2.0",
                 ".template.txt"
             )),
-            Self::GLWTPL => include_str!(concat!(
+            Self::BSDOneClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GLWTPL",
+                "BSD-1-Clause",
                 ".template.txt"
             )),
-            Self::Curl => include_str!(concat!(
+            Self::BSDTwoClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "curl",
+                "BSD-2-Clause",
                 ".template.txt"
             )),
-            Self::GPLTwoDotZeroOrLater => include_str!(concat!(
+            Self::BSDTwoClauseDarwin => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-2.0-or-later",
+                "BSD-2-Clause-Darwin",
                 ".template.txt"
             )),
-            Self::OFLOneDotOneNoRFN => include_str!(concat!(
+            Self::BSDTwoClausePatent => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OFL-1.1-no-RFN",
+                "BSD-2-Clause-Patent",
                 ".template.txt"
             )),
-            Self::MSLPL => include_str!(concat!(
+            Self::BSDTwoClauseViews => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MS-LPL",
+                "BSD-2-Clause-Views",
                 ".template.txt"
             )),
-            Self::CCBYOneDotZero => include_str!(concat!(
+            Self::BSDTwoClauseFirstLines => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-1.0",
+                "BSD-2-Clause-first-lines",
                 ".template.txt"
             )),
-            Self::CDLAPermissiveOneDotZero => include_str!(concat!(
+            Self::BSDTwoClausePkgconfDisclaimer => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CDLA-Permissive-1.0",
+                "BSD-2-Clause-pkgconf-disclaimer",
                 ".template.txt"
             )),
-            Self::QPLOneDotZeroINRIATwoZeroZeroFourException => include_str!(concat!(
+            Self::BSDThreeClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "QPL-1.0-INRIA-2004-exception",
+                "BSD-3-Clause",
                 ".template.txt"
             )),
-            Self::ClasspathExceptionTwoDotZero => include_str!(concat!(
+            Self::BSDThreeClauseAttribution => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Classpath-exception-2.0",
+                "BSD-3-Clause-Attribution",
                 ".template.txt"
             )),
-            Self::BSDThreeClause => include_str!(concat!(
+            Self::BSDThreeClauseClear => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause",
+                "BSD-3-Clause-Clear",
                 ".template.txt"
             )),
-            Self::OPLOneDotZero => include_str!(concat!(
+            Self::BSDThreeClauseHP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OPL-1.0",
+                "BSD-3-Clause-HP",
                 ".template.txt"
             )),
-            Self::ODbLOneDotZero => include_str!(concat!(
+            Self::BSDThreeClauseLBNL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ODbL-1.0",
+                "BSD-3-Clause-LBNL",
                 ".template.txt"
             )),
-            Self::IndependentModulesException => include_str!(concat!(
+            Self::BSDThreeClauseModification => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Independent-modules-exception",
+                "BSD-3-Clause-Modification",
                 ".template.txt"
             )),
-            Self::MITFestival => include_str!(concat!(
+            Self::BSDThreeClauseNoMilitaryLicense => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-Festival",
+                "BSD-3-Clause-No-Military-License",
                 ".template.txt"
             )),
-            Self::CalderaNoPreamble => include_str!(concat!(
+            Self::BSDThreeClauseNoNuclearLicense => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Caldera-no-preamble",
+                "BSD-3-Clause-No-Nuclear-License",
                 ".template.txt"
             )),
-            Self::SGIBOneDotOne => include_str!(concat!(
+            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SGI-B-1.1",
+                "BSD-3-Clause-No-Nuclear-License-2014",
                 ".template.txt"
             )),
-            Self::Soundex => include_str!(concat!(
+            Self::BSDThreeClauseNoNuclearWarranty => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Soundex",
+                "BSD-3-Clause-No-Nuclear-Warranty",
                 ".template.txt"
             )),
-            Self::SANEException => include_str!(concat!(
+            Self::BSDThreeClauseOpenMPI => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SANE-exception",
+                "BSD-3-Clause-Open-MPI",
                 ".template.txt"
             )),
-            Self::MirOs => include_str!(concat!(
+            Self::BSDThreeClauseSun => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MirOS",
+                "BSD-3-Clause-Sun",
                 ".template.txt"
             )),
-            Self::Ruby => include_str!(concat!(
+            Self::BSDThreeClauseAcpica => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Ruby",
+                "BSD-3-Clause-acpica",
                 ".template.txt"
             )),
-            Self::PolyparseException => include_str!(concat!(
+            Self::BSDThreeClauseFlex => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "polyparse-exception",
+                "BSD-3-Clause-flex",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseLBNL => include_str!(concat!(
+            Self::BSDFourClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-LBNL",
+                "BSD-4-Clause",
                 ".template.txt"
             )),
-            Self::BSDTwoClause => include_str!(concat!(
+            Self::BSDFourClauseShortened => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-2-Clause",
+                "BSD-4-Clause-Shortened",
                 ".template.txt"
             )),
-            Self::EFLTwoDotZero => include_str!(concat!(
+            Self::BSDFourClauseUC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EFL-2.0",
+                "BSD-4-Clause-UC",
                 ".template.txt"
             )),
-            Self::LLGPL => include_str!(concat!(
+            Self::BSDFourDotThreeReno => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LLGPL",
+                "BSD-4.3RENO",
                 ".template.txt"
             )),
-            Self::VsftpdOpensslException => include_str!(concat!(
+            Self::BSDFourDotThreeTahoe => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "vsftpd-openssl-exception",
+                "BSD-4.3TAHOE",
                 ".template.txt"
             )),
-            Self::OFLOneDotZeroNoRFN => include_str!(concat!(
+            Self::BSDAdvertisingAcknowledgement => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OFL-1.0-no-RFN",
+                "BSD-Advertising-Acknowledgement",
                 ".template.txt"
             )),
-            Self::CCBYNCNDThreeDotZeroDE => include_str!(concat!(
+            Self::BSDAttributionHPNDDisclaimer => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-ND-3.0-DE",
+                "BSD-Attribution-HPND-disclaimer",
                 ".template.txt"
             )),
-            Self::ArtisticTwoDotZero => include_str!(concat!(
+            Self::BSDInfernoNettverk => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Artistic-2.0",
+                "BSD-Inferno-Nettverk",
                 ".template.txt"
             )),
-            Self::CDDLOneDotZero => include_str!(concat!(
+            Self::BSDProtection => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CDDL-1.0",
+                "BSD-Protection",
                 ".template.txt"
             )),
-            Self::SWIException => include_str!(concat!(
+            Self::BSDSourceCode => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SWI-exception",
+                "BSD-Source-Code",
                 ".template.txt"
             )),
-            Self::GFDLOneDotThreeInvariantsOnly => include_str!(concat!(
+            Self::BSDSourceBeginningFile => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.3-invariants-only",
+                "BSD-Source-beginning-file",
                 ".template.txt"
             )),
-            Self::MSRL => include_str!(concat!(
+            Self::BSDSystemics => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MS-RL",
+                "BSD-Systemics",
                 ".template.txt"
             )),
-            Self::ASWFDigitalAssetsOneDotOne => include_str!(concat!(
+            Self::BSDSystemicsWThreeWorks => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ASWF-Digital-Assets-1.1",
+                "BSD-Systemics-W3Works",
                 ".template.txt"
             )),
-            Self::LGPLTwoDotOneOnly => include_str!(concat!(
+            Self::BSLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPL-2.1-only",
+                "BSL-1.0",
                 ".template.txt"
             )),
-            Self::HPOneNineEightSix => include_str!(concat!(
+            Self::BUSLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HP-1986",
+                "BUSL-1.1",
                 ".template.txt"
             )),
-            Self::PythonTwoDotZeroDotOne => include_str!(concat!(
+            Self::Baekmuk => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Python-2.0.1",
+                "Baekmuk",
                 ".template.txt"
             )),
-            Self::Abstyles => include_str!(concat!(
+            Self::Bahyph => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Abstyles",
+                "Bahyph",
                 ".template.txt"
             )),
-            Self::RHeCosOneDotOne => include_str!(concat!(
+            Self::Barr => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "RHeCos-1.1",
+                "Barr",
                 ".template.txt"
             )),
-            Self::MIT => include_str!(concat!(
+            Self::Beerware => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT",
+                "Beerware",
                 ".template.txt"
             )),
-            Self::BSDOneClause => include_str!(concat!(
+            Self::BitTorrentOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-1-Clause",
+                "BitTorrent-1.0",
                 ".template.txt"
             )),
-            Self::RomicException => include_str!(concat!(
+            Self::BitTorrentOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "romic-exception",
+                "BitTorrent-1.1",
                 ".template.txt"
             )),
-            Self::OSLThreeDotZero => include_str!(concat!(
+            Self::BitstreamCharter => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OSL-3.0",
+                "Bitstream-Charter",
                 ".template.txt"
             )),
-            Self::CCBYNCThreeDotZero => include_str!(concat!(
+            Self::BitstreamVera => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-3.0",
+                "Bitstream-Vera",
                 ".template.txt"
             )),
-            Self::GCCExceptionTwoDotZero => include_str!(concat!(
+            Self::BlueOakOneDotZeroDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GCC-exception-2.0",
+                "BlueOak-1.0.0",
                 ".template.txt"
             )),
-            Self::DeprecatedLGPLThreeDotZeroPlus => include_str!(concat!(
+            Self::BoehmGC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_LGPL-3.0+",
+                "Boehm-GC",
                 ".template.txt"
             )),
-            Self::Clips => include_str!(concat!(
+            Self::BoehmGCWithoutFee => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Clips",
+                "Boehm-GC-without-fee",
                 ".template.txt"
             )),
-            Self::BSDTwoClauseDarwin => include_str!(concat!(
+            Self::Borceux => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-2-Clause-Darwin",
+                "Borceux",
                 ".template.txt"
             )),
-            Self::CNRIPythonGPLCompatible => include_str!(concat!(
+            Self::BrianGladmanTwoClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CNRI-Python-GPL-Compatible",
+                "Brian-Gladman-2-Clause",
                 ".template.txt"
             )),
-            Self::LPDDocument => include_str!(concat!(
+            Self::BrianGladmanThreeClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LPD-document",
+                "Brian-Gladman-3-Clause",
                 ".template.txt"
             )),
-            Self::GPLCCOneDotZero => include_str!(concat!(
+            Self::CUDAOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-CC-1.0",
+                "C-UDA-1.0",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseFlex => include_str!(concat!(
+            Self::CALOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-flex",
+                "CAL-1.0",
                 ".template.txt"
             )),
-            Self::HPNDUC => include_str!(concat!(
+            Self::CALOneDotZeroCombinedWorkException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-UC",
+                "CAL-1.0-Combined-Work-Exception",
                 ".template.txt"
             )),
-            Self::ISCVeillard => include_str!(concat!(
+            Self::CATOSLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ISC-Veillard",
+                "CATOSL-1.1",
                 ".template.txt"
             )),
-            Self::PDDLOneDotZero => include_str!(concat!(
+            Self::CCBYOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PDDL-1.0",
+                "CC-BY-1.0",
                 ".template.txt"
             )),
-            Self::LGPLTwoDotZeroOrLater => include_str!(concat!(
+            Self::CCBYTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPL-2.0-or-later",
+                "CC-BY-2.0",
                 ".template.txt"
             )),
-            Self::CCBYNCSAThreeDotZeroIGO => include_str!(concat!(
+            Self::CCBYTwoDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-3.0-IGO",
+                "CC-BY-2.5",
                 ".template.txt"
             )),
-            Self::TORQUEOneDotOne => include_str!(concat!(
+            Self::CCBYTwoDotFiveAU => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TORQUE-1.1",
+                "CC-BY-2.5-AU",
                 ".template.txt"
             )),
-            Self::FrameworxOneDotZero => include_str!(concat!(
+            Self::CCBYThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Frameworx-1.0",
+                "CC-BY-3.0",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotEight => include_str!(concat!(
+            Self::CCBYThreeDotZeroAT => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.8",
+                "CC-BY-3.0-AT",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLThreeDotZero => include_str!(concat!(
+            Self::CCBYThreeDotZeroAU => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-3.0",
+                "CC-BY-3.0-AU",
                 ".template.txt"
             )),
-            Self::QwtExceptionOneDotZero => include_str!(concat!(
+            Self::CCBYThreeDotZeroDE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Qwt-exception-1.0",
+                "CC-BY-3.0-DE",
                 ".template.txt"
             )),
-            Self::DocBookDTD => include_str!(concat!(
+            Self::CCBYThreeDotZeroIGO => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DocBook-DTD",
+                "CC-BY-3.0-IGO",
                 ".template.txt"
             )),
-            Self::Psfrag => include_str!(concat!(
+            Self::CCBYThreeDotZeroNL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "psfrag",
+                "CC-BY-3.0-NL",
                 ".template.txt"
             )),
-            Self::IJGShort => include_str!(concat!(
+            Self::CCBYThreeDotZeroUS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "IJG-short",
+                "CC-BY-3.0-US",
                 ".template.txt"
             )),
-            Self::Psutils => include_str!(concat!(
+            Self::CCBYFourDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "psutils",
+                "CC-BY-4.0",
                 ".template.txt"
             )),
-            Self::DRLOneDotOne => include_str!(concat!(
+            Self::CCBYNCOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DRL-1.1",
+                "CC-BY-NC-1.0",
                 ".template.txt"
             )),
-            Self::BoehmGC => include_str!(concat!(
+            Self::CCBYNCTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Boehm-GC",
+                "CC-BY-NC-2.0",
                 ".template.txt"
             )),
-            Self::CECILLTwoDotZero => include_str!(concat!(
+            Self::CCBYNCTwoDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CECILL-2.0",
+                "CC-BY-NC-2.5",
                 ".template.txt"
             )),
-            Self::WThreeCOneNineNineEightZeroSevenTwoZero => include_str!(concat!(
+            Self::CCBYNCThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "W3C-19980720",
+                "CC-BY-NC-3.0",
                 ".template.txt"
             )),
-            Self::OUDAOneDotZero => include_str!(concat!(
+            Self::CCBYNCThreeDotZeroDE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "O-UDA-1.0",
+                "CC-BY-NC-3.0-DE",
                 ".template.txt"
             )),
-            Self::CLISPExceptionTwoDotZero => include_str!(concat!(
+            Self::CCBYNCFourDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CLISP-exception-2.0",
+                "CC-BY-NC-4.0",
                 ".template.txt"
             )),
-            Self::DLDEZEROTwoDotZero => include_str!(concat!(
+            Self::CCBYNCNDOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DL-DE-ZERO-2.0",
+                "CC-BY-NC-ND-1.0",
                 ".template.txt"
             )),
-            Self::DeprecatedGFDLOneDotThree => include_str!(concat!(
+            Self::CCBYNCNDTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GFDL-1.3",
+                "CC-BY-NC-ND-2.0",
                 ".template.txt"
             )),
-            Self::SgpFour => include_str!(concat!(
+            Self::CCBYNCNDTwoDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SGP4",
+                "CC-BY-NC-ND-2.5",
                 ".template.txt"
             )),
-            Self::FDKAAC => include_str!(concat!(
+            Self::CCBYNCNDThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FDK-AAC",
+                "CC-BY-NC-ND-3.0",
                 ".template.txt"
             )),
-            Self::ApacheOneDotOne => include_str!(concat!(
+            Self::CCBYNCNDThreeDotZeroDE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Apache-1.1",
+                "CC-BY-NC-ND-3.0-DE",
                 ".template.txt"
             )),
-            Self::TTWL => include_str!(concat!(
+            Self::CCBYNCNDThreeDotZeroIGO => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TTWL",
+                "CC-BY-NC-ND-3.0-IGO",
                 ".template.txt"
             )),
-            Self::MartinBirgmeier => include_str!(concat!(
+            Self::CCBYNCNDFourDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Martin-Birgmeier",
+                "CC-BY-NC-ND-4.0",
                 ".template.txt"
             )),
-            Self::CCBYNDThreeDotZeroDE => include_str!(concat!(
+            Self::CCBYNCSAOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-ND-3.0-DE",
+                "CC-BY-NC-SA-1.0",
                 ".template.txt"
             )),
-            Self::ECLTwoDotZero => include_str!(concat!(
+            Self::CCBYNCSATwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ECL-2.0",
+                "CC-BY-NC-SA-2.0",
                 ".template.txt"
             )),
-            Self::ICU => include_str!(concat!(
+            Self::CCBYNCSATwoDotZeroDE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ICU",
+                "CC-BY-NC-SA-2.0-DE",
                 ".template.txt"
             )),
-            Self::StunnelException => include_str!(concat!(
+            Self::CCBYNCSATwoDotZeroFR => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "stunnel-exception",
+                "CC-BY-NC-SA-2.0-FR",
                 ".template.txt"
             )),
-            Self::GameProgrammingGems => include_str!(concat!(
+            Self::CCBYNCSATwoDotZeroUK => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Game-Programming-Gems",
+                "CC-BY-NC-SA-2.0-UK",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseAttribution => include_str!(concat!(
+            Self::CCBYNCSATwoDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-Attribution",
+                "CC-BY-NC-SA-2.5",
                 ".template.txt"
             )),
-            Self::SGIBOneDotZero => include_str!(concat!(
+            Self::CCBYNCSAThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SGI-B-1.0",
+                "CC-BY-NC-SA-3.0",
                 ".template.txt"
             )),
-            Self::NRL => include_str!(concat!(
+            Self::CCBYNCSAThreeDotZeroDE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NRL",
+                "CC-BY-NC-SA-3.0-DE",
                 ".template.txt"
             )),
-            Self::Kazlib => include_str!(concat!(
+            Self::CCBYNCSAThreeDotZeroIGO => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Kazlib",
+                "CC-BY-NC-SA-3.0-IGO",
                 ".template.txt"
             )),
-            Self::PHPThreeDotZeroOne => include_str!(concat!(
+            Self::CCBYNCSAFourDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PHP-3.01",
+                "CC-BY-NC-SA-4.0",
                 ".template.txt"
             )),
-            Self::Magaz => include_str!(concat!(
+            Self::CCBYNDOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "magaz",
+                "CC-BY-ND-1.0",
                 ".template.txt"
             )),
-            Self::CPALOneDotZero => include_str!(concat!(
+            Self::CCBYNDTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CPAL-1.0",
+                "CC-BY-ND-2.0",
                 ".template.txt"
             )),
-            Self::AppSTwop => include_str!(concat!(
+            Self::CCBYNDTwoDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "App-s2p",
+                "CC-BY-ND-2.5",
                 ".template.txt"
             )),
-            Self::RrDtoolFLOSSExceptionTwoDotZero => include_str!(concat!(
+            Self::CCBYNDThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "RRDtool-FLOSS-exception-2.0",
+                "CC-BY-ND-3.0",
                 ".template.txt"
             )),
-            Self::LibtoolException => include_str!(concat!(
+            Self::CCBYNDThreeDotZeroDE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Libtool-exception",
+                "CC-BY-ND-3.0-DE",
                 ".template.txt"
             )),
-            Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven => include_str!(concat!(
+            Self::CCBYNDFourDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PS-or-PDF-font-exception-20170817",
+                "CC-BY-ND-4.0",
                 ".template.txt"
             )),
-            Self::BrianGladmanTwoClause => include_str!(concat!(
+            Self::CCBYSAOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Brian-Gladman-2-Clause",
+                "CC-BY-SA-1.0",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLTwoDotZero => include_str!(concat!(
+            Self::CCBYSATwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-2.0",
+                "CC-BY-SA-2.0",
                 ".template.txt"
             )),
-            Self::LGPLThreeDotZeroOnly => include_str!(concat!(
+            Self::CCBYSATwoDotZeroUK => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPL-3.0-only",
+                "CC-BY-SA-2.0-UK",
                 ".template.txt"
             )),
-            Self::DigiRuleFOSSException => include_str!(concat!(
+            Self::CCBYSATwoDotOneJP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DigiRule-FOSS-exception",
+                "CC-BY-SA-2.1-JP",
                 ".template.txt"
             )),
-            Self::GPLThreeDotZeroLinkingException => include_str!(concat!(
+            Self::CCBYSATwoDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-3.0-linking-exception",
+                "CC-BY-SA-2.5",
                 ".template.txt"
             )),
-            Self::ASWFDigitalAssetsOneDotZero => include_str!(concat!(
+            Self::CCBYSAThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ASWF-Digital-Assets-1.0",
+                "CC-BY-SA-3.0",
                 ".template.txt"
             )),
-            Self::Mailprio => include_str!(concat!(
+            Self::CCBYSAThreeDotZeroAT => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "mailprio",
+                "CC-BY-SA-3.0-AT",
                 ".template.txt"
             )),
-            Self::LPPLOneDotTwo => include_str!(concat!(
+            Self::CCBYSAThreeDotZeroDE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LPPL-1.2",
+                "CC-BY-SA-3.0-DE",
                 ".template.txt"
             )),
-            Self::CDDLOneDotOne => include_str!(concat!(
+            Self::CCBYSAThreeDotZeroIGO => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CDDL-1.1",
+                "CC-BY-SA-3.0-IGO",
                 ".template.txt"
             )),
-            Self::HPND => include_str!(concat!(
+            Self::CCBYSAFourDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND",
+                "CC-BY-SA-4.0",
                 ".template.txt"
             )),
-            Self::NTPZero => include_str!(concat!(
+            Self::CCPDDC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NTP-0",
+                "CC-PDDC",
                 ".template.txt"
             )),
-            Self::CCBYNDTwoDotFive => include_str!(concat!(
+            Self::CCPDMOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-ND-2.5",
+                "CC-PDM-1.0",
                 ".template.txt"
             )),
-            Self::CCBYNCNDTwoDotFive => include_str!(concat!(
+            Self::CCSAOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-ND-2.5",
+                "CC-SA-1.0",
                 ".template.txt"
             )),
-            Self::FreertosExceptionTwoDotZero => include_str!(concat!(
+            Self::CcZeroOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "freertos-exception-2.0",
+                "CC0-1.0",
                 ".template.txt"
             )),
-            Self::BrianGladmanThreeClause => include_str!(concat!(
+            Self::CDDLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Brian-Gladman-3-Clause",
+                "CDDL-1.0",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLTwoDotZeroWithBisonException => include_str!(concat!(
+            Self::CDDLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-2.0-with-bison-exception",
+                "CDDL-1.1",
                 ".template.txt"
             )),
-            Self::SugarCrmOneDotOneDotThree => include_str!(concat!(
+            Self::CDLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SugarCRM-1.1.3",
+                "CDL-1.0",
                 ".template.txt"
             )),
-            Self::SISSL => include_str!(concat!(
+            Self::CDLAPermissiveOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SISSL",
+                "CDLA-Permissive-1.0",
                 ".template.txt"
             )),
-            Self::CFITSIO => include_str!(concat!(
+            Self::CDLAPermissiveTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CFITSIO",
+                "CDLA-Permissive-2.0",
                 ".template.txt"
             )),
-            Self::TUBerlinTwoDotZero => include_str!(concat!(
+            Self::CDLASharingOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TU-Berlin-2.0",
+                "CDLA-Sharing-1.0",
                 ".template.txt"
             )),
-            Self::CMUMachNodoc => include_str!(concat!(
+            Self::CECILLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CMU-Mach-nodoc",
+                "CECILL-1.0",
                 ".template.txt"
             )),
-            Self::CondorOneDotOne => include_str!(concat!(
+            Self::CECILLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Condor-1.1",
+                "CECILL-1.1",
                 ".template.txt"
             )),
-            Self::OSLTwoDotZero => include_str!(concat!(
+            Self::CECILLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OSL-2.0",
+                "CECILL-2.0",
                 ".template.txt"
             )),
-            Self::CCBYNCTwoDotZero => include_str!(concat!(
+            Self::CECILLTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-2.0",
+                "CECILL-2.1",
                 ".template.txt"
             )),
-            Self::AdaCoreDoc => include_str!(concat!(
+            Self::CECILLB => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AdaCore-doc",
+                "CECILL-B",
                 ".template.txt"
             )),
-            Self::TermReadKey => include_str!(concat!(
+            Self::CECILLC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TermReadKey",
+                "CECILL-C",
                 ".template.txt"
             )),
-            Self::UnixCrypt => include_str!(concat!(
+            Self::CERNOHLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "UnixCrypt",
+                "CERN-OHL-1.1",
                 ".template.txt"
             )),
-            Self::Jove => include_str!(concat!(
+            Self::CERNOHLOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "jove",
+                "CERN-OHL-1.2",
                 ".template.txt"
             )),
-            Self::NTP => include_str!(concat!(
+            Self::CERNOHLPTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NTP",
+                "CERN-OHL-P-2.0",
                 ".template.txt"
             )),
-            Self::AdobeDisplayPostScript => include_str!(concat!(
+            Self::CERNOHLSTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Adobe-Display-PostScript",
+                "CERN-OHL-S-2.0",
                 ".template.txt"
             )),
-            Self::CCBYThreeDotZeroDE => include_str!(concat!(
+            Self::CERNOHLWTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-3.0-DE",
+                "CERN-OHL-W-2.0",
                 ".template.txt"
             )),
-            Self::Kastrup => include_str!(concat!(
+            Self::CFITSIO => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Kastrup",
+                "CFITSIO",
                 ".template.txt"
             )),
-            Self::LGPLTwoDotZeroOnly => include_str!(concat!(
+            Self::CMUMach => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPL-2.0-only",
+                "CMU-Mach",
                 ".template.txt"
             )),
-            Self::OLDAPOneDotTwo => include_str!(concat!(
+            Self::CMUMachNodoc => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-1.2",
+                "CMU-Mach-nodoc",
                 ".template.txt"
             )),
-            Self::LinuxManPagesOnePara => include_str!(concat!(
+            Self::CNRIJython => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Linux-man-pages-1-para",
+                "CNRI-Jython",
                 ".template.txt"
             )),
-            Self::DLDEBYTwoDotZero => include_str!(concat!(
+            Self::CNRIPython => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DL-DE-BY-2.0",
+                "CNRI-Python",
                 ".template.txt"
             )),
-            Self::CCBYNCThreeDotZeroDE => include_str!(concat!(
+            Self::CNRIPythonGPLCompatible => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-3.0-DE",
+                "CNRI-Python-GPL-Compatible",
                 ".template.txt"
             )),
-            Self::FreeImage => include_str!(concat!(
+            Self::COILOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FreeImage",
+                "COIL-1.0",
                 ".template.txt"
             )),
-            Self::CECILLTwoDotOne => include_str!(concat!(
+            Self::CPALOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CECILL-2.1",
+                "CPAL-1.0",
                 ".template.txt"
             )),
-            Self::DRLOneDotZero => include_str!(concat!(
+            Self::CPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DRL-1.0",
+                "CPL-1.0",
                 ".template.txt"
             )),
-            Self::HPNDExportUSAcknowledgement => include_str!(concat!(
+            Self::CPOLOneDotZeroTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-export-US-acknowledgement",
+                "CPOL-1.02",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotZeroDotOne => include_str!(concat!(
+            Self::CUAOPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.0.1",
+                "CUA-OPL-1.0",
                 ".template.txt"
             )),
-            Self::Threeparttable => include_str!(concat!(
+            Self::Caldera => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "threeparttable",
+                "Caldera",
                 ".template.txt"
             )),
-            Self::MITOpenGroup => include_str!(concat!(
+            Self::CalderaNoPreamble => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-open-group",
+                "Caldera-no-preamble",
                 ".template.txt"
             )),
-            Self::FSFULLR => include_str!(concat!(
+            Self::Catharon => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FSFULLR",
+                "Catharon",
                 ".template.txt"
             )),
-            Self::AutoconfExceptionMacro => include_str!(concat!(
+            Self::ClArtistic => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Autoconf-exception-macro",
+                "ClArtistic",
                 ".template.txt"
             )),
-            Self::NCL => include_str!(concat!(
+            Self::Clips => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NCL",
+                "Clips",
                 ".template.txt"
             )),
-            Self::EPICS => include_str!(concat!(
+            Self::CommunitySpecOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EPICS",
+                "Community-Spec-1.0",
                 ".template.txt"
             )),
-            Self::ApacheOneDotZero => include_str!(concat!(
+            Self::CondorOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Apache-1.0",
+                "Condor-1.1",
                 ".template.txt"
             )),
-            Self::JSON => include_str!(concat!(
+            Self::CornellLosslessJPEG => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "JSON",
+                "Cornell-Lossless-JPEG",
                 ".template.txt"
             )),
-            Self::DeprecatedGFDLOneDotTwo => include_str!(concat!(
+            Self::Cronyx => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GFDL-1.2",
+                "Cronyx",
                 ".template.txt"
             )),
-            Self::SpencerEightSix => include_str!(concat!(
+            Self::Crossword => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Spencer-86",
+                "Crossword",
                 ".template.txt"
             )),
-            Self::BSDFourClause => include_str!(concat!(
+            Self::CryptoSwift => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-4-Clause",
+                "CryptoSwift",
                 ".template.txt"
             )),
-            Self::SHLZeroDotFiveOne => include_str!(concat!(
+            Self::CrystalStacker => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SHL-0.51",
+                "CrystalStacker",
                 ".template.txt"
             )),
-            Self::IJG => include_str!(concat!(
+            Self::Cube => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "IJG",
+                "Cube",
                 ".template.txt"
             )),
-            Self::APSLOneDotTwo => include_str!(concat!(
+            Self::DFSLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "APSL-1.2",
+                "D-FSL-1.0",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLTwoDotZeroPlus => include_str!(concat!(
+            Self::DECThreeClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-2.0+",
+                "DEC-3-Clause",
                 ".template.txt"
             )),
-            Self::ErlangOtpLinkingException => include_str!(concat!(
+            Self::DLDEBYTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "erlang-otp-linking-exception",
+                "DL-DE-BY-2.0",
                 ".template.txt"
             )),
-            Self::SAXPD => include_str!(concat!(
+            Self::DLDEZEROTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SAX-PD",
+                "DL-DE-ZERO-2.0",
                 ".template.txt"
             )),
-            Self::PSFTwoDotZero => include_str!(concat!(
+            Self::DOC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PSF-2.0",
+                "DOC",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseModification => include_str!(concat!(
+            Self::DRLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-Modification",
+                "DRL-1.0",
                 ".template.txt"
             )),
-            Self::QPLOneDotZeroINRIATwoZeroZeroFour => include_str!(concat!(
+            Self::DRLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "QPL-1.0-INRIA-2004",
+                "DRL-1.1",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLTwoDotZeroWithClasspathException => include_str!(concat!(
+            Self::DSDP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-2.0-with-classpath-exception",
+                "DSDP",
                 ".template.txt"
             )),
-            Self::EUPLOneDotTwo => include_str!(concat!(
+            Self::DocBookDTD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EUPL-1.2",
+                "DocBook-DTD",
                 ".template.txt"
             )),
-            Self::BSDTwoClauseFirstLines => include_str!(concat!(
+            Self::DocBookSchema => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-2-Clause-first-lines",
+                "DocBook-Schema",
                 ".template.txt"
             )),
-            Self::GNOMEExamplesException => include_str!(concat!(
+            Self::DocBookStylesheet => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GNOME-examples-exception",
+                "DocBook-Stylesheet",
                 ".template.txt"
             )),
-            Self::XOneOneDistributeModificationsVariant => include_str!(concat!(
+            Self::DocBookXML => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "X11-distribute-modifications-variant",
+                "DocBook-XML",
                 ".template.txt"
             )),
-            Self::LPLOneDotZero => include_str!(concat!(
+            Self::Dotseqn => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LPL-1.0",
+                "Dotseqn",
                 ".template.txt"
             )),
-            Self::SpencerNineFour => include_str!(concat!(
+            Self::ECLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Spencer-94",
+                "ECL-1.0",
                 ".template.txt"
             )),
-            Self::CCBYNCSATwoDotFive => include_str!(concat!(
+            Self::ECLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-2.5",
+                "ECL-2.0",
                 ".template.txt"
             )),
-            Self::UBootExceptionTwoDotZero => include_str!(concat!(
+            Self::EFLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "u-boot-exception-2.0",
+                "EFL-1.0",
                 ".template.txt"
             )),
-            Self::CCBYSATwoDotFive => include_str!(concat!(
+            Self::EFLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-2.5",
+                "EFL-2.0",
                 ".template.txt"
             )),
-            Self::Newsletr => include_str!(concat!(
+            Self::EPICS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Newsletr",
+                "EPICS",
                 ".template.txt"
             )),
-            Self::Noweb => include_str!(concat!(
+            Self::EPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Noweb",
+                "EPL-1.0",
                 ".template.txt"
             )),
-            Self::APLOneDotZero => include_str!(concat!(
+            Self::EPLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "APL-1.0",
+                "EPL-2.0",
                 ".template.txt"
             )),
-            Self::WThreem => include_str!(concat!(
+            Self::EuDatagrid => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "w3m",
+                "EUDatagrid",
                 ".template.txt"
             )),
-            Self::FSLOneDotOneMIT => include_str!(concat!(
+            Self::EUPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FSL-1.1-MIT",
+                "EUPL-1.0",
                 ".template.txt"
             )),
-            Self::TexinfoException => include_str!(concat!(
+            Self::EUPLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Texinfo-exception",
+                "EUPL-1.1",
                 ".template.txt"
             )),
-            Self::GFDLOneDotTwoNoInvariantsOrLater => include_str!(concat!(
+            Self::EUPLOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.2-no-invariants-or-later",
+                "EUPL-1.2",
                 ".template.txt"
             )),
-            Self::ArtisticOneDotZero => include_str!(concat!(
+            Self::ElasticTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Artistic-1.0",
+                "Elastic-2.0",
                 ".template.txt"
             )),
-            Self::EFLOneDotZero => include_str!(concat!(
+            Self::Entessa => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EFL-1.0",
+                "Entessa",
                 ".template.txt"
             )),
-            Self::WatcomOneDotZero => include_str!(concat!(
+            Self::ErlPlOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Watcom-1.0",
+                "ErlPL-1.1",
                 ".template.txt"
             )),
-            Self::CCBYThreeDotZeroAU => include_str!(concat!(
+            Self::Eurosym => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-3.0-AU",
+                "Eurosym",
                 ".template.txt"
             )),
-            Self::ISC => include_str!(concat!(
+            Self::FBM => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ISC",
+                "FBM",
                 ".template.txt"
             )),
-            Self::ZlibAcknowledgement => include_str!(concat!(
+            Self::FDKAAC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "zlib-acknowledgement",
+                "FDK-AAC",
                 ".template.txt"
             )),
-            Self::TCPWrappers => include_str!(concat!(
+            Self::FSFAP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TCP-wrappers",
+                "FSFAP",
                 ".template.txt"
             )),
-            Self::UniversalFOSSExceptionOneDotZero => include_str!(concat!(
+            Self::FSFAPNoWarrantyDisclaimer => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Universal-FOSS-exception-1.0",
+                "FSFAP-no-warranty-disclaimer",
                 ".template.txt"
             )),
-            Self::Hdparm => include_str!(concat!(
+            Self::FSFUL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "hdparm",
+                "FSFUL",
                 ".template.txt"
             )),
-            Self::SHLTwoDotZero => include_str!(concat!(
+            Self::FSFULLR => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SHL-2.0",
+                "FSFULLR",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotTwoDotOne => include_str!(concat!(
+            Self::FSFULLRWD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.2.1",
+                "FSFULLRWD",
                 ".template.txt"
             )),
-            Self::GFDLOneDotThreeNoInvariantsOnly => include_str!(concat!(
+            Self::FSLOneDotOneALvTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.3-no-invariants-only",
+                "FSL-1.1-ALv2",
                 ".template.txt"
             )),
-            Self::EGenix => include_str!(concat!(
+            Self::FSLOneDotOneMIT => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "eGenix",
+                "FSL-1.1-MIT",
                 ".template.txt"
             )),
-            Self::AGPLOneDotZeroOnly => include_str!(concat!(
+            Self::FTL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AGPL-1.0-only",
+                "FTL",
                 ".template.txt"
             )),
-            Self::FSFAP => include_str!(concat!(
+            Self::Fair => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FSFAP",
+                "Fair",
                 ".template.txt"
             )),
-            Self::SpencerNineNine => include_str!(concat!(
+            Self::FergusonTwofish => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Spencer-99",
+                "Ferguson-Twofish",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotThree => include_str!(concat!(
+            Self::FrameworxOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.3",
+                "Frameworx-1.0",
                 ".template.txt"
             )),
-            Self::GmshException => include_str!(concat!(
+            Self::FreeBsdDOC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Gmsh-exception",
+                "FreeBSD-DOC",
                 ".template.txt"
             )),
-            Self::Dvipdfm => include_str!(concat!(
+            Self::FreeImage => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "dvipdfm",
+                "FreeImage",
                 ".template.txt"
             )),
-            Self::DeprecatedNetSNMP => include_str!(concat!(
+            Self::Furuseth => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_Net-SNMP",
+                "Furuseth",
                 ".template.txt"
             )),
-            Self::LZMAException => include_str!(concat!(
+            Self::GCRDocs => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LZMA-exception",
+                "GCR-docs",
                 ".template.txt"
             )),
-            Self::Libpng => include_str!(concat!(
+            Self::GD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Libpng",
+                "GD",
                 ".template.txt"
             )),
-            Self::Xnet => include_str!(concat!(
+            Self::GFDLOneDotOneInvariantsOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Xnet",
+                "GFDL-1.1-invariants-only",
                 ".template.txt"
             )),
-            Self::HPNDMerchantabilityVariant => include_str!(concat!(
+            Self::GFDLOneDotOneInvariantsOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-merchantability-variant",
+                "GFDL-1.1-invariants-or-later",
                 ".template.txt"
             )),
-            Self::Intel => include_str!(concat!(
+            Self::GFDLOneDotOneNoInvariantsOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Intel",
+                "GFDL-1.1-no-invariants-only",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotFour => include_str!(concat!(
+            Self::GFDLOneDotOneNoInvariantsOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.4",
+                "GFDL-1.1-no-invariants-or-later",
                 ".template.txt"
             )),
-            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => include_str!(concat!(
+            Self::GFDLOneDotOneOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "W3C-20150513",
+                "GFDL-1.1-only",
                 ".template.txt"
             )),
-            Self::MITKhronosOld => include_str!(concat!(
+            Self::GFDLOneDotOneOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-Khronos-old",
+                "GFDL-1.1-or-later",
                 ".template.txt"
             )),
-            Self::CDLAPermissiveTwoDotZero => include_str!(concat!(
+            Self::GFDLOneDotTwoInvariantsOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CDLA-Permissive-2.0",
+                "GFDL-1.2-invariants-only",
                 ".template.txt"
             )),
-            Self::CCBYTwoDotZero => include_str!(concat!(
+            Self::GFDLOneDotTwoInvariantsOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-2.0",
+                "GFDL-1.2-invariants-or-later",
                 ".template.txt"
             )),
-            Self::MpiPermissive => include_str!(concat!(
+            Self::GFDLOneDotTwoNoInvariantsOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "mpi-permissive",
+                "GFDL-1.2-no-invariants-only",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => include_str!(concat!(
+            Self::GFDLOneDotTwoNoInvariantsOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-2.0-with-autoconf-exception",
+                "GFDL-1.2-no-invariants-or-later",
                 ".template.txt"
             )),
-            Self::MIPS => include_str!(concat!(
+            Self::GFDLOneDotTwoOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIPS",
+                "GFDL-1.2-only",
                 ".template.txt"
             )),
-            Self::Cube => include_str!(concat!(
+            Self::GFDLOneDotTwoOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Cube",
+                "GFDL-1.2-or-later",
                 ".template.txt"
             )),
-            Self::OSLOneDotOne => include_str!(concat!(
+            Self::GFDLOneDotThreeInvariantsOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OSL-1.1",
+                "GFDL-1.3-invariants-only",
                 ".template.txt"
             )),
-            Self::MPLTwoDotZeroNoCopyleftException => include_str!(concat!(
+            Self::GFDLOneDotThreeInvariantsOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MPL-2.0-no-copyleft-exception",
+                "GFDL-1.3-invariants-or-later",
                 ".template.txt"
             )),
-            Self::NLODOneDotZero => include_str!(concat!(
+            Self::GFDLOneDotThreeNoInvariantsOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NLOD-1.0",
+                "GFDL-1.3-no-invariants-only",
                 ".template.txt"
             )),
-            Self::GFDLOneDotOneOrLater => include_str!(concat!(
+            Self::GFDLOneDotThreeNoInvariantsOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.1-or-later",
+                "GFDL-1.3-no-invariants-or-later",
                 ".template.txt"
             )),
-            Self::WidgetWorkshop => include_str!(concat!(
+            Self::GFDLOneDotThreeOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Widget-Workshop",
+                "GFDL-1.3-only",
                 ".template.txt"
             )),
-            Self::CATOSLOneDotOne => include_str!(concat!(
+            Self::GFDLOneDotThreeOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CATOSL-1.1",
+                "GFDL-1.3-or-later",
                 ".template.txt"
             )),
-            Self::CryptsetupOpenSslException => include_str!(concat!(
+            Self::GlTwoPs => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "cryptsetup-OpenSSL-exception",
+                "GL2PS",
                 ".template.txt"
             )),
-            Self::BSDFourDotThreeReno => include_str!(concat!(
+            Self::GLWTPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-4.3RENO",
+                "GLWTPL",
                 ".template.txt"
             )),
-            Self::BSDSystemics => include_str!(concat!(
+            Self::GPLOneDotZeroOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Systemics",
+                "GPL-1.0-only",
                 ".template.txt"
             )),
-            Self::BoehmGCWithoutFee => include_str!(concat!(
+            Self::GPLOneDotZeroOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Boehm-GC-without-fee",
+                "GPL-1.0-or-later",
                 ".template.txt"
             )),
-            Self::GCCExceptionTwoDotZeroNote => include_str!(concat!(
+            Self::GPLTwoDotZeroOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GCC-exception-2.0-note",
+                "GPL-2.0-only",
                 ".template.txt"
             )),
-            Self::AGPLThreeDotZeroOnly => include_str!(concat!(
+            Self::GPLTwoDotZeroOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AGPL-3.0-only",
+                "GPL-2.0-or-later",
                 ".template.txt"
             )),
-            Self::BSDSystemicsWThreeWorks => include_str!(concat!(
+            Self::GPLThreeDotZeroOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Systemics-W3Works",
+                "GPL-3.0-only",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseOpenMPI => include_str!(concat!(
+            Self::GPLThreeDotZeroOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-Open-MPI",
+                "GPL-3.0-or-later",
                 ".template.txt"
             )),
-            Self::OCLCTwoDotZero => include_str!(concat!(
+            Self::GameProgrammingGems => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OCLC-2.0",
+                "Game-Programming-Gems",
                 ".template.txt"
             )),
-            Self::LALOneDotTwo => include_str!(concat!(
+            Self::Giftware => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LAL-1.2",
+                "Giftware",
                 ".template.txt"
             )),
-            Self::XdebugOneDotZeroThree => include_str!(concat!(
+            Self::Glide => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Xdebug-1.03",
+                "Glide",
                 ".template.txt"
             )),
-            Self::FSLOneDotOneALvTwo => include_str!(concat!(
+            Self::Glulxe => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FSL-1.1-ALv2",
+                "Glulxe",
                 ".template.txt"
             )),
-            Self::HPNDUCExportUS => include_str!(concat!(
+            Self::GraphicsGems => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-UC-export-US",
+                "Graphics-Gems",
                 ".template.txt"
             )),
-            Self::ECLOneDotZero => include_str!(concat!(
+            Self::Gutmann => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ECL-1.0",
+                "Gutmann",
                 ".template.txt"
             )),
-            Self::CCBYNCNDThreeDotZeroIGO => include_str!(concat!(
+            Self::HIDAPI => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-ND-3.0-IGO",
+                "HIDAPI",
                 ".template.txt"
             )),
-            Self::OGLCanadaTwoDotZero => include_str!(concat!(
+            Self::HPOneNineEightSix => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OGL-Canada-2.0",
+                "HP-1986",
                 ".template.txt"
             )),
-            Self::AGPLOneDotZeroOrLater => include_str!(concat!(
+            Self::HPOneNineEightNine => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AGPL-1.0-or-later",
+                "HP-1989",
                 ".template.txt"
             )),
-            Self::LGPLLR => include_str!(concat!(
+            Self::HPND => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPLLR",
+                "HPND",
                 ".template.txt"
             )),
-            Self::Fair => include_str!(concat!(
+            Self::HPNDDEC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Fair",
+                "HPND-DEC",
                 ".template.txt"
             )),
-            Self::SwiftException => include_str!(concat!(
+            Self::HPNDFennebergLivingston => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Swift-exception",
+                "HPND-Fenneberg-Livingston",
                 ".template.txt"
             )),
-            Self::JasPerTwoDotZero => include_str!(concat!(
+            Self::HPNDINRIAIMAG => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "JasPer-2.0",
+                "HPND-INRIA-IMAG",
                 ".template.txt"
             )),
-            Self::CECILLOneDotZero => include_str!(concat!(
+            Self::HPNDIntel => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CECILL-1.0",
+                "HPND-Intel",
                 ".template.txt"
             )),
-            Self::GPLThreeDotZeroLinkingSourceException => include_str!(concat!(
+            Self::HPNDKevlinHenney => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-3.0-linking-source-exception",
+                "HPND-Kevlin-Henney",
                 ".template.txt"
             )),
-            Self::BSDSourceCode => include_str!(concat!(
+            Self::HPNDMITDisclaimer => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Source-Code",
+                "HPND-MIT-disclaimer",
                 ".template.txt"
             )),
-            Self::Metamail => include_str!(concat!(
+            Self::HPNDMarkusKuhn => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "metamail",
+                "HPND-Markus-Kuhn",
                 ".template.txt"
             )),
-            Self::LLVMException => include_str!(concat!(
+            Self::HPNDNetrek => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LLVM-exception",
+                "HPND-Netrek",
                 ".template.txt"
             )),
-            Self::VSLOneDotZero => include_str!(concat!(
+            Self::HPNDPbmplus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "VSL-1.0",
+                "HPND-Pbmplus",
                 ".template.txt"
             )),
-            Self::LPPLOneDotThreec => include_str!(concat!(
+            Self::HPNDUC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LPPL-1.3c",
+                "HPND-UC",
                 ".template.txt"
             )),
-            Self::Furuseth => include_str!(concat!(
+            Self::HPNDUCExportUS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Furuseth",
+                "HPND-UC-export-US",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseAcpica => include_str!(concat!(
+            Self::HPNDDoc => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-acpica",
+                "HPND-doc",
                 ".template.txt"
             )),
-            Self::CNRIJython => include_str!(concat!(
+            Self::HPNDDocSell => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CNRI-Jython",
+                "HPND-doc-sell",
                 ".template.txt"
             )),
-            Self::LiLiQPOneDotOne => include_str!(concat!(
+            Self::HPNDExportUS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LiLiQ-P-1.1",
+                "HPND-export-US",
                 ".template.txt"
             )),
-            Self::FergusonTwofish => include_str!(concat!(
+            Self::HPNDExportUSAcknowledgement => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Ferguson-Twofish",
+                "HPND-export-US-acknowledgement",
                 ".template.txt"
             )),
-            Self::HPNDINRIAIMAG => include_str!(concat!(
+            Self::HPNDExportUSModify => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-INRIA-IMAG",
+                "HPND-export-US-modify",
                 ".template.txt"
             )),
-            Self::BitstreamCharter => include_str!(concat!(
+            Self::HPNDExportTwoUS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Bitstream-Charter",
+                "HPND-export2-US",
                 ".template.txt"
             )),
-            Self::UnicodeDFSTwoZeroOneSix => include_str!(concat!(
+            Self::HPNDMerchantabilityVariant => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Unicode-DFS-2016",
+                "HPND-merchantability-variant",
                 ".template.txt"
             )),
-            Self::MITModernVariant => include_str!(concat!(
+            Self::HPNDSellMITDisclaimerXserver => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-Modern-Variant",
+                "HPND-sell-MIT-disclaimer-xserver",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLThreeDotZeroPlus => include_str!(concat!(
+            Self::HPNDSellRegexpr => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-3.0+",
+                "HPND-sell-regexpr",
                 ".template.txt"
             )),
-            Self::Afmparse => include_str!(concat!(
+            Self::HPNDSellVariant => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Afmparse",
+                "HPND-sell-variant",
                 ".template.txt"
             )),
-            Self::HPNDFennebergLivingston => include_str!(concat!(
+            Self::HPNDSellVariantMITDisclaimer => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-Fenneberg-Livingston",
+                "HPND-sell-variant-MIT-disclaimer",
                 ".template.txt"
             )),
-            Self::Wwl => include_str!(concat!(
+            Self::HPNDSellVariantMITDisclaimerRev => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "wwl",
+                "HPND-sell-variant-MIT-disclaimer-rev",
                 ".template.txt"
             )),
-            Self::ClArtistic => include_str!(concat!(
+            Self::HTMLTIDY => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ClArtistic",
+                "HTMLTIDY",
                 ".template.txt"
             )),
-            Self::HPNDMarkusKuhn => include_str!(concat!(
+            Self::HaskellReport => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-Markus-Kuhn",
+                "HaskellReport",
                 ".template.txt"
             )),
-            Self::Blessing => include_str!(concat!(
+            Self::HippocraticTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "blessing",
+                "Hippocratic-2.1",
                 ".template.txt"
             )),
-            Self::SoftSurfer => include_str!(concat!(
+            Self::IBMPibs => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "softSurfer",
+                "IBM-pibs",
                 ".template.txt"
             )),
-            Self::BisonExceptionOneDotTwoFour => include_str!(concat!(
+            Self::ICU => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Bison-exception-1.24",
+                "ICU",
                 ".template.txt"
             )),
-            Self::CrystalStacker => include_str!(concat!(
+            Self::IECCodeComponentsEULA => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CrystalStacker",
+                "IEC-Code-Components-EULA",
                 ".template.txt"
             )),
-            Self::AML => include_str!(concat!(
+            Self::IJG => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AML",
+                "IJG",
                 ".template.txt"
             )),
-            Self::NCBIPD => include_str!(concat!(
+            Self::IJGShort => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NCBI-PD",
+                "IJG-short",
                 ".template.txt"
             )),
-            Self::GFDLOneDotTwoInvariantsOrLater => include_str!(concat!(
+            Self::IPA => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.2-invariants-or-later",
+                "IPA",
                 ".template.txt"
             )),
-            Self::DeprecatedECosTwoDotZero => include_str!(concat!(
+            Self::IPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_eCos-2.0",
+                "IPL-1.0",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotFive => include_str!(concat!(
+            Self::ISC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.5",
+                "ISC",
                 ".template.txt"
             )),
-            Self::AMPAS => include_str!(concat!(
+            Self::ISCVeillard => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AMPAS",
+                "ISC-Veillard",
                 ".template.txt"
             )),
-            Self::GFDLOneDotOneNoInvariantsOrLater => include_str!(concat!(
+            Self::ImageMagick => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.1-no-invariants-or-later",
+                "ImageMagick",
                 ".template.txt"
             )),
-            Self::CCBYFourDotZero => include_str!(concat!(
+            Self::ImlibTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-4.0",
+                "Imlib2",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotTwo => include_str!(concat!(
+            Self::InfoZIP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.2",
+                "Info-ZIP",
                 ".template.txt"
             )),
-            Self::CNRIPython => include_str!(concat!(
+            Self::InnerNetTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CNRI-Python",
+                "Inner-Net-2.0",
                 ".template.txt"
             )),
-            Self::BSLOneDotZero => include_str!(concat!(
+            Self::InnoSetup => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSL-1.0",
+                "InnoSetup",
                 ".template.txt"
             )),
-            Self::SMAILGPL => include_str!(concat!(
+            Self::Intel => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SMAIL-GPL",
+                "Intel",
                 ".template.txt"
             )),
-            Self::TUBerlinOneDotZero => include_str!(concat!(
+            Self::IntelACPI => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TU-Berlin-1.0",
+                "Intel-ACPI",
                 ".template.txt"
             )),
-            Self::VOSTROM => include_str!(concat!(
+            Self::InterbaseOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "VOSTROM",
+                "Interbase-1.0",
                 ".template.txt"
             )),
-            Self::LibpngTwoDotZero => include_str!(concat!(
+            Self::JPLImage => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "libpng-2.0",
+                "JPL-image",
                 ".template.txt"
             )),
-            Self::GnuJavamailException => include_str!(concat!(
+            Self::JPNIC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "gnu-javamail-exception",
+                "JPNIC",
                 ".template.txt"
             )),
-            Self::CCBYNCOneDotZero => include_str!(concat!(
+            Self::JSON => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-1.0",
+                "JSON",
                 ".template.txt"
             )),
-            Self::GPLThreeDotZeroThreeEightNineDsBaseException => include_str!(concat!(
+            Self::Jam => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-3.0-389-ds-base-exception",
+                "Jam",
                 ".template.txt"
             )),
-            Self::OSLOneDotZero => include_str!(concat!(
+            Self::JasPerTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OSL-1.0",
+                "JasPer-2.0",
                 ".template.txt"
             )),
-            Self::UnicodeThreeDotZero => include_str!(concat!(
+            Self::Kastrup => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Unicode-3.0",
+                "Kastrup",
                 ".template.txt"
             )),
-            Self::GFDLOneDotThreeOrLater => include_str!(concat!(
+            Self::Kazlib => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.3-or-later",
+                "Kazlib",
                 ".template.txt"
             )),
-            Self::InnoSetup => include_str!(concat!(
+            Self::KnuthCTAN => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "InnoSetup",
+                "Knuth-CTAN",
                 ".template.txt"
             )),
-            Self::CALOneDotZero => include_str!(concat!(
+            Self::LALOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CAL-1.0",
+                "LAL-1.2",
                 ".template.txt"
             )),
-            Self::CCBYSAThreeDotZeroIGO => include_str!(concat!(
+            Self::LALOneDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-3.0-IGO",
+                "LAL-1.3",
                 ".template.txt"
             )),
-            Self::Saxpath => include_str!(concat!(
+            Self::LGPLTwoDotZeroOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Saxpath",
+                "LGPL-2.0-only",
                 ".template.txt"
             )),
-            Self::OPUBLOneDotZero => include_str!(concat!(
+            Self::LGPLTwoDotZeroOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OPUBL-1.0",
+                "LGPL-2.0-or-later",
                 ".template.txt"
             )),
-            Self::LinuxManPagesCopyleftTwoPara => include_str!(concat!(
+            Self::LGPLTwoDotOneOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Linux-man-pages-copyleft-2-para",
+                "LGPL-2.1-only",
                 ".template.txt"
             )),
-            Self::CCBYThreeDotZeroAT => include_str!(concat!(
+            Self::LGPLTwoDotOneOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-3.0-AT",
+                "LGPL-2.1-or-later",
                 ".template.txt"
             )),
-            Self::GNATException => include_str!(concat!(
+            Self::LGPLThreeDotZeroOnly => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GNAT-exception",
+                "LGPL-3.0-only",
                 ".template.txt"
             )),
-            Self::CERNOHLPTwoDotZero => include_str!(concat!(
+            Self::LGPLThreeDotZeroOrLater => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CERN-OHL-P-2.0",
+                "LGPL-3.0-or-later",
                 ".template.txt"
             )),
-            Self::XOneOnevncOpensslException => include_str!(concat!(
+            Self::LGPLLR => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "x11vnc-openssl-exception",
+                "LGPLLR",
                 ".template.txt"
             )),
-            Self::FSFUL => include_str!(concat!(
+            Self::LOOP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FSFUL",
+                "LOOP",
                 ".template.txt"
             )),
-            Self::OPLUKThreeDotZero => include_str!(concat!(
+            Self::LPDDocument => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OPL-UK-3.0",
+                "LPD-document",
                 ".template.txt"
             )),
-            Self::UMichMerit => include_str!(concat!(
+            Self::LPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "UMich-Merit",
+                "LPL-1.0",
                 ".template.txt"
             )),
-            Self::CornellLosslessJPEG => include_str!(concat!(
+            Self::LPLOneDotZeroTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Cornell-Lossless-JPEG",
+                "LPL-1.02",
                 ".template.txt"
             )),
-            Self::OGDLTaiwanOneDotZero => include_str!(concat!(
+            Self::LPPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OGDL-Taiwan-1.0",
+                "LPPL-1.0",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseHP => include_str!(concat!(
+            Self::LPPLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-HP",
+                "LPPL-1.1",
                 ".template.txt"
             )),
-            Self::Plexus => include_str!(concat!(
+            Self::LPPLOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Plexus",
+                "LPPL-1.2",
                 ".template.txt"
             )),
-            Self::BcryptSolarDesigner => include_str!(concat!(
+            Self::LPPLOneDotThreea => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "bcrypt-Solar-Designer",
+                "LPPL-1.3a",
                 ".template.txt"
             )),
-            Self::CCBYNCSATwoDotZeroUK => include_str!(concat!(
+            Self::LPPLOneDotThreec => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-2.0-UK",
+                "LPPL-1.3c",
                 ".template.txt"
             )),
-            Self::SGIBTwoDotZero => include_str!(concat!(
+            Self::LZMASDKNineDotOneOneToNineDotTwoZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SGI-B-2.0",
+                "LZMA-SDK-9.11-to-9.20",
                 ".template.txt"
             )),
-            Self::CCBYThreeDotZeroIGO => include_str!(concat!(
+            Self::LZMASDKNineDotTwoTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-3.0-IGO",
+                "LZMA-SDK-9.22",
                 ".template.txt"
             )),
-            Self::HippocraticTwoDotOne => include_str!(concat!(
+            Self::LatexTwoe => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Hippocratic-2.1",
+                "Latex2e",
                 ".template.txt"
             )),
-            Self::SHLTwoDotOne => include_str!(concat!(
+            Self::LatexTwoeTranslatedNotice => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SHL-2.1",
+                "Latex2e-translated-notice",
                 ".template.txt"
             )),
-            Self::KiCadLibrariesException => include_str!(concat!(
+            Self::Leptonica => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "KiCad-libraries-exception",
+                "Leptonica",
                 ".template.txt"
             )),
-            Self::CPOLOneDotZeroTwo => include_str!(concat!(
+            Self::LiLiQPOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CPOL-1.02",
+                "LiLiQ-P-1.1",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLOneDotZero => include_str!(concat!(
+            Self::LiLiQROneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-1.0",
+                "LiLiQ-R-1.1",
                 ".template.txt"
             )),
-            Self::Cronyx => include_str!(concat!(
+            Self::LiLiQRplusOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Cronyx",
+                "LiLiQ-Rplus-1.1",
                 ".template.txt"
             )),
-            Self::LatexTwoeTranslatedNotice => include_str!(concat!(
+            Self::Libpng => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Latex2e-translated-notice",
+                "Libpng",
                 ".template.txt"
             )),
-            Self::KnuthCTAN => include_str!(concat!(
+            Self::LinuxOpenIb => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Knuth-CTAN",
+                "Linux-OpenIB",
                 ".template.txt"
             )),
-            Self::CCBYThreeDotZero => include_str!(concat!(
+            Self::LinuxManPagesOnePara => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-3.0",
+                "Linux-man-pages-1-para",
                 ".template.txt"
             )),
-            Self::BzipTwoOneDotZeroDotSix => include_str!(concat!(
+            Self::LinuxManPagesCopyleft => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "bzip2-1.0.6",
+                "Linux-man-pages-copyleft",
                 ".template.txt"
             )),
-            Self::OCCTPL => include_str!(concat!(
+            Self::LinuxManPagesCopyleftTwoPara => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OCCT-PL",
+                "Linux-man-pages-copyleft-2-para",
                 ".template.txt"
             )),
-            Self::SendmailEightDotTwoThree => include_str!(concat!(
+            Self::LinuxManPagesCopyleftVar => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Sendmail-8.23",
+                "Linux-man-pages-copyleft-var",
                 ".template.txt"
             )),
-            Self::Catharon => include_str!(concat!(
+            Self::LucidaBitmapFonts => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Catharon",
+                "Lucida-Bitmap-Fonts",
                 ".template.txt"
             )),
-            Self::IPLOneDotZero => include_str!(concat!(
+            Self::MIPS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "IPL-1.0",
+                "MIPS",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLTwoDotZeroWithFontException => include_str!(concat!(
+            Self::MIT => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-2.0-with-font-exception",
+                "MIT",
                 ".template.txt"
             )),
-            Self::APAFML => include_str!(concat!(
+            Self::MITZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "APAFML",
+                "MIT-0",
                 ".template.txt"
             )),
-            Self::Motosoto => include_str!(concat!(
+            Self::MITCMU => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Motosoto",
+                "MIT-CMU",
                 ".template.txt"
             )),
-            Self::CheckCvs => include_str!(concat!(
+            Self::MITClick => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "check-cvs",
+                "MIT-Click",
                 ".template.txt"
             )),
-            Self::Sendmail => include_str!(concat!(
+            Self::MITFestival => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Sendmail",
+                "MIT-Festival",
                 ".template.txt"
             )),
-            Self::PPL => include_str!(concat!(
+            Self::MITKhronosOld => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PPL",
+                "MIT-Khronos-old",
                 ".template.txt"
             )),
-            Self::PostgreSql => include_str!(concat!(
+            Self::MITModernVariant => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PostgreSQL",
+                "MIT-Modern-Variant",
                 ".template.txt"
             )),
-            Self::CDLOneDotZero => include_str!(concat!(
+            Self::MITWu => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CDL-1.0",
+                "MIT-Wu",
                 ".template.txt"
             )),
-            Self::GPLOneDotZeroOrLater => include_str!(concat!(
+            Self::MITAdvertising => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-1.0-or-later",
+                "MIT-advertising",
                 ".template.txt"
             )),
-            Self::CCSAOneDotZero => include_str!(concat!(
+            Self::MITEnna => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-SA-1.0",
+                "MIT-enna",
                 ".template.txt"
             )),
-            Self::IBMPibs => include_str!(concat!(
+            Self::MITFeh => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "IBM-pibs",
+                "MIT-feh",
                 ".template.txt"
             )),
-            Self::CERNOHLOneDotTwo => include_str!(concat!(
+            Self::MITOpenGroup => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CERN-OHL-1.2",
+                "MIT-open-group",
                 ".template.txt"
             )),
-            Self::IntelACPI => include_str!(concat!(
+            Self::MITTestregex => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Intel-ACPI",
+                "MIT-testregex",
                 ".template.txt"
             )),
-            Self::AdobeTwoZeroZeroSix => include_str!(concat!(
+            Self::MITNFA => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Adobe-2006",
+                "MITNFA",
                 ".template.txt"
             )),
-            Self::DeprecatedLGPLTwoDotZeroPlus => include_str!(concat!(
+            Self::MmiXware => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_LGPL-2.0+",
+                "MMIXware",
                 ".template.txt"
             )),
-            Self::SPLOneDotZero => include_str!(concat!(
+            Self::MPEGSSG => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SPL-1.0",
+                "MPEG-SSG",
                 ".template.txt"
             )),
-            Self::OML => include_str!(concat!(
+            Self::MPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OML",
+                "MPL-1.0",
                 ".template.txt"
             )),
-            Self::DOC => include_str!(concat!(
+            Self::MPLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DOC",
+                "MPL-1.1",
                 ".template.txt"
             )),
-            Self::MITClick => include_str!(concat!(
+            Self::MPLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-Click",
+                "MPL-2.0",
                 ".template.txt"
             )),
-            Self::MxmlException => include_str!(concat!(
+            Self::MPLTwoDotZeroNoCopyleftException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "mxml-exception",
+                "MPL-2.0-no-copyleft-exception",
                 ".template.txt"
             )),
-            Self::ApacheTwoDotZero => include_str!(concat!(
+            Self::MSLPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Apache-2.0",
+                "MS-LPL",
                 ".template.txt"
             )),
-            Self::NCGLUKTwoDotZero => include_str!(concat!(
+            Self::MSPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NCGL-UK-2.0",
+                "MS-PL",
                 ".template.txt"
             )),
-            Self::HTMLTIDY => include_str!(concat!(
+            Self::MSRL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HTMLTIDY",
+                "MS-RL",
                 ".template.txt"
             )),
-            Self::ANTLRPD => include_str!(concat!(
+            Self::MTLL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ANTLR-PD",
+                "MTLL",
                 ".template.txt"
             )),
-            Self::LALOneDotThree => include_str!(concat!(
+            Self::MackerrasThreeClause => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LAL-1.3",
+                "Mackerras-3-Clause",
                 ".template.txt"
             )),
-            Self::BSDInfernoNettverk => include_str!(concat!(
+            Self::MackerrasThreeClauseAcknowledgment => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Inferno-Nettverk",
+                "Mackerras-3-Clause-acknowledgment",
                 ".template.txt"
             )),
-            Self::RPLOneDotFive => include_str!(concat!(
+            Self::MakeIndex => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "RPL-1.5",
+                "MakeIndex",
                 ".template.txt"
             )),
-            Self::CcZeroOneDotZero => include_str!(concat!(
+            Self::MartinBirgmeier => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC0-1.0",
+                "Martin-Birgmeier",
                 ".template.txt"
             )),
-            Self::CECILLOneDotOne => include_str!(concat!(
+            Self::McPheeSlideshow => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CECILL-1.1",
+                "McPhee-slideshow",
                 ".template.txt"
             )),
-            Self::AMLGlslang => include_str!(concat!(
+            Self::Minpack => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AML-glslang",
+                "Minpack",
                 ".template.txt"
             )),
-            Self::DigiaQtLGPLExceptionOneDotOne => include_str!(concat!(
+            Self::MirOs => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Digia-Qt-LGPL-exception-1.1",
+                "MirOS",
                 ".template.txt"
             )),
-            Self::GCRDocs => include_str!(concat!(
+            Self::Motosoto => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GCR-docs",
+                "Motosoto",
                 ".template.txt"
             )),
-            Self::Glide => include_str!(concat!(
+            Self::MulanPslOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Glide",
+                "MulanPSL-1.0",
                 ".template.txt"
             )),
-            Self::CCBYSATwoDotZeroUK => include_str!(concat!(
+            Self::MulanPslTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-2.0-UK",
+                "MulanPSL-2.0",
                 ".template.txt"
             )),
-            Self::Glulxe => include_str!(concat!(
+            Self::Multics => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Glulxe",
+                "Multics",
                 ".template.txt"
             )),
-            Self::MITZero => include_str!(concat!(
+            Self::Mup => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-0",
+                "Mup",
                 ".template.txt"
             )),
-            Self::BSDFourClauseUC => include_str!(concat!(
+            Self::NAISTTwoZeroZeroThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-4-Clause-UC",
+                "NAIST-2003",
                 ".template.txt"
             )),
-            Self::DeprecatedLGPLTwoDotOne => include_str!(concat!(
+            Self::NASAOneDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_LGPL-2.1",
+                "NASA-1.3",
                 ".template.txt"
             )),
-            Self::AFLTwoDotOne => include_str!(concat!(
+            Self::NBPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AFL-2.1",
+                "NBPL-1.0",
                 ".template.txt"
             )),
-            Self::ECosExceptionTwoDotZero => include_str!(concat!(
+            Self::NCBIPD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "eCos-exception-2.0",
+                "NCBI-PD",
                 ".template.txt"
             )),
-            Self::CERNOHLWTwoDotZero => include_str!(concat!(
+            Self::NCGLUKTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CERN-OHL-W-2.0",
+                "NCGL-UK-2.0",
                 ".template.txt"
             )),
-            Self::Minpack => include_str!(concat!(
+            Self::NCL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Minpack",
+                "NCL",
                 ".template.txt"
             )),
-            Self::HPNDNetrek => include_str!(concat!(
+            Self::NCSA => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-Netrek",
+                "NCSA",
                 ".template.txt"
             )),
-            Self::DocBookSchema => include_str!(concat!(
+            Self::NGPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DocBook-Schema",
+                "NGPL",
                 ".template.txt"
             )),
-            Self::ManTwohtml => include_str!(concat!(
+            Self::NICTAOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "man2html",
+                "NICTA-1.0",
                 ".template.txt"
             )),
-            Self::LGPLTwoDotOneOrLater => include_str!(concat!(
+            Self::NISTPD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPL-2.1-or-later",
+                "NIST-PD",
                 ".template.txt"
             )),
-            Self::CMUMach => include_str!(concat!(
+            Self::NISTPDFallback => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CMU-Mach",
+                "NIST-PD-fallback",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotZero => include_str!(concat!(
+            Self::NISTSoftware => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.0",
+                "NIST-Software",
                 ".template.txt"
             )),
-            Self::Borceux => include_str!(concat!(
+            Self::NLODOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Borceux",
+                "NLOD-1.0",
                 ".template.txt"
             )),
-            Self::PHPThreeDotZero => include_str!(concat!(
+            Self::NLODTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PHP-3.0",
+                "NLOD-2.0",
                 ".template.txt"
             )),
-            Self::HPNDDEC => include_str!(concat!(
+            Self::NLPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-DEC",
+                "NLPL",
                 ".template.txt"
             )),
-            Self::Radvd => include_str!(concat!(
+            Self::NOSL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "radvd",
+                "NOSL",
                 ".template.txt"
             )),
-            Self::OGTSL => include_str!(concat!(
+            Self::NPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OGTSL",
+                "NPL-1.0",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotSeven => include_str!(concat!(
+            Self::NPLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.7",
+                "NPL-1.1",
                 ".template.txt"
             )),
-            Self::OGLUKTwoDotZero => include_str!(concat!(
+            Self::NPOSLThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OGL-UK-2.0",
+                "NPOSL-3.0",
                 ".template.txt"
             )),
-            Self::LGPLThreeDotZeroLinkingException => include_str!(concat!(
+            Self::NRL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPL-3.0-linking-exception",
+                "NRL",
                 ".template.txt"
             )),
-            Self::TtypZero => include_str!(concat!(
+            Self::NTIAPD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TTYP0",
+                "NTIA-PD",
                 ".template.txt"
             )),
-            Self::Zed => include_str!(concat!(
+            Self::NTP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Zed",
+                "NTP",
                 ".template.txt"
             )),
-            Self::PADL => include_str!(concat!(
+            Self::NTPZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PADL",
+                "NTP-0",
                 ".template.txt"
             )),
-            Self::OFLOneDotOneRFN => include_str!(concat!(
+            Self::Naumen => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OFL-1.1-RFN",
+                "Naumen",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotTwoDotTwo => include_str!(concat!(
+            Self::NetCdf => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.2.2",
+                "NetCDF",
                 ".template.txt"
             )),
-            Self::Diffmark => include_str!(concat!(
+            Self::Newsletr => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "diffmark",
+                "Newsletr",
                 ".template.txt"
             )),
-            Self::WThreeC => include_str!(concat!(
+            Self::Nokia => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "W3C",
+                "Nokia",
                 ".template.txt"
             )),
-            Self::HPNDSellVariantMITDisclaimer => include_str!(concat!(
+            Self::Noweb => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-sell-variant-MIT-disclaimer",
+                "Noweb",
                 ".template.txt"
             )),
-            Self::ArtisticOneDotZeroPerl => include_str!(concat!(
+            Self::OUDAOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Artistic-1.0-Perl",
+                "O-UDA-1.0",
                 ".template.txt"
             )),
-            Self::OCamlLGPLLinkingException => include_str!(concat!(
+            Self::OAR => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OCaml-LGPL-linking-exception",
+                "OAR",
                 ".template.txt"
             )),
-            Self::PythonTwoDotZero => include_str!(concat!(
+            Self::OCCTPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Python-2.0",
+                "OCCT-PL",
                 ".template.txt"
             )),
-            Self::MackerrasThreeClauseAcknowledgment => include_str!(concat!(
+            Self::OCLCTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Mackerras-3-Clause-acknowledgment",
+                "OCLC-2.0",
                 ".template.txt"
             )),
-            Self::CCBYNCNDOneDotZero => include_str!(concat!(
+            Self::ODCByOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-ND-1.0",
+                "ODC-By-1.0",
                 ".template.txt"
             )),
-            Self::CCBYNDOneDotZero => include_str!(concat!(
+            Self::ODbLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-ND-1.0",
+                "ODbL-1.0",
                 ".template.txt"
             )),
-            Self::Mup => include_str!(concat!(
+            Self::OFFIS => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Mup",
+                "OFFIS",
                 ".template.txt"
             )),
-            Self::TPDL => include_str!(concat!(
+            Self::OFLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TPDL",
+                "OFL-1.0",
                 ".template.txt"
             )),
-            Self::HIDAPI => include_str!(concat!(
+            Self::OFLOneDotZeroRFN => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HIDAPI",
+                "OFL-1.0-RFN",
                 ".template.txt"
             )),
-            Self::InfoZIP => include_str!(concat!(
+            Self::OFLOneDotZeroNoRFN => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Info-ZIP",
+                "OFL-1.0-no-RFN",
                 ".template.txt"
             )),
-            Self::DeprecatedLGPLThreeDotZero => include_str!(concat!(
+            Self::OFLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_LGPL-3.0",
+                "OFL-1.1",
                 ".template.txt"
             )),
-            Self::AFLThreeDotZero => include_str!(concat!(
+            Self::OFLOneDotOneRFN => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AFL-3.0",
+                "OFL-1.1-RFN",
                 ".template.txt"
             )),
-            Self::HPOneNineEightNine => include_str!(concat!(
+            Self::OFLOneDotOneNoRFN => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HP-1989",
+                "OFL-1.1-no-RFN",
                 ".template.txt"
             )),
-            Self::FSFULLRWD => include_str!(concat!(
+            Self::OGCOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FSFULLRWD",
+                "OGC-1.0",
                 ".template.txt"
             )),
-            Self::Leptonica => include_str!(concat!(
+            Self::OGDLTaiwanOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Leptonica",
+                "OGDL-Taiwan-1.0",
                 ".template.txt"
             )),
-            Self::GraphicsGems => include_str!(concat!(
+            Self::OGLCanadaTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Graphics-Gems",
+                "OGL-Canada-2.0",
                 ".template.txt"
             )),
-            Self::UnicodeDFSTwoZeroOneFive => include_str!(concat!(
+            Self::OGLUKOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Unicode-DFS-2015",
+                "OGL-UK-1.0",
                 ".template.txt"
             )),
-            Self::TrustedQsl => include_str!(concat!(
+            Self::OGLUKTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TrustedQSL",
+                "OGL-UK-2.0",
                 ".template.txt"
             )),
-            Self::APSLTwoDotZero => include_str!(concat!(
+            Self::OGLUKThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "APSL-2.0",
+                "OGL-UK-3.0",
                 ".template.txt"
             )),
-            Self::NLPL => include_str!(concat!(
+            Self::OGTSL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NLPL",
+                "OGTSL",
                 ".template.txt"
             )),
-            Self::Giftware => include_str!(concat!(
+            Self::OLDAPOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Giftware",
+                "OLDAP-1.1",
                 ".template.txt"
             )),
-            Self::SSPLOneDotZero => include_str!(concat!(
+            Self::OLDAPOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SSPL-1.0",
+                "OLDAP-1.2",
                 ".template.txt"
             )),
-            Self::CCBYTwoDotFiveAU => include_str!(concat!(
+            Self::OLDAPOneDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-2.5-AU",
+                "OLDAP-1.3",
                 ".template.txt"
             )),
-            Self::HPNDSellVariantMITDisclaimerRev => include_str!(concat!(
+            Self::OLDAPOneDotFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-sell-variant-MIT-disclaimer-rev",
+                "OLDAP-1.4",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLOneDotZeroPlus => include_str!(concat!(
+            Self::OLDAPTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-1.0+",
+                "OLDAP-2.0",
                 ".template.txt"
             )),
-            Self::Libtiff => include_str!(concat!(
+            Self::OLDAPTwoDotZeroDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "libtiff",
+                "OLDAP-2.0.1",
                 ".template.txt"
             )),
-            Self::IPA => include_str!(concat!(
+            Self::OLDAPTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "IPA",
+                "OLDAP-2.1",
                 ".template.txt"
             )),
-            Self::CCBYSAOneDotZero => include_str!(concat!(
+            Self::OLDAPTwoDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-1.0",
+                "OLDAP-2.2",
                 ".template.txt"
             )),
-            Self::CCBYNCSAOneDotZero => include_str!(concat!(
+            Self::OLDAPTwoDotTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-1.0",
+                "OLDAP-2.2.1",
                 ".template.txt"
             )),
-            Self::ErlPlOneDotOne => include_str!(concat!(
+            Self::OLDAPTwoDotTwoDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ErlPL-1.1",
+                "OLDAP-2.2.2",
                 ".template.txt"
             )),
-            Self::MPLTwoDotZero => include_str!(concat!(
+            Self::OLDAPTwoDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MPL-2.0",
+                "OLDAP-2.3",
                 ".template.txt"
             )),
-            Self::Pkgconf => include_str!(concat!(
+            Self::OLDAPTwoDotFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "pkgconf",
+                "OLDAP-2.4",
                 ".template.txt"
             )),
-            Self::BSDProtection => include_str!(concat!(
+            Self::OLDAPTwoDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Protection",
+                "OLDAP-2.5",
                 ".template.txt"
             )),
-            Self::GFDLOneDotTwoNoInvariantsOnly => include_str!(concat!(
+            Self::OLDAPTwoDotSix => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.2-no-invariants-only",
+                "OLDAP-2.6",
                 ".template.txt"
             )),
-            Self::AdobeGlyph => include_str!(concat!(
+            Self::OLDAPTwoDotSeven => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Adobe-Glyph",
+                "OLDAP-2.7",
                 ".template.txt"
             )),
-            Self::EPLOneDotZero => include_str!(concat!(
+            Self::OLDAPTwoDotEight => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EPL-1.0",
+                "OLDAP-2.8",
                 ".template.txt"
             )),
-            Self::DeprecatedLGPLTwoDotOnePlus => include_str!(concat!(
+            Self::OLFLOneDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_LGPL-2.1+",
+                "OLFL-1.3",
                 ".template.txt"
             )),
-            Self::ITwopGplJavaException => include_str!(concat!(
+            Self::OML => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "i2p-gpl-java-exception",
+                "OML",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLThreeDotZeroWithGCCException => include_str!(concat!(
+            Self::OPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-3.0-with-GCC-exception",
+                "OPL-1.0",
                 ".template.txt"
             )),
-            Self::AMDNewlib => include_str!(concat!(
+            Self::OPLUKThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AMD-newlib",
+                "OPL-UK-3.0",
                 ".template.txt"
             )),
-            Self::Jam => include_str!(concat!(
+            Self::OPUBLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Jam",
+                "OPUBL-1.0",
                 ".template.txt"
             )),
-            Self::DeprecatedAGPLOneDotZero => include_str!(concat!(
+            Self::OSETPLTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_AGPL-1.0",
+                "OSET-PL-2.1",
                 ".template.txt"
             )),
-            Self::Baekmuk => include_str!(concat!(
+            Self::OSLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Baekmuk",
+                "OSL-1.0",
                 ".template.txt"
             )),
-            Self::Qhull => include_str!(concat!(
+            Self::OSLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Qhull",
+                "OSL-1.1",
                 ".template.txt"
             )),
-            Self::OpenSslStandalone => include_str!(concat!(
+            Self::OSLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OpenSSL-standalone",
+                "OSL-2.0",
                 ".template.txt"
             )),
-            Self::PcreTwoException => include_str!(concat!(
+            Self::OSLTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PCRE2-exception",
+                "OSL-2.1",
                 ".template.txt"
             )),
-            Self::BSDTwoClausePkgconfDisclaimer => include_str!(concat!(
+            Self::OSLThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-2-Clause-pkgconf-disclaimer",
+                "OSL-3.0",
                 ".template.txt"
             )),
-            Self::NBPLOneDotZero => include_str!(concat!(
+            Self::OpenPbsTwoDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NBPL-1.0",
+                "OpenPBS-2.3",
                 ".template.txt"
             )),
-            Self::MulanPslTwoDotZero => include_str!(concat!(
+            Self::OpenSsl => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MulanPSL-2.0",
+                "OpenSSL",
                 ".template.txt"
             )),
-            Self::CCBYThreeDotZeroUS => include_str!(concat!(
+            Self::OpenSslStandalone => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-3.0-US",
+                "OpenSSL-standalone",
                 ".template.txt"
             )),
-            Self::LucidaBitmapFonts => include_str!(concat!(
+            Self::OpenVision => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Lucida-Bitmap-Fonts",
+                "OpenVision",
                 ".template.txt"
             )),
-            Self::CCBYNCSATwoDotZeroFR => include_str!(concat!(
+            Self::PADL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-2.0-FR",
+                "PADL",
                 ".template.txt"
             )),
-            Self::ANTLRPDFallback => include_str!(concat!(
+            Self::PDDLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ANTLR-PD-fallback",
+                "PDDL-1.0",
                 ".template.txt"
             )),
-            Self::MITAdvertising => include_str!(concat!(
+            Self::PHPThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-advertising",
+                "PHP-3.0",
                 ".template.txt"
             )),
-            Self::HPNDExportUSModify => include_str!(concat!(
+            Self::PHPThreeDotZeroOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-export-US-modify",
+                "PHP-3.01",
                 ".template.txt"
             )),
-            Self::Swrule => include_str!(concat!(
+            Self::PPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "swrule",
+                "PPL",
                 ".template.txt"
             )),
-            Self::Beerware => include_str!(concat!(
+            Self::PSFTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Beerware",
+                "PSF-2.0",
                 ".template.txt"
             )),
-            Self::SMLNJ => include_str!(concat!(
+            Self::ParitySixDotZeroDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SMLNJ",
+                "Parity-6.0.0",
                 ".template.txt"
             )),
-            Self::MPEGSSG => include_str!(concat!(
+            Self::ParitySevenDotZeroDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MPEG-SSG",
+                "Parity-7.0.0",
                 ".template.txt"
             )),
-            Self::PolyFormSmallBusinessOneDotZeroDotZero => include_str!(concat!(
+            Self::Pixar => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PolyForm-Small-Business-1.0.0",
+                "Pixar",
                 ".template.txt"
             )),
-            Self::AGPLThreeDotZeroOrLater => include_str!(concat!(
+            Self::Plexus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AGPL-3.0-or-later",
+                "Plexus",
                 ".template.txt"
             )),
-            Self::BSDAdvertisingAcknowledgement => include_str!(concat!(
+            Self::PolyFormNoncommercialOneDotZeroDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Advertising-Acknowledgement",
+                "PolyForm-Noncommercial-1.0.0",
                 ".template.txt"
             )),
-            Self::ZPLTwoDotZero => include_str!(concat!(
+            Self::PolyFormSmallBusinessOneDotZeroDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ZPL-2.0",
+                "PolyForm-Small-Business-1.0.0",
                 ".template.txt"
             )),
-            Self::Xpp => include_str!(concat!(
+            Self::PostgreSql => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "xpp",
+                "PostgreSQL",
                 ".template.txt"
             )),
-            Self::Nokia => include_str!(concat!(
+            Self::PythonTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Nokia",
+                "Python-2.0",
                 ".template.txt"
             )),
-            Self::HPNDKevlinHenney => include_str!(concat!(
+            Self::PythonTwoDotZeroDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-Kevlin-Henney",
+                "Python-2.0.1",
                 ".template.txt"
             )),
-            Self::PolyFormNoncommercialOneDotZeroDotZero => include_str!(concat!(
+            Self::QPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "PolyForm-Noncommercial-1.0.0",
+                "QPL-1.0",
                 ".template.txt"
             )),
-            Self::CCBYSATwoDotOneJP => include_str!(concat!(
+            Self::QPLOneDotZeroINRIATwoZeroZeroFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-2.1-JP",
+                "QPL-1.0-INRIA-2004",
                 ".template.txt"
             )),
-            Self::XkeyboardConfigZinoviev => include_str!(concat!(
+            Self::Qhull => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "xkeyboard-config-Zinoviev",
+                "Qhull",
                 ".template.txt"
             )),
-            Self::NAISTTwoZeroZeroThree => include_str!(concat!(
+            Self::RHeCosOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NAIST-2003",
+                "RHeCos-1.1",
                 ".template.txt"
             )),
-            Self::HPNDExportUS => include_str!(concat!(
+            Self::RPLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-export-US",
+                "RPL-1.1",
                 ".template.txt"
             )),
-            Self::GStreamerExceptionTwoZeroZeroEight => include_str!(concat!(
+            Self::RPLOneDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GStreamer-exception-2008",
+                "RPL-1.5",
                 ".template.txt"
             )),
-            Self::DeprecatedBSDTwoClauseNetBsd => include_str!(concat!(
+            Self::RPSLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_BSD-2-Clause-NetBSD",
+                "RPSL-1.0",
                 ".template.txt"
             )),
-            Self::WxWindowsExceptionThreeDotOne => include_str!(concat!(
+            Self::RSAMD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "WxWindows-exception-3.1",
+                "RSA-MD",
                 ".template.txt"
             )),
-            Self::LibselinuxOneDotZero => include_str!(concat!(
+            Self::RSCPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "libselinux-1.0",
+                "RSCPL",
                 ".template.txt"
             )),
-            Self::QPLOneDotZero => include_str!(concat!(
+            Self::Rdisc => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "QPL-1.0",
+                "Rdisc",
                 ".template.txt"
             )),
-            Self::Pixar => include_str!(concat!(
+            Self::Ruby => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Pixar",
+                "Ruby",
                 ".template.txt"
             )),
-            Self::FawkesRuntimeException => include_str!(concat!(
+            Self::RubyPty => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Fawkes-Runtime-exception",
+                "Ruby-pty",
                 ".template.txt"
             )),
-            Self::CCPDDC => include_str!(concat!(
+            Self::SAXPD => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-PDDC",
+                "SAX-PD",
                 ".template.txt"
             )),
-            Self::Xerox => include_str!(concat!(
+            Self::SAXPDTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Xerox",
+                "SAX-PD-2.0",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseNoNuclearWarranty => include_str!(concat!(
+            Self::SCEA => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-No-Nuclear-Warranty",
+                "SCEA",
                 ".template.txt"
             )),
-            Self::AnyOSIPerlModules => include_str!(concat!(
+            Self::SGIBOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "any-OSI-perl-modules",
+                "SGI-B-1.0",
                 ".template.txt"
             )),
-            Self::BUSLOneDotOne => include_str!(concat!(
+            Self::SGIBOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BUSL-1.1",
+                "SGI-B-1.1",
                 ".template.txt"
             )),
-            Self::MakeIndex => include_str!(concat!(
+            Self::SGIBTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MakeIndex",
+                "SGI-B-2.0",
                 ".template.txt"
             )),
-            Self::NTIAPD => include_str!(concat!(
+            Self::SGIOpenGl => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NTIA-PD",
+                "SGI-OpenGL",
                 ".template.txt"
             )),
-            Self::LGPLThreeDotZeroOrLater => include_str!(concat!(
+            Self::SgpFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LGPL-3.0-or-later",
+                "SGP4",
                 ".template.txt"
             )),
-            Self::DeprecatedNunit => include_str!(concat!(
+            Self::SHLZeroDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_Nunit",
+                "SHL-0.5",
                 ".template.txt"
             )),
-            Self::OFFIS => include_str!(concat!(
+            Self::SHLZeroDotFiveOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OFFIS",
+                "SHL-0.51",
                 ".template.txt"
             )),
-            Self::LatexTwoe => include_str!(concat!(
+            Self::SISSL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Latex2e",
+                "SISSL",
                 ".template.txt"
             )),
-            Self::SSHOpenSsh => include_str!(concat!(
+            Self::SISSLOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SSH-OpenSSH",
+                "SISSL-1.2",
                 ".template.txt"
             )),
-            Self::Entessa => include_str!(concat!(
+            Self::SL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Entessa",
+                "SL",
                 ".template.txt"
             )),
-            Self::AFLTwoDotZero => include_str!(concat!(
+            Self::SMAILGPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AFL-2.0",
+                "SMAIL-GPL",
                 ".template.txt"
             )),
-            Self::DeprecatedLGPLTwoDotZero => include_str!(concat!(
+            Self::SMLNJ => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_LGPL-2.0",
+                "SMLNJ",
                 ".template.txt"
             )),
-            Self::ZendTwoDotZero => include_str!(concat!(
+            Self::SMPPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Zend-2.0",
+                "SMPPL",
                 ".template.txt"
             )),
-            Self::MifException => include_str!(concat!(
+            Self::SNIA => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "mif-exception",
+                "SNIA",
                 ".template.txt"
             )),
-            Self::Xfig => include_str!(concat!(
+            Self::SPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Xfig",
+                "SPL-1.0",
                 ".template.txt"
             )),
-            Self::Caldera => include_str!(concat!(
+            Self::SSHOpenSsh => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Caldera",
+                "SSH-OpenSSH",
                 ".template.txt"
             )),
-            Self::LibutilDavidNugent => include_str!(concat!(
+            Self::SSHShort => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "libutil-David-Nugent",
+                "SSH-short",
                 ".template.txt"
             )),
-            Self::AsteriskLinkingProtocolsException => include_str!(concat!(
+            Self::SsLeayStandalone => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Asterisk-linking-protocols-exception",
+                "SSLeay-standalone",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotSix => include_str!(concat!(
+            Self::SSPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.6",
+                "SSPL-1.0",
                 ".template.txt"
             )),
-            Self::ZeroBsd => include_str!(concat!(
+            Self::SWL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "0BSD",
+                "SWL",
                 ".template.txt"
             )),
-            Self::GNUCompilerException => include_str!(concat!(
+            Self::Saxpath => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GNU-compiler-exception",
+                "Saxpath",
                 ".template.txt"
             )),
-            Self::Barr => include_str!(concat!(
+            Self::SchemeReport => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Barr",
+                "SchemeReport",
                 ".template.txt"
             )),
-            Self::SGIOpenGl => include_str!(concat!(
+            Self::Sendmail => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SGI-OpenGL",
+                "Sendmail",
                 ".template.txt"
             )),
-            Self::CCPDMOneDotZero => include_str!(concat!(
+            Self::SendmailEightDotTwoThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-PDM-1.0",
+                "Sendmail-8.23",
                 ".template.txt"
             )),
-            Self::MITEnna => include_str!(concat!(
+            Self::SendmailOpenSourceOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-enna",
+                "Sendmail-Open-Source-1.1",
                 ".template.txt"
             )),
-            Self::NISTPD => include_str!(concat!(
+            Self::SimPlTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NIST-PD",
+                "SimPL-2.0",
                 ".template.txt"
             )),
-            Self::CCBYTwoDotFive => include_str!(concat!(
+            Self::Sleepycat => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-2.5",
+                "Sleepycat",
                 ".template.txt"
             )),
-            Self::HPNDSellRegexpr => include_str!(concat!(
+            Self::Soundex => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-sell-regexpr",
+                "Soundex",
                 ".template.txt"
             )),
-            Self::LOOP => include_str!(concat!(
+            Self::SpencerEightSix => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LOOP",
+                "Spencer-86",
                 ".template.txt"
             )),
-            Self::OLDAPTwoDotOne => include_str!(concat!(
+            Self::SpencerNineFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLDAP-2.1",
+                "Spencer-94",
                 ".template.txt"
             )),
-            Self::SISSLOneDotTwo => include_str!(concat!(
+            Self::SpencerNineNine => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SISSL-1.2",
+                "Spencer-99",
                 ".template.txt"
             )),
-            Self::ThreeDSlicerOneDotZero => include_str!(concat!(
+            Self::SugarCrmOneDotOneDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "3D-Slicer-1.0",
+                "SugarCRM-1.1.3",
                 ".template.txt"
             )),
-            Self::OCCTExceptionOneDotZero => include_str!(concat!(
+            Self::SunPPP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OCCT-exception-1.0",
+                "Sun-PPP",
                 ".template.txt"
             )),
-            Self::ThreeEightNineException => include_str!(concat!(
+            Self::SunPPPTwoZeroZeroZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "389-exception",
+                "Sun-PPP-2000",
                 ".template.txt"
             )),
-            Self::SL => include_str!(concat!(
+            Self::SunPro => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SL",
+                "SunPro",
                 ".template.txt"
             )),
-            Self::FLTKException => include_str!(concat!(
+            Self::Symlinks => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FLTK-exception",
+                "Symlinks",
                 ".template.txt"
             )),
-            Self::GFDLOneDotOneOnly => include_str!(concat!(
+            Self::TAPROHLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.1-only",
+                "TAPR-OHL-1.0",
                 ".template.txt"
             )),
-            Self::CALOneDotZeroCombinedWorkException => include_str!(concat!(
+            Self::TCL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CAL-1.0-Combined-Work-Exception",
+                "TCL",
                 ".template.txt"
             )),
-            Self::DeprecatedStandardMlNJ => include_str!(concat!(
+            Self::TCPWrappers => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_StandardML-NJ",
+                "TCP-wrappers",
                 ".template.txt"
             )),
-            Self::ADSL => include_str!(concat!(
+            Self::TGPPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ADSL",
+                "TGPPL-1.0",
                 ".template.txt"
             )),
-            Self::BSDFourDotThreeTahoe => include_str!(concat!(
+            Self::TMate => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-4.3TAHOE",
+                "TMate",
                 ".template.txt"
             )),
-            Self::ZPLTwoDotOne => include_str!(concat!(
+            Self::TORQUEOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ZPL-2.1",
+                "TORQUE-1.1",
                 ".template.txt"
             )),
-            Self::ImlibTwo => include_str!(concat!(
+            Self::TOSL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Imlib2",
+                "TOSL",
                 ".template.txt"
             )),
-            Self::RPLOneDotOne => include_str!(concat!(
+            Self::TPDL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "RPL-1.1",
+                "TPDL",
                 ".template.txt"
             )),
-            Self::Gnuplot => include_str!(concat!(
+            Self::TPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "gnuplot",
+                "TPL-1.0",
                 ".template.txt"
             )),
-            Self::DFSLOneDotZero => include_str!(concat!(
+            Self::TTWL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "D-FSL-1.0",
+                "TTWL",
                 ".template.txt"
             )),
-            Self::AdobeUtopia => include_str!(concat!(
+            Self::TtypZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Adobe-Utopia",
+                "TTYP0",
                 ".template.txt"
             )),
-            Self::OpenSsl => include_str!(concat!(
+            Self::TUBerlinOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OpenSSL",
+                "TU-Berlin-1.0",
                 ".template.txt"
             )),
-            Self::GPLThreeDotZeroOrLater => include_str!(concat!(
+            Self::TUBerlinTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-3.0-or-later",
+                "TU-Berlin-2.0",
                 ".template.txt"
             )),
-            Self::OSETPLTwoDotOne => include_str!(concat!(
+            Self::TermReadKey => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OSET-PL-2.1",
+                "TermReadKey",
                 ".template.txt"
             )),
-            Self::LZMASDKNineDotOneOneToNineDotTwoZero => include_str!(concat!(
+            Self::ThirdEye => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LZMA-SDK-9.11-to-9.20",
+                "ThirdEye",
                 ".template.txt"
             )),
-            Self::SAXPDTwoDotZero => include_str!(concat!(
+            Self::TrustedQsl => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SAX-PD-2.0",
+                "TrustedQSL",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseClear => include_str!(concat!(
+            Self::UCAR => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-Clear",
+                "UCAR",
                 ".template.txt"
             )),
-            Self::NASAOneDotThree => include_str!(concat!(
+            Self::UCLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NASA-1.3",
+                "UCL-1.0",
                 ".template.txt"
             )),
-            Self::EuDatagrid => include_str!(concat!(
+            Self::UMichMerit => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EUDatagrid",
+                "UMich-Merit",
                 ".template.txt"
             )),
-            Self::CERNOHLOneDotOne => include_str!(concat!(
+            Self::UPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CERN-OHL-1.1",
+                "UPL-1.0",
                 ".template.txt"
             )),
-            Self::EtalabTwoDotZero => include_str!(concat!(
+            Self::URTRLE => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "etalab-2.0",
+                "URT-RLE",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => include_str!(concat!(
+            Self::UbuntuFontOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-3.0-with-autoconf-exception",
+                "Ubuntu-font-1.0",
                 ".template.txt"
             )),
-            Self::CERNOHLSTwoDotZero => include_str!(concat!(
+            Self::UnicodeThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CERN-OHL-S-2.0",
+                "Unicode-3.0",
                 ".template.txt"
             )),
-            Self::Gutmann => include_str!(concat!(
+            Self::UnicodeDFSTwoZeroOneFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Gutmann",
+                "Unicode-DFS-2015",
                 ".template.txt"
             )),
-            Self::OLFLOneDotThree => include_str!(concat!(
+            Self::UnicodeDFSTwoZeroOneSix => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OLFL-1.3",
+                "Unicode-DFS-2016",
                 ".template.txt"
             )),
-            Self::LinuxManPagesCopyleftVar => include_str!(concat!(
+            Self::UnicodeTOU => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Linux-man-pages-copyleft-var",
+                "Unicode-TOU",
                 ".template.txt"
             )),
-            Self::OGLUKThreeDotZero => include_str!(concat!(
+            Self::UnixCrypt => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OGL-UK-3.0",
+                "UnixCrypt",
                 ".template.txt"
             )),
-            Self::ParitySixDotZeroDotZero => include_str!(concat!(
+            Self::Unlicense => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Parity-6.0.0",
+                "Unlicense",
                 ".template.txt"
             )),
-            Self::LPPLOneDotThreea => include_str!(concat!(
+            Self::VOSTROM => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LPPL-1.3a",
+                "VOSTROM",
                 ".template.txt"
             )),
-            Self::Mplus => include_str!(concat!(
+            Self::VSLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "mplus",
+                "VSL-1.0",
                 ".template.txt"
             )),
-            Self::COILOneDotZero => include_str!(concat!(
+            Self::Vim => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "COIL-1.0",
+                "Vim",
                 ".template.txt"
             )),
-            Self::Gtkbook => include_str!(concat!(
+            Self::WThreeC => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "gtkbook",
+                "W3C",
                 ".template.txt"
             )),
-            Self::XSkat => include_str!(concat!(
+            Self::WThreeCOneNineNineEightZeroSevenTwoZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "XSkat",
+                "W3C-19980720",
                 ".template.txt"
             )),
-            Self::HPNDDocSell => include_str!(concat!(
+            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-doc-sell",
+                "W3C-20150513",
                 ".template.txt"
             )),
-            Self::RSAMD => include_str!(concat!(
+            Self::WTFPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "RSA-MD",
+                "WTFPL",
                 ".template.txt"
             )),
-            Self::CCBYSAThreeDotZeroAT => include_str!(concat!(
+            Self::WatcomOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-3.0-AT",
+                "Watcom-1.0",
                 ".template.txt"
             )),
-            Self::NISTPDFallback => include_str!(concat!(
+            Self::WidgetWorkshop => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NIST-PD-fallback",
+                "Widget-Workshop",
                 ".template.txt"
             )),
-            Self::IMatix => include_str!(concat!(
+            Self::Wsuipa => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "iMatix",
+                "Wsuipa",
                 ".template.txt"
             )),
-            Self::GPLThreeDotZeroOnly => include_str!(concat!(
+            Self::XOneOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-3.0-only",
+                "X11",
                 ".template.txt"
             )),
-            Self::CryptoSwift => include_str!(concat!(
+            Self::XOneOneDistributeModificationsVariant => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CryptoSwift",
+                "X11-distribute-modifications-variant",
                 ".template.txt"
             )),
-            Self::WTFPL => include_str!(concat!(
+            Self::XOneOneSwapped => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "WTFPL",
+                "X11-swapped",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseNoNuclearLicense => include_str!(concat!(
+            Self::XFreeEightSixOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-No-Nuclear-License",
+                "XFree86-1.1",
                 ".template.txt"
             )),
-            Self::CveTou => include_str!(concat!(
+            Self::XSkat => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "cve-tou",
+                "XSkat",
                 ".template.txt"
             )),
-            Self::HPNDMITDisclaimer => include_str!(concat!(
+            Self::XdebugOneDotZeroThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-MIT-disclaimer",
+                "Xdebug-1.03",
                 ".template.txt"
             )),
-            Self::CCBYNDTwoDotZero => include_str!(concat!(
+            Self::Xerox => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-ND-2.0",
+                "Xerox",
                 ".template.txt"
             )),
-            Self::Vim => include_str!(concat!(
+            Self::Xfig => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Vim",
+                "Xfig",
                 ".template.txt"
             )),
-            Self::NISTSoftware => include_str!(concat!(
+            Self::Xnet => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NIST-Software",
+                "Xnet",
                 ".template.txt"
             )),
-            Self::LPPLOneDotZero => include_str!(concat!(
+            Self::YPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LPPL-1.0",
+                "YPL-1.0",
                 ".template.txt"
             )),
             Self::YPLOneDotOne => include_str!(concat!(
@@ -4208,622 +6527,2251 @@ impl License {
                 "YPL-1.1",
                 ".template.txt"
             )),
-            Self::CCBYNCNDTwoDotZero => include_str!(concat!(
+            Self::ZPLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-ND-2.0",
+                "ZPL-1.1",
                 ".template.txt"
             )),
-            Self::CopyleftNextZeroDotThreeDotOne => include_str!(concat!(
+            Self::ZPLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "copyleft-next-0.3.1",
+                "ZPL-2.0",
                 ".template.txt"
             )),
-            Self::MITCMU => include_str!(concat!(
+            Self::ZPLTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-CMU",
+                "ZPL-2.1",
                 ".template.txt"
             )),
-            Self::RPSLOneDotZero => include_str!(concat!(
+            Self::Zed => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "RPSL-1.0",
+                "Zed",
                 ".template.txt"
             )),
-            Self::BSDTwoClausePatent => include_str!(concat!(
+            Self::Zeeff => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-2-Clause-Patent",
+                "Zeeff",
                 ".template.txt"
             )),
-            Self::Dtoa => include_str!(concat!(
+            Self::ZendTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "dtoa",
+                "Zend-2.0",
                 ".template.txt"
             )),
-            Self::NCSA => include_str!(concat!(
+            Self::ZimbraOneDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NCSA",
+                "Zimbra-1.3",
                 ".template.txt"
             )),
-            Self::NPLOneDotOne => include_str!(concat!(
+            Self::ZimbraOneDotFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NPL-1.1",
+                "Zimbra-1.4",
                 ".template.txt"
             )),
-            Self::SCEA => include_str!(concat!(
+            Self::Zlib => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SCEA",
+                "Zlib",
                 ".template.txt"
             )),
-            Self::SMPPL => include_str!(concat!(
+            Self::AnyOSI => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SMPPL",
+                "any-OSI",
                 ".template.txt"
             )),
-            Self::LiLiQROneDotOne => include_str!(concat!(
+            Self::AnyOSIPerlModules => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LiLiQ-R-1.1",
+                "any-OSI-perl-modules",
                 ".template.txt"
             )),
-            Self::OFLOneDotZeroRFN => include_str!(concat!(
+            Self::BcryptSolarDesigner => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OFL-1.0-RFN",
+                "bcrypt-Solar-Designer",
                 ".template.txt"
             )),
-            Self::NPOSLThreeDotZero => include_str!(concat!(
+            Self::Blessing => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NPOSL-3.0",
+                "blessing",
                 ".template.txt"
             )),
-            Self::ImageMagick => include_str!(concat!(
+            Self::BzipTwoOneDotZeroDotSix => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ImageMagick",
+                "bzip2-1.0.6",
                 ".template.txt"
             )),
-            Self::BSDFourClauseShortened => include_str!(concat!(
+            Self::CheckCvs => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-4-Clause-Shortened",
+                "check-cvs",
                 ".template.txt"
             )),
-            Self::AsteriskException => include_str!(concat!(
+            Self::Checkmk => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Asterisk-exception",
+                "checkmk",
                 ".template.txt"
             )),
-            Self::LibpriOpenHThreeTwoThreeException => include_str!(concat!(
+            Self::CopyleftNextZeroDotThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "libpri-OpenH323-exception",
+                "copyleft-next-0.3.0",
                 ".template.txt"
             )),
-            Self::Aladdin => include_str!(concat!(
+            Self::CopyleftNextZeroDotThreeDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Aladdin",
+                "copyleft-next-0.3.1",
                 ".template.txt"
             )),
-            Self::UnicodeTOU => include_str!(concat!(
+            Self::Curl => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Unicode-TOU",
+                "curl",
                 ".template.txt"
             )),
-            Self::OpenPbsTwoDotThree => include_str!(concat!(
+            Self::CveTou => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OpenPBS-2.3",
+                "cve-tou",
                 ".template.txt"
             )),
-            Self::AnyOSI => include_str!(concat!(
+            Self::DeprecatedAGPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "any-OSI",
+                "deprecated_AGPL-1.0",
                 ".template.txt"
             )),
-            Self::UCLOneDotZero => include_str!(concat!(
+            Self::DeprecatedAGPLThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "UCL-1.0",
+                "deprecated_AGPL-3.0",
                 ".template.txt"
             )),
-            Self::ZimbraOneDotFour => include_str!(concat!(
+            Self::DeprecatedBSDTwoClauseFreeBsd => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Zimbra-1.4",
+                "deprecated_BSD-2-Clause-FreeBSD",
                 ".template.txt"
             )),
-            Self::BootloaderException => include_str!(concat!(
+            Self::DeprecatedBSDTwoClauseNetBsd => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Bootloader-exception",
+                "deprecated_BSD-2-Clause-NetBSD",
                 ".template.txt"
             )),
-            Self::BisonExceptionTwoDotTwo => include_str!(concat!(
+            Self::DeprecatedGFDLOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Bison-exception-2.2",
+                "deprecated_GFDL-1.1",
                 ".template.txt"
             )),
-            Self::TGPPLOneDotZero => include_str!(concat!(
+            Self::DeprecatedGFDLOneDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TGPPL-1.0",
+                "deprecated_GFDL-1.2",
                 ".template.txt"
             )),
-            Self::BitTorrentOneDotOne => include_str!(concat!(
+            Self::DeprecatedGFDLOneDotThree => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BitTorrent-1.1",
+                "deprecated_GFDL-1.3",
                 ".template.txt"
             )),
-            Self::Wsuipa => include_str!(concat!(
+            Self::DeprecatedGPLOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Wsuipa",
+                "deprecated_GPL-1.0",
                 ".template.txt"
             )),
-            Self::DeprecatedNokiaQtExceptionOneDotOne => include_str!(concat!(
+            Self::DeprecatedGPLOneDotZeroPlus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_Nokia-Qt-exception-1.1",
+                "deprecated_GPL-1.0+",
                 ".template.txt"
             )),
-            Self::CCBYNCSATwoDotZeroDE => include_str!(concat!(
+            Self::DeprecatedGPLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-2.0-DE",
+                "deprecated_GPL-2.0",
                 ".template.txt"
             )),
-            Self::LinuxManPagesCopyleft => include_str!(concat!(
+            Self::DeprecatedGPLTwoDotZeroPlus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Linux-man-pages-copyleft",
+                "deprecated_GPL-2.0+",
                 ".template.txt"
             )),
-            Self::Xlock => include_str!(concat!(
+            Self::DeprecatedGPLTwoDotZeroWithGCCException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "xlock",
+                "deprecated_GPL-2.0-with-GCC-exception",
                 ".template.txt"
             )),
-            Self::LiLiQRplusOneDotOne => include_str!(concat!(
+            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LiLiQ-Rplus-1.1",
+                "deprecated_GPL-2.0-with-autoconf-exception",
                 ".template.txt"
             )),
-            Self::GenericXts => include_str!(concat!(
+            Self::DeprecatedGPLTwoDotZeroWithBisonException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "generic-xts",
+                "deprecated_GPL-2.0-with-bison-exception",
                 ".template.txt"
             )),
-            Self::ZimbraOneDotThree => include_str!(concat!(
+            Self::DeprecatedGPLTwoDotZeroWithClasspathException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Zimbra-1.3",
+                "deprecated_GPL-2.0-with-classpath-exception",
                 ".template.txt"
             )),
-            Self::GPLTwoDotZeroOnly => include_str!(concat!(
+            Self::DeprecatedGPLTwoDotZeroWithFontException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-2.0-only",
+                "deprecated_GPL-2.0-with-font-exception",
                 ".template.txt"
             )),
-            Self::OGLUKOneDotZero => include_str!(concat!(
+            Self::DeprecatedGPLThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OGL-UK-1.0",
+                "deprecated_GPL-3.0",
                 ".template.txt"
             )),
-            Self::AFLOneDotOne => include_str!(concat!(
+            Self::DeprecatedGPLThreeDotZeroPlus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AFL-1.1",
+                "deprecated_GPL-3.0+",
                 ".template.txt"
             )),
-            Self::SsLeayStandalone => include_str!(concat!(
+            Self::DeprecatedGPLThreeDotZeroWithGCCException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SSLeay-standalone",
+                "deprecated_GPL-3.0-with-GCC-exception",
                 ".template.txt"
             )),
-            Self::XOneOneSwapped => include_str!(concat!(
+            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "X11-swapped",
+                "deprecated_GPL-3.0-with-autoconf-exception",
                 ".template.txt"
             )),
-            Self::OpenJdkAssemblyExceptionOneDotZero => include_str!(concat!(
+            Self::DeprecatedLGPLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OpenJDK-assembly-exception-1.0",
+                "deprecated_LGPL-2.0",
                 ".template.txt"
             )),
-            Self::GFDLOneDotThreeNoInvariantsOrLater => include_str!(concat!(
+            Self::DeprecatedLGPLTwoDotZeroPlus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.3-no-invariants-or-later",
+                "deprecated_LGPL-2.0+",
                 ".template.txt"
             )),
-            Self::MTLL => include_str!(concat!(
+            Self::DeprecatedLGPLTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MTLL",
+                "deprecated_LGPL-2.1",
                 ".template.txt"
             )),
-            Self::UbuntuFontOneDotZero => include_str!(concat!(
+            Self::DeprecatedLGPLTwoDotOnePlus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Ubuntu-font-1.0",
+                "deprecated_LGPL-2.1+",
                 ".template.txt"
             )),
-            Self::DocBookXML => include_str!(concat!(
+            Self::DeprecatedLGPLThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DocBook-XML",
+                "deprecated_LGPL-3.0",
                 ".template.txt"
             )),
-            Self::MITTestregex => include_str!(concat!(
+            Self::DeprecatedLGPLThreeDotZeroPlus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-testregex",
+                "deprecated_LGPL-3.0+",
                 ".template.txt"
             )),
-            Self::CCBYNCTwoDotFive => include_str!(concat!(
+            Self::DeprecatedNetSNMP => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-2.5",
+                "deprecated_Net-SNMP",
                 ".template.txt"
             )),
-            Self::PythonLdap => include_str!(concat!(
+            Self::DeprecatedNunit => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "python-ldap",
+                "deprecated_Nunit",
                 ".template.txt"
             )),
-            Self::GlTwoPs => include_str!(concat!(
+            Self::DeprecatedStandardMlNJ => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GL2PS",
+                "deprecated_StandardML-NJ",
                 ".template.txt"
             )),
-            Self::LPLOneDotZeroTwo => include_str!(concat!(
+            Self::DeprecatedBzipTwoOneDotZeroDotFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LPL-1.02",
+                "deprecated_bzip2-1.0.5",
                 ".template.txt"
             )),
-            Self::MITNFA => include_str!(concat!(
+            Self::DeprecatedECosTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MITNFA",
+                "deprecated_eCos-2.0",
                 ".template.txt"
             )),
-            Self::Checkmk => include_str!(concat!(
+            Self::DeprecatedWxWindows => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "checkmk",
+                "deprecated_wxWindows",
                 ".template.txt"
             )),
-            Self::GFDLOneDotTwoOnly => include_str!(concat!(
+            Self::Diffmark => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.2-only",
+                "diffmark",
                 ".template.txt"
             )),
-            Self::NGPL => include_str!(concat!(
+            Self::Dtoa => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NGPL",
+                "dtoa",
                 ".template.txt"
             )),
-            Self::MulanPslOneDotZero => include_str!(concat!(
+            Self::Dvipdfm => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MulanPSL-1.0",
+                "dvipdfm",
                 ".template.txt"
             )),
-            Self::DeprecatedWxWindows => include_str!(concat!(
+            Self::EGenix => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_wxWindows",
+                "eGenix",
                 ".template.txt"
             )),
-            Self::OGCOneDotZero => include_str!(concat!(
+            Self::EtalabTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OGC-1.0",
+                "etalab-2.0",
                 ".template.txt"
             )),
-            Self::Ulem => include_str!(concat!(
+            Self::Fwlw => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ulem",
+                "fwlw",
                 ".template.txt"
             )),
-            Self::AutoconfExceptionThreeDotZero => include_str!(concat!(
+            Self::GSoapOneDotThreeb => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Autoconf-exception-3.0",
+                "gSOAP-1.3b",
                 ".template.txt"
             )),
-            Self::HarbourException => include_str!(concat!(
+            Self::GenericXts => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "harbour-exception",
+                "generic-xts",
                 ".template.txt"
             )),
-            Self::UCAR => include_str!(concat!(
+            Self::Gnuplot => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "UCAR",
+                "gnuplot",
                 ".template.txt"
             )),
-            Self::MSPL => include_str!(concat!(
+            Self::Gtkbook => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MS-PL",
+                "gtkbook",
                 ".template.txt"
             )),
-            Self::JPLImage => include_str!(concat!(
+            Self::Hdparm => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "JPL-image",
+                "hdparm",
                 ".template.txt"
             )),
-            Self::FontExceptionTwoDotZero => include_str!(concat!(
+            Self::IMatix => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Font-exception-2.0",
+                "iMatix",
                 ".template.txt"
             )),
-            Self::GFDLOneDotThreeInvariantsOrLater => include_str!(concat!(
+            Self::Jove => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.3-invariants-or-later",
+                "jove",
                 ".template.txt"
             )),
-            Self::Fwlw => include_str!(concat!(
+            Self::LibpngTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "fwlw",
+                "libpng-2.0",
                 ".template.txt"
             )),
-            Self::InnerNetTwoDotZero => include_str!(concat!(
+            Self::LibselinuxOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Inner-Net-2.0",
+                "libselinux-1.0",
                 ".template.txt"
             )),
-            Self::MPLOneDotZero => include_str!(concat!(
+            Self::Libtiff => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MPL-1.0",
+                "libtiff",
                 ".template.txt"
             )),
-            Self::CommunitySpecOneDotZero => include_str!(concat!(
+            Self::LibutilDavidNugent => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Community-Spec-1.0",
+                "libutil-David-Nugent",
                 ".template.txt"
             )),
-            Self::CUAOPLOneDotZero => include_str!(concat!(
+            Self::Lsof => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CUA-OPL-1.0",
+                "lsof",
                 ".template.txt"
             )),
-            Self::UBDLException => include_str!(concat!(
+            Self::Magaz => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "UBDL-exception",
+                "magaz",
                 ".template.txt"
             )),
-            Self::GFDLOneDotOneInvariantsOnly => include_str!(concat!(
+            Self::Mailprio => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.1-invariants-only",
+                "mailprio",
                 ".template.txt"
             )),
-            Self::FreeBsdDOC => include_str!(concat!(
+            Self::ManTwohtml => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FreeBSD-DOC",
+                "man2html",
                 ".template.txt"
             )),
-            Self::EPLTwoDotZero => include_str!(concat!(
+            Self::Metamail => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EPL-2.0",
+                "metamail",
                 ".template.txt"
             )),
-            Self::SendmailOpenSourceOneDotOne => include_str!(concat!(
+            Self::MpiPermissive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Sendmail-Open-Source-1.1",
+                "mpi-permissive",
                 ".template.txt"
             )),
-            Self::Eurosym => include_str!(concat!(
+            Self::MpichTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Eurosym",
+                "mpich2",
                 ".template.txt"
             )),
-            Self::GPLOneDotZeroOnly => include_str!(concat!(
+            Self::Mplus => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-1.0-only",
+                "mplus",
                 ".template.txt"
             )),
-            Self::DeprecatedBSDTwoClauseFreeBsd => include_str!(concat!(
+            Self::Pkgconf => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_BSD-2-Clause-FreeBSD",
+                "pkgconf",
                 ".template.txt"
             )),
-            Self::SHLZeroDotFive => include_str!(concat!(
+            Self::Pnmstitch => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SHL-0.5",
+                "pnmstitch",
                 ".template.txt"
             )),
-            Self::XOneOne => include_str!(concat!(
+            Self::Psfrag => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "X11",
+                "psfrag",
                 ".template.txt"
             )),
-            Self::ThirdEye => include_str!(concat!(
+            Self::Psutils => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ThirdEye",
+                "psutils",
                 ".template.txt"
             )),
-            Self::FSFAPNoWarrantyDisclaimer => include_str!(concat!(
+            Self::PythonLdap => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FSFAP-no-warranty-disclaimer",
+                "python-ldap",
                 ".template.txt"
             )),
-            Self::SimPlTwoDotZero => include_str!(concat!(
+            Self::Radvd => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SimPL-2.0",
+                "radvd",
                 ".template.txt"
             )),
-            Self::InterbaseOneDotZero => include_str!(concat!(
+            Self::Snprintf => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Interbase-1.0",
+                "snprintf",
                 ".template.txt"
             )),
-            Self::DeprecatedBzipTwoOneDotZeroDotFive => include_str!(concat!(
+            Self::SoftSurfer => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_bzip2-1.0.5",
+                "softSurfer",
                 ".template.txt"
             )),
-            Self::Multics => include_str!(concat!(
+            Self::SshKeyscan => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Multics",
+                "ssh-keyscan",
                 ".template.txt"
             )),
-            Self::GStreamerExceptionTwoZeroZeroFive => include_str!(concat!(
+            Self::Swrule => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GStreamer-exception-2005",
+                "swrule",
                 ".template.txt"
             )),
-            Self::CCBYNCSATwoDotZero => include_str!(concat!(
+            Self::Threeparttable => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-2.0",
+                "threeparttable",
                 ".template.txt"
             )),
-            Self::GD => include_str!(concat!(
+            Self::Ulem => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GD",
+                "ulem",
                 ".template.txt"
             )),
-            Self::CCBYSATwoDotZero => include_str!(concat!(
+            Self::WThreem => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-2.0",
+                "w3m",
                 ".template.txt"
             )),
-            Self::CECILLC => include_str!(concat!(
+            Self::Wwl => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CECILL-C",
+                "wwl",
                 ".template.txt"
             )),
-            Self::ElasticTwoDotZero => include_str!(concat!(
+            Self::Xinetd => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Elastic-2.0",
+                "xinetd",
                 ".template.txt"
             )),
-            Self::MITWu => include_str!(concat!(
+            Self::XkeyboardConfigZinoviev => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-Wu",
+                "xkeyboard-config-Zinoviev",
                 ".template.txt"
             )),
-            Self::Snprintf => include_str!(concat!(
+            Self::Xlock => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "snprintf",
+                "xlock",
                 ".template.txt"
             )),
-            Self::EUPLOneDotZero => include_str!(concat!(
+            Self::Xpp => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EUPL-1.0",
+                "xpp",
                 ".template.txt"
             )),
-            Self::AMDPLPA => include_str!(concat!(
+            Self::Xzoom => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AMDPLPA",
+                "xzoom",
                 ".template.txt"
             )),
-            Self::APSLOneDotZero => include_str!(concat!(
+            Self::ZlibAcknowledgement => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "APSL-1.0",
+                "zlib-acknowledgement",
                 ".template.txt"
             )),
-            Self::BSDSourceBeginningFile => include_str!(concat!(
+        }
+    }
+}
+impl std::fmt::Display for License {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.spdx_id())
+    }
+}
+impl std::str::FromStr for License {
+    type Err = ParseLicenseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::models::parse_license_id(s).map_err(ParseLicenseError)
+    }
+}
+impl License {
+    #[doc = r#" Returns the full name of the license (e.g., "MIT License")."#]
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::ZeroBsd => Some("BSD Zero Clause License"),
+            Self::ThreeDSlicerOneDotZero => Some("3D Slicer License v1.0"),
+            Self::AAL => Some("Attribution Assurance License"),
+            Self::ADSL => Some("Amazon Digital Services License"),
+            Self::AFLOneDotOne => Some("Academic Free License v1.1"),
+            Self::AFLOneDotTwo => Some("Academic Free License v1.2"),
+            Self::AFLTwoDotZero => Some("Academic Free License v2.0"),
+            Self::AFLTwoDotOne => Some("Academic Free License v2.1"),
+            Self::AFLThreeDotZero => Some("Academic Free License v3.0"),
+            Self::AGPLOneDotZeroOnly => Some("Affero General Public License v1.0 only"),
+            Self::AGPLOneDotZeroOrLater => Some("Affero General Public License v1.0 or later"),
+            Self::AGPLThreeDotZeroOnly => Some("GNU Affero General Public License v3.0 only"),
+            Self::AGPLThreeDotZeroOrLater => {
+                Some("GNU Affero General Public License v3.0 or later")
+            }
+            Self::AMDNewlib => Some("AMD newlib License"),
+            Self::AMDPLPA => Some("AMD's plpa_map.c License"),
+            Self::AML => Some("Apple MIT License"),
+            Self::AMLGlslang => Some("AML glslang variant License"),
+            Self::AMPAS => Some("Academy of Motion Picture Arts and Sciences BSD"),
+            Self::ANTLRPD => Some("ANTLR Software Rights Notice"),
+            Self::ANTLRPDFallback => Some("ANTLR Software Rights Notice with license fallback"),
+            Self::APAFML => Some("Adobe Postscript AFM License"),
+            Self::APLOneDotZero => Some("Adaptive Public License 1.0"),
+            Self::APSLOneDotZero => Some("Apple Public Source License 1.0"),
+            Self::APSLOneDotOne => Some("Apple Public Source License 1.1"),
+            Self::APSLOneDotTwo => Some("Apple Public Source License 1.2"),
+            Self::APSLTwoDotZero => Some("Apple Public Source License 2.0"),
+            Self::ASWFDigitalAssetsOneDotZero => Some("ASWF Digital Assets License version 1.0"),
+            Self::ASWFDigitalAssetsOneDotOne => Some("ASWF Digital Assets License 1.1"),
+            Self::Abstyles => Some("Abstyles License"),
+            Self::AdaCoreDoc => Some("AdaCore Doc License"),
+            Self::AdobeTwoZeroZeroSix => {
+                Some("Adobe Systems Incorporated Source Code License Agreement")
+            }
+            Self::AdobeDisplayPostScript => Some("Adobe Display PostScript License"),
+            Self::AdobeGlyph => Some("Adobe Glyph List License"),
+            Self::AdobeUtopia => Some("Adobe Utopia Font License"),
+            Self::Afmparse => Some("Afmparse License"),
+            Self::Aladdin => Some("Aladdin Free Public License"),
+            Self::ApacheOneDotZero => Some("Apache License 1.0"),
+            Self::ApacheOneDotOne => Some("Apache License 1.1"),
+            Self::ApacheTwoDotZero => Some("Apache License 2.0"),
+            Self::AppSTwop => Some("App::s2p License"),
+            Self::ArphicOneNineNineNine => Some("Arphic Public License"),
+            Self::ArtisticOneDotZero => Some("Artistic License 1.0"),
+            Self::ArtisticOneDotZeroPerl => Some("Artistic License 1.0 (Perl)"),
+            Self::ArtisticOneDotZeroClEight => Some("Artistic License 1.0 w/clause 8"),
+            Self::ArtisticTwoDotZero => Some("Artistic License 2.0"),
+            Self::BSDOneClause => Some("BSD 1-Clause License"),
+            Self::BSDTwoClause => Some("BSD 2-Clause \"Simplified\" License"),
+            Self::BSDTwoClauseDarwin => Some("BSD 2-Clause - Ian Darwin variant"),
+            Self::BSDTwoClausePatent => Some("BSD-2-Clause Plus Patent License"),
+            Self::BSDTwoClauseViews => Some("BSD 2-Clause with views sentence"),
+            Self::BSDTwoClauseFirstLines => Some("BSD 2-Clause - first lines requirement"),
+            Self::BSDTwoClausePkgconfDisclaimer => Some("BSD 2-Clause pkgconf disclaimer variant"),
+            Self::BSDThreeClause => Some("BSD 3-Clause \"New\" or \"Revised\" License"),
+            Self::BSDThreeClauseAttribution => Some("BSD with attribution"),
+            Self::BSDThreeClauseClear => Some("BSD 3-Clause Clear License"),
+            Self::BSDThreeClauseHP => Some("Hewlett-Packard BSD variant license"),
+            Self::BSDThreeClauseLBNL => Some("Lawrence Berkeley National Labs BSD variant license"),
+            Self::BSDThreeClauseModification => Some("BSD 3-Clause Modification"),
+            Self::BSDThreeClauseNoMilitaryLicense => Some("BSD 3-Clause No Military License"),
+            Self::BSDThreeClauseNoNuclearLicense => Some("BSD 3-Clause No Nuclear License"),
+            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => {
+                Some("BSD 3-Clause No Nuclear License 2014")
+            }
+            Self::BSDThreeClauseNoNuclearWarranty => Some("BSD 3-Clause No Nuclear Warranty"),
+            Self::BSDThreeClauseOpenMPI => Some("BSD 3-Clause Open MPI variant"),
+            Self::BSDThreeClauseSun => Some("BSD 3-Clause Sun Microsystems"),
+            Self::BSDThreeClauseAcpica => Some("BSD 3-Clause acpica variant"),
+            Self::BSDThreeClauseFlex => Some("BSD 3-Clause Flex variant"),
+            Self::BSDFourClause => Some("BSD 4-Clause \"Original\" or \"Old\" License"),
+            Self::BSDFourClauseShortened => Some("BSD 4 Clause Shortened"),
+            Self::BSDFourClauseUC => Some("BSD-4-Clause (University of California-Specific)"),
+            Self::BSDFourDotThreeReno => Some("BSD 4.3 RENO License"),
+            Self::BSDFourDotThreeTahoe => Some("BSD 4.3 TAHOE License"),
+            Self::BSDAdvertisingAcknowledgement => Some("BSD Advertising Acknowledgement License"),
+            Self::BSDAttributionHPNDDisclaimer => Some("BSD with Attribution and HPND disclaimer"),
+            Self::BSDInfernoNettverk => Some("BSD-Inferno-Nettverk"),
+            Self::BSDProtection => Some("BSD Protection License"),
+            Self::BSDSourceCode => Some("BSD Source Code Attribution"),
+            Self::BSDSourceBeginningFile => {
+                Some("BSD Source Code Attribution - beginning of file variant")
+            }
+            Self::BSDSystemics => Some("Systemics BSD variant license"),
+            Self::BSDSystemicsWThreeWorks => Some("Systemics W3Works BSD variant license"),
+            Self::BSLOneDotZero => Some("Boost Software License 1.0"),
+            Self::BUSLOneDotOne => Some("Business Source License 1.1"),
+            Self::Baekmuk => Some("Baekmuk License"),
+            Self::Bahyph => Some("Bahyph License"),
+            Self::Barr => Some("Barr License"),
+            Self::Beerware => Some("Beerware License"),
+            Self::BitTorrentOneDotZero => Some("BitTorrent Open Source License v1.0"),
+            Self::BitTorrentOneDotOne => Some("BitTorrent Open Source License v1.1"),
+            Self::BitstreamCharter => Some("Bitstream Charter Font License"),
+            Self::BitstreamVera => Some("Bitstream Vera Font License"),
+            Self::BlueOakOneDotZeroDotZero => Some("Blue Oak Model License 1.0.0"),
+            Self::BoehmGC => Some("Boehm-Demers-Weiser GC License"),
+            Self::BoehmGCWithoutFee => Some("Boehm-Demers-Weiser GC License (without fee)"),
+            Self::Borceux => Some("Borceux license"),
+            Self::BrianGladmanTwoClause => Some("Brian Gladman 2-Clause License"),
+            Self::BrianGladmanThreeClause => Some("Brian Gladman 3-Clause License"),
+            Self::CUDAOneDotZero => Some("Computational Use of Data Agreement v1.0"),
+            Self::CALOneDotZero => Some("Cryptographic Autonomy License 1.0"),
+            Self::CALOneDotZeroCombinedWorkException => {
+                Some("Cryptographic Autonomy License 1.0 (Combined Work Exception)")
+            }
+            Self::CATOSLOneDotOne => Some("Computer Associates Trusted Open Source License 1.1"),
+            Self::CCBYOneDotZero => Some("Creative Commons Attribution 1.0 Generic"),
+            Self::CCBYTwoDotZero => Some("Creative Commons Attribution 2.0 Generic"),
+            Self::CCBYTwoDotFive => Some("Creative Commons Attribution 2.5 Generic"),
+            Self::CCBYTwoDotFiveAU => Some("Creative Commons Attribution 2.5 Australia"),
+            Self::CCBYThreeDotZero => Some("Creative Commons Attribution 3.0 Unported"),
+            Self::CCBYThreeDotZeroAT => Some("Creative Commons Attribution 3.0 Austria"),
+            Self::CCBYThreeDotZeroAU => Some("Creative Commons Attribution 3.0 Australia"),
+            Self::CCBYThreeDotZeroDE => Some("Creative Commons Attribution 3.0 Germany"),
+            Self::CCBYThreeDotZeroIGO => Some("Creative Commons Attribution 3.0 IGO"),
+            Self::CCBYThreeDotZeroNL => Some("Creative Commons Attribution 3.0 Netherlands"),
+            Self::CCBYThreeDotZeroUS => Some("Creative Commons Attribution 3.0 United States"),
+            Self::CCBYFourDotZero => Some("Creative Commons Attribution 4.0 International"),
+            Self::CCBYNCOneDotZero => {
+                Some("Creative Commons Attribution Non Commercial 1.0 Generic")
+            }
+            Self::CCBYNCTwoDotZero => {
+                Some("Creative Commons Attribution Non Commercial 2.0 Generic")
+            }
+            Self::CCBYNCTwoDotFive => {
+                Some("Creative Commons Attribution Non Commercial 2.5 Generic")
+            }
+            Self::CCBYNCThreeDotZero => {
+                Some("Creative Commons Attribution Non Commercial 3.0 Unported")
+            }
+            Self::CCBYNCThreeDotZeroDE => {
+                Some("Creative Commons Attribution Non Commercial 3.0 Germany")
+            }
+            Self::CCBYNCFourDotZero => {
+                Some("Creative Commons Attribution Non Commercial 4.0 International")
+            }
+            Self::CCBYNCNDOneDotZero => {
+                Some("Creative Commons Attribution Non Commercial No Derivatives 1.0 Generic")
+            }
+            Self::CCBYNCNDTwoDotZero => {
+                Some("Creative Commons Attribution Non Commercial No Derivatives 2.0 Generic")
+            }
+            Self::CCBYNCNDTwoDotFive => {
+                Some("Creative Commons Attribution Non Commercial No Derivatives 2.5 Generic")
+            }
+            Self::CCBYNCNDThreeDotZero => {
+                Some("Creative Commons Attribution Non Commercial No Derivatives 3.0 Unported")
+            }
+            Self::CCBYNCNDThreeDotZeroDE => {
+                Some("Creative Commons Attribution Non Commercial No Derivatives 3.0 Germany")
+            }
+            Self::CCBYNCNDThreeDotZeroIGO => {
+                Some("Creative Commons Attribution Non Commercial No Derivatives 3.0 IGO")
+            }
+            Self::CCBYNCNDFourDotZero => {
+                Some("Creative Commons Attribution Non Commercial No Derivatives 4.0 International")
+            }
+            Self::CCBYNCSAOneDotZero => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 1.0 Generic")
+            }
+            Self::CCBYNCSATwoDotZero => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 2.0 Generic")
+            }
+            Self::CCBYNCSATwoDotZeroDE => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 2.0 Germany")
+            }
+            Self::CCBYNCSATwoDotZeroFR => {
+                Some("Creative Commons Attribution-NonCommercial-ShareAlike 2.0 France")
+            }
+            Self::CCBYNCSATwoDotZeroUK => Some(
+                "Creative Commons Attribution Non Commercial Share Alike 2.0 England and Wales",
+            ),
+            Self::CCBYNCSATwoDotFive => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 2.5 Generic")
+            }
+            Self::CCBYNCSAThreeDotZero => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 3.0 Unported")
+            }
+            Self::CCBYNCSAThreeDotZeroDE => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 3.0 Germany")
+            }
+            Self::CCBYNCSAThreeDotZeroIGO => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 3.0 IGO")
+            }
+            Self::CCBYNCSAFourDotZero => {
+                Some("Creative Commons Attribution Non Commercial Share Alike 4.0 International")
+            }
+            Self::CCBYNDOneDotZero => {
+                Some("Creative Commons Attribution No Derivatives 1.0 Generic")
+            }
+            Self::CCBYNDTwoDotZero => {
+                Some("Creative Commons Attribution No Derivatives 2.0 Generic")
+            }
+            Self::CCBYNDTwoDotFive => {
+                Some("Creative Commons Attribution No Derivatives 2.5 Generic")
+            }
+            Self::CCBYNDThreeDotZero => {
+                Some("Creative Commons Attribution No Derivatives 3.0 Unported")
+            }
+            Self::CCBYNDThreeDotZeroDE => {
+                Some("Creative Commons Attribution No Derivatives 3.0 Germany")
+            }
+            Self::CCBYNDFourDotZero => {
+                Some("Creative Commons Attribution No Derivatives 4.0 International")
+            }
+            Self::CCBYSAOneDotZero => Some("Creative Commons Attribution Share Alike 1.0 Generic"),
+            Self::CCBYSATwoDotZero => Some("Creative Commons Attribution Share Alike 2.0 Generic"),
+            Self::CCBYSATwoDotZeroUK => {
+                Some("Creative Commons Attribution Share Alike 2.0 England and Wales")
+            }
+            Self::CCBYSATwoDotOneJP => Some("Creative Commons Attribution Share Alike 2.1 Japan"),
+            Self::CCBYSATwoDotFive => Some("Creative Commons Attribution Share Alike 2.5 Generic"),
+            Self::CCBYSAThreeDotZero => {
+                Some("Creative Commons Attribution Share Alike 3.0 Unported")
+            }
+            Self::CCBYSAThreeDotZeroAT => {
+                Some("Creative Commons Attribution Share Alike 3.0 Austria")
+            }
+            Self::CCBYSAThreeDotZeroDE => {
+                Some("Creative Commons Attribution Share Alike 3.0 Germany")
+            }
+            Self::CCBYSAThreeDotZeroIGO => Some("Creative Commons Attribution-ShareAlike 3.0 IGO"),
+            Self::CCBYSAFourDotZero => {
+                Some("Creative Commons Attribution Share Alike 4.0 International")
+            }
+            Self::CCPDDC => Some("Creative Commons Public Domain Dedication and Certification"),
+            Self::CCPDMOneDotZero => Some("Creative    Commons Public Domain Mark 1.0 Universal"),
+            Self::CCSAOneDotZero => Some("Creative Commons Share Alike 1.0 Generic"),
+            Self::CcZeroOneDotZero => Some("Creative Commons Zero v1.0 Universal"),
+            Self::CDDLOneDotZero => Some("Common Development and Distribution License 1.0"),
+            Self::CDDLOneDotOne => Some("Common Development and Distribution License 1.1"),
+            Self::CDLOneDotZero => Some("Common Documentation License 1.0"),
+            Self::CDLAPermissiveOneDotZero => {
+                Some("Community Data License Agreement Permissive 1.0")
+            }
+            Self::CDLAPermissiveTwoDotZero => {
+                Some("Community Data License Agreement Permissive 2.0")
+            }
+            Self::CDLASharingOneDotZero => Some("Community Data License Agreement Sharing 1.0"),
+            Self::CECILLOneDotZero => Some("CeCILL Free Software License Agreement v1.0"),
+            Self::CECILLOneDotOne => Some("CeCILL Free Software License Agreement v1.1"),
+            Self::CECILLTwoDotZero => Some("CeCILL Free Software License Agreement v2.0"),
+            Self::CECILLTwoDotOne => Some("CeCILL Free Software License Agreement v2.1"),
+            Self::CECILLB => Some("CeCILL-B Free Software License Agreement"),
+            Self::CECILLC => Some("CeCILL-C Free Software License Agreement"),
+            Self::CERNOHLOneDotOne => Some("CERN Open Hardware Licence v1.1"),
+            Self::CERNOHLOneDotTwo => Some("CERN Open Hardware Licence v1.2"),
+            Self::CERNOHLPTwoDotZero => Some("CERN Open Hardware Licence Version 2 - Permissive"),
+            Self::CERNOHLSTwoDotZero => {
+                Some("CERN Open Hardware Licence Version 2 - Strongly Reciprocal")
+            }
+            Self::CERNOHLWTwoDotZero => {
+                Some("CERN Open Hardware Licence Version 2 - Weakly Reciprocal")
+            }
+            Self::CFITSIO => Some("CFITSIO License"),
+            Self::CMUMach => Some("CMU Mach License"),
+            Self::CMUMachNodoc => Some("CMU    Mach - no notices-in-documentation variant"),
+            Self::CNRIJython => Some("CNRI Jython License"),
+            Self::CNRIPython => Some("CNRI Python License"),
+            Self::CNRIPythonGPLCompatible => {
+                Some("CNRI Python Open Source GPL Compatible License Agreement")
+            }
+            Self::COILOneDotZero => Some("Copyfree Open Innovation License"),
+            Self::CPALOneDotZero => Some("Common Public Attribution License 1.0"),
+            Self::CPLOneDotZero => Some("Common Public License 1.0"),
+            Self::CPOLOneDotZeroTwo => Some("Code Project Open License 1.02"),
+            Self::CUAOPLOneDotZero => Some("CUA Office Public License v1.0"),
+            Self::Caldera => Some("Caldera License"),
+            Self::CalderaNoPreamble => Some("Caldera License (without preamble)"),
+            Self::Catharon => Some("Catharon License"),
+            Self::ClArtistic => Some("Clarified Artistic License"),
+            Self::Clips => Some("Clips License"),
+            Self::CommunitySpecOneDotZero => Some("Community Specification License 1.0"),
+            Self::CondorOneDotOne => Some("Condor Public License v1.1"),
+            Self::CornellLosslessJPEG => Some("Cornell Lossless JPEG License"),
+            Self::Cronyx => Some("Cronyx License"),
+            Self::Crossword => Some("Crossword License"),
+            Self::CryptoSwift => Some("CryptoSwift License"),
+            Self::CrystalStacker => Some("CrystalStacker License"),
+            Self::Cube => Some("Cube License"),
+            Self::DFSLOneDotZero => Some("Deutsche Freie Software Lizenz"),
+            Self::DECThreeClause => Some("DEC 3-Clause License"),
+            Self::DLDEBYTwoDotZero => Some("Data licence Germany – attribution – version 2.0"),
+            Self::DLDEZEROTwoDotZero => Some("Data licence Germany – zero – version 2.0"),
+            Self::DOC => Some("DOC License"),
+            Self::DRLOneDotZero => Some("Detection Rule License 1.0"),
+            Self::DRLOneDotOne => Some("Detection Rule License 1.1"),
+            Self::DSDP => Some("DSDP License"),
+            Self::DocBookDTD => Some("DocBook DTD License"),
+            Self::DocBookSchema => Some("DocBook Schema License"),
+            Self::DocBookStylesheet => Some("DocBook Stylesheet License"),
+            Self::DocBookXML => Some("DocBook XML License"),
+            Self::Dotseqn => Some("Dotseqn License"),
+            Self::ECLOneDotZero => Some("Educational Community License v1.0"),
+            Self::ECLTwoDotZero => Some("Educational Community License v2.0"),
+            Self::EFLOneDotZero => Some("Eiffel Forum License v1.0"),
+            Self::EFLTwoDotZero => Some("Eiffel Forum License v2.0"),
+            Self::EPICS => Some("EPICS Open License"),
+            Self::EPLOneDotZero => Some("Eclipse Public License 1.0"),
+            Self::EPLTwoDotZero => Some("Eclipse Public License 2.0"),
+            Self::EuDatagrid => Some("EU DataGrid Software License"),
+            Self::EUPLOneDotZero => Some("European Union Public License 1.0"),
+            Self::EUPLOneDotOne => Some("European Union Public License 1.1"),
+            Self::EUPLOneDotTwo => Some("European Union Public License 1.2"),
+            Self::ElasticTwoDotZero => Some("Elastic License 2.0"),
+            Self::Entessa => Some("Entessa Public License v1.0"),
+            Self::ErlPlOneDotOne => Some("Erlang Public License v1.1"),
+            Self::Eurosym => Some("Eurosym License"),
+            Self::FBM => Some("Fuzzy Bitmap License"),
+            Self::FDKAAC => Some("Fraunhofer FDK AAC Codec Library"),
+            Self::FSFAP => Some("FSF All Permissive License"),
+            Self::FSFAPNoWarrantyDisclaimer => {
+                Some("FSF All Permissive License (without Warranty)")
+            }
+            Self::FSFUL => Some("FSF Unlimited License"),
+            Self::FSFULLR => Some("FSF Unlimited License (with License Retention)"),
+            Self::FSFULLRWD => {
+                Some("FSF Unlimited License (With License Retention and Warranty Disclaimer)")
+            }
+            Self::FSLOneDotOneALvTwo => {
+                Some("Functional Source License, Version 1.1, ALv2 Future License")
+            }
+            Self::FSLOneDotOneMIT => {
+                Some("Functional Source License, Version 1.1, MIT Future License")
+            }
+            Self::FTL => Some("Freetype Project License"),
+            Self::Fair => Some("Fair License"),
+            Self::FergusonTwofish => Some("Ferguson Twofish License"),
+            Self::FrameworxOneDotZero => Some("Frameworx Open License 1.0"),
+            Self::FreeBsdDOC => Some("FreeBSD Documentation License"),
+            Self::FreeImage => Some("FreeImage Public License v1.0"),
+            Self::Furuseth => Some("Furuseth License"),
+            Self::GCRDocs => Some("Gnome GCR Documentation License"),
+            Self::GD => Some("GD License"),
+            Self::GFDLOneDotOneInvariantsOnly => {
+                Some("GNU Free Documentation License v1.1 only - invariants")
+            }
+            Self::GFDLOneDotOneInvariantsOrLater => {
+                Some("GNU Free Documentation License v1.1 or later - invariants")
+            }
+            Self::GFDLOneDotOneNoInvariantsOnly => {
+                Some("GNU Free Documentation License v1.1 only - no invariants")
+            }
+            Self::GFDLOneDotOneNoInvariantsOrLater => {
+                Some("GNU Free Documentation License v1.1 or later - no invariants")
+            }
+            Self::GFDLOneDotOneOnly => Some("GNU Free Documentation License v1.1 only"),
+            Self::GFDLOneDotOneOrLater => Some("GNU Free Documentation License v1.1 or later"),
+            Self::GFDLOneDotTwoInvariantsOnly => {
+                Some("GNU Free Documentation License v1.2 only - invariants")
+            }
+            Self::GFDLOneDotTwoInvariantsOrLater => {
+                Some("GNU Free Documentation License v1.2 or later - invariants")
+            }
+            Self::GFDLOneDotTwoNoInvariantsOnly => {
+                Some("GNU Free Documentation License v1.2 only - no invariants")
+            }
+            Self::GFDLOneDotTwoNoInvariantsOrLater => {
+                Some("GNU Free Documentation License v1.2 or later - no invariants")
+            }
+            Self::GFDLOneDotTwoOnly => Some("GNU Free Documentation License v1.2 only"),
+            Self::GFDLOneDotTwoOrLater => Some("GNU Free Documentation License v1.2 or later"),
+            Self::GFDLOneDotThreeInvariantsOnly => {
+                Some("GNU Free Documentation License v1.3 only - invariants")
+            }
+            Self::GFDLOneDotThreeInvariantsOrLater => {
+                Some("GNU Free Documentation License v1.3 or later - invariants")
+            }
+            Self::GFDLOneDotThreeNoInvariantsOnly => {
+                Some("GNU Free Documentation License v1.3 only - no invariants")
+            }
+            Self::GFDLOneDotThreeNoInvariantsOrLater => {
+                Some("GNU Free Documentation License v1.3 or later - no invariants")
+            }
+            Self::GFDLOneDotThreeOnly => Some("GNU Free Documentation License v1.3 only"),
+            Self::GFDLOneDotThreeOrLater => Some("GNU Free Documentation License v1.3 or later"),
+            Self::GlTwoPs => Some("GL2PS License"),
+            Self::GLWTPL => Some("Good Luck With That Public License"),
+            Self::GPLOneDotZeroOnly => Some("GNU General Public License v1.0 only"),
+            Self::GPLOneDotZeroOrLater => Some("GNU General Public License v1.0 or later"),
+            Self::GPLTwoDotZeroOnly => Some("GNU General Public License v2.0 only"),
+            Self::GPLTwoDotZeroOrLater => Some("GNU General Public License v2.0 or later"),
+            Self::GPLThreeDotZeroOnly => Some("GNU General Public License v3.0 only"),
+            Self::GPLThreeDotZeroOrLater => Some("GNU General Public License v3.0 or later"),
+            Self::GameProgrammingGems => Some("Game Programming Gems License"),
+            Self::Giftware => Some("Giftware License"),
+            Self::Glide => Some("3dfx Glide License"),
+            Self::Glulxe => Some("Glulxe License"),
+            Self::GraphicsGems => Some("Graphics Gems License"),
+            Self::Gutmann => Some("Gutmann License"),
+            Self::HIDAPI => Some("HIDAPI License"),
+            Self::HPOneNineEightSix => Some("Hewlett-Packard 1986 License"),
+            Self::HPOneNineEightNine => Some("Hewlett-Packard 1989 License"),
+            Self::HPND => Some("Historical Permission Notice and Disclaimer"),
+            Self::HPNDDEC => Some("Historical Permission Notice and Disclaimer - DEC variant"),
+            Self::HPNDFennebergLivingston => {
+                Some("Historical Permission Notice and Disclaimer - Fenneberg-Livingston variant")
+            }
+            Self::HPNDINRIAIMAG => {
+                Some("Historical Permission Notice and Disclaimer    - INRIA-IMAG variant")
+            }
+            Self::HPNDIntel => Some("Historical Permission Notice and Disclaimer - Intel variant"),
+            Self::HPNDKevlinHenney => {
+                Some("Historical Permission Notice and Disclaimer - Kevlin Henney variant")
+            }
+            Self::HPNDMITDisclaimer => {
+                Some("Historical Permission Notice and Disclaimer with MIT disclaimer")
+            }
+            Self::HPNDMarkusKuhn => {
+                Some("Historical Permission Notice and Disclaimer - Markus Kuhn variant")
+            }
+            Self::HPNDNetrek => {
+                Some("Historical Permission Notice and Disclaimer - Netrek variant")
+            }
+            Self::HPNDPbmplus => {
+                Some("Historical Permission Notice and Disclaimer - Pbmplus variant")
+            }
+            Self::HPNDUC => Some(
+                "Historical Permission Notice and Disclaimer - University of California variant",
+            ),
+            Self::HPNDUCExportUS => Some(
+                "Historical Permission Notice and Disclaimer - University of California, US export warning",
+            ),
+            Self::HPNDDoc => {
+                Some("Historical Permission Notice and Disclaimer - documentation variant")
+            }
+            Self::HPNDDocSell => {
+                Some("Historical Permission Notice and Disclaimer - documentation sell variant")
+            }
+            Self::HPNDExportUS => Some("HPND with US Government export control warning"),
+            Self::HPNDExportUSAcknowledgement => {
+                Some("HPND with US Government export control warning and acknowledgment")
+            }
+            Self::HPNDExportUSModify => {
+                Some("HPND with US Government export control warning and modification rqmt")
+            }
+            Self::HPNDExportTwoUS => {
+                Some("HPND with US Government export control and 2 disclaimers")
+            }
+            Self::HPNDMerchantabilityVariant => {
+                Some("Historical Permission Notice and Disclaimer - merchantability variant")
+            }
+            Self::HPNDSellMITDisclaimerXserver => Some(
+                "Historical Permission Notice and Disclaimer - sell xserver variant with MIT disclaimer",
+            ),
+            Self::HPNDSellRegexpr => {
+                Some("Historical Permission Notice and Disclaimer - sell regexpr variant")
+            }
+            Self::HPNDSellVariant => {
+                Some("Historical Permission Notice and Disclaimer - sell variant")
+            }
+            Self::HPNDSellVariantMITDisclaimer => Some("HPND sell variant with MIT disclaimer"),
+            Self::HPNDSellVariantMITDisclaimerRev => {
+                Some("HPND sell variant with MIT disclaimer - reverse")
+            }
+            Self::HTMLTIDY => Some("HTML Tidy License"),
+            Self::HaskellReport => Some("Haskell Language Report License"),
+            Self::HippocraticTwoDotOne => Some("Hippocratic License 2.1"),
+            Self::IBMPibs => Some("IBM PowerPC Initialization and Boot Software"),
+            Self::ICU => Some("ICU License"),
+            Self::IECCodeComponentsEULA => {
+                Some("IEC    Code Components End-user licence agreement")
+            }
+            Self::IJG => Some("Independent JPEG Group License"),
+            Self::IJGShort => Some("Independent JPEG Group License - short"),
+            Self::IPA => Some("IPA Font License"),
+            Self::IPLOneDotZero => Some("IBM Public License v1.0"),
+            Self::ISC => Some("ISC License"),
+            Self::ISCVeillard => Some("ISC Veillard variant"),
+            Self::ImageMagick => Some("ImageMagick License"),
+            Self::ImlibTwo => Some("Imlib2 License"),
+            Self::InfoZIP => Some("Info-ZIP License"),
+            Self::InnerNetTwoDotZero => Some("Inner Net License v2.0"),
+            Self::InnoSetup => Some("Inno Setup License"),
+            Self::Intel => Some("Intel Open Source License"),
+            Self::IntelACPI => Some("Intel ACPI Software License Agreement"),
+            Self::InterbaseOneDotZero => Some("Interbase Public License v1.0"),
+            Self::JPLImage => Some("JPL Image Use Policy"),
+            Self::JPNIC => Some("Japan Network Information Center License"),
+            Self::JSON => Some("JSON License"),
+            Self::Jam => Some("Jam License"),
+            Self::JasPerTwoDotZero => Some("JasPer License"),
+            Self::Kastrup => Some("Kastrup License"),
+            Self::Kazlib => Some("Kazlib License"),
+            Self::KnuthCTAN => Some("Knuth CTAN License"),
+            Self::LALOneDotTwo => Some("Licence Art Libre 1.2"),
+            Self::LALOneDotThree => Some("Licence Art Libre 1.3"),
+            Self::LGPLTwoDotZeroOnly => Some("GNU Library General Public License v2 only"),
+            Self::LGPLTwoDotZeroOrLater => Some("GNU Library General Public License v2 or later"),
+            Self::LGPLTwoDotOneOnly => Some("GNU Lesser General Public License v2.1 only"),
+            Self::LGPLTwoDotOneOrLater => Some("GNU Lesser General Public License v2.1 or later"),
+            Self::LGPLThreeDotZeroOnly => Some("GNU Lesser General Public License v3.0 only"),
+            Self::LGPLThreeDotZeroOrLater => {
+                Some("GNU Lesser General Public License v3.0 or later")
+            }
+            Self::LGPLLR => Some("Lesser General Public License For Linguistic Resources"),
+            Self::LOOP => Some("Common Lisp LOOP License"),
+            Self::LPDDocument => Some("LPD Documentation License"),
+            Self::LPLOneDotZero => Some("Lucent Public License Version 1.0"),
+            Self::LPLOneDotZeroTwo => Some("Lucent Public License v1.02"),
+            Self::LPPLOneDotZero => Some("LaTeX Project Public License v1.0"),
+            Self::LPPLOneDotOne => Some("LaTeX Project Public License v1.1"),
+            Self::LPPLOneDotTwo => Some("LaTeX Project Public License v1.2"),
+            Self::LPPLOneDotThreea => Some("LaTeX Project Public License v1.3a"),
+            Self::LPPLOneDotThreec => Some("LaTeX Project Public License v1.3c"),
+            Self::LZMASDKNineDotOneOneToNineDotTwoZero => {
+                Some("LZMA SDK License (versions 9.11 to 9.20)")
+            }
+            Self::LZMASDKNineDotTwoTwo => Some("LZMA SDK License (versions 9.22 and beyond)"),
+            Self::LatexTwoe => Some("Latex2e License"),
+            Self::LatexTwoeTranslatedNotice => Some("Latex2e with translated notice permission"),
+            Self::Leptonica => Some("Leptonica License"),
+            Self::LiLiQPOneDotOne => Some("Licence Libre du Québec – Permissive version 1.1"),
+            Self::LiLiQROneDotOne => Some("Licence Libre du Québec – Réciprocité version 1.1"),
+            Self::LiLiQRplusOneDotOne => {
+                Some("Licence Libre du Québec – Réciprocité forte version 1.1")
+            }
+            Self::Libpng => Some("libpng License"),
+            Self::LinuxOpenIb => Some("Linux Kernel Variant of OpenIB.org license"),
+            Self::LinuxManPagesOnePara => Some("Linux man-pages - 1 paragraph"),
+            Self::LinuxManPagesCopyleft => Some("Linux man-pages Copyleft"),
+            Self::LinuxManPagesCopyleftTwoPara => Some("Linux man-pages Copyleft - 2 paragraphs"),
+            Self::LinuxManPagesCopyleftVar => Some("Linux man-pages Copyleft Variant"),
+            Self::LucidaBitmapFonts => Some("Lucida Bitmap Fonts License"),
+            Self::MIPS => Some("MIPS License"),
+            Self::MIT => Some("MIT License"),
+            Self::MITZero => Some("MIT No Attribution"),
+            Self::MITCMU => Some("CMU License"),
+            Self::MITClick => Some("MIT Click License"),
+            Self::MITFestival => Some("MIT Festival Variant"),
+            Self::MITKhronosOld => Some("MIT Khronos - old variant"),
+            Self::MITModernVariant => Some("MIT License Modern Variant"),
+            Self::MITWu => Some("MIT Tom Wu Variant"),
+            Self::MITAdvertising => Some("Enlightenment License (e16)"),
+            Self::MITEnna => Some("enna License"),
+            Self::MITFeh => Some("feh License"),
+            Self::MITOpenGroup => Some("MIT Open Group variant"),
+            Self::MITTestregex => Some("MIT testregex Variant"),
+            Self::MITNFA => Some("MIT +no-false-attribs license"),
+            Self::MmiXware => Some("MMIXware License"),
+            Self::MPEGSSG => Some("MPEG Software Simulation"),
+            Self::MPLOneDotZero => Some("Mozilla Public License 1.0"),
+            Self::MPLOneDotOne => Some("Mozilla Public License 1.1"),
+            Self::MPLTwoDotZero => Some("Mozilla Public License 2.0"),
+            Self::MPLTwoDotZeroNoCopyleftException => {
+                Some("Mozilla Public License 2.0 (no copyleft exception)")
+            }
+            Self::MSLPL => Some("Microsoft Limited Public License"),
+            Self::MSPL => Some("Microsoft Public License"),
+            Self::MSRL => Some("Microsoft Reciprocal License"),
+            Self::MTLL => Some("Matrix Template Library License"),
+            Self::MackerrasThreeClause => Some("Mackerras 3-Clause License"),
+            Self::MackerrasThreeClauseAcknowledgment => {
+                Some("Mackerras 3-Clause - acknowledgment variant")
+            }
+            Self::MakeIndex => Some("MakeIndex License"),
+            Self::MartinBirgmeier => Some("Martin Birgmeier License"),
+            Self::McPheeSlideshow => Some("McPhee Slideshow License"),
+            Self::Minpack => Some("Minpack License"),
+            Self::MirOs => Some("The MirOS Licence"),
+            Self::Motosoto => Some("Motosoto License"),
+            Self::MulanPslOneDotZero => Some("Mulan Permissive Software License, Version 1"),
+            Self::MulanPslTwoDotZero => Some("Mulan Permissive Software License, Version 2"),
+            Self::Multics => Some("Multics License"),
+            Self::Mup => Some("Mup License"),
+            Self::NAISTTwoZeroZeroThree => {
+                Some("Nara Institute of Science and Technology License (2003)")
+            }
+            Self::NASAOneDotThree => Some("NASA Open Source Agreement 1.3"),
+            Self::NBPLOneDotZero => Some("Net Boolean Public License v1"),
+            Self::NCBIPD => Some("NCBI Public Domain Notice"),
+            Self::NCGLUKTwoDotZero => Some("Non-Commercial Government Licence"),
+            Self::NCL => Some("NCL Source Code License"),
+            Self::NCSA => Some("University of Illinois/NCSA Open Source License"),
+            Self::NGPL => Some("Nethack General Public License"),
+            Self::NICTAOneDotZero => Some("NICTA Public Software License, Version 1.0"),
+            Self::NISTPD => Some("NIST Public Domain Notice"),
+            Self::NISTPDFallback => Some("NIST Public Domain Notice with license fallback"),
+            Self::NISTSoftware => Some("NIST Software License"),
+            Self::NLODOneDotZero => Some("Norwegian Licence for Open Government Data (NLOD) 1.0"),
+            Self::NLODTwoDotZero => Some("Norwegian Licence for Open Government Data (NLOD) 2.0"),
+            Self::NLPL => Some("No Limit Public License"),
+            Self::NOSL => Some("Netizen Open Source License"),
+            Self::NPLOneDotZero => Some("Netscape Public License v1.0"),
+            Self::NPLOneDotOne => Some("Netscape Public License v1.1"),
+            Self::NPOSLThreeDotZero => Some("Non-Profit Open Software License 3.0"),
+            Self::NRL => Some("NRL License"),
+            Self::NTIAPD => Some("NTIA Public Domain Notice"),
+            Self::NTP => Some("NTP License"),
+            Self::NTPZero => Some("NTP No Attribution"),
+            Self::Naumen => Some("Naumen Public License"),
+            Self::NetCdf => Some("NetCDF license"),
+            Self::Newsletr => Some("Newsletr License"),
+            Self::Nokia => Some("Nokia Open Source License"),
+            Self::Noweb => Some("Noweb License"),
+            Self::OUDAOneDotZero => Some("Open Use of Data Agreement v1.0"),
+            Self::OAR => Some("OAR License"),
+            Self::OCCTPL => Some("Open CASCADE Technology Public License"),
+            Self::OCLCTwoDotZero => Some("OCLC Research Public License 2.0"),
+            Self::ODCByOneDotZero => Some("Open Data Commons Attribution License v1.0"),
+            Self::ODbLOneDotZero => Some("Open Data Commons Open Database License v1.0"),
+            Self::OFFIS => Some("OFFIS License"),
+            Self::OFLOneDotZero => Some("SIL Open Font License 1.0"),
+            Self::OFLOneDotZeroRFN => Some("SIL Open Font License 1.0 with Reserved Font Name"),
+            Self::OFLOneDotZeroNoRFN => {
+                Some("SIL Open Font License 1.0 with no Reserved Font Name")
+            }
+            Self::OFLOneDotOne => Some("SIL Open Font License 1.1"),
+            Self::OFLOneDotOneRFN => Some("SIL Open Font License 1.1 with Reserved Font Name"),
+            Self::OFLOneDotOneNoRFN => Some("SIL Open Font License 1.1 with no Reserved Font Name"),
+            Self::OGCOneDotZero => Some("OGC Software License, Version 1.0"),
+            Self::OGDLTaiwanOneDotZero => Some("Taiwan Open Government Data License, version 1.0"),
+            Self::OGLCanadaTwoDotZero => Some("Open Government Licence - Canada"),
+            Self::OGLUKOneDotZero => Some("Open Government Licence v1.0"),
+            Self::OGLUKTwoDotZero => Some("Open Government Licence v2.0"),
+            Self::OGLUKThreeDotZero => Some("Open Government Licence v3.0"),
+            Self::OGTSL => Some("Open Group Test Suite License"),
+            Self::OLDAPOneDotOne => Some("Open LDAP Public License v1.1"),
+            Self::OLDAPOneDotTwo => Some("Open LDAP Public License v1.2"),
+            Self::OLDAPOneDotThree => Some("Open LDAP Public License v1.3"),
+            Self::OLDAPOneDotFour => Some("Open LDAP Public License v1.4"),
+            Self::OLDAPTwoDotZero => {
+                Some("Open LDAP Public License v2.0 (or possibly 2.0A and 2.0B)")
+            }
+            Self::OLDAPTwoDotZeroDotOne => Some("Open LDAP Public License v2.0.1"),
+            Self::OLDAPTwoDotOne => Some("Open LDAP Public License v2.1"),
+            Self::OLDAPTwoDotTwo => Some("Open LDAP Public License v2.2"),
+            Self::OLDAPTwoDotTwoDotOne => Some("Open LDAP Public License v2.2.1"),
+            Self::OLDAPTwoDotTwoDotTwo => Some("Open LDAP Public License 2.2.2"),
+            Self::OLDAPTwoDotThree => Some("Open LDAP Public License v2.3"),
+            Self::OLDAPTwoDotFour => Some("Open LDAP Public License v2.4"),
+            Self::OLDAPTwoDotFive => Some("Open LDAP Public License v2.5"),
+            Self::OLDAPTwoDotSix => Some("Open LDAP Public License v2.6"),
+            Self::OLDAPTwoDotSeven => Some("Open LDAP Public License v2.7"),
+            Self::OLDAPTwoDotEight => Some("Open LDAP Public License v2.8"),
+            Self::OLFLOneDotThree => Some("Open Logistics Foundation License Version 1.3"),
+            Self::OML => Some("Open Market License"),
+            Self::OPLOneDotZero => Some("Open Public License v1.0"),
+            Self::OPLUKThreeDotZero => Some("United    Kingdom Open Parliament Licence v3.0"),
+            Self::OPUBLOneDotZero => Some("Open Publication License v1.0"),
+            Self::OSETPLTwoDotOne => Some("OSET Public License version 2.1"),
+            Self::OSLOneDotZero => Some("Open Software License 1.0"),
+            Self::OSLOneDotOne => Some("Open Software License 1.1"),
+            Self::OSLTwoDotZero => Some("Open Software License 2.0"),
+            Self::OSLTwoDotOne => Some("Open Software License 2.1"),
+            Self::OSLThreeDotZero => Some("Open Software License 3.0"),
+            Self::OpenPbsTwoDotThree => Some("OpenPBS v2.3 Software License"),
+            Self::OpenSsl => Some("OpenSSL License"),
+            Self::OpenSslStandalone => Some("OpenSSL License - standalone"),
+            Self::OpenVision => Some("OpenVision License"),
+            Self::PADL => Some("PADL License"),
+            Self::PDDLOneDotZero => {
+                Some("Open Data Commons Public Domain Dedication & License 1.0")
+            }
+            Self::PHPThreeDotZero => Some("PHP License v3.0"),
+            Self::PHPThreeDotZeroOne => Some("PHP License v3.01"),
+            Self::PPL => Some("Peer Production License"),
+            Self::PSFTwoDotZero => Some("Python Software Foundation License 2.0"),
+            Self::ParitySixDotZeroDotZero => Some("The Parity Public License 6.0.0"),
+            Self::ParitySevenDotZeroDotZero => Some("The Parity Public License 7.0.0"),
+            Self::Pixar => Some("Pixar License"),
+            Self::Plexus => Some("Plexus Classworlds License"),
+            Self::PolyFormNoncommercialOneDotZeroDotZero => {
+                Some("PolyForm Noncommercial License 1.0.0")
+            }
+            Self::PolyFormSmallBusinessOneDotZeroDotZero => {
+                Some("PolyForm Small Business License 1.0.0")
+            }
+            Self::PostgreSql => Some("PostgreSQL License"),
+            Self::PythonTwoDotZero => Some("Python License 2.0"),
+            Self::PythonTwoDotZeroDotOne => Some("Python License 2.0.1"),
+            Self::QPLOneDotZero => Some("Q Public License 1.0"),
+            Self::QPLOneDotZeroINRIATwoZeroZeroFour => {
+                Some("Q Public License 1.0 - INRIA 2004 variant")
+            }
+            Self::Qhull => Some("Qhull License"),
+            Self::RHeCosOneDotOne => Some("Red Hat eCos Public License v1.1"),
+            Self::RPLOneDotOne => Some("Reciprocal Public License 1.1"),
+            Self::RPLOneDotFive => Some("Reciprocal Public License 1.5"),
+            Self::RPSLOneDotZero => Some("RealNetworks Public Source License v1.0"),
+            Self::RSAMD => Some("RSA Message-Digest License"),
+            Self::RSCPL => Some("Ricoh Source Code Public License"),
+            Self::Rdisc => Some("Rdisc License"),
+            Self::Ruby => Some("Ruby License"),
+            Self::RubyPty => Some("Ruby pty extension license"),
+            Self::SAXPD => Some("Sax Public Domain Notice"),
+            Self::SAXPDTwoDotZero => Some("Sax Public Domain Notice 2.0"),
+            Self::SCEA => Some("SCEA Shared Source License"),
+            Self::SGIBOneDotZero => Some("SGI Free Software License B v1.0"),
+            Self::SGIBOneDotOne => Some("SGI Free Software License B v1.1"),
+            Self::SGIBTwoDotZero => Some("SGI Free Software License B v2.0"),
+            Self::SGIOpenGl => Some("SGI OpenGL License"),
+            Self::SgpFour => Some("SGP4 Permission Notice"),
+            Self::SHLZeroDotFive => Some("Solderpad Hardware License v0.5"),
+            Self::SHLZeroDotFiveOne => Some("Solderpad Hardware License, Version 0.51"),
+            Self::SISSL => Some("Sun Industry Standards Source License v1.1"),
+            Self::SISSLOneDotTwo => Some("Sun Industry Standards Source License v1.2"),
+            Self::SL => Some("SL License"),
+            Self::SMAILGPL => Some("SMAIL General Public License"),
+            Self::SMLNJ => Some("Standard ML of New Jersey License"),
+            Self::SMPPL => Some("Secure Messaging Protocol Public License"),
+            Self::SNIA => Some("SNIA Public License 1.1"),
+            Self::SPLOneDotZero => Some("Sun Public License v1.0"),
+            Self::SSHOpenSsh => Some("SSH OpenSSH license"),
+            Self::SSHShort => Some("SSH short notice"),
+            Self::SsLeayStandalone => Some("SSLeay License - standalone"),
+            Self::SSPLOneDotZero => Some("Server Side Public License, v 1"),
+            Self::SWL => Some("Scheme Widget Library (SWL) Software License Agreement"),
+            Self::Saxpath => Some("Saxpath License"),
+            Self::SchemeReport => Some("Scheme Language Report License"),
+            Self::Sendmail => Some("Sendmail License"),
+            Self::SendmailEightDotTwoThree => Some("Sendmail License 8.23"),
+            Self::SendmailOpenSourceOneDotOne => Some("Sendmail Open Source License v1.1"),
+            Self::SimPlTwoDotZero => Some("Simple Public License 2.0"),
+            Self::Sleepycat => Some("Sleepycat License"),
+            Self::Soundex => Some("Soundex License"),
+            Self::SpencerEightSix => Some("Spencer License 86"),
+            Self::SpencerNineFour => Some("Spencer License 94"),
+            Self::SpencerNineNine => Some("Spencer License 99"),
+            Self::SugarCrmOneDotOneDotThree => Some("SugarCRM Public License v1.1.3"),
+            Self::SunPPP => Some("Sun PPP License"),
+            Self::SunPPPTwoZeroZeroZero => Some("Sun PPP License (2000)"),
+            Self::SunPro => Some("SunPro License"),
+            Self::Symlinks => Some("Symlinks License"),
+            Self::TAPROHLOneDotZero => Some("TAPR Open Hardware License v1.0"),
+            Self::TCL => Some("TCL/TK License"),
+            Self::TCPWrappers => Some("TCP Wrappers License"),
+            Self::TGPPLOneDotZero => Some("Transitive Grace Period Public Licence 1.0"),
+            Self::TMate => Some("TMate Open Source License"),
+            Self::TORQUEOneDotOne => Some("TORQUE v2.5+ Software License v1.1"),
+            Self::TOSL => Some("Trusster Open Source License"),
+            Self::TPDL => Some("Time::ParseDate License"),
+            Self::TPLOneDotZero => Some("THOR Public License 1.0"),
+            Self::TTWL => Some("Text-Tabs+Wrap License"),
+            Self::TtypZero => Some("TTYP0 License"),
+            Self::TUBerlinOneDotZero => Some("Technische Universitaet Berlin License 1.0"),
+            Self::TUBerlinTwoDotZero => Some("Technische Universitaet Berlin License 2.0"),
+            Self::TermReadKey => Some("TermReadKey License"),
+            Self::ThirdEye => Some("ThirdEye License"),
+            Self::TrustedQsl => Some("TrustedQSL License"),
+            Self::UCAR => Some("UCAR License"),
+            Self::UCLOneDotZero => Some("Upstream Compatibility License v1.0"),
+            Self::UMichMerit => Some("Michigan/Merit Networks License"),
+            Self::UPLOneDotZero => Some("Universal Permissive License v1.0"),
+            Self::URTRLE => Some("Utah Raster Toolkit Run Length Encoded License"),
+            Self::UbuntuFontOneDotZero => Some("Ubuntu Font Licence v1.0"),
+            Self::UnicodeThreeDotZero => Some("Unicode License v3"),
+            Self::UnicodeDFSTwoZeroOneFive => {
+                Some("Unicode License Agreement - Data Files and Software (2015)")
+            }
+            Self::UnicodeDFSTwoZeroOneSix => {
+                Some("Unicode License Agreement - Data Files and Software (2016)")
+            }
+            Self::UnicodeTOU => Some("Unicode Terms of Use"),
+            Self::UnixCrypt => Some("UnixCrypt License"),
+            Self::Unlicense => Some("The Unlicense"),
+            Self::VOSTROM => Some("VOSTROM Public License for Open Source"),
+            Self::VSLOneDotZero => Some("Vovida Software License v1.0"),
+            Self::Vim => Some("Vim License"),
+            Self::WThreeC => Some("W3C Software Notice and License (2002-12-31)"),
+            Self::WThreeCOneNineNineEightZeroSevenTwoZero => {
+                Some("W3C Software Notice and License (1998-07-20)")
+            }
+            Self::WThreeCTwoZeroOneFiveZeroFiveOneThree => {
+                Some("W3C Software Notice and Document License (2015-05-13)")
+            }
+            Self::WTFPL => Some("Do What The F*ck You Want To Public License"),
+            Self::WatcomOneDotZero => Some("Sybase Open Watcom Public License 1.0"),
+            Self::WidgetWorkshop => Some("Widget Workshop License"),
+            Self::Wsuipa => Some("Wsuipa License"),
+            Self::XOneOne => Some("X11 License"),
+            Self::XOneOneDistributeModificationsVariant => {
+                Some("X11 License Distribution Modification Variant")
+            }
+            Self::XOneOneSwapped => Some("X11 swapped final paragraphs"),
+            Self::XFreeEightSixOneDotOne => Some("XFree86 License 1.1"),
+            Self::XSkat => Some("XSkat License"),
+            Self::XdebugOneDotZeroThree => Some("Xdebug License v 1.03"),
+            Self::Xerox => Some("Xerox License"),
+            Self::Xfig => Some("Xfig License"),
+            Self::Xnet => Some("X.Net License"),
+            Self::YPLOneDotZero => Some("Yahoo! Public License v1.0"),
+            Self::YPLOneDotOne => Some("Yahoo! Public License v1.1"),
+            Self::ZPLOneDotOne => Some("Zope Public License 1.1"),
+            Self::ZPLTwoDotZero => Some("Zope Public License 2.0"),
+            Self::ZPLTwoDotOne => Some("Zope Public License 2.1"),
+            Self::Zed => Some("Zed License"),
+            Self::Zeeff => Some("Zeeff License"),
+            Self::ZendTwoDotZero => Some("Zend License v2.0"),
+            Self::ZimbraOneDotThree => Some("Zimbra Public License v1.3"),
+            Self::ZimbraOneDotFour => Some("Zimbra Public License v1.4"),
+            Self::Zlib => Some("zlib License"),
+            Self::AnyOSI => Some("Any OSI License"),
+            Self::AnyOSIPerlModules => Some("Any OSI License - Perl Modules"),
+            Self::BcryptSolarDesigner => Some("bcrypt Solar Designer License"),
+            Self::Blessing => Some("SQLite Blessing"),
+            Self::BzipTwoOneDotZeroDotSix => Some("bzip2 and libbzip2 License v1.0.6"),
+            Self::CheckCvs => Some("check-cvs License"),
+            Self::Checkmk => Some("Checkmk License"),
+            Self::CopyleftNextZeroDotThreeDotZero => Some("copyleft-next 0.3.0"),
+            Self::CopyleftNextZeroDotThreeDotOne => Some("copyleft-next 0.3.1"),
+            Self::Curl => Some("curl License"),
+            Self::CveTou => Some("Common Vulnerability Enumeration ToU License"),
+            Self::DeprecatedAGPLOneDotZero => Some("Affero General Public License v1.0"),
+            Self::DeprecatedAGPLThreeDotZero => Some("GNU Affero General Public License v3.0"),
+            Self::DeprecatedBSDTwoClauseFreeBsd => Some("BSD 2-Clause FreeBSD License"),
+            Self::DeprecatedBSDTwoClauseNetBsd => Some("BSD 2-Clause NetBSD License"),
+            Self::DeprecatedGFDLOneDotOne => Some("GNU Free Documentation License v1.1"),
+            Self::DeprecatedGFDLOneDotTwo => Some("GNU Free Documentation License v1.2"),
+            Self::DeprecatedGFDLOneDotThree => Some("GNU Free Documentation License v1.3"),
+            Self::DeprecatedGPLOneDotZero => Some("GNU General Public License v1.0 only"),
+            Self::DeprecatedGPLOneDotZeroPlus => Some("GNU General Public License v1.0 or later"),
+            Self::DeprecatedGPLTwoDotZero => Some("GNU General Public License v2.0 only"),
+            Self::DeprecatedGPLTwoDotZeroPlus => Some("GNU General Public License v2.0 or later"),
+            Self::DeprecatedGPLTwoDotZeroWithGCCException => {
+                Some("GNU General Public License v2.0 w/GCC Runtime Library exception")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithAutoconfException => {
+                Some("GNU General Public License v2.0 w/Autoconf exception")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithBisonException => {
+                Some("GNU General Public License v2.0 w/Bison exception")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithClasspathException => {
+                Some("GNU General Public License v2.0 w/Classpath exception")
+            }
+            Self::DeprecatedGPLTwoDotZeroWithFontException => {
+                Some("GNU General Public License v2.0 w/Font exception")
+            }
+            Self::DeprecatedGPLThreeDotZero => Some("GNU General Public License v3.0 only"),
+            Self::DeprecatedGPLThreeDotZeroPlus => Some("GNU General Public License v3.0 or later"),
+            Self::DeprecatedGPLThreeDotZeroWithGCCException => {
+                Some("GNU General Public License v3.0 w/GCC Runtime Library exception")
+            }
+            Self::DeprecatedGPLThreeDotZeroWithAutoconfException => {
+                Some("GNU General Public License v3.0 w/Autoconf exception")
+            }
+            Self::DeprecatedLGPLTwoDotZero => Some("GNU Library General Public License v2 only"),
+            Self::DeprecatedLGPLTwoDotZeroPlus => {
+                Some("GNU Library General Public License v2 or later")
+            }
+            Self::DeprecatedLGPLTwoDotOne => Some("GNU Lesser General Public License v2.1 only"),
+            Self::DeprecatedLGPLTwoDotOnePlus => {
+                Some("GNU Lesser General Public License v2.1 or later")
+            }
+            Self::DeprecatedLGPLThreeDotZero => Some("GNU Lesser General Public License v3.0 only"),
+            Self::DeprecatedLGPLThreeDotZeroPlus => {
+                Some("GNU Lesser General Public License v3.0 or later")
+            }
+            Self::DeprecatedNetSNMP => Some("Net-SNMP License"),
+            Self::DeprecatedNunit => Some("Nunit License"),
+            Self::DeprecatedStandardMlNJ => Some("Standard ML of New Jersey License"),
+            Self::DeprecatedBzipTwoOneDotZeroDotFive => Some("bzip2 and libbzip2 License v1.0.5"),
+            Self::DeprecatedECosTwoDotZero => Some("eCos license version 2.0"),
+            Self::DeprecatedWxWindows => Some("wxWindows Library License"),
+            Self::Diffmark => Some("diffmark license"),
+            Self::Dtoa => Some("David M. Gay dtoa License"),
+            Self::Dvipdfm => Some("dvipdfm License"),
+            Self::EGenix => Some("eGenix.com Public License 1.1.0"),
+            Self::EtalabTwoDotZero => Some("Etalab Open License 2.0"),
+            Self::Fwlw => Some("fwlw License"),
+            Self::GSoapOneDotThreeb => Some("gSOAP Public License v1.3b"),
+            Self::GenericXts => Some("Generic XTS License"),
+            Self::Gnuplot => Some("gnuplot License"),
+            Self::Gtkbook => Some("gtkbook License"),
+            Self::Hdparm => Some("hdparm License"),
+            Self::IMatix => Some("iMatix Standard Function Library Agreement"),
+            Self::Jove => Some("Jove License"),
+            Self::LibpngTwoDotZero => Some("PNG Reference Library version 2"),
+            Self::LibselinuxOneDotZero => Some("libselinux public domain notice"),
+            Self::Libtiff => Some("libtiff License"),
+            Self::LibutilDavidNugent => Some("libutil David Nugent License"),
+            Self::Lsof => Some("lsof License"),
+            Self::Magaz => Some("magaz License"),
+            Self::Mailprio => Some("mailprio License"),
+            Self::ManTwohtml => Some("man2html License"),
+            Self::Metamail => Some("metamail License"),
+            Self::MpiPermissive => Some("mpi Permissive License"),
+            Self::MpichTwo => Some("mpich2 License"),
+            Self::Mplus => Some("mplus Font License"),
+            Self::Pkgconf => Some("pkgconf License"),
+            Self::Pnmstitch => Some("pnmstitch License"),
+            Self::Psfrag => Some("psfrag License"),
+            Self::Psutils => Some("psutils License"),
+            Self::PythonLdap => Some("Python ldap License"),
+            Self::Radvd => Some("radvd License"),
+            Self::Snprintf => Some("snprintf License"),
+            Self::SoftSurfer => Some("softSurfer License"),
+            Self::SshKeyscan => Some("ssh-keyscan License"),
+            Self::Swrule => Some("swrule License"),
+            Self::Threeparttable => Some("threeparttable License"),
+            Self::Ulem => Some("ulem License"),
+            Self::WThreem => Some("w3m License"),
+            Self::Wwl => Some("WWL License"),
+            Self::Xinetd => Some("xinetd License"),
+            Self::XkeyboardConfigZinoviev => Some("xkeyboard-config Zinoviev License"),
+            Self::Xlock => Some("xlock License"),
+            Self::Xpp => Some("XPP License"),
+            Self::Xzoom => Some("xzoom License"),
+            Self::ZlibAcknowledgement => Some("zlib/libpng License with Acknowledgement"),
+        }
+    }
+    #[doc = r" Returns true if the license is approved by the Open Source Initiative."]
+    pub fn is_osi_approved(&self) -> bool {
+        matches!(
+            self,
+            Self::ZeroBsd
+                | Self::AAL
+                | Self::AFLOneDotOne
+                | Self::AFLOneDotTwo
+                | Self::AFLTwoDotZero
+                | Self::AFLTwoDotOne
+                | Self::AFLThreeDotZero
+                | Self::AGPLThreeDotZeroOnly
+                | Self::AGPLThreeDotZeroOrLater
+                | Self::APLOneDotZero
+                | Self::APSLOneDotZero
+                | Self::APSLOneDotOne
+                | Self::APSLOneDotTwo
+                | Self::APSLTwoDotZero
+                | Self::ApacheOneDotOne
+                | Self::ApacheTwoDotZero
+                | Self::ArtisticOneDotZero
+                | Self::ArtisticOneDotZeroPerl
+                | Self::ArtisticOneDotZeroClEight
+                | Self::ArtisticTwoDotZero
+                | Self::BSDOneClause
+                | Self::BSDTwoClause
+                | Self::BSDTwoClausePatent
+                | Self::BSDThreeClause
+                | Self::BSDThreeClauseLBNL
+                | Self::BSLOneDotZero
+                | Self::BlueOakOneDotZeroDotZero
+                | Self::CALOneDotZero
+                | Self::CALOneDotZeroCombinedWorkException
+                | Self::CATOSLOneDotOne
+                | Self::CDDLOneDotZero
+                | Self::CECILLTwoDotOne
+                | Self::CERNOHLPTwoDotZero
+                | Self::CERNOHLSTwoDotZero
+                | Self::CERNOHLWTwoDotZero
+                | Self::CNRIPython
+                | Self::CPALOneDotZero
+                | Self::CPLOneDotZero
+                | Self::CUAOPLOneDotZero
+                | Self::ECLOneDotZero
+                | Self::ECLTwoDotZero
+                | Self::EFLOneDotZero
+                | Self::EFLTwoDotZero
+                | Self::EPLOneDotZero
+                | Self::EPLTwoDotZero
+                | Self::EuDatagrid
+                | Self::EUPLOneDotOne
+                | Self::EUPLOneDotTwo
+                | Self::Entessa
+                | Self::Fair
+                | Self::FrameworxOneDotZero
+                | Self::GPLTwoDotZeroOnly
+                | Self::GPLTwoDotZeroOrLater
+                | Self::GPLThreeDotZeroOnly
+                | Self::GPLThreeDotZeroOrLater
+                | Self::HPND
+                | Self::ICU
+                | Self::IPA
+                | Self::IPLOneDotZero
+                | Self::ISC
+                | Self::Intel
+                | Self::Jam
+                | Self::LGPLTwoDotZeroOnly
+                | Self::LGPLTwoDotZeroOrLater
+                | Self::LGPLTwoDotOneOnly
+                | Self::LGPLTwoDotOneOrLater
+                | Self::LGPLThreeDotZeroOnly
+                | Self::LGPLThreeDotZeroOrLater
+                | Self::LPLOneDotZero
+                | Self::LPLOneDotZeroTwo
+                | Self::LPPLOneDotThreec
+                | Self::LiLiQPOneDotOne
+                | Self::LiLiQROneDotOne
+                | Self::LiLiQRplusOneDotOne
+                | Self::MIT
+                | Self::MITZero
+                | Self::MITModernVariant
+                | Self::MPLOneDotZero
+                | Self::MPLOneDotOne
+                | Self::MPLTwoDotZero
+                | Self::MPLTwoDotZeroNoCopyleftException
+                | Self::MSPL
+                | Self::MSRL
+                | Self::MirOs
+                | Self::Motosoto
+                | Self::MulanPslTwoDotZero
+                | Self::Multics
+                | Self::NASAOneDotThree
+                | Self::NCSA
+                | Self::NGPL
+                | Self::NPOSLThreeDotZero
+                | Self::NTP
+                | Self::Naumen
+                | Self::Nokia
+                | Self::OCLCTwoDotZero
+                | Self::OFLOneDotOne
+                | Self::OFLOneDotOneRFN
+                | Self::OFLOneDotOneNoRFN
+                | Self::OGTSL
+                | Self::OLDAPTwoDotEight
+                | Self::OLFLOneDotThree
+                | Self::OSETPLTwoDotOne
+                | Self::OSLOneDotZero
+                | Self::OSLTwoDotZero
+                | Self::OSLTwoDotOne
+                | Self::OSLThreeDotZero
+                | Self::PHPThreeDotZero
+                | Self::PHPThreeDotZeroOne
+                | Self::PostgreSql
+                | Self::PythonTwoDotZero
+                | Self::QPLOneDotZero
+                | Self::RPLOneDotOne
+                | Self::RPLOneDotFive
+                | Self::RPSLOneDotZero
+                | Self::RSCPL
+                | Self::SISSL
+                | Self::SPLOneDotZero
+                | Self::SimPlTwoDotZero
+                | Self::Sleepycat
+                | Self::UCLOneDotZero
+                | Self::UPLOneDotZero
+                | Self::UnicodeThreeDotZero
+                | Self::UnicodeDFSTwoZeroOneSix
+                | Self::Unlicense
+                | Self::VSLOneDotZero
+                | Self::WThreeC
+                | Self::WThreeCTwoZeroOneFiveZeroFiveOneThree
+                | Self::WatcomOneDotZero
+                | Self::Xnet
+                | Self::ZPLTwoDotZero
+                | Self::ZPLTwoDotOne
+                | Self::Zlib
+                | Self::DeprecatedAGPLThreeDotZero
+                | Self::DeprecatedGPLTwoDotZero
+                | Self::DeprecatedGPLTwoDotZeroPlus
+                | Self::DeprecatedGPLThreeDotZero
+                | Self::DeprecatedGPLThreeDotZeroPlus
+                | Self::DeprecatedGPLThreeDotZeroWithGCCException
+                | Self::DeprecatedLGPLTwoDotZero
+                | Self::DeprecatedLGPLTwoDotZeroPlus
+                | Self::DeprecatedLGPLTwoDotOne
+                | Self::DeprecatedLGPLTwoDotOnePlus
+                | Self::DeprecatedLGPLThreeDotZero
+                | Self::DeprecatedLGPLThreeDotZeroPlus
+                | Self::DeprecatedWxWindows
+        )
+    }
+    #[doc = r" Returns true if the Free Software Foundation considers the license libre."]
+    pub fn is_fsf_libre(&self) -> bool {
+        matches!(
+            self,
+            Self::AFLOneDotOne
+                | Self::AFLOneDotTwo
+                | Self::AFLTwoDotZero
+                | Self::AFLTwoDotOne
+                | Self::AFLThreeDotZero
+                | Self::AGPLThreeDotZeroOnly
+                | Self::AGPLThreeDotZeroOrLater
+                | Self::APSLTwoDotZero
+                | Self::ApacheOneDotZero
+                | Self::ApacheOneDotOne
+                | Self::ApacheTwoDotZero
+                | Self::ArtisticTwoDotZero
+                | Self::BSDTwoClause
+                | Self::BSDThreeClause
+                | Self::BSDThreeClauseClear
+                | Self::BSDFourClause
+                | Self::BSLOneDotZero
+                | Self::BitTorrentOneDotOne
+                | Self::CCBYFourDotZero
+                | Self::CCBYSAFourDotZero
+                | Self::CcZeroOneDotZero
+                | Self::CDDLOneDotZero
+                | Self::CECILLTwoDotZero
+                | Self::CECILLB
+                | Self::CECILLC
+                | Self::CPALOneDotZero
+                | Self::CPLOneDotZero
+                | Self::ClArtistic
+                | Self::CondorOneDotOne
+                | Self::ECLTwoDotZero
+                | Self::EFLTwoDotZero
+                | Self::EPLOneDotZero
+                | Self::EPLTwoDotZero
+                | Self::EuDatagrid
+                | Self::EUPLOneDotOne
+                | Self::EUPLOneDotTwo
+                | Self::FSFAP
+                | Self::FTL
+                | Self::GFDLOneDotOneOnly
+                | Self::GFDLOneDotOneOrLater
+                | Self::GFDLOneDotTwoOnly
+                | Self::GFDLOneDotTwoOrLater
+                | Self::GFDLOneDotThreeOnly
+                | Self::GFDLOneDotThreeOrLater
+                | Self::GPLTwoDotZeroOnly
+                | Self::GPLTwoDotZeroOrLater
+                | Self::GPLThreeDotZeroOnly
+                | Self::GPLThreeDotZeroOrLater
+                | Self::HPND
+                | Self::IJG
+                | Self::IPA
+                | Self::IPLOneDotZero
+                | Self::ISC
+                | Self::ImlibTwo
+                | Self::Intel
+                | Self::LGPLTwoDotOneOnly
+                | Self::LGPLTwoDotOneOrLater
+                | Self::LGPLThreeDotZeroOnly
+                | Self::LGPLThreeDotZeroOrLater
+                | Self::LPLOneDotZeroTwo
+                | Self::LPPLOneDotTwo
+                | Self::LPPLOneDotThreea
+                | Self::MIT
+                | Self::MPLOneDotOne
+                | Self::MPLTwoDotZero
+                | Self::MSPL
+                | Self::MSRL
+                | Self::NCSA
+                | Self::NOSL
+                | Self::NPLOneDotZero
+                | Self::NPLOneDotOne
+                | Self::Nokia
+                | Self::ODbLOneDotZero
+                | Self::OFLOneDotZero
+                | Self::OFLOneDotOne
+                | Self::OLDAPTwoDotThree
+                | Self::OLDAPTwoDotSeven
+                | Self::OSLOneDotZero
+                | Self::OSLOneDotOne
+                | Self::OSLTwoDotZero
+                | Self::OSLTwoDotOne
+                | Self::OSLThreeDotZero
+                | Self::OpenSsl
+                | Self::PHPThreeDotZeroOne
+                | Self::PythonTwoDotZero
+                | Self::QPLOneDotZero
+                | Self::RPSLOneDotZero
+                | Self::Ruby
+                | Self::SGIBTwoDotZero
+                | Self::SISSL
+                | Self::SMLNJ
+                | Self::SPLOneDotZero
+                | Self::Sleepycat
+                | Self::UPLOneDotZero
+                | Self::Unlicense
+                | Self::Vim
+                | Self::WThreeC
+                | Self::WTFPL
+                | Self::XOneOne
+                | Self::XFreeEightSixOneDotOne
+                | Self::YPLOneDotOne
+                | Self::ZPLTwoDotZero
+                | Self::ZPLTwoDotOne
+                | Self::ZendTwoDotZero
+                | Self::ZimbraOneDotThree
+                | Self::Zlib
+                | Self::DeprecatedAGPLOneDotZero
+                | Self::DeprecatedAGPLThreeDotZero
+                | Self::DeprecatedBSDTwoClauseFreeBsd
+                | Self::DeprecatedBSDTwoClauseNetBsd
+                | Self::DeprecatedGFDLOneDotOne
+                | Self::DeprecatedGFDLOneDotTwo
+                | Self::DeprecatedGFDLOneDotThree
+                | Self::DeprecatedGPLTwoDotZero
+                | Self::DeprecatedGPLTwoDotZeroPlus
+                | Self::DeprecatedGPLThreeDotZero
+                | Self::DeprecatedGPLThreeDotZeroPlus
+                | Self::DeprecatedLGPLTwoDotOne
+                | Self::DeprecatedLGPLTwoDotOnePlus
+                | Self::DeprecatedLGPLThreeDotZero
+                | Self::DeprecatedLGPLThreeDotZeroPlus
+                | Self::DeprecatedNunit
+                | Self::DeprecatedStandardMlNJ
+                | Self::DeprecatedECosTwoDotZero
+                | Self::Gnuplot
+                | Self::IMatix
+                | Self::Xinetd
+        )
+    }
+}
+impl LicenseException {
+    #[doc = r#" Returns the SPDX identifier of the license (e.g., "Apache-2.0")."#]
+    pub fn spdx_id(&self) -> &'static str {
+        match self {
+            Self::ThreeEightNineException => "389-exception",
+            Self::AsteriskException => "Asterisk-exception",
+            Self::AsteriskLinkingProtocolsException => "Asterisk-linking-protocols-exception",
+            Self::AutoconfExceptionTwoDotZero => "Autoconf-exception-2.0",
+            Self::AutoconfExceptionThreeDotZero => "Autoconf-exception-3.0",
+            Self::AutoconfExceptionGeneric => "Autoconf-exception-generic",
+            Self::AutoconfExceptionGenericThreeDotZero => "Autoconf-exception-generic-3.0",
+            Self::AutoconfExceptionMacro => "Autoconf-exception-macro",
+            Self::BisonExceptionOneDotTwoFour => "Bison-exception-1.24",
+            Self::BisonExceptionTwoDotTwo => "Bison-exception-2.2",
+            Self::BootloaderException => "Bootloader-exception",
+            Self::CGALLinkingException => "CGAL-linking-exception",
+            Self::CLISPExceptionTwoDotZero => "CLISP-exception-2.0",
+            Self::ClasspathExceptionTwoDotZero => "Classpath-exception-2.0",
+            Self::DigiRuleFOSSException => "DigiRule-FOSS-exception",
+            Self::DigiaQtLGPLExceptionOneDotOne => "Digia-Qt-LGPL-exception-1.1",
+            Self::FLTKException => "FLTK-exception",
+            Self::FawkesRuntimeException => "Fawkes-Runtime-exception",
+            Self::FontExceptionTwoDotZero => "Font-exception-2.0",
+            Self::GCCExceptionTwoDotZero => "GCC-exception-2.0",
+            Self::GCCExceptionTwoDotZeroNote => "GCC-exception-2.0-note",
+            Self::GCCExceptionThreeDotOne => "GCC-exception-3.1",
+            Self::GNATException => "GNAT-exception",
+            Self::GNOMEExamplesException => "GNOME-examples-exception",
+            Self::GNUCompilerException => "GNU-compiler-exception",
+            Self::GPLThreeDotZeroThreeEightNineDsBaseException => "GPL-3.0-389-ds-base-exception",
+            Self::GPLThreeDotZeroInterfaceException => "GPL-3.0-interface-exception",
+            Self::GPLThreeDotZeroLinkingException => "GPL-3.0-linking-exception",
+            Self::GPLThreeDotZeroLinkingSourceException => "GPL-3.0-linking-source-exception",
+            Self::GPLCCOneDotZero => "GPL-CC-1.0",
+            Self::GStreamerExceptionTwoZeroZeroFive => "GStreamer-exception-2005",
+            Self::GStreamerExceptionTwoZeroZeroEight => "GStreamer-exception-2008",
+            Self::GmshException => "Gmsh-exception",
+            Self::IndependentModulesException => "Independent-modules-exception",
+            Self::KiCadLibrariesException => "KiCad-libraries-exception",
+            Self::LGPLThreeDotZeroLinkingException => "LGPL-3.0-linking-exception",
+            Self::LLGPL => "LLGPL",
+            Self::LLVMException => "LLVM-exception",
+            Self::LZMAException => "LZMA-exception",
+            Self::LibtoolException => "Libtool-exception",
+            Self::LinuxSyscallNote => "Linux-syscall-note",
+            Self::OCCTExceptionOneDotZero => "OCCT-exception-1.0",
+            Self::OCamlLGPLLinkingException => "OCaml-LGPL-linking-exception",
+            Self::OpenJdkAssemblyExceptionOneDotZero => "OpenJDK-assembly-exception-1.0",
+            Self::PcreTwoException => "PCRE2-exception",
+            Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven => {
+                "PS-or-PDF-font-exception-20170817"
+            }
+            Self::QPLOneDotZeroINRIATwoZeroZeroFourException => "QPL-1.0-INRIA-2004-exception",
+            Self::QtGPLExceptionOneDotZero => "Qt-GPL-exception-1.0",
+            Self::QtLGPLExceptionOneDotOne => "Qt-LGPL-exception-1.1",
+            Self::QwtExceptionOneDotZero => "Qwt-exception-1.0",
+            Self::RrDtoolFLOSSExceptionTwoDotZero => "RRDtool-FLOSS-exception-2.0",
+            Self::SANEException => "SANE-exception",
+            Self::SHLTwoDotZero => "SHL-2.0",
+            Self::SHLTwoDotOne => "SHL-2.1",
+            Self::SWIException => "SWI-exception",
+            Self::SwiftException => "Swift-exception",
+            Self::TexinfoException => "Texinfo-exception",
+            Self::UBDLException => "UBDL-exception",
+            Self::UniversalFOSSExceptionOneDotZero => "Universal-FOSS-exception-1.0",
+            Self::WxWindowsExceptionThreeDotOne => "WxWindows-exception-3.1",
+            Self::CryptsetupOpenSslException => "cryptsetup-OpenSSL-exception",
+            Self::DeprecatedNokiaQtExceptionOneDotOne => "Nokia-Qt-exception-1.1",
+            Self::ECosExceptionTwoDotZero => "eCos-exception-2.0",
+            Self::ErlangOtpLinkingException => "erlang-otp-linking-exception",
+            Self::FmtException => "fmt-exception",
+            Self::FreertosExceptionTwoDotZero => "freertos-exception-2.0",
+            Self::GnuJavamailException => "gnu-javamail-exception",
+            Self::HarbourException => "harbour-exception",
+            Self::ITwopGplJavaException => "i2p-gpl-java-exception",
+            Self::LibpriOpenHThreeTwoThreeException => "libpri-OpenH323-exception",
+            Self::MifException => "mif-exception",
+            Self::MxmlException => "mxml-exception",
+            Self::OpenvpnOpensslException => "openvpn-openssl-exception",
+            Self::PolyparseException => "polyparse-exception",
+            Self::RomicException => "romic-exception",
+            Self::StunnelException => "stunnel-exception",
+            Self::UBootExceptionTwoDotZero => "u-boot-exception-2.0",
+            Self::VsftpdOpensslException => "vsftpd-openssl-exception",
+            Self::XOneOnevncOpensslException => "x11vnc-openssl-exception",
+        }
+    }
+    #[doc = r" Returns the page of the license on the SPDX website."]
+    pub fn reference_url(&self) -> &'static str {
+        match self {
+            Self::ThreeEightNineException => "https://spdx.org/licenses/389-exception.html",
+            Self::AsteriskException => "https://spdx.org/licenses/Asterisk-exception.html",
+            Self::AsteriskLinkingProtocolsException => {
+                "https://spdx.org/licenses/Asterisk-linking-protocols-exception.html"
+            }
+            Self::AutoconfExceptionTwoDotZero => {
+                "https://spdx.org/licenses/Autoconf-exception-2.0.html"
+            }
+            Self::AutoconfExceptionThreeDotZero => {
+                "https://spdx.org/licenses/Autoconf-exception-3.0.html"
+            }
+            Self::AutoconfExceptionGeneric => {
+                "https://spdx.org/licenses/Autoconf-exception-generic.html"
+            }
+            Self::AutoconfExceptionGenericThreeDotZero => {
+                "https://spdx.org/licenses/Autoconf-exception-generic-3.0.html"
+            }
+            Self::AutoconfExceptionMacro => {
+                "https://spdx.org/licenses/Autoconf-exception-macro.html"
+            }
+            Self::BisonExceptionOneDotTwoFour => {
+                "https://spdx.org/licenses/Bison-exception-1.24.html"
+            }
+            Self::BisonExceptionTwoDotTwo => "https://spdx.org/licenses/Bison-exception-2.2.html",
+            Self::BootloaderException => "https://spdx.org/licenses/Bootloader-exception.html",
+            Self::CGALLinkingException => "https://spdx.org/licenses/CGAL-linking-exception.html",
+            Self::CLISPExceptionTwoDotZero => "https://spdx.org/licenses/CLISP-exception-2.0.html",
+            Self::ClasspathExceptionTwoDotZero => {
+                "https://spdx.org/licenses/Classpath-exception-2.0.html"
+            }
+            Self::DigiRuleFOSSException => "https://spdx.org/licenses/DigiRule-FOSS-exception.html",
+            Self::DigiaQtLGPLExceptionOneDotOne => {
+                "https://spdx.org/licenses/Digia-Qt-LGPL-exception-1.1.html"
+            }
+            Self::FLTKException => "https://spdx.org/licenses/FLTK-exception.html",
+            Self::FawkesRuntimeException => {
+                "https://spdx.org/licenses/Fawkes-Runtime-exception.html"
+            }
+            Self::FontExceptionTwoDotZero => "https://spdx.org/licenses/Font-exception-2.0.html",
+            Self::GCCExceptionTwoDotZero => "https://spdx.org/licenses/GCC-exception-2.0.html",
+            Self::GCCExceptionTwoDotZeroNote => {
+                "https://spdx.org/licenses/GCC-exception-2.0-note.html"
+            }
+            Self::GCCExceptionThreeDotOne => "https://spdx.org/licenses/GCC-exception-3.1.html",
+            Self::GNATException => "https://spdx.org/licenses/GNAT-exception.html",
+            Self::GNOMEExamplesException => {
+                "https://spdx.org/licenses/GNOME-examples-exception.html"
+            }
+            Self::GNUCompilerException => "https://spdx.org/licenses/GNU-compiler-exception.html",
+            Self::GPLThreeDotZeroThreeEightNineDsBaseException => {
+                "https://spdx.org/licenses/GPL-3.0-389-ds-base-exception.html"
+            }
+            Self::GPLThreeDotZeroInterfaceException => {
+                "https://spdx.org/licenses/GPL-3.0-interface-exception.html"
+            }
+            Self::GPLThreeDotZeroLinkingException => {
+                "https://spdx.org/licenses/GPL-3.0-linking-exception.html"
+            }
+            Self::GPLThreeDotZeroLinkingSourceException => {
+                "https://spdx.org/licenses/GPL-3.0-linking-source-exception.html"
+            }
+            Self::GPLCCOneDotZero => "https://spdx.org/licenses/GPL-CC-1.0.html",
+            Self::GStreamerExceptionTwoZeroZeroFive => {
+                "https://spdx.org/licenses/GStreamer-exception-2005.html"
+            }
+            Self::GStreamerExceptionTwoZeroZeroEight => {
+                "https://spdx.org/licenses/GStreamer-exception-2008.html"
+            }
+            Self::GmshException => "https://spdx.org/licenses/Gmsh-exception.html",
+            Self::IndependentModulesException => {
+                "https://spdx.org/licenses/Independent-modules-exception.html"
+            }
+            Self::KiCadLibrariesException => {
+                "https://spdx.org/licenses/KiCad-libraries-exception.html"
+            }
+            Self::LGPLThreeDotZeroLinkingException => {
+                "https://spdx.org/licenses/LGPL-3.0-linking-exception.html"
+            }
+            Self::LLGPL => "https://spdx.org/licenses/LLGPL.html",
+            Self::LLVMException => "https://spdx.org/licenses/LLVM-exception.html",
+            Self::LZMAException => "https://spdx.org/licenses/LZMA-exception.html",
+            Self::LibtoolException => "https://spdx.org/licenses/Libtool-exception.html",
+            Self::LinuxSyscallNote => "https://spdx.org/licenses/Linux-syscall-note.html",
+            Self::OCCTExceptionOneDotZero => "https://spdx.org/licenses/OCCT-exception-1.0.html",
+            Self::OCamlLGPLLinkingException => {
+                "https://spdx.org/licenses/OCaml-LGPL-linking-exception.html"
+            }
+            Self::OpenJdkAssemblyExceptionOneDotZero => {
+                "https://spdx.org/licenses/OpenJDK-assembly-exception-1.0.html"
+            }
+            Self::PcreTwoException => "https://spdx.org/licenses/PCRE2-exception.html",
+            Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven => {
+                "https://spdx.org/licenses/PS-or-PDF-font-exception-20170817.html"
+            }
+            Self::QPLOneDotZeroINRIATwoZeroZeroFourException => {
+                "https://spdx.org/licenses/QPL-1.0-INRIA-2004-exception.html"
+            }
+            Self::QtGPLExceptionOneDotZero => "https://spdx.org/licenses/Qt-GPL-exception-1.0.html",
+            Self::QtLGPLExceptionOneDotOne => {
+                "https://spdx.org/licenses/Qt-LGPL-exception-1.1.html"
+            }
+            Self::QwtExceptionOneDotZero => "https://spdx.org/licenses/Qwt-exception-1.0.html",
+            Self::RrDtoolFLOSSExceptionTwoDotZero => {
+                "https://spdx.org/licenses/RRDtool-FLOSS-exception-2.0.html"
+            }
+            Self::SANEException => "https://spdx.org/licenses/SANE-exception.html",
+            Self::SHLTwoDotZero => "https://spdx.org/licenses/SHL-2.0.html",
+            Self::SHLTwoDotOne => "https://spdx.org/licenses/SHL-2.1.html",
+            Self::SWIException => "https://spdx.org/licenses/SWI-exception.html",
+            Self::SwiftException => "https://spdx.org/licenses/Swift-exception.html",
+            Self::TexinfoException => "https://spdx.org/licenses/Texinfo-exception.html",
+            Self::UBDLException => "https://spdx.org/licenses/UBDL-exception.html",
+            Self::UniversalFOSSExceptionOneDotZero => {
+                "https://spdx.org/licenses/Universal-FOSS-exception-1.0.html"
+            }
+            Self::WxWindowsExceptionThreeDotOne => {
+                "https://spdx.org/licenses/WxWindows-exception-3.1.html"
+            }
+            Self::CryptsetupOpenSslException => {
+                "https://spdx.org/licenses/cryptsetup-OpenSSL-exception.html"
+            }
+            Self::DeprecatedNokiaQtExceptionOneDotOne => {
+                "https://spdx.org/licenses/Nokia-Qt-exception-1.1.html"
+            }
+            Self::ECosExceptionTwoDotZero => "https://spdx.org/licenses/eCos-exception-2.0.html",
+            Self::ErlangOtpLinkingException => {
+                "https://spdx.org/licenses/erlang-otp-linking-exception.html"
+            }
+            Self::FmtException => "https://spdx.org/licenses/fmt-exception.html",
+            Self::FreertosExceptionTwoDotZero => {
+                "https://spdx.org/licenses/freertos-exception-2.0.html"
+            }
+            Self::GnuJavamailException => "https://spdx.org/licenses/gnu-javamail-exception.html",
+            Self::HarbourException => "https://spdx.org/licenses/harbour-exception.html",
+            Self::ITwopGplJavaException => "https://spdx.org/licenses/i2p-gpl-java-exception.html",
+            Self::LibpriOpenHThreeTwoThreeException => {
+                "https://spdx.org/licenses/libpri-OpenH323-exception.html"
+            }
+            Self::MifException => "https://spdx.org/licenses/mif-exception.html",
+            Self::MxmlException => "https://spdx.org/licenses/mxml-exception.html",
+            Self::OpenvpnOpensslException => {
+                "https://spdx.org/licenses/openvpn-openssl-exception.html"
+            }
+            Self::PolyparseException => "https://spdx.org/licenses/polyparse-exception.html",
+            Self::RomicException => "https://spdx.org/licenses/romic-exception.html",
+            Self::StunnelException => "https://spdx.org/licenses/stunnel-exception.html",
+            Self::UBootExceptionTwoDotZero => "https://spdx.org/licenses/u-boot-exception-2.0.html",
+            Self::VsftpdOpensslException => {
+                "https://spdx.org/licenses/vsftpd-openssl-exception.html"
+            }
+            Self::XOneOnevncOpensslException => {
+                "https://spdx.org/licenses/x11vnc-openssl-exception.html"
+            }
+        }
+    }
+    #[doc = r" Returns the other pages the SPDX license list gives for the text, ex: its upstream."]
+    pub fn see_also(&self) -> &'static [&'static str] {
+        match self {
+            Self::ThreeEightNineException => &[],
+            Self::AsteriskException => &[],
+            Self::AsteriskLinkingProtocolsException => &[],
+            Self::AutoconfExceptionTwoDotZero => &[],
+            Self::AutoconfExceptionThreeDotZero => &[],
+            Self::AutoconfExceptionGeneric => &[],
+            Self::AutoconfExceptionGenericThreeDotZero => &[],
+            Self::AutoconfExceptionMacro => &[],
+            Self::BisonExceptionOneDotTwoFour => &[],
+            Self::BisonExceptionTwoDotTwo => &[],
+            Self::BootloaderException => &[],
+            Self::CGALLinkingException => &[],
+            Self::CLISPExceptionTwoDotZero => &[],
+            Self::ClasspathExceptionTwoDotZero => &[],
+            Self::DigiRuleFOSSException => &[],
+            Self::DigiaQtLGPLExceptionOneDotOne => &[],
+            Self::FLTKException => &[],
+            Self::FawkesRuntimeException => &[],
+            Self::FontExceptionTwoDotZero => &[],
+            Self::GCCExceptionTwoDotZero => &[],
+            Self::GCCExceptionTwoDotZeroNote => &[],
+            Self::GCCExceptionThreeDotOne => &[],
+            Self::GNATException => &[],
+            Self::GNOMEExamplesException => &[],
+            Self::GNUCompilerException => &[],
+            Self::GPLThreeDotZeroThreeEightNineDsBaseException => &[],
+            Self::GPLThreeDotZeroInterfaceException => &[],
+            Self::GPLThreeDotZeroLinkingException => &[],
+            Self::GPLThreeDotZeroLinkingSourceException => &[],
+            Self::GPLCCOneDotZero => &[],
+            Self::GStreamerExceptionTwoZeroZeroFive => &[],
+            Self::GStreamerExceptionTwoZeroZeroEight => &[],
+            Self::GmshException => &[],
+            Self::IndependentModulesException => &[],
+            Self::KiCadLibrariesException => &[],
+            Self::LGPLThreeDotZeroLinkingException => &[],
+            Self::LLGPL => &[],
+            Self::LLVMException => &[],
+            Self::LZMAException => &[],
+            Self::LibtoolException => &[],
+            Self::LinuxSyscallNote => &[],
+            Self::OCCTExceptionOneDotZero => &[],
+            Self::OCamlLGPLLinkingException => &[],
+            Self::OpenJdkAssemblyExceptionOneDotZero => &[],
+            Self::PcreTwoException => &[],
+            Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven => &[],
+            Self::QPLOneDotZeroINRIATwoZeroZeroFourException => &[],
+            Self::QtGPLExceptionOneDotZero => &[],
+            Self::QtLGPLExceptionOneDotOne => &[],
+            Self::QwtExceptionOneDotZero => &[],
+            Self::RrDtoolFLOSSExceptionTwoDotZero => &[],
+            Self::SANEException => &[],
+            Self::SHLTwoDotZero => &[],
+            Self::SHLTwoDotOne => &[],
+            Self::SWIException => &[],
+            Self::SwiftException => &[],
+            Self::TexinfoException => &[],
+            Self::UBDLException => &[],
+            Self::UniversalFOSSExceptionOneDotZero => &[],
+            Self::WxWindowsExceptionThreeDotOne => &[],
+            Self::CryptsetupOpenSslException => &[],
+            Self::DeprecatedNokiaQtExceptionOneDotOne => &[],
+            Self::ECosExceptionTwoDotZero => &[],
+            Self::ErlangOtpLinkingException => &[],
+            Self::FmtException => &[],
+            Self::FreertosExceptionTwoDotZero => &[],
+            Self::GnuJavamailException => &[],
+            Self::HarbourException => &[],
+            Self::ITwopGplJavaException => &[],
+            Self::LibpriOpenHThreeTwoThreeException => &[],
+            Self::MifException => &[],
+            Self::MxmlException => &[],
+            Self::OpenvpnOpensslException => &[],
+            Self::PolyparseException => &[],
+            Self::RomicException => &[],
+            Self::StunnelException => &[],
+            Self::UBootExceptionTwoDotZero => &[],
+            Self::VsftpdOpensslException => &[],
+            Self::XOneOnevncOpensslException => &[],
+        }
+    }
+    #[doc = r" Returns true if the SPDX identifier is deprecated."]
+    pub fn is_deprecated(&self) -> bool {
+        matches!(self, Self::DeprecatedNokiaQtExceptionOneDotOne)
+    }
+    #[doc = r" Returns the SPDX expression recommended in place of a deprecated identifier, if any."]
+    pub fn replacement(&self) -> Option<&'static str> {
+        match self {
+            Self::ThreeEightNineException => None,
+            Self::AsteriskException => None,
+            Self::AsteriskLinkingProtocolsException => None,
+            Self::AutoconfExceptionTwoDotZero => None,
+            Self::AutoconfExceptionThreeDotZero => None,
+            Self::AutoconfExceptionGeneric => None,
+            Self::AutoconfExceptionGenericThreeDotZero => None,
+            Self::AutoconfExceptionMacro => None,
+            Self::BisonExceptionOneDotTwoFour => None,
+            Self::BisonExceptionTwoDotTwo => None,
+            Self::BootloaderException => None,
+            Self::CGALLinkingException => None,
+            Self::CLISPExceptionTwoDotZero => None,
+            Self::ClasspathExceptionTwoDotZero => None,
+            Self::DigiRuleFOSSException => None,
+            Self::DigiaQtLGPLExceptionOneDotOne => None,
+            Self::FLTKException => None,
+            Self::FawkesRuntimeException => None,
+            Self::FontExceptionTwoDotZero => None,
+            Self::GCCExceptionTwoDotZero => None,
+            Self::GCCExceptionTwoDotZeroNote => None,
+            Self::GCCExceptionThreeDotOne => None,
+            Self::GNATException => None,
+            Self::GNOMEExamplesException => None,
+            Self::GNUCompilerException => None,
+            Self::GPLThreeDotZeroThreeEightNineDsBaseException => None,
+            Self::GPLThreeDotZeroInterfaceException => None,
+            Self::GPLThreeDotZeroLinkingException => None,
+            Self::GPLThreeDotZeroLinkingSourceException => None,
+            Self::GPLCCOneDotZero => None,
+            Self::GStreamerExceptionTwoZeroZeroFive => None,
+            Self::GStreamerExceptionTwoZeroZeroEight => None,
+            Self::GmshException => None,
+            Self::IndependentModulesException => None,
+            Self::KiCadLibrariesException => None,
+            Self::LGPLThreeDotZeroLinkingException => None,
+            Self::LLGPL => None,
+            Self::LLVMException => None,
+            Self::LZMAException => None,
+            Self::LibtoolException => None,
+            Self::LinuxSyscallNote => None,
+            Self::OCCTExceptionOneDotZero => None,
+            Self::OCamlLGPLLinkingException => None,
+            Self::OpenJdkAssemblyExceptionOneDotZero => None,
+            Self::PcreTwoException => None,
+            Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven => None,
+            Self::QPLOneDotZeroINRIATwoZeroZeroFourException => None,
+            Self::QtGPLExceptionOneDotZero => None,
+            Self::QtLGPLExceptionOneDotOne => None,
+            Self::QwtExceptionOneDotZero => None,
+            Self::RrDtoolFLOSSExceptionTwoDotZero => None,
+            Self::SANEException => None,
+            Self::SHLTwoDotZero => None,
+            Self::SHLTwoDotOne => None,
+            Self::SWIException => None,
+            Self::SwiftException => None,
+            Self::TexinfoException => None,
+            Self::UBDLException => None,
+            Self::UniversalFOSSExceptionOneDotZero => None,
+            Self::WxWindowsExceptionThreeDotOne => None,
+            Self::CryptsetupOpenSslException => None,
+            Self::DeprecatedNokiaQtExceptionOneDotOne => None,
+            Self::ECosExceptionTwoDotZero => None,
+            Self::ErlangOtpLinkingException => None,
+            Self::FmtException => None,
+            Self::FreertosExceptionTwoDotZero => None,
+            Self::GnuJavamailException => None,
+            Self::HarbourException => None,
+            Self::ITwopGplJavaException => None,
+            Self::LibpriOpenHThreeTwoThreeException => None,
+            Self::MifException => None,
+            Self::MxmlException => None,
+            Self::OpenvpnOpensslException => None,
+            Self::PolyparseException => None,
+            Self::RomicException => None,
+            Self::StunnelException => None,
+            Self::UBootExceptionTwoDotZero => None,
+            Self::VsftpdOpensslException => None,
+            Self::XOneOnevncOpensslException => None,
+        }
+    }
+    #[doc = r" Returns the embedded template content for the license."]
+    #[doc = r" The content will be from `.template.txt` if available, otherwise `.txt`."]
+    pub fn template_content(&self) -> &'static str {
+        match self {
+            Self::ThreeEightNineException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Source-beginning-file",
+                "389-exception",
                 ".template.txt"
             )),
-            Self::SunPro => include_str!(concat!(
+            Self::AsteriskException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SunPro",
+                "Asterisk-exception",
                 ".template.txt"
             )),
-            Self::HPNDIntel => include_str!(concat!(
+            Self::AsteriskLinkingProtocolsException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-Intel",
+                "Asterisk-linking-protocols-exception",
                 ".template.txt"
             )),
-            Self::LZMASDKNineDotTwoTwo => include_str!(concat!(
+            Self::AutoconfExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "LZMA-SDK-9.22",
+                "Autoconf-exception-2.0",
                 ".template.txt"
             )),
-            Self::OFLOneDotZero => include_str!(concat!(
+            Self::AutoconfExceptionThreeDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OFL-1.0",
+                "Autoconf-exception-3.0",
                 ".template.txt"
             )),
-            Self::DeprecatedGPLTwoDotZeroWithGCCException => include_str!(concat!(
+            Self::AutoconfExceptionGeneric => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GPL-2.0-with-GCC-exception",
+                "Autoconf-exception-generic",
                 ".template.txt"
             )),
             Self::AutoconfExceptionGenericThreeDotZero => include_str!(concat!(
@@ -4832,1391 +8780,449 @@ impl License {
                 "Autoconf-exception-generic-3.0",
                 ".template.txt"
             )),
-            Self::CCBYSAThreeDotZero => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "CC-BY-SA-3.0",
-                ".template.txt"
-            )),
-            Self::CCBYNCSAThreeDotZero => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "CC-BY-NC-SA-3.0",
-                ".template.txt"
-            )),
-            Self::Bahyph => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "Bahyph",
-                ".template.txt"
-            )),
-            Self::DSDP => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "DSDP",
-                ".template.txt"
-            )),
-            Self::McPheeSlideshow => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "McPhee-slideshow",
-                ".template.txt"
-            )),
-            Self::BitTorrentOneDotZero => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "BitTorrent-1.0",
-                ".template.txt"
-            )),
-            Self::CCBYThreeDotZeroNL => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "CC-BY-3.0-NL",
-                ".template.txt"
-            )),
-            Self::OLDAPOneDotOne => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "OLDAP-1.1",
-                ".template.txt"
-            )),
-            Self::BitstreamVera => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "Bitstream-Vera",
-                ".template.txt"
-            )),
-            Self::GFDLOneDotTwoInvariantsOnly => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "GFDL-1.2-invariants-only",
-                ".template.txt"
-            )),
-            Self::SchemeReport => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "SchemeReport",
-                ".template.txt"
-            )),
-            Self::HPNDExportTwoUS => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "HPND-export2-US",
-                ".template.txt"
-            )),
-            Self::Xinetd => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "xinetd",
-                ".template.txt"
-            )),
-            Self::HaskellReport => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "HaskellReport",
-                ".template.txt"
-            )),
-            Self::TCL => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "TCL",
-                ".template.txt"
-            )),
-            Self::NetCdf => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "NetCDF",
-                ".template.txt"
-            )),
-            Self::Symlinks => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "Symlinks",
-                ".template.txt"
-            )),
-            Self::OpenvpnOpensslException => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "openvpn-openssl-exception",
-                ".template.txt"
-            )),
-            Self::ArphicOneNineNineNine => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "Arphic-1999",
-                ".template.txt"
-            )),
-            Self::HPNDPbmplus => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "HPND-Pbmplus",
-                ".template.txt"
-            )),
-            Self::OAR => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "OAR",
-                ".template.txt"
-            )),
-            Self::CopyleftNextZeroDotThreeDotZero => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "copyleft-next-0.3.0",
-                ".template.txt"
-            )),
-            Self::YPLOneDotZero => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "YPL-1.0",
-                ".template.txt"
-            )),
-            Self::LPPLOneDotOne => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "LPPL-1.1",
-                ".template.txt"
-            )),
-            Self::CCBYNDFourDotZero => include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/licenses/",
-                "CC-BY-ND-4.0",
-                ".template.txt"
-            )),
-            Self::AutoconfExceptionTwoDotZero => include_str!(concat!(
+            Self::AutoconfExceptionMacro => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Autoconf-exception-2.0",
+                "Autoconf-exception-macro",
                 ".template.txt"
             )),
-            Self::XFreeEightSixOneDotOne => include_str!(concat!(
+            Self::BisonExceptionOneDotTwoFour => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "XFree86-1.1",
+                "Bison-exception-1.24",
                 ".template.txt"
             )),
-            Self::CCBYNCNDFourDotZero => include_str!(concat!(
+            Self::BisonExceptionTwoDotTwo => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-ND-4.0",
+                "Bison-exception-2.2",
                 ".template.txt"
             )),
-            Self::CCBYNCSAThreeDotZeroDE => include_str!(concat!(
+            Self::BootloaderException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-3.0-DE",
+                "Bootloader-exception",
                 ".template.txt"
             )),
-            Self::TPLOneDotZero => include_str!(concat!(
+            Self::CGALLinkingException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TPL-1.0",
+                "CGAL-linking-exception",
                 ".template.txt"
             )),
-            Self::Naumen => include_str!(concat!(
+            Self::CLISPExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Naumen",
+                "CLISP-exception-2.0",
                 ".template.txt"
             )),
-            Self::NICTAOneDotZero => include_str!(concat!(
+            Self::ClasspathExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NICTA-1.0",
+                "Classpath-exception-2.0",
                 ".template.txt"
             )),
-            Self::NOSL => include_str!(concat!(
+            Self::DigiRuleFOSSException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NOSL",
+                "DigiRule-FOSS-exception",
                 ".template.txt"
             )),
-            Self::Pnmstitch => include_str!(concat!(
+            Self::DigiaQtLGPLExceptionOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "pnmstitch",
+                "Digia-Qt-LGPL-exception-1.1",
                 ".template.txt"
             )),
-            Self::CPLOneDotZero => include_str!(concat!(
+            Self::FLTKException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CPL-1.0",
+                "FLTK-exception",
                 ".template.txt"
             )),
-            Self::Xzoom => include_str!(concat!(
+            Self::FawkesRuntimeException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "xzoom",
+                "Fawkes-Runtime-exception",
                 ".template.txt"
             )),
-            Self::IECCodeComponentsEULA => include_str!(concat!(
+            Self::FontExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "IEC-Code-Components-EULA",
+                "Font-exception-2.0",
                 ".template.txt"
             )),
-            Self::DeprecatedAGPLThreeDotZero => include_str!(concat!(
+            Self::GCCExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_AGPL-3.0",
+                "GCC-exception-2.0",
                 ".template.txt"
             )),
-            Self::RSCPL => include_str!(concat!(
+            Self::GCCExceptionTwoDotZeroNote => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "RSCPL",
+                "GCC-exception-2.0-note",
                 ".template.txt"
             )),
-            Self::NPLOneDotZero => include_str!(concat!(
+            Self::GCCExceptionThreeDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "NPL-1.0",
+                "GCC-exception-3.1",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour => include_str!(concat!(
+            Self::GNATException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-No-Nuclear-License-2014",
+                "GNAT-exception",
                 ".template.txt"
             )),
-            Self::Sleepycat => include_str!(concat!(
+            Self::GNOMEExamplesException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Sleepycat",
+                "GNOME-examples-exception",
                 ".template.txt"
             )),
-            Self::CDLASharingOneDotZero => include_str!(concat!(
+            Self::GNUCompilerException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CDLA-Sharing-1.0",
+                "GNU-compiler-exception",
                 ".template.txt"
             )),
-            Self::GFDLOneDotThreeOnly => include_str!(concat!(
+            Self::GPLThreeDotZeroThreeEightNineDsBaseException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.3-only",
+                "GPL-3.0-389-ds-base-exception",
                 ".template.txt"
             )),
-            Self::Lsof => include_str!(concat!(
+            Self::GPLThreeDotZeroInterfaceException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "lsof",
+                "GPL-3.0-interface-exception",
                 ".template.txt"
             )),
-            Self::ParitySevenDotZeroDotZero => include_str!(concat!(
+            Self::GPLThreeDotZeroLinkingException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Parity-7.0.0",
+                "GPL-3.0-linking-exception",
                 ".template.txt"
             )),
-            Self::AAL => include_str!(concat!(
+            Self::GPLThreeDotZeroLinkingSourceException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "AAL",
+                "GPL-3.0-linking-source-exception",
                 ".template.txt"
             )),
-            Self::Zeeff => include_str!(concat!(
+            Self::GPLCCOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Zeeff",
+                "GPL-CC-1.0",
                 ".template.txt"
             )),
-            Self::CCBYNCSAFourDotZero => include_str!(concat!(
+            Self::GStreamerExceptionTwoZeroZeroFive => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-SA-4.0",
+                "GStreamer-exception-2005",
                 ".template.txt"
             )),
-            Self::BlueOakOneDotZeroDotZero => include_str!(concat!(
+            Self::GStreamerExceptionTwoZeroZeroEight => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BlueOak-1.0.0",
+                "GStreamer-exception-2008",
                 ".template.txt"
             )),
-            Self::CCBYSAFourDotZero => include_str!(concat!(
+            Self::GmshException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-4.0",
+                "Gmsh-exception",
                 ".template.txt"
             )),
-            Self::GFDLOneDotTwoOrLater => include_str!(concat!(
+            Self::IndependentModulesException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.2-or-later",
+                "Independent-modules-exception",
                 ".template.txt"
             )),
-            Self::OFLOneDotOne => include_str!(concat!(
+            Self::KiCadLibrariesException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OFL-1.1",
+                "KiCad-libraries-exception",
                 ".template.txt"
             )),
-            Self::APSLOneDotOne => include_str!(concat!(
+            Self::LGPLThreeDotZeroLinkingException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "APSL-1.1",
+                "LGPL-3.0-linking-exception",
                 ".template.txt"
             )),
-            Self::GPLThreeDotZeroInterfaceException => include_str!(concat!(
+            Self::LLGPL => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GPL-3.0-interface-exception",
+                "LLGPL",
                 ".template.txt"
             )),
-            Self::QtLGPLExceptionOneDotOne => include_str!(concat!(
+            Self::LLVMException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Qt-LGPL-exception-1.1",
+                "LLVM-exception",
                 ".template.txt"
             )),
-            Self::MackerrasThreeClause => include_str!(concat!(
+            Self::LZMAException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Mackerras-3-Clause",
+                "LZMA-exception",
                 ".template.txt"
             )),
-            Self::EUPLOneDotOne => include_str!(concat!(
+            Self::LibtoolException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "EUPL-1.1",
+                "Libtool-exception",
                 ".template.txt"
             )),
-            Self::AutoconfExceptionGeneric => include_str!(concat!(
+            Self::LinuxSyscallNote => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Autoconf-exception-generic",
+                "Linux-syscall-note",
                 ".template.txt"
             )),
-            Self::SunPPP => include_str!(concat!(
+            Self::OCCTExceptionOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Sun-PPP",
+                "OCCT-exception-1.0",
                 ".template.txt"
             )),
-            Self::CECILLB => include_str!(concat!(
+            Self::OCamlLGPLLinkingException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CECILL-B",
+                "OCaml-LGPL-linking-exception",
                 ".template.txt"
             )),
-            Self::LinuxOpenIb => include_str!(concat!(
+            Self::OpenJdkAssemblyExceptionOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Linux-OpenIB",
+                "OpenJDK-assembly-exception-1.0",
                 ".template.txt"
             )),
-            Self::BSDAttributionHPNDDisclaimer => include_str!(concat!(
+            Self::PcreTwoException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-Attribution-HPND-disclaimer",
+                "PCRE2-exception",
                 ".template.txt"
             )),
-            Self::FmtException => include_str!(concat!(
+            Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "fmt-exception",
+                "PS-or-PDF-font-exception-20170817",
                 ".template.txt"
             )),
-            Self::MITFeh => include_str!(concat!(
+            Self::QPLOneDotZeroINRIATwoZeroZeroFourException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MIT-feh",
+                "QPL-1.0-INRIA-2004-exception",
                 ".template.txt"
             )),
-            Self::RubyPty => include_str!(concat!(
+            Self::QtGPLExceptionOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Ruby-pty",
+                "Qt-GPL-exception-1.0",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseNoMilitaryLicense => include_str!(concat!(
+            Self::QtLGPLExceptionOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-No-Military-License",
+                "Qt-LGPL-exception-1.1",
                 ".template.txt"
             )),
-            Self::HPNDSellVariant => include_str!(concat!(
+            Self::QwtExceptionOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-sell-variant",
+                "Qwt-exception-1.0",
                 ".template.txt"
             )),
-            Self::DECThreeClause => include_str!(concat!(
+            Self::RrDtoolFLOSSExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "DEC-3-Clause",
+                "RRDtool-FLOSS-exception-2.0",
                 ".template.txt"
             )),
-            Self::ZPLOneDotOne => include_str!(concat!(
+            Self::SANEException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ZPL-1.1",
+                "SANE-exception",
                 ".template.txt"
             )),
-            Self::Zlib => include_str!(concat!(
+            Self::SHLTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Zlib",
+                "SHL-2.0",
                 ".template.txt"
             )),
-            Self::FTL => include_str!(concat!(
+            Self::SHLTwoDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FTL",
+                "SHL-2.1",
                 ".template.txt"
             )),
-            Self::CCBYSAThreeDotZeroDE => include_str!(concat!(
+            Self::SWIException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-SA-3.0-DE",
+                "SWI-exception",
                 ".template.txt"
             )),
-            Self::CCBYNCNDThreeDotZero => include_str!(concat!(
+            Self::SwiftException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-NC-ND-3.0",
+                "Swift-exception",
                 ".template.txt"
             )),
-            Self::TOSL => include_str!(concat!(
+            Self::TexinfoException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TOSL",
+                "Texinfo-exception",
                 ".template.txt"
             )),
-            Self::FBM => include_str!(concat!(
+            Self::UBDLException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "FBM",
+                "UBDL-exception",
                 ".template.txt"
             )),
-            Self::CCBYNDThreeDotZero => include_str!(concat!(
+            Self::UniversalFOSSExceptionOneDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CC-BY-ND-3.0",
+                "Universal-FOSS-exception-1.0",
                 ".template.txt"
             )),
-            Self::CUDAOneDotZero => include_str!(concat!(
+            Self::WxWindowsExceptionThreeDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "C-UDA-1.0",
+                "WxWindows-exception-3.1",
                 ".template.txt"
             )),
-            Self::QtGPLExceptionOneDotZero => include_str!(concat!(
+            Self::CryptsetupOpenSslException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Qt-GPL-exception-1.0",
+                "cryptsetup-OpenSSL-exception",
                 ".template.txt"
             )),
-            Self::SunPPPTwoZeroZeroZero => include_str!(concat!(
+            Self::DeprecatedNokiaQtExceptionOneDotOne => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Sun-PPP-2000",
+                "deprecated_Nokia-Qt-exception-1.1",
                 ".template.txt"
             )),
-            Self::OpenVision => include_str!(concat!(
+            Self::ECosExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "OpenVision",
+                "eCos-exception-2.0",
                 ".template.txt"
             )),
-            Self::DeprecatedGFDLOneDotOne => include_str!(concat!(
+            Self::ErlangOtpLinkingException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "deprecated_GFDL-1.1",
+                "erlang-otp-linking-exception",
                 ".template.txt"
             )),
-            Self::SshKeyscan => include_str!(concat!(
+            Self::FmtException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "ssh-keyscan",
+                "fmt-exception",
                 ".template.txt"
             )),
-            Self::SSHShort => include_str!(concat!(
+            Self::FreertosExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "SSH-short",
+                "freertos-exception-2.0",
                 ".template.txt"
             )),
-            Self::GSoapOneDotThreeb => include_str!(concat!(
+            Self::GnuJavamailException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "gSOAP-1.3b",
+                "gnu-javamail-exception",
                 ".template.txt"
             )),
-            Self::JPNIC => include_str!(concat!(
+            Self::HarbourException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "JPNIC",
+                "harbour-exception",
                 ".template.txt"
             )),
-            Self::GFDLOneDotOneNoInvariantsOnly => include_str!(concat!(
+            Self::ITwopGplJavaException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "GFDL-1.1-no-invariants-only",
+                "i2p-gpl-java-exception",
                 ".template.txt"
             )),
-            Self::TAPROHLOneDotZero => include_str!(concat!(
+            Self::LibpriOpenHThreeTwoThreeException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "TAPR-OHL-1.0",
+                "libpri-OpenH323-exception",
                 ".template.txt"
             )),
-            Self::Rdisc => include_str!(concat!(
+            Self::MifException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Rdisc",
+                "mif-exception",
                 ".template.txt"
             )),
-            Self::BSDTwoClauseViews => include_str!(concat!(
+            Self::MxmlException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-2-Clause-Views",
+                "mxml-exception",
                 ".template.txt"
             )),
-            Self::ArtisticOneDotZeroClEight => include_str!(concat!(
+            Self::OpenvpnOpensslException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "Artistic-1.0-cl8",
+                "openvpn-openssl-exception",
                 ".template.txt"
             )),
-            Self::HPNDDoc => include_str!(concat!(
+            Self::PolyparseException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "HPND-doc",
+                "polyparse-exception",
                 ".template.txt"
             )),
-            Self::BSDThreeClauseSun => include_str!(concat!(
+            Self::RomicException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "BSD-3-Clause-Sun",
+                "romic-exception",
                 ".template.txt"
             )),
-            Self::URTRLE => include_str!(concat!(
+            Self::StunnelException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "URT-RLE",
+                "stunnel-exception",
                 ".template.txt"
             )),
-            Self::MpichTwo => include_str!(concat!(
+            Self::UBootExceptionTwoDotZero => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "mpich2",
+                "u-boot-exception-2.0",
                 ".template.txt"
             )),
-            Self::CGALLinkingException => include_str!(concat!(
+            Self::VsftpdOpensslException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "CGAL-linking-exception",
+                "vsftpd-openssl-exception",
                 ".template.txt"
             )),
-            Self::MPLOneDotOne => include_str!(concat!(
+            Self::XOneOnevncOpensslException => include_str!(concat!(
                 env!("CARGO_MANIFEST_DIR"),
                 "/assets/licenses/",
-                "MPL-1.1",
+                "x11vnc-openssl-exception",
                 ".template.txt"
             )),
         }
     }
 }
-impl std::fmt::Display for License {
+impl std::fmt::Display for LicenseException {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.spdx_id())
     }
 }
-impl std::str::FromStr for License {
+impl std::str::FromStr for LicenseException {
     type Err = ParseLicenseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "AFL-1.2" => Ok(Self::AFLOneDotTwo),
-            "Linux-syscall-note" => Ok(Self::LinuxSyscallNote),
-            "DocBook-Stylesheet" => Ok(Self::DocBookStylesheet),
-            "HPND-sell-MIT-disclaimer-xserver" => Ok(Self::HPNDSellMITDisclaimerXserver),
-            "OSL-2.1" => Ok(Self::OSLTwoDotOne),
-            "SWL" => Ok(Self::SWL),
-            "CC-BY-NC-4.0" => Ok(Self::CCBYNCFourDotZero),
-            "NLOD-2.0" => Ok(Self::NLODTwoDotZero),
-            "Unlicense" => Ok(Self::Unlicense),
-            "ODC-By-1.0" => Ok(Self::ODCByOneDotZero),
-            "UPL-1.0" => Ok(Self::UPLOneDotZero),
-            "SNIA" => Ok(Self::SNIA),
-            "GCC-exception-3.1" => Ok(Self::GCCExceptionThreeDotOne),
-            "OLDAP-1.3" => Ok(Self::OLDAPOneDotThree),
-            "Dotseqn" => Ok(Self::Dotseqn),
-            "TMate" => Ok(Self::TMate),
-            "GFDL-1.1-invariants-or-later" => Ok(Self::GFDLOneDotOneInvariantsOrLater),
-            "MMIXware" => Ok(Self::MmiXware),
-            "Crossword" => Ok(Self::Crossword),
-            "OLDAP-1.4" => Ok(Self::OLDAPOneDotFour),
-            "GLWTPL" => Ok(Self::GLWTPL),
-            "curl" => Ok(Self::Curl),
-            "GPL-2.0-or-later" => Ok(Self::GPLTwoDotZeroOrLater),
-            "OFL-1.1-no-RFN" => Ok(Self::OFLOneDotOneNoRFN),
-            "MS-LPL" => Ok(Self::MSLPL),
-            "CC-BY-1.0" => Ok(Self::CCBYOneDotZero),
-            "CDLA-Permissive-1.0" => Ok(Self::CDLAPermissiveOneDotZero),
-            "QPL-1.0-INRIA-2004-exception" => Ok(Self::QPLOneDotZeroINRIATwoZeroZeroFourException),
-            "Classpath-exception-2.0" => Ok(Self::ClasspathExceptionTwoDotZero),
-            "BSD-3-Clause" => Ok(Self::BSDThreeClause),
-            "OPL-1.0" => Ok(Self::OPLOneDotZero),
-            "ODbL-1.0" => Ok(Self::ODbLOneDotZero),
-            "Independent-modules-exception" => Ok(Self::IndependentModulesException),
-            "MIT-Festival" => Ok(Self::MITFestival),
-            "Caldera-no-preamble" => Ok(Self::CalderaNoPreamble),
-            "SGI-B-1.1" => Ok(Self::SGIBOneDotOne),
-            "Soundex" => Ok(Self::Soundex),
-            "SANE-exception" => Ok(Self::SANEException),
-            "MirOS" => Ok(Self::MirOs),
-            "Ruby" => Ok(Self::Ruby),
-            "polyparse-exception" => Ok(Self::PolyparseException),
-            "BSD-3-Clause-LBNL" => Ok(Self::BSDThreeClauseLBNL),
-            "BSD-2-Clause" => Ok(Self::BSDTwoClause),
-            "EFL-2.0" => Ok(Self::EFLTwoDotZero),
-            "LLGPL" => Ok(Self::LLGPL),
-            "vsftpd-openssl-exception" => Ok(Self::VsftpdOpensslException),
-            "OFL-1.0-no-RFN" => Ok(Self::OFLOneDotZeroNoRFN),
-            "CC-BY-NC-ND-3.0-DE" => Ok(Self::CCBYNCNDThreeDotZeroDE),
-            "Artistic-2.0" => Ok(Self::ArtisticTwoDotZero),
-            "CDDL-1.0" => Ok(Self::CDDLOneDotZero),
-            "SWI-exception" => Ok(Self::SWIException),
-            "GFDL-1.3-invariants-only" => Ok(Self::GFDLOneDotThreeInvariantsOnly),
-            "MS-RL" => Ok(Self::MSRL),
-            "ASWF-Digital-Assets-1.1" => Ok(Self::ASWFDigitalAssetsOneDotOne),
-            "LGPL-2.1-only" => Ok(Self::LGPLTwoDotOneOnly),
-            "HP-1986" => Ok(Self::HPOneNineEightSix),
-            "Python-2.0.1" => Ok(Self::PythonTwoDotZeroDotOne),
-            "Abstyles" => Ok(Self::Abstyles),
-            "RHeCos-1.1" => Ok(Self::RHeCosOneDotOne),
-            "MIT" => Ok(Self::MIT),
-            "BSD-1-Clause" => Ok(Self::BSDOneClause),
-            "romic-exception" => Ok(Self::RomicException),
-            "OSL-3.0" => Ok(Self::OSLThreeDotZero),
-            "CC-BY-NC-3.0" => Ok(Self::CCBYNCThreeDotZero),
-            "GCC-exception-2.0" => Ok(Self::GCCExceptionTwoDotZero),
-            "deprecated_LGPL-3.0+" => Ok(Self::DeprecatedLGPLThreeDotZeroPlus),
-            "Clips" => Ok(Self::Clips),
-            "BSD-2-Clause-Darwin" => Ok(Self::BSDTwoClauseDarwin),
-            "CNRI-Python-GPL-Compatible" => Ok(Self::CNRIPythonGPLCompatible),
-            "LPD-document" => Ok(Self::LPDDocument),
-            "GPL-CC-1.0" => Ok(Self::GPLCCOneDotZero),
-            "BSD-3-Clause-flex" => Ok(Self::BSDThreeClauseFlex),
-            "HPND-UC" => Ok(Self::HPNDUC),
-            "ISC-Veillard" => Ok(Self::ISCVeillard),
-            "PDDL-1.0" => Ok(Self::PDDLOneDotZero),
-            "LGPL-2.0-or-later" => Ok(Self::LGPLTwoDotZeroOrLater),
-            "CC-BY-NC-SA-3.0-IGO" => Ok(Self::CCBYNCSAThreeDotZeroIGO),
-            "TORQUE-1.1" => Ok(Self::TORQUEOneDotOne),
-            "Frameworx-1.0" => Ok(Self::FrameworxOneDotZero),
-            "OLDAP-2.8" => Ok(Self::OLDAPTwoDotEight),
-            "deprecated_GPL-3.0" => Ok(Self::DeprecatedGPLThreeDotZero),
-            "Qwt-exception-1.0" => Ok(Self::QwtExceptionOneDotZero),
-            "DocBook-DTD" => Ok(Self::DocBookDTD),
-            "psfrag" => Ok(Self::Psfrag),
-            "IJG-short" => Ok(Self::IJGShort),
-            "psutils" => Ok(Self::Psutils),
-            "DRL-1.1" => Ok(Self::DRLOneDotOne),
-            "Boehm-GC" => Ok(Self::BoehmGC),
-            "CECILL-2.0" => Ok(Self::CECILLTwoDotZero),
-            "W3C-19980720" => Ok(Self::WThreeCOneNineNineEightZeroSevenTwoZero),
-            "O-UDA-1.0" => Ok(Self::OUDAOneDotZero),
-            "CLISP-exception-2.0" => Ok(Self::CLISPExceptionTwoDotZero),
-            "DL-DE-ZERO-2.0" => Ok(Self::DLDEZEROTwoDotZero),
-            "deprecated_GFDL-1.3" => Ok(Self::DeprecatedGFDLOneDotThree),
-            "SGP4" => Ok(Self::SgpFour),
-            "FDK-AAC" => Ok(Self::FDKAAC),
-            "Apache-1.1" => Ok(Self::ApacheOneDotOne),
-            "TTWL" => Ok(Self::TTWL),
-            "Martin-Birgmeier" => Ok(Self::MartinBirgmeier),
-            "CC-BY-ND-3.0-DE" => Ok(Self::CCBYNDThreeDotZeroDE),
-            "ECL-2.0" => Ok(Self::ECLTwoDotZero),
-            "ICU" => Ok(Self::ICU),
-            "stunnel-exception" => Ok(Self::StunnelException),
-            "Game-Programming-Gems" => Ok(Self::GameProgrammingGems),
-            "BSD-3-Clause-Attribution" => Ok(Self::BSDThreeClauseAttribution),
-            "SGI-B-1.0" => Ok(Self::SGIBOneDotZero),
-            "NRL" => Ok(Self::NRL),
-            "Kazlib" => Ok(Self::Kazlib),
-            "PHP-3.01" => Ok(Self::PHPThreeDotZeroOne),
-            "magaz" => Ok(Self::Magaz),
-            "CPAL-1.0" => Ok(Self::CPALOneDotZero),
-            "App-s2p" => Ok(Self::AppSTwop),
-            "RRDtool-FLOSS-exception-2.0" => Ok(Self::RrDtoolFLOSSExceptionTwoDotZero),
-            "Libtool-exception" => Ok(Self::LibtoolException),
-            "PS-or-PDF-font-exception-20170817" => {
-                Ok(Self::PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven)
-            }
-            "Brian-Gladman-2-Clause" => Ok(Self::BrianGladmanTwoClause),
-            "deprecated_GPL-2.0" => Ok(Self::DeprecatedGPLTwoDotZero),
-            "LGPL-3.0-only" => Ok(Self::LGPLThreeDotZeroOnly),
-            "DigiRule-FOSS-exception" => Ok(Self::DigiRuleFOSSException),
-            "GPL-3.0-linking-exception" => Ok(Self::GPLThreeDotZeroLinkingException),
-            "ASWF-Digital-Assets-1.0" => Ok(Self::ASWFDigitalAssetsOneDotZero),
-            "mailprio" => Ok(Self::Mailprio),
-            "LPPL-1.2" => Ok(Self::LPPLOneDotTwo),
-            "CDDL-1.1" => Ok(Self::CDDLOneDotOne),
-            "HPND" => Ok(Self::HPND),
-            "NTP-0" => Ok(Self::NTPZero),
-            "CC-BY-ND-2.5" => Ok(Self::CCBYNDTwoDotFive),
-            "CC-BY-NC-ND-2.5" => Ok(Self::CCBYNCNDTwoDotFive),
-            "freertos-exception-2.0" => Ok(Self::FreertosExceptionTwoDotZero),
-            "Brian-Gladman-3-Clause" => Ok(Self::BrianGladmanThreeClause),
-            "deprecated_GPL-2.0-with-bison-exception" => {
-                Ok(Self::DeprecatedGPLTwoDotZeroWithBisonException)
-            }
-            "SugarCRM-1.1.3" => Ok(Self::SugarCrmOneDotOneDotThree),
-            "SISSL" => Ok(Self::SISSL),
-            "CFITSIO" => Ok(Self::CFITSIO),
-            "TU-Berlin-2.0" => Ok(Self::TUBerlinTwoDotZero),
-            "CMU-Mach-nodoc" => Ok(Self::CMUMachNodoc),
-            "Condor-1.1" => Ok(Self::CondorOneDotOne),
-            "OSL-2.0" => Ok(Self::OSLTwoDotZero),
-            "CC-BY-NC-2.0" => Ok(Self::CCBYNCTwoDotZero),
-            "AdaCore-doc" => Ok(Self::AdaCoreDoc),
-            "TermReadKey" => Ok(Self::TermReadKey),
-            "UnixCrypt" => Ok(Self::UnixCrypt),
-            "jove" => Ok(Self::Jove),
-            "NTP" => Ok(Self::NTP),
-            "Adobe-Display-PostScript" => Ok(Self::AdobeDisplayPostScript),
-            "CC-BY-3.0-DE" => Ok(Self::CCBYThreeDotZeroDE),
-            "Kastrup" => Ok(Self::Kastrup),
-            "LGPL-2.0-only" => Ok(Self::LGPLTwoDotZeroOnly),
-            "OLDAP-1.2" => Ok(Self::OLDAPOneDotTwo),
-            "Linux-man-pages-1-para" => Ok(Self::LinuxManPagesOnePara),
-            "DL-DE-BY-2.0" => Ok(Self::DLDEBYTwoDotZero),
-            "CC-BY-NC-3.0-DE" => Ok(Self::CCBYNCThreeDotZeroDE),
-            "FreeImage" => Ok(Self::FreeImage),
-            "CECILL-2.1" => Ok(Self::CECILLTwoDotOne),
-            "DRL-1.0" => Ok(Self::DRLOneDotZero),
-            "HPND-export-US-acknowledgement" => Ok(Self::HPNDExportUSAcknowledgement),
-            "OLDAP-2.0.1" => Ok(Self::OLDAPTwoDotZeroDotOne),
-            "threeparttable" => Ok(Self::Threeparttable),
-            "MIT-open-group" => Ok(Self::MITOpenGroup),
-            "FSFULLR" => Ok(Self::FSFULLR),
-            "Autoconf-exception-macro" => Ok(Self::AutoconfExceptionMacro),
-            "NCL" => Ok(Self::NCL),
-            "EPICS" => Ok(Self::EPICS),
-            "Apache-1.0" => Ok(Self::ApacheOneDotZero),
-            "JSON" => Ok(Self::JSON),
-            "deprecated_GFDL-1.2" => Ok(Self::DeprecatedGFDLOneDotTwo),
-            "Spencer-86" => Ok(Self::SpencerEightSix),
-            "BSD-4-Clause" => Ok(Self::BSDFourClause),
-            "SHL-0.51" => Ok(Self::SHLZeroDotFiveOne),
-            "IJG" => Ok(Self::IJG),
-            "APSL-1.2" => Ok(Self::APSLOneDotTwo),
-            "deprecated_GPL-2.0+" => Ok(Self::DeprecatedGPLTwoDotZeroPlus),
-            "erlang-otp-linking-exception" => Ok(Self::ErlangOtpLinkingException),
-            "SAX-PD" => Ok(Self::SAXPD),
-            "PSF-2.0" => Ok(Self::PSFTwoDotZero),
-            "BSD-3-Clause-Modification" => Ok(Self::BSDThreeClauseModification),
-            "QPL-1.0-INRIA-2004" => Ok(Self::QPLOneDotZeroINRIATwoZeroZeroFour),
-            "deprecated_GPL-2.0-with-classpath-exception" => {
-                Ok(Self::DeprecatedGPLTwoDotZeroWithClasspathException)
-            }
-            "EUPL-1.2" => Ok(Self::EUPLOneDotTwo),
-            "BSD-2-Clause-first-lines" => Ok(Self::BSDTwoClauseFirstLines),
-            "GNOME-examples-exception" => Ok(Self::GNOMEExamplesException),
-            "X11-distribute-modifications-variant" => {
-                Ok(Self::XOneOneDistributeModificationsVariant)
-            }
-            "LPL-1.0" => Ok(Self::LPLOneDotZero),
-            "Spencer-94" => Ok(Self::SpencerNineFour),
-            "CC-BY-NC-SA-2.5" => Ok(Self::CCBYNCSATwoDotFive),
-            "u-boot-exception-2.0" => Ok(Self::UBootExceptionTwoDotZero),
-            "CC-BY-SA-2.5" => Ok(Self::CCBYSATwoDotFive),
-            "Newsletr" => Ok(Self::Newsletr),
-            "Noweb" => Ok(Self::Noweb),
-            "APL-1.0" => Ok(Self::APLOneDotZero),
-            "w3m" => Ok(Self::WThreem),
-            "FSL-1.1-MIT" => Ok(Self::FSLOneDotOneMIT),
-            "Texinfo-exception" => Ok(Self::TexinfoException),
-            "GFDL-1.2-no-invariants-or-later" => Ok(Self::GFDLOneDotTwoNoInvariantsOrLater),
-            "Artistic-1.0" => Ok(Self::ArtisticOneDotZero),
-            "EFL-1.0" => Ok(Self::EFLOneDotZero),
-            "Watcom-1.0" => Ok(Self::WatcomOneDotZero),
-            "CC-BY-3.0-AU" => Ok(Self::CCBYThreeDotZeroAU),
-            "ISC" => Ok(Self::ISC),
-            "zlib-acknowledgement" => Ok(Self::ZlibAcknowledgement),
-            "TCP-wrappers" => Ok(Self::TCPWrappers),
-            "Universal-FOSS-exception-1.0" => Ok(Self::UniversalFOSSExceptionOneDotZero),
-            "hdparm" => Ok(Self::Hdparm),
-            "SHL-2.0" => Ok(Self::SHLTwoDotZero),
-            "OLDAP-2.2.1" => Ok(Self::OLDAPTwoDotTwoDotOne),
-            "GFDL-1.3-no-invariants-only" => Ok(Self::GFDLOneDotThreeNoInvariantsOnly),
-            "eGenix" => Ok(Self::EGenix),
-            "AGPL-1.0-only" => Ok(Self::AGPLOneDotZeroOnly),
-            "FSFAP" => Ok(Self::FSFAP),
-            "Spencer-99" => Ok(Self::SpencerNineNine),
-            "OLDAP-2.3" => Ok(Self::OLDAPTwoDotThree),
-            "Gmsh-exception" => Ok(Self::GmshException),
-            "dvipdfm" => Ok(Self::Dvipdfm),
-            "deprecated_Net-SNMP" => Ok(Self::DeprecatedNetSNMP),
-            "LZMA-exception" => Ok(Self::LZMAException),
-            "Libpng" => Ok(Self::Libpng),
-            "Xnet" => Ok(Self::Xnet),
-            "HPND-merchantability-variant" => Ok(Self::HPNDMerchantabilityVariant),
-            "Intel" => Ok(Self::Intel),
-            "OLDAP-2.4" => Ok(Self::OLDAPTwoDotFour),
-            "W3C-20150513" => Ok(Self::WThreeCTwoZeroOneFiveZeroFiveOneThree),
-            "MIT-Khronos-old" => Ok(Self::MITKhronosOld),
-            "CDLA-Permissive-2.0" => Ok(Self::CDLAPermissiveTwoDotZero),
-            "CC-BY-2.0" => Ok(Self::CCBYTwoDotZero),
-            "mpi-permissive" => Ok(Self::MpiPermissive),
-            "deprecated_GPL-2.0-with-autoconf-exception" => {
-                Ok(Self::DeprecatedGPLTwoDotZeroWithAutoconfException)
-            }
-            "MIPS" => Ok(Self::MIPS),
-            "Cube" => Ok(Self::Cube),
-            "OSL-1.1" => Ok(Self::OSLOneDotOne),
-            "MPL-2.0-no-copyleft-exception" => Ok(Self::MPLTwoDotZeroNoCopyleftException),
-            "NLOD-1.0" => Ok(Self::NLODOneDotZero),
-            "GFDL-1.1-or-later" => Ok(Self::GFDLOneDotOneOrLater),
-            "Widget-Workshop" => Ok(Self::WidgetWorkshop),
-            "CATOSL-1.1" => Ok(Self::CATOSLOneDotOne),
-            "cryptsetup-OpenSSL-exception" => Ok(Self::CryptsetupOpenSslException),
-            "BSD-4.3RENO" => Ok(Self::BSDFourDotThreeReno),
-            "BSD-Systemics" => Ok(Self::BSDSystemics),
-            "Boehm-GC-without-fee" => Ok(Self::BoehmGCWithoutFee),
-            "GCC-exception-2.0-note" => Ok(Self::GCCExceptionTwoDotZeroNote),
-            "AGPL-3.0-only" => Ok(Self::AGPLThreeDotZeroOnly),
-            "BSD-Systemics-W3Works" => Ok(Self::BSDSystemicsWThreeWorks),
-            "BSD-3-Clause-Open-MPI" => Ok(Self::BSDThreeClauseOpenMPI),
-            "OCLC-2.0" => Ok(Self::OCLCTwoDotZero),
-            "LAL-1.2" => Ok(Self::LALOneDotTwo),
-            "Xdebug-1.03" => Ok(Self::XdebugOneDotZeroThree),
-            "FSL-1.1-ALv2" => Ok(Self::FSLOneDotOneALvTwo),
-            "HPND-UC-export-US" => Ok(Self::HPNDUCExportUS),
-            "ECL-1.0" => Ok(Self::ECLOneDotZero),
-            "CC-BY-NC-ND-3.0-IGO" => Ok(Self::CCBYNCNDThreeDotZeroIGO),
-            "OGL-Canada-2.0" => Ok(Self::OGLCanadaTwoDotZero),
-            "AGPL-1.0-or-later" => Ok(Self::AGPLOneDotZeroOrLater),
-            "LGPLLR" => Ok(Self::LGPLLR),
-            "Fair" => Ok(Self::Fair),
-            "Swift-exception" => Ok(Self::SwiftException),
-            "JasPer-2.0" => Ok(Self::JasPerTwoDotZero),
-            "CECILL-1.0" => Ok(Self::CECILLOneDotZero),
-            "GPL-3.0-linking-source-exception" => Ok(Self::GPLThreeDotZeroLinkingSourceException),
-            "BSD-Source-Code" => Ok(Self::BSDSourceCode),
-            "metamail" => Ok(Self::Metamail),
-            "LLVM-exception" => Ok(Self::LLVMException),
-            "VSL-1.0" => Ok(Self::VSLOneDotZero),
-            "LPPL-1.3c" => Ok(Self::LPPLOneDotThreec),
-            "Furuseth" => Ok(Self::Furuseth),
-            "BSD-3-Clause-acpica" => Ok(Self::BSDThreeClauseAcpica),
-            "CNRI-Jython" => Ok(Self::CNRIJython),
-            "LiLiQ-P-1.1" => Ok(Self::LiLiQPOneDotOne),
-            "Ferguson-Twofish" => Ok(Self::FergusonTwofish),
-            "HPND-INRIA-IMAG" => Ok(Self::HPNDINRIAIMAG),
-            "Bitstream-Charter" => Ok(Self::BitstreamCharter),
-            "Unicode-DFS-2016" => Ok(Self::UnicodeDFSTwoZeroOneSix),
-            "MIT-Modern-Variant" => Ok(Self::MITModernVariant),
-            "deprecated_GPL-3.0+" => Ok(Self::DeprecatedGPLThreeDotZeroPlus),
-            "Afmparse" => Ok(Self::Afmparse),
-            "HPND-Fenneberg-Livingston" => Ok(Self::HPNDFennebergLivingston),
-            "wwl" => Ok(Self::Wwl),
-            "ClArtistic" => Ok(Self::ClArtistic),
-            "HPND-Markus-Kuhn" => Ok(Self::HPNDMarkusKuhn),
-            "blessing" => Ok(Self::Blessing),
-            "softSurfer" => Ok(Self::SoftSurfer),
-            "Bison-exception-1.24" => Ok(Self::BisonExceptionOneDotTwoFour),
-            "CrystalStacker" => Ok(Self::CrystalStacker),
-            "AML" => Ok(Self::AML),
-            "NCBI-PD" => Ok(Self::NCBIPD),
-            "GFDL-1.2-invariants-or-later" => Ok(Self::GFDLOneDotTwoInvariantsOrLater),
-            "deprecated_eCos-2.0" => Ok(Self::DeprecatedECosTwoDotZero),
-            "OLDAP-2.5" => Ok(Self::OLDAPTwoDotFive),
-            "AMPAS" => Ok(Self::AMPAS),
-            "GFDL-1.1-no-invariants-or-later" => Ok(Self::GFDLOneDotOneNoInvariantsOrLater),
-            "CC-BY-4.0" => Ok(Self::CCBYFourDotZero),
-            "OLDAP-2.2" => Ok(Self::OLDAPTwoDotTwo),
-            "CNRI-Python" => Ok(Self::CNRIPython),
-            "BSL-1.0" => Ok(Self::BSLOneDotZero),
-            "SMAIL-GPL" => Ok(Self::SMAILGPL),
-            "TU-Berlin-1.0" => Ok(Self::TUBerlinOneDotZero),
-            "VOSTROM" => Ok(Self::VOSTROM),
-            "libpng-2.0" => Ok(Self::LibpngTwoDotZero),
-            "gnu-javamail-exception" => Ok(Self::GnuJavamailException),
-            "CC-BY-NC-1.0" => Ok(Self::CCBYNCOneDotZero),
-            "GPL-3.0-389-ds-base-exception" => {
-                Ok(Self::GPLThreeDotZeroThreeEightNineDsBaseException)
-            }
-            "OSL-1.0" => Ok(Self::OSLOneDotZero),
-            "Unicode-3.0" => Ok(Self::UnicodeThreeDotZero),
-            "GFDL-1.3-or-later" => Ok(Self::GFDLOneDotThreeOrLater),
-            "InnoSetup" => Ok(Self::InnoSetup),
-            "CAL-1.0" => Ok(Self::CALOneDotZero),
-            "CC-BY-SA-3.0-IGO" => Ok(Self::CCBYSAThreeDotZeroIGO),
-            "Saxpath" => Ok(Self::Saxpath),
-            "OPUBL-1.0" => Ok(Self::OPUBLOneDotZero),
-            "Linux-man-pages-copyleft-2-para" => Ok(Self::LinuxManPagesCopyleftTwoPara),
-            "CC-BY-3.0-AT" => Ok(Self::CCBYThreeDotZeroAT),
-            "GNAT-exception" => Ok(Self::GNATException),
-            "CERN-OHL-P-2.0" => Ok(Self::CERNOHLPTwoDotZero),
-            "x11vnc-openssl-exception" => Ok(Self::XOneOnevncOpensslException),
-            "FSFUL" => Ok(Self::FSFUL),
-            "OPL-UK-3.0" => Ok(Self::OPLUKThreeDotZero),
-            "UMich-Merit" => Ok(Self::UMichMerit),
-            "Cornell-Lossless-JPEG" => Ok(Self::CornellLosslessJPEG),
-            "OGDL-Taiwan-1.0" => Ok(Self::OGDLTaiwanOneDotZero),
-            "BSD-3-Clause-HP" => Ok(Self::BSDThreeClauseHP),
-            "Plexus" => Ok(Self::Plexus),
-            "bcrypt-Solar-Designer" => Ok(Self::BcryptSolarDesigner),
-            "CC-BY-NC-SA-2.0-UK" => Ok(Self::CCBYNCSATwoDotZeroUK),
-            "SGI-B-2.0" => Ok(Self::SGIBTwoDotZero),
-            "CC-BY-3.0-IGO" => Ok(Self::CCBYThreeDotZeroIGO),
-            "Hippocratic-2.1" => Ok(Self::HippocraticTwoDotOne),
-            "SHL-2.1" => Ok(Self::SHLTwoDotOne),
-            "KiCad-libraries-exception" => Ok(Self::KiCadLibrariesException),
-            "CPOL-1.02" => Ok(Self::CPOLOneDotZeroTwo),
-            "deprecated_GPL-1.0" => Ok(Self::DeprecatedGPLOneDotZero),
-            "Cronyx" => Ok(Self::Cronyx),
-            "Latex2e-translated-notice" => Ok(Self::LatexTwoeTranslatedNotice),
-            "Knuth-CTAN" => Ok(Self::KnuthCTAN),
-            "CC-BY-3.0" => Ok(Self::CCBYThreeDotZero),
-            "bzip2-1.0.6" => Ok(Self::BzipTwoOneDotZeroDotSix),
-            "OCCT-PL" => Ok(Self::OCCTPL),
-            "Sendmail-8.23" => Ok(Self::SendmailEightDotTwoThree),
-            "Catharon" => Ok(Self::Catharon),
-            "IPL-1.0" => Ok(Self::IPLOneDotZero),
-            "deprecated_GPL-2.0-with-font-exception" => {
-                Ok(Self::DeprecatedGPLTwoDotZeroWithFontException)
-            }
-            "APAFML" => Ok(Self::APAFML),
-            "Motosoto" => Ok(Self::Motosoto),
-            "check-cvs" => Ok(Self::CheckCvs),
-            "Sendmail" => Ok(Self::Sendmail),
-            "PPL" => Ok(Self::PPL),
-            "PostgreSQL" => Ok(Self::PostgreSql),
-            "CDL-1.0" => Ok(Self::CDLOneDotZero),
-            "GPL-1.0-or-later" => Ok(Self::GPLOneDotZeroOrLater),
-            "CC-SA-1.0" => Ok(Self::CCSAOneDotZero),
-            "IBM-pibs" => Ok(Self::IBMPibs),
-            "CERN-OHL-1.2" => Ok(Self::CERNOHLOneDotTwo),
-            "Intel-ACPI" => Ok(Self::IntelACPI),
-            "Adobe-2006" => Ok(Self::AdobeTwoZeroZeroSix),
-            "deprecated_LGPL-2.0+" => Ok(Self::DeprecatedLGPLTwoDotZeroPlus),
-            "SPL-1.0" => Ok(Self::SPLOneDotZero),
-            "OML" => Ok(Self::OML),
-            "DOC" => Ok(Self::DOC),
-            "MIT-Click" => Ok(Self::MITClick),
-            "mxml-exception" => Ok(Self::MxmlException),
-            "Apache-2.0" => Ok(Self::ApacheTwoDotZero),
-            "NCGL-UK-2.0" => Ok(Self::NCGLUKTwoDotZero),
-            "HTMLTIDY" => Ok(Self::HTMLTIDY),
-            "ANTLR-PD" => Ok(Self::ANTLRPD),
-            "LAL-1.3" => Ok(Self::LALOneDotThree),
-            "BSD-Inferno-Nettverk" => Ok(Self::BSDInfernoNettverk),
-            "RPL-1.5" => Ok(Self::RPLOneDotFive),
-            "CC0-1.0" => Ok(Self::CcZeroOneDotZero),
-            "CECILL-1.1" => Ok(Self::CECILLOneDotOne),
-            "AML-glslang" => Ok(Self::AMLGlslang),
-            "Digia-Qt-LGPL-exception-1.1" => Ok(Self::DigiaQtLGPLExceptionOneDotOne),
-            "GCR-docs" => Ok(Self::GCRDocs),
-            "Glide" => Ok(Self::Glide),
-            "CC-BY-SA-2.0-UK" => Ok(Self::CCBYSATwoDotZeroUK),
-            "Glulxe" => Ok(Self::Glulxe),
-            "MIT-0" => Ok(Self::MITZero),
-            "BSD-4-Clause-UC" => Ok(Self::BSDFourClauseUC),
-            "deprecated_LGPL-2.1" => Ok(Self::DeprecatedLGPLTwoDotOne),
-            "AFL-2.1" => Ok(Self::AFLTwoDotOne),
-            "eCos-exception-2.0" => Ok(Self::ECosExceptionTwoDotZero),
-            "CERN-OHL-W-2.0" => Ok(Self::CERNOHLWTwoDotZero),
-            "Minpack" => Ok(Self::Minpack),
-            "HPND-Netrek" => Ok(Self::HPNDNetrek),
-            "DocBook-Schema" => Ok(Self::DocBookSchema),
-            "man2html" => Ok(Self::ManTwohtml),
-            "LGPL-2.1-or-later" => Ok(Self::LGPLTwoDotOneOrLater),
-            "CMU-Mach" => Ok(Self::CMUMach),
-            "OLDAP-2.0" => Ok(Self::OLDAPTwoDotZero),
-            "Borceux" => Ok(Self::Borceux),
-            "PHP-3.0" => Ok(Self::PHPThreeDotZero),
-            "HPND-DEC" => Ok(Self::HPNDDEC),
-            "radvd" => Ok(Self::Radvd),
-            "OGTSL" => Ok(Self::OGTSL),
-            "OLDAP-2.7" => Ok(Self::OLDAPTwoDotSeven),
-            "OGL-UK-2.0" => Ok(Self::OGLUKTwoDotZero),
-            "LGPL-3.0-linking-exception" => Ok(Self::LGPLThreeDotZeroLinkingException),
-            "TTYP0" => Ok(Self::TtypZero),
-            "Zed" => Ok(Self::Zed),
-            "PADL" => Ok(Self::PADL),
-            "OFL-1.1-RFN" => Ok(Self::OFLOneDotOneRFN),
-            "OLDAP-2.2.2" => Ok(Self::OLDAPTwoDotTwoDotTwo),
-            "diffmark" => Ok(Self::Diffmark),
-            "W3C" => Ok(Self::WThreeC),
-            "HPND-sell-variant-MIT-disclaimer" => Ok(Self::HPNDSellVariantMITDisclaimer),
-            "Artistic-1.0-Perl" => Ok(Self::ArtisticOneDotZeroPerl),
-            "OCaml-LGPL-linking-exception" => Ok(Self::OCamlLGPLLinkingException),
-            "Python-2.0" => Ok(Self::PythonTwoDotZero),
-            "Mackerras-3-Clause-acknowledgment" => Ok(Self::MackerrasThreeClauseAcknowledgment),
-            "CC-BY-NC-ND-1.0" => Ok(Self::CCBYNCNDOneDotZero),
-            "CC-BY-ND-1.0" => Ok(Self::CCBYNDOneDotZero),
-            "Mup" => Ok(Self::Mup),
-            "TPDL" => Ok(Self::TPDL),
-            "HIDAPI" => Ok(Self::HIDAPI),
-            "Info-ZIP" => Ok(Self::InfoZIP),
-            "deprecated_LGPL-3.0" => Ok(Self::DeprecatedLGPLThreeDotZero),
-            "AFL-3.0" => Ok(Self::AFLThreeDotZero),
-            "HP-1989" => Ok(Self::HPOneNineEightNine),
-            "FSFULLRWD" => Ok(Self::FSFULLRWD),
-            "Leptonica" => Ok(Self::Leptonica),
-            "Graphics-Gems" => Ok(Self::GraphicsGems),
-            "Unicode-DFS-2015" => Ok(Self::UnicodeDFSTwoZeroOneFive),
-            "TrustedQSL" => Ok(Self::TrustedQsl),
-            "APSL-2.0" => Ok(Self::APSLTwoDotZero),
-            "NLPL" => Ok(Self::NLPL),
-            "Giftware" => Ok(Self::Giftware),
-            "SSPL-1.0" => Ok(Self::SSPLOneDotZero),
-            "CC-BY-2.5-AU" => Ok(Self::CCBYTwoDotFiveAU),
-            "HPND-sell-variant-MIT-disclaimer-rev" => Ok(Self::HPNDSellVariantMITDisclaimerRev),
-            "deprecated_GPL-1.0+" => Ok(Self::DeprecatedGPLOneDotZeroPlus),
-            "libtiff" => Ok(Self::Libtiff),
-            "IPA" => Ok(Self::IPA),
-            "CC-BY-SA-1.0" => Ok(Self::CCBYSAOneDotZero),
-            "CC-BY-NC-SA-1.0" => Ok(Self::CCBYNCSAOneDotZero),
-            "ErlPL-1.1" => Ok(Self::ErlPlOneDotOne),
-            "MPL-2.0" => Ok(Self::MPLTwoDotZero),
-            "pkgconf" => Ok(Self::Pkgconf),
-            "BSD-Protection" => Ok(Self::BSDProtection),
-            "GFDL-1.2-no-invariants-only" => Ok(Self::GFDLOneDotTwoNoInvariantsOnly),
-            "Adobe-Glyph" => Ok(Self::AdobeGlyph),
-            "EPL-1.0" => Ok(Self::EPLOneDotZero),
-            "deprecated_LGPL-2.1+" => Ok(Self::DeprecatedLGPLTwoDotOnePlus),
-            "i2p-gpl-java-exception" => Ok(Self::ITwopGplJavaException),
-            "deprecated_GPL-3.0-with-GCC-exception" => {
-                Ok(Self::DeprecatedGPLThreeDotZeroWithGCCException)
-            }
-            "AMD-newlib" => Ok(Self::AMDNewlib),
-            "Jam" => Ok(Self::Jam),
-            "deprecated_AGPL-1.0" => Ok(Self::DeprecatedAGPLOneDotZero),
-            "Baekmuk" => Ok(Self::Baekmuk),
-            "Qhull" => Ok(Self::Qhull),
-            "OpenSSL-standalone" => Ok(Self::OpenSslStandalone),
-            "PCRE2-exception" => Ok(Self::PcreTwoException),
-            "BSD-2-Clause-pkgconf-disclaimer" => Ok(Self::BSDTwoClausePkgconfDisclaimer),
-            "NBPL-1.0" => Ok(Self::NBPLOneDotZero),
-            "MulanPSL-2.0" => Ok(Self::MulanPslTwoDotZero),
-            "CC-BY-3.0-US" => Ok(Self::CCBYThreeDotZeroUS),
-            "Lucida-Bitmap-Fonts" => Ok(Self::LucidaBitmapFonts),
-            "CC-BY-NC-SA-2.0-FR" => Ok(Self::CCBYNCSATwoDotZeroFR),
-            "ANTLR-PD-fallback" => Ok(Self::ANTLRPDFallback),
-            "MIT-advertising" => Ok(Self::MITAdvertising),
-            "HPND-export-US-modify" => Ok(Self::HPNDExportUSModify),
-            "swrule" => Ok(Self::Swrule),
-            "Beerware" => Ok(Self::Beerware),
-            "SMLNJ" => Ok(Self::SMLNJ),
-            "MPEG-SSG" => Ok(Self::MPEGSSG),
-            "PolyForm-Small-Business-1.0.0" => Ok(Self::PolyFormSmallBusinessOneDotZeroDotZero),
-            "AGPL-3.0-or-later" => Ok(Self::AGPLThreeDotZeroOrLater),
-            "BSD-Advertising-Acknowledgement" => Ok(Self::BSDAdvertisingAcknowledgement),
-            "ZPL-2.0" => Ok(Self::ZPLTwoDotZero),
-            "xpp" => Ok(Self::Xpp),
-            "Nokia" => Ok(Self::Nokia),
-            "HPND-Kevlin-Henney" => Ok(Self::HPNDKevlinHenney),
-            "PolyForm-Noncommercial-1.0.0" => Ok(Self::PolyFormNoncommercialOneDotZeroDotZero),
-            "CC-BY-SA-2.1-JP" => Ok(Self::CCBYSATwoDotOneJP),
-            "xkeyboard-config-Zinoviev" => Ok(Self::XkeyboardConfigZinoviev),
-            "NAIST-2003" => Ok(Self::NAISTTwoZeroZeroThree),
-            "HPND-export-US" => Ok(Self::HPNDExportUS),
-            "GStreamer-exception-2008" => Ok(Self::GStreamerExceptionTwoZeroZeroEight),
-            "deprecated_BSD-2-Clause-NetBSD" => Ok(Self::DeprecatedBSDTwoClauseNetBsd),
-            "WxWindows-exception-3.1" => Ok(Self::WxWindowsExceptionThreeDotOne),
-            "libselinux-1.0" => Ok(Self::LibselinuxOneDotZero),
-            "QPL-1.0" => Ok(Self::QPLOneDotZero),
-            "Pixar" => Ok(Self::Pixar),
-            "Fawkes-Runtime-exception" => Ok(Self::FawkesRuntimeException),
-            "CC-PDDC" => Ok(Self::CCPDDC),
-            "Xerox" => Ok(Self::Xerox),
-            "BSD-3-Clause-No-Nuclear-Warranty" => Ok(Self::BSDThreeClauseNoNuclearWarranty),
-            "any-OSI-perl-modules" => Ok(Self::AnyOSIPerlModules),
-            "BUSL-1.1" => Ok(Self::BUSLOneDotOne),
-            "MakeIndex" => Ok(Self::MakeIndex),
-            "NTIA-PD" => Ok(Self::NTIAPD),
-            "LGPL-3.0-or-later" => Ok(Self::LGPLThreeDotZeroOrLater),
-            "deprecated_Nunit" => Ok(Self::DeprecatedNunit),
-            "OFFIS" => Ok(Self::OFFIS),
-            "Latex2e" => Ok(Self::LatexTwoe),
-            "SSH-OpenSSH" => Ok(Self::SSHOpenSsh),
-            "Entessa" => Ok(Self::Entessa),
-            "AFL-2.0" => Ok(Self::AFLTwoDotZero),
-            "deprecated_LGPL-2.0" => Ok(Self::DeprecatedLGPLTwoDotZero),
-            "Zend-2.0" => Ok(Self::ZendTwoDotZero),
-            "mif-exception" => Ok(Self::MifException),
-            "Xfig" => Ok(Self::Xfig),
-            "Caldera" => Ok(Self::Caldera),
-            "libutil-David-Nugent" => Ok(Self::LibutilDavidNugent),
-            "Asterisk-linking-protocols-exception" => Ok(Self::AsteriskLinkingProtocolsException),
-            "OLDAP-2.6" => Ok(Self::OLDAPTwoDotSix),
-            "0BSD" => Ok(Self::ZeroBsd),
-            "GNU-compiler-exception" => Ok(Self::GNUCompilerException),
-            "Barr" => Ok(Self::Barr),
-            "SGI-OpenGL" => Ok(Self::SGIOpenGl),
-            "CC-PDM-1.0" => Ok(Self::CCPDMOneDotZero),
-            "MIT-enna" => Ok(Self::MITEnna),
-            "NIST-PD" => Ok(Self::NISTPD),
-            "CC-BY-2.5" => Ok(Self::CCBYTwoDotFive),
-            "HPND-sell-regexpr" => Ok(Self::HPNDSellRegexpr),
-            "LOOP" => Ok(Self::LOOP),
-            "OLDAP-2.1" => Ok(Self::OLDAPTwoDotOne),
-            "SISSL-1.2" => Ok(Self::SISSLOneDotTwo),
-            "3D-Slicer-1.0" => Ok(Self::ThreeDSlicerOneDotZero),
-            "OCCT-exception-1.0" => Ok(Self::OCCTExceptionOneDotZero),
-            "389-exception" => Ok(Self::ThreeEightNineException),
-            "SL" => Ok(Self::SL),
-            "FLTK-exception" => Ok(Self::FLTKException),
-            "GFDL-1.1-only" => Ok(Self::GFDLOneDotOneOnly),
-            "CAL-1.0-Combined-Work-Exception" => Ok(Self::CALOneDotZeroCombinedWorkException),
-            "deprecated_StandardML-NJ" => Ok(Self::DeprecatedStandardMlNJ),
-            "ADSL" => Ok(Self::ADSL),
-            "BSD-4.3TAHOE" => Ok(Self::BSDFourDotThreeTahoe),
-            "ZPL-2.1" => Ok(Self::ZPLTwoDotOne),
-            "Imlib2" => Ok(Self::ImlibTwo),
-            "RPL-1.1" => Ok(Self::RPLOneDotOne),
-            "gnuplot" => Ok(Self::Gnuplot),
-            "D-FSL-1.0" => Ok(Self::DFSLOneDotZero),
-            "Adobe-Utopia" => Ok(Self::AdobeUtopia),
-            "OpenSSL" => Ok(Self::OpenSsl),
-            "GPL-3.0-or-later" => Ok(Self::GPLThreeDotZeroOrLater),
-            "OSET-PL-2.1" => Ok(Self::OSETPLTwoDotOne),
-            "LZMA-SDK-9.11-to-9.20" => Ok(Self::LZMASDKNineDotOneOneToNineDotTwoZero),
-            "SAX-PD-2.0" => Ok(Self::SAXPDTwoDotZero),
-            "BSD-3-Clause-Clear" => Ok(Self::BSDThreeClauseClear),
-            "NASA-1.3" => Ok(Self::NASAOneDotThree),
-            "EUDatagrid" => Ok(Self::EuDatagrid),
-            "CERN-OHL-1.1" => Ok(Self::CERNOHLOneDotOne),
-            "etalab-2.0" => Ok(Self::EtalabTwoDotZero),
-            "deprecated_GPL-3.0-with-autoconf-exception" => {
-                Ok(Self::DeprecatedGPLThreeDotZeroWithAutoconfException)
-            }
-            "CERN-OHL-S-2.0" => Ok(Self::CERNOHLSTwoDotZero),
-            "Gutmann" => Ok(Self::Gutmann),
-            "OLFL-1.3" => Ok(Self::OLFLOneDotThree),
-            "Linux-man-pages-copyleft-var" => Ok(Self::LinuxManPagesCopyleftVar),
-            "OGL-UK-3.0" => Ok(Self::OGLUKThreeDotZero),
-            "Parity-6.0.0" => Ok(Self::ParitySixDotZeroDotZero),
-            "LPPL-1.3a" => Ok(Self::LPPLOneDotThreea),
-            "mplus" => Ok(Self::Mplus),
-            "COIL-1.0" => Ok(Self::COILOneDotZero),
-            "gtkbook" => Ok(Self::Gtkbook),
-            "XSkat" => Ok(Self::XSkat),
-            "HPND-doc-sell" => Ok(Self::HPNDDocSell),
-            "RSA-MD" => Ok(Self::RSAMD),
-            "CC-BY-SA-3.0-AT" => Ok(Self::CCBYSAThreeDotZeroAT),
-            "NIST-PD-fallback" => Ok(Self::NISTPDFallback),
-            "iMatix" => Ok(Self::IMatix),
-            "GPL-3.0-only" => Ok(Self::GPLThreeDotZeroOnly),
-            "CryptoSwift" => Ok(Self::CryptoSwift),
-            "WTFPL" => Ok(Self::WTFPL),
-            "BSD-3-Clause-No-Nuclear-License" => Ok(Self::BSDThreeClauseNoNuclearLicense),
-            "cve-tou" => Ok(Self::CveTou),
-            "HPND-MIT-disclaimer" => Ok(Self::HPNDMITDisclaimer),
-            "CC-BY-ND-2.0" => Ok(Self::CCBYNDTwoDotZero),
-            "Vim" => Ok(Self::Vim),
-            "NIST-Software" => Ok(Self::NISTSoftware),
-            "LPPL-1.0" => Ok(Self::LPPLOneDotZero),
-            "YPL-1.1" => Ok(Self::YPLOneDotOne),
-            "CC-BY-NC-ND-2.0" => Ok(Self::CCBYNCNDTwoDotZero),
-            "copyleft-next-0.3.1" => Ok(Self::CopyleftNextZeroDotThreeDotOne),
-            "MIT-CMU" => Ok(Self::MITCMU),
-            "RPSL-1.0" => Ok(Self::RPSLOneDotZero),
-            "BSD-2-Clause-Patent" => Ok(Self::BSDTwoClausePatent),
-            "dtoa" => Ok(Self::Dtoa),
-            "NCSA" => Ok(Self::NCSA),
-            "NPL-1.1" => Ok(Self::NPLOneDotOne),
-            "SCEA" => Ok(Self::SCEA),
-            "SMPPL" => Ok(Self::SMPPL),
-            "LiLiQ-R-1.1" => Ok(Self::LiLiQROneDotOne),
-            "OFL-1.0-RFN" => Ok(Self::OFLOneDotZeroRFN),
-            "NPOSL-3.0" => Ok(Self::NPOSLThreeDotZero),
-            "ImageMagick" => Ok(Self::ImageMagick),
-            "BSD-4-Clause-Shortened" => Ok(Self::BSDFourClauseShortened),
-            "Asterisk-exception" => Ok(Self::AsteriskException),
-            "libpri-OpenH323-exception" => Ok(Self::LibpriOpenHThreeTwoThreeException),
-            "Aladdin" => Ok(Self::Aladdin),
-            "Unicode-TOU" => Ok(Self::UnicodeTOU),
-            "OpenPBS-2.3" => Ok(Self::OpenPbsTwoDotThree),
-            "any-OSI" => Ok(Self::AnyOSI),
-            "UCL-1.0" => Ok(Self::UCLOneDotZero),
-            "Zimbra-1.4" => Ok(Self::ZimbraOneDotFour),
-            "Bootloader-exception" => Ok(Self::BootloaderException),
-            "Bison-exception-2.2" => Ok(Self::BisonExceptionTwoDotTwo),
-            "TGPPL-1.0" => Ok(Self::TGPPLOneDotZero),
-            "BitTorrent-1.1" => Ok(Self::BitTorrentOneDotOne),
-            "Wsuipa" => Ok(Self::Wsuipa),
-            "deprecated_Nokia-Qt-exception-1.1" => Ok(Self::DeprecatedNokiaQtExceptionOneDotOne),
-            "CC-BY-NC-SA-2.0-DE" => Ok(Self::CCBYNCSATwoDotZeroDE),
-            "Linux-man-pages-copyleft" => Ok(Self::LinuxManPagesCopyleft),
-            "xlock" => Ok(Self::Xlock),
-            "LiLiQ-Rplus-1.1" => Ok(Self::LiLiQRplusOneDotOne),
-            "generic-xts" => Ok(Self::GenericXts),
-            "Zimbra-1.3" => Ok(Self::ZimbraOneDotThree),
-            "GPL-2.0-only" => Ok(Self::GPLTwoDotZeroOnly),
-            "OGL-UK-1.0" => Ok(Self::OGLUKOneDotZero),
-            "AFL-1.1" => Ok(Self::AFLOneDotOne),
-            "SSLeay-standalone" => Ok(Self::SsLeayStandalone),
-            "X11-swapped" => Ok(Self::XOneOneSwapped),
-            "OpenJDK-assembly-exception-1.0" => Ok(Self::OpenJdkAssemblyExceptionOneDotZero),
-            "GFDL-1.3-no-invariants-or-later" => Ok(Self::GFDLOneDotThreeNoInvariantsOrLater),
-            "MTLL" => Ok(Self::MTLL),
-            "Ubuntu-font-1.0" => Ok(Self::UbuntuFontOneDotZero),
-            "DocBook-XML" => Ok(Self::DocBookXML),
-            "MIT-testregex" => Ok(Self::MITTestregex),
-            "CC-BY-NC-2.5" => Ok(Self::CCBYNCTwoDotFive),
-            "python-ldap" => Ok(Self::PythonLdap),
-            "GL2PS" => Ok(Self::GlTwoPs),
-            "LPL-1.02" => Ok(Self::LPLOneDotZeroTwo),
-            "MITNFA" => Ok(Self::MITNFA),
-            "checkmk" => Ok(Self::Checkmk),
-            "GFDL-1.2-only" => Ok(Self::GFDLOneDotTwoOnly),
-            "NGPL" => Ok(Self::NGPL),
-            "MulanPSL-1.0" => Ok(Self::MulanPslOneDotZero),
-            "deprecated_wxWindows" => Ok(Self::DeprecatedWxWindows),
-            "OGC-1.0" => Ok(Self::OGCOneDotZero),
-            "ulem" => Ok(Self::Ulem),
-            "Autoconf-exception-3.0" => Ok(Self::AutoconfExceptionThreeDotZero),
-            "harbour-exception" => Ok(Self::HarbourException),
-            "UCAR" => Ok(Self::UCAR),
-            "MS-PL" => Ok(Self::MSPL),
-            "JPL-image" => Ok(Self::JPLImage),
-            "Font-exception-2.0" => Ok(Self::FontExceptionTwoDotZero),
-            "GFDL-1.3-invariants-or-later" => Ok(Self::GFDLOneDotThreeInvariantsOrLater),
-            "fwlw" => Ok(Self::Fwlw),
-            "Inner-Net-2.0" => Ok(Self::InnerNetTwoDotZero),
-            "MPL-1.0" => Ok(Self::MPLOneDotZero),
-            "Community-Spec-1.0" => Ok(Self::CommunitySpecOneDotZero),
-            "CUA-OPL-1.0" => Ok(Self::CUAOPLOneDotZero),
-            "UBDL-exception" => Ok(Self::UBDLException),
-            "GFDL-1.1-invariants-only" => Ok(Self::GFDLOneDotOneInvariantsOnly),
-            "FreeBSD-DOC" => Ok(Self::FreeBsdDOC),
-            "EPL-2.0" => Ok(Self::EPLTwoDotZero),
-            "Sendmail-Open-Source-1.1" => Ok(Self::SendmailOpenSourceOneDotOne),
-            "Eurosym" => Ok(Self::Eurosym),
-            "GPL-1.0-only" => Ok(Self::GPLOneDotZeroOnly),
-            "deprecated_BSD-2-Clause-FreeBSD" => Ok(Self::DeprecatedBSDTwoClauseFreeBsd),
-            "SHL-0.5" => Ok(Self::SHLZeroDotFive),
-            "X11" => Ok(Self::XOneOne),
-            "ThirdEye" => Ok(Self::ThirdEye),
-            "FSFAP-no-warranty-disclaimer" => Ok(Self::FSFAPNoWarrantyDisclaimer),
-            "SimPL-2.0" => Ok(Self::SimPlTwoDotZero),
-            "Interbase-1.0" => Ok(Self::InterbaseOneDotZero),
-            "deprecated_bzip2-1.0.5" => Ok(Self::DeprecatedBzipTwoOneDotZeroDotFive),
-            "Multics" => Ok(Self::Multics),
-            "GStreamer-exception-2005" => Ok(Self::GStreamerExceptionTwoZeroZeroFive),
-            "CC-BY-NC-SA-2.0" => Ok(Self::CCBYNCSATwoDotZero),
-            "GD" => Ok(Self::GD),
-            "CC-BY-SA-2.0" => Ok(Self::CCBYSATwoDotZero),
-            "CECILL-C" => Ok(Self::CECILLC),
-            "Elastic-2.0" => Ok(Self::ElasticTwoDotZero),
-            "MIT-Wu" => Ok(Self::MITWu),
-            "snprintf" => Ok(Self::Snprintf),
-            "EUPL-1.0" => Ok(Self::EUPLOneDotZero),
-            "AMDPLPA" => Ok(Self::AMDPLPA),
-            "APSL-1.0" => Ok(Self::APSLOneDotZero),
-            "BSD-Source-beginning-file" => Ok(Self::BSDSourceBeginningFile),
-            "SunPro" => Ok(Self::SunPro),
-            "HPND-Intel" => Ok(Self::HPNDIntel),
-            "LZMA-SDK-9.22" => Ok(Self::LZMASDKNineDotTwoTwo),
-            "OFL-1.0" => Ok(Self::OFLOneDotZero),
-            "deprecated_GPL-2.0-with-GCC-exception" => {
-                Ok(Self::DeprecatedGPLTwoDotZeroWithGCCException)
-            }
-            "Autoconf-exception-generic-3.0" => Ok(Self::AutoconfExceptionGenericThreeDotZero),
-            "CC-BY-SA-3.0" => Ok(Self::CCBYSAThreeDotZero),
-            "CC-BY-NC-SA-3.0" => Ok(Self::CCBYNCSAThreeDotZero),
-            "Bahyph" => Ok(Self::Bahyph),
-            "DSDP" => Ok(Self::DSDP),
-            "McPhee-slideshow" => Ok(Self::McPheeSlideshow),
-            "BitTorrent-1.0" => Ok(Self::BitTorrentOneDotZero),
-            "CC-BY-3.0-NL" => Ok(Self::CCBYThreeDotZeroNL),
-            "OLDAP-1.1" => Ok(Self::OLDAPOneDotOne),
-            "Bitstream-Vera" => Ok(Self::BitstreamVera),
-            "GFDL-1.2-invariants-only" => Ok(Self::GFDLOneDotTwoInvariantsOnly),
-            "SchemeReport" => Ok(Self::SchemeReport),
-            "HPND-export2-US" => Ok(Self::HPNDExportTwoUS),
-            "xinetd" => Ok(Self::Xinetd),
-            "HaskellReport" => Ok(Self::HaskellReport),
-            "TCL" => Ok(Self::TCL),
-            "NetCDF" => Ok(Self::NetCdf),
-            "Symlinks" => Ok(Self::Symlinks),
-            "openvpn-openssl-exception" => Ok(Self::OpenvpnOpensslException),
-            "Arphic-1999" => Ok(Self::ArphicOneNineNineNine),
-            "HPND-Pbmplus" => Ok(Self::HPNDPbmplus),
-            "OAR" => Ok(Self::OAR),
-            "copyleft-next-0.3.0" => Ok(Self::CopyleftNextZeroDotThreeDotZero),
-            "YPL-1.0" => Ok(Self::YPLOneDotZero),
-            "LPPL-1.1" => Ok(Self::LPPLOneDotOne),
-            "CC-BY-ND-4.0" => Ok(Self::CCBYNDFourDotZero),
-            "Autoconf-exception-2.0" => Ok(Self::AutoconfExceptionTwoDotZero),
-            "XFree86-1.1" => Ok(Self::XFreeEightSixOneDotOne),
-            "CC-BY-NC-ND-4.0" => Ok(Self::CCBYNCNDFourDotZero),
-            "CC-BY-NC-SA-3.0-DE" => Ok(Self::CCBYNCSAThreeDotZeroDE),
-            "TPL-1.0" => Ok(Self::TPLOneDotZero),
-            "Naumen" => Ok(Self::Naumen),
-            "NICTA-1.0" => Ok(Self::NICTAOneDotZero),
-            "NOSL" => Ok(Self::NOSL),
-            "pnmstitch" => Ok(Self::Pnmstitch),
-            "CPL-1.0" => Ok(Self::CPLOneDotZero),
-            "xzoom" => Ok(Self::Xzoom),
-            "IEC-Code-Components-EULA" => Ok(Self::IECCodeComponentsEULA),
-            "deprecated_AGPL-3.0" => Ok(Self::DeprecatedAGPLThreeDotZero),
-            "RSCPL" => Ok(Self::RSCPL),
-            "NPL-1.0" => Ok(Self::NPLOneDotZero),
-            "BSD-3-Clause-No-Nuclear-License-2014" => {
-                Ok(Self::BSDThreeClauseNoNuclearLicenseTwoZeroOneFour)
-            }
-            "Sleepycat" => Ok(Self::Sleepycat),
-            "CDLA-Sharing-1.0" => Ok(Self::CDLASharingOneDotZero),
-            "GFDL-1.3-only" => Ok(Self::GFDLOneDotThreeOnly),
-            "lsof" => Ok(Self::Lsof),
-            "Parity-7.0.0" => Ok(Self::ParitySevenDotZeroDotZero),
-            "AAL" => Ok(Self::AAL),
-            "Zeeff" => Ok(Self::Zeeff),
-            "CC-BY-NC-SA-4.0" => Ok(Self::CCBYNCSAFourDotZero),
-            "BlueOak-1.0.0" => Ok(Self::BlueOakOneDotZeroDotZero),
-            "CC-BY-SA-4.0" => Ok(Self::CCBYSAFourDotZero),
-            "GFDL-1.2-or-later" => Ok(Self::GFDLOneDotTwoOrLater),
-            "OFL-1.1" => Ok(Self::OFLOneDotOne),
-            "APSL-1.1" => Ok(Self::APSLOneDotOne),
-            "GPL-3.0-interface-exception" => Ok(Self::GPLThreeDotZeroInterfaceException),
-            "Qt-LGPL-exception-1.1" => Ok(Self::QtLGPLExceptionOneDotOne),
-            "Mackerras-3-Clause" => Ok(Self::MackerrasThreeClause),
-            "EUPL-1.1" => Ok(Self::EUPLOneDotOne),
-            "Autoconf-exception-generic" => Ok(Self::AutoconfExceptionGeneric),
-            "Sun-PPP" => Ok(Self::SunPPP),
-            "CECILL-B" => Ok(Self::CECILLB),
-            "Linux-OpenIB" => Ok(Self::LinuxOpenIb),
-            "BSD-Attribution-HPND-disclaimer" => Ok(Self::BSDAttributionHPNDDisclaimer),
-            "fmt-exception" => Ok(Self::FmtException),
-            "MIT-feh" => Ok(Self::MITFeh),
-            "Ruby-pty" => Ok(Self::RubyPty),
-            "BSD-3-Clause-No-Military-License" => Ok(Self::BSDThreeClauseNoMilitaryLicense),
-            "HPND-sell-variant" => Ok(Self::HPNDSellVariant),
-            "DEC-3-Clause" => Ok(Self::DECThreeClause),
-            "ZPL-1.1" => Ok(Self::ZPLOneDotOne),
-            "Zlib" => Ok(Self::Zlib),
-            "FTL" => Ok(Self::FTL),
-            "CC-BY-SA-3.0-DE" => Ok(Self::CCBYSAThreeDotZeroDE),
-            "CC-BY-NC-ND-3.0" => Ok(Self::CCBYNCNDThreeDotZero),
-            "TOSL" => Ok(Self::TOSL),
-            "FBM" => Ok(Self::FBM),
-            "CC-BY-ND-3.0" => Ok(Self::CCBYNDThreeDotZero),
-            "C-UDA-1.0" => Ok(Self::CUDAOneDotZero),
-            "Qt-GPL-exception-1.0" => Ok(Self::QtGPLExceptionOneDotZero),
-            "Sun-PPP-2000" => Ok(Self::SunPPPTwoZeroZeroZero),
-            "OpenVision" => Ok(Self::OpenVision),
-            "deprecated_GFDL-1.1" => Ok(Self::DeprecatedGFDLOneDotOne),
-            "ssh-keyscan" => Ok(Self::SshKeyscan),
-            "SSH-short" => Ok(Self::SSHShort),
-            "gSOAP-1.3b" => Ok(Self::GSoapOneDotThreeb),
-            "JPNIC" => Ok(Self::JPNIC),
-            "GFDL-1.1-no-invariants-only" => Ok(Self::GFDLOneDotOneNoInvariantsOnly),
-            "TAPR-OHL-1.0" => Ok(Self::TAPROHLOneDotZero),
-            "Rdisc" => Ok(Self::Rdisc),
-            "BSD-2-Clause-Views" => Ok(Self::BSDTwoClauseViews),
-            "Artistic-1.0-cl8" => Ok(Self::ArtisticOneDotZeroClEight),
-            "HPND-doc" => Ok(Self::HPNDDoc),
-            "BSD-3-Clause-Sun" => Ok(Self::BSDThreeClauseSun),
-            "URT-RLE" => Ok(Self::URTRLE),
-            "mpich2" => Ok(Self::MpichTwo),
-            "CGAL-linking-exception" => Ok(Self::CGALLinkingException),
-            "MPL-1.1" => Ok(Self::MPLOneDotOne),
-            _ => Err(ParseLicenseError),
-        }
+        crate::models::parse_exception_id(s).map_err(ParseLicenseError)
     }
 }
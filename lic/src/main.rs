@@ -5,13 +5,19 @@
 // Application modules
 mod app;
 mod commands;
+mod compat;
 mod config;
+mod deps;
 mod error;
+mod header;
+mod journal;
 mod license;
 mod models;
+mod policy;
 mod utils;
 
 // Core imports
+use crate::error::LichenError;
 use crate::models::Cli;
 use app::LichenApp;
 
@@ -20,7 +26,7 @@ use std::process::ExitCode;
 
 // External crate imports
 use clap::Parser;
-use log::{debug, error, trace};
+use log::{debug, error, trace, warn};
 
 // Main application logic
 #[tokio::main]
@@ -50,9 +56,14 @@ async fn main() -> ExitCode {
     // Handle any errors and exit :)
     match result {
         Ok(_) => ExitCode::SUCCESS,
+        // Nothing to do isn't a failure, but scripts can still tell it apart
+        Err(e @ LichenError::NothingToDo(_)) => {
+            warn!("{}", e);
+            ExitCode::from(e.exit_code())
+        }
         Err(e) => {
-            error!("Command failure, because of... a {}", e);
-            ExitCode::FAILURE
+            error!("{}", e);
+            ExitCode::from(e.exit_code())
         }
     }
 }
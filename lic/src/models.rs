@@ -28,1548 +28,2188 @@ pub struct Authors(pub Vec<Author>);
 #[derive(Debug, Clone, Deserialize, Copy, PartialEq, Eq, Hash, clap :: ValueEnum)]
 #[allow(non_camel_case_types)]
 pub enum License {
+    #[value(name = "0BSD")]
+    ZeroBsd,
+    #[value(name = "3D-Slicer-1.0")]
+    ThreeDSlicerOneDotZero,
+    #[value(name = "AAL")]
+    AAL,
+    #[value(name = "ADSL")]
+    ADSL,
+    #[value(name = "AFL-1.1")]
+    AFLOneDotOne,
     #[value(name = "AFL-1.2")]
     AFLOneDotTwo,
-    #[value(name = "Linux-syscall-note")]
-    LinuxSyscallNote,
-    #[value(name = "DocBook-Stylesheet")]
-    DocBookStylesheet,
-    #[value(name = "HPND-sell-MIT-disclaimer-xserver")]
-    HPNDSellMITDisclaimerXserver,
-    #[value(name = "OSL-2.1")]
-    OSLTwoDotOne,
-    #[value(name = "SWL")]
-    SWL,
-    #[value(name = "CC-BY-NC-4.0")]
-    CCBYNCFourDotZero,
-    #[value(name = "NLOD-2.0")]
-    NLODTwoDotZero,
-    #[value(name = "Unlicense")]
-    Unlicense,
-    #[value(name = "ODC-By-1.0")]
-    ODCByOneDotZero,
-    #[value(name = "UPL-1.0")]
-    UPLOneDotZero,
-    #[value(name = "SNIA")]
-    SNIA,
-    #[value(name = "GCC-exception-3.1")]
-    GCCExceptionThreeDotOne,
-    #[value(name = "OLDAP-1.3")]
-    OLDAPOneDotThree,
-    #[value(name = "Dotseqn")]
-    Dotseqn,
-    #[value(name = "TMate")]
-    TMate,
-    #[value(name = "GFDL-1.1-invariants-or-later")]
-    GFDLOneDotOneInvariantsOrLater,
-    #[value(name = "MMIXware")]
-    MmiXware,
-    #[value(name = "Crossword")]
-    Crossword,
-    #[value(name = "OLDAP-1.4")]
-    OLDAPOneDotFour,
-    #[value(name = "GLWTPL")]
-    GLWTPL,
-    #[value(name = "curl")]
-    Curl,
-    #[value(name = "GPL-2.0-or-later")]
-    GPLTwoDotZeroOrLater,
-    #[value(name = "OFL-1.1-no-RFN")]
-    OFLOneDotOneNoRFN,
-    #[value(name = "MS-LPL")]
-    MSLPL,
-    #[value(name = "CC-BY-1.0")]
-    CCBYOneDotZero,
-    #[value(name = "CDLA-Permissive-1.0")]
-    CDLAPermissiveOneDotZero,
-    #[value(name = "QPL-1.0-INRIA-2004-exception")]
-    QPLOneDotZeroINRIATwoZeroZeroFourException,
-    #[value(name = "Classpath-exception-2.0")]
-    ClasspathExceptionTwoDotZero,
-    #[value(name = "BSD-3-Clause")]
-    BSDThreeClause,
-    #[value(name = "OPL-1.0")]
-    OPLOneDotZero,
-    #[value(name = "ODbL-1.0")]
-    ODbLOneDotZero,
-    #[value(name = "Independent-modules-exception")]
-    IndependentModulesException,
-    #[value(name = "MIT-Festival")]
-    MITFestival,
-    #[value(name = "Caldera-no-preamble")]
-    CalderaNoPreamble,
-    #[value(name = "SGI-B-1.1")]
-    SGIBOneDotOne,
-    #[value(name = "Soundex")]
-    Soundex,
-    #[value(name = "SANE-exception")]
-    SANEException,
-    #[value(name = "MirOS")]
-    MirOs,
-    #[value(name = "Ruby")]
-    Ruby,
-    #[value(name = "polyparse-exception")]
-    PolyparseException,
-    #[value(name = "BSD-3-Clause-LBNL")]
-    BSDThreeClauseLBNL,
-    #[value(name = "BSD-2-Clause")]
-    BSDTwoClause,
-    #[value(name = "EFL-2.0")]
-    EFLTwoDotZero,
-    #[value(name = "LLGPL")]
-    LLGPL,
-    #[value(name = "vsftpd-openssl-exception")]
-    VsftpdOpensslException,
-    #[value(name = "OFL-1.0-no-RFN")]
-    OFLOneDotZeroNoRFN,
-    #[value(name = "CC-BY-NC-ND-3.0-DE")]
-    CCBYNCNDThreeDotZeroDE,
-    #[value(name = "Artistic-2.0")]
-    ArtisticTwoDotZero,
-    #[value(name = "CDDL-1.0")]
-    CDDLOneDotZero,
-    #[value(name = "SWI-exception")]
-    SWIException,
-    #[value(name = "GFDL-1.3-invariants-only")]
-    GFDLOneDotThreeInvariantsOnly,
-    #[value(name = "MS-RL")]
-    MSRL,
+    #[value(name = "AFL-2.0")]
+    AFLTwoDotZero,
+    #[value(name = "AFL-2.1")]
+    AFLTwoDotOne,
+    #[value(name = "AFL-3.0")]
+    AFLThreeDotZero,
+    #[value(name = "AGPL-1.0-only")]
+    AGPLOneDotZeroOnly,
+    #[value(name = "AGPL-1.0-or-later")]
+    AGPLOneDotZeroOrLater,
+    #[value(name = "AGPL-3.0-only")]
+    AGPLThreeDotZeroOnly,
+    #[value(name = "AGPL-3.0-or-later")]
+    AGPLThreeDotZeroOrLater,
+    #[value(name = "AMD-newlib")]
+    AMDNewlib,
+    #[value(name = "AMDPLPA")]
+    AMDPLPA,
+    #[value(name = "AML")]
+    AML,
+    #[value(name = "AML-glslang")]
+    AMLGlslang,
+    #[value(name = "AMPAS")]
+    AMPAS,
+    #[value(name = "ANTLR-PD")]
+    ANTLRPD,
+    #[value(name = "ANTLR-PD-fallback")]
+    ANTLRPDFallback,
+    #[value(name = "APAFML")]
+    APAFML,
+    #[value(name = "APL-1.0")]
+    APLOneDotZero,
+    #[value(name = "APSL-1.0")]
+    APSLOneDotZero,
+    #[value(name = "APSL-1.1")]
+    APSLOneDotOne,
+    #[value(name = "APSL-1.2")]
+    APSLOneDotTwo,
+    #[value(name = "APSL-2.0")]
+    APSLTwoDotZero,
+    #[value(name = "ASWF-Digital-Assets-1.0")]
+    ASWFDigitalAssetsOneDotZero,
     #[value(name = "ASWF-Digital-Assets-1.1")]
     ASWFDigitalAssetsOneDotOne,
-    #[value(name = "LGPL-2.1-only")]
-    LGPLTwoDotOneOnly,
-    #[value(name = "HP-1986")]
-    HPOneNineEightSix,
-    #[value(name = "Python-2.0.1")]
-    PythonTwoDotZeroDotOne,
     #[value(name = "Abstyles")]
     Abstyles,
-    #[value(name = "RHeCos-1.1")]
-    RHeCosOneDotOne,
-    #[value(name = "MIT")]
-    MIT,
+    #[value(name = "AdaCore-doc")]
+    AdaCoreDoc,
+    #[value(name = "Adobe-2006")]
+    AdobeTwoZeroZeroSix,
+    #[value(name = "Adobe-Display-PostScript")]
+    AdobeDisplayPostScript,
+    #[value(name = "Adobe-Glyph")]
+    AdobeGlyph,
+    #[value(name = "Adobe-Utopia")]
+    AdobeUtopia,
+    #[value(name = "Afmparse")]
+    Afmparse,
+    #[value(name = "Aladdin")]
+    Aladdin,
+    #[value(name = "Apache-1.0")]
+    ApacheOneDotZero,
+    #[value(name = "Apache-1.1")]
+    ApacheOneDotOne,
+    #[value(name = "Apache-2.0")]
+    ApacheTwoDotZero,
+    #[value(name = "App-s2p")]
+    AppSTwop,
+    #[value(name = "Arphic-1999")]
+    ArphicOneNineNineNine,
+    #[value(name = "Artistic-1.0")]
+    ArtisticOneDotZero,
+    #[value(name = "Artistic-1.0-Perl")]
+    ArtisticOneDotZeroPerl,
+    #[value(name = "Artistic-1.0-cl8")]
+    ArtisticOneDotZeroClEight,
+    #[value(name = "Artistic-2.0")]
+    ArtisticTwoDotZero,
     #[value(name = "BSD-1-Clause")]
     BSDOneClause,
-    #[value(name = "romic-exception")]
-    RomicException,
-    #[value(name = "OSL-3.0")]
-    OSLThreeDotZero,
-    #[value(name = "CC-BY-NC-3.0")]
-    CCBYNCThreeDotZero,
-    #[value(name = "GCC-exception-2.0")]
-    GCCExceptionTwoDotZero,
-    #[value(name = "deprecated_LGPL-3.0+")]
-    DeprecatedLGPLThreeDotZeroPlus,
-    #[value(name = "Clips")]
-    Clips,
+    #[value(name = "BSD-2-Clause")]
+    BSDTwoClause,
     #[value(name = "BSD-2-Clause-Darwin")]
     BSDTwoClauseDarwin,
-    #[value(name = "CNRI-Python-GPL-Compatible")]
-    CNRIPythonGPLCompatible,
-    #[value(name = "LPD-document")]
-    LPDDocument,
-    #[value(name = "GPL-CC-1.0")]
-    GPLCCOneDotZero,
+    #[value(name = "BSD-2-Clause-Patent")]
+    BSDTwoClausePatent,
+    #[value(name = "BSD-2-Clause-Views")]
+    BSDTwoClauseViews,
+    #[value(name = "BSD-2-Clause-first-lines")]
+    BSDTwoClauseFirstLines,
+    #[value(name = "BSD-2-Clause-pkgconf-disclaimer")]
+    BSDTwoClausePkgconfDisclaimer,
+    #[value(name = "BSD-3-Clause")]
+    BSDThreeClause,
+    #[value(name = "BSD-3-Clause-Attribution")]
+    BSDThreeClauseAttribution,
+    #[value(name = "BSD-3-Clause-Clear")]
+    BSDThreeClauseClear,
+    #[value(name = "BSD-3-Clause-HP")]
+    BSDThreeClauseHP,
+    #[value(name = "BSD-3-Clause-LBNL")]
+    BSDThreeClauseLBNL,
+    #[value(name = "BSD-3-Clause-Modification")]
+    BSDThreeClauseModification,
+    #[value(name = "BSD-3-Clause-No-Military-License")]
+    BSDThreeClauseNoMilitaryLicense,
+    #[value(name = "BSD-3-Clause-No-Nuclear-License")]
+    BSDThreeClauseNoNuclearLicense,
+    #[value(name = "BSD-3-Clause-No-Nuclear-License-2014")]
+    BSDThreeClauseNoNuclearLicenseTwoZeroOneFour,
+    #[value(name = "BSD-3-Clause-No-Nuclear-Warranty")]
+    BSDThreeClauseNoNuclearWarranty,
+    #[value(name = "BSD-3-Clause-Open-MPI")]
+    BSDThreeClauseOpenMPI,
+    #[value(name = "BSD-3-Clause-Sun")]
+    BSDThreeClauseSun,
+    #[value(name = "BSD-3-Clause-acpica")]
+    BSDThreeClauseAcpica,
     #[value(name = "BSD-3-Clause-flex")]
     BSDThreeClauseFlex,
-    #[value(name = "HPND-UC")]
-    HPNDUC,
-    #[value(name = "ISC-Veillard")]
-    ISCVeillard,
-    #[value(name = "PDDL-1.0")]
-    PDDLOneDotZero,
-    #[value(name = "LGPL-2.0-or-later")]
-    LGPLTwoDotZeroOrLater,
-    #[value(name = "CC-BY-NC-SA-3.0-IGO")]
-    CCBYNCSAThreeDotZeroIGO,
-    #[value(name = "TORQUE-1.1")]
-    TORQUEOneDotOne,
-    #[value(name = "Frameworx-1.0")]
-    FrameworxOneDotZero,
-    #[value(name = "OLDAP-2.8")]
-    OLDAPTwoDotEight,
-    #[value(name = "deprecated_GPL-3.0")]
-    DeprecatedGPLThreeDotZero,
-    #[value(name = "Qwt-exception-1.0")]
-    QwtExceptionOneDotZero,
-    #[value(name = "DocBook-DTD")]
-    DocBookDTD,
-    #[value(name = "psfrag")]
-    Psfrag,
-    #[value(name = "IJG-short")]
-    IJGShort,
-    #[value(name = "psutils")]
-    Psutils,
-    #[value(name = "DRL-1.1")]
-    DRLOneDotOne,
+    #[value(name = "BSD-4-Clause")]
+    BSDFourClause,
+    #[value(name = "BSD-4-Clause-Shortened")]
+    BSDFourClauseShortened,
+    #[value(name = "BSD-4-Clause-UC")]
+    BSDFourClauseUC,
+    #[value(name = "BSD-4.3RENO")]
+    BSDFourDotThreeReno,
+    #[value(name = "BSD-4.3TAHOE")]
+    BSDFourDotThreeTahoe,
+    #[value(name = "BSD-Advertising-Acknowledgement")]
+    BSDAdvertisingAcknowledgement,
+    #[value(name = "BSD-Attribution-HPND-disclaimer")]
+    BSDAttributionHPNDDisclaimer,
+    #[value(name = "BSD-Inferno-Nettverk")]
+    BSDInfernoNettverk,
+    #[value(name = "BSD-Protection")]
+    BSDProtection,
+    #[value(name = "BSD-Source-Code")]
+    BSDSourceCode,
+    #[value(name = "BSD-Source-beginning-file")]
+    BSDSourceBeginningFile,
+    #[value(name = "BSD-Systemics")]
+    BSDSystemics,
+    #[value(name = "BSD-Systemics-W3Works")]
+    BSDSystemicsWThreeWorks,
+    #[value(name = "BSL-1.0")]
+    BSLOneDotZero,
+    #[value(name = "BUSL-1.1")]
+    BUSLOneDotOne,
+    #[value(name = "Baekmuk")]
+    Baekmuk,
+    #[value(name = "Bahyph")]
+    Bahyph,
+    #[value(name = "Barr")]
+    Barr,
+    #[value(name = "Beerware")]
+    Beerware,
+    #[value(name = "BitTorrent-1.0")]
+    BitTorrentOneDotZero,
+    #[value(name = "BitTorrent-1.1")]
+    BitTorrentOneDotOne,
+    #[value(name = "Bitstream-Charter")]
+    BitstreamCharter,
+    #[value(name = "Bitstream-Vera")]
+    BitstreamVera,
+    #[value(name = "BlueOak-1.0.0")]
+    BlueOakOneDotZeroDotZero,
     #[value(name = "Boehm-GC")]
     BoehmGC,
-    #[value(name = "CECILL-2.0")]
-    CECILLTwoDotZero,
-    #[value(name = "W3C-19980720")]
-    WThreeCOneNineNineEightZeroSevenTwoZero,
-    #[value(name = "O-UDA-1.0")]
-    OUDAOneDotZero,
-    #[value(name = "CLISP-exception-2.0")]
-    CLISPExceptionTwoDotZero,
-    #[value(name = "DL-DE-ZERO-2.0")]
-    DLDEZEROTwoDotZero,
-    #[value(name = "deprecated_GFDL-1.3")]
-    DeprecatedGFDLOneDotThree,
-    #[value(name = "SGP4")]
-    SgpFour,
-    #[value(name = "FDK-AAC")]
-    FDKAAC,
-    #[value(name = "Apache-1.1")]
-    ApacheOneDotOne,
-    #[value(name = "TTWL")]
-    TTWL,
-    #[value(name = "Martin-Birgmeier")]
-    MartinBirgmeier,
-    #[value(name = "CC-BY-ND-3.0-DE")]
-    CCBYNDThreeDotZeroDE,
-    #[value(name = "ECL-2.0")]
-    ECLTwoDotZero,
-    #[value(name = "ICU")]
-    ICU,
-    #[value(name = "stunnel-exception")]
-    StunnelException,
-    #[value(name = "Game-Programming-Gems")]
-    GameProgrammingGems,
-    #[value(name = "BSD-3-Clause-Attribution")]
-    BSDThreeClauseAttribution,
-    #[value(name = "SGI-B-1.0")]
-    SGIBOneDotZero,
-    #[value(name = "NRL")]
-    NRL,
-    #[value(name = "Kazlib")]
-    Kazlib,
-    #[value(name = "PHP-3.01")]
-    PHPThreeDotZeroOne,
-    #[value(name = "magaz")]
-    Magaz,
-    #[value(name = "CPAL-1.0")]
-    CPALOneDotZero,
-    #[value(name = "App-s2p")]
-    AppSTwop,
-    #[value(name = "RRDtool-FLOSS-exception-2.0")]
-    RrDtoolFLOSSExceptionTwoDotZero,
-    #[value(name = "Libtool-exception")]
-    LibtoolException,
-    #[value(name = "PS-or-PDF-font-exception-20170817")]
-    PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven,
+    #[value(name = "Boehm-GC-without-fee")]
+    BoehmGCWithoutFee,
+    #[value(name = "Borceux")]
+    Borceux,
     #[value(name = "Brian-Gladman-2-Clause")]
     BrianGladmanTwoClause,
-    #[value(name = "deprecated_GPL-2.0")]
-    DeprecatedGPLTwoDotZero,
-    #[value(name = "LGPL-3.0-only")]
-    LGPLThreeDotZeroOnly,
-    #[value(name = "DigiRule-FOSS-exception")]
-    DigiRuleFOSSException,
-    #[value(name = "GPL-3.0-linking-exception")]
-    GPLThreeDotZeroLinkingException,
-    #[value(name = "ASWF-Digital-Assets-1.0")]
-    ASWFDigitalAssetsOneDotZero,
-    #[value(name = "mailprio")]
-    Mailprio,
-    #[value(name = "LPPL-1.2")]
-    LPPLOneDotTwo,
-    #[value(name = "CDDL-1.1")]
-    CDDLOneDotOne,
-    #[value(name = "HPND")]
-    HPND,
-    #[value(name = "NTP-0")]
-    NTPZero,
-    #[value(name = "CC-BY-ND-2.5")]
-    CCBYNDTwoDotFive,
-    #[value(name = "CC-BY-NC-ND-2.5")]
-    CCBYNCNDTwoDotFive,
-    #[value(name = "freertos-exception-2.0")]
-    FreertosExceptionTwoDotZero,
     #[value(name = "Brian-Gladman-3-Clause")]
     BrianGladmanThreeClause,
-    #[value(name = "deprecated_GPL-2.0-with-bison-exception")]
-    DeprecatedGPLTwoDotZeroWithBisonException,
-    #[value(name = "SugarCRM-1.1.3")]
-    SugarCrmOneDotOneDotThree,
-    #[value(name = "SISSL")]
-    SISSL,
-    #[value(name = "CFITSIO")]
-    CFITSIO,
-    #[value(name = "TU-Berlin-2.0")]
-    TUBerlinTwoDotZero,
-    #[value(name = "CMU-Mach-nodoc")]
-    CMUMachNodoc,
-    #[value(name = "Condor-1.1")]
-    CondorOneDotOne,
-    #[value(name = "OSL-2.0")]
-    OSLTwoDotZero,
-    #[value(name = "CC-BY-NC-2.0")]
-    CCBYNCTwoDotZero,
-    #[value(name = "AdaCore-doc")]
-    AdaCoreDoc,
-    #[value(name = "TermReadKey")]
-    TermReadKey,
-    #[value(name = "UnixCrypt")]
-    UnixCrypt,
-    #[value(name = "jove")]
-    Jove,
-    #[value(name = "NTP")]
-    NTP,
-    #[value(name = "Adobe-Display-PostScript")]
-    AdobeDisplayPostScript,
+    #[value(name = "C-UDA-1.0")]
+    CUDAOneDotZero,
+    #[value(name = "CAL-1.0")]
+    CALOneDotZero,
+    #[value(name = "CAL-1.0-Combined-Work-Exception")]
+    CALOneDotZeroCombinedWorkException,
+    #[value(name = "CATOSL-1.1")]
+    CATOSLOneDotOne,
+    #[value(name = "CC-BY-1.0")]
+    CCBYOneDotZero,
+    #[value(name = "CC-BY-2.0")]
+    CCBYTwoDotZero,
+    #[value(name = "CC-BY-2.5")]
+    CCBYTwoDotFive,
+    #[value(name = "CC-BY-2.5-AU")]
+    CCBYTwoDotFiveAU,
+    #[value(name = "CC-BY-3.0")]
+    CCBYThreeDotZero,
+    #[value(name = "CC-BY-3.0-AT")]
+    CCBYThreeDotZeroAT,
+    #[value(name = "CC-BY-3.0-AU")]
+    CCBYThreeDotZeroAU,
     #[value(name = "CC-BY-3.0-DE")]
     CCBYThreeDotZeroDE,
-    #[value(name = "Kastrup")]
-    Kastrup,
-    #[value(name = "LGPL-2.0-only")]
-    LGPLTwoDotZeroOnly,
-    #[value(name = "OLDAP-1.2")]
-    OLDAPOneDotTwo,
-    #[value(name = "Linux-man-pages-1-para")]
-    LinuxManPagesOnePara,
-    #[value(name = "DL-DE-BY-2.0")]
-    DLDEBYTwoDotZero,
+    #[value(name = "CC-BY-3.0-IGO")]
+    CCBYThreeDotZeroIGO,
+    #[value(name = "CC-BY-3.0-NL")]
+    CCBYThreeDotZeroNL,
+    #[value(name = "CC-BY-3.0-US")]
+    CCBYThreeDotZeroUS,
+    #[value(name = "CC-BY-4.0")]
+    CCBYFourDotZero,
+    #[value(name = "CC-BY-NC-1.0")]
+    CCBYNCOneDotZero,
+    #[value(name = "CC-BY-NC-2.0")]
+    CCBYNCTwoDotZero,
+    #[value(name = "CC-BY-NC-2.5")]
+    CCBYNCTwoDotFive,
+    #[value(name = "CC-BY-NC-3.0")]
+    CCBYNCThreeDotZero,
     #[value(name = "CC-BY-NC-3.0-DE")]
     CCBYNCThreeDotZeroDE,
-    #[value(name = "FreeImage")]
-    FreeImage,
-    #[value(name = "CECILL-2.1")]
-    CECILLTwoDotOne,
-    #[value(name = "DRL-1.0")]
-    DRLOneDotZero,
-    #[value(name = "HPND-export-US-acknowledgement")]
-    HPNDExportUSAcknowledgement,
-    #[value(name = "OLDAP-2.0.1")]
-    OLDAPTwoDotZeroDotOne,
-    #[value(name = "threeparttable")]
-    Threeparttable,
-    #[value(name = "MIT-open-group")]
-    MITOpenGroup,
-    #[value(name = "FSFULLR")]
-    FSFULLR,
-    #[value(name = "Autoconf-exception-macro")]
-    AutoconfExceptionMacro,
-    #[value(name = "NCL")]
-    NCL,
-    #[value(name = "EPICS")]
-    EPICS,
-    #[value(name = "Apache-1.0")]
-    ApacheOneDotZero,
-    #[value(name = "JSON")]
-    JSON,
-    #[value(name = "deprecated_GFDL-1.2")]
-    DeprecatedGFDLOneDotTwo,
-    #[value(name = "Spencer-86")]
-    SpencerEightSix,
-    #[value(name = "BSD-4-Clause")]
-    BSDFourClause,
-    #[value(name = "SHL-0.51")]
-    SHLZeroDotFiveOne,
-    #[value(name = "IJG")]
-    IJG,
-    #[value(name = "APSL-1.2")]
-    APSLOneDotTwo,
-    #[value(name = "deprecated_GPL-2.0+")]
-    DeprecatedGPLTwoDotZeroPlus,
-    #[value(name = "erlang-otp-linking-exception")]
-    ErlangOtpLinkingException,
-    #[value(name = "SAX-PD")]
-    SAXPD,
-    #[value(name = "PSF-2.0")]
-    PSFTwoDotZero,
-    #[value(name = "BSD-3-Clause-Modification")]
-    BSDThreeClauseModification,
-    #[value(name = "QPL-1.0-INRIA-2004")]
-    QPLOneDotZeroINRIATwoZeroZeroFour,
-    #[value(name = "deprecated_GPL-2.0-with-classpath-exception")]
-    DeprecatedGPLTwoDotZeroWithClasspathException,
-    #[value(name = "EUPL-1.2")]
-    EUPLOneDotTwo,
-    #[value(name = "BSD-2-Clause-first-lines")]
-    BSDTwoClauseFirstLines,
-    #[value(name = "GNOME-examples-exception")]
-    GNOMEExamplesException,
-    #[value(name = "X11-distribute-modifications-variant")]
-    XOneOneDistributeModificationsVariant,
-    #[value(name = "LPL-1.0")]
-    LPLOneDotZero,
-    #[value(name = "Spencer-94")]
-    SpencerNineFour,
+    #[value(name = "CC-BY-NC-4.0")]
+    CCBYNCFourDotZero,
+    #[value(name = "CC-BY-NC-ND-1.0")]
+    CCBYNCNDOneDotZero,
+    #[value(name = "CC-BY-NC-ND-2.0")]
+    CCBYNCNDTwoDotZero,
+    #[value(name = "CC-BY-NC-ND-2.5")]
+    CCBYNCNDTwoDotFive,
+    #[value(name = "CC-BY-NC-ND-3.0")]
+    CCBYNCNDThreeDotZero,
+    #[value(name = "CC-BY-NC-ND-3.0-DE")]
+    CCBYNCNDThreeDotZeroDE,
+    #[value(name = "CC-BY-NC-ND-3.0-IGO")]
+    CCBYNCNDThreeDotZeroIGO,
+    #[value(name = "CC-BY-NC-ND-4.0")]
+    CCBYNCNDFourDotZero,
+    #[value(name = "CC-BY-NC-SA-1.0")]
+    CCBYNCSAOneDotZero,
+    #[value(name = "CC-BY-NC-SA-2.0")]
+    CCBYNCSATwoDotZero,
+    #[value(name = "CC-BY-NC-SA-2.0-DE")]
+    CCBYNCSATwoDotZeroDE,
+    #[value(name = "CC-BY-NC-SA-2.0-FR")]
+    CCBYNCSATwoDotZeroFR,
+    #[value(name = "CC-BY-NC-SA-2.0-UK")]
+    CCBYNCSATwoDotZeroUK,
     #[value(name = "CC-BY-NC-SA-2.5")]
     CCBYNCSATwoDotFive,
-    #[value(name = "u-boot-exception-2.0")]
-    UBootExceptionTwoDotZero,
+    #[value(name = "CC-BY-NC-SA-3.0")]
+    CCBYNCSAThreeDotZero,
+    #[value(name = "CC-BY-NC-SA-3.0-DE")]
+    CCBYNCSAThreeDotZeroDE,
+    #[value(name = "CC-BY-NC-SA-3.0-IGO")]
+    CCBYNCSAThreeDotZeroIGO,
+    #[value(name = "CC-BY-NC-SA-4.0")]
+    CCBYNCSAFourDotZero,
+    #[value(name = "CC-BY-ND-1.0")]
+    CCBYNDOneDotZero,
+    #[value(name = "CC-BY-ND-2.0")]
+    CCBYNDTwoDotZero,
+    #[value(name = "CC-BY-ND-2.5")]
+    CCBYNDTwoDotFive,
+    #[value(name = "CC-BY-ND-3.0")]
+    CCBYNDThreeDotZero,
+    #[value(name = "CC-BY-ND-3.0-DE")]
+    CCBYNDThreeDotZeroDE,
+    #[value(name = "CC-BY-ND-4.0")]
+    CCBYNDFourDotZero,
+    #[value(name = "CC-BY-SA-1.0")]
+    CCBYSAOneDotZero,
+    #[value(name = "CC-BY-SA-2.0")]
+    CCBYSATwoDotZero,
+    #[value(name = "CC-BY-SA-2.0-UK")]
+    CCBYSATwoDotZeroUK,
+    #[value(name = "CC-BY-SA-2.1-JP")]
+    CCBYSATwoDotOneJP,
     #[value(name = "CC-BY-SA-2.5")]
     CCBYSATwoDotFive,
-    #[value(name = "Newsletr")]
-    Newsletr,
-    #[value(name = "Noweb")]
-    Noweb,
-    #[value(name = "APL-1.0")]
-    APLOneDotZero,
-    #[value(name = "w3m")]
-    WThreem,
-    #[value(name = "FSL-1.1-MIT")]
-    FSLOneDotOneMIT,
-    #[value(name = "Texinfo-exception")]
-    TexinfoException,
-    #[value(name = "GFDL-1.2-no-invariants-or-later")]
-    GFDLOneDotTwoNoInvariantsOrLater,
-    #[value(name = "Artistic-1.0")]
-    ArtisticOneDotZero,
-    #[value(name = "EFL-1.0")]
-    EFLOneDotZero,
-    #[value(name = "Watcom-1.0")]
-    WatcomOneDotZero,
-    #[value(name = "CC-BY-3.0-AU")]
-    CCBYThreeDotZeroAU,
-    #[value(name = "ISC")]
-    ISC,
-    #[value(name = "zlib-acknowledgement")]
-    ZlibAcknowledgement,
-    #[value(name = "TCP-wrappers")]
-    TCPWrappers,
-    #[value(name = "Universal-FOSS-exception-1.0")]
-    UniversalFOSSExceptionOneDotZero,
-    #[value(name = "hdparm")]
-    Hdparm,
-    #[value(name = "SHL-2.0")]
-    SHLTwoDotZero,
-    #[value(name = "OLDAP-2.2.1")]
-    OLDAPTwoDotTwoDotOne,
-    #[value(name = "GFDL-1.3-no-invariants-only")]
-    GFDLOneDotThreeNoInvariantsOnly,
-    #[value(name = "eGenix")]
-    EGenix,
-    #[value(name = "AGPL-1.0-only")]
-    AGPLOneDotZeroOnly,
-    #[value(name = "FSFAP")]
-    FSFAP,
-    #[value(name = "Spencer-99")]
-    SpencerNineNine,
-    #[value(name = "OLDAP-2.3")]
-    OLDAPTwoDotThree,
-    #[value(name = "Gmsh-exception")]
-    GmshException,
-    #[value(name = "dvipdfm")]
-    Dvipdfm,
-    #[value(name = "deprecated_Net-SNMP")]
-    DeprecatedNetSNMP,
-    #[value(name = "LZMA-exception")]
-    LZMAException,
-    #[value(name = "Libpng")]
-    Libpng,
-    #[value(name = "Xnet")]
-    Xnet,
-    #[value(name = "HPND-merchantability-variant")]
-    HPNDMerchantabilityVariant,
-    #[value(name = "Intel")]
-    Intel,
-    #[value(name = "OLDAP-2.4")]
-    OLDAPTwoDotFour,
-    #[value(name = "W3C-20150513")]
-    WThreeCTwoZeroOneFiveZeroFiveOneThree,
-    #[value(name = "MIT-Khronos-old")]
-    MITKhronosOld,
+    #[value(name = "CC-BY-SA-3.0")]
+    CCBYSAThreeDotZero,
+    #[value(name = "CC-BY-SA-3.0-AT")]
+    CCBYSAThreeDotZeroAT,
+    #[value(name = "CC-BY-SA-3.0-DE")]
+    CCBYSAThreeDotZeroDE,
+    #[value(name = "CC-BY-SA-3.0-IGO")]
+    CCBYSAThreeDotZeroIGO,
+    #[value(name = "CC-BY-SA-4.0")]
+    CCBYSAFourDotZero,
+    #[value(name = "CC-PDDC")]
+    CCPDDC,
+    #[value(name = "CC-PDM-1.0")]
+    CCPDMOneDotZero,
+    #[value(name = "CC-SA-1.0")]
+    CCSAOneDotZero,
+    #[value(name = "CC0-1.0")]
+    CcZeroOneDotZero,
+    #[value(name = "CDDL-1.0")]
+    CDDLOneDotZero,
+    #[value(name = "CDDL-1.1")]
+    CDDLOneDotOne,
+    #[value(name = "CDL-1.0")]
+    CDLOneDotZero,
+    #[value(name = "CDLA-Permissive-1.0")]
+    CDLAPermissiveOneDotZero,
     #[value(name = "CDLA-Permissive-2.0")]
     CDLAPermissiveTwoDotZero,
-    #[value(name = "CC-BY-2.0")]
-    CCBYTwoDotZero,
-    #[value(name = "mpi-permissive")]
-    MpiPermissive,
-    #[value(name = "deprecated_GPL-2.0-with-autoconf-exception")]
-    DeprecatedGPLTwoDotZeroWithAutoconfException,
-    #[value(name = "MIPS")]
-    MIPS,
-    #[value(name = "Cube")]
-    Cube,
-    #[value(name = "OSL-1.1")]
-    OSLOneDotOne,
-    #[value(name = "MPL-2.0-no-copyleft-exception")]
-    MPLTwoDotZeroNoCopyleftException,
-    #[value(name = "NLOD-1.0")]
-    NLODOneDotZero,
-    #[value(name = "GFDL-1.1-or-later")]
-    GFDLOneDotOneOrLater,
-    #[value(name = "Widget-Workshop")]
-    WidgetWorkshop,
-    #[value(name = "CATOSL-1.1")]
-    CATOSLOneDotOne,
-    #[value(name = "cryptsetup-OpenSSL-exception")]
-    CryptsetupOpenSslException,
-    #[value(name = "BSD-4.3RENO")]
-    BSDFourDotThreeReno,
-    #[value(name = "BSD-Systemics")]
-    BSDSystemics,
-    #[value(name = "Boehm-GC-without-fee")]
-    BoehmGCWithoutFee,
-    #[value(name = "GCC-exception-2.0-note")]
-    GCCExceptionTwoDotZeroNote,
-    #[value(name = "AGPL-3.0-only")]
-    AGPLThreeDotZeroOnly,
-    #[value(name = "BSD-Systemics-W3Works")]
-    BSDSystemicsWThreeWorks,
-    #[value(name = "BSD-3-Clause-Open-MPI")]
-    BSDThreeClauseOpenMPI,
-    #[value(name = "OCLC-2.0")]
-    OCLCTwoDotZero,
-    #[value(name = "LAL-1.2")]
-    LALOneDotTwo,
-    #[value(name = "Xdebug-1.03")]
-    XdebugOneDotZeroThree,
-    #[value(name = "FSL-1.1-ALv2")]
-    FSLOneDotOneALvTwo,
-    #[value(name = "HPND-UC-export-US")]
-    HPNDUCExportUS,
-    #[value(name = "ECL-1.0")]
-    ECLOneDotZero,
-    #[value(name = "CC-BY-NC-ND-3.0-IGO")]
-    CCBYNCNDThreeDotZeroIGO,
-    #[value(name = "OGL-Canada-2.0")]
-    OGLCanadaTwoDotZero,
-    #[value(name = "AGPL-1.0-or-later")]
-    AGPLOneDotZeroOrLater,
-    #[value(name = "LGPLLR")]
-    LGPLLR,
-    #[value(name = "Fair")]
-    Fair,
-    #[value(name = "Swift-exception")]
-    SwiftException,
-    #[value(name = "JasPer-2.0")]
-    JasPerTwoDotZero,
+    #[value(name = "CDLA-Sharing-1.0")]
+    CDLASharingOneDotZero,
     #[value(name = "CECILL-1.0")]
     CECILLOneDotZero,
-    #[value(name = "GPL-3.0-linking-source-exception")]
-    GPLThreeDotZeroLinkingSourceException,
-    #[value(name = "BSD-Source-Code")]
-    BSDSourceCode,
-    #[value(name = "metamail")]
-    Metamail,
-    #[value(name = "LLVM-exception")]
-    LLVMException,
-    #[value(name = "VSL-1.0")]
-    VSLOneDotZero,
-    #[value(name = "LPPL-1.3c")]
-    LPPLOneDotThreec,
-    #[value(name = "Furuseth")]
-    Furuseth,
-    #[value(name = "BSD-3-Clause-acpica")]
-    BSDThreeClauseAcpica,
+    #[value(name = "CECILL-1.1")]
+    CECILLOneDotOne,
+    #[value(name = "CECILL-2.0")]
+    CECILLTwoDotZero,
+    #[value(name = "CECILL-2.1")]
+    CECILLTwoDotOne,
+    #[value(name = "CECILL-B")]
+    CECILLB,
+    #[value(name = "CECILL-C")]
+    CECILLC,
+    #[value(name = "CERN-OHL-1.1")]
+    CERNOHLOneDotOne,
+    #[value(name = "CERN-OHL-1.2")]
+    CERNOHLOneDotTwo,
+    #[value(name = "CERN-OHL-P-2.0")]
+    CERNOHLPTwoDotZero,
+    #[value(name = "CERN-OHL-S-2.0")]
+    CERNOHLSTwoDotZero,
+    #[value(name = "CERN-OHL-W-2.0")]
+    CERNOHLWTwoDotZero,
+    #[value(name = "CFITSIO")]
+    CFITSIO,
+    #[value(name = "CMU-Mach")]
+    CMUMach,
+    #[value(name = "CMU-Mach-nodoc")]
+    CMUMachNodoc,
     #[value(name = "CNRI-Jython")]
     CNRIJython,
-    #[value(name = "LiLiQ-P-1.1")]
-    LiLiQPOneDotOne,
-    #[value(name = "Ferguson-Twofish")]
-    FergusonTwofish,
-    #[value(name = "HPND-INRIA-IMAG")]
-    HPNDINRIAIMAG,
-    #[value(name = "Bitstream-Charter")]
-    BitstreamCharter,
-    #[value(name = "Unicode-DFS-2016")]
-    UnicodeDFSTwoZeroOneSix,
-    #[value(name = "MIT-Modern-Variant")]
-    MITModernVariant,
-    #[value(name = "deprecated_GPL-3.0+")]
-    DeprecatedGPLThreeDotZeroPlus,
-    #[value(name = "Afmparse")]
-    Afmparse,
-    #[value(name = "HPND-Fenneberg-Livingston")]
-    HPNDFennebergLivingston,
-    #[value(name = "wwl")]
-    Wwl,
-    #[value(name = "ClArtistic")]
-    ClArtistic,
-    #[value(name = "HPND-Markus-Kuhn")]
-    HPNDMarkusKuhn,
-    #[value(name = "blessing")]
-    Blessing,
-    #[value(name = "softSurfer")]
-    SoftSurfer,
-    #[value(name = "Bison-exception-1.24")]
-    BisonExceptionOneDotTwoFour,
-    #[value(name = "CrystalStacker")]
-    CrystalStacker,
-    #[value(name = "AML")]
-    AML,
-    #[value(name = "NCBI-PD")]
-    NCBIPD,
-    #[value(name = "GFDL-1.2-invariants-or-later")]
-    GFDLOneDotTwoInvariantsOrLater,
-    #[value(name = "deprecated_eCos-2.0")]
-    DeprecatedECosTwoDotZero,
-    #[value(name = "OLDAP-2.5")]
-    OLDAPTwoDotFive,
-    #[value(name = "AMPAS")]
-    AMPAS,
-    #[value(name = "GFDL-1.1-no-invariants-or-later")]
-    GFDLOneDotOneNoInvariantsOrLater,
-    #[value(name = "CC-BY-4.0")]
-    CCBYFourDotZero,
-    #[value(name = "OLDAP-2.2")]
-    OLDAPTwoDotTwo,
     #[value(name = "CNRI-Python")]
     CNRIPython,
-    #[value(name = "BSL-1.0")]
-    BSLOneDotZero,
-    #[value(name = "SMAIL-GPL")]
-    SMAILGPL,
-    #[value(name = "TU-Berlin-1.0")]
-    TUBerlinOneDotZero,
-    #[value(name = "VOSTROM")]
-    VOSTROM,
-    #[value(name = "libpng-2.0")]
-    LibpngTwoDotZero,
-    #[value(name = "gnu-javamail-exception")]
-    GnuJavamailException,
-    #[value(name = "CC-BY-NC-1.0")]
-    CCBYNCOneDotZero,
-    #[value(name = "GPL-3.0-389-ds-base-exception")]
-    GPLThreeDotZeroThreeEightNineDsBaseException,
-    #[value(name = "OSL-1.0")]
-    OSLOneDotZero,
-    #[value(name = "Unicode-3.0")]
-    UnicodeThreeDotZero,
-    #[value(name = "GFDL-1.3-or-later")]
-    GFDLOneDotThreeOrLater,
-    #[value(name = "InnoSetup")]
-    InnoSetup,
-    #[value(name = "CAL-1.0")]
-    CALOneDotZero,
-    #[value(name = "CC-BY-SA-3.0-IGO")]
-    CCBYSAThreeDotZeroIGO,
-    #[value(name = "Saxpath")]
-    Saxpath,
-    #[value(name = "OPUBL-1.0")]
-    OPUBLOneDotZero,
-    #[value(name = "Linux-man-pages-copyleft-2-para")]
-    LinuxManPagesCopyleftTwoPara,
-    #[value(name = "CC-BY-3.0-AT")]
-    CCBYThreeDotZeroAT,
-    #[value(name = "GNAT-exception")]
-    GNATException,
-    #[value(name = "CERN-OHL-P-2.0")]
-    CERNOHLPTwoDotZero,
-    #[value(name = "x11vnc-openssl-exception")]
-    XOneOnevncOpensslException,
-    #[value(name = "FSFUL")]
-    FSFUL,
-    #[value(name = "OPL-UK-3.0")]
-    OPLUKThreeDotZero,
-    #[value(name = "UMich-Merit")]
-    UMichMerit,
-    #[value(name = "Cornell-Lossless-JPEG")]
-    CornellLosslessJPEG,
-    #[value(name = "OGDL-Taiwan-1.0")]
-    OGDLTaiwanOneDotZero,
-    #[value(name = "BSD-3-Clause-HP")]
-    BSDThreeClauseHP,
-    #[value(name = "Plexus")]
-    Plexus,
-    #[value(name = "bcrypt-Solar-Designer")]
-    BcryptSolarDesigner,
-    #[value(name = "CC-BY-NC-SA-2.0-UK")]
-    CCBYNCSATwoDotZeroUK,
-    #[value(name = "SGI-B-2.0")]
-    SGIBTwoDotZero,
-    #[value(name = "CC-BY-3.0-IGO")]
-    CCBYThreeDotZeroIGO,
-    #[value(name = "Hippocratic-2.1")]
-    HippocraticTwoDotOne,
-    #[value(name = "SHL-2.1")]
-    SHLTwoDotOne,
-    #[value(name = "KiCad-libraries-exception")]
-    KiCadLibrariesException,
+    #[value(name = "CNRI-Python-GPL-Compatible")]
+    CNRIPythonGPLCompatible,
+    #[value(name = "COIL-1.0")]
+    COILOneDotZero,
+    #[value(name = "CPAL-1.0")]
+    CPALOneDotZero,
+    #[value(name = "CPL-1.0")]
+    CPLOneDotZero,
     #[value(name = "CPOL-1.02")]
     CPOLOneDotZeroTwo,
-    #[value(name = "deprecated_GPL-1.0")]
-    DeprecatedGPLOneDotZero,
-    #[value(name = "Cronyx")]
-    Cronyx,
-    #[value(name = "Latex2e-translated-notice")]
-    LatexTwoeTranslatedNotice,
-    #[value(name = "Knuth-CTAN")]
-    KnuthCTAN,
-    #[value(name = "CC-BY-3.0")]
-    CCBYThreeDotZero,
-    #[value(name = "bzip2-1.0.6")]
-    BzipTwoOneDotZeroDotSix,
-    #[value(name = "OCCT-PL")]
-    OCCTPL,
-    #[value(name = "Sendmail-8.23")]
-    SendmailEightDotTwoThree,
+    #[value(name = "CUA-OPL-1.0")]
+    CUAOPLOneDotZero,
+    #[value(name = "Caldera")]
+    Caldera,
+    #[value(name = "Caldera-no-preamble")]
+    CalderaNoPreamble,
     #[value(name = "Catharon")]
     Catharon,
-    #[value(name = "IPL-1.0")]
-    IPLOneDotZero,
-    #[value(name = "deprecated_GPL-2.0-with-font-exception")]
-    DeprecatedGPLTwoDotZeroWithFontException,
-    #[value(name = "APAFML")]
-    APAFML,
-    #[value(name = "Motosoto")]
-    Motosoto,
-    #[value(name = "check-cvs")]
-    CheckCvs,
-    #[value(name = "Sendmail")]
-    Sendmail,
-    #[value(name = "PPL")]
-    PPL,
-    #[value(name = "PostgreSQL")]
-    PostgreSql,
-    #[value(name = "CDL-1.0")]
-    CDLOneDotZero,
-    #[value(name = "GPL-1.0-or-later")]
-    GPLOneDotZeroOrLater,
-    #[value(name = "CC-SA-1.0")]
-    CCSAOneDotZero,
-    #[value(name = "IBM-pibs")]
-    IBMPibs,
-    #[value(name = "CERN-OHL-1.2")]
-    CERNOHLOneDotTwo,
-    #[value(name = "Intel-ACPI")]
-    IntelACPI,
-    #[value(name = "Adobe-2006")]
-    AdobeTwoZeroZeroSix,
-    #[value(name = "deprecated_LGPL-2.0+")]
-    DeprecatedLGPLTwoDotZeroPlus,
-    #[value(name = "SPL-1.0")]
-    SPLOneDotZero,
-    #[value(name = "OML")]
-    OML,
+    #[value(name = "ClArtistic")]
+    ClArtistic,
+    #[value(name = "Clips")]
+    Clips,
+    #[value(name = "Community-Spec-1.0")]
+    CommunitySpecOneDotZero,
+    #[value(name = "Condor-1.1")]
+    CondorOneDotOne,
+    #[value(name = "Cornell-Lossless-JPEG")]
+    CornellLosslessJPEG,
+    #[value(name = "Cronyx")]
+    Cronyx,
+    #[value(name = "Crossword")]
+    Crossword,
+    #[value(name = "CryptoSwift")]
+    CryptoSwift,
+    #[value(name = "CrystalStacker")]
+    CrystalStacker,
+    #[value(name = "Cube")]
+    Cube,
+    #[value(name = "D-FSL-1.0")]
+    DFSLOneDotZero,
+    #[value(name = "DEC-3-Clause")]
+    DECThreeClause,
+    #[value(name = "DL-DE-BY-2.0")]
+    DLDEBYTwoDotZero,
+    #[value(name = "DL-DE-ZERO-2.0")]
+    DLDEZEROTwoDotZero,
     #[value(name = "DOC")]
     DOC,
-    #[value(name = "MIT-Click")]
-    MITClick,
-    #[value(name = "mxml-exception")]
-    MxmlException,
-    #[value(name = "Apache-2.0")]
-    ApacheTwoDotZero,
-    #[value(name = "NCGL-UK-2.0")]
-    NCGLUKTwoDotZero,
-    #[value(name = "HTMLTIDY")]
-    HTMLTIDY,
-    #[value(name = "ANTLR-PD")]
-    ANTLRPD,
-    #[value(name = "LAL-1.3")]
-    LALOneDotThree,
-    #[value(name = "BSD-Inferno-Nettverk")]
-    BSDInfernoNettverk,
-    #[value(name = "RPL-1.5")]
-    RPLOneDotFive,
-    #[value(name = "CC0-1.0")]
-    CcZeroOneDotZero,
-    #[value(name = "CECILL-1.1")]
-    CECILLOneDotOne,
-    #[value(name = "AML-glslang")]
-    AMLGlslang,
-    #[value(name = "Digia-Qt-LGPL-exception-1.1")]
-    DigiaQtLGPLExceptionOneDotOne,
+    #[value(name = "DRL-1.0")]
+    DRLOneDotZero,
+    #[value(name = "DRL-1.1")]
+    DRLOneDotOne,
+    #[value(name = "DSDP")]
+    DSDP,
+    #[value(name = "DocBook-DTD")]
+    DocBookDTD,
+    #[value(name = "DocBook-Schema")]
+    DocBookSchema,
+    #[value(name = "DocBook-Stylesheet")]
+    DocBookStylesheet,
+    #[value(name = "DocBook-XML")]
+    DocBookXML,
+    #[value(name = "Dotseqn")]
+    Dotseqn,
+    #[value(name = "ECL-1.0")]
+    ECLOneDotZero,
+    #[value(name = "ECL-2.0")]
+    ECLTwoDotZero,
+    #[value(name = "EFL-1.0")]
+    EFLOneDotZero,
+    #[value(name = "EFL-2.0")]
+    EFLTwoDotZero,
+    #[value(name = "EPICS")]
+    EPICS,
+    #[value(name = "EPL-1.0")]
+    EPLOneDotZero,
+    #[value(name = "EPL-2.0")]
+    EPLTwoDotZero,
+    #[value(name = "EUDatagrid")]
+    EuDatagrid,
+    #[value(name = "EUPL-1.0")]
+    EUPLOneDotZero,
+    #[value(name = "EUPL-1.1")]
+    EUPLOneDotOne,
+    #[value(name = "EUPL-1.2")]
+    EUPLOneDotTwo,
+    #[value(name = "Elastic-2.0")]
+    ElasticTwoDotZero,
+    #[value(name = "Entessa")]
+    Entessa,
+    #[value(name = "ErlPL-1.1")]
+    ErlPlOneDotOne,
+    #[value(name = "Eurosym")]
+    Eurosym,
+    #[value(name = "FBM")]
+    FBM,
+    #[value(name = "FDK-AAC")]
+    FDKAAC,
+    #[value(name = "FSFAP")]
+    FSFAP,
+    #[value(name = "FSFAP-no-warranty-disclaimer")]
+    FSFAPNoWarrantyDisclaimer,
+    #[value(name = "FSFUL")]
+    FSFUL,
+    #[value(name = "FSFULLR")]
+    FSFULLR,
+    #[value(name = "FSFULLRWD")]
+    FSFULLRWD,
+    #[value(name = "FSL-1.1-ALv2")]
+    FSLOneDotOneALvTwo,
+    #[value(name = "FSL-1.1-MIT")]
+    FSLOneDotOneMIT,
+    #[value(name = "FTL")]
+    FTL,
+    #[value(name = "Fair")]
+    Fair,
+    #[value(name = "Ferguson-Twofish")]
+    FergusonTwofish,
+    #[value(name = "Frameworx-1.0")]
+    FrameworxOneDotZero,
+    #[value(name = "FreeBSD-DOC")]
+    FreeBsdDOC,
+    #[value(name = "FreeImage")]
+    FreeImage,
+    #[value(name = "Furuseth")]
+    Furuseth,
     #[value(name = "GCR-docs")]
     GCRDocs,
+    #[value(name = "GD")]
+    GD,
+    #[value(name = "GFDL-1.1-invariants-only")]
+    GFDLOneDotOneInvariantsOnly,
+    #[value(name = "GFDL-1.1-invariants-or-later")]
+    GFDLOneDotOneInvariantsOrLater,
+    #[value(name = "GFDL-1.1-no-invariants-only")]
+    GFDLOneDotOneNoInvariantsOnly,
+    #[value(name = "GFDL-1.1-no-invariants-or-later")]
+    GFDLOneDotOneNoInvariantsOrLater,
+    #[value(name = "GFDL-1.1-only")]
+    GFDLOneDotOneOnly,
+    #[value(name = "GFDL-1.1-or-later")]
+    GFDLOneDotOneOrLater,
+    #[value(name = "GFDL-1.2-invariants-only")]
+    GFDLOneDotTwoInvariantsOnly,
+    #[value(name = "GFDL-1.2-invariants-or-later")]
+    GFDLOneDotTwoInvariantsOrLater,
+    #[value(name = "GFDL-1.2-no-invariants-only")]
+    GFDLOneDotTwoNoInvariantsOnly,
+    #[value(name = "GFDL-1.2-no-invariants-or-later")]
+    GFDLOneDotTwoNoInvariantsOrLater,
+    #[value(name = "GFDL-1.2-only")]
+    GFDLOneDotTwoOnly,
+    #[value(name = "GFDL-1.2-or-later")]
+    GFDLOneDotTwoOrLater,
+    #[value(name = "GFDL-1.3-invariants-only")]
+    GFDLOneDotThreeInvariantsOnly,
+    #[value(name = "GFDL-1.3-invariants-or-later")]
+    GFDLOneDotThreeInvariantsOrLater,
+    #[value(name = "GFDL-1.3-no-invariants-only")]
+    GFDLOneDotThreeNoInvariantsOnly,
+    #[value(name = "GFDL-1.3-no-invariants-or-later")]
+    GFDLOneDotThreeNoInvariantsOrLater,
+    #[value(name = "GFDL-1.3-only")]
+    GFDLOneDotThreeOnly,
+    #[value(name = "GFDL-1.3-or-later")]
+    GFDLOneDotThreeOrLater,
+    #[value(name = "GL2PS")]
+    GlTwoPs,
+    #[value(name = "GLWTPL")]
+    GLWTPL,
+    #[value(name = "GPL-1.0-only")]
+    GPLOneDotZeroOnly,
+    #[value(name = "GPL-1.0-or-later")]
+    GPLOneDotZeroOrLater,
+    #[value(name = "GPL-2.0-only")]
+    GPLTwoDotZeroOnly,
+    #[value(name = "GPL-2.0-or-later")]
+    GPLTwoDotZeroOrLater,
+    #[value(name = "GPL-3.0-only")]
+    GPLThreeDotZeroOnly,
+    #[value(name = "GPL-3.0-or-later")]
+    GPLThreeDotZeroOrLater,
+    #[value(name = "Game-Programming-Gems")]
+    GameProgrammingGems,
+    #[value(name = "Giftware")]
+    Giftware,
     #[value(name = "Glide")]
     Glide,
-    #[value(name = "CC-BY-SA-2.0-UK")]
-    CCBYSATwoDotZeroUK,
     #[value(name = "Glulxe")]
     Glulxe,
-    #[value(name = "MIT-0")]
-    MITZero,
-    #[value(name = "BSD-4-Clause-UC")]
-    BSDFourClauseUC,
-    #[value(name = "deprecated_LGPL-2.1")]
-    DeprecatedLGPLTwoDotOne,
-    #[value(name = "AFL-2.1")]
-    AFLTwoDotOne,
-    #[value(name = "eCos-exception-2.0")]
-    ECosExceptionTwoDotZero,
-    #[value(name = "CERN-OHL-W-2.0")]
-    CERNOHLWTwoDotZero,
-    #[value(name = "Minpack")]
-    Minpack,
-    #[value(name = "HPND-Netrek")]
-    HPNDNetrek,
-    #[value(name = "DocBook-Schema")]
-    DocBookSchema,
-    #[value(name = "man2html")]
-    ManTwohtml,
-    #[value(name = "LGPL-2.1-or-later")]
-    LGPLTwoDotOneOrLater,
-    #[value(name = "CMU-Mach")]
-    CMUMach,
-    #[value(name = "OLDAP-2.0")]
-    OLDAPTwoDotZero,
-    #[value(name = "Borceux")]
-    Borceux,
-    #[value(name = "PHP-3.0")]
-    PHPThreeDotZero,
-    #[value(name = "HPND-DEC")]
-    HPNDDEC,
-    #[value(name = "radvd")]
-    Radvd,
-    #[value(name = "OGTSL")]
-    OGTSL,
-    #[value(name = "OLDAP-2.7")]
-    OLDAPTwoDotSeven,
-    #[value(name = "OGL-UK-2.0")]
-    OGLUKTwoDotZero,
-    #[value(name = "LGPL-3.0-linking-exception")]
-    LGPLThreeDotZeroLinkingException,
-    #[value(name = "TTYP0")]
-    TtypZero,
-    #[value(name = "Zed")]
-    Zed,
-    #[value(name = "PADL")]
-    PADL,
-    #[value(name = "OFL-1.1-RFN")]
-    OFLOneDotOneRFN,
-    #[value(name = "OLDAP-2.2.2")]
-    OLDAPTwoDotTwoDotTwo,
-    #[value(name = "diffmark")]
-    Diffmark,
-    #[value(name = "W3C")]
-    WThreeC,
-    #[value(name = "HPND-sell-variant-MIT-disclaimer")]
-    HPNDSellVariantMITDisclaimer,
-    #[value(name = "Artistic-1.0-Perl")]
-    ArtisticOneDotZeroPerl,
-    #[value(name = "OCaml-LGPL-linking-exception")]
-    OCamlLGPLLinkingException,
-    #[value(name = "Python-2.0")]
-    PythonTwoDotZero,
-    #[value(name = "Mackerras-3-Clause-acknowledgment")]
-    MackerrasThreeClauseAcknowledgment,
-    #[value(name = "CC-BY-NC-ND-1.0")]
-    CCBYNCNDOneDotZero,
-    #[value(name = "CC-BY-ND-1.0")]
-    CCBYNDOneDotZero,
-    #[value(name = "Mup")]
-    Mup,
-    #[value(name = "TPDL")]
-    TPDL,
+    #[value(name = "Graphics-Gems")]
+    GraphicsGems,
+    #[value(name = "Gutmann")]
+    Gutmann,
     #[value(name = "HIDAPI")]
     HIDAPI,
-    #[value(name = "Info-ZIP")]
-    InfoZIP,
-    #[value(name = "deprecated_LGPL-3.0")]
-    DeprecatedLGPLThreeDotZero,
-    #[value(name = "AFL-3.0")]
-    AFLThreeDotZero,
+    #[value(name = "HP-1986")]
+    HPOneNineEightSix,
     #[value(name = "HP-1989")]
     HPOneNineEightNine,
-    #[value(name = "FSFULLRWD")]
-    FSFULLRWD,
-    #[value(name = "Leptonica")]
-    Leptonica,
-    #[value(name = "Graphics-Gems")]
-    GraphicsGems,
-    #[value(name = "Unicode-DFS-2015")]
-    UnicodeDFSTwoZeroOneFive,
-    #[value(name = "TrustedQSL")]
-    TrustedQsl,
-    #[value(name = "APSL-2.0")]
-    APSLTwoDotZero,
-    #[value(name = "NLPL")]
-    NLPL,
-    #[value(name = "Giftware")]
-    Giftware,
-    #[value(name = "SSPL-1.0")]
-    SSPLOneDotZero,
-    #[value(name = "CC-BY-2.5-AU")]
-    CCBYTwoDotFiveAU,
+    #[value(name = "HPND")]
+    HPND,
+    #[value(name = "HPND-DEC")]
+    HPNDDEC,
+    #[value(name = "HPND-Fenneberg-Livingston")]
+    HPNDFennebergLivingston,
+    #[value(name = "HPND-INRIA-IMAG")]
+    HPNDINRIAIMAG,
+    #[value(name = "HPND-Intel")]
+    HPNDIntel,
+    #[value(name = "HPND-Kevlin-Henney")]
+    HPNDKevlinHenney,
+    #[value(name = "HPND-MIT-disclaimer")]
+    HPNDMITDisclaimer,
+    #[value(name = "HPND-Markus-Kuhn")]
+    HPNDMarkusKuhn,
+    #[value(name = "HPND-Netrek")]
+    HPNDNetrek,
+    #[value(name = "HPND-Pbmplus")]
+    HPNDPbmplus,
+    #[value(name = "HPND-UC")]
+    HPNDUC,
+    #[value(name = "HPND-UC-export-US")]
+    HPNDUCExportUS,
+    #[value(name = "HPND-doc")]
+    HPNDDoc,
+    #[value(name = "HPND-doc-sell")]
+    HPNDDocSell,
+    #[value(name = "HPND-export-US")]
+    HPNDExportUS,
+    #[value(name = "HPND-export-US-acknowledgement")]
+    HPNDExportUSAcknowledgement,
+    #[value(name = "HPND-export-US-modify")]
+    HPNDExportUSModify,
+    #[value(name = "HPND-export2-US")]
+    HPNDExportTwoUS,
+    #[value(name = "HPND-merchantability-variant")]
+    HPNDMerchantabilityVariant,
+    #[value(name = "HPND-sell-MIT-disclaimer-xserver")]
+    HPNDSellMITDisclaimerXserver,
+    #[value(name = "HPND-sell-regexpr")]
+    HPNDSellRegexpr,
+    #[value(name = "HPND-sell-variant")]
+    HPNDSellVariant,
+    #[value(name = "HPND-sell-variant-MIT-disclaimer")]
+    HPNDSellVariantMITDisclaimer,
     #[value(name = "HPND-sell-variant-MIT-disclaimer-rev")]
     HPNDSellVariantMITDisclaimerRev,
-    #[value(name = "deprecated_GPL-1.0+")]
-    DeprecatedGPLOneDotZeroPlus,
-    #[value(name = "libtiff")]
-    Libtiff,
+    #[value(name = "HTMLTIDY")]
+    HTMLTIDY,
+    #[value(name = "HaskellReport")]
+    HaskellReport,
+    #[value(name = "Hippocratic-2.1")]
+    HippocraticTwoDotOne,
+    #[value(name = "IBM-pibs")]
+    IBMPibs,
+    #[value(name = "ICU")]
+    ICU,
+    #[value(name = "IEC-Code-Components-EULA")]
+    IECCodeComponentsEULA,
+    #[value(name = "IJG")]
+    IJG,
+    #[value(name = "IJG-short")]
+    IJGShort,
     #[value(name = "IPA")]
     IPA,
-    #[value(name = "CC-BY-SA-1.0")]
-    CCBYSAOneDotZero,
-    #[value(name = "CC-BY-NC-SA-1.0")]
-    CCBYNCSAOneDotZero,
-    #[value(name = "ErlPL-1.1")]
-    ErlPlOneDotOne,
-    #[value(name = "MPL-2.0")]
-    MPLTwoDotZero,
-    #[value(name = "pkgconf")]
-    Pkgconf,
-    #[value(name = "BSD-Protection")]
-    BSDProtection,
-    #[value(name = "GFDL-1.2-no-invariants-only")]
-    GFDLOneDotTwoNoInvariantsOnly,
-    #[value(name = "Adobe-Glyph")]
-    AdobeGlyph,
-    #[value(name = "EPL-1.0")]
-    EPLOneDotZero,
-    #[value(name = "deprecated_LGPL-2.1+")]
-    DeprecatedLGPLTwoDotOnePlus,
-    #[value(name = "i2p-gpl-java-exception")]
-    ITwopGplJavaException,
-    #[value(name = "deprecated_GPL-3.0-with-GCC-exception")]
-    DeprecatedGPLThreeDotZeroWithGCCException,
-    #[value(name = "AMD-newlib")]
-    AMDNewlib,
+    #[value(name = "IPL-1.0")]
+    IPLOneDotZero,
+    #[value(name = "ISC")]
+    ISC,
+    #[value(name = "ISC-Veillard")]
+    ISCVeillard,
+    #[value(name = "ImageMagick")]
+    ImageMagick,
+    #[value(name = "Imlib2")]
+    ImlibTwo,
+    #[value(name = "Info-ZIP")]
+    InfoZIP,
+    #[value(name = "Inner-Net-2.0")]
+    InnerNetTwoDotZero,
+    #[value(name = "InnoSetup")]
+    InnoSetup,
+    #[value(name = "Intel")]
+    Intel,
+    #[value(name = "Intel-ACPI")]
+    IntelACPI,
+    #[value(name = "Interbase-1.0")]
+    InterbaseOneDotZero,
+    #[value(name = "JPL-image")]
+    JPLImage,
+    #[value(name = "JPNIC")]
+    JPNIC,
+    #[value(name = "JSON")]
+    JSON,
     #[value(name = "Jam")]
     Jam,
-    #[value(name = "deprecated_AGPL-1.0")]
-    DeprecatedAGPLOneDotZero,
-    #[value(name = "Baekmuk")]
-    Baekmuk,
-    #[value(name = "Qhull")]
-    Qhull,
-    #[value(name = "OpenSSL-standalone")]
-    OpenSslStandalone,
-    #[value(name = "PCRE2-exception")]
-    PcreTwoException,
-    #[value(name = "BSD-2-Clause-pkgconf-disclaimer")]
-    BSDTwoClausePkgconfDisclaimer,
-    #[value(name = "NBPL-1.0")]
-    NBPLOneDotZero,
-    #[value(name = "MulanPSL-2.0")]
-    MulanPslTwoDotZero,
-    #[value(name = "CC-BY-3.0-US")]
-    CCBYThreeDotZeroUS,
+    #[value(name = "JasPer-2.0")]
+    JasPerTwoDotZero,
+    #[value(name = "Kastrup")]
+    Kastrup,
+    #[value(name = "Kazlib")]
+    Kazlib,
+    #[value(name = "Knuth-CTAN")]
+    KnuthCTAN,
+    #[value(name = "LAL-1.2")]
+    LALOneDotTwo,
+    #[value(name = "LAL-1.3")]
+    LALOneDotThree,
+    #[value(name = "LGPL-2.0-only")]
+    LGPLTwoDotZeroOnly,
+    #[value(name = "LGPL-2.0-or-later")]
+    LGPLTwoDotZeroOrLater,
+    #[value(name = "LGPL-2.1-only")]
+    LGPLTwoDotOneOnly,
+    #[value(name = "LGPL-2.1-or-later")]
+    LGPLTwoDotOneOrLater,
+    #[value(name = "LGPL-3.0-only")]
+    LGPLThreeDotZeroOnly,
+    #[value(name = "LGPL-3.0-or-later")]
+    LGPLThreeDotZeroOrLater,
+    #[value(name = "LGPLLR")]
+    LGPLLR,
+    #[value(name = "LOOP")]
+    LOOP,
+    #[value(name = "LPD-document")]
+    LPDDocument,
+    #[value(name = "LPL-1.0")]
+    LPLOneDotZero,
+    #[value(name = "LPL-1.02")]
+    LPLOneDotZeroTwo,
+    #[value(name = "LPPL-1.0")]
+    LPPLOneDotZero,
+    #[value(name = "LPPL-1.1")]
+    LPPLOneDotOne,
+    #[value(name = "LPPL-1.2")]
+    LPPLOneDotTwo,
+    #[value(name = "LPPL-1.3a")]
+    LPPLOneDotThreea,
+    #[value(name = "LPPL-1.3c")]
+    LPPLOneDotThreec,
+    #[value(name = "LZMA-SDK-9.11-to-9.20")]
+    LZMASDKNineDotOneOneToNineDotTwoZero,
+    #[value(name = "LZMA-SDK-9.22")]
+    LZMASDKNineDotTwoTwo,
+    #[value(name = "Latex2e")]
+    LatexTwoe,
+    #[value(name = "Latex2e-translated-notice")]
+    LatexTwoeTranslatedNotice,
+    #[value(name = "Leptonica")]
+    Leptonica,
+    #[value(name = "LiLiQ-P-1.1")]
+    LiLiQPOneDotOne,
+    #[value(name = "LiLiQ-R-1.1")]
+    LiLiQROneDotOne,
+    #[value(name = "LiLiQ-Rplus-1.1")]
+    LiLiQRplusOneDotOne,
+    #[value(name = "Libpng")]
+    Libpng,
+    #[value(name = "Linux-OpenIB")]
+    LinuxOpenIb,
+    #[value(name = "Linux-man-pages-1-para")]
+    LinuxManPagesOnePara,
+    #[value(name = "Linux-man-pages-copyleft")]
+    LinuxManPagesCopyleft,
+    #[value(name = "Linux-man-pages-copyleft-2-para")]
+    LinuxManPagesCopyleftTwoPara,
+    #[value(name = "Linux-man-pages-copyleft-var")]
+    LinuxManPagesCopyleftVar,
     #[value(name = "Lucida-Bitmap-Fonts")]
     LucidaBitmapFonts,
-    #[value(name = "CC-BY-NC-SA-2.0-FR")]
-    CCBYNCSATwoDotZeroFR,
-    #[value(name = "ANTLR-PD-fallback")]
-    ANTLRPDFallback,
+    #[value(name = "MIPS")]
+    MIPS,
+    #[value(name = "MIT")]
+    MIT,
+    #[value(name = "MIT-0")]
+    MITZero,
+    #[value(name = "MIT-CMU")]
+    MITCMU,
+    #[value(name = "MIT-Click")]
+    MITClick,
+    #[value(name = "MIT-Festival")]
+    MITFestival,
+    #[value(name = "MIT-Khronos-old")]
+    MITKhronosOld,
+    #[value(name = "MIT-Modern-Variant")]
+    MITModernVariant,
+    #[value(name = "MIT-Wu")]
+    MITWu,
     #[value(name = "MIT-advertising")]
     MITAdvertising,
-    #[value(name = "HPND-export-US-modify")]
-    HPNDExportUSModify,
-    #[value(name = "swrule")]
-    Swrule,
-    #[value(name = "Beerware")]
-    Beerware,
-    #[value(name = "SMLNJ")]
-    SMLNJ,
+    #[value(name = "MIT-enna")]
+    MITEnna,
+    #[value(name = "MIT-feh")]
+    MITFeh,
+    #[value(name = "MIT-open-group")]
+    MITOpenGroup,
+    #[value(name = "MIT-testregex")]
+    MITTestregex,
+    #[value(name = "MITNFA")]
+    MITNFA,
+    #[value(name = "MMIXware")]
+    MmiXware,
     #[value(name = "MPEG-SSG")]
     MPEGSSG,
-    #[value(name = "PolyForm-Small-Business-1.0.0")]
-    PolyFormSmallBusinessOneDotZeroDotZero,
-    #[value(name = "AGPL-3.0-or-later")]
-    AGPLThreeDotZeroOrLater,
-    #[value(name = "BSD-Advertising-Acknowledgement")]
-    BSDAdvertisingAcknowledgement,
-    #[value(name = "ZPL-2.0")]
-    ZPLTwoDotZero,
-    #[value(name = "xpp")]
-    Xpp,
-    #[value(name = "Nokia")]
-    Nokia,
-    #[value(name = "HPND-Kevlin-Henney")]
-    HPNDKevlinHenney,
-    #[value(name = "PolyForm-Noncommercial-1.0.0")]
-    PolyFormNoncommercialOneDotZeroDotZero,
-    #[value(name = "CC-BY-SA-2.1-JP")]
-    CCBYSATwoDotOneJP,
-    #[value(name = "xkeyboard-config-Zinoviev")]
-    XkeyboardConfigZinoviev,
-    #[value(name = "NAIST-2003")]
-    NAISTTwoZeroZeroThree,
-    #[value(name = "HPND-export-US")]
-    HPNDExportUS,
-    #[value(name = "GStreamer-exception-2008")]
-    GStreamerExceptionTwoZeroZeroEight,
-    #[value(name = "deprecated_BSD-2-Clause-NetBSD")]
-    DeprecatedBSDTwoClauseNetBsd,
-    #[value(name = "WxWindows-exception-3.1")]
-    WxWindowsExceptionThreeDotOne,
-    #[value(name = "libselinux-1.0")]
-    LibselinuxOneDotZero,
-    #[value(name = "QPL-1.0")]
-    QPLOneDotZero,
-    #[value(name = "Pixar")]
-    Pixar,
-    #[value(name = "Fawkes-Runtime-exception")]
-    FawkesRuntimeException,
-    #[value(name = "CC-PDDC")]
-    CCPDDC,
-    #[value(name = "Xerox")]
-    Xerox,
-    #[value(name = "BSD-3-Clause-No-Nuclear-Warranty")]
-    BSDThreeClauseNoNuclearWarranty,
-    #[value(name = "any-OSI-perl-modules")]
-    AnyOSIPerlModules,
-    #[value(name = "BUSL-1.1")]
-    BUSLOneDotOne,
+    #[value(name = "MPL-1.0")]
+    MPLOneDotZero,
+    #[value(name = "MPL-1.1")]
+    MPLOneDotOne,
+    #[value(name = "MPL-2.0")]
+    MPLTwoDotZero,
+    #[value(name = "MPL-2.0-no-copyleft-exception")]
+    MPLTwoDotZeroNoCopyleftException,
+    #[value(name = "MS-LPL")]
+    MSLPL,
+    #[value(name = "MS-PL")]
+    MSPL,
+    #[value(name = "MS-RL")]
+    MSRL,
+    #[value(name = "MTLL")]
+    MTLL,
+    #[value(name = "Mackerras-3-Clause")]
+    MackerrasThreeClause,
+    #[value(name = "Mackerras-3-Clause-acknowledgment")]
+    MackerrasThreeClauseAcknowledgment,
     #[value(name = "MakeIndex")]
     MakeIndex,
-    #[value(name = "NTIA-PD")]
-    NTIAPD,
-    #[value(name = "LGPL-3.0-or-later")]
-    LGPLThreeDotZeroOrLater,
-    #[value(name = "deprecated_Nunit")]
-    DeprecatedNunit,
-    #[value(name = "OFFIS")]
-    OFFIS,
-    #[value(name = "Latex2e")]
-    LatexTwoe,
-    #[value(name = "SSH-OpenSSH")]
-    SSHOpenSsh,
-    #[value(name = "Entessa")]
-    Entessa,
-    #[value(name = "AFL-2.0")]
-    AFLTwoDotZero,
-    #[value(name = "deprecated_LGPL-2.0")]
-    DeprecatedLGPLTwoDotZero,
-    #[value(name = "Zend-2.0")]
-    ZendTwoDotZero,
-    #[value(name = "mif-exception")]
-    MifException,
-    #[value(name = "Xfig")]
-    Xfig,
-    #[value(name = "Caldera")]
-    Caldera,
-    #[value(name = "libutil-David-Nugent")]
-    LibutilDavidNugent,
-    #[value(name = "Asterisk-linking-protocols-exception")]
-    AsteriskLinkingProtocolsException,
-    #[value(name = "OLDAP-2.6")]
-    OLDAPTwoDotSix,
-    #[value(name = "0BSD")]
-    ZeroBsd,
-    #[value(name = "GNU-compiler-exception")]
-    GNUCompilerException,
-    #[value(name = "Barr")]
-    Barr,
-    #[value(name = "SGI-OpenGL")]
-    SGIOpenGl,
-    #[value(name = "CC-PDM-1.0")]
-    CCPDMOneDotZero,
-    #[value(name = "MIT-enna")]
-    MITEnna,
-    #[value(name = "NIST-PD")]
-    NISTPD,
-    #[value(name = "CC-BY-2.5")]
-    CCBYTwoDotFive,
-    #[value(name = "HPND-sell-regexpr")]
-    HPNDSellRegexpr,
-    #[value(name = "LOOP")]
-    LOOP,
-    #[value(name = "OLDAP-2.1")]
-    OLDAPTwoDotOne,
-    #[value(name = "SISSL-1.2")]
-    SISSLOneDotTwo,
-    #[value(name = "3D-Slicer-1.0")]
-    ThreeDSlicerOneDotZero,
-    #[value(name = "OCCT-exception-1.0")]
-    OCCTExceptionOneDotZero,
-    #[value(name = "389-exception")]
-    ThreeEightNineException,
-    #[value(name = "SL")]
-    SL,
-    #[value(name = "FLTK-exception")]
-    FLTKException,
-    #[value(name = "GFDL-1.1-only")]
-    GFDLOneDotOneOnly,
-    #[value(name = "CAL-1.0-Combined-Work-Exception")]
-    CALOneDotZeroCombinedWorkException,
-    #[value(name = "deprecated_StandardML-NJ")]
-    DeprecatedStandardMlNJ,
-    #[value(name = "ADSL")]
-    ADSL,
-    #[value(name = "BSD-4.3TAHOE")]
-    BSDFourDotThreeTahoe,
-    #[value(name = "ZPL-2.1")]
-    ZPLTwoDotOne,
-    #[value(name = "Imlib2")]
-    ImlibTwo,
-    #[value(name = "RPL-1.1")]
-    RPLOneDotOne,
-    #[value(name = "gnuplot")]
-    Gnuplot,
-    #[value(name = "D-FSL-1.0")]
-    DFSLOneDotZero,
-    #[value(name = "Adobe-Utopia")]
-    AdobeUtopia,
-    #[value(name = "OpenSSL")]
-    OpenSsl,
-    #[value(name = "GPL-3.0-or-later")]
-    GPLThreeDotZeroOrLater,
-    #[value(name = "OSET-PL-2.1")]
-    OSETPLTwoDotOne,
-    #[value(name = "LZMA-SDK-9.11-to-9.20")]
-    LZMASDKNineDotOneOneToNineDotTwoZero,
-    #[value(name = "SAX-PD-2.0")]
-    SAXPDTwoDotZero,
-    #[value(name = "BSD-3-Clause-Clear")]
-    BSDThreeClauseClear,
+    #[value(name = "Martin-Birgmeier")]
+    MartinBirgmeier,
+    #[value(name = "McPhee-slideshow")]
+    McPheeSlideshow,
+    #[value(name = "Minpack")]
+    Minpack,
+    #[value(name = "MirOS")]
+    MirOs,
+    #[value(name = "Motosoto")]
+    Motosoto,
+    #[value(name = "MulanPSL-1.0")]
+    MulanPslOneDotZero,
+    #[value(name = "MulanPSL-2.0")]
+    MulanPslTwoDotZero,
+    #[value(name = "Multics")]
+    Multics,
+    #[value(name = "Mup")]
+    Mup,
+    #[value(name = "NAIST-2003")]
+    NAISTTwoZeroZeroThree,
     #[value(name = "NASA-1.3")]
     NASAOneDotThree,
-    #[value(name = "EUDatagrid")]
-    EuDatagrid,
-    #[value(name = "CERN-OHL-1.1")]
-    CERNOHLOneDotOne,
-    #[value(name = "etalab-2.0")]
-    EtalabTwoDotZero,
-    #[value(name = "deprecated_GPL-3.0-with-autoconf-exception")]
-    DeprecatedGPLThreeDotZeroWithAutoconfException,
-    #[value(name = "CERN-OHL-S-2.0")]
-    CERNOHLSTwoDotZero,
-    #[value(name = "Gutmann")]
-    Gutmann,
-    #[value(name = "OLFL-1.3")]
-    OLFLOneDotThree,
-    #[value(name = "Linux-man-pages-copyleft-var")]
-    LinuxManPagesCopyleftVar,
-    #[value(name = "OGL-UK-3.0")]
-    OGLUKThreeDotZero,
-    #[value(name = "Parity-6.0.0")]
-    ParitySixDotZeroDotZero,
-    #[value(name = "LPPL-1.3a")]
-    LPPLOneDotThreea,
-    #[value(name = "mplus")]
-    Mplus,
-    #[value(name = "COIL-1.0")]
-    COILOneDotZero,
-    #[value(name = "gtkbook")]
-    Gtkbook,
-    #[value(name = "XSkat")]
-    XSkat,
-    #[value(name = "HPND-doc-sell")]
-    HPNDDocSell,
-    #[value(name = "RSA-MD")]
-    RSAMD,
-    #[value(name = "CC-BY-SA-3.0-AT")]
-    CCBYSAThreeDotZeroAT,
+    #[value(name = "NBPL-1.0")]
+    NBPLOneDotZero,
+    #[value(name = "NCBI-PD")]
+    NCBIPD,
+    #[value(name = "NCGL-UK-2.0")]
+    NCGLUKTwoDotZero,
+    #[value(name = "NCL")]
+    NCL,
+    #[value(name = "NCSA")]
+    NCSA,
+    #[value(name = "NGPL")]
+    NGPL,
+    #[value(name = "NICTA-1.0")]
+    NICTAOneDotZero,
+    #[value(name = "NIST-PD")]
+    NISTPD,
     #[value(name = "NIST-PD-fallback")]
     NISTPDFallback,
-    #[value(name = "iMatix")]
-    IMatix,
-    #[value(name = "GPL-3.0-only")]
-    GPLThreeDotZeroOnly,
-    #[value(name = "CryptoSwift")]
-    CryptoSwift,
-    #[value(name = "WTFPL")]
-    WTFPL,
-    #[value(name = "BSD-3-Clause-No-Nuclear-License")]
-    BSDThreeClauseNoNuclearLicense,
-    #[value(name = "cve-tou")]
-    CveTou,
-    #[value(name = "HPND-MIT-disclaimer")]
-    HPNDMITDisclaimer,
-    #[value(name = "CC-BY-ND-2.0")]
-    CCBYNDTwoDotZero,
-    #[value(name = "Vim")]
-    Vim,
     #[value(name = "NIST-Software")]
     NISTSoftware,
-    #[value(name = "LPPL-1.0")]
-    LPPLOneDotZero,
-    #[value(name = "YPL-1.1")]
-    YPLOneDotOne,
-    #[value(name = "CC-BY-NC-ND-2.0")]
-    CCBYNCNDTwoDotZero,
-    #[value(name = "copyleft-next-0.3.1")]
-    CopyleftNextZeroDotThreeDotOne,
-    #[value(name = "MIT-CMU")]
-    MITCMU,
-    #[value(name = "RPSL-1.0")]
-    RPSLOneDotZero,
-    #[value(name = "BSD-2-Clause-Patent")]
-    BSDTwoClausePatent,
-    #[value(name = "dtoa")]
-    Dtoa,
-    #[value(name = "NCSA")]
-    NCSA,
+    #[value(name = "NLOD-1.0")]
+    NLODOneDotZero,
+    #[value(name = "NLOD-2.0")]
+    NLODTwoDotZero,
+    #[value(name = "NLPL")]
+    NLPL,
+    #[value(name = "NOSL")]
+    NOSL,
+    #[value(name = "NPL-1.0")]
+    NPLOneDotZero,
     #[value(name = "NPL-1.1")]
     NPLOneDotOne,
-    #[value(name = "SCEA")]
-    SCEA,
-    #[value(name = "SMPPL")]
-    SMPPL,
-    #[value(name = "LiLiQ-R-1.1")]
-    LiLiQROneDotOne,
-    #[value(name = "OFL-1.0-RFN")]
-    OFLOneDotZeroRFN,
     #[value(name = "NPOSL-3.0")]
     NPOSLThreeDotZero,
-    #[value(name = "ImageMagick")]
-    ImageMagick,
-    #[value(name = "BSD-4-Clause-Shortened")]
-    BSDFourClauseShortened,
-    #[value(name = "Asterisk-exception")]
-    AsteriskException,
-    #[value(name = "libpri-OpenH323-exception")]
-    LibpriOpenHThreeTwoThreeException,
-    #[value(name = "Aladdin")]
-    Aladdin,
-    #[value(name = "Unicode-TOU")]
-    UnicodeTOU,
+    #[value(name = "NRL")]
+    NRL,
+    #[value(name = "NTIA-PD")]
+    NTIAPD,
+    #[value(name = "NTP")]
+    NTP,
+    #[value(name = "NTP-0")]
+    NTPZero,
+    #[value(name = "Naumen")]
+    Naumen,
+    #[value(name = "NetCDF")]
+    NetCdf,
+    #[value(name = "Newsletr")]
+    Newsletr,
+    #[value(name = "Nokia")]
+    Nokia,
+    #[value(name = "Noweb")]
+    Noweb,
+    #[value(name = "O-UDA-1.0")]
+    OUDAOneDotZero,
+    #[value(name = "OAR")]
+    OAR,
+    #[value(name = "OCCT-PL")]
+    OCCTPL,
+    #[value(name = "OCLC-2.0")]
+    OCLCTwoDotZero,
+    #[value(name = "ODC-By-1.0")]
+    ODCByOneDotZero,
+    #[value(name = "ODbL-1.0")]
+    ODbLOneDotZero,
+    #[value(name = "OFFIS")]
+    OFFIS,
+    #[value(name = "OFL-1.0")]
+    OFLOneDotZero,
+    #[value(name = "OFL-1.0-RFN")]
+    OFLOneDotZeroRFN,
+    #[value(name = "OFL-1.0-no-RFN")]
+    OFLOneDotZeroNoRFN,
+    #[value(name = "OFL-1.1")]
+    OFLOneDotOne,
+    #[value(name = "OFL-1.1-RFN")]
+    OFLOneDotOneRFN,
+    #[value(name = "OFL-1.1-no-RFN")]
+    OFLOneDotOneNoRFN,
+    #[value(name = "OGC-1.0")]
+    OGCOneDotZero,
+    #[value(name = "OGDL-Taiwan-1.0")]
+    OGDLTaiwanOneDotZero,
+    #[value(name = "OGL-Canada-2.0")]
+    OGLCanadaTwoDotZero,
+    #[value(name = "OGL-UK-1.0")]
+    OGLUKOneDotZero,
+    #[value(name = "OGL-UK-2.0")]
+    OGLUKTwoDotZero,
+    #[value(name = "OGL-UK-3.0")]
+    OGLUKThreeDotZero,
+    #[value(name = "OGTSL")]
+    OGTSL,
+    #[value(name = "OLDAP-1.1")]
+    OLDAPOneDotOne,
+    #[value(name = "OLDAP-1.2")]
+    OLDAPOneDotTwo,
+    #[value(name = "OLDAP-1.3")]
+    OLDAPOneDotThree,
+    #[value(name = "OLDAP-1.4")]
+    OLDAPOneDotFour,
+    #[value(name = "OLDAP-2.0")]
+    OLDAPTwoDotZero,
+    #[value(name = "OLDAP-2.0.1")]
+    OLDAPTwoDotZeroDotOne,
+    #[value(name = "OLDAP-2.1")]
+    OLDAPTwoDotOne,
+    #[value(name = "OLDAP-2.2")]
+    OLDAPTwoDotTwo,
+    #[value(name = "OLDAP-2.2.1")]
+    OLDAPTwoDotTwoDotOne,
+    #[value(name = "OLDAP-2.2.2")]
+    OLDAPTwoDotTwoDotTwo,
+    #[value(name = "OLDAP-2.3")]
+    OLDAPTwoDotThree,
+    #[value(name = "OLDAP-2.4")]
+    OLDAPTwoDotFour,
+    #[value(name = "OLDAP-2.5")]
+    OLDAPTwoDotFive,
+    #[value(name = "OLDAP-2.6")]
+    OLDAPTwoDotSix,
+    #[value(name = "OLDAP-2.7")]
+    OLDAPTwoDotSeven,
+    #[value(name = "OLDAP-2.8")]
+    OLDAPTwoDotEight,
+    #[value(name = "OLFL-1.3")]
+    OLFLOneDotThree,
+    #[value(name = "OML")]
+    OML,
+    #[value(name = "OPL-1.0")]
+    OPLOneDotZero,
+    #[value(name = "OPL-UK-3.0")]
+    OPLUKThreeDotZero,
+    #[value(name = "OPUBL-1.0")]
+    OPUBLOneDotZero,
+    #[value(name = "OSET-PL-2.1")]
+    OSETPLTwoDotOne,
+    #[value(name = "OSL-1.0")]
+    OSLOneDotZero,
+    #[value(name = "OSL-1.1")]
+    OSLOneDotOne,
+    #[value(name = "OSL-2.0")]
+    OSLTwoDotZero,
+    #[value(name = "OSL-2.1")]
+    OSLTwoDotOne,
+    #[value(name = "OSL-3.0")]
+    OSLThreeDotZero,
     #[value(name = "OpenPBS-2.3")]
     OpenPbsTwoDotThree,
-    #[value(name = "any-OSI")]
-    AnyOSI,
-    #[value(name = "UCL-1.0")]
-    UCLOneDotZero,
-    #[value(name = "Zimbra-1.4")]
-    ZimbraOneDotFour,
-    #[value(name = "Bootloader-exception")]
-    BootloaderException,
-    #[value(name = "Bison-exception-2.2")]
-    BisonExceptionTwoDotTwo,
+    #[value(name = "OpenSSL")]
+    OpenSsl,
+    #[value(name = "OpenSSL-standalone")]
+    OpenSslStandalone,
+    #[value(name = "OpenVision")]
+    OpenVision,
+    #[value(name = "PADL")]
+    PADL,
+    #[value(name = "PDDL-1.0")]
+    PDDLOneDotZero,
+    #[value(name = "PHP-3.0")]
+    PHPThreeDotZero,
+    #[value(name = "PHP-3.01")]
+    PHPThreeDotZeroOne,
+    #[value(name = "PPL")]
+    PPL,
+    #[value(name = "PSF-2.0")]
+    PSFTwoDotZero,
+    #[value(name = "Parity-6.0.0")]
+    ParitySixDotZeroDotZero,
+    #[value(name = "Parity-7.0.0")]
+    ParitySevenDotZeroDotZero,
+    #[value(name = "Pixar")]
+    Pixar,
+    #[value(name = "Plexus")]
+    Plexus,
+    #[value(name = "PolyForm-Noncommercial-1.0.0")]
+    PolyFormNoncommercialOneDotZeroDotZero,
+    #[value(name = "PolyForm-Small-Business-1.0.0")]
+    PolyFormSmallBusinessOneDotZeroDotZero,
+    #[value(name = "PostgreSQL")]
+    PostgreSql,
+    #[value(name = "Python-2.0")]
+    PythonTwoDotZero,
+    #[value(name = "Python-2.0.1")]
+    PythonTwoDotZeroDotOne,
+    #[value(name = "QPL-1.0")]
+    QPLOneDotZero,
+    #[value(name = "QPL-1.0-INRIA-2004")]
+    QPLOneDotZeroINRIATwoZeroZeroFour,
+    #[value(name = "Qhull")]
+    Qhull,
+    #[value(name = "RHeCos-1.1")]
+    RHeCosOneDotOne,
+    #[value(name = "RPL-1.1")]
+    RPLOneDotOne,
+    #[value(name = "RPL-1.5")]
+    RPLOneDotFive,
+    #[value(name = "RPSL-1.0")]
+    RPSLOneDotZero,
+    #[value(name = "RSA-MD")]
+    RSAMD,
+    #[value(name = "RSCPL")]
+    RSCPL,
+    #[value(name = "Rdisc")]
+    Rdisc,
+    #[value(name = "Ruby")]
+    Ruby,
+    #[value(name = "Ruby-pty")]
+    RubyPty,
+    #[value(name = "SAX-PD")]
+    SAXPD,
+    #[value(name = "SAX-PD-2.0")]
+    SAXPDTwoDotZero,
+    #[value(name = "SCEA")]
+    SCEA,
+    #[value(name = "SGI-B-1.0")]
+    SGIBOneDotZero,
+    #[value(name = "SGI-B-1.1")]
+    SGIBOneDotOne,
+    #[value(name = "SGI-B-2.0")]
+    SGIBTwoDotZero,
+    #[value(name = "SGI-OpenGL")]
+    SGIOpenGl,
+    #[value(name = "SGP4")]
+    SgpFour,
+    #[value(name = "SHL-0.5")]
+    SHLZeroDotFive,
+    #[value(name = "SHL-0.51")]
+    SHLZeroDotFiveOne,
+    #[value(name = "SISSL")]
+    SISSL,
+    #[value(name = "SISSL-1.2")]
+    SISSLOneDotTwo,
+    #[value(name = "SL")]
+    SL,
+    #[value(name = "SMAIL-GPL")]
+    SMAILGPL,
+    #[value(name = "SMLNJ")]
+    SMLNJ,
+    #[value(name = "SMPPL")]
+    SMPPL,
+    #[value(name = "SNIA")]
+    SNIA,
+    #[value(name = "SPL-1.0")]
+    SPLOneDotZero,
+    #[value(name = "SSH-OpenSSH")]
+    SSHOpenSsh,
+    #[value(name = "SSH-short")]
+    SSHShort,
+    #[value(name = "SSLeay-standalone")]
+    SsLeayStandalone,
+    #[value(name = "SSPL-1.0")]
+    SSPLOneDotZero,
+    #[value(name = "SWL")]
+    SWL,
+    #[value(name = "Saxpath")]
+    Saxpath,
+    #[value(name = "SchemeReport")]
+    SchemeReport,
+    #[value(name = "Sendmail")]
+    Sendmail,
+    #[value(name = "Sendmail-8.23")]
+    SendmailEightDotTwoThree,
+    #[value(name = "Sendmail-Open-Source-1.1")]
+    SendmailOpenSourceOneDotOne,
+    #[value(name = "SimPL-2.0")]
+    SimPlTwoDotZero,
+    #[value(name = "Sleepycat")]
+    Sleepycat,
+    #[value(name = "Soundex")]
+    Soundex,
+    #[value(name = "Spencer-86")]
+    SpencerEightSix,
+    #[value(name = "Spencer-94")]
+    SpencerNineFour,
+    #[value(name = "Spencer-99")]
+    SpencerNineNine,
+    #[value(name = "SugarCRM-1.1.3")]
+    SugarCrmOneDotOneDotThree,
+    #[value(name = "Sun-PPP")]
+    SunPPP,
+    #[value(name = "Sun-PPP-2000")]
+    SunPPPTwoZeroZeroZero,
+    #[value(name = "SunPro")]
+    SunPro,
+    #[value(name = "Symlinks")]
+    Symlinks,
+    #[value(name = "TAPR-OHL-1.0")]
+    TAPROHLOneDotZero,
+    #[value(name = "TCL")]
+    TCL,
+    #[value(name = "TCP-wrappers")]
+    TCPWrappers,
     #[value(name = "TGPPL-1.0")]
     TGPPLOneDotZero,
-    #[value(name = "BitTorrent-1.1")]
-    BitTorrentOneDotOne,
+    #[value(name = "TMate")]
+    TMate,
+    #[value(name = "TORQUE-1.1")]
+    TORQUEOneDotOne,
+    #[value(name = "TOSL")]
+    TOSL,
+    #[value(name = "TPDL")]
+    TPDL,
+    #[value(name = "TPL-1.0")]
+    TPLOneDotZero,
+    #[value(name = "TTWL")]
+    TTWL,
+    #[value(name = "TTYP0")]
+    TtypZero,
+    #[value(name = "TU-Berlin-1.0")]
+    TUBerlinOneDotZero,
+    #[value(name = "TU-Berlin-2.0")]
+    TUBerlinTwoDotZero,
+    #[value(name = "TermReadKey")]
+    TermReadKey,
+    #[value(name = "ThirdEye")]
+    ThirdEye,
+    #[value(name = "TrustedQSL")]
+    TrustedQsl,
+    #[value(name = "UCAR")]
+    UCAR,
+    #[value(name = "UCL-1.0")]
+    UCLOneDotZero,
+    #[value(name = "UMich-Merit")]
+    UMichMerit,
+    #[value(name = "UPL-1.0")]
+    UPLOneDotZero,
+    #[value(name = "URT-RLE")]
+    URTRLE,
+    #[value(name = "Ubuntu-font-1.0")]
+    UbuntuFontOneDotZero,
+    #[value(name = "Unicode-3.0")]
+    UnicodeThreeDotZero,
+    #[value(name = "Unicode-DFS-2015")]
+    UnicodeDFSTwoZeroOneFive,
+    #[value(name = "Unicode-DFS-2016")]
+    UnicodeDFSTwoZeroOneSix,
+    #[value(name = "Unicode-TOU")]
+    UnicodeTOU,
+    #[value(name = "UnixCrypt")]
+    UnixCrypt,
+    #[value(name = "Unlicense")]
+    Unlicense,
+    #[value(name = "VOSTROM")]
+    VOSTROM,
+    #[value(name = "VSL-1.0")]
+    VSLOneDotZero,
+    #[value(name = "Vim")]
+    Vim,
+    #[value(name = "W3C")]
+    WThreeC,
+    #[value(name = "W3C-19980720")]
+    WThreeCOneNineNineEightZeroSevenTwoZero,
+    #[value(name = "W3C-20150513")]
+    WThreeCTwoZeroOneFiveZeroFiveOneThree,
+    #[value(name = "WTFPL")]
+    WTFPL,
+    #[value(name = "Watcom-1.0")]
+    WatcomOneDotZero,
+    #[value(name = "Widget-Workshop")]
+    WidgetWorkshop,
     #[value(name = "Wsuipa")]
     Wsuipa,
-    #[value(name = "deprecated_Nokia-Qt-exception-1.1")]
-    DeprecatedNokiaQtExceptionOneDotOne,
-    #[value(name = "CC-BY-NC-SA-2.0-DE")]
-    CCBYNCSATwoDotZeroDE,
-    #[value(name = "Linux-man-pages-copyleft")]
-    LinuxManPagesCopyleft,
-    #[value(name = "xlock")]
-    Xlock,
-    #[value(name = "LiLiQ-Rplus-1.1")]
-    LiLiQRplusOneDotOne,
-    #[value(name = "generic-xts")]
-    GenericXts,
-    #[value(name = "Zimbra-1.3")]
-    ZimbraOneDotThree,
-    #[value(name = "GPL-2.0-only")]
-    GPLTwoDotZeroOnly,
-    #[value(name = "OGL-UK-1.0")]
-    OGLUKOneDotZero,
-    #[value(name = "AFL-1.1")]
-    AFLOneDotOne,
-    #[value(name = "SSLeay-standalone")]
-    SsLeayStandalone,
+    #[value(name = "X11")]
+    XOneOne,
+    #[value(name = "X11-distribute-modifications-variant")]
+    XOneOneDistributeModificationsVariant,
     #[value(name = "X11-swapped")]
     XOneOneSwapped,
-    #[value(name = "OpenJDK-assembly-exception-1.0")]
-    OpenJdkAssemblyExceptionOneDotZero,
-    #[value(name = "GFDL-1.3-no-invariants-or-later")]
-    GFDLOneDotThreeNoInvariantsOrLater,
-    #[value(name = "MTLL")]
-    MTLL,
-    #[value(name = "Ubuntu-font-1.0")]
-    UbuntuFontOneDotZero,
-    #[value(name = "DocBook-XML")]
-    DocBookXML,
-    #[value(name = "MIT-testregex")]
-    MITTestregex,
-    #[value(name = "CC-BY-NC-2.5")]
-    CCBYNCTwoDotFive,
-    #[value(name = "python-ldap")]
-    PythonLdap,
-    #[value(name = "GL2PS")]
-    GlTwoPs,
-    #[value(name = "LPL-1.02")]
-    LPLOneDotZeroTwo,
-    #[value(name = "MITNFA")]
-    MITNFA,
+    #[value(name = "XFree86-1.1")]
+    XFreeEightSixOneDotOne,
+    #[value(name = "XSkat")]
+    XSkat,
+    #[value(name = "Xdebug-1.03")]
+    XdebugOneDotZeroThree,
+    #[value(name = "Xerox")]
+    Xerox,
+    #[value(name = "Xfig")]
+    Xfig,
+    #[value(name = "Xnet")]
+    Xnet,
+    #[value(name = "YPL-1.0")]
+    YPLOneDotZero,
+    #[value(name = "YPL-1.1")]
+    YPLOneDotOne,
+    #[value(name = "ZPL-1.1")]
+    ZPLOneDotOne,
+    #[value(name = "ZPL-2.0")]
+    ZPLTwoDotZero,
+    #[value(name = "ZPL-2.1")]
+    ZPLTwoDotOne,
+    #[value(name = "Zed")]
+    Zed,
+    #[value(name = "Zeeff")]
+    Zeeff,
+    #[value(name = "Zend-2.0")]
+    ZendTwoDotZero,
+    #[value(name = "Zimbra-1.3")]
+    ZimbraOneDotThree,
+    #[value(name = "Zimbra-1.4")]
+    ZimbraOneDotFour,
+    #[value(name = "Zlib")]
+    Zlib,
+    #[value(name = "any-OSI")]
+    AnyOSI,
+    #[value(name = "any-OSI-perl-modules")]
+    AnyOSIPerlModules,
+    #[value(name = "bcrypt-Solar-Designer")]
+    BcryptSolarDesigner,
+    #[value(name = "blessing")]
+    Blessing,
+    #[value(name = "bzip2-1.0.6")]
+    BzipTwoOneDotZeroDotSix,
+    #[value(name = "check-cvs")]
+    CheckCvs,
     #[value(name = "checkmk")]
     Checkmk,
-    #[value(name = "GFDL-1.2-only")]
-    GFDLOneDotTwoOnly,
-    #[value(name = "NGPL")]
-    NGPL,
-    #[value(name = "MulanPSL-1.0")]
-    MulanPslOneDotZero,
-    #[value(name = "deprecated_wxWindows")]
-    DeprecatedWxWindows,
-    #[value(name = "OGC-1.0")]
-    OGCOneDotZero,
-    #[value(name = "ulem")]
-    Ulem,
-    #[value(name = "Autoconf-exception-3.0")]
-    AutoconfExceptionThreeDotZero,
-    #[value(name = "harbour-exception")]
-    HarbourException,
-    #[value(name = "UCAR")]
-    UCAR,
-    #[value(name = "MS-PL")]
-    MSPL,
-    #[value(name = "JPL-image")]
-    JPLImage,
-    #[value(name = "Font-exception-2.0")]
-    FontExceptionTwoDotZero,
-    #[value(name = "GFDL-1.3-invariants-or-later")]
-    GFDLOneDotThreeInvariantsOrLater,
+    #[value(name = "copyleft-next-0.3.0")]
+    CopyleftNextZeroDotThreeDotZero,
+    #[value(name = "copyleft-next-0.3.1")]
+    CopyleftNextZeroDotThreeDotOne,
+    #[value(name = "curl")]
+    Curl,
+    #[value(name = "cve-tou")]
+    CveTou,
+    #[value(name = "AGPL-1.0")]
+    DeprecatedAGPLOneDotZero,
+    #[value(name = "AGPL-3.0")]
+    DeprecatedAGPLThreeDotZero,
+    #[value(name = "BSD-2-Clause-FreeBSD")]
+    DeprecatedBSDTwoClauseFreeBsd,
+    #[value(name = "BSD-2-Clause-NetBSD")]
+    DeprecatedBSDTwoClauseNetBsd,
+    #[value(name = "GFDL-1.1")]
+    DeprecatedGFDLOneDotOne,
+    #[value(name = "GFDL-1.2")]
+    DeprecatedGFDLOneDotTwo,
+    #[value(name = "GFDL-1.3")]
+    DeprecatedGFDLOneDotThree,
+    #[value(name = "GPL-1.0")]
+    DeprecatedGPLOneDotZero,
+    #[value(name = "GPL-1.0+")]
+    DeprecatedGPLOneDotZeroPlus,
+    #[value(name = "GPL-2.0")]
+    DeprecatedGPLTwoDotZero,
+    #[value(name = "GPL-2.0+")]
+    DeprecatedGPLTwoDotZeroPlus,
+    #[value(name = "GPL-2.0-with-GCC-exception")]
+    DeprecatedGPLTwoDotZeroWithGCCException,
+    #[value(name = "GPL-2.0-with-autoconf-exception")]
+    DeprecatedGPLTwoDotZeroWithAutoconfException,
+    #[value(name = "GPL-2.0-with-bison-exception")]
+    DeprecatedGPLTwoDotZeroWithBisonException,
+    #[value(name = "GPL-2.0-with-classpath-exception")]
+    DeprecatedGPLTwoDotZeroWithClasspathException,
+    #[value(name = "GPL-2.0-with-font-exception")]
+    DeprecatedGPLTwoDotZeroWithFontException,
+    #[value(name = "GPL-3.0")]
+    DeprecatedGPLThreeDotZero,
+    #[value(name = "GPL-3.0+")]
+    DeprecatedGPLThreeDotZeroPlus,
+    #[value(name = "GPL-3.0-with-GCC-exception")]
+    DeprecatedGPLThreeDotZeroWithGCCException,
+    #[value(name = "GPL-3.0-with-autoconf-exception")]
+    DeprecatedGPLThreeDotZeroWithAutoconfException,
+    #[value(name = "LGPL-2.0")]
+    DeprecatedLGPLTwoDotZero,
+    #[value(name = "LGPL-2.0+")]
+    DeprecatedLGPLTwoDotZeroPlus,
+    #[value(name = "LGPL-2.1")]
+    DeprecatedLGPLTwoDotOne,
+    #[value(name = "LGPL-2.1+")]
+    DeprecatedLGPLTwoDotOnePlus,
+    #[value(name = "LGPL-3.0")]
+    DeprecatedLGPLThreeDotZero,
+    #[value(name = "LGPL-3.0+")]
+    DeprecatedLGPLThreeDotZeroPlus,
+    #[value(name = "Net-SNMP")]
+    DeprecatedNetSNMP,
+    #[value(name = "Nunit")]
+    DeprecatedNunit,
+    #[value(name = "StandardML-NJ")]
+    DeprecatedStandardMlNJ,
+    #[value(name = "bzip2-1.0.5")]
+    DeprecatedBzipTwoOneDotZeroDotFive,
+    #[value(name = "eCos-2.0")]
+    DeprecatedECosTwoDotZero,
+    #[value(name = "wxWindows")]
+    DeprecatedWxWindows,
+    #[value(name = "diffmark")]
+    Diffmark,
+    #[value(name = "dtoa")]
+    Dtoa,
+    #[value(name = "dvipdfm")]
+    Dvipdfm,
+    #[value(name = "eGenix")]
+    EGenix,
+    #[value(name = "etalab-2.0")]
+    EtalabTwoDotZero,
     #[value(name = "fwlw")]
     Fwlw,
-    #[value(name = "Inner-Net-2.0")]
-    InnerNetTwoDotZero,
-    #[value(name = "MPL-1.0")]
-    MPLOneDotZero,
-    #[value(name = "Community-Spec-1.0")]
-    CommunitySpecOneDotZero,
-    #[value(name = "CUA-OPL-1.0")]
-    CUAOPLOneDotZero,
-    #[value(name = "UBDL-exception")]
-    UBDLException,
-    #[value(name = "GFDL-1.1-invariants-only")]
-    GFDLOneDotOneInvariantsOnly,
-    #[value(name = "FreeBSD-DOC")]
-    FreeBsdDOC,
-    #[value(name = "EPL-2.0")]
-    EPLTwoDotZero,
-    #[value(name = "Sendmail-Open-Source-1.1")]
-    SendmailOpenSourceOneDotOne,
-    #[value(name = "Eurosym")]
-    Eurosym,
-    #[value(name = "GPL-1.0-only")]
-    GPLOneDotZeroOnly,
-    #[value(name = "deprecated_BSD-2-Clause-FreeBSD")]
-    DeprecatedBSDTwoClauseFreeBsd,
-    #[value(name = "SHL-0.5")]
-    SHLZeroDotFive,
-    #[value(name = "X11")]
-    XOneOne,
-    #[value(name = "ThirdEye")]
-    ThirdEye,
-    #[value(name = "FSFAP-no-warranty-disclaimer")]
-    FSFAPNoWarrantyDisclaimer,
-    #[value(name = "SimPL-2.0")]
-    SimPlTwoDotZero,
-    #[value(name = "Interbase-1.0")]
-    InterbaseOneDotZero,
-    #[value(name = "deprecated_bzip2-1.0.5")]
-    DeprecatedBzipTwoOneDotZeroDotFive,
-    #[value(name = "Multics")]
-    Multics,
-    #[value(name = "GStreamer-exception-2005")]
-    GStreamerExceptionTwoZeroZeroFive,
-    #[value(name = "CC-BY-NC-SA-2.0")]
-    CCBYNCSATwoDotZero,
-    #[value(name = "GD")]
-    GD,
-    #[value(name = "CC-BY-SA-2.0")]
-    CCBYSATwoDotZero,
-    #[value(name = "CECILL-C")]
-    CECILLC,
-    #[value(name = "Elastic-2.0")]
-    ElasticTwoDotZero,
-    #[value(name = "MIT-Wu")]
-    MITWu,
+    #[value(name = "gSOAP-1.3b")]
+    GSoapOneDotThreeb,
+    #[value(name = "generic-xts")]
+    GenericXts,
+    #[value(name = "gnuplot")]
+    Gnuplot,
+    #[value(name = "gtkbook")]
+    Gtkbook,
+    #[value(name = "hdparm")]
+    Hdparm,
+    #[value(name = "iMatix")]
+    IMatix,
+    #[value(name = "jove")]
+    Jove,
+    #[value(name = "libpng-2.0")]
+    LibpngTwoDotZero,
+    #[value(name = "libselinux-1.0")]
+    LibselinuxOneDotZero,
+    #[value(name = "libtiff")]
+    Libtiff,
+    #[value(name = "libutil-David-Nugent")]
+    LibutilDavidNugent,
+    #[value(name = "lsof")]
+    Lsof,
+    #[value(name = "magaz")]
+    Magaz,
+    #[value(name = "mailprio")]
+    Mailprio,
+    #[value(name = "man2html")]
+    ManTwohtml,
+    #[value(name = "metamail")]
+    Metamail,
+    #[value(name = "mpi-permissive")]
+    MpiPermissive,
+    #[value(name = "mpich2")]
+    MpichTwo,
+    #[value(name = "mplus")]
+    Mplus,
+    #[value(name = "pkgconf")]
+    Pkgconf,
+    #[value(name = "pnmstitch")]
+    Pnmstitch,
+    #[value(name = "psfrag")]
+    Psfrag,
+    #[value(name = "psutils")]
+    Psutils,
+    #[value(name = "python-ldap")]
+    PythonLdap,
+    #[value(name = "radvd")]
+    Radvd,
     #[value(name = "snprintf")]
     Snprintf,
-    #[value(name = "EUPL-1.0")]
-    EUPLOneDotZero,
-    #[value(name = "AMDPLPA")]
-    AMDPLPA,
-    #[value(name = "APSL-1.0")]
-    APSLOneDotZero,
-    #[value(name = "BSD-Source-beginning-file")]
-    BSDSourceBeginningFile,
-    #[value(name = "SunPro")]
-    SunPro,
-    #[value(name = "HPND-Intel")]
-    HPNDIntel,
-    #[value(name = "LZMA-SDK-9.22")]
-    LZMASDKNineDotTwoTwo,
-    #[value(name = "OFL-1.0")]
-    OFLOneDotZero,
-    #[value(name = "deprecated_GPL-2.0-with-GCC-exception")]
-    DeprecatedGPLTwoDotZeroWithGCCException,
-    #[value(name = "Autoconf-exception-generic-3.0")]
-    AutoconfExceptionGenericThreeDotZero,
-    #[value(name = "CC-BY-SA-3.0")]
-    CCBYSAThreeDotZero,
-    #[value(name = "CC-BY-NC-SA-3.0")]
-    CCBYNCSAThreeDotZero,
-    #[value(name = "Bahyph")]
-    Bahyph,
-    #[value(name = "DSDP")]
-    DSDP,
-    #[value(name = "McPhee-slideshow")]
-    McPheeSlideshow,
-    #[value(name = "BitTorrent-1.0")]
-    BitTorrentOneDotZero,
-    #[value(name = "CC-BY-3.0-NL")]
-    CCBYThreeDotZeroNL,
-    #[value(name = "OLDAP-1.1")]
-    OLDAPOneDotOne,
-    #[value(name = "Bitstream-Vera")]
-    BitstreamVera,
-    #[value(name = "GFDL-1.2-invariants-only")]
-    GFDLOneDotTwoInvariantsOnly,
-    #[value(name = "SchemeReport")]
-    SchemeReport,
-    #[value(name = "HPND-export2-US")]
-    HPNDExportTwoUS,
+    #[value(name = "softSurfer")]
+    SoftSurfer,
+    #[value(name = "ssh-keyscan")]
+    SshKeyscan,
+    #[value(name = "swrule")]
+    Swrule,
+    #[value(name = "threeparttable")]
+    Threeparttable,
+    #[value(name = "ulem")]
+    Ulem,
+    #[value(name = "w3m")]
+    WThreem,
+    #[value(name = "wwl")]
+    Wwl,
     #[value(name = "xinetd")]
     Xinetd,
-    #[value(name = "HaskellReport")]
-    HaskellReport,
-    #[value(name = "TCL")]
-    TCL,
-    #[value(name = "NetCDF")]
-    NetCdf,
-    #[value(name = "Symlinks")]
-    Symlinks,
-    #[value(name = "openvpn-openssl-exception")]
-    OpenvpnOpensslException,
-    #[value(name = "Arphic-1999")]
-    ArphicOneNineNineNine,
-    #[value(name = "HPND-Pbmplus")]
-    HPNDPbmplus,
-    #[value(name = "OAR")]
-    OAR,
-    #[value(name = "copyleft-next-0.3.0")]
-    CopyleftNextZeroDotThreeDotZero,
-    #[value(name = "YPL-1.0")]
-    YPLOneDotZero,
-    #[value(name = "LPPL-1.1")]
-    LPPLOneDotOne,
-    #[value(name = "CC-BY-ND-4.0")]
-    CCBYNDFourDotZero,
-    #[value(name = "Autoconf-exception-2.0")]
-    AutoconfExceptionTwoDotZero,
-    #[value(name = "XFree86-1.1")]
-    XFreeEightSixOneDotOne,
-    #[value(name = "CC-BY-NC-ND-4.0")]
-    CCBYNCNDFourDotZero,
-    #[value(name = "CC-BY-NC-SA-3.0-DE")]
-    CCBYNCSAThreeDotZeroDE,
-    #[value(name = "TPL-1.0")]
-    TPLOneDotZero,
-    #[value(name = "Naumen")]
-    Naumen,
-    #[value(name = "NICTA-1.0")]
-    NICTAOneDotZero,
-    #[value(name = "NOSL")]
-    NOSL,
-    #[value(name = "pnmstitch")]
-    Pnmstitch,
-    #[value(name = "CPL-1.0")]
-    CPLOneDotZero,
-    #[value(name = "xzoom")]
-    Xzoom,
-    #[value(name = "IEC-Code-Components-EULA")]
-    IECCodeComponentsEULA,
-    #[value(name = "deprecated_AGPL-3.0")]
-    DeprecatedAGPLThreeDotZero,
-    #[value(name = "RSCPL")]
-    RSCPL,
-    #[value(name = "NPL-1.0")]
-    NPLOneDotZero,
-    #[value(name = "BSD-3-Clause-No-Nuclear-License-2014")]
-    BSDThreeClauseNoNuclearLicenseTwoZeroOneFour,
-    #[value(name = "Sleepycat")]
-    Sleepycat,
-    #[value(name = "CDLA-Sharing-1.0")]
-    CDLASharingOneDotZero,
-    #[value(name = "GFDL-1.3-only")]
-    GFDLOneDotThreeOnly,
-    #[value(name = "lsof")]
-    Lsof,
-    #[value(name = "Parity-7.0.0")]
-    ParitySevenDotZeroDotZero,
-    #[value(name = "AAL")]
-    AAL,
-    #[value(name = "Zeeff")]
-    Zeeff,
-    #[value(name = "CC-BY-NC-SA-4.0")]
-    CCBYNCSAFourDotZero,
-    #[value(name = "BlueOak-1.0.0")]
-    BlueOakOneDotZeroDotZero,
-    #[value(name = "CC-BY-SA-4.0")]
-    CCBYSAFourDotZero,
-    #[value(name = "GFDL-1.2-or-later")]
-    GFDLOneDotTwoOrLater,
-    #[value(name = "OFL-1.1")]
-    OFLOneDotOne,
-    #[value(name = "APSL-1.1")]
-    APSLOneDotOne,
+    #[value(name = "xkeyboard-config-Zinoviev")]
+    XkeyboardConfigZinoviev,
+    #[value(name = "xlock")]
+    Xlock,
+    #[value(name = "xpp")]
+    Xpp,
+    #[value(name = "xzoom")]
+    Xzoom,
+    #[value(name = "zlib-acknowledgement")]
+    ZlibAcknowledgement,
+}
+#[allow(clippy::all)]
+#[derive(Debug, Clone, Deserialize, Copy, PartialEq, Eq, Hash, clap :: ValueEnum)]
+#[allow(non_camel_case_types)]
+pub enum LicenseException {
+    #[value(name = "389-exception")]
+    ThreeEightNineException,
+    #[value(name = "Asterisk-exception")]
+    AsteriskException,
+    #[value(name = "Asterisk-linking-protocols-exception")]
+    AsteriskLinkingProtocolsException,
+    #[value(name = "Autoconf-exception-2.0")]
+    AutoconfExceptionTwoDotZero,
+    #[value(name = "Autoconf-exception-3.0")]
+    AutoconfExceptionThreeDotZero,
+    #[value(name = "Autoconf-exception-generic")]
+    AutoconfExceptionGeneric,
+    #[value(name = "Autoconf-exception-generic-3.0")]
+    AutoconfExceptionGenericThreeDotZero,
+    #[value(name = "Autoconf-exception-macro")]
+    AutoconfExceptionMacro,
+    #[value(name = "Bison-exception-1.24")]
+    BisonExceptionOneDotTwoFour,
+    #[value(name = "Bison-exception-2.2")]
+    BisonExceptionTwoDotTwo,
+    #[value(name = "Bootloader-exception")]
+    BootloaderException,
+    #[value(name = "CGAL-linking-exception")]
+    CGALLinkingException,
+    #[value(name = "CLISP-exception-2.0")]
+    CLISPExceptionTwoDotZero,
+    #[value(name = "Classpath-exception-2.0")]
+    ClasspathExceptionTwoDotZero,
+    #[value(name = "DigiRule-FOSS-exception")]
+    DigiRuleFOSSException,
+    #[value(name = "Digia-Qt-LGPL-exception-1.1")]
+    DigiaQtLGPLExceptionOneDotOne,
+    #[value(name = "FLTK-exception")]
+    FLTKException,
+    #[value(name = "Fawkes-Runtime-exception")]
+    FawkesRuntimeException,
+    #[value(name = "Font-exception-2.0")]
+    FontExceptionTwoDotZero,
+    #[value(name = "GCC-exception-2.0")]
+    GCCExceptionTwoDotZero,
+    #[value(name = "GCC-exception-2.0-note")]
+    GCCExceptionTwoDotZeroNote,
+    #[value(name = "GCC-exception-3.1")]
+    GCCExceptionThreeDotOne,
+    #[value(name = "GNAT-exception")]
+    GNATException,
+    #[value(name = "GNOME-examples-exception")]
+    GNOMEExamplesException,
+    #[value(name = "GNU-compiler-exception")]
+    GNUCompilerException,
+    #[value(name = "GPL-3.0-389-ds-base-exception")]
+    GPLThreeDotZeroThreeEightNineDsBaseException,
     #[value(name = "GPL-3.0-interface-exception")]
     GPLThreeDotZeroInterfaceException,
+    #[value(name = "GPL-3.0-linking-exception")]
+    GPLThreeDotZeroLinkingException,
+    #[value(name = "GPL-3.0-linking-source-exception")]
+    GPLThreeDotZeroLinkingSourceException,
+    #[value(name = "GPL-CC-1.0")]
+    GPLCCOneDotZero,
+    #[value(name = "GStreamer-exception-2005")]
+    GStreamerExceptionTwoZeroZeroFive,
+    #[value(name = "GStreamer-exception-2008")]
+    GStreamerExceptionTwoZeroZeroEight,
+    #[value(name = "Gmsh-exception")]
+    GmshException,
+    #[value(name = "Independent-modules-exception")]
+    IndependentModulesException,
+    #[value(name = "KiCad-libraries-exception")]
+    KiCadLibrariesException,
+    #[value(name = "LGPL-3.0-linking-exception")]
+    LGPLThreeDotZeroLinkingException,
+    #[value(name = "LLGPL")]
+    LLGPL,
+    #[value(name = "LLVM-exception")]
+    LLVMException,
+    #[value(name = "LZMA-exception")]
+    LZMAException,
+    #[value(name = "Libtool-exception")]
+    LibtoolException,
+    #[value(name = "Linux-syscall-note")]
+    LinuxSyscallNote,
+    #[value(name = "OCCT-exception-1.0")]
+    OCCTExceptionOneDotZero,
+    #[value(name = "OCaml-LGPL-linking-exception")]
+    OCamlLGPLLinkingException,
+    #[value(name = "OpenJDK-assembly-exception-1.0")]
+    OpenJdkAssemblyExceptionOneDotZero,
+    #[value(name = "PCRE2-exception")]
+    PcreTwoException,
+    #[value(name = "PS-or-PDF-font-exception-20170817")]
+    PSOrPDFFontExceptionTwoZeroOneSevenZeroEightOneSeven,
+    #[value(name = "QPL-1.0-INRIA-2004-exception")]
+    QPLOneDotZeroINRIATwoZeroZeroFourException,
+    #[value(name = "Qt-GPL-exception-1.0")]
+    QtGPLExceptionOneDotZero,
     #[value(name = "Qt-LGPL-exception-1.1")]
     QtLGPLExceptionOneDotOne,
-    #[value(name = "Mackerras-3-Clause")]
-    MackerrasThreeClause,
-    #[value(name = "EUPL-1.1")]
-    EUPLOneDotOne,
-    #[value(name = "Autoconf-exception-generic")]
-    AutoconfExceptionGeneric,
-    #[value(name = "Sun-PPP")]
-    SunPPP,
-    #[value(name = "CECILL-B")]
-    CECILLB,
-    #[value(name = "Linux-OpenIB")]
-    LinuxOpenIb,
-    #[value(name = "BSD-Attribution-HPND-disclaimer")]
-    BSDAttributionHPNDDisclaimer,
+    #[value(name = "Qwt-exception-1.0")]
+    QwtExceptionOneDotZero,
+    #[value(name = "RRDtool-FLOSS-exception-2.0")]
+    RrDtoolFLOSSExceptionTwoDotZero,
+    #[value(name = "SANE-exception")]
+    SANEException,
+    #[value(name = "SHL-2.0")]
+    SHLTwoDotZero,
+    #[value(name = "SHL-2.1")]
+    SHLTwoDotOne,
+    #[value(name = "SWI-exception")]
+    SWIException,
+    #[value(name = "Swift-exception")]
+    SwiftException,
+    #[value(name = "Texinfo-exception")]
+    TexinfoException,
+    #[value(name = "UBDL-exception")]
+    UBDLException,
+    #[value(name = "Universal-FOSS-exception-1.0")]
+    UniversalFOSSExceptionOneDotZero,
+    #[value(name = "WxWindows-exception-3.1")]
+    WxWindowsExceptionThreeDotOne,
+    #[value(name = "cryptsetup-OpenSSL-exception")]
+    CryptsetupOpenSslException,
+    #[value(name = "Nokia-Qt-exception-1.1")]
+    DeprecatedNokiaQtExceptionOneDotOne,
+    #[value(name = "eCos-exception-2.0")]
+    ECosExceptionTwoDotZero,
+    #[value(name = "erlang-otp-linking-exception")]
+    ErlangOtpLinkingException,
     #[value(name = "fmt-exception")]
     FmtException,
-    #[value(name = "MIT-feh")]
-    MITFeh,
-    #[value(name = "Ruby-pty")]
-    RubyPty,
-    #[value(name = "BSD-3-Clause-No-Military-License")]
-    BSDThreeClauseNoMilitaryLicense,
-    #[value(name = "HPND-sell-variant")]
-    HPNDSellVariant,
-    #[value(name = "DEC-3-Clause")]
-    DECThreeClause,
-    #[value(name = "ZPL-1.1")]
-    ZPLOneDotOne,
-    #[value(name = "Zlib")]
-    Zlib,
-    #[value(name = "FTL")]
-    FTL,
-    #[value(name = "CC-BY-SA-3.0-DE")]
-    CCBYSAThreeDotZeroDE,
-    #[value(name = "CC-BY-NC-ND-3.0")]
-    CCBYNCNDThreeDotZero,
-    #[value(name = "TOSL")]
-    TOSL,
-    #[value(name = "FBM")]
-    FBM,
-    #[value(name = "CC-BY-ND-3.0")]
-    CCBYNDThreeDotZero,
-    #[value(name = "C-UDA-1.0")]
-    CUDAOneDotZero,
-    #[value(name = "Qt-GPL-exception-1.0")]
-    QtGPLExceptionOneDotZero,
-    #[value(name = "Sun-PPP-2000")]
-    SunPPPTwoZeroZeroZero,
-    #[value(name = "OpenVision")]
-    OpenVision,
-    #[value(name = "deprecated_GFDL-1.1")]
-    DeprecatedGFDLOneDotOne,
-    #[value(name = "ssh-keyscan")]
-    SshKeyscan,
-    #[value(name = "SSH-short")]
-    SSHShort,
-    #[value(name = "gSOAP-1.3b")]
-    GSoapOneDotThreeb,
-    #[value(name = "JPNIC")]
-    JPNIC,
-    #[value(name = "GFDL-1.1-no-invariants-only")]
-    GFDLOneDotOneNoInvariantsOnly,
-    #[value(name = "TAPR-OHL-1.0")]
-    TAPROHLOneDotZero,
-    #[value(name = "Rdisc")]
-    Rdisc,
-    #[value(name = "BSD-2-Clause-Views")]
-    BSDTwoClauseViews,
-    #[value(name = "Artistic-1.0-cl8")]
-    ArtisticOneDotZeroClEight,
-    #[value(name = "HPND-doc")]
-    HPNDDoc,
-    #[value(name = "BSD-3-Clause-Sun")]
-    BSDThreeClauseSun,
-    #[value(name = "URT-RLE")]
-    URTRLE,
-    #[value(name = "mpich2")]
-    MpichTwo,
-    #[value(name = "CGAL-linking-exception")]
-    CGALLinkingException,
-    #[value(name = "MPL-1.1")]
-    MPLOneDotOne,
+    #[value(name = "freertos-exception-2.0")]
+    FreertosExceptionTwoDotZero,
+    #[value(name = "gnu-javamail-exception")]
+    GnuJavamailException,
+    #[value(name = "harbour-exception")]
+    HarbourException,
+    #[value(name = "i2p-gpl-java-exception")]
+    ITwopGplJavaException,
+    #[value(name = "libpri-OpenH323-exception")]
+    LibpriOpenHThreeTwoThreeException,
+    #[value(name = "mif-exception")]
+    MifException,
+    #[value(name = "mxml-exception")]
+    MxmlException,
+    #[value(name = "openvpn-openssl-exception")]
+    OpenvpnOpensslException,
+    #[value(name = "polyparse-exception")]
+    PolyparseException,
+    #[value(name = "romic-exception")]
+    RomicException,
+    #[value(name = "stunnel-exception")]
+    StunnelException,
+    #[value(name = "u-boot-exception-2.0")]
+    UBootExceptionTwoDotZero,
+    #[value(name = "vsftpd-openssl-exception")]
+    VsftpdOpensslException,
+    #[value(name = "x11vnc-openssl-exception")]
+    XOneOnevncOpensslException,
+}
+
+/// A license, optionally followed by `WITH` and an exception to it, as in SPDX expressions
+/// (e.g., "GPL-2.0-or-later WITH Classpath-exception-2.0").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LicenseExpression {
+    pub license: License,
+    pub exception: Option<LicenseException>,
+}
+
+impl From<License> for LicenseExpression {
+    fn from(license: License) -> Self {
+        LicenseExpression {
+            license,
+            exception: None,
+        }
+    }
+}
+
+impl std::str::FromStr for LicenseExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use clap::ValueEnum;
+
+        // Like other SPDX operators, WITH is matched whatever its case
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let mut parts = words
+            .split(|word| word.eq_ignore_ascii_case("WITH"))
+            .map(|part| part.join(" "));
+        let license_id = parts.next().unwrap_or_default();
+        let exception_id = parts.next();
+        if parts.next().is_some() {
+            return Err(format!("'{}' has more than one WITH", s));
+        }
+
+        let license = match parse_license_id(&license_id) {
+            Ok(license) => license,
+            Err(e) => {
+                // Exceptions alone aren't licenses
+                if let Ok(exception) = parse_exception_id(&license_id) {
+                    let exception = exception
+                        .to_possible_value()
+                        .expect("No exception is skipped");
+                    return Err(format!(
+                        "'{}' is a license exception, use it after WITH, ex: `Apache-2.0 WITH {}`",
+                        license_id,
+                        exception.get_name()
+                    ));
+                }
+                return Err(e);
+            }
+        };
+        let exception = match exception_id {
+            Some(id) => Some(parse_exception_id(&id)?),
+            None => None,
+        };
+
+        Ok(LicenseExpression { license, exception })
+    }
+}
+
+/// An entry of the SPDX catalog: a license, optionally with an exception, or an exception alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogEntry {
+    License(LicenseExpression),
+    Exception(LicenseException),
+}
+
+impl std::str::FromStr for CatalogEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<LicenseExpression>() {
+            Ok(expression) => Ok(CatalogEntry::License(expression)),
+            // Suggestions for licenses are kept, unless the id is an exception
+            Err(e) => parse_exception_id(s.trim())
+                .map(CatalogEntry::Exception)
+                .map_err(|_| e),
+        }
+    }
+}
+
+/// Informal names of licenses and the SPDX identifier they stand for.
+/// Compared as `normalize_id` leaves them, so "GPL v3" is "GPLv3" too.
+const LICENSE_ALIASES: &[(&str, &str)] = &[
+    ("Apache 2", "Apache-2.0"),
+    ("Apache License 2.0", "Apache-2.0"),
+    ("Apache License, Version 2.0", "Apache-2.0"),
+    ("Apache Software License", "Apache-2.0"),
+    ("ASL 2.0", "Apache-2.0"),
+    ("MIT License", "MIT"),
+    ("Expat", "MIT"),
+    ("BSD 2", "BSD-2-Clause"),
+    ("Simplified BSD", "BSD-2-Clause"),
+    ("FreeBSD", "BSD-2-Clause"),
+    ("BSD 3", "BSD-3-Clause"),
+    ("New BSD", "BSD-3-Clause"),
+    ("Modified BSD", "BSD-3-Clause"),
+    ("Revised BSD", "BSD-3-Clause"),
+    ("GPLv2", "GPL-2.0-only"),
+    ("GPL 2", "GPL-2.0-only"),
+    ("GPLv2+", "GPL-2.0-or-later"),
+    ("GPL 2+", "GPL-2.0-or-later"),
+    ("GPLv3", "GPL-3.0-only"),
+    ("GPL 3", "GPL-3.0-only"),
+    ("GPLv3+", "GPL-3.0-or-later"),
+    ("GPL 3+", "GPL-3.0-or-later"),
+    ("LGPLv2.1", "LGPL-2.1-only"),
+    ("LGPLv2.1+", "LGPL-2.1-or-later"),
+    ("LGPLv3", "LGPL-3.0-only"),
+    ("LGPL 3", "LGPL-3.0-only"),
+    ("LGPLv3+", "LGPL-3.0-or-later"),
+    ("LGPL 3+", "LGPL-3.0-or-later"),
+    ("AGPLv3", "AGPL-3.0-only"),
+    ("AGPL 3", "AGPL-3.0-only"),
+    ("AGPLv3+", "AGPL-3.0-or-later"),
+    ("AGPL 3+", "AGPL-3.0-or-later"),
+    ("MPL 2", "MPL-2.0"),
+    ("EPL 2", "EPL-2.0"),
+    ("CC0", "CC0-1.0"),
+    ("Boost", "BSL-1.0"),
+    ("Boost Software License", "BSL-1.0"),
+    ("ISC License", "ISC"),
+    ("zlib License", "Zlib"),
+    ("The Unlicense", "Unlicense"),
+    ("Artistic 2", "Artistic-2.0"),
+    ("Python", "PSF-2.0"),
+];
+
+/// How many identifiers are suggested for an unknown one.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Parses a license identifier leniently, see `parse_catalog_id`.
+pub fn parse_license_id(id: &str) -> Result<License, String> {
+    parse_catalog_id(id, "license", LICENSE_ALIASES)
+}
+
+/// Parses a license exception identifier leniently, see `parse_catalog_id`.
+pub fn parse_exception_id(id: &str) -> Result<LicenseException, String> {
+    parse_catalog_id(id, "license exception", &[])
+}
+
+/// Finds the entry of a catalog named by `id`: its SPDX identifier, ignoring case and separators,
+/// or one of its aliases. Fails naming the closest identifiers, if any is close enough.
+fn parse_catalog_id<T: clap::ValueEnum>(
+    id: &str,
+    kind: &str,
+    aliases: &[(&str, &str)],
+) -> Result<T, String> {
+    if let Ok(entry) = T::from_str(id, false) {
+        return Ok(entry);
+    }
+
+    let key = normalize_id(id);
+    let spdx_id = |entry: &T| {
+        entry
+            .to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default()
+    };
+    let names = T::value_variants()
+        .iter()
+        .map(|entry| {
+            let name = spdx_id(entry);
+            (normalize_id(&name), name)
+        })
+        .chain(
+            aliases
+                .iter()
+                .map(|(alias, name)| (normalize_id(alias), name.to_string())),
+        );
+
+    // Either the id matches, or it's close enough to be suggested
+    let max_distance = 1 + key.len() / 4;
+    let mut suggestions: Vec<(usize, String)> = Vec::new();
+    for (candidate, name) in names {
+        if candidate == key {
+            return T::from_str(&name, false);
+        }
+        let distance = edit_distance(&key, &candidate);
+        if distance <= max_distance && !suggestions.iter().any(|(_, seen)| *seen == name) {
+            suggestions.push((distance, name));
+        }
+    }
+    suggestions.sort();
+
+    let names: Vec<String> = suggestions
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| format!("'{}'", name))
+        .collect();
+    Err(match names.as_slice() {
+        [] => format!(
+            "Unknown {} '{}', see `lic list` for the known ones",
+            kind, id
+        ),
+        [name] => format!("Unknown {} '{}', did you mean {}?", kind, id, name),
+        [rest @ .., last] => format!(
+            "Unknown {} '{}', did you mean {} or {}?",
+            kind,
+            id,
+            rest.join(", "),
+            last
+        ),
+    })
+}
+
+/// Folds case and separators away, so "Apache 2.0", "apache_2.0" and "Apache-2.0" compare equal.
+fn normalize_id(id: &str) -> String {
+    id.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// The number of edits turning one text into the other, swapping two neighbours counting as one.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // Distances between the prefixes of a and b, a row per character of a
+    let mut rows = vec![(0..=b.len()).collect::<Vec<usize>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+    rows[a.len()][b.len()]
+}
+
+impl std::fmt::Display for LicenseExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use clap::ValueEnum;
+
+        let license = self
+            .license
+            .to_possible_value()
+            .expect("No license is skipped");
+        write!(f, "{}", license.get_name())?;
+        if let Some(exception) = self.exception {
+            let exception = exception
+                .to_possible_value()
+                .expect("No exception is skipped");
+            write!(f, " WITH {}", exception.get_name())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for LicenseExpression {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let expression = String::deserialize(deserializer)?;
+        expression.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A whole SPDX license expression, licenses joined by AND and OR, grouped with parentheses
+/// (e.g., "MIT OR Apache-2.0", "(MIT AND Zlib) OR GPL-2.0-or-later WITH Classpath-exception-2.0").
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpdxExpression {
+    /// A license of the catalog, with its exception if any.
+    License(LicenseExpression),
+    /// A license outside the catalog, such as `LicenseRef-Proprietary`.
+    Other(String),
+    /// Every part applies.
+    And(Vec<SpdxExpression>),
+    /// Any one of the parts may be chosen.
+    Or(Vec<SpdxExpression>),
+}
+
+impl SpdxExpression {
+    /// Parses an expression as found in the wild, such as the `license` field of a package
+    /// manifest: `/` is read as OR ("MIT/Apache-2.0"), and licenses the catalog doesn't know are
+    /// kept as `Other` instead of failing. Only malformed expressions fail.
+    pub fn parse_lenient(s: &str) -> Result<Self, String> {
+        Self::parse(&s.replace('/', " OR "), true)
+    }
+
+    /// The licenses of the expression, in order, whatever the operators joining them.
+    pub fn leaves(&self) -> Vec<&SpdxExpression> {
+        match self {
+            SpdxExpression::And(parts) | SpdxExpression::Or(parts) => {
+                parts.iter().flat_map(|part| part.leaves()).collect()
+            }
+            leaf => vec![leaf],
+        }
+    }
+
+    /// Why the expression isn't acceptable, given why each of its licenses isn't: any of the
+    /// licenses joined by OR being acceptable is enough, all of those joined by AND must be.
+    ///
+    /// # Returns
+    ///
+    /// The reasons of the rejected licenses, empty when the expression is acceptable.
+    pub fn rejections<F>(&self, reject: &F) -> Vec<String>
+    where
+        F: Fn(&SpdxExpression) -> Option<String>,
+    {
+        match self {
+            SpdxExpression::And(parts) => parts
+                .iter()
+                .flat_map(|part| part.rejections(reject))
+                .collect(),
+            SpdxExpression::Or(choices) => {
+                let rejected: Vec<Vec<String>> = choices
+                    .iter()
+                    .map(|choice| choice.rejections(reject))
+                    .collect();
+                // A single acceptable choice is enough
+                if rejected.iter().any(Vec::is_empty) {
+                    Vec::new()
+                } else {
+                    rejected.into_iter().flatten().collect()
+                }
+            }
+            leaf => reject(leaf).into_iter().collect(),
+        }
+    }
+
+    fn parse(s: &str, lenient: bool) -> Result<Self, String> {
+        let spaced = s.replace('(', " ( ").replace(')', " ) ");
+        let tokens: Vec<&str> = spaced.split_whitespace().collect();
+        let mut parser = SpdxParser {
+            tokens: &tokens,
+            pos: 0,
+            lenient,
+        };
+        let expression = parser.or_expression()?;
+        match tokens.get(parser.pos) {
+            Some(token) => Err(format!(
+                "Unexpected '{}' in license expression '{}'",
+                token, s
+            )),
+            None => Ok(expression),
+        }
+    }
+}
+
+/// Recursive descent over the tokens of an SPDX expression, OR binding looser than AND.
+struct SpdxParser<'a> {
+    tokens: &'a [&'a str],
+    pos: usize,
+    lenient: bool,
+}
+
+impl SpdxParser<'_> {
+    /// Whether the next token is the operator, consuming it if so.
+    fn eat(&mut self, operator: &str) -> bool {
+        let found = self
+            .tokens
+            .get(self.pos)
+            .is_some_and(|token| token.eq_ignore_ascii_case(operator));
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn or_expression(&mut self) -> Result<SpdxExpression, String> {
+        let mut parts = vec![self.and_expression()?];
+        while self.eat("OR") {
+            parts.push(self.and_expression()?);
+        }
+        Ok(join(parts, false))
+    }
+
+    fn and_expression(&mut self) -> Result<SpdxExpression, String> {
+        let mut parts = vec![self.term()?];
+        while self.eat("AND") {
+            parts.push(self.term()?);
+        }
+        Ok(join(parts, true))
+    }
+
+    /// A parenthesized expression, or a license with its exception.
+    fn term(&mut self) -> Result<SpdxExpression, String> {
+        if self.eat("(") {
+            let expression = self.or_expression()?;
+            if !self.eat(")") {
+                return Err("Unclosed parenthesis in license expression".to_string());
+            }
+            return Ok(expression);
+        }
+
+        // The words up to the next operator make up the license, `WITH` included
+        let start = self.pos;
+        while self.tokens.get(self.pos).is_some_and(|token| {
+            !["AND", "OR", "(", ")"]
+                .iter()
+                .any(|operator| token.eq_ignore_ascii_case(operator))
+        }) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(match self.tokens.get(self.pos) {
+                Some(token) => format!("Expected a license, found '{}'", token),
+                None => "Expected a license, the expression ends early".to_string(),
+            });
+        }
+
+        let id = self.tokens[start..self.pos].join(" ");
+        if id.starts_with("LicenseRef-") || id.starts_with("DocumentRef-") {
+            return Ok(SpdxExpression::Other(id));
+        }
+        match id.parse::<LicenseExpression>() {
+            Ok(license) => Ok(SpdxExpression::License(license)),
+            Err(_) if self.lenient => Ok(SpdxExpression::Other(id)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Joins the parts with AND, or with OR, flattening parts already joined by the same operator.
+fn join(parts: Vec<SpdxExpression>, conjunction: bool) -> SpdxExpression {
+    if parts.len() == 1 {
+        return parts.into_iter().next().expect("There is one part");
+    }
+    let mut flat = Vec::with_capacity(parts.len());
+    for part in parts {
+        match part {
+            SpdxExpression::And(inner) if conjunction => flat.extend(inner),
+            SpdxExpression::Or(inner) if !conjunction => flat.extend(inner),
+            part => flat.push(part),
+        }
+    }
+    if conjunction {
+        SpdxExpression::And(flat)
+    } else {
+        SpdxExpression::Or(flat)
+    }
+}
+
+impl From<License> for SpdxExpression {
+    fn from(license: License) -> Self {
+        SpdxExpression::License(license.into())
+    }
+}
+
+impl From<LicenseExpression> for SpdxExpression {
+    fn from(license: LicenseExpression) -> Self {
+        SpdxExpression::License(license)
+    }
+}
+
+impl std::str::FromStr for SpdxExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, false)
+    }
+}
+
+impl std::fmt::Display for SpdxExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (parts, operator) = match self {
+            SpdxExpression::License(license) => return write!(f, "{}", license),
+            SpdxExpression::Other(id) => return f.write_str(id),
+            SpdxExpression::And(parts) => (parts, " AND "),
+            SpdxExpression::Or(parts) => (parts, " OR "),
+        };
+        for (idx, part) in parts.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(operator)?;
+            }
+            match part {
+                SpdxExpression::And(_) | SpdxExpression::Or(_) => write!(f, "({})", part)?,
+                _ => write!(f, "{}", part)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl serde::Serialize for SpdxExpression {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SpdxExpression {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let expression = String::deserialize(deserializer)?;
+        expression.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// What to do with files that carry copyright notices of others, such as vendored code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ThirdPartyAction {
+    /// Leave the file untouched.
+    #[default]
+    Skip,
+    /// Add the header alongside the existing notices, never replacing them.
+    Annotate,
+}
+
+/// What the git pre-commit hook runs on the staged files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum HookMode {
+    /// Fail the commit when a staged file is missing its header.
+    #[default]
+    Check,
+    /// Add missing headers, and stage the modified files.
+    Apply,
+}
+
+/// The package managers whose dependencies Lichen reads.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    Deserialize,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    /// Crates of Cargo.lock, read from `vendor/` or Cargo's registry sources.
+    Cargo,
+    /// Packages of package-lock.json, read from the lockfile or `node_modules/`.
+    Npm,
+    /// Packages installed in the Python virtual environment.
+    Python,
+}
+
+/// Groups of licenses the dependency policy may allow or deny at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LicenseCategory {
+    /// Free licenses without copyleft, such as MIT or Apache-2.0.
+    Permissive,
+    /// Licenses whose copyleft reaches modified files, or the whole work.
+    Copyleft,
+    /// Copyleft limited to the files or library under the license, such as LGPL or MPL.
+    WeakCopyleft,
+    /// Copyleft reaching the whole work combining the code, such as GPL or AGPL.
+    StrongCopyleft,
+    /// Licenses approved by the Open Source Initiative.
+    Osi,
+    /// Licenses the Open Source Initiative hasn't approved.
+    NonOsi,
+    /// Licenses the Free Software Foundation considers free.
+    FsfLibre,
+    /// Licenses the Free Software Foundation doesn't list as free.
+    NonFsfLibre,
+    /// Licenses restricting uses free software licenses allow, such as BUSL-1.1.
+    NonFree,
+    /// No license granted at all, such as `UNLICENSED` or `LicenseRef-Proprietary`.
+    Proprietary,
+    /// Licenses with a deprecated SPDX identifier.
+    Deprecated,
+    /// Missing licenses, and licenses outside the catalog.
+    Unknown,
+}
+
+/// A license, or a category of licenses, in the `allow` and `deny` lists of the dependency policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyEntry {
+    Category(LicenseCategory),
+    License(LicenseExpression),
+}
+
+impl std::str::FromStr for PolicyEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use clap::ValueEnum;
+
+        if let Ok(category) = LicenseCategory::from_str(s.trim(), true) {
+            return Ok(PolicyEntry::Category(category));
+        }
+        s.parse::<LicenseExpression>()
+            .map(PolicyEntry::License)
+            .map_err(|e| {
+                let categories: Vec<String> = LicenseCategory::value_variants()
+                    .iter()
+                    .filter_map(|category| category.to_possible_value())
+                    .map(|value| value.get_name().to_string())
+                    .collect();
+                format!(
+                    "{} Categories of licenses are: {}.",
+                    e,
+                    categories.join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for PolicyEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use clap::ValueEnum;
+
+        match self {
+            PolicyEntry::Category(category) => match category.to_possible_value() {
+                Some(value) => write!(f, "{}", value.get_name()),
+                None => write!(f, "{:?}", category),
+            },
+            PolicyEntry::License(license) => write!(f, "{}", license),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PolicyEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// How the outcome of a run is reported on stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Diffs of dry runs, with the summary in the logs.
+    #[default]
+    Text,
+    /// A JSON report listing every file and its outcome.
+    Json,
+    /// A SARIF 2.1.0 log of header problems, for code scanning tools. Only for `check`.
+    Sarif,
 }
 
 // ▰▰▰ CLI Argument Structs ▰▰▰ //
 
 pub fn parse_year_to_date(s: &str) -> Result<Date, String> {
-    println!("{s}");
     // Attempt to parse as a full date; If parsing fails, it's only a problem if it was *meant* to be a full-date.
     match s.parse::<Date>() {
         Ok(date) => return Ok(date),
@@ -1630,12 +2270,13 @@ pub fn parse_to_author(input: &str) -> Result<Authors, String> {
 }
 
 // Common arguments related to license information
-#[derive(Args, Debug)]
+#[derive(Args, Debug, Clone)]
 pub struct LicenseArgs {
-    /// SPDX identifier of the license to generate (e.g., MIT, Apache-2.0).
+    /// SPDX identifier of the license to generate (e.g., MIT, Apache-2.0), optionally followed
+    /// by WITH and an exception ("GPL-2.0-or-later WITH Classpath-exception-2.0").
     /// Can be omitted if specified in configuration.
     #[arg()]
-    pub license: Option<License>,
+    pub license: Option<LicenseExpression>,
 
     /// Author names and emails (In the format NAME:EMAIL; entries seperated by a comma. Email optional).
     #[arg(short, long, value_parser = parse_to_author)]
@@ -1648,10 +2289,14 @@ pub struct LicenseArgs {
     /// Enable support for multiple licenses in the same project (Default is replace)
     #[arg(long, action = clap::ArgAction::SetTrue)]
     pub multiple: Option<bool>,
+
+    /// Refuse deprecated SPDX identifiers instead of warning about them
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub deny_deprecated: Option<bool>,
 }
 
 // Common arguments for file processing
-#[derive(Args, Debug)]
+#[derive(Args, Debug, Clone)]
 pub struct FileProcessingArgs {
     /// Files or directories to process. Defaults to the current directory (`.`).
     #[arg(num_args = 1..)]
@@ -1664,6 +2309,18 @@ pub struct FileProcessingArgs {
     /// Do not respect the git_ignore file (If present in directory) and other pattern defaults
     #[arg(short = 'A', long, action = clap::ArgAction::SetTrue)]
     pub all: Option<bool>,
+
+    /// Decode UTF-16 (with a byte order mark) and ISO-8859-1 files instead of skipping them
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub legacy_encodings: Option<bool>,
+
+    /// Only process files added or modified since this git ref (ex: `main`, `HEAD~3`)
+    #[arg(long, value_name = "REF", conflicts_with = "staged")]
+    pub changed_since: Option<String>,
+
+    /// Only process files added or modified in the git index, for pre-commit hooks
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub staged: Option<bool>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -1679,6 +2336,27 @@ pub enum Commands {
 
     /// Initialize a default configuration file
     Init(InitArgs),
+
+    /// Roll back the files changed by the last apply or unapply
+    Undo,
+
+    /// Report files missing their license header, without modifying them
+    Check(CheckArgs),
+
+    /// Manage the git pre-commit hook
+    Hook(HookArgs),
+
+    /// List the embedded licenses and exceptions
+    List(ListArgs),
+
+    /// Show a license template, its variables and metadata
+    Show(ShowArgs),
+
+    /// Check the licenses of files and local dependencies against the project's license
+    Compat(CompatArgs),
+
+    /// List the dependencies of the project and their licenses, from lockfiles and installed packages
+    Deps(DepsArgs),
 }
 
 #[derive(Args, Debug)]
@@ -1707,6 +2385,64 @@ pub struct ApplyArgs {
     /// Completely possible line or block doesn't exist, in which case it falls back to the other.
     #[arg(long, action = clap::ArgAction::SetTrue)]
     pub prefer_block: Option<bool>,
+
+    /// Reflow the header text so lines (comment prefix included) fit within this many columns.
+    #[arg(short, long, value_name = "COLUMNS")]
+    pub width: Option<usize>,
+
+    /// Do not mark headers with an invisible character. They are then found by their content.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub no_marker: Option<bool>,
+
+    /// Replace existing copyright or license comments at the top of files, such as those
+    /// written by other tools or by hand. Combine with --dry-run to review the changes first.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub replace_foreign: Option<bool>,
+
+    /// What to do with files carrying copyright notices not naming the authors (Default is skip)
+    #[arg(long, value_enum)]
+    pub third_party: Option<ThirdPartyAction>,
+
+    /// Write the changes to a patch file instead of printing them. Implies --dry-run.
+    #[arg(long, value_name = "FILE")]
+    pub patch: Option<PathBuf>,
+
+    /// Report format on stdout (Default is text)
+    #[arg(long, value_enum, conflicts_with_all = ["stdin", "watch"])]
+    pub format: Option<OutputFormat>,
+
+    /// Read the file content from stdin and write the result to stdout, for editors and formatters.
+    #[arg(long, action = clap::ArgAction::SetTrue, requires = "filename", conflicts_with_all = ["dry_run", "patch"])]
+    pub stdin: Option<bool>,
+
+    /// Path of the file read from stdin, picking its comment syntax and matching license.
+    #[arg(long, value_name = "PATH", requires = "stdin")]
+    pub filename: Option<PathBuf>,
+
+    /// Keep running, applying headers to files created or renamed under the targets.
+    #[arg(long, action = clap::ArgAction::SetTrue, conflicts_with_all = ["stdin", "dry_run", "patch"])]
+    pub watch: Option<bool>,
+}
+
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    #[command(flatten)]
+    pub license_args: LicenseArgs,
+
+    #[command(flatten)]
+    pub file_args: FileProcessingArgs,
+
+    /// Print the fixes as a diff, along with the report.
+    #[arg(short = 'D', long, action = clap::ArgAction::SetTrue)]
+    pub dry_run: Option<bool>,
+
+    /// Write the fixes to a patch file.
+    #[arg(long, value_name = "FILE")]
+    pub patch: Option<PathBuf>,
+
+    /// Report format on stdout (Default is text)
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
 }
 
 #[derive(Args, Debug)]
@@ -1723,8 +2459,142 @@ pub struct UnapplyArgs {
     pub file_args: FileProcessingArgs,
 
     /// Run without modification. See what would be changed.
-    #[arg(short = 'D', long)]
+    #[arg(short = 'D', long, action = clap::ArgAction::SetTrue)]
     pub dry_run: Option<bool>,
+
+    /// Write the changes to a patch file instead of printing them. Implies --dry-run.
+    #[arg(long, value_name = "FILE")]
+    pub patch: Option<PathBuf>,
+
+    /// Report format on stdout (Default is text)
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
+
+    /// SPDX identifier of the license whose headers to remove, for headers without a marker.
+    /// Defaults to the configured licenses, or any license.
+    #[arg(short, long, value_parser = parse_license_id)]
+    pub license: Option<License>,
+
+    /// Authors a header without a marker must name to be removed (In the format NAME:EMAIL;
+    /// entries seperated by a comma). Defaults to the configured authors.
+    #[arg(short, long, value_parser = parse_to_author)]
+    pub authors: Option<Authors>,
+}
+
+#[derive(Args, Debug)]
+pub struct HookArgs {
+    #[command(subcommand)]
+    pub action: HookAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HookAction {
+    /// Install a pre-commit hook running Lichen on the staged files
+    Install(HookInstallArgs),
+
+    /// Remove the pre-commit hook installed by Lichen
+    Uninstall,
+}
+
+#[derive(Args, Debug)]
+pub struct HookInstallArgs {
+    /// Whether the hook checks the staged files or applies headers to them.
+    #[arg(long, value_enum, default_value_t)]
+    pub mode: HookMode,
+
+    /// Replace an existing pre-commit hook that wasn't installed by Lichen.
+    #[arg(short, long, action = clap::ArgAction::SetTrue)]
+    pub force: Option<bool>,
+}
+
+#[derive(Args, Debug)]
+pub struct ListArgs {
+    /// Only list licenses whose identifier or name contains this text, ignoring case.
+    #[arg()]
+    pub search: Option<String>,
+
+    /// Only list licenses approved by the Open Source Initiative.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub osi: Option<bool>,
+
+    /// Only list licenses the Free Software Foundation considers libre.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub fsf: Option<bool>,
+
+    /// Only list deprecated identifiers.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub deprecated: Option<bool>,
+
+    /// Only list license exceptions, used with `WITH` in SPDX expressions.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub exceptions: Option<bool>,
+
+    /// Output format on stdout (Default is text)
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Args, Debug)]
+pub struct ShowArgs {
+    /// SPDX identifier of the license to show (e.g., MIT, Apache-2.0), optionally followed
+    /// by WITH and an exception, or of an exception alone (e.g., LLVM-exception).
+    #[arg()]
+    pub license: CatalogEntry,
+
+    /// Author names and emails to render the template with (In the format NAME:EMAIL; entries seperated by a comma).
+    #[arg(short, long, value_parser = parse_to_author)]
+    pub authors: Option<Authors>,
+
+    /// Date to render the template with (defaults to the current year).
+    #[arg(short, long, value_parser = parse_year_to_date)]
+    pub date: Option<Date>,
+
+    /// Print the template as embedded, with its variables, instead of rendering it.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub raw: Option<bool>,
+
+    /// Output format on stdout (Default is text)
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Args, Debug)]
+pub struct CompatArgs {
+    /// SPDX license expression of the project (e.g., "GPL-2.0-only", "MIT OR Apache-2.0").
+    /// Defaults to the licenses of the configuration, then to the `license` of Cargo.toml or package.json.
+    #[arg(short, long)]
+    pub license: Option<SpdxExpression>,
+
+    #[command(flatten)]
+    pub file_args: FileProcessingArgs,
+
+    /// Only check the licenses of files, not of local dependencies.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub no_deps: Option<bool>,
+
+    /// Output format on stdout (Default is text)
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Args, Debug)]
+pub struct DepsArgs {
+    /// Directory of the project. Defaults to the current directory (`.`).
+    #[arg()]
+    pub path: Option<PathBuf>,
+
+    /// Only list the dependencies of these package managers.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub ecosystem: Option<Vec<Ecosystem>>,
+
+    /// Check the licenses against the `[policy]` of the configuration, failing when any
+    /// dependency violates it.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub check: Option<bool>,
+
+    /// Output format on stdout (Default is text)
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
 }
 
 // The main Cli struct
@@ -1743,3 +2613,111 @@ pub struct Cli {
     #[arg(long, short)]
     pub config: Option<PathBuf>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn license_ids_are_parsed_leniently() {
+        let parse = |s: &str| s.parse::<LicenseExpression>().map(|e| e.to_string());
+        assert_eq!(parse("mit").unwrap(), "MIT");
+        assert_eq!(parse("apache-2").unwrap(), "Apache-2.0");
+        assert_eq!(parse("Apache 2.0").unwrap(), "Apache-2.0");
+        assert_eq!(parse("GPLv3").unwrap(), "GPL-3.0-only");
+        assert_eq!(
+            parse("apache-2.0 with llvm-exception").unwrap(),
+            "Apache-2.0 WITH LLVM-exception"
+        );
+
+        assert_eq!(
+            parse("mti").unwrap_err(),
+            "Unknown license 'mti', did you mean 'MIT'?"
+        );
+        assert!(
+            parse("MIT WITH llvm-excepton")
+                .unwrap_err()
+                .contains("did you mean 'LLVM-exception'")
+        );
+        assert!(parse("zzzqqq").unwrap_err().contains("see `lic list`"));
+    }
+
+    #[test]
+    fn catalog_entries_take_exceptions_alone() {
+        assert_eq!(
+            "llvm-exception".parse::<CatalogEntry>().unwrap(),
+            CatalogEntry::Exception(LicenseException::LLVMException)
+        );
+        assert!(matches!(
+            "Apache-2.0 WITH LLVM-exception".parse::<CatalogEntry>(),
+            Ok(CatalogEntry::License(LicenseExpression {
+                exception: Some(_),
+                ..
+            }))
+        ));
+        assert_eq!(
+            "mti".parse::<CatalogEntry>().unwrap_err(),
+            "Unknown license 'mti', did you mean 'MIT'?"
+        );
+    }
+
+    #[test]
+    fn license_aliases_name_known_licenses() {
+        for (alias, id) in LICENSE_ALIASES {
+            let license = parse_license_id(alias).unwrap();
+            assert_eq!(license.spdx_id(), *id);
+        }
+    }
+
+    #[test]
+    fn spdx_expressions_are_parsed() {
+        let parse = |s: &str| s.parse::<SpdxExpression>().map(|e| e.to_string());
+        assert_eq!(parse("MIT OR Apache-2.0").unwrap(), "MIT OR Apache-2.0");
+        assert_eq!(
+            parse("(mit and zlib) or GPL-2.0-or-later with classpath-exception-2.0").unwrap(),
+            "(MIT AND Zlib) OR GPL-2.0-or-later WITH Classpath-exception-2.0"
+        );
+        // AND binds tighter than OR, and nested operators are flattened
+        assert_eq!(
+            "MIT OR (ISC OR Zlib AND 0BSD)"
+                .parse::<SpdxExpression>()
+                .unwrap(),
+            SpdxExpression::Or(vec![
+                License::MIT.into(),
+                License::ISC.into(),
+                SpdxExpression::And(vec![License::Zlib.into(), License::ZeroBsd.into()]),
+            ])
+        );
+        assert_eq!(
+            parse("LicenseRef-Proprietary").unwrap(),
+            "LicenseRef-Proprietary"
+        );
+
+        assert!(parse("MIT OR").is_err());
+        assert!(parse("(MIT").is_err());
+        assert!(parse("MIT)").is_err());
+        assert!(parse("MIT OR Foo-License").is_err());
+    }
+
+    #[test]
+    fn spdx_expressions_are_parsed_leniently() {
+        let parse = |s: &str| SpdxExpression::parse_lenient(s).unwrap();
+        assert_eq!(parse("MIT/Apache-2.0").to_string(), "MIT OR Apache-2.0");
+        assert_eq!(
+            parse("Apache License, Version 2.0 AND Foo-License"),
+            SpdxExpression::And(vec![
+                License::ApacheTwoDotZero.into(),
+                SpdxExpression::Other("Foo-License".to_string()),
+            ])
+        );
+        assert_eq!(parse("MIT OR GPL-3.0+").leaves().len(), 2);
+    }
+
+    #[test]
+    fn edit_distance_counts_swaps_once() {
+        assert_eq!(edit_distance("mit", "mit"), 0);
+        assert_eq!(edit_distance("mti", "mit"), 1);
+        assert_eq!(edit_distance("apach", "apache"), 1);
+        assert_eq!(edit_distance("", "isc"), 3);
+    }
+}
@@ -0,0 +1,338 @@
+//! # Dependency Policy
+//!
+//! Checks the licenses of dependencies against the `[policy]` of the configuration: the licenses
+//! and categories of licenses it allows or denies, and the exceptions it makes for packages.
+
+use crate::compat::{self, Copyleft};
+use crate::config::{PolicyConfig, PolicyException};
+use crate::deps::Dependency;
+use crate::models::{LicenseCategory, LicenseExpression, PolicyEntry, SpdxExpression};
+use log::debug;
+use serde::Serialize;
+
+/// A dependency whose license the policy doesn't accept.
+#[derive(Debug, Serialize)]
+pub struct Violation<'a> {
+    #[serde(flatten)]
+    pub dependency: &'a Dependency,
+    /// Why the license isn't accepted, one reason per rejected license of its expression.
+    pub reasons: Vec<String>,
+}
+
+/// The outcome of checking dependencies against the policy.
+#[derive(Debug)]
+pub struct PolicyReport<'a> {
+    pub violations: Vec<Violation<'a>>,
+    /// Exceptions matching none of the dependencies, likely left over from removed packages.
+    pub unused_exceptions: Vec<&'a PolicyException>,
+}
+
+/// Checks the license of every dependency against the policy.
+pub fn check<'a>(policy: &'a PolicyConfig, dependencies: &'a [Dependency]) -> PolicyReport<'a> {
+    let mut used = vec![false; policy.exceptions.len()];
+    let mut violations = Vec::new();
+
+    for dependency in dependencies {
+        let mut exempt = false;
+        let mut excepted: Vec<PolicyEntry> = Vec::new();
+        for (index, exception) in policy.exceptions.iter().enumerate() {
+            if !applies_to(exception, dependency) {
+                continue;
+            }
+            used[index] = true;
+            debug!(
+                "Policy exception for {} {}: {}",
+                dependency.name, dependency.version, exception.reason
+            );
+            if exception.allow.is_empty() {
+                exempt = true;
+            }
+            excepted.extend(&exception.allow);
+        }
+        if exempt {
+            continue;
+        }
+
+        let rules = Rules {
+            policy,
+            excepted: &excepted,
+        };
+        let reasons = match &dependency.license {
+            Some(license) => license.rejections(&|leaf| rules.rejection(Some(leaf))),
+            None => rules.rejection(None).into_iter().collect(),
+        };
+        if !reasons.is_empty() {
+            violations.push(Violation {
+                dependency,
+                reasons,
+            });
+        }
+    }
+
+    let unused_exceptions = policy
+        .exceptions
+        .iter()
+        .zip(used)
+        .filter(|(_, used)| !used)
+        .map(|(exception, _)| exception)
+        .collect();
+    PolicyReport {
+        violations,
+        unused_exceptions,
+    }
+}
+
+/// Whether the exception is made for the dependency.
+fn applies_to(exception: &PolicyException, dependency: &Dependency) -> bool {
+    exception.name == dependency.name
+        && exception
+            .version
+            .as_ref()
+            .is_none_or(|version| *version == dependency.version)
+        && exception
+            .ecosystem
+            .is_none_or(|ecosystem| ecosystem == dependency.ecosystem)
+}
+
+/// The policy as it applies to a single dependency.
+struct Rules<'a> {
+    policy: &'a PolicyConfig,
+    /// What the exceptions made for the dependency allow on top of the policy.
+    excepted: &'a [PolicyEntry],
+}
+
+impl Rules<'_> {
+    /// Why the policy rejects a single license of an expression, `None` standing for a missing
+    /// license. Licenses named explicitly take precedence over categories, and denying over
+    /// allowing, so that `allow = ["permissive"]` and `deny = ["MIT"]` denies MIT.
+    fn rejection(&self, leaf: Option<&SpdxExpression>) -> Option<String> {
+        let explicit = |entries: &[PolicyEntry]| {
+            entries.iter().any(|entry| {
+                matches!(entry, PolicyEntry::License(license) if license_matches(*license, leaf))
+            })
+        };
+        let category = |entries: &[PolicyEntry]| {
+            entries.iter().find_map(|entry| match entry {
+                PolicyEntry::Category(category) if in_category(*category, leaf) => Some(*entry),
+                _ => None,
+            })
+        };
+
+        if explicit(self.excepted) || category(self.excepted).is_some() {
+            return None;
+        }
+        let subject = match leaf {
+            Some(leaf) => leaf.to_string(),
+            None => "A missing license".to_string(),
+        };
+        if explicit(&self.policy.deny) {
+            return Some(format!("{} is denied by the policy.", subject));
+        }
+        if explicit(&self.policy.allow) {
+            return None;
+        }
+        if let Some(entry) = category(&self.policy.deny) {
+            return Some(format!(
+                "{} is denied by the policy, as `{}`.",
+                subject, entry
+            ));
+        }
+        if self.policy.allow.is_empty() || category(&self.policy.allow).is_some() {
+            return None;
+        }
+        Some(format!("{} isn't allowed by the policy.", subject))
+    }
+}
+
+/// Whether a license of the policy names the license, deprecated identifiers read as their
+/// replacement. A license without exception names the license with any exception, which only
+/// grants more.
+fn license_matches(entry: LicenseExpression, leaf: Option<&SpdxExpression>) -> bool {
+    let Some(SpdxExpression::License(license)) = leaf else {
+        return false;
+    };
+    let (entry, license) = (
+        compat::current_license(entry),
+        compat::current_license(*license),
+    );
+    entry.license == license.license
+        && (entry.exception.is_none() || entry.exception == license.exception)
+}
+
+/// Whether the license belongs to the category, `None` standing for a missing license.
+/// Nothing is known of licenses outside the catalog, only proprietary ones are told apart.
+fn in_category(category: LicenseCategory, leaf: Option<&SpdxExpression>) -> bool {
+    let license = match leaf {
+        Some(SpdxExpression::License(license)) => *license,
+        Some(SpdxExpression::Other(id)) => {
+            return match category {
+                LicenseCategory::Unknown => true,
+                LicenseCategory::Proprietary => compat::is_proprietary(id),
+                _ => false,
+            };
+        }
+        _ => return category == LicenseCategory::Unknown,
+    };
+
+    let copyleft = compat::copyleft(license);
+    match category {
+        LicenseCategory::Permissive => copyleft.is_none() && !compat::is_non_free(license),
+        LicenseCategory::Copyleft => copyleft.is_some(),
+        LicenseCategory::WeakCopyleft => copyleft == Some(Copyleft::Weak),
+        LicenseCategory::StrongCopyleft => copyleft == Some(Copyleft::Strong),
+        LicenseCategory::Osi => license.license.is_osi_approved(),
+        LicenseCategory::NonOsi => !license.license.is_osi_approved(),
+        LicenseCategory::FsfLibre => license.license.is_fsf_libre(),
+        LicenseCategory::NonFsfLibre => !license.license.is_fsf_libre(),
+        LicenseCategory::NonFree => compat::is_non_free(license),
+        LicenseCategory::Deprecated => license.license.is_deprecated(),
+        LicenseCategory::Proprietary | LicenseCategory::Unknown => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Ecosystem;
+
+    fn policy(allow: &[&str], deny: &[&str]) -> PolicyConfig {
+        PolicyConfig {
+            allow: allow.iter().map(|entry| entry.parse().unwrap()).collect(),
+            deny: deny.iter().map(|entry| entry.parse().unwrap()).collect(),
+            exceptions: Vec::new(),
+        }
+    }
+
+    fn dependency(name: &str, license: Option<&str>) -> Dependency {
+        Dependency {
+            ecosystem: Ecosystem::Cargo,
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            declared: license.map(str::to_string),
+            license: license.map(|l| SpdxExpression::parse_lenient(l).unwrap()),
+            manifest: None,
+        }
+    }
+
+    /// The reasons the policy rejects a dependency under the license.
+    fn rejections(policy: &PolicyConfig, license: Option<&str>) -> Vec<String> {
+        let dependencies = [dependency("crate", license)];
+        check(policy, &dependencies)
+            .violations
+            .into_iter()
+            .flat_map(|violation| violation.reasons)
+            .collect()
+    }
+
+    #[test]
+    fn licenses_are_sorted_into_categories() {
+        let license = |id: &str| SpdxExpression::parse_lenient(id).unwrap();
+        let is = |category, id: &str| in_category(category, Some(&license(id)));
+
+        assert!(is(LicenseCategory::Permissive, "MIT"));
+        assert!(is(LicenseCategory::Permissive, "Apache-2.0"));
+        assert!(!is(LicenseCategory::Permissive, "MPL-2.0"));
+        assert!(!is(LicenseCategory::Permissive, "BUSL-1.1"));
+        assert!(is(LicenseCategory::WeakCopyleft, "LGPL-2.1-or-later"));
+        assert!(is(LicenseCategory::WeakCopyleft, "MPL-2.0"));
+        assert!(is(LicenseCategory::StrongCopyleft, "GPL-2.0+"));
+        assert!(is(LicenseCategory::StrongCopyleft, "AGPL-3.0-only"));
+        assert!(is(LicenseCategory::Copyleft, "EUPL-1.2"));
+        // Linking exceptions stop the copyleft at the library
+        assert!(is(
+            LicenseCategory::WeakCopyleft,
+            "GPL-2.0-only WITH Classpath-exception-2.0"
+        ));
+        assert!(is(LicenseCategory::NonFree, "CC-BY-NC-4.0"));
+        assert!(is(LicenseCategory::NonOsi, "BUSL-1.1"));
+        assert!(is(LicenseCategory::Osi, "MIT"));
+        assert!(is(LicenseCategory::Deprecated, "GPL-2.0+"));
+        assert!(is(LicenseCategory::Proprietary, "UNLICENSED"));
+        assert!(is(LicenseCategory::Unknown, "LicenseRef-Custom"));
+        assert!(!is(LicenseCategory::Permissive, "LicenseRef-Custom"));
+        assert!(in_category(LicenseCategory::Unknown, None));
+    }
+
+    #[test]
+    fn explicit_licenses_take_precedence_over_categories() {
+        let policy = policy(&["permissive", "MPL-2.0"], &["copyleft", "ISC"]);
+        assert!(rejections(&policy, Some("MIT")).is_empty());
+        assert!(rejections(&policy, Some("MPL-2.0")).is_empty());
+        assert_eq!(
+            rejections(&policy, Some("ISC")),
+            vec!["ISC is denied by the policy.".to_string()]
+        );
+        assert_eq!(
+            rejections(&policy, Some("GPL-3.0-only")),
+            vec!["GPL-3.0-only is denied by the policy, as `copyleft`.".to_string()]
+        );
+        assert_eq!(
+            rejections(&policy, Some("BUSL-1.1")),
+            vec!["BUSL-1.1 isn't allowed by the policy.".to_string()]
+        );
+        assert_eq!(
+            rejections(&policy, None),
+            vec!["A missing license isn't allowed by the policy.".to_string()]
+        );
+
+        // Without allow list, anything not denied is allowed
+        let policy = self::policy(&[], &["non-free", "unknown"]);
+        assert!(rejections(&policy, Some("GPL-3.0-only")).is_empty());
+        assert_eq!(rejections(&policy, Some("LicenseRef-Custom")).len(), 1);
+        assert_eq!(rejections(&policy, None).len(), 1);
+    }
+
+    #[test]
+    fn expressions_are_allowed_by_any_choice_and_every_part() {
+        let policy = policy(&["permissive"], &[]);
+        assert!(rejections(&policy, Some("MIT OR GPL-3.0-only")).is_empty());
+        assert!(rejections(&policy, Some("MIT/Apache-2.0")).is_empty());
+        assert_eq!(
+            rejections(&policy, Some("MIT AND GPL-3.0-only")),
+            vec!["GPL-3.0-only isn't allowed by the policy.".to_string()]
+        );
+        assert_eq!(
+            rejections(&policy, Some("GPL-3.0-only OR MPL-2.0")).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn exceptions_allow_more_for_their_packages() {
+        let mut policy = policy(&["permissive"], &["strong-copyleft"]);
+        policy.exceptions = vec![
+            PolicyException {
+                name: "webpki-roots".to_string(),
+                version: None,
+                ecosystem: Some(Ecosystem::Cargo),
+                allow: vec!["MPL-2.0".parse().unwrap()],
+                reason: "Cleared by legal review".to_string(),
+            },
+            PolicyException {
+                name: "gpl-tool".to_string(),
+                version: Some("1.0.0".to_string()),
+                ecosystem: None,
+                allow: Vec::new(),
+                reason: "Build tool, never distributed".to_string(),
+            },
+            PolicyException {
+                name: "removed".to_string(),
+                version: None,
+                ecosystem: None,
+                allow: Vec::new(),
+                reason: "Was needed once".to_string(),
+            },
+        ];
+        let dependencies = [
+            dependency("webpki-roots", Some("MPL-2.0")),
+            dependency("gpl-tool", Some("GPL-3.0-only")),
+            dependency("other", Some("MPL-2.0")),
+        ];
+
+        let report = check(&policy, &dependencies);
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].dependency.name, "other");
+        assert_eq!(report.unused_exceptions.len(), 1);
+        assert_eq!(report.unused_exceptions[0].name, "removed");
+    }
+}
@@ -5,8 +5,16 @@
 // Internal imports
 use crate::config::Config;
 use crate::error::LichenError;
+use crate::header::{
+    HeaderFinding, HeaderMatcher, HeaderProblem, diagnose_header, find_foreign_header, find_header,
+    migrate_deprecated_tags, third_party_notices,
+};
+use crate::journal::Journal;
 use crate::models::Authors;
 use crate::models::CommentToken;
+use crate::models::OutputFormat;
+use crate::models::ThirdPartyAction;
+use crate::models::{License, LicenseException, LicenseExpression};
 
 // External imports
 use futures::stream::{self, StreamExt};
@@ -14,15 +22,20 @@ use handlebars::{Handlebars, RenderError};
 use jiff::civil::Date;
 use log::{debug, error, info, trace, warn};
 use regex::Regex;
+use serde::Serialize;
+use similar::TextDiff;
 use walkdir::{self, WalkDir};
 
 // STD
 use std::collections::{BTreeMap, HashSet};
+use std::fmt;
 use std::fs::{self};
+use std::io::{IsTerminal, Write};
+use std::ops::Range;
 use std::path::MAIN_SEPARATOR;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
 // Embed comment tokens at build-time
 const COMMENT_TOKENS_JSON: &str = include_str!(concat!(
@@ -33,6 +46,111 @@ const COMMENT_TOKENS_JSON: &str = include_str!(concat!(
 // Marker for start/end of header, blank unicode joiner.
 pub const HEADER_MARKER: char = '\u{2060}';
 
+/// What the SPDX license list says about a license or an exception.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct LicenseMetadata {
+    /// Full name, ex: "MIT License". Exceptions go without.
+    pub name: Option<&'static str>,
+    pub reference: &'static str,
+    /// Other pages with the text, ex: its upstream.
+    pub see_also: &'static [&'static str],
+    pub osi_approved: bool,
+    pub fsf_libre: bool,
+    pub deprecated: bool,
+    pub exception: bool,
+    /// The expression recommended in place of a deprecated identifier.
+    pub replacement: Option<&'static str>,
+}
+
+/// Gathers what the SPDX license list says about a license, embedded at build-time.
+pub fn license_metadata(license: License) -> LicenseMetadata {
+    LicenseMetadata {
+        name: license.name(),
+        reference: license.reference_url(),
+        see_also: license.see_also(),
+        osi_approved: license.is_osi_approved(),
+        fsf_libre: license.is_fsf_libre(),
+        deprecated: license.is_deprecated(),
+        exception: false,
+        replacement: license.replacement(),
+    }
+}
+
+/// Gathers what the SPDX license list says about an exception, embedded at build-time.
+pub fn exception_metadata(exception: LicenseException) -> LicenseMetadata {
+    LicenseMetadata {
+        reference: exception.reference_url(),
+        see_also: exception.see_also(),
+        deprecated: exception.is_deprecated(),
+        exception: true,
+        replacement: exception.replacement(),
+        ..Default::default()
+    }
+}
+
+/// Describes a deprecated SPDX identifier, naming its recommended replacement when there's one.
+pub fn deprecation_notice(id: &str, replacement: Option<&str>) -> String {
+    match replacement {
+        Some(replacement) => format!(
+            "'{}' is a deprecated SPDX identifier, use '{}' instead",
+            id, replacement
+        ),
+        None => format!(
+            "'{}' is a deprecated SPDX identifier, with no direct replacement",
+            id
+        ),
+    }
+}
+
+/// Warns about the deprecated identifiers of the expression, or refuses them when `deny` is set.
+pub fn check_deprecated(expression: &LicenseExpression, deny: bool) -> Result<(), LichenError> {
+    let license = expression.license;
+    let mut notices = Vec::new();
+    if license.is_deprecated() {
+        notices.push(deprecation_notice(license.spdx_id(), license.replacement()));
+    }
+    if let Some(exception) = expression.exception
+        && exception.is_deprecated()
+    {
+        notices.push(deprecation_notice(
+            exception.spdx_id(),
+            exception.replacement(),
+        ));
+    }
+
+    if notices.is_empty() {
+        Ok(())
+    } else if deny {
+        Err(LichenError::NonCompliant(format!(
+            "{} (denied by deny_deprecated)",
+            notices.join("; ")
+        )))
+    } else {
+        for notice in notices {
+            warn!("{}", notice);
+        }
+        Ok(())
+    }
+}
+
+/// The variables of a license template, with the text used when they're left unset.
+pub fn template_variables(template: &str) -> Vec<(String, Option<String>)> {
+    static VARIABLE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"(?s)\{\{#if (\w+)\}\}.*?(?:\{\{else\}\}(.*?))?\{\{/if\}\}").unwrap()
+    });
+
+    let mut variables: Vec<(String, Option<String>)> = Vec::new();
+    for captures in VARIABLE_REGEX.captures_iter(template) {
+        let name = captures[1].to_string();
+        if variables.iter().any(|(seen, _)| *seen == name) {
+            continue;
+        }
+        let default = captures.get(2).map(|m| m.as_str().trim().to_string());
+        variables.push((name, default));
+    }
+    variables
+}
+
 /// Renders a license template using Handlebars.
 ///
 /// # Arguments
@@ -92,6 +210,64 @@ pub fn render_license(
     }
 }
 
+/// Renders the text of a license expression: the license, followed by its exception if any.
+pub fn render_expression(
+    expression: &LicenseExpression,
+    date: &Date,
+    authors: &Option<Authors>,
+) -> Result<String, RenderError> {
+    let rendered = render_license(expression.license.template_content(), date, authors)?;
+    let Some(exception) = expression.exception else {
+        return Ok(rendered);
+    };
+
+    let exception_text = render_exception(exception)?;
+    Ok(format!(
+        "{}\n\n{}\n",
+        rendered.trim_end(),
+        exception_text.trim()
+    ))
+}
+
+/// Renders the text of a license exception.
+pub fn render_exception(exception: LicenseException) -> Result<String, RenderError> {
+    // The copyright belongs to the license text, the exception's variables keep their defaults
+    let mut handlebars = Handlebars::new();
+    handlebars
+        .register_template_string("exception", exception.template_content())
+        .map_err(RenderError::from)?;
+    handlebars.render("exception", &BTreeMap::<String, String>::new())
+}
+
+/// Whether the file falls under the targets, and isn't excluded.
+/// Relative and absolute paths are both compared from the current directory.
+pub fn covers(targets: &[PathBuf], exclude: Option<&Regex>, path: &Path) -> bool {
+    let path = resolved(path);
+    let targeted = targets
+        .iter()
+        .any(|target| path.starts_with(resolved(target)));
+
+    // Exclusions are written relative to the current directory
+    let current_dir = resolved(Path::new("."));
+    let relative = path.strip_prefix(&current_dir).unwrap_or(&path);
+    let path_string = relative.to_string_lossy().replace(MAIN_SEPARATOR, "/");
+    let excluded = exclude.is_some_and(|re| re.is_match(&path_string));
+    targeted && !excluded
+}
+
+/// The absolute path, symlinks resolved as far as it exists, such as a `--filename` that is only
+/// the name of content read from stdin.
+fn resolved(path: &Path) -> PathBuf {
+    if let Ok(path) = std::fs::canonicalize(path) {
+        return path;
+    }
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    match (absolute.parent(), absolute.file_name()) {
+        (Some(parent), Some(name)) => resolved(parent).join(name),
+        _ => absolute,
+    }
+}
+
 /// Recursively finds all files within the target paths, applying exclusions.
 ///
 /// # Arguments
@@ -114,11 +290,11 @@ pub fn get_valid_files(
     let mut seen_paths = HashSet::new(); // To handle potential overlaps or duplicates
 
     for target in targets {
-        if let Some(re) = exclude_regex.as_ref() {
-            if re.is_match(&target.to_string_lossy()) {
-                debug!("Excluding target file {}", target.display());
-                continue;
-            }
+        if let Some(re) = exclude_regex.as_ref()
+            && re.is_match(&target.to_string_lossy())
+        {
+            debug!("Excluding target file {}", target.display());
+            continue;
         }
         if !target.exists() {
             error!("Target path does not exist: '{}'", target.display());
@@ -138,16 +314,13 @@ pub fn get_valid_files(
             let path_string = path_string.replace(MAIN_SEPARATOR, "/");
             trace!("Considering entry: '{}'", path.display());
             match exclude_regex {
-                Some(regex) => {
-                    // Check if the path string matches the exclusion regex
-                    if regex.is_match(&path_string) {
-                        debug!("Excluding path '{}' due to regex match.", path.display());
-                        false // Exclude this entry and its children if it's a directory
-                    } else {
-                        true // Keep this entry
-                    }
+                // Check if the path string matches the exclusion regex
+                Some(regex) if regex.is_match(&path_string) => {
+                    debug!("Excluding path '{}' due to regex match.", path.display());
+                    false // Exclude this entry and its children if it's a directory
                 }
-                None => true, // No regex, keep everything
+                Some(_) => true, // Keep this entry
+                None => true,    // No regex, keep everything
             }
         });
 
@@ -203,6 +376,139 @@ pub fn get_valid_files(
     Ok(files_to_process)
 }
 
+/// Restricts processing to the files git reports as changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitChanges {
+    /// Files added or modified since a ref, committed or not, plus untracked files.
+    Since(String),
+    /// Files added or modified in the index.
+    Staged,
+    /// Files modified in the work tree since they were last staged.
+    Unstaged,
+}
+
+impl GitChanges {
+    /// Reads the selection from the file processing options, if any.
+    pub fn from_args(changed_since: &Option<String>, staged: Option<bool>) -> Option<Self> {
+        match (changed_since, staged.unwrap_or(false)) {
+            (Some(reference), _) => Some(GitChanges::Since(reference.clone())),
+            (None, true) => Some(GitChanges::Staged),
+            (None, false) => None,
+        }
+    }
+}
+
+/// Runs a git command in `repo`, failing with git's own message.
+pub fn run_git(repo: &Path, args: &[&str]) -> Result<String, LichenError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(LichenError::Msg(format!(
+            "'git {}' failed: {}",
+            args.join(" "),
+            stderr.trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The top directory of the git repository containing `dir`.
+pub fn git_toplevel(dir: &Path) -> Result<PathBuf, LichenError> {
+    Ok(PathBuf::from(
+        run_git(dir, &["rev-parse", "--show-toplevel"])?.trim(),
+    ))
+}
+
+/// Lists the files selected by `changes`, as canonical paths.
+/// Deleted files are left out, there is nothing to process in them.
+///
+/// # Arguments
+///
+/// * `repo`: Any directory inside the git repository.
+/// * `changes`: Which changes to select.
+pub fn git_changed_files(
+    repo: &Path,
+    changes: &GitChanges,
+) -> Result<HashSet<PathBuf>, LichenError> {
+    // Git prints paths relative to the top of the repository
+    let toplevel = git_toplevel(repo)?;
+
+    let mut listings = Vec::new();
+    match changes {
+        GitChanges::Since(reference) => {
+            // Fail early on typos, rather than processing nothing
+            let commit = format!("{}^{{commit}}", reference);
+            run_git(repo, &["rev-parse", "--verify", "--quiet", &commit])
+                .map_err(|_| LichenError::Msg(format!("Unknown git ref '{}'", reference)))?;
+            listings.push(run_git(
+                &toplevel,
+                // `--` keeps a ref named like a file from being read as a path
+                &[
+                    "diff",
+                    "--name-only",
+                    "-z",
+                    "--diff-filter=ACMR",
+                    reference,
+                    "--",
+                ],
+            )?);
+            listings.push(run_git(
+                &toplevel,
+                &["ls-files", "--others", "--exclude-standard", "-z"],
+            )?);
+        }
+        GitChanges::Staged => {
+            listings.push(run_git(
+                &toplevel,
+                &[
+                    "diff",
+                    "--cached",
+                    "--name-only",
+                    "-z",
+                    "--diff-filter=ACMR",
+                    "--",
+                ],
+            )?);
+        }
+        GitChanges::Unstaged => {
+            listings.push(run_git(
+                &toplevel,
+                &["diff", "--name-only", "-z", "--diff-filter=ACMR", "--"],
+            )?);
+        }
+    }
+
+    let files: HashSet<PathBuf> = listings
+        .iter()
+        .flat_map(|listing| listing.split('\0'))
+        .filter(|path| !path.is_empty())
+        .filter_map(|path| fs::canonicalize(toplevel.join(path)).ok())
+        .collect();
+    debug!("Git reports {} changed files ({:?})", files.len(), changes);
+    Ok(files)
+}
+
+/// Keeps the files selected by `changes`, in their original order.
+pub fn filter_git_changes(
+    files: Vec<PathBuf>,
+    changes: &GitChanges,
+) -> Result<Vec<PathBuf>, LichenError> {
+    let toplevel = git_toplevel(Path::new("."))?;
+    let changed = git_changed_files(&toplevel, changes)?;
+    Ok(files
+        .into_iter()
+        .filter(|file| {
+            fs::canonicalize(file)
+                .map(|file| changed.contains(&file))
+                .unwrap_or(false)
+        })
+        .collect())
+}
+
 /// Looks up the appropriate comment tokens for a given file extension.
 /// Reads from a JSON configuration file (`comment-tokens.json`) in the data directory.
 ///
@@ -404,31 +710,88 @@ pub fn get_comment_tokens_for_ext(extension: &str) -> Result<Vec<CommentToken>,
     Ok(vec![CommentToken::Line("#".to_string())])
 }
 
-/// Formats the raw license header text by prepending the appropriate comment syntax.
+/// Matches the leading indentation of a line and any list marker that follows it,
+/// e.g. "-", "*", "1.", "(a)" or "iv)", along with the whitespace after the marker.
+/// Roman numerals must be well-formed and closed by a parenthesis, so that words such as
+/// "did." aren't taken for one.
+static LIST_ITEM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(\s*)((?:[-*+•]|\(?(?:[0-9]+|[a-zA-Z])[.)]|\(?(?:x{0,3}(?:ix|iv|vi{0,3}|i{1,3})|x{1,3})\))\s+)?",
+    )
+    .expect("List item pattern is valid")
+});
+
+/// Reflows text so that no line exceeds `width` characters, where possible.
+///
+/// Every source line is treated as its own paragraph, as the templates store each
+/// paragraph on a single line. Leading indentation is kept, and lines that open
+/// with a list marker (e.g. "1." or "(a)") wrap with a hanging indent aligned to
+/// the text after the marker. Words longer than the width are left unbroken.
 ///
 /// # Arguments
 ///
-/// * `header_content`: The raw license header text.
-/// * `comment_tokens`: A list of available `CommentToken`s for the file type.
-/// * `prefers_block`: Whether to prefer block comments if available.
-/// * `separator`: The character to append after the header block (e.g., SOT marker).
+/// * `text`: The text to reflow.
+/// * `width`: The maximum number of characters per line.
 ///
 /// # Returns
 ///
-/// A `String` containing the formatted header, or `None` if no suitable comment token is found.
-pub fn format_header_with_comments(
-    header_content: &str,
+/// The reflowed text, without a trailing newline.
+pub fn reflow_text(text: &str, width: usize) -> String {
+    let mut reflowed: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        // Whitespace-only lines are paragraph spacers, keep them but drop the noise
+        if line.trim().is_empty() {
+            reflowed.push(String::new());
+            continue;
+        }
+
+        // Short lines need no work
+        if line.chars().count() <= width {
+            reflowed.push(line.trim_end().to_string());
+            continue;
+        }
+
+        // |1| Split the line into its indentation, list marker and body
+        let captures = LIST_ITEM_REGEX
+            .captures(line)
+            .expect("Pattern matches any line, as both groups are optional");
+        let indent = captures.get(1).map_or("", |m| m.as_str());
+        let marker = captures.get(2).map_or("", |m| m.as_str());
+        let body = &line[indent.len() + marker.len()..];
+
+        // Continuation lines align with the text following the marker
+        let hanging_indent = " ".repeat(indent.chars().count() + marker.chars().count());
+
+        // |2| Greedily fill each line with as many words as fit
+        let mut current = format!("{}{}", indent, marker);
+        let mut has_words = false;
+        for word in body.split_whitespace() {
+            let projected = current.chars().count() + word.chars().count() + has_words as usize;
+            if has_words && projected > width {
+                reflowed.push(current);
+                current = hanging_indent.clone();
+                has_words = false;
+            }
+            if has_words {
+                current.push(' ');
+            }
+            current.push_str(word);
+            has_words = true;
+        }
+        reflowed.push(current);
+    }
+
+    reflowed.join("\n")
+}
+
+/// Picks the comment token headers are written with, the preferred kind if the language has one.
+fn choose_comment_token(
     comment_tokens: &[CommentToken],
     prefers_block: bool,
-    separator: char,
-) -> Option<String> {
-    trace!(
-        "Determining comment token from options: '{:?}', prefers_block: {}",
-        comment_tokens, prefers_block
-    );
-
+) -> Option<&CommentToken> {
     // Attempt to find preferred variant
-    let chosen_token = comment_tokens
+    comment_tokens
         .iter()
         .find(|ct| match ct {
             CommentToken::Block { .. } => prefers_block,
@@ -440,9 +803,35 @@ pub fn format_header_with_comments(
                 CommentToken::Block { .. } => !prefers_block, // Find the other type
                 CommentToken::Line(_) => prefers_block,       // Find the other type
             })
-        });
+        })
+}
+
+/// Formats the raw license header text by prepending the appropriate comment syntax.
+///
+/// # Arguments
+///
+/// * `header_content`: The raw license header text.
+/// * `comment_tokens`: A list of available `CommentToken`s for the file type.
+/// * `prefers_block`: Whether to prefer block comments if available.
+/// * `separator`: The character marking the first and last lines of the header (e.g., `HEADER_MARKER`), if any.
+/// * `width`: The column to reflow the header to, including the comment prefix. `None` leaves lines as-is.
+///
+/// # Returns
+///
+/// A `String` containing the formatted header, or `None` if no suitable comment token is found.
+pub fn format_header_with_comments(
+    header_content: &str,
+    comment_tokens: &[CommentToken],
+    prefers_block: bool,
+    separator: Option<char>,
+    width: Option<usize>,
+) -> Option<String> {
+    trace!(
+        "Determining comment token from options: '{:?}', prefers_block: {}",
+        comment_tokens, prefers_block
+    );
 
-    let comment_token = match chosen_token {
+    let comment_token = match choose_comment_token(comment_tokens, prefers_block) {
         Some(token) => token,
         None => {
             warn!("No suitable comment token found in the provided list.");
@@ -455,6 +844,16 @@ pub fn format_header_with_comments(
         comment_token
     );
 
+    // Reflow the content if a width was requested, leaving room for the line comment prefix (token and space)
+    let header_content = match (width, comment_token) {
+        (Some(width), CommentToken::Line(token)) => reflow_text(
+            header_content,
+            width.saturating_sub(token.chars().count() + 1).max(1),
+        ),
+        (Some(width), CommentToken::Block { .. }) => reflow_text(header_content, width.max(1)),
+        (None, _) => header_content.to_string(),
+    };
+
     // String backer for the generated final header
     let mut formatted_header = String::new();
     let newline: char = '\n';
@@ -473,7 +872,7 @@ pub fn format_header_with_comments(
                 formatted_header.push_str(comment_token);
                 if i == 0 {
                     // A separater to denote the first line of the comment
-                    formatted_header.push(separator);
+                    formatted_header.extend(separator);
                 }
                 // Add a space and complete the line only if the line isn't empty, otherwise just the token
                 if !line.is_empty() {
@@ -486,16 +885,16 @@ pub fn format_header_with_comments(
                 }
             }
             // Append separator and final newline after the loop, marking the last line
-            formatted_header.push(separator);
+            formatted_header.extend(separator);
         }
         CommentToken::Block { start, end } => {
             formatted_header.push(newline); // Spacer newline
             formatted_header.push_str(start);
-            formatted_header.push(separator); // The first line of the block
+            formatted_header.extend(separator); // The first line of the block
             formatted_header.push(newline); // Add newline after start token
             formatted_header.push_str(header_content.trim()); // Trim whitespace
             formatted_header.push(newline); // Add newline before end token
-            formatted_header.push(separator); // The last line of the block
+            formatted_header.extend(separator); // The last line of the block
             formatted_header.push_str(end);
             formatted_header.push(newline); // Padding newline
         }
@@ -505,6 +904,265 @@ pub fn format_header_with_comments(
     Some(formatted_header)
 }
 
+/// Byte order mark, kept at the very start of files that have one.
+const BOM: char = '\u{FEFF}';
+
+/// The textual conventions of a file that must survive an edit.
+///
+/// Files are normalized (no BOM, `\n` line endings) before headers are added or removed,
+/// and restored to their own conventions before being written back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextConventions {
+    /// Whether the file starts with a UTF-8 byte order mark.
+    pub bom: bool,
+    /// Whether the file mostly uses `\r\n` line endings.
+    pub crlf: bool,
+    /// Whether the file ends with a line ending.
+    pub trailing_newline: bool,
+    /// Whether each line ends with `\r\n`, when the file mixes line endings. Empty otherwise.
+    pub mixed_endings: Vec<bool>,
+}
+
+impl TextConventions {
+    /// Detects the conventions used by some file content.
+    /// Lines added to files with mixed line endings take on whichever ending is in the majority.
+    pub fn detect(content: &str) -> Self {
+        let newlines = content.matches('\n').count();
+        let crlf_newlines = content.matches("\r\n").count();
+        let mixed_endings = if crlf_newlines == 0 || crlf_newlines == newlines {
+            Vec::new()
+        } else {
+            content
+                .split_inclusive('\n')
+                .filter(|line| line.ends_with('\n'))
+                .map(|line| line.ends_with("\r\n"))
+                .collect()
+        };
+
+        TextConventions {
+            bom: content.starts_with(BOM),
+            crlf: crlf_newlines * 2 > newlines,
+            trailing_newline: content.ends_with('\n'),
+            mixed_endings,
+        }
+    }
+
+    /// Strips the byte order mark and converts line endings to `\n`.
+    pub fn normalize(&self, content: &str) -> String {
+        content
+            .strip_prefix(BOM)
+            .unwrap_or(content)
+            .replace("\r\n", "\n")
+    }
+
+    /// Converts normalized content back to these conventions. `original` is the normalized
+    /// content the conventions were detected on: the lines the edit left in place, before and
+    /// after the changed ones, keep their own line ending.
+    pub fn restore(&self, original: &str, content: &str) -> String {
+        let original = self.with_trailing_newline(original);
+        let content = self.with_trailing_newline(content);
+        let mut restored = String::with_capacity(content.len() + 1);
+
+        // The BOM must stay at byte zero, ahead of any header
+        if self.bom {
+            restored.push(BOM);
+        }
+
+        if self.mixed_endings.is_empty() {
+            match self.crlf {
+                true => restored.push_str(&content.replace('\n', "\r\n")),
+                false => restored.push_str(&content),
+            }
+            return restored;
+        }
+
+        // Lines are matched from both ends, the ones in between were changed
+        let original_lines: Vec<&str> = original.split_inclusive('\n').collect();
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let shortest = original_lines.len().min(lines.len());
+        let kept_before = original_lines
+            .iter()
+            .zip(&lines)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let kept_after = original_lines
+            .iter()
+            .rev()
+            .zip(lines.iter().rev())
+            .take(shortest - kept_before)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        for (index, line) in lines.iter().enumerate() {
+            let original_index = if index < kept_before {
+                Some(index)
+            } else if index >= lines.len() - kept_after {
+                Some(original_lines.len() - (lines.len() - index))
+            } else {
+                None
+            };
+            let crlf = original_index
+                .and_then(|index| self.mixed_endings.get(index).copied())
+                .unwrap_or(self.crlf);
+            match line.strip_suffix('\n') {
+                Some(text) if crlf => {
+                    restored.push_str(text);
+                    restored.push_str("\r\n");
+                }
+                _ => restored.push_str(line),
+            }
+        }
+        restored
+    }
+
+    /// The content, ending with a line ending only if the original file did.
+    fn with_trailing_newline(&self, content: &str) -> String {
+        if !self.trailing_newline {
+            return content.strip_suffix('\n').unwrap_or(content).to_string();
+        }
+        let mut content = content.to_string();
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content
+    }
+}
+
+/// A kind of line that a language requires to stay above any comment, such as a shebang.
+struct PreambleRule {
+    /// Pattern matched against the start of a line.
+    pattern: Regex,
+    /// Closing text for constructs that may span several lines, like XML declarations.
+    terminator: Option<&'static str>,
+    /// Whether consecutive lines may all match, like stacked build constraints.
+    repeats: bool,
+}
+
+impl PreambleRule {
+    fn new(pattern: &str, terminator: Option<&'static str>, repeats: bool) -> Self {
+        PreambleRule {
+            pattern: Regex::new(pattern).expect("Preamble patterns are valid"),
+            terminator,
+            repeats,
+        }
+    }
+}
+
+// `#!` that isn't the start of a Rust inner attribute (`#![...]`)
+const SHEBANG_PATTERN: &str = r"^#!\s*(?:[^\s\[]|$)";
+
+// Only a shebang may precede the header
+static DEFAULT_PREAMBLE: LazyLock<Vec<PreambleRule>> =
+    LazyLock::new(|| vec![PreambleRule::new(SHEBANG_PATTERN, None, false)]);
+
+// `<?xml ...?>` declarations and `<!DOCTYPE ...>` must be the first things in a document
+static MARKUP_PREAMBLE: LazyLock<Vec<PreambleRule>> = LazyLock::new(|| {
+    vec![
+        PreambleRule::new(r"^\s*<\?xml\b", Some("?>"), false),
+        PreambleRule::new(r"(?i)^\s*<!DOCTYPE\b", Some(">"), false),
+    ]
+});
+
+// Comments are only valid inside the `<?php` open tag
+static PHP_PREAMBLE: LazyLock<Vec<PreambleRule>> = LazyLock::new(|| {
+    vec![
+        PreambleRule::new(SHEBANG_PATTERN, None, false),
+        PreambleRule::new(r"^\s*<\?php\b", None, false),
+    ]
+});
+
+// PEP 263 encoding declarations only count on the first or second line
+static PYTHON_PREAMBLE: LazyLock<Vec<PreambleRule>> = LazyLock::new(|| {
+    vec![
+        PreambleRule::new(SHEBANG_PATTERN, None, false),
+        PreambleRule::new(r"^[ \t\f]*#.*?coding[:=][ \t]*[-\w.]+", None, false),
+    ]
+});
+
+// Magic comments are only honoured before any other comment
+static RUBY_PREAMBLE: LazyLock<Vec<PreambleRule>> = LazyLock::new(|| {
+    vec![
+        PreambleRule::new(SHEBANG_PATTERN, None, false),
+        PreambleRule::new(
+            r"^#\s*(?:-\*-\s*)?(?:frozen_string_literal|encoding|coding|warn_indent|shareable_constant_value)\s*:",
+            None,
+            true,
+        ),
+    ]
+});
+
+// Build constraints must be separated from the rest of the file by a blank line
+static GO_PREAMBLE: LazyLock<Vec<PreambleRule>> = LazyLock::new(|| {
+    vec![
+        PreambleRule::new(r"^(?://go:build\b|// \+build\b)", None, true),
+        PreambleRule::new(r"^\s*$", None, true),
+    ]
+});
+
+/// Looks up the preamble rules that apply to files of a given extension.
+fn preamble_rules_for_ext(extension: &str) -> &'static [PreambleRule] {
+    match extension {
+        "xml" | "xsd" | "xsl" | "xslt" | "svg" | "plist" | "xaml" | "rss" | "atom" | "wsdl"
+        | "html" | "htm" | "xhtml" => &MARKUP_PREAMBLE,
+        "php" | "phtml" => &PHP_PREAMBLE,
+        "py" | "pyw" | "pyi" => &PYTHON_PREAMBLE,
+        "rb" => &RUBY_PREAMBLE,
+        "go" => &GO_PREAMBLE,
+        _ => &DEFAULT_PREAMBLE,
+    }
+}
+
+/// Measures the leading lines of a file that must stay above a license header,
+/// like shebangs, XML declarations, PHP open tags, encoding lines and build constraints.
+///
+/// Rules for the file's language are tried in order, each matching at most once unless it repeats.
+///
+/// # Arguments
+///
+/// * `content`: The file content.
+/// * `extension`: The file extension (e.g., "py", "xml"), which selects the rules.
+///
+/// # Returns
+///
+/// The length in bytes of the preamble, always ending on a line boundary.
+pub fn preamble_length(content: &str, extension: &str) -> usize {
+    let rules = preamble_rules_for_ext(extension);
+    let mut consumed = 0;
+    let mut next_rule = 0;
+    let mut lines = content.split_inclusive('\n');
+
+    'lines: while let Some(line) = lines.next() {
+        for (offset, rule) in rules[next_rule..].iter().enumerate() {
+            if !rule.pattern.is_match(line) {
+                continue;
+            }
+            trace!("Line '{}' belongs to the preamble", line.trim_end());
+            consumed += line.len();
+
+            // Consume the rest of constructs spanning multiple lines
+            if let Some(terminator) = rule.terminator
+                && !line.contains(terminator)
+            {
+                for continuation in lines.by_ref() {
+                    consumed += continuation.len();
+                    if continuation.contains(terminator) {
+                        break;
+                    }
+                }
+            }
+
+            // Rules are ordered, so never look back at earlier ones
+            next_rule += offset + usize::from(!rule.repeats);
+            continue 'lines;
+        }
+
+        // First line that isn't part of the preamble
+        break;
+    }
+
+    consumed
+}
+
 pub trait ReplaceBetween {
     fn replace_between<'a>(&'a self, delim: char, replacement: &str) -> Cow<'a, str>;
 }
@@ -574,378 +1232,1234 @@ impl ReplaceBetween for str {
     }
 }
 
-/// Removes license headers previously marked by HEADER_MARKER from files asynchronously.
-/// Modifies files directly. It identifies the header as the content between the start
-/// of the file (or after a shebang) and the HEADER_MARKER.
-///
-/// # Arguments
-///
-/// * `paths`: A slice of `PathBuf` representing the files to modify.
-/// * `max_concurrency`: The maximum number of files to process concurrently.
-///
-/// # Returns
-///
-/// A `Result<(), LichenError>` indicating overall success or the first error encountered.
-pub async fn remove_headers_from_files(
-    paths: &[PathBuf],
-    max_concurrency: std::num::NonZero<usize>,
-) -> Result<(), LichenError> {
-    use tokio::fs; // Use the fs module from tokio
-
-    debug!(
-        "Starting to remove headers from {} files with concurrency {}",
-        paths.len(),
-        max_concurrency
-    );
-
-    let results = stream::iter(paths.to_owned())
-        .map(|path| {
-            async move {
-                trace!("Processing file for header removal: '{}'", path.display());
-
-                // Skip directories
-                if path.is_dir() {
-                    warn!("Skipping directory during removal: '{}'", path.display());
-                    // Return Ok with stats: (removed, skipped, errors)
-                    return Ok((0, 1, 0));
-                }
-
-                // Get the file content as a string
-                let content = match fs::read_to_string(&path).await {
-                    Ok(c) => c,
-                    Err(e) => {
-                        warn!(
-                            "Failed to read '{}' for removal: {}. Skipping.",
-                            path.display(),
-                            e
-                        );
-                        // Return Ok with stats
-                        return Ok((0, 1, 0));
-                    }
-                };
-
-                // Handle any shebangs found
-                let mut shebang_len = 0;
-                if content.starts_with("#!") {
-                    // Find the first newline character
-                    if let Some(pos) = content.find('\n') {
-                        // Length includes the newline
-                        shebang_len = pos + 1;
-                    } else {
-                        // Handle the case where the whole file is just a shebang line (unlikely but possible)
-                        // In this case, no header can exist after it.
-                        trace!(
-                            "File '{}' is only a shebang line. Skipping removal.",
-                            path.display()
-                        );
-                        return Ok((0, 1, 0)); // Skip
-                    }
-                }
-
-                // Find the LAST header marker *after* the shebang (if any)
-                let search_area = &content[shebang_len..];
-                let last_marker_position = search_area.rfind(HEADER_MARKER);
-
-                if let Some(relative_pos) = last_marker_position {
-                    // Calculate position of the marker in the original content
-                    let marker_start_pos = shebang_len + relative_pos;
+/// Why a file was left untouched while processing headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SkipReason {
+    /// Directories can't hold a header.
+    Directory,
+    /// The file couldn't be read.
+    Unreadable,
+    /// The file holds binary content (NUL bytes or a known magic number).
+    Binary,
+    /// The file isn't UTF-8, and couldn't (or wasn't allowed to) be decoded otherwise.
+    UnsupportedEncoding,
+    /// The file is marked as generated code (`@generated`, `DO NOT EDIT`, ...).
+    Generated,
+    /// No comment syntax is known for the file type.
+    NoCommentSyntax,
+    /// There was no header to remove.
+    NoHeader,
+    /// The file carries someone else's copyright notice.
+    ThirdParty,
+    /// The file already has the header.
+    UpToDate,
+}
 
-                    // Calculate the position directly *after* the marker
-                    let content_after_marker_pos = marker_start_pos + 1;
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            SkipReason::Directory => "directory",
+            SkipReason::Unreadable => "unreadable",
+            SkipReason::Binary => "binary",
+            SkipReason::UnsupportedEncoding => "unsupported encoding",
+            SkipReason::Generated => "generated",
+            SkipReason::NoCommentSyntax => "no comment syntax",
+            SkipReason::NoHeader => "no header",
+            SkipReason::ThirdParty => "third-party",
+            SkipReason::UpToDate => "up to date",
+        };
+        write!(f, "{}", reason)
+    }
+}
 
-                    // Saved text buffer
-                    let mut new_text = String::with_capacity(
-                        shebang_len + (content.len() - content_after_marker_pos),
-                    );
+/// The result of processing a single file.
+#[derive(Debug)]
+pub enum FileOutcome {
+    /// The file was modified.
+    Changed,
+    /// The file was left untouched, for the given reason.
+    Skipped(SkipReason),
+    /// Processing the file failed.
+    Failed(LichenError),
+    /// The file carries copyright notices of others. It was either skipped, or annotated
+    /// with the header alongside the notices.
+    ThirdParty {
+        path: PathBuf,
+        notices: Vec<String>,
+        annotated: bool,
+    },
+}
 
-                    // Add shebang if it exists
-                    if shebang_len > 0 {
-                        new_text.push_str(&content[..shebang_len]);
-                    }
+/// The outcome of a single file, as listed in the JSON report.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileReport {
+    pub path: PathBuf,
+    /// `changed`, `skipped` or `failed`. Changes are only planned on dry runs.
+    pub outcome: &'static str,
+    /// Why the file was skipped.
+    pub reason: Option<String>,
+    /// Kind of comment the header was written as, `line` or `block`.
+    pub comment_style: Option<&'static str>,
+    /// What was wrong with the header, for changed files.
+    pub problem: Option<HeaderProblem>,
+    /// SPDX identifier of the license the file was processed for, when known.
+    pub license: Option<String>,
+    pub error: Option<String>,
+    /// Third-party copyright notices found in the file.
+    pub notices: Vec<String>,
+    /// The change as a unified diff, on dry runs.
+    pub diff: Option<String>,
+}
 
-                    // Add the rest of the content, trimming potential leading newline
-                    // left over from the header removal.
-                    let rest_content = &content[content_after_marker_pos..];
-                    new_text.push_str(rest_content.trim_start_matches('\n'));
+/// Running totals of file outcomes, reported at the end of a run.
+#[derive(Debug, Default)]
+pub struct ProcessingSummary {
+    pub changed: usize,
+    pub skipped: BTreeMap<SkipReason, usize>,
+    pub errors: usize,
+    /// The error of every file that failed, in the order they finished.
+    pub failures: Vec<LichenError>,
+    /// Files carrying copyright notices of others, with those notices.
+    pub third_party: Vec<(PathBuf, Vec<String>)>,
+    /// Every file processed, in the order they finished.
+    pub files: Vec<FileReport>,
+    /// The edits of a dry run.
+    pub edits: Vec<FileEdit>,
+}
 
-                    // Write it all back
-                    match fs::write(&path, new_text).await {
-                        Ok(_) => {
-                            info!("Removed header from '{}'", path.display());
-                            Ok((1, 0, 0)) // Removed
-                        }
-                        Err(e) => {
-                            error!(
-                                "Failed to write removed header to '{}': {}",
-                                path.display(),
-                                e
-                            );
-                            Ok((0, 0, 1)) // Error
-                        }
-                    }
-                } else {
-                    // Header marker not found after shebang (or at start if no shebang)
-                    debug!(
-                        "Header marker not found in '{}'. Skipping removal.",
-                        path.display()
-                    );
-                    Ok((0, 1, 0)) // Skip
-                }
+impl ProcessingSummary {
+    /// Adds the outcome of a file to the totals, and lists it for the report.
+    pub fn record_file(
+        &mut self,
+        path: PathBuf,
+        outcome: FileOutcome,
+        comment_style: Option<&'static str>,
+        problem: Option<HeaderProblem>,
+    ) {
+        let mut report = FileReport {
+            // Relative to where Lichen runs, as in diffs
+            path: path
+                .strip_prefix(".")
+                .map(Path::to_path_buf)
+                .unwrap_or(path),
+            outcome: "changed",
+            reason: None,
+            comment_style,
+            problem,
+            license: None,
+            error: None,
+            notices: Vec::new(),
+            diff: None,
+        };
+        match &outcome {
+            FileOutcome::Changed => {}
+            FileOutcome::Skipped(reason) => {
+                report.outcome = "skipped";
+                report.reason = Some(reason.to_string());
             }
-        })
-        .buffer_unordered(max_concurrency.into()) // Process concurrently
-        .collect::<Vec<Result<(usize, usize, usize), LichenError>>>() // Collect results
-        .await;
-
-    // Aggregate results and check for errors
-    let mut total_removed = 0;
-    let mut total_skipped = 0;
-    let mut total_errors = 0;
-    let mut first_error: Option<LichenError> = None;
-
-    for result in results {
-        match result {
-            Ok((removed, skipped, errors)) => {
-                total_removed += removed;
-                total_skipped += skipped;
-                total_errors += errors;
+            FileOutcome::Failed(e) => {
+                report.outcome = "failed";
+                report.error = Some(e.to_string());
             }
-            Err(e) => {
-                // This handles errors from the async block itself returning Err
-                error!("Unexpected error during stream processing: {}", e);
-                total_errors += 1;
-                if first_error.is_none() {
-                    first_error = Some(e);
+            FileOutcome::ThirdParty {
+                notices, annotated, ..
+            } => {
+                report.notices = notices.clone();
+                if !annotated {
+                    report.outcome = "skipped";
+                    report.reason = Some(SkipReason::ThirdParty.to_string());
                 }
             }
         }
+        self.files.push(report);
+        self.record(outcome);
     }
 
-    info!(
-        "Header removal summary: {} removed, {} skipped, {} errors.",
-        total_removed, total_skipped, total_errors
-    );
-
-    if total_errors > 0 {
-        // Return the first specific error encountered, or a generic one
-        Err(first_error.unwrap_or_else(|| {
-            LichenError::Msg(format!(
-                "Encountered {} errors during header removal.",
-                total_errors
-            ))
-        }))
-    } else {
-        Ok(())
+    /// Adds up the outcomes of another run, ex: for another license.
+    pub fn merge(&mut self, other: ProcessingSummary) {
+        self.changed += other.changed;
+        for (reason, count) in other.skipped {
+            *self.skipped.entry(reason).or_default() += count;
+        }
+        self.errors += other.errors;
+        self.failures.extend(other.failures);
+        self.third_party.extend(other.third_party);
+        self.files.extend(other.files);
+        self.edits.extend(other.edits);
     }
-}
 
-/// Applies the license header to a list of files asynchronously.
-/// Modifies files directly.
+    /// Marks the files as processed for the given license.
+    pub fn set_license(&mut self, spdx_id: &str) {
+        for file in &mut self.files {
+            file.license = Some(spdx_id.to_string());
+        }
+        for edit in &mut self.edits {
+            edit.license = Some(spdx_id.to_string());
+        }
+    }
+
+    /// Adds the outcome of a single file to the totals.
+    pub fn record(&mut self, outcome: FileOutcome) {
+        match outcome {
+            FileOutcome::Changed => self.changed += 1,
+            FileOutcome::Skipped(reason) => *self.skipped.entry(reason).or_default() += 1,
+            FileOutcome::Failed(e) => {
+                self.errors += 1;
+                self.failures.push(e);
+            }
+            FileOutcome::ThirdParty {
+                path,
+                notices,
+                annotated,
+            } => {
+                if annotated {
+                    self.changed += 1;
+                } else {
+                    *self.skipped.entry(SkipReason::ThirdParty).or_default() += 1;
+                }
+                self.third_party.push((path, notices));
+            }
+        }
+    }
+
+    /// Lists the third-party files and their notices, for review after the run.
+    pub fn third_party_report(&self) -> Option<String> {
+        if self.third_party.is_empty() {
+            return None;
+        }
+
+        let mut entries: Vec<_> = self.third_party.iter().collect();
+        entries.sort();
+
+        let mut report = format!("Third-party files ({}):", entries.len());
+        for (path, notices) in entries {
+            report.push_str(&format!("\n  {}: {}", path.display(), notices.join("; ")));
+        }
+        Some(report)
+    }
+
+    /// Total number of skipped files, whatever the reason.
+    pub fn skipped_total(&self) -> usize {
+        self.skipped.values().sum()
+    }
+
+    /// Converts the totals into the overall result of the run, the edits of a dry run.
+    pub fn into_result(self, action: &str) -> Result<Vec<FileEdit>, LichenError> {
+        if self.errors > 0 {
+            // Every file that failed is listed, or a generic error is returned
+            Err(LichenError::combine(self.failures).unwrap_or_else(|| {
+                LichenError::Msg(format!(
+                    "Encountered {} errors during header {}.",
+                    self.errors, action
+                ))
+            }))
+        } else {
+            Ok(self.edits)
+        }
+    }
+
+    /// Prints every file and its outcome as JSON, for scripts and CI dashboards.
+    pub fn write_json_report(&self, dry_run: bool) -> Result<(), LichenError> {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &self.json_report(dry_run)?)?;
+        writeln!(stdout)?;
+        Ok(())
+    }
+
+    /// Every file and its outcome, as printed by `write_json_report`.
+    fn json_report(&self, dry_run: bool) -> Result<serde_json::Value, LichenError> {
+        #[derive(Serialize)]
+        struct Totals {
+            changed: usize,
+            skipped: usize,
+            errors: usize,
+        }
+        #[derive(Serialize)]
+        struct Report<'a> {
+            dry_run: bool,
+            summary: Totals,
+            files: &'a [FileReport],
+        }
+
+        // The diffs of a dry run are only rendered for the report. A file may be edited for
+        // several licenses, each edit goes with the entry of its license.
+        let mut files: Vec<FileReport> = self.files.clone();
+        for file in &mut files {
+            file.diff = self
+                .edits
+                .iter()
+                .find(|edit| {
+                    edit.path.strip_prefix(".").unwrap_or(&edit.path) == file.path
+                        && edit.license == file.license
+                })
+                .map(|edit| edit.unified_diff(false));
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let report = Report {
+            dry_run,
+            summary: Totals {
+                changed: self.changed,
+                skipped: self.skipped_total(),
+                errors: self.errors,
+            },
+            files: &files,
+        };
+        Ok(serde_json::to_value(&report)?)
+    }
+}
+
+impl fmt::Display for ProcessingSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} skipped", self.skipped_total())?;
+
+        // Break down the skips by reason, ex: 3 skipped (2 binary, 1 generated)
+        if !self.skipped.is_empty() {
+            let reasons = self
+                .skipped
+                .iter()
+                .map(|(reason, count)| format!("{} {}", count, reason))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, " ({})", reasons)?;
+        }
+
+        write!(f, ", {} errors", self.errors)
+    }
+}
+
+/// How a file's bytes map to text, so that edits can be written back the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Utf8,
+    /// UTF-16, little endian, with a byte order mark.
+    Utf16Le,
+    /// UTF-16, big endian, with a byte order mark.
+    Utf16Be,
+    /// ISO-8859-1, where every byte is a character.
+    Latin1,
+}
+
+impl TextEncoding {
+    /// Encodes text back into bytes of this encoding.
+    pub fn encode(&self, text: &str) -> Result<Vec<u8>, LichenError> {
+        match self {
+            TextEncoding::Utf8 => Ok(text.as_bytes().to_vec()),
+            TextEncoding::Utf16Le => Ok([0xFF, 0xFE]
+                .into_iter()
+                .chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+                .collect()),
+            TextEncoding::Utf16Be => Ok([0xFE, 0xFF]
+                .into_iter()
+                .chain(text.encode_utf16().flat_map(u16::to_be_bytes))
+                .collect()),
+            TextEncoding::Latin1 => text
+                .chars()
+                .map(|c| {
+                    u8::try_from(u32::from(c)).map_err(|_| {
+                        LichenError::Msg(format!(
+                            "Character {:?} can't be represented in ISO-8859-1",
+                            c
+                        ))
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+// Leading bytes of common binary formats that may not contain a NUL early on
+const BINARY_MAGIC_NUMBERS: &[&[u8]] = &[
+    b"\x89PNG",             // PNG
+    b"\xFF\xD8\xFF",        // JPEG
+    b"GIF87a",              // GIF
+    b"GIF89a",              // GIF
+    b"%PDF-",               // PDF
+    b"PK\x03\x04",          // Zip, jar, docx...
+    b"\x7FELF",             // ELF executables
+    b"\xCA\xFE\xBA\xBE",    // Java class, Mach-O fat binaries
+    b"\xCF\xFA\xED\xFE",    // Mach-O
+    b"\x1F\x8B",            // Gzip
+    b"BZh",                 // Bzip2
+    b"\xFD7zXZ\x00",        // XZ
+    b"7z\xBC\xAF\x27\x1C",  // 7-Zip
+    b"\x00asm",             // WebAssembly
+    b"SQLite format 3\x00", // SQLite
+];
+
+// Git's heuristic: a NUL byte within the first 8000 bytes means binary
+const BINARY_SNIFF_LENGTH: usize = 8000;
+
+/// Decodes the raw bytes of a file into text, detecting binary content and encodings.
+///
+/// UTF-16 (with a byte order mark) and ISO-8859-1 are only decoded when `legacy_encodings` is set,
+/// otherwise those files are skipped as unsupported.
+///
+/// # Arguments
+///
+/// * `bytes`: The raw file content.
+/// * `legacy_encodings`: Whether to decode UTF-16 and ISO-8859-1 files.
+///
+/// # Returns
+///
+/// The decoded text and its encoding, or the reason the file should be skipped.
+pub fn decode_file_content(
+    bytes: &[u8],
+    legacy_encodings: bool,
+) -> Result<(String, TextEncoding), SkipReason> {
+    // |1| UTF-16 is full of NUL bytes, so look for its byte order mark before sniffing for binary
+    let utf16 = match bytes {
+        [0xFF, 0xFE, rest @ ..] => Some((TextEncoding::Utf16Le, rest)),
+        [0xFE, 0xFF, rest @ ..] => Some((TextEncoding::Utf16Be, rest)),
+        _ => None,
+    };
+    if let Some((encoding, rest)) = utf16 {
+        if !legacy_encodings || rest.len() % 2 != 0 {
+            return Err(SkipReason::UnsupportedEncoding);
+        }
+        let units = rest.chunks_exact(2).map(|pair| match encoding {
+            TextEncoding::Utf16Le => u16::from_le_bytes([pair[0], pair[1]]),
+            _ => u16::from_be_bytes([pair[0], pair[1]]),
+        });
+        return char::decode_utf16(units)
+            .collect::<Result<String, _>>()
+            .map(|text| (text, encoding))
+            .map_err(|_| SkipReason::UnsupportedEncoding);
+    }
+
+    // |2| Binary content
+    let sniffed = &bytes[..bytes.len().min(BINARY_SNIFF_LENGTH)];
+    if sniffed.contains(&0)
+        || BINARY_MAGIC_NUMBERS
+            .iter()
+            .any(|magic| bytes.starts_with(magic))
+    {
+        return Err(SkipReason::Binary);
+    }
+
+    // |3| UTF-8, falling back on ISO-8859-1, which can decode any byte
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Ok((text.to_string(), TextEncoding::Utf8)),
+        Err(_) if legacy_encodings => Ok((
+            bytes.iter().map(|&b| char::from(b)).collect(),
+            TextEncoding::Latin1,
+        )),
+        Err(_) => Err(SkipReason::UnsupportedEncoding),
+    }
+}
+
+/// Markers tools leave at the top of files they generate.
+static GENERATED_MARKER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"@generated|DO NOT EDIT|(?i:<auto-generated|auto-?generated by|automatically generated)",
+    )
+    .expect("Generated marker pattern is valid")
+});
+
+// Generated markers are expected near the top of the file
+const GENERATED_MARKER_SEARCH_LINES: usize = 20;
+
+/// Checks whether content is marked as generated code, like `@generated` or
+/// Go's `// Code generated ... DO NOT EDIT.`, within its first lines.
+pub fn is_generated(content: &str) -> bool {
+    content
+        .lines()
+        .take(GENERATED_MARKER_SEARCH_LINES)
+        .any(|line| GENERATED_MARKER_REGEX.is_match(line))
+}
+
+/// Reads a file and decodes it to text, or explains why it should be skipped.
+pub async fn read_text_file(
+    path: &PathBuf,
+    legacy_encodings: bool,
+) -> Result<(String, TextEncoding), SkipReason> {
+    let bytes = match tokio::fs::read(path).await {
+        Ok(b) => b,
+        Err(e) => {
+            warn!("Failed to read '{}': {}. Skipping.", path.display(), e);
+            return Err(SkipReason::Unreadable);
+        }
+    };
+
+    decode_file_content(&bytes, legacy_encodings).inspect_err(|reason| {
+        debug!("Skipping '{}', as it is {}", path.display(), reason);
+    })
+}
+
+/// Computes the removal of the header from a single file, see `remove_headers_from_files`.
+/// Files left untouched give the reason instead.
+async fn plan_header_removal(
+    path: PathBuf,
+    matcher: Arc<HeaderMatcher>,
+    owners: Arc<Vec<String>>,
+    legacy_encodings: bool,
+) -> Result<FileEdit, FileOutcome> {
+    trace!("Processing file for header removal: '{}'", path.display());
+
+    // Skip directories
+    if path.is_dir() {
+        warn!("Skipping directory during removal: '{}'", path.display());
+        return Err(FileOutcome::Skipped(SkipReason::Directory));
+    }
+
+    // Get the file content as a string
+    let (raw_content, encoding) = match read_text_file(&path, legacy_encodings).await {
+        Ok(decoded) => decoded,
+        Err(reason) => return Err(FileOutcome::Skipped(reason)),
+    };
+
+    // Work on normalized text, restoring line endings and BOM on write
+    let conventions = TextConventions::detect(&raw_content);
+    let content = conventions.normalize(&raw_content);
+
+    // Handle any preamble (shebangs, XML declarations, etc.) found
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let preamble_len = preamble_length(&content, ext);
+
+    // Without comment syntax, there can't be a marker-less header, only marked ones
+    let comment_tokens = get_comment_tokens_for_ext(ext).unwrap_or_default();
+
+    // Find the header *after* the preamble (if any), by its marker or its content
+    let Some(header) = find_header(&content, preamble_len, &comment_tokens, &matcher, &owners)
+    else {
+        debug!("No header found in '{}'. Skipping removal.", path.display());
+        return Err(FileOutcome::Skipped(SkipReason::NoHeader));
+    };
+
+    // Saved text buffer
+    let mut new_text = String::with_capacity(content.len() - header.len());
+
+    // Keep the preamble in place
+    new_text.push_str(&content[..header.start]);
+
+    // Add the rest of the content, trimming potential leading newline
+    // left over from the header removal.
+    let rest_content = &content[header.end..];
+    new_text.push_str(rest_content.trim_start_matches('\n'));
+
+    Ok(FileEdit {
+        path,
+        original: content,
+        updated: new_text,
+        conventions,
+        encoding,
+        notices: Vec::new(),
+        finding: None,
+        comment_style: None,
+        license: None,
+    })
+}
+
+/// Removes license headers from files asynchronously.
+/// Modifies files directly. The header is looked for after the file's preamble (see `preamble_length`),
+/// either by the lines holding the HEADER_MARKER, or as a leading comment rendered from a known template.
 ///
 /// # Arguments
 ///
-/// * `header_content`: The license header text (raw, without comment markers).
 /// * `paths`: A slice of `PathBuf` representing the files to modify.
+/// * `matcher`: The license templates that headers without a marker are recognized by.
+/// * `owners`: Names of the authors a header without a marker must name to be removed.
 /// * `max_concurrency`: The maximum number of files to process concurrently.
-/// * `prefers_block`: Whether to prefer block comments.
-/// * `multiple`: Whether to overwrite existing headers or append to
+/// * `legacy_encodings`: Whether to decode UTF-16 and ISO-8859-1 files instead of skipping them.
+/// * `dry_run`: Whether to leave files untouched, returning the edits instead.
+/// * `journal`: Where the original content of modified files is kept, for `lic undo`.
 ///
 /// # Returns
 ///
-/// A `Result<(), FileProcessingError>` indicating overall success or the first error encountered.
-pub async fn apply_headers_to_files(
-    header_content: &str,
+/// The outcome of every file, and the edits of a dry run. See `ProcessingSummary::into_result`.
+pub async fn remove_headers_from_files(
     paths: &[PathBuf],
+    matcher: HeaderMatcher,
+    owners: Vec<String>,
     max_concurrency: std::num::NonZero<usize>,
-    prefers_block: bool,
-    multiple: bool,
-) -> Result<(), LichenError> {
-    use tokio::fs; // Use the fs module from tokio
-
+    legacy_encodings: bool,
+    dry_run: bool,
+    journal: Arc<Journal>,
+) -> ProcessingSummary {
     debug!(
-        "Starting to apply headers to {} files with concurrency {}",
+        "Starting to remove headers from {} files with concurrency {}",
         paths.len(),
         max_concurrency
     );
 
-    // Share header content safely across tasks
-    let header_content_arc = Arc::new(header_content.to_string());
+    // Share the matcher, and the patterns it compiles, across tasks
+    let matcher = Arc::new(matcher);
+    let owners = Arc::new(owners);
 
-    let results = stream::iter(paths.to_owned())
+    let outcomes = stream::iter(paths.to_owned())
         .map(|path| {
-            let header_content = header_content_arc.clone(); // Clone Arc, not the String
+            let matcher = matcher.clone();
+            let owners = owners.clone();
+            let journal = journal.clone();
             async move {
-                trace!("Processing file: '{}'", path.display());
-
-                // |1| Directories cannot be written to skip.
-                if path.is_dir() {
-                    warn!("Skipping directory: '{}'", path.display());
-                    // Return Ok with stats: (applied, skipped, errors)
-                    return Ok((0, 1, 0));
+                match plan_header_removal(path.clone(), matcher, owners, legacy_encodings).await {
+                    Ok(edit) => {
+                        let (outcome, edit) = finish_edit(edit, dry_run, journal).await;
+                        (path, outcome, None, None, edit)
+                    }
+                    Err(outcome) => (path, outcome, None, None, None),
                 }
+            }
+        })
+        .buffer_unordered(max_concurrency.into()) // Process concurrently
+        .collect::<Vec<_>>() // Collect results
+        .await;
 
-                // |2| Read file content as string.
-                let content = match fs::read_to_string(&path).await {
-                    Ok(c) => c,
-                    Err(e) => {
-                        warn!("Failed to read '{}': {}. Skipping.", path.display(), e);
-                        // Return Ok with stats
-                        return Ok((0, 1, 0));
-                    }
-                };
+    // Aggregate results and check for errors
+    let mut summary = ProcessingSummary::default();
+    for (path, outcome, comment_style, problem, edit) in outcomes {
+        summary.record_file(path, outcome, comment_style, problem);
+        summary.edits.extend(edit);
+    }
 
-                // |4| Find comment token for extension
-                let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
-                let comment_tokens = match get_comment_tokens_for_ext(ext) {
-                    Ok(tokens) if !tokens.is_empty() => tokens,
-                    Ok(_) => {
-                        // No tokens found for this extension
-                        warn!(
-                            "No comment tokens defined for extension '{}' (file '{}'). Skipping.",
-                            ext,
-                            path.display()
-                        );
-                        return Ok((0, 1, 0)); // Skip
-                    }
-                    Err(e) => {
-                        // Error occurred trying to get tokens (e.g., JSON read error)
-                        error!(
-                            "Failed to get comment tokens for '{}': {}. Skipping.",
-                            path.display(),
-                            e
-                        );
-                        return Ok((0, 0, 1)); // Error
-                    }
-                };
+    info!(
+        "Header removal summary: {} {}, {}.",
+        summary.changed,
+        if dry_run {
+            "would be removed"
+        } else {
+            "removed"
+        },
+        summary
+    );
 
-                // |5| Header formatting
-                let formatted_header = match format_header_with_comments(
-                    &header_content,
-                    &comment_tokens,
-                    prefers_block,
-                    HEADER_MARKER,
-                ) {
-                    Some(h) => h,
-                    None => {
-                        // This case should be rare if get_comment_tokens_for_ext returned non-empty
-                        error!(
-                            "Failed to format header for '{}' (no suitable token found). Skipping.",
-                            path.display()
-                        );
-                        return Ok((0, 1, 0)); // Skip
-                    }
-                };
+    summary
+}
 
-                // If multiple, do not overwrite any headers
-                if !multiple {
-                    // |6| If header is already present, simply replace it.
-                    if content.contains(HEADER_MARKER) {
-                        debug!(
-                            "Already contains header marker, replacing '{}'",
-                            path.display()
-                        );
-                        let content = content.replace_between(HEADER_MARKER, &formatted_header);
-                        fs::write(&path, content.to_string()).await?;
-                        // Return Ok with stats
-                        return Ok((1, 0, 0));
-                    }
-                }
+/// Options that apply to every file a header is applied to, see `apply_headers_to_files`.
+#[derive(Debug, Clone, Copy)]
+pub struct ApplyOptions {
+    /// Whether to prefer block comments.
+    pub prefers_block: bool,
+    /// Whether to add to existing headers instead of replacing them.
+    pub multiple: bool,
+    /// The column to reflow headers to, if any.
+    pub width: Option<usize>,
+    /// Whether to decode UTF-16 and ISO-8859-1 files instead of skipping them.
+    pub legacy_encodings: bool,
+    /// Whether to mark headers with the invisible HEADER_MARKER, to find them faster.
+    pub marker: bool,
+    /// Whether to replace copyright and license comments written by other tools or by hand.
+    pub replace_foreign: bool,
+    /// Whether to leave files untouched, returning the edits instead.
+    pub dry_run: bool,
+    /// What to do with files carrying copyright notices of others.
+    pub third_party: ThirdPartyAction,
+}
 
-                // |6| Shebang handling
-                let (shebang, rest) = if content.starts_with("#!") {
-                    // Find the first newline character
-                    if let Some(pos) = content.find('\n') {
-                        // Split at the position *after* the newline
-                        let (sb, rem) = content.split_at(pos + 1);
-                        (Some(sb), rem)
-                    } else {
-                        // The whole file is just a shebang line (unlikely but possible)
-                        (Some(content.as_str()), "")
-                    }
+/// A change to a file's text, computed before anything is written.
+#[derive(Debug)]
+pub struct FileEdit {
+    pub path: PathBuf,
+    /// The normalized text of the file, see `TextConventions`.
+    pub original: String,
+    /// The normalized text the file is changed to.
+    pub updated: String,
+    conventions: TextConventions,
+    encoding: TextEncoding,
+    /// Third-party copyright notices that the change was made alongside, see `third_party_notices`.
+    notices: Vec<String>,
+    /// Kind of comment the header was written as (`line` or `block`), for applied headers.
+    comment_style: Option<&'static str>,
+    /// What was wrong with the header that the change fixes, for applied headers.
+    pub finding: Option<HeaderFinding>,
+    /// SPDX identifier of the license the change was made for, see `ProcessingSummary::set_license`.
+    pub license: Option<String>,
+}
+
+impl FileEdit {
+    /// The outcome of the edit, once written.
+    fn outcome(&self) -> FileOutcome {
+        if self.notices.is_empty() {
+            FileOutcome::Changed
+        } else {
+            // Our header was added alongside the notices of others
+            FileOutcome::ThirdParty {
+                path: self.path.clone(),
+                notices: self.notices.clone(),
+                annotated: true,
+            }
+        }
+    }
+
+    /// The updated content, with the file's own line endings, BOM and encoding.
+    pub fn encoded(&self) -> Result<Vec<u8>, LichenError> {
+        self.encoding
+            .encode(&self.conventions.restore(&self.original, &self.updated))
+    }
+
+    /// Renders the edit as a unified diff of the file, as it is on disk and as it would be written.
+    /// Lines are colored with ANSI escapes when asked to.
+    pub fn unified_diff(&self, color: bool) -> String {
+        let original = self.conventions.restore(&self.original, &self.original);
+        let updated = self.conventions.restore(&self.original, &self.updated);
+
+        // Paths relative to the current directory are displayed as such, ex: `a/src/main.rs`
+        let path = self.path.strip_prefix(".").unwrap_or(&self.path).display();
+        let diff = TextDiff::from_lines(&original, &updated)
+            .unified_diff()
+            .context_radius(3)
+            .header(&format!("a/{}", path), &format!("b/{}", path))
+            .to_string();
+
+        if !color {
+            return diff;
+        }
+
+        diff.lines()
+            .map(|line| {
+                let style = if line.starts_with("+++") || line.starts_with("---") {
+                    "\x1b[1m"
+                } else if line.starts_with('+') {
+                    "\x1b[32m"
+                } else if line.starts_with('-') {
+                    "\x1b[31m"
+                } else if line.starts_with("@@") {
+                    "\x1b[36m"
                 } else {
-                    (None, content.as_str())
+                    return format!("{}\n", line);
                 };
+                format!("{}{}\x1b[0m\n", style, line)
+            })
+            .collect()
+    }
+}
 
-                // |7| Create buffer for text and write to it
-                let mut new_text = String::with_capacity(
-                    shebang.map_or(0, |s| s.len()) +
-                    formatted_header.len() +
-                    1 + // Potential extra newline
-                    rest.len(),
-                );
-
-                if let Some(sb) = shebang {
-                    new_text.push_str(sb);
-                    // Ensure the rest starts on a new line if shebang didn't end with one
-                    if !sb.ends_with('\n') {
-                        new_text.push('\n');
-                    }
-                }
-                new_text.push_str(&formatted_header);
-                // Ensure there's a newline between header and original content
-                if !formatted_header.ends_with('\n') {
-                    new_text.push('\n');
-                }
-                // Avoid double newline if original content already starts with one
-                new_text.push_str(rest.trim_start_matches('\n'));
-
-                // |8| Write the modified content back to the file
-                match fs::write(&path, new_text).await {
-                    Ok(_) => {
-                        debug!("Applied header to '{}'", path.display());
-                        Ok((1, 0, 0)) // Applied
-                    }
-                    Err(e) => {
-                        error!("Failed to write header to '{}': {}", path.display(), e);
-                        Ok((0, 0, 1)) // Error
+/// Replaces the content of a file in a single step, so an interrupted write never leaves it truncated.
+/// The content goes to a temporary file in the same directory, which is then renamed over the
+/// original, keeping its permissions. Symlinks are followed, and their target replaced.
+pub fn write_atomically(path: &Path, content: &[u8]) -> Result<(), LichenError> {
+    let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let permissions = fs::metadata(&path).ok().map(|meta| meta.permissions());
+
+    let mut temp = tempfile::NamedTempFile::new_in(dir)?;
+    temp.write_all(content)?;
+    temp.as_file().sync_all()?;
+    if let Some(permissions) = permissions {
+        fs::set_permissions(temp.path(), permissions)?;
+    }
+    temp.persist(&path)
+        .map_err(|e| LichenError::from(e.error))?;
+
+    Ok(())
+}
+
+/// Writes an edit to its file, recording the original in the journal first,
+/// or hands the edit back on a dry run.
+async fn finish_edit(
+    edit: FileEdit,
+    dry_run: bool,
+    journal: Arc<Journal>,
+) -> (FileOutcome, Option<FileEdit>) {
+    let outcome = edit.outcome();
+    if dry_run {
+        return (outcome, Some(edit));
+    }
+
+    // Write it all back, in the file's own encoding
+    let write_result = match edit.encoded() {
+        Ok(bytes) => {
+            let path = edit.path.clone();
+            tokio::task::spawn_blocking(move || {
+                journal
+                    .record(&path)
+                    .map_err(|e| e.for_file("recording the original of", &path))?;
+                write_atomically(&path, &bytes).map_err(|e| e.for_file("writing", &path))
+            })
+            .await
+            .unwrap_or_else(|e| Err(LichenError::Msg(format!("Write task failed: {}", e))))
+        }
+        Err(e) => Err(e.for_file("encoding", &edit.path)),
+    };
+    match write_result {
+        Ok(_) => {
+            debug!("Updated '{}'", edit.path.display());
+            (outcome, None)
+        }
+        Err(e) => {
+            error!("{}", e);
+            (FileOutcome::Failed(e), None)
+        }
+    }
+}
+
+/// Outputs the edits of a dry run as unified diffs, ordered by path. They are written to the
+/// patch file if one is given, or else printed, colored when printing to a terminal.
+pub fn write_diffs(mut edits: Vec<FileEdit>, patch: Option<&Path>) -> Result<(), LichenError> {
+    edits.sort_by(|a, b| a.path.cmp(&b.path));
+
+    match patch {
+        Some(patch) => {
+            let diffs: String = edits.iter().map(|edit| edit.unified_diff(false)).collect();
+            fs::write(patch, diffs)?;
+            info!(
+                "Wrote {} file changes to '{}'",
+                edits.len(),
+                patch.display()
+            );
+        }
+        None => {
+            let stdout = std::io::stdout();
+            let color = stdout.is_terminal() && std::env::var_os("NO_COLOR").is_none();
+            let mut stdout = stdout.lock();
+            for edit in &edits {
+                stdout.write_all(edit.unified_diff(color).as_bytes())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// SARIF logs hold header problems, which only `check` reports.
+pub fn sarif_unsupported() -> LichenError {
+    LichenError::Usage("SARIF output is only available for `lic check`.".to_string())
+}
+
+/// Outputs the result of a run, the JSON report or the diffs of a dry run, then fails listing its errors.
+pub fn finish_run(
+    summary: ProcessingSummary,
+    action: &str,
+    format: OutputFormat,
+    dry_run: bool,
+    patch: Option<&Path>,
+) -> Result<(), LichenError> {
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => summary.write_json_report(dry_run)?,
+        OutputFormat::Sarif => return Err(sarif_unsupported()),
+    }
+    let edits = summary.into_result(action)?;
+    // The report holds the diffs, printing them too would break the JSON
+    if format == OutputFormat::Text || patch.is_some() {
+        write_diffs(edits, patch)?;
+    }
+    Ok(())
+}
+
+/// Computes the application of the header to a single file, see `apply_headers_to_files`.
+/// Files left untouched give the reason instead.
+async fn plan_header_application(
+    path: PathBuf,
+    header_content: Arc<String>,
+    matcher: Arc<HeaderMatcher>,
+    owners: Arc<Vec<String>>,
+    options: ApplyOptions,
+) -> Result<FileEdit, FileOutcome> {
+    trace!("Processing file: '{}'", path.display());
+
+    // |1| Directories cannot be written to skip.
+    if path.is_dir() {
+        warn!("Skipping directory: '{}'", path.display());
+        return Err(FileOutcome::Skipped(SkipReason::Directory));
+    }
+
+    // |2| Read file content as text, skipping binary and undecodable files.
+    let (raw_content, encoding) = match read_text_file(&path, options.legacy_encodings).await {
+        Ok(decoded) => decoded,
+        Err(reason) => return Err(FileOutcome::Skipped(reason)),
+    };
+
+    plan_header_for_text(
+        path,
+        &raw_content,
+        encoding,
+        &header_content,
+        &matcher,
+        &owners,
+        options,
+    )
+}
+
+/// Computes the application of the header to decoded text, named `path` for its comment syntax.
+fn plan_header_for_text(
+    path: PathBuf,
+    raw_content: &str,
+    encoding: TextEncoding,
+    header_content: &str,
+    matcher: &HeaderMatcher,
+    owners: &[String],
+    options: ApplyOptions,
+) -> Result<FileEdit, FileOutcome> {
+    let ApplyOptions {
+        prefers_block,
+        multiple,
+        width,
+        marker,
+        replace_foreign,
+        third_party,
+        ..
+    } = options;
+
+    // |3| Normalize line endings and BOM, the file's own conventions are restored on write
+    let conventions = TextConventions::detect(raw_content);
+    let content = conventions.normalize(raw_content);
+
+    // Generated files will be overwritten by their generator, leave them be
+    if is_generated(&content) {
+        debug!("'{}' is marked as generated. Skipping.", path.display());
+        return Err(FileOutcome::Skipped(SkipReason::Generated));
+    }
+
+    // |4| Find comment token for extension
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let comment_tokens = match get_comment_tokens_for_ext(ext) {
+        Ok(tokens) if !tokens.is_empty() => tokens,
+        Ok(_) => {
+            // No tokens found for this extension
+            warn!(
+                "No comment tokens defined for extension '{}' (file '{}'). Skipping.",
+                ext,
+                path.display()
+            );
+            return Err(FileOutcome::Skipped(SkipReason::NoCommentSyntax));
+        }
+        Err(e) => {
+            // Error occurred trying to get tokens (e.g., JSON read error)
+            error!(
+                "Failed to get comment tokens for '{}': {}. Skipping.",
+                path.display(),
+                e
+            );
+            return Err(FileOutcome::Failed(
+                e.for_file("finding the comment syntax of", &path),
+            ));
+        }
+    };
+
+    // |5| Header formatting, ISO-8859-1 can't encode the marker so those files go without
+    let separator = (marker && encoding != TextEncoding::Latin1).then_some(HEADER_MARKER);
+    let formatted_header = match format_header_with_comments(
+        header_content,
+        &comment_tokens,
+        prefers_block,
+        separator,
+        width,
+    ) {
+        Some(h) => h,
+        None => {
+            // This case should be rare if get_comment_tokens_for_ext returned non-empty
+            error!(
+                "Failed to format header for '{}' (no suitable token found). Skipping.",
+                path.display()
+            );
+            return Err(FileOutcome::Skipped(SkipReason::NoCommentSyntax));
+        }
+    };
+
+    // |6| Preamble handling (shebangs, XML declarations, encoding lines, etc.)
+    let preamble_len = preamble_length(&content, ext);
+
+    // A header that lost its marker is still found, once recognized by its content and copyright
+    let own_header = find_header(&content, preamble_len, &comment_tokens, matcher, owners);
+
+    // |7| Copyright notices of others are never overwritten. Our own header is left out, as
+    // without its marker it is only ours when its copyright line names the owners
+    let notices = match &own_header {
+        Some(h) => third_party_notices(
+            &format!("{}{}", &content[..h.start], &content[h.end..]),
+            owners,
+        ),
+        None => third_party_notices(&content, owners),
+    };
+    if !notices.is_empty() {
+        debug!(
+            "'{}' carries third-party notices: {:?}",
+            path.display(),
+            notices
+        );
+        if third_party == ThirdPartyAction::Skip {
+            return Err(FileOutcome::ThirdParty {
+                path,
+                notices,
+                annotated: false,
+            });
+        }
+    }
+
+    // The header being replaced, if any
+    let existing: Option<Range<usize>>;
+    let new_text = if !multiple && content.contains(HEADER_MARKER) {
+        // |8| If header is already present, simply replace it.
+        // If multiple, do not overwrite any headers
+        debug!(
+            "Already contains header marker, replacing '{}'",
+            path.display()
+        );
+        existing = own_header;
+        content
+            .replace_between(HEADER_MARKER, &formatted_header)
+            .into_owned()
+    } else {
+        // Headers from other tools are only replaced when asked to, and when they are not someone else's
+        let header = if multiple {
+            None
+        } else if let Some(header) = own_header {
+            debug!("Replacing unmarked header in '{}'", path.display());
+            Some(header)
+        } else if replace_foreign && notices.is_empty() {
+            // Foreign headers may have been written from any license's text
+            let catalog = HeaderMatcher::catalog();
+            find_foreign_header(&content, preamble_len, &comment_tokens, catalog).inspect(|_| {
+                info!("Replacing foreign license header in '{}'", path.display());
+            })
+        } else {
+            None
+        };
+        let (start, end) = header
+            .as_ref()
+            .map_or((preamble_len, preamble_len), |h| (h.start, h.end));
+        existing = header;
+        let (preamble, rest) = (&content[..start], &content[end..]);
+
+        // |9| Create buffer for text and write to it
+        let mut new_text = String::with_capacity(
+            preamble.len() +
+            formatted_header.len() +
+            1 + // Potential extra newline
+            rest.len(),
+        );
+
+        if !preamble.is_empty() {
+            new_text.push_str(preamble);
+            // Ensure the rest starts on a new line if the preamble didn't end with one
+            if !preamble.ends_with('\n') {
+                new_text.push('\n');
+            }
+        }
+        new_text.push_str(&formatted_header);
+        // Ensure there's a newline between header and original content
+        if !formatted_header.ends_with('\n') {
+            new_text.push('\n');
+        }
+        // Avoid double newline if original content already starts with one
+        new_text.push_str(rest.trim_start_matches('\n'));
+        new_text
+    };
+
+    // Deprecated ids of SPDX tags are migrated, unless the tags are someone else's
+    let header_changed = new_text != content;
+    let mut deprecated_tag = None;
+    let new_text = match migrate_deprecated_tags(&new_text, formatted_header.lines().count())
+        .filter(|_| notices.is_empty())
+    {
+        Some((migrated, tag)) => {
+            deprecated_tag = Some(tag);
+            migrated
+        }
+        None => new_text,
+    };
+
+    // Rewriting the same header would only churn the file and the journal
+    if new_text == content {
+        trace!("Header already up to date in '{}'", path.display());
+        return Err(if notices.is_empty() {
+            FileOutcome::Skipped(SkipReason::UpToDate)
+        } else {
+            FileOutcome::ThirdParty {
+                path,
+                notices,
+                annotated: false,
+            }
+        });
+    }
+
+    let finding = match (existing, deprecated_tag) {
+        // The header is fine, only its tags are outdated
+        (_, Some(tag)) if !header_changed => {
+            HeaderFinding::new(HeaderProblem::DeprecatedLicense, &content, tag)
+        }
+        (Some(range), _) => HeaderFinding::new(
+            diagnose_header(&content[range.clone()], &formatted_header, &comment_tokens),
+            &content,
+            range,
+        ),
+        (None, _) => {
+            HeaderFinding::new(HeaderProblem::Missing, &content, preamble_len..preamble_len)
+        }
+    };
+
+    Ok(FileEdit {
+        path,
+        original: content,
+        updated: new_text,
+        conventions,
+        encoding,
+        notices,
+        finding: Some(finding),
+        license: None,
+        comment_style: choose_comment_token(&comment_tokens, prefers_block).map(
+            |token| match token {
+                CommentToken::Line(_) => "line",
+                CommentToken::Block { .. } => "block",
+            },
+        ),
+    })
+}
+
+/// Applies the license header to a list of files asynchronously.
+/// Modifies files directly, unless on a dry run. Binary, undecodable and generated files are skipped.
+///
+/// # Arguments
+///
+/// * `header_content`: The license header text (raw, without comment markers).
+/// * `matcher`: The license templates that existing headers without a marker are recognized by.
+/// * `owners`: Names of the project's authors. Copyright notices naming anyone else are third-party.
+/// * `paths`: A slice of `PathBuf` representing the files to modify.
+/// * `max_concurrency`: The maximum number of files to process concurrently.
+/// * `options`: How headers are formatted and existing ones treated, see `ApplyOptions`.
+/// * `journal`: Where the original content of modified files is kept, for `lic undo`.
+///
+/// # Returns
+///
+/// The outcome of every file, and the edits of a dry run. See `ProcessingSummary::into_result`.
+pub async fn apply_headers_to_files(
+    header_content: &str,
+    matcher: HeaderMatcher,
+    owners: &[String],
+    paths: &[PathBuf],
+    max_concurrency: std::num::NonZero<usize>,
+    options: ApplyOptions,
+    journal: Arc<Journal>,
+) -> ProcessingSummary {
+    debug!(
+        "Starting to apply headers to {} files with concurrency {}",
+        paths.len(),
+        max_concurrency
+    );
+
+    // Share header content safely across tasks
+    let header_content_arc = Arc::new(header_content.to_string());
+    let matcher = Arc::new(matcher);
+    let owners = Arc::new(owners.to_vec());
+
+    let outcomes = stream::iter(paths.to_owned())
+        .map(|path| {
+            let plan = plan_header_application(
+                path.clone(),
+                header_content_arc.clone(), // Clone Arc, not the String
+                matcher.clone(),
+                owners.clone(),
+                options,
+            );
+            let journal = journal.clone();
+            async move {
+                match plan.await {
+                    Ok(edit) => {
+                        let comment_style = edit.comment_style;
+                        let problem = edit.finding.map(|finding| finding.problem);
+                        let (outcome, edit) = finish_edit(edit, options.dry_run, journal).await;
+                        (path, outcome, comment_style, problem, edit)
                     }
+                    Err(outcome) => (path, outcome, None, None, None),
                 }
             }
         })
         .buffer_unordered(max_concurrency.into()) // Process concurrently
-        .collect::<Vec<Result<(usize, usize, usize), LichenError>>>() // Collect results
+        .collect::<Vec<_>>() // Collect results
         .await;
 
     // Aggregate results and check for errors
-    let mut total_applied = 0;
-    let mut total_skipped = 0;
-    let mut total_errors = 0;
-    let mut first_error: Option<LichenError> = None;
-
-    for result in results {
-        match result {
-            Ok((applied, skipped, errors)) => {
-                total_applied += applied;
-                total_skipped += skipped;
-                total_errors += errors;
-            }
-            Err(e) => {
-                // This happens if the async block itself returns Err, which doesn't happen
-                // But keep it here for robustness.
-                error!("Unexpected error during stream processing: {}", e);
-                total_errors += 1;
-                if first_error.is_none() {
-                    first_error = Some(e);
-                }
-            }
-        }
+    let mut summary = ProcessingSummary::default();
+    for (path, outcome, comment_style, problem, edit) in outcomes {
+        summary.record_file(path, outcome, comment_style, problem);
+        summary.edits.extend(edit);
     }
 
     info!(
-        "Header application summary: {} applied, {} skipped, {} errors.",
-        total_applied, total_skipped, total_errors
+        "Header application summary: {} {}, {}.",
+        summary.changed,
+        if options.dry_run {
+            "would be applied"
+        } else {
+            "applied"
+        },
+        summary
     );
+    if let Some(report) = summary.third_party_report() {
+        warn!("{}", report);
+    }
 
-    if total_errors > 0 {
-        // Return the first specific error encountered, or a generic one
-        Err(first_error.unwrap_or_else(|| {
-            LichenError::Msg(format!(
-                "Encountered {} errors during header application.",
-                total_errors
-            ))
-        }))
-    } else {
-        Ok(())
+    summary
+}
+
+/// Adds the files to the git index, so headers applied to staged files are committed with them.
+/// Files in `unstaged`, that had unstaged changes before the run, are left out: staging them
+/// would commit the hunks left unstaged too, so their header is left for the user to stage.
+pub fn stage_files(
+    repo: &Path,
+    paths: &[PathBuf],
+    unstaged: &HashSet<PathBuf>,
+) -> Result<(), LichenError> {
+    let (partial, paths): (Vec<&PathBuf>, Vec<&PathBuf>) =
+        paths.iter().partition(|path| unstaged.contains(*path));
+    for path in partial {
+        warn!(
+            "'{}' has unstaged changes, its header wasn't staged. Stage it with `git add -p`.",
+            path.display()
+        );
+    }
+    if paths.is_empty() {
+        return Ok(());
+    }
+    let paths: Vec<String> = paths
+        .iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    let mut args = vec!["add", "--"];
+    args.extend(paths.iter().map(String::as_str));
+    run_git(repo, &args)?;
+    info!("Staged {} modified files.", paths.len());
+    Ok(())
+}
+
+/// Applies the license header to content that isn't on disk (ex: read from stdin).
+/// The path only picks the comment syntax and is never read. Content that would be skipped
+/// (binary, generated, third-party, ...) is handed back unchanged.
+///
+/// # Arguments
+///
+/// * `header_content`: The license header text (raw, without comment markers).
+/// * `matcher`: The license templates that existing headers without a marker are recognized by.
+/// * `owners`: Names of the project's authors. Copyright notices naming anyone else are third-party.
+/// * `path`: The name of the file the content belongs to.
+/// * `content`: The raw content.
+/// * `options`: The header options, `dry_run` is ignored.
+pub fn apply_header_to_content(
+    header_content: &str,
+    matcher: &HeaderMatcher,
+    owners: &[String],
+    path: &Path,
+    content: Vec<u8>,
+    options: ApplyOptions,
+) -> Result<Vec<u8>, LichenError> {
+    let (text, encoding) = match decode_file_content(&content, options.legacy_encodings) {
+        Ok(decoded) => decoded,
+        Err(reason) => {
+            debug!("Passing '{}' through, as it is {}", path.display(), reason);
+            return Ok(content);
+        }
+    };
+
+    match plan_header_for_text(
+        path.to_path_buf(),
+        &text,
+        encoding,
+        header_content,
+        matcher,
+        owners,
+        options,
+    ) {
+        Ok(edit) => edit.encoded(),
+        Err(FileOutcome::Failed(e)) => Err(e),
+        Err(outcome) => {
+            debug!("Passing '{}' through: {:?}", path.display(), outcome);
+            Ok(content)
+        }
     }
 }
 
@@ -1023,6 +2537,7 @@ pub fn build_exclude_regex(
         ".*\\.(md|rst|txt)".to_string(),
         "Cargo.toml".to_string(),
         ".*\\.github/.*".to_string(),
+        "\\.lichen/.*".to_string(),
     ];
 
     // Add the giginore patterns if not disabled.
@@ -1044,20 +2559,20 @@ pub fn build_exclude_regex(
         }
 
         // The per-license exclude
-        if let Some(i) = index {
-            if let Some(licenses) = cfg.licenses.as_ref() {
-                if let Some(lic) = licenses.get(i) {
-                    // If the license exists, check if it has an exclude pattern
-                    if let Some(exc) = lic.exclude.as_ref() {
-                        pats.push(exc.to_string());
-                    }
-                } else {
-                    // If the index is provided but out of bounds for the licenses vec,
-                    return Err(LichenError::InvalidIndex(i));
+        if let Some(i) = index
+            && let Some(licenses) = cfg.licenses.as_ref()
+        {
+            if let Some(lic) = licenses.get(i) {
+                // If the license exists, check if it has an exclude pattern
+                if let Some(exc) = lic.exclude.as_ref() {
+                    pats.push(exc.to_string());
                 }
+            } else {
+                // If the index is provided but out of bounds for the licenses vec,
+                return Err(LichenError::InvalidIndex(i));
             }
-            // If cfg.licenses was None, do nothing.
         }
+        // If cfg.licenses was None, do nothing.
         // If no cfg, do nothing
     }
 
@@ -1092,6 +2607,34 @@ mod tests {
     use jiff::civil::Date;
     use std::borrow::Cow;
 
+    #[test]
+    fn license_metadata_and_variables_come_with_the_catalog() {
+        let mit = license_metadata(License::MIT);
+        assert_eq!(mit.name, Some("MIT License"));
+        assert!(mit.osi_approved && mit.fsf_libre && !mit.deprecated && !mit.exception);
+        assert_eq!(mit.reference, "https://spdx.org/licenses/MIT.html");
+        // Deprecated templates keep a prefix in their filename, not in their id
+        let gpl = license_metadata(License::DeprecatedGPLThreeDotZero);
+        assert!(gpl.deprecated);
+        assert_eq!(gpl.replacement, Some("GPL-3.0-only"));
+        assert_eq!(License::DeprecatedGPLThreeDotZero.spdx_id(), "GPL-3.0");
+        assert!(exception_metadata(LicenseException::LLVMException).exception);
+
+        let variables = template_variables(License::MIT.template_content());
+        assert_eq!(variables[0].0, "copyright");
+        assert_eq!(
+            variables[0].1.as_deref(),
+            Some("Copyright (c) <year> <copyright holders>")
+        );
+        assert_eq!(
+            variables
+                .iter()
+                .filter(|(name, _)| name == "copyright")
+                .count(),
+            1
+        );
+    }
+
     #[test]
     fn render_license_injects_copyright_and_authors() {
         let template = "/* {{copyright}} */";
@@ -1112,6 +2655,21 @@ mod tests {
         assert!(out.contains("B [b@e]"));
     }
 
+    #[test]
+    fn render_expression_appends_the_exception() {
+        let year = Date::new(2025, 1, 1).unwrap();
+        let plain: LicenseExpression = License::ApacheTwoDotZero.into();
+        let with: LicenseExpression = "Apache-2.0 WITH LLVM-exception".parse().unwrap();
+
+        let license = render_expression(&plain, &year, &None).unwrap();
+        let out = render_expression(&with, &year, &None).unwrap();
+        assert!(out.starts_with(license.trim_end()));
+        assert!(
+            out.trim_end()
+                .ends_with("only with respect to the Combined Software.")
+        );
+    }
+
     #[test]
     fn replace_between_replaces_delimited_region() {
         let text = "line1\n* old\n* old2\nline4";
@@ -1138,7 +2696,21 @@ mod tests {
 mod tests_utils {
     // Separate module to avoid conflicts
     use super::*;
-    use crate::models::CommentToken;
+    use crate::journal::JOURNAL_DIR;
+    use crate::models::{CommentToken, License};
+
+    fn default_apply_options() -> ApplyOptions {
+        ApplyOptions {
+            prefers_block: false,
+            multiple: false,
+            width: None,
+            legacy_encodings: false,
+            marker: true,
+            replace_foreign: false,
+            dry_run: false,
+            third_party: ThirdPartyAction::Skip,
+        }
+    }
 
     #[test]
     fn get_comment_tokens_known_extensions() {
@@ -1183,7 +2755,8 @@ mod tests_utils {
     fn format_header_line_comment() {
         let header = "Line 1\nLine 2";
         let tokens = vec![CommentToken::Line("//".to_string())];
-        let formatted = format_header_with_comments(header, &tokens, false, HEADER_MARKER).unwrap(); // Prefer line doesn't matter here
+        let formatted =
+            format_header_with_comments(header, &tokens, false, Some(HEADER_MARKER), None).unwrap(); // Prefer line doesn't matter here
         let expected = format!(
             "//{marker} Line 1\n// Line 2{marker}", // Marker on first and last line content
             marker = HEADER_MARKER
@@ -1199,7 +2772,8 @@ mod tests_utils {
             start: "/*".to_string(),
             end: "*/".to_string(),
         }];
-        let formatted = format_header_with_comments(header, &tokens, true, HEADER_MARKER).unwrap(); // Prefer block doesn't matter here
+        let formatted =
+            format_header_with_comments(header, &tokens, true, Some(HEADER_MARKER), None).unwrap(); // Prefer block doesn't matter here
         let expected = format!(
             "\n/*{marker}\nLine 1\nLine 2\n{marker}*/\n", // Newlines added by function
             marker = HEADER_MARKER
@@ -1207,4 +2781,926 @@ mod tests_utils {
         assert_eq!(formatted, expected);
         assert!(formatted.contains(HEADER_MARKER));
     }
+
+    #[test]
+    fn format_header_line_comment_wraps_to_width() {
+        let header = "one two three four five six";
+        let tokens = vec![CommentToken::Line("//".to_string())];
+        let formatted =
+            format_header_with_comments(header, &tokens, false, Some(HEADER_MARKER), Some(16))
+                .unwrap();
+        let expected = format!(
+            "//{marker} one two three\n// four five six{marker}",
+            marker = HEADER_MARKER
+        );
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn preamble_length_keeps_shebang_but_not_rust_inner_attributes() {
+        let script = "#!/bin/sh\necho hi\n";
+        assert_eq!(preamble_length(script, "sh"), "#!/bin/sh\n".len());
+
+        let rust = "#![allow(dead_code)]\nfn main() {}\n";
+        assert_eq!(preamble_length(rust, "rs"), 0);
+    }
+
+    #[test]
+    fn preamble_length_keeps_xml_declaration_and_doctype() {
+        let xml = "<?xml version=\"1.0\"\n  encoding=\"UTF-8\"?>\n<!DOCTYPE note>\n<note/>\n";
+        let expected = xml.find("<note/>").unwrap();
+        assert_eq!(preamble_length(xml, "xml"), expected);
+
+        let html = "<!doctype html>\n<html></html>\n";
+        assert_eq!(preamble_length(html, "html"), "<!doctype html>\n".len());
+    }
+
+    #[test]
+    fn preamble_length_keeps_php_tag_and_python_encoding() {
+        let php = "<?php\necho 'hi';\n";
+        assert_eq!(preamble_length(php, "php"), "<?php\n".len());
+
+        let python = "#!/usr/bin/env python\n# -*- coding: latin-1 -*-\nprint(1)\n";
+        let expected = python.find("print").unwrap();
+        assert_eq!(preamble_length(python, "py"), expected);
+
+        // An encoding line is only honoured near the top, not after code
+        let late = "print(1)\n# -*- coding: latin-1 -*-\n";
+        assert_eq!(preamble_length(late, "py"), 0);
+    }
+
+    #[test]
+    fn preamble_length_keeps_go_build_constraints_and_spacer() {
+        let go = "//go:build linux\n// +build linux\n\npackage main\n";
+        let expected = go.find("package").unwrap();
+        assert_eq!(preamble_length(go, "go"), expected);
+    }
+
+    #[tokio::test]
+    async fn apply_and_remove_headers_respect_preamble() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("index.php");
+        let original = "<?php\necho 'hi';\n";
+        fs::write(&path, original).unwrap();
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+
+        apply_headers_to_files(
+            "Header",
+            HeaderMatcher::default(),
+            &[],
+            &paths,
+            concurrency,
+            default_apply_options(),
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("application")
+        .unwrap();
+        let applied = fs::read_to_string(&path).unwrap();
+        assert!(applied.starts_with("<?php\n//"));
+        assert!(applied.contains("Header"));
+
+        remove_headers_from_files(
+            &paths,
+            HeaderMatcher::default(),
+            Vec::new(),
+            concurrency,
+            false,
+            false,
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("removal")
+        .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+    }
+
+    #[test]
+    fn text_conventions_round_trip() {
+        let original = "\u{FEFF}line one\r\nline two";
+        let conventions = TextConventions::detect(original);
+        assert_eq!(
+            conventions,
+            TextConventions {
+                bom: true,
+                crlf: true,
+                trailing_newline: false,
+                mixed_endings: Vec::new(),
+            }
+        );
+
+        let normalized = conventions.normalize(original);
+        assert_eq!(normalized, "line one\nline two");
+        assert_eq!(
+            conventions.restore(&normalized, &format!("{}\n", normalized)),
+            original
+        );
+    }
+
+    #[test]
+    fn text_conventions_keep_mixed_line_endings() {
+        let original = "a\r\nb\nc\r\nd\r\n";
+        let conventions = TextConventions::detect(original);
+        assert_eq!(conventions.mixed_endings, vec![true, false, true, true]);
+        let normalized = conventions.normalize(original);
+        assert_eq!(conventions.restore(&normalized, &normalized), original);
+
+        // Only the added lines take the majority ending
+        let updated = format!("// Header\n\n{}", normalized);
+        assert_eq!(
+            conventions.restore(&normalized, &updated),
+            "// Header\r\n\r\na\r\nb\nc\r\nd\r\n"
+        );
+        let removed = normalized.strip_prefix("a\n").unwrap();
+        assert_eq!(conventions.restore(&normalized, removed), "b\nc\r\nd\r\n");
+    }
+
+    #[tokio::test]
+    async fn apply_and_remove_headers_preserve_crlf_and_bom() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.c");
+        let original = "\u{FEFF}int main() {\r\n  return 0;\r\n}";
+        fs::write(&path, original).unwrap();
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+
+        apply_headers_to_files(
+            "Line 1\nLine 2",
+            HeaderMatcher::default(),
+            &[],
+            &paths,
+            concurrency,
+            default_apply_options(),
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("application")
+        .unwrap();
+        let applied = fs::read_to_string(&path).unwrap();
+        assert!(applied.starts_with("\u{FEFF}//"));
+        assert_eq!(
+            applied.matches('\n').count(),
+            applied.matches("\r\n").count()
+        );
+        assert!(applied.ends_with('}'));
+
+        remove_headers_from_files(
+            &paths,
+            HeaderMatcher::default(),
+            Vec::new(),
+            concurrency,
+            false,
+            false,
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("removal")
+        .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+    }
+
+    #[tokio::test]
+    async fn apply_and_remove_headers_without_marker() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        let original = "// Regular comment\nfn main() {}\n";
+        fs::write(&path, original).unwrap();
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+        let date = Date::new(2024, 1, 1).unwrap();
+        let header = &render_license(License::MIT.template_content(), &date, &None).unwrap();
+        let options = ApplyOptions {
+            marker: false,
+            ..default_apply_options()
+        };
+
+        // Re-applying recognizes the unmarked header instead of stacking another one
+        for width in [None, Some(60)] {
+            let options = ApplyOptions { width, ..options };
+            let matcher = HeaderMatcher::new([License::MIT]);
+            apply_headers_to_files(
+                header,
+                matcher,
+                &[],
+                &paths,
+                concurrency,
+                options,
+                Arc::new(Journal::new(dir.path())),
+            )
+            .await
+            .into_result("application")
+            .unwrap();
+        }
+        let applied = fs::read_to_string(&path).unwrap();
+        assert!(!applied.contains(HEADER_MARKER));
+        assert_eq!(applied.matches("Permission is hereby granted").count(), 1);
+        assert!(applied.ends_with("// Regular comment\nfn main() {}\n"));
+
+        let matcher = HeaderMatcher::new([License::MIT]);
+        remove_headers_from_files(
+            &paths,
+            matcher,
+            Vec::new(),
+            concurrency,
+            false,
+            false,
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("removal")
+        .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+    }
+
+    #[tokio::test]
+    async fn apply_headers_replaces_foreign_header_only_when_asked() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.go");
+        let original = "// Copyright 2020 Google LLC\n// SPDX-License-Identifier: Apache-2.0\n\npackage main\n";
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+        let owners = vec!["Google LLC".to_string()];
+
+        fs::write(&path, original).unwrap();
+        apply_headers_to_files(
+            "Header",
+            HeaderMatcher::default(),
+            &owners,
+            &paths,
+            concurrency,
+            default_apply_options(),
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("application")
+        .unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("Google LLC"));
+
+        // A dry run leaves the file untouched
+        fs::write(&path, original).unwrap();
+        let options = ApplyOptions {
+            replace_foreign: true,
+            ..default_apply_options()
+        };
+        let dry_run = ApplyOptions {
+            dry_run: true,
+            ..options
+        };
+        for options in [dry_run, options] {
+            apply_headers_to_files(
+                "Header",
+                HeaderMatcher::default(),
+                &owners,
+                &paths,
+                concurrency,
+                options,
+                Arc::new(Journal::new(dir.path())),
+            )
+            .await
+            .into_result("application")
+            .unwrap();
+            if options.dry_run {
+                assert_eq!(fs::read_to_string(&path).unwrap(), original);
+            }
+        }
+        let applied = fs::read_to_string(&path).unwrap();
+        assert!(!applied.contains("Google LLC"));
+        assert!(applied.contains("Header"));
+        assert!(applied.ends_with("\npackage main\n"));
+    }
+
+    #[tokio::test]
+    async fn apply_headers_protects_third_party_notices() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("vendored.c");
+        let original = "/* Copyright (c) 2019 Jane Doe */\nint x;\n";
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+        let owners = vec!["Project Team".to_string()];
+        let options = ApplyOptions {
+            replace_foreign: true,
+            ..default_apply_options()
+        };
+
+        // Skipped by default, even when replacing foreign headers
+        fs::write(&path, original).unwrap();
+        apply_headers_to_files(
+            "Header",
+            HeaderMatcher::default(),
+            &owners,
+            &paths,
+            concurrency,
+            options,
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("application")
+        .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+
+        // Annotated, our header goes alongside the notice
+        let options = ApplyOptions {
+            third_party: ThirdPartyAction::Annotate,
+            ..options
+        };
+        for _ in 0..2 {
+            apply_headers_to_files(
+                "Header",
+                HeaderMatcher::default(),
+                &owners,
+                &paths,
+                concurrency,
+                options,
+                Arc::new(Journal::new(dir.path())),
+            )
+            .await
+            .into_result("application")
+            .unwrap();
+        }
+        let applied = fs::read_to_string(&path).unwrap();
+        assert!(applied.starts_with("//"));
+        assert_eq!(applied.matches("Header").count(), 1);
+        assert!(applied.ends_with("/* Copyright (c) 2019 Jane Doe */\nint x;\n"));
+    }
+
+    #[tokio::test]
+    async fn apply_headers_keeps_notices_of_unmarked_headers_of_others() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.rs");
+        let original = "// Copyright (c) 2019 Jane Doe
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the \"Software\"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+fn main() {}
+";
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+        let owners = vec!["Me".to_string()];
+        let header = render_license(
+            License::MIT.template_content(),
+            &Date::new(2026, 1, 1).unwrap(),
+            &Some(crate::models::parse_to_author("Me").unwrap()),
+        )
+        .unwrap();
+        fs::write(&path, original).unwrap();
+
+        let summary = apply_headers_to_files(
+            &header,
+            HeaderMatcher::new([License::MIT]),
+            &owners,
+            &paths,
+            concurrency,
+            ApplyOptions {
+                dry_run: true,
+                ..default_apply_options()
+            },
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await;
+        assert!(summary.edits.is_empty());
+        assert_eq!(
+            summary.third_party_report().unwrap(),
+            format!(
+                "Third-party files (1):\n  {}: Copyright (c) 2019 Jane Doe",
+                path.display()
+            )
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+    }
+
+    #[tokio::test]
+    async fn apply_headers_leaves_others_license_headers_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("vendored.rs");
+        let rendered = render_license(
+            License::ApacheTwoDotZero.template_content(),
+            &Date::new(2019, 1, 1).unwrap(),
+            &Some(crate::models::parse_to_author("Jane Doe").unwrap()),
+        )
+        .unwrap();
+        let header = format_header_with_comments(
+            &rendered,
+            &[CommentToken::Line("//".to_string())],
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+        let original = format!("{}\nfn main() {{}}\n", header);
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+        let owners = vec!["Me".to_string()];
+
+        // A configured license's text under someone else's copyright isn't ours to replace,
+        // even when asked to replace foreign headers
+        for replace_foreign in [false, true] {
+            fs::write(&path, &original).unwrap();
+            let options = ApplyOptions {
+                replace_foreign,
+                third_party: ThirdPartyAction::Annotate,
+                ..default_apply_options()
+            };
+            apply_headers_to_files(
+                "Header",
+                HeaderMatcher::new([License::MIT, License::ApacheTwoDotZero]),
+                &owners,
+                &paths,
+                concurrency,
+                options,
+                Arc::new(Journal::new(dir.path())),
+            )
+            .await
+            .into_result("application")
+            .unwrap();
+            let applied = fs::read_to_string(&path).unwrap();
+            assert!(applied.contains("Header"));
+            assert!(applied.ends_with(&original), "{}", applied);
+        }
+    }
+
+    #[test]
+    fn processing_summary_lists_third_party_files() {
+        let mut summary = ProcessingSummary::default();
+        assert!(summary.third_party_report().is_none());
+
+        summary.record(FileOutcome::ThirdParty {
+            path: PathBuf::from("vendor/b.c"),
+            notices: vec!["Copyright 2019 B".to_string()],
+            annotated: true,
+        });
+        summary.record(FileOutcome::ThirdParty {
+            path: PathBuf::from("vendor/a.c"),
+            notices: vec!["Copyright 2019 A".to_string(), "© 2020 C".to_string()],
+            annotated: false,
+        });
+        assert_eq!(summary.changed, 1);
+        assert_eq!(summary.to_string(), "1 skipped (1 third-party), 0 errors");
+        assert_eq!(
+            summary.third_party_report().unwrap(),
+            "Third-party files (2):\n  vendor/a.c: Copyright 2019 A; © 2020 C\n  vendor/b.c: Copyright 2019 B"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn writes_keep_permissions_and_are_journaled() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("run.sh");
+        let original = "#!/bin/sh\necho hi\n";
+        fs::write(&path, original).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+
+        apply_headers_to_files(
+            "Header",
+            HeaderMatcher::default(),
+            &[],
+            &paths,
+            concurrency,
+            default_apply_options(),
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("application")
+        .unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        assert!(dir.path().join(JOURNAL_DIR).exists());
+
+        crate::journal::undo(dir.path()).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+    }
+
+    #[tokio::test]
+    async fn dry_runs_return_edits_as_unified_diffs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.rs");
+        let original = "fn main() {}\r\n";
+        fs::write(&path, original).unwrap();
+        let paths = vec![path.clone()];
+        let concurrency = std::num::NonZero::new(1).unwrap();
+        let options = ApplyOptions {
+            dry_run: true,
+            marker: false,
+            ..default_apply_options()
+        };
+
+        let edits = apply_headers_to_files(
+            "Header",
+            HeaderMatcher::default(),
+            &[],
+            &paths,
+            concurrency,
+            options,
+            Arc::new(Journal::new(dir.path())),
+        )
+        .await
+        .into_result("application")
+        .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+        assert_eq!(edits.len(), 1);
+
+        // The diff is of the file as written, CRLF line endings included
+        let diff = edits[0].unified_diff(false);
+        let display = path.display();
+        assert_eq!(
+            diff,
+            format!(
+                "--- a/{display}\n+++ b/{display}\n@@ -1 +1,2 @@\n+// Header\r\n fn main() {{}}\r\n"
+            )
+        );
+        assert!(edits[0].unified_diff(true).contains("\x1b[32m+// Header"));
+
+        // Writing the patch file instead of printing
+        let patch = dir.path().join("changes.patch");
+        write_diffs(edits, Some(&patch)).unwrap();
+        assert_eq!(fs::read_to_string(&patch).unwrap(), diff);
+    }
+
+    #[test]
+    fn decode_file_content_detects_binary() {
+        assert_eq!(
+            decode_file_content(b"abc\x00def", false),
+            Err(SkipReason::Binary)
+        );
+        assert_eq!(
+            decode_file_content(b"\x89PNG\r\n\x1a\n", true),
+            Err(SkipReason::Binary)
+        );
+    }
+
+    #[test]
+    fn decode_file_content_handles_legacy_encodings_when_enabled() {
+        let latin1 = b"caf\xe9\n";
+        assert_eq!(
+            decode_file_content(latin1, false),
+            Err(SkipReason::UnsupportedEncoding)
+        );
+        let (text, encoding) = decode_file_content(latin1, true).unwrap();
+        assert_eq!(text, "café\n");
+        assert_eq!(encoding.encode(&text).unwrap(), latin1);
+
+        let utf16 = [0xFF, 0xFE, b'h', 0, b'i', 0];
+        assert_eq!(
+            decode_file_content(&utf16, false),
+            Err(SkipReason::UnsupportedEncoding)
+        );
+        let (text, encoding) = decode_file_content(&utf16, true).unwrap();
+        assert_eq!((text.as_str(), encoding), ("hi", TextEncoding::Utf16Le));
+        assert_eq!(encoding.encode(&text).unwrap(), utf16);
+    }
+
+    #[test]
+    fn is_generated_detects_common_markers() {
+        assert!(is_generated(
+            "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage pb\n"
+        ));
+        assert!(is_generated("# @generated by some-tool\n"));
+        assert!(!is_generated("fn main() {}\n"));
+    }
+
+    #[test]
+    fn processing_summary_breaks_down_skips() {
+        let mut summary = ProcessingSummary::default();
+        summary.record(FileOutcome::Changed);
+        summary.record(FileOutcome::Skipped(SkipReason::Binary));
+        summary.record(FileOutcome::Skipped(SkipReason::Binary));
+        summary.record(FileOutcome::Skipped(SkipReason::Generated));
+        assert_eq!(summary.changed, 1);
+        assert_eq!(
+            summary.to_string(),
+            "3 skipped (2 binary, 1 generated), 0 errors"
+        );
+        assert!(summary.into_result("application").is_ok());
+    }
+
+    #[test]
+    fn reflow_text_keeps_short_lines_and_spacers() {
+        let text = "Short line\n   \nAnother";
+        assert_eq!(reflow_text(text, 40), "Short line\n\nAnother");
+    }
+
+    #[test]
+    fn reflow_text_uses_hanging_indent_for_list_items() {
+        let text = "   1. Definitions of the many terms used below.\n      (a) You must give recipients a copy.";
+        let expected = "   1. Definitions of the many\n      terms used below.\n      (a) You must give\n          recipients a copy.";
+        assert_eq!(reflow_text(text, 30), expected);
+    }
+
+    #[test]
+    fn reflow_text_tells_roman_numerals_from_words() {
+        let text = "did. you read the terms of this license before using it?";
+        assert_eq!(
+            reflow_text(text, 30),
+            "did. you read the terms of\nthis license before using it?"
+        );
+        let text = "   iv) The names of the contributors may not be used.";
+        assert_eq!(
+            reflow_text(text, 30),
+            "   iv) The names of the\n       contributors may not be\n       used."
+        );
+    }
+
+    #[test]
+    fn reflow_text_leaves_long_words_intact() {
+        let text = "see https://example.com/a/very/long/url for details";
+        assert_eq!(
+            reflow_text(text, 10),
+            "see\nhttps://example.com/a/very/long/url\nfor\ndetails"
+        );
+    }
+
+    #[test]
+    fn git_changes_select_staged_and_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path();
+        let git = |args: &[&str]| {
+            run_git(
+                repo,
+                &[
+                    &["-c", "user.name=test", "-c", "user.email=test@example.com"],
+                    args,
+                ]
+                .concat(),
+            )
+            .unwrap()
+        };
+        git(&["init", "--quiet"]);
+        for name in ["committed.rs", "modified.rs", "deleted.rs"] {
+            fs::write(repo.join(name), "fn main() {}\n").unwrap();
+        }
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "base"]);
+
+        fs::write(repo.join("modified.rs"), "fn main() { todo!() }\n").unwrap();
+        fs::write(repo.join("staged.rs"), "fn main() {}\n").unwrap();
+        fs::write(repo.join("untracked.rs"), "fn main() {}\n").unwrap();
+        fs::remove_file(repo.join("deleted.rs")).unwrap();
+        git(&["add", "staged.rs", "deleted.rs"]);
+
+        let names = |changes: &GitChanges| {
+            let mut names: Vec<String> = git_changed_files(repo, changes)
+                .unwrap()
+                .iter()
+                .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(names(&GitChanges::Staged), ["staged.rs"]);
+        assert_eq!(
+            names(&GitChanges::Since("HEAD".to_string())),
+            ["modified.rs", "staged.rs", "untracked.rs"]
+        );
+        // A ref named like a file is still read as a ref
+        git(&["branch", "modified.rs"]);
+        assert_eq!(
+            names(&GitChanges::Since("modified.rs".to_string())),
+            ["modified.rs", "staged.rs", "untracked.rs"]
+        );
+        assert!(matches!(
+            git_changed_files(repo, &GitChanges::Since("no-such-ref".to_string())),
+            Err(LichenError::Msg(_))
+        ));
+    }
+
+    #[test]
+    fn stage_files_leaves_partially_staged_files_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path();
+        let git = |args: &[&str]| {
+            run_git(
+                repo,
+                &[
+                    &["-c", "user.name=test", "-c", "user.email=test@example.com"],
+                    args,
+                ]
+                .concat(),
+            )
+            .unwrap()
+        };
+        git(&["init", "--quiet"]);
+        fs::write(repo.join("full.rs"), "fn main() {}\n").unwrap();
+        fs::write(repo.join("partial.rs"), "fn main() {}\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "base"]);
+
+        // Only part of the changes to `partial.rs` are staged
+        fs::write(repo.join("full.rs"), "fn main() { 1; }\n").unwrap();
+        fs::write(repo.join("partial.rs"), "fn main() { 1; }\n").unwrap();
+        git(&["add", "."]);
+        fs::write(repo.join("partial.rs"), "fn main() { 2; }\n").unwrap();
+        let unstaged = git_changed_files(repo, &GitChanges::Unstaged).unwrap();
+
+        let paths: Vec<PathBuf> = ["full.rs", "partial.rs"]
+            .iter()
+            .map(|name| {
+                let path = repo.join(name);
+                let content = fs::read_to_string(&path).unwrap();
+                fs::write(&path, format!("// Header\n{}", content)).unwrap();
+                fs::canonicalize(path).unwrap()
+            })
+            .collect();
+        stage_files(repo, &paths, &unstaged).unwrap();
+
+        assert_eq!(git(&["diff", "--name-only"]).trim(), "partial.rs");
+        assert_eq!(
+            git(&["show", ":partial.rs"]),
+            "fn main() { 1; }\n",
+            "The unstaged changes must stay out of the index"
+        );
+    }
+
+    #[test]
+    fn git_changes_from_args() {
+        assert_eq!(GitChanges::from_args(&None, None), None);
+        assert_eq!(
+            GitChanges::from_args(&None, Some(true)),
+            Some(GitChanges::Staged)
+        );
+        assert_eq!(
+            GitChanges::from_args(&Some("main".to_string()), None),
+            Some(GitChanges::Since("main".to_string()))
+        );
+    }
+
+    #[test]
+    fn apply_header_to_content_keeps_conventions_and_passes_through() {
+        let matcher = HeaderMatcher::new([License::MIT]);
+        let options = default_apply_options();
+        let apply = |name: &str, content: &[u8]| {
+            apply_header_to_content(
+                "Header",
+                &matcher,
+                &[],
+                Path::new(name),
+                content.to_vec(),
+                options,
+            )
+            .unwrap()
+        };
+
+        let applied = apply("src/main.rs", b"fn main() {}\r\n");
+        let text = String::from_utf8(applied.clone()).unwrap();
+        assert!(
+            text.starts_with("//") && text.contains("Header"),
+            "{:?}",
+            text
+        );
+        assert!(text.ends_with("\r\nfn main() {}\r\n"), "{:?}", text);
+        // Applying again changes nothing
+        assert_eq!(apply("src/main.rs", &applied), applied);
+
+        // Generated and binary content come back untouched
+        let generated = b"// @generated by build.rs\nfn main() {}\n";
+        assert_eq!(apply("src/lib.rs", generated), generated);
+        assert_eq!(apply("src/main.rs", b"\0\x01\x02"), b"\0\x01\x02");
+    }
+
+    #[test]
+    fn json_report_gives_each_license_its_own_diff() {
+        let mut summary = ProcessingSummary::default();
+        for (spdx_id, header) in [("MIT", "// MIT\n"), ("Apache-2.0", "// Apache\n")] {
+            let original = "fn main() {}\n".to_string();
+            let mut run = ProcessingSummary::default();
+            run.record_file(PathBuf::from("a.rs"), FileOutcome::Changed, None, None);
+            run.edits.push(FileEdit {
+                path: PathBuf::from("a.rs"),
+                updated: format!("{}{}", header, original),
+                conventions: TextConventions::detect(&original),
+                original,
+                encoding: TextEncoding::Utf8,
+                notices: Vec::new(),
+                comment_style: None,
+                finding: None,
+                license: None,
+            });
+            run.set_license(spdx_id);
+            summary.merge(run);
+        }
+
+        let report = summary.json_report(true).unwrap();
+        let files = report["files"].as_array().unwrap();
+        assert_eq!(files.len(), 2);
+        for file in files {
+            let diff = file["diff"].as_str().unwrap();
+            match file["license"].as_str().unwrap() {
+                "MIT" => assert!(diff.contains("+// MIT") && !diff.contains("Apache")),
+                _ => assert!(diff.contains("+// Apache") && !diff.contains("MIT")),
+            }
+        }
+    }
+
+    #[test]
+    fn processing_summary_reports_every_file() {
+        let mut summary = ProcessingSummary::default();
+        summary.record_file(
+            PathBuf::from("./a.rs"),
+            FileOutcome::Changed,
+            Some("line"),
+            Some(HeaderProblem::Missing),
+        );
+        summary.record_file(
+            PathBuf::from("b.png"),
+            FileOutcome::Skipped(SkipReason::Binary),
+            None,
+            None,
+        );
+        summary.set_license("MIT");
+
+        let mut other = ProcessingSummary::default();
+        other.record_file(
+            PathBuf::from("c.rs"),
+            FileOutcome::Failed(LichenError::Msg("Disk full".to_string())),
+            None,
+            None,
+        );
+        other.record_file(
+            PathBuf::from("vendor/d.c"),
+            FileOutcome::ThirdParty {
+                path: PathBuf::from("vendor/d.c"),
+                notices: vec!["Copyright 2019 D".to_string()],
+                annotated: false,
+            },
+            None,
+            None,
+        );
+        summary.merge(other);
+
+        assert_eq!(
+            (summary.changed, summary.skipped_total(), summary.errors),
+            (1, 2, 1)
+        );
+        let files = serde_json::to_value(&summary.files).unwrap();
+        assert_eq!(
+            files[0],
+            serde_json::json!({
+                "path": "a.rs",
+                "outcome": "changed",
+                "reason": null,
+                "comment_style": "line",
+                "problem": "missing-header",
+                "license": "MIT",
+                "error": null,
+                "notices": [],
+                "diff": null,
+            })
+        );
+        assert_eq!(files[1]["reason"], "binary");
+        assert_eq!(files[2]["outcome"], "failed");
+        assert_eq!(files[2]["error"], "Disk full");
+        assert_eq!(files[2]["license"], serde_json::Value::Null);
+        assert_eq!(files[3]["reason"], "third-party");
+        assert_eq!(files[3]["notices"][0], "Copyright 2019 D");
+        assert!(summary.into_result("application").is_err());
+    }
+
+    #[test]
+    fn processing_summary_lists_every_failed_file() {
+        let mut summary = ProcessingSummary::default();
+        for (name, kind) in [
+            ("a.rs", std::io::ErrorKind::PermissionDenied),
+            ("b.rs", std::io::ErrorKind::StorageFull),
+        ] {
+            let path = PathBuf::from(name);
+            let err = LichenError::from(std::io::Error::from(kind)).for_file("writing", &path);
+            summary.record_file(path, FileOutcome::Failed(err), None, None);
+        }
+
+        let err = summary.into_result("application").unwrap_err();
+        let message = err.to_string();
+        assert!(message.starts_with("2 files failed:"), "{}", message);
+        assert!(
+            message.contains("Failed writing 'a.rs': IO error"),
+            "{}",
+            message
+        );
+        assert!(
+            message.contains("Failed writing 'b.rs': IO error"),
+            "{}",
+            message
+        );
+        // Both are I/O failures, which keeps their exit code
+        assert_eq!(err.exit_code(), 5);
+    }
 }
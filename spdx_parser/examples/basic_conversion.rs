@@ -2,7 +2,6 @@
 use std::path::PathBuf;
 use std::process::ExitCode;
 // Use the actual name of your library crate defined in Cargo.toml
-use env_logger;
 use spdx_parser::convert_templates;
 
 fn main() -> ExitCode {